@@ -0,0 +1,227 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// The names of the groups and benchmarks are used by criterion to store the results in
+// `target/criterion/<group>/<benchmark>` and to compare them with the previous run, they must
+// stay stable to be able to track regressions.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Barrier;
+use std::time::{Duration, Instant};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use iceoryx2::port::update_connections::UpdateConnections;
+use iceoryx2::prelude::*;
+use iceoryx2::service::port_factory::publisher::{MemoryHygiene, UnableToDeliverStrategy};
+use iceoryx2::testing::generate_isolated_config;
+use iceoryx2_bb_log::{set_log_level, LogLevel};
+
+const PAYLOAD_SIZES: [(&str, usize); 3] = [("8B", 8), ("1KiB", 1024), ("1MiB", 1024 * 1024)];
+const NUMBER_OF_FAN_OUT_SUBSCRIBERS: [usize; 3] = [1, 2, 4];
+const MEMORY_HYGIENES: [(&str, MemoryHygiene); 3] = [
+    ("none", MemoryHygiene::None),
+    ("zero_on_release", MemoryHygiene::ZeroOnRelease),
+    ("zero_on_acquire", MemoryHygiene::ZeroOnAcquire),
+];
+
+fn service_name(name: &str) -> ServiceName {
+    ServiceName::new(&format!("benchmark/publish_subscribe/{}", name)).unwrap()
+}
+
+/// Loans a sample, writes the payload, sends it and receives it in the same thread.
+fn round_trip(c: &mut Criterion) {
+    set_log_level(LogLevel::Error);
+    let mut group = c.benchmark_group("round_trip");
+
+    for (label, size) in PAYLOAD_SIZES {
+        let config = generate_isolated_config();
+        let service = zero_copy::Service::new(&service_name("round_trip"))
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(size)
+            .history_size(0)
+            .subscriber_max_buffer_size(1)
+            .create::<[u8]>()
+            .unwrap();
+
+        let publisher = service.publisher().create().unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+        publisher.update_connections().unwrap();
+
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_function(BenchmarkId::from_parameter(label), |b| {
+            b.iter(|| {
+                let sample = publisher
+                    .loan_slice_uninit(size)
+                    .unwrap()
+                    .write_from_fn(|n| n as u8);
+                sample.send().unwrap();
+
+                let sample = subscriber.receive().unwrap().unwrap();
+                criterion::black_box(sample.len());
+            })
+        });
+    }
+
+    group.finish();
+}
+
+/// A publisher thread sends as fast as possible while the subscriber measures how long it
+/// takes to receive a number of samples. The publisher blocks when the buffer of the
+/// subscriber is full, therefore no sample is lost.
+fn saturating_publisher(c: &mut Criterion) {
+    set_log_level(LogLevel::Error);
+    let mut group = c.benchmark_group("saturating_publisher");
+
+    let config = generate_isolated_config();
+    let service = zero_copy::Service::new(&service_name("saturating_publisher"))
+        .publish_subscribe_with_custom_config(&config)
+        .history_size(0)
+        .subscriber_max_buffer_size(128)
+        .enable_safe_overflow(false)
+        .create::<u64>()
+        .unwrap();
+
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("8B", |b| {
+        b.iter_custom(|iterations| {
+            let keep_running = AtomicBool::new(true);
+            let barrier = Barrier::new(2);
+            let subscriber = service.subscriber().create().unwrap();
+
+            std::thread::scope(|s| {
+                let publisher_thread = s.spawn(|| {
+                    let publisher = service
+                        .publisher()
+                        .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+                        .create()
+                        .unwrap();
+                    publisher.update_connections().unwrap();
+                    barrier.wait();
+
+                    let mut counter: u64 = 0;
+                    while keep_running.load(Ordering::Relaxed) {
+                        publisher.send_copy(counter).unwrap();
+                        counter += 1;
+                    }
+                });
+
+                barrier.wait();
+                let start = Instant::now();
+                let mut number_of_received_samples = 0;
+                while number_of_received_samples < iterations {
+                    if subscriber.receive().unwrap().is_some() {
+                        number_of_received_samples += 1;
+                    }
+                }
+                let elapsed = start.elapsed();
+
+                // the publisher may be blocked until the subscriber makes room in its buffer
+                keep_running.store(false, Ordering::Relaxed);
+                while !publisher_thread.is_finished() {
+                    while subscriber.receive().unwrap().is_some() {}
+                }
+
+                elapsed
+            })
+        })
+    });
+
+    group.finish();
+}
+
+/// Sends one sample to multiple subscribers and receives it with every one of them.
+fn fan_out(c: &mut Criterion) {
+    set_log_level(LogLevel::Error);
+    let mut group = c.benchmark_group("fan_out");
+
+    for number_of_subscribers in NUMBER_OF_FAN_OUT_SUBSCRIBERS {
+        let config = generate_isolated_config();
+        let service = zero_copy::Service::new(&service_name("fan_out"))
+            .publish_subscribe_with_custom_config(&config)
+            .max_subscribers(number_of_subscribers)
+            .history_size(0)
+            .subscriber_max_buffer_size(1)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = service.publisher().create().unwrap();
+        let subscribers: Vec<_> = (0..number_of_subscribers)
+            .map(|_| service.subscriber().create().unwrap())
+            .collect();
+        publisher.update_connections().unwrap();
+
+        group.bench_function(
+            BenchmarkId::from_parameter(format!("1to{}", number_of_subscribers)),
+            |b| {
+                b.iter(|| {
+                    publisher.send_copy(1234).unwrap();
+                    for subscriber in &subscribers {
+                        criterion::black_box(*subscriber.receive().unwrap().unwrap());
+                    }
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// The round trip of [`round_trip`] with every [`MemoryHygiene`] to quantify the cost of
+/// wiping the payload.
+fn memory_hygiene(c: &mut Criterion) {
+    set_log_level(LogLevel::Error);
+    let mut group = c.benchmark_group("memory_hygiene");
+
+    for (hygiene_label, hygiene) in MEMORY_HYGIENES {
+        for (label, size) in PAYLOAD_SIZES {
+            let config = generate_isolated_config();
+            let service = zero_copy::Service::new(&service_name("memory_hygiene"))
+                .publish_subscribe_with_custom_config(&config)
+                .max_slice_len(size)
+                .history_size(0)
+                .subscriber_max_buffer_size(1)
+                .create::<[u8]>()
+                .unwrap();
+
+            let publisher = service
+                .publisher()
+                .memory_hygiene(hygiene)
+                .create()
+                .unwrap();
+            let subscriber = service.subscriber().create().unwrap();
+            publisher.update_connections().unwrap();
+
+            group.throughput(Throughput::Bytes(size as u64));
+            group.bench_function(BenchmarkId::new(hygiene_label, label), |b| {
+                b.iter(|| {
+                    let sample = publisher
+                        .loan_slice_uninit(size)
+                        .unwrap()
+                        .write_from_fn(|n| n as u8);
+                    sample.send().unwrap();
+
+                    let sample = subscriber.receive().unwrap().unwrap();
+                    criterion::black_box(sample.len());
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().measurement_time(Duration::from_secs(5));
+    targets = round_trip, saturating_publisher, fan_out, memory_hygiene
+}
+criterion_main!(benches);
@@ -10,19 +10,30 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use iceoryx2::config::Config;
 use iceoryx2::prelude::*;
 use iceoryx2_bb_log::set_log_level;
 use iceoryx2_bb_posix::barrier::BarrierHandle;
 use iceoryx2_bb_posix::{barrier::BarrierBuilder, clock::Time};
 
 const ITERATIONS: u64 = 10000000;
+const DRAIN_SAMPLES: usize = 1000000;
+const DRAIN_BATCH_SIZE: usize = 128;
 
-fn perform_benchmark<T: Service>() {
+fn perform_benchmark<T: Service>(enable_intra_process_fast_path: bool) {
     let service_name_a2b = ServiceName::new("a2b").unwrap();
     let service_name_b2a = ServiceName::new("b2a").unwrap();
 
+    // both threads belong to the same process, the publishers deliver via the intra process
+    // fast path unless it is disabled
+    let mut config = Config::default();
+    config
+        .defaults
+        .publish_subscribe
+        .enable_intra_process_fast_path = enable_intra_process_fast_path;
+
     let service_a2b = T::new(&service_name_a2b)
-        .publish_subscribe()
+        .publish_subscribe_with_custom_config(&config)
         .max_publishers(1)
         .max_subscribers(1)
         .history_size(0)
@@ -32,7 +43,7 @@ fn perform_benchmark<T: Service>() {
         .unwrap();
 
     let service_b2a = T::new(&service_name_b2a)
-        .publish_subscribe()
+        .publish_subscribe_with_custom_config(&config)
         .max_publishers(1)
         .max_subscribers(1)
         .history_size(0)
@@ -79,16 +90,74 @@ fn perform_benchmark<T: Service>() {
         t2.join().expect("thread failure");
         let stop = start.elapsed().expect("failed to measure time");
         println!(
-            "{} ::: Time: {}, Latency: {} ns",
+            "{} ::: Intra process fast path: {}, Time: {}, Latency: {} ns",
             std::any::type_name::<T>(),
+            enable_intra_process_fast_path,
             stop.as_secs_f64(),
             stop.as_nanos() / (ITERATIONS as u128 * 2)
         );
     });
 }
 
+fn perform_drain_benchmark<T: Service>(use_batch_receive: bool) {
+    let service_name = ServiceName::new("drain").unwrap();
+
+    let service = T::new(&service_name)
+        .publish_subscribe()
+        .max_publishers(1)
+        .max_subscribers(1)
+        .history_size(0)
+        .subscriber_max_buffer_size(DRAIN_BATCH_SIZE)
+        .subscriber_max_borrowed_samples(DRAIN_BATCH_SIZE)
+        .enable_safe_overflow(false)
+        .create::<u64>()
+        .unwrap();
+
+    let publisher = service.publisher().create().unwrap();
+    let subscriber = service.subscriber().create().unwrap();
+
+    let mut drain_time = std::time::Duration::ZERO;
+    let mut number_of_received_samples = 0;
+    while number_of_received_samples < DRAIN_SAMPLES {
+        for i in 0..DRAIN_BATCH_SIZE {
+            publisher.send_copy(i as u64).expect("failed to send");
+        }
+
+        let start = Time::now().expect("failed to acquire time");
+        if use_batch_receive {
+            number_of_received_samples += subscriber
+                .receive_batch(DRAIN_BATCH_SIZE)
+                .expect("failed to receive")
+                .len();
+        } else {
+            while subscriber.receive().expect("failed to receive").is_some() {
+                number_of_received_samples += 1;
+            }
+        }
+        drain_time += start.elapsed().expect("failed to measure time");
+    }
+
+    println!(
+        "{} ::: Drain with {}: {} ns per sample",
+        std::any::type_name::<T>(),
+        if use_batch_receive {
+            "receive_batch()"
+        } else {
+            "receive()"
+        },
+        drain_time.as_nanos() / number_of_received_samples as u128
+    );
+}
+
 fn main() {
     set_log_level(iceoryx2_bb_log::LogLevel::Error);
-    perform_benchmark::<zero_copy::Service>();
-    perform_benchmark::<process_local::Service>();
+    for enable_intra_process_fast_path in [false, true] {
+        perform_benchmark::<zero_copy::Service>(enable_intra_process_fast_path);
+    }
+    perform_benchmark::<process_local::Service>(false);
+
+    for use_batch_receive in [false, true] {
+        perform_drain_benchmark::<zero_copy::Service>(use_batch_receive);
+        perform_drain_benchmark::<process_local::Service>(use_batch_receive);
+    }
 }
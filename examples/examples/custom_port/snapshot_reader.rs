@@ -0,0 +1,100 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::advanced::{self, DataSegmentView, Header, PointerOffset, RawSample, SegmentOffset};
+use iceoryx2::config::Config;
+use iceoryx2::prelude::*;
+use transmission_data::TransmissionData;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+
+// A custom port that does not receive samples, it maps the data segment of the publisher on its
+// own and reads the sample at the offset it was handed.
+struct SnapshotReader<'config> {
+    data_segment: DataSegmentView<'config, zero_copy::Service<'config>>,
+    segment_id: usize,
+    payload_offset: usize,
+}
+
+impl SnapshotReader<'_> {
+    fn snapshot(&self, offset: PointerOffset) -> Option<TransmissionData> {
+        let offset = SegmentOffset::from(offset);
+        if offset.segment_id() != self.segment_id {
+            return None;
+        }
+
+        let address = self.data_segment.address_of(offset)?;
+        // SAFETY: the publisher initialized the sample and does not modify it until it is sent
+        let sample = unsafe {
+            RawSample::<Header, (), TransmissionData>::from_header_and_payload_ptrs(
+                address as *const _,
+                (address + self.payload_offset) as *const TransmissionData,
+            )
+        };
+
+        if sample.as_header_ref().layout_version() != Header::LAYOUT_VERSION {
+            return None;
+        }
+
+        let payload = sample.as_data_ref();
+        Some(TransmissionData {
+            x: payload.x,
+            y: payload.y,
+            funky: payload.funky,
+        })
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new("My/Funk/ServiceName")?;
+
+    let service = zero_copy::Service::new(&service_name)
+        .publish_subscribe()
+        .open_or_create::<TransmissionData>()?;
+
+    let publisher = service.publisher().create()?;
+
+    let reader = SnapshotReader {
+        data_segment: DataSegmentView::open(
+            Config::get_global_config(),
+            &publisher.data_segment_name(),
+        )?,
+        segment_id: publisher.segment_id(),
+        payload_offset: advanced::payload_offset::<()>(service.static_config().payload_alignment()),
+    };
+
+    let mut counter: u64 = 0;
+
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        counter += 1;
+        let sample = publisher.loan_uninit()?;
+
+        let sample = sample.write_payload(TransmissionData {
+            x: counter as i32,
+            y: counter as i32 * 3,
+            funky: counter as f64 * 812.12,
+        });
+
+        match reader.snapshot(advanced::offset_of(&sample)) {
+            // the sample is read before it is sent, afterwards it can be reclaimed at any time
+            Some(snapshot) => println!("Snapshot of sample {}: {:?}", counter, snapshot),
+            None => println!("sample is not stored in the mapped data segment"),
+        }
+
+        sample.send()?;
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+use iceoryx2::service::discovery::{ServiceDiscovery, ServiceEvent};
+use iceoryx2::service::ServiceDetails;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+
+fn is_sensor(service: &ServiceDetails) -> bool {
+    service.service_name().as_str().starts_with("sensors/")
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut discovery = ServiceDiscovery::<zero_copy::Service>::new()?;
+
+    // the sensors that existed before the mirror was started
+    let mut sensors: Vec<ServiceDetails> = discovery
+        .services()
+        .iter()
+        .filter(|s| is_sensor(s))
+        .cloned()
+        .collect();
+
+    for sensor in &sensors {
+        println!("attached: {}", sensor.service_name());
+    }
+
+    while let Iox2Event::Tick = Iox2::wait(Duration::ZERO) {
+        for event in discovery.timed_wait_for_change(CYCLE_TIME)? {
+            if !is_sensor(event.details()) {
+                continue;
+            }
+
+            match event {
+                ServiceEvent::Created(sensor) => {
+                    println!("attached: {}", sensor.service_name());
+                    sensors.push(sensor);
+                }
+                ServiceEvent::Removed(sensor) => {
+                    println!("detached: {}", sensor.service_name());
+                    sensors.retain(|s| s.instance_uuid() != sensor.instance_uuid());
+                }
+            }
+
+            println!("number of mirrored sensors: {}", sensors.len());
+        }
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
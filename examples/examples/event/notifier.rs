@@ -23,10 +23,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .open_or_create()?;
 
     let notifier = event.notifier().create()?;
+    let max_event_id = event.static_config().event_id_max_value();
 
     let mut counter: u64 = 0;
     while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
-        counter += 1;
+        counter = counter % max_event_id + 1;
         notifier.notify_with_custom_event_id(EventId::new(counter))?;
 
         println!("Trigger event with id {} ...", counter);
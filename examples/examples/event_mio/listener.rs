@@ -0,0 +1,65 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(unix)]
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    use core::time::Duration;
+    use iceoryx2::prelude::*;
+    use mio::unix::SourceFd;
+    use mio::{Events, Interest, Poll, Token};
+    use std::os::unix::io::AsRawFd;
+
+    const LISTENER: Token = Token(0);
+    const CYCLE_TIME: Duration = Duration::from_secs(1);
+
+    let event = zero_copy::Service::new(&ServiceName::new("MyEventName")?)
+        .event()
+        .open_or_create()?;
+
+    let mut listener = event.listener().create()?;
+
+    let mut poll = Poll::new()?;
+    let mut events = Events::with_capacity(16);
+    let listener_fd = listener.as_raw_fd();
+    poll.registry()
+        .register(&mut SourceFd(&listener_fd), LISTENER, Interest::READABLE)?;
+
+    while let Iox2Event::Tick = Iox2::wait(Duration::ZERO) {
+        match poll.poll(&mut events, Some(CYCLE_TIME)) {
+            Ok(()) => (),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+
+        for event in events.iter() {
+            if event.token() == LISTENER {
+                // mio is edge triggered, try_wait() consumes all pending notifications so
+                // that the next notification is reported again
+                for event_id in listener.try_wait()? {
+                    println!("event was triggered with id: {:?}", event_id);
+                }
+            }
+        }
+    }
+
+    // the file descriptor is closed when the listener is dropped, remove it before
+    poll.registry().deregister(&mut SourceFd(&listener_fd))?;
+
+    println!("exit ...");
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn main() {
+    println!("This example requires a unix platform.");
+}
@@ -0,0 +1,44 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+
+const CYCLE_TIME: Duration = Duration::from_millis(700);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let event_a = zero_copy::Service::new(&ServiceName::new("MyEventName")?)
+        .event()
+        .open_or_create()?;
+    let event_b = zero_copy::Service::new(&ServiceName::new("MyOtherEventName")?)
+        .event()
+        .open_or_create()?;
+
+    let notifier_a = event_a.notifier().create()?;
+    let notifier_b = event_b.notifier().create()?;
+
+    let mut counter: u64 = 0;
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        counter += 1;
+        notifier_a.notify_with_custom_event_id(EventId::new(counter % 16))?;
+        println!("Trigger MyEventName with id {} ...", counter % 16);
+
+        if counter % 3 == 0 {
+            notifier_b.notify_with_custom_event_id(EventId::new(counter % 16))?;
+            println!("Trigger MyOtherEventName with id {} ...", counter % 16);
+        }
+    }
+
+    println!("exit ... ");
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+use iceoryx2::waitset::{WaitSetBuilder, WaitSetRunError};
+
+const TICK: Duration = Duration::from_millis(100);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let event_a = zero_copy::Service::new(&ServiceName::new("MyEventName")?)
+        .event()
+        .open_or_create()?;
+    let event_b = zero_copy::Service::new(&ServiceName::new("MyOtherEventName")?)
+        .event()
+        .open_or_create()?;
+
+    let mut listener_a = event_a.listener().create()?;
+    let mut listener_b = event_b.listener().create()?;
+
+    let waitset = WaitSetBuilder::new().create()?;
+    let guard_a = waitset.attach(&listener_a)?;
+    let guard_b = waitset.attach(&listener_b)?;
+    let tick_guard = waitset.attach_interval(TICK)?;
+
+    let mut number_of_ticks: u64 = 0;
+    let mut triggered = vec![];
+
+    while let Iox2Event::Tick = Iox2::wait(Duration::ZERO) {
+        triggered.clear();
+        match waitset.wait_and_process(|attachment_id| triggered.push(attachment_id)) {
+            Ok(()) => (),
+            Err(WaitSetRunError::Interrupt) => break,
+            Err(e) => return Err(e.into()),
+        }
+
+        for attachment_id in &triggered {
+            if *attachment_id == guard_a.id() {
+                for event_id in listener_a.try_wait()? {
+                    println!("MyEventName was triggered with id: {:?}", event_id);
+                }
+            } else if *attachment_id == guard_b.id() {
+                for event_id in listener_b.try_wait()? {
+                    println!("MyOtherEventName was triggered with id: {:?}", event_id);
+                }
+            } else if *attachment_id == tick_guard.id() {
+                number_of_ticks += 1;
+                if number_of_ticks % 10 == 0 {
+                    println!("tick {}", number_of_ticks);
+                }
+            }
+        }
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
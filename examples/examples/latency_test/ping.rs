@@ -0,0 +1,78 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use std::time::Instant;
+
+use hdrhistogram::Histogram;
+use iceoryx2::prelude::*;
+
+const WARM_UP_ITERATIONS: u64 = 1000;
+const ITERATIONS: u64 = 100000;
+const STOP_PONG: u64 = u64::MAX;
+const CONNECTION_CYCLE_TIME: Duration = Duration::from_millis(10);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let ping_service = zero_copy::Service::new(&ServiceName::new("Latency/Ping")?)
+        .publish_subscribe()
+        .history_size(0)
+        .subscriber_max_buffer_size(1)
+        .open_or_create::<u64>()?;
+    let pong_service = zero_copy::Service::new(&ServiceName::new("Latency/Pong")?)
+        .publish_subscribe()
+        .history_size(0)
+        .subscriber_max_buffer_size(1)
+        .open_or_create::<u64>()?;
+
+    let publisher = ping_service.publisher().create()?;
+    let subscriber = pong_service.subscriber().create()?;
+
+    println!("waiting for pong ...");
+    while ping_service.dynamic_config().number_of_subscribers() == 0
+        || pong_service.dynamic_config().number_of_publishers() == 0
+    {
+        if let Iox2Event::TerminationRequest = Iox2::wait(CONNECTION_CYCLE_TIME) {
+            return Ok(());
+        }
+    }
+
+    // the round trip time is recorded in nanoseconds, everything above one minute is out of
+    // range
+    let mut histogram = Histogram::<u64>::new_with_bounds(1, 60_000_000_000, 3)?;
+
+    for i in 0..WARM_UP_ITERATIONS + ITERATIONS {
+        let start = Instant::now();
+        while publisher.send_copy(i)? == 0 {}
+
+        loop {
+            if let Some(sample) = subscriber.receive()? {
+                if *sample == i {
+                    break;
+                }
+            }
+        }
+
+        if i >= WARM_UP_ITERATIONS {
+            histogram.saturating_record(start.elapsed().as_nanos() as u64);
+        }
+    }
+
+    publisher.send_copy(STOP_PONG)?;
+
+    println!("round trip latency of {} samples:", histogram.len());
+    println!("  p50:  {} ns", histogram.value_at_quantile(0.5));
+    println!("  p99:  {} ns", histogram.value_at_quantile(0.99));
+    println!("  p999: {} ns", histogram.value_at_quantile(0.999));
+    println!("  max:  {} ns", histogram.max());
+
+    Ok(())
+}
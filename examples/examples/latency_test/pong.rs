@@ -0,0 +1,48 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::*;
+
+const STOP_PONG: u64 = u64::MAX;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let ping_service = zero_copy::Service::new(&ServiceName::new("Latency/Ping")?)
+        .publish_subscribe()
+        .history_size(0)
+        .subscriber_max_buffer_size(1)
+        .open_or_create::<u64>()?;
+    let pong_service = zero_copy::Service::new(&ServiceName::new("Latency/Pong")?)
+        .publish_subscribe()
+        .history_size(0)
+        .subscriber_max_buffer_size(1)
+        .open_or_create::<u64>()?;
+
+    let subscriber = ping_service.subscriber().create()?;
+    let publisher = pong_service.publisher().create()?;
+
+    println!("answering pings ...");
+
+    // busy waiting, a sleep between two receive calls would be part of the measured latency
+    loop {
+        if let Some(sample) = subscriber.receive()? {
+            if *sample == STOP_PONG {
+                break;
+            }
+
+            while publisher.send_copy(*sample)? == 0 {}
+        }
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
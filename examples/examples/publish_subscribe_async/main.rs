@@ -0,0 +1,87 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+use transmission_data::TransmissionData;
+
+const CYCLE_TIME: Duration = Duration::from_millis(500);
+const NUMBER_OF_SAMPLES: u64 = 10;
+
+fn service_name() -> ServiceName {
+    ServiceName::new("My/Funk/AsyncServiceName").unwrap()
+}
+
+async fn publish() -> Result<(), Box<dyn std::error::Error>> {
+    let service = zero_copy::Service::new(&service_name())
+        .publish_subscribe()
+        .enable_notifications(true)
+        .open_or_create::<TransmissionData>()?;
+
+    let publisher = service.publisher().create()?;
+    // the first sample is sent after one cycle, when the subscriber is connected
+    let mut interval =
+        tokio::time::interval_at(tokio::time::Instant::now() + CYCLE_TIME, CYCLE_TIME);
+
+    for counter in 1..=NUMBER_OF_SAMPLES {
+        interval.tick().await;
+
+        let sample = publisher.loan_uninit()?;
+        let sample = sample.write_payload(TransmissionData {
+            x: counter as i32,
+            y: counter as i32 * 3,
+            funky: counter as f64 * 812.12,
+        });
+        sample.send()?;
+
+        println!("Send sample {} ...", counter);
+    }
+
+    // dropping the publisher lets receive_async() of the subscriber return None
+    Ok(())
+}
+
+async fn receive() -> Result<(), Box<dyn std::error::Error>> {
+    let service = zero_copy::Service::new(&service_name())
+        .publish_subscribe()
+        .enable_notifications(true)
+        .open_or_create::<TransmissionData>()?;
+
+    let subscriber = service.subscriber().create()?;
+
+    while let Some(sample) = subscriber.receive_async().await? {
+        println!("received: {:?}", *sample);
+    }
+
+    println!("publisher disconnected, exit ...");
+
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // the ports are bound to the thread that created them, all tasks run on the same thread
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    let tasks = tokio::task::LocalSet::new();
+
+    tasks.block_on(&runtime, async {
+        // the tasks are started in the order they were spawned, the publisher task creates its
+        // publisher before it waits for the first time and the subscriber finds it connected
+        let publisher = tokio::task::spawn_local(publish());
+        let subscriber = tokio::task::spawn_local(receive());
+
+        publisher.await??;
+        subscriber.await??;
+        Ok::<(), Box<dyn std::error::Error>>(())
+    })
+}
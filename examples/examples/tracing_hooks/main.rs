@@ -0,0 +1,121 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use iceoryx2::port::tracing_hooks::TracingHooks;
+use iceoryx2::prelude::*;
+use iceoryx2::service::header::publish_subscribe::Header;
+use transmission_data::TransmissionData;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+
+// carries the trace id from the publisher to the subscriber
+#[derive(Debug, Default, ZeroCopySend)]
+#[repr(C)]
+struct TraceContext {
+    trace_id: u64,
+}
+
+// starts a new trace for every loaned sample and records when it is sent
+#[derive(Default)]
+struct PublisherTracing {
+    next_trace_id: AtomicU64,
+}
+
+impl TracingHooks<TraceContext> for PublisherTracing {
+    fn on_loan(&self, _header: &Header, user_header: &mut TraceContext) {
+        user_header.trace_id = self.next_trace_id.fetch_add(1, Ordering::Relaxed) + 1;
+    }
+
+    fn on_send(&self, header: &Header, user_header: &TraceContext) {
+        let span = tracing::info_span!(
+            "iceoryx2.send",
+            trace_id = user_header.trace_id,
+            sequence_number = header.sequence_number()
+        );
+        span.in_scope(|| tracing::info!("sample sent"));
+    }
+}
+
+// keeps a span open from the reception of a sample until it is released, it covers the
+// processing time of the sample
+#[derive(Default)]
+struct SubscriberTracing {
+    spans: Mutex<HashMap<u64, tracing::Span>>,
+}
+
+impl TracingHooks<TraceContext> for SubscriberTracing {
+    fn on_receive(&self, header: &Header, user_header: &TraceContext) {
+        let span = tracing::info_span!(
+            "iceoryx2.process",
+            trace_id = user_header.trace_id,
+            sequence_number = header.sequence_number()
+        );
+        self.spans
+            .lock()
+            .unwrap()
+            .insert(header.sequence_number(), span);
+    }
+
+    fn on_release(&self, header: &Header, _user_header: &TraceContext) {
+        // dropping the span closes it
+        if let Some(span) = self.spans.lock().unwrap().remove(&header.sequence_number()) {
+            span.in_scope(|| tracing::info!("sample released"));
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new("My/Funk/TracedServiceName")?;
+
+    let service = zero_copy::Service::new(&service_name)
+        .publish_subscribe()
+        .user_header::<TraceContext>()
+        .open_or_create::<TransmissionData>()?;
+
+    // install a tracing subscriber, for instance of tracing-opentelemetry, to export the spans
+    let mut publisher = service.publisher().create()?;
+    publisher.set_tracing_hooks(Some(PublisherTracing::default()));
+    let mut subscriber = service.subscriber().create()?;
+    subscriber.set_tracing_hooks(Some(SubscriberTracing::default()));
+
+    let mut counter: u64 = 0;
+
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        counter += 1;
+        let sample = publisher.loan_uninit()?;
+
+        let sample = sample.write_payload(TransmissionData {
+            x: counter as i32,
+            y: counter as i32 * 3,
+            funky: counter as f64 * 812.12,
+        });
+
+        sample.send()?;
+
+        while let Some(sample) = subscriber.receive()? {
+            println!(
+                "received: {:?} of trace {}",
+                *sample,
+                sample.user_header().trace_id
+            );
+        }
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
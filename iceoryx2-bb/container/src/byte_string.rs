@@ -37,6 +37,7 @@ use std::{
 };
 
 use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_core::zero_copy_send::{layout_hash, ZeroCopySend};
 
 /// Returns the length of a string
 ///
@@ -73,6 +74,14 @@ pub struct FixedSizeByteString<const CAPACITY: usize> {
 unsafe impl<const CAPACITY: usize> Send for FixedSizeByteString<CAPACITY> {}
 unsafe impl<const CAPACITY: usize> Sync for FixedSizeByteString<CAPACITY> {}
 
+// the bytes are stored inline
+unsafe impl<const CAPACITY: usize> ZeroCopySend for FixedSizeByteString<CAPACITY> {
+    const LAYOUT_HASH: Option<u64> = Some(layout_hash::with_value(
+        layout_hash::of_layout::<Self>(layout_hash::of_name("FixedSizeByteString")),
+        CAPACITY as u64,
+    ));
+}
+
 impl<const CAPACITY: usize> Hash for FixedSizeByteString<CAPACITY> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         state.write(self.as_bytes())
@@ -157,6 +166,81 @@ impl<const CAPACITY: usize, const BYTE_CAPACITY: usize> From<&[u8; BYTE_CAPACITY
     }
 }
 
+impl<const CAPACITY: usize> TryFrom<&[u8]> for FixedSizeByteString<CAPACITY> {
+    type Error = FixedSizeByteStringModificationError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(value)
+    }
+}
+
+impl<const CAPACITY: usize> TryFrom<&str> for FixedSizeByteString<CAPACITY> {
+    type Error = FixedSizeByteStringModificationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_bytes(value.as_bytes())
+    }
+}
+
+impl<const CAPACITY: usize> TryFrom<String> for FixedSizeByteString<CAPACITY> {
+    type Error = FixedSizeByteStringModificationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_bytes(value.as_bytes())
+    }
+}
+
+// strings that are valid UTF-8 are human readable in text based formats, all others are
+// serialized as bytes
+#[cfg(feature = "serde")]
+impl<const CAPACITY: usize> serde::Serialize for FixedSizeByteString<CAPACITY> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match std::str::from_utf8(self.as_bytes()) {
+            Ok(value) => serializer.serialize_str(value),
+            Err(_) => serializer.serialize_bytes(self.as_bytes()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const CAPACITY: usize> serde::Deserialize<'de> for FixedSizeByteString<CAPACITY> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedSizeByteStringVisitor<const CAPACITY: usize>;
+
+        impl<'de, const CAPACITY: usize> serde::de::Visitor<'de> for FixedSizeByteStringVisitor<CAPACITY> {
+            type Value = FixedSizeByteString<CAPACITY>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(formatter, "a string with at most {} bytes", CAPACITY)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                self.visit_bytes(v.as_bytes())
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                FixedSizeByteString::from_bytes(v).map_err(|_| E::invalid_length(v.len(), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut value = FixedSizeByteString::new();
+                while let Some(byte) = seq.next_element()? {
+                    if value.push(byte).is_err() {
+                        return Err(serde::de::Error::invalid_length(CAPACITY + 1, &self));
+                    }
+                }
+
+                Ok(value)
+            }
+        }
+
+        deserializer.deserialize_bytes(FixedSizeByteStringVisitor::<CAPACITY>)
+    }
+}
+
 impl<const CAPACITY: usize> Default for FixedSizeByteString<CAPACITY> {
     fn default() -> Self {
         Self::new()
@@ -242,6 +326,29 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
         new_self
     }
 
+    /// Creates a new [`FixedSizeByteString`] from a byte slice in a const context, for instance to
+    /// initialize a `static`. When the bytes exceed the capacity it panics which turns into a
+    /// compile time error when it is evaluated in a const context.
+    pub const fn from_bytes_const(bytes: &[u8]) -> Self {
+        if CAPACITY < bytes.len() {
+            panic!("Insufficient capacity to store bytes.");
+        }
+
+        let mut new_self = Self::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            new_self.data[i] = MaybeUninit::new(bytes[i]);
+            i += 1;
+        }
+
+        if bytes.len() < CAPACITY {
+            new_self.data[bytes.len()] = MaybeUninit::new(0);
+        }
+        new_self.len = bytes.len();
+
+        new_self
+    }
+
     /// Creates a new byte string from a given null-terminated string
     ///
     /// # Safety
@@ -189,7 +189,8 @@ mod details {
                 .is_initialized
                 .load(std::sync::atomic::Ordering::Relaxed)
             {
-                fatal_panic!(from source, "Undefined behavior - the object was not initialized with 'init' before.");
+                // the source is formatted only on failure, the callers must not allocate
+                fatal_panic!(from format!("Queue<{}>::{}", std::any::type_name::<T>(), source), "Undefined behavior - the object was not initialized with 'init' before.");
             }
         }
 
@@ -234,7 +235,7 @@ mod details {
                 return None;
             }
 
-            self.verify_init(&format!("Queue<{}>::pop()", std::any::type_name::<T>()));
+            self.verify_init("pop()");
             let index = (self.start - self.len) % self.capacity;
             self.len -= 1;
             let value = std::mem::replace(
@@ -250,7 +251,7 @@ mod details {
                 return false;
             }
 
-            self.verify_init(&format!("Queue<{}>::push()", std::any::type_name::<T>()));
+            self.verify_init("push()");
 
             self.unchecked_push(value);
             true
@@ -265,10 +266,7 @@ mod details {
                 None
             };
 
-            self.verify_init(&format!(
-                "Queue<{}>::push_with_overflow()",
-                std::any::type_name::<T>()
-            ));
+            self.verify_init("push_with_overflow()");
             self.unchecked_push(value);
             overridden_value
         }
@@ -74,6 +74,13 @@ use iceoryx2_bb_elementary::{
     relocatable_ptr::RelocatablePointer,
 };
 use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_core::zero_copy_send::{layout_hash, ZeroCopySend};
+
+/// Error which can occur when a [`FixedSizeVec`] is created from another container.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FixedSizeVecCreateError {
+    ExceedsCapacity,
+}
 
 /// **Non-movable** relocatable vector with runtime fixed size capacity.
 #[repr(C)]
@@ -142,14 +149,14 @@ impl<T> Deref for Vec<T> {
     type Target = [T];
 
     fn deref(&self) -> &Self::Target {
-        self.verify_init(&format!("Vec<{}>::push()", std::any::type_name::<T>()));
+        self.verify_init("push()");
         unsafe { core::slice::from_raw_parts((*self.data_ptr.as_ptr()).as_ptr(), self.len) }
     }
 }
 
 impl<T> DerefMut for Vec<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        self.verify_init(&format!("Vec<{}>::push()", std::any::type_name::<T>()));
+        self.verify_init("push()");
         unsafe {
             core::slice::from_raw_parts_mut((*self.data_ptr.as_mut_ptr()).as_mut_ptr(), self.len)
         }
@@ -180,7 +187,8 @@ impl<T> Vec<T> {
             .is_initialized
             .load(std::sync::atomic::Ordering::Relaxed)
         {
-            fatal_panic!(from source, "Undefined behavior - the object was not initialized with 'init' before.");
+            // the source is formatted only on failure, the callers must not allocate
+            fatal_panic!(from format!("Vec<{}>::{}", std::any::type_name::<T>(), source), "Undefined behavior - the object was not initialized with 'init' before.");
         }
     }
 
@@ -221,7 +229,7 @@ impl<T> Vec<T> {
             return false;
         }
 
-        self.verify_init(&format!("Vec<{}>::push()", std::any::type_name::<T>()));
+        self.verify_init("push()");
         self.push_unchecked(value);
         true
     }
@@ -283,10 +291,59 @@ impl<T> Vec<T> {
             return None;
         }
 
-        self.verify_init(&format!("Vec<{}>::pop()", std::any::type_name::<T>()));
+        self.verify_init("pop()");
         Some(self.pop_unchecked())
     }
 
+    /// Inserts an element at the provided index and shifts all following elements to the
+    /// right. If the index is out of bounds it panics. If the vector is full and the element
+    /// cannot be added it returns false, otherwise true.
+    ///
+    /// # Safety
+    ///
+    ///  * Only use this method when [`Vec::init()`] was called before
+    ///
+    pub unsafe fn insert(&mut self, idx: usize, value: T) -> bool {
+        if self.len < idx {
+            fatal_panic!(from "Vec::insert()",
+                "Unable to insert the element at position {} since it is out of bounds.", idx);
+        }
+
+        if self.is_full() {
+            return false;
+        }
+
+        self.verify_init("insert()");
+        let element = self.data_ptr.as_mut_ptr().add(idx);
+        std::ptr::copy(element, element.add(1), self.len - idx);
+        element.write(MaybeUninit::new(value));
+        self.len += 1;
+
+        true
+    }
+
+    /// Removes the element at the provided index, shifts all following elements to the left
+    /// and returns it to the user. If the index is out of bounds it panics.
+    ///
+    /// # Safety
+    ///
+    ///  * Only use this method when [`Vec::init()`] was called before
+    ///
+    pub unsafe fn remove(&mut self, idx: usize) -> T {
+        if self.len <= idx {
+            fatal_panic!(from "Vec::remove()",
+                "Unable to remove the element at position {} since it is out of bounds.", idx);
+        }
+
+        self.verify_init("remove()");
+        let element = self.data_ptr.as_mut_ptr().add(idx);
+        let value = element.read().assume_init();
+        std::ptr::copy(element.add(1), element, self.len - idx - 1);
+        self.len -= 1;
+
+        value
+    }
+
     /// Removes all elements from the vector
     ///
     /// # Safety
@@ -363,9 +420,97 @@ impl<T: Clone, const CAPACITY: usize> Clone for FixedSizeVec<T, CAPACITY> {
     }
 }
 
+impl<T: Clone, const CAPACITY: usize> TryFrom<&[T]> for FixedSizeVec<T, CAPACITY> {
+    type Error = FixedSizeVecCreateError;
+
+    fn try_from(value: &[T]) -> Result<Self, Self::Error> {
+        let mut new_self = Self::new();
+        if !new_self.extend_from_slice(value) {
+            fail!(from "FixedSizeVec::try_from()", with FixedSizeVecCreateError::ExceedsCapacity,
+                "Unable to create the vector from a slice with {} elements since it would exceed the capacity of {}.",
+                value.len(), CAPACITY);
+        }
+
+        Ok(new_self)
+    }
+}
+
+impl<T, const CAPACITY: usize> TryFrom<std::vec::Vec<T>> for FixedSizeVec<T, CAPACITY> {
+    type Error = FixedSizeVecCreateError;
+
+    fn try_from(value: std::vec::Vec<T>) -> Result<Self, Self::Error> {
+        if CAPACITY < value.len() {
+            fail!(from "FixedSizeVec::try_from()", with FixedSizeVecCreateError::ExceedsCapacity,
+                "Unable to create the vector from a vector with {} elements since it would exceed the capacity of {}.",
+                value.len(), CAPACITY);
+        }
+
+        let mut new_self = Self::new();
+        for element in value {
+            new_self.push(element);
+        }
+
+        Ok(new_self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const CAPACITY: usize> serde::Serialize for FixedSizeVec<T, CAPACITY> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const CAPACITY: usize> serde::Deserialize<'de>
+    for FixedSizeVec<T, CAPACITY>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedSizeVecVisitor<T, const CAPACITY: usize>(std::marker::PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const CAPACITY: usize> serde::de::Visitor<'de>
+            for FixedSizeVecVisitor<T, CAPACITY>
+        {
+            type Value = FixedSizeVec<T, CAPACITY>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(formatter, "a sequence with at most {} elements", CAPACITY)
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut vec = FixedSizeVec::new();
+                while let Some(element) = seq.next_element()? {
+                    if !vec.push(element) {
+                        return Err(serde::de::Error::invalid_length(CAPACITY + 1, &self));
+                    }
+                }
+
+                Ok(vec)
+            }
+        }
+
+        deserializer.deserialize_seq(FixedSizeVecVisitor::<T, CAPACITY>(std::marker::PhantomData))
+    }
+}
+
 unsafe impl<T: Send, const CAPACITY: usize> Send for FixedSizeVec<T, CAPACITY> {}
 unsafe impl<T: Sync, const CAPACITY: usize> Sync for FixedSizeVec<T, CAPACITY> {}
 
+// the elements are stored inline and are referred to by a relative pointer that stays valid in
+// every process
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeVec<T, CAPACITY> {
+    const LAYOUT_HASH: Option<u64> = layout_hash::combine(
+        layout_hash::with_value(
+            layout_hash::of_layout::<Self>(layout_hash::of_name("FixedSizeVec")),
+            CAPACITY as u64,
+        ),
+        T::LAYOUT_HASH,
+    );
+}
+
 impl<T, const CAPACITY: usize> FixedSizeVec<T, CAPACITY> {
     /// Creates a new vector.
     pub fn new() -> Self {
@@ -414,12 +559,25 @@ impl<T, const CAPACITY: usize> FixedSizeVec<T, CAPACITY> {
         unsafe { self.state.extend_from_slice(other) }
     }
 
+    /// Inserts an element at the provided index and shifts all following elements to the
+    /// right. If the index is out of bounds it panics. If the vector is full and the element
+    /// cannot be added it returns false, otherwise true.
+    pub fn insert(&mut self, idx: usize, value: T) -> bool {
+        unsafe { self.state.insert(idx, value) }
+    }
+
     /// Removes the last element of the vector and returns it to the user. If the vector is empty
     /// it returns [`None`].
     pub fn pop(&mut self) -> Option<T> {
         unsafe { self.state.pop() }
     }
 
+    /// Removes the element at the provided index, shifts all following elements to the left
+    /// and returns it to the user. If the index is out of bounds it panics.
+    pub fn remove(&mut self, idx: usize) -> T {
+        unsafe { self.state.remove(idx) }
+    }
+
     /// Removes all elements from the vector
     pub fn clear(&mut self) {
         unsafe { self.state.clear() }
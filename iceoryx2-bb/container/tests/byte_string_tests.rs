@@ -244,6 +244,19 @@ fn fixed_size_byte_string_new_unchecked_works() {
     assert_that!(sut.as_bytes_with_nul(), eq b"\0");
 }
 
+#[test]
+fn fixed_size_byte_string_from_bytes_const_works() {
+    const SUT: Sut = Sut::from_bytes_const(b"const me up");
+
+    assert_that!(SUT, len 11);
+    assert_that!(SUT, eq b"const me up");
+    assert_that!(SUT.as_bytes_with_nul(), eq b"const me up\0");
+
+    let sut = Sut::from_bytes_const(b"");
+    assert_that!(sut, len 0);
+    assert_that!(sut.as_bytes_with_nul(), eq b"\0");
+}
+
 #[test]
 fn fixed_size_byte_string_truncate_works() {
     let mut sut = unsafe { Sut::new_unchecked(b"droubadix") };
@@ -343,3 +356,18 @@ fn fixed_size_byte_string_strip_suffix_works() {
     assert_that!(sut_clone.strip_suffix(b"msla"), eq false);
     assert_that!(sut_clone, eq sut);
 }
+
+#[test]
+fn fixed_size_byte_string_try_from_works() {
+    let sut = FixedSizeByteString::<5>::try_from("hello").unwrap();
+    assert_that!(sut, eq b"hello");
+
+    let sut = FixedSizeByteString::<5>::try_from("hell".to_string()).unwrap();
+    assert_that!(sut, eq b"hell");
+
+    let sut = FixedSizeByteString::<5>::try_from(b"hel".as_slice()).unwrap();
+    assert_that!(sut, eq b"hel");
+
+    let sut = FixedSizeByteString::<5>::try_from("hello world");
+    assert_that!(sut.err(), eq Some(FixedSizeByteStringModificationError::InsertWouldExceedCapacity));
+}
@@ -197,3 +197,45 @@ fn fixed_size_vec_clone_works() {
     assert_that!(Sut::new() == sut1, eq true);
     assert_that!(sut == sut2, eq true);
 }
+
+#[test]
+fn fixed_size_vec_insert_and_remove_works() {
+    let mut sut = FixedSizeVec::<usize, 4>::new();
+
+    assert_that!(sut.insert(0, 3), eq true);
+    assert_that!(sut.insert(0, 1), eq true);
+    assert_that!(sut.insert(1, 2), eq true);
+    assert_that!(sut.insert(3, 4), eq true);
+    assert_that!(sut.insert(2, 5), eq false);
+    assert_that!(*sut, eq [1, 2, 3, 4]);
+
+    assert_that!(sut.remove(1), eq 2);
+    assert_that!(*sut, eq [1, 3, 4]);
+    assert_that!(sut.remove(2), eq 4);
+    assert_that!(sut.remove(0), eq 1);
+    assert_that!(*sut, eq[3]);
+}
+
+#[test]
+#[should_panic]
+fn fixed_size_vec_remove_out_of_bounds_panics() {
+    let mut sut = FixedSizeVec::<usize, 4>::new();
+    sut.push(1);
+
+    sut.remove(1);
+}
+
+#[test]
+fn fixed_size_vec_try_from_fails_when_capacity_is_exceeded() {
+    let sut = FixedSizeVec::<usize, 3>::try_from([1, 2, 3].as_slice()).unwrap();
+    assert_that!(*sut, eq [1, 2, 3]);
+
+    let sut = FixedSizeVec::<usize, 3>::try_from(vec![4, 5]).unwrap();
+    assert_that!(*sut, eq [4, 5]);
+
+    let sut = FixedSizeVec::<usize, 3>::try_from([1, 2, 3, 4].as_slice());
+    assert_that!(sut.err(), eq Some(FixedSizeVecCreateError::ExceedsCapacity));
+
+    let sut = FixedSizeVec::<usize, 3>::try_from(vec![1, 2, 3, 4]);
+    assert_that!(sut.err(), eq Some(FixedSizeVecCreateError::ExceedsCapacity));
+}
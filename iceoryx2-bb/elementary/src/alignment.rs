@@ -0,0 +1,50 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Contains the [`Alignment`] of a memory region which is always a power of two.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_elementary::alignment::Alignment;
+//!
+//! let alignment = Alignment::new(64).unwrap();
+//! assert_eq!(alignment.value(), 64);
+//!
+//! assert!(Alignment::new(48).is_none());
+//! assert_eq!(Alignment::of::<u32>().value(), std::mem::align_of::<u32>());
+//! ```
+
+/// The alignment of a memory region, it is guaranteed to be a power of two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Alignment(usize);
+
+impl Alignment {
+    /// Creates a new [`Alignment`]. Returns [`None`] when the value is not a power of two.
+    pub const fn new(value: usize) -> Option<Self> {
+        if value.is_power_of_two() {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [`Alignment`] of the type `T`.
+    pub const fn of<T>() -> Self {
+        Self(std::mem::align_of::<T>())
+    }
+
+    /// Returns the underlying value.
+    pub const fn value(&self) -> usize {
+        self.0
+    }
+}
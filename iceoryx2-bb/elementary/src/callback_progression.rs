@@ -0,0 +1,38 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Returned by callbacks that visit a sequence of elements to decide if the iteration shall
+//! continue.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_elementary::callback_progression::CallbackProgression;
+//!
+//! let visit = |value: &u32| match *value {
+//!     2 => CallbackProgression::Stop,
+//!     _ => CallbackProgression::Continue,
+//! };
+//! assert_eq!(visit(&1), CallbackProgression::Continue);
+//! assert_eq!(visit(&2), CallbackProgression::Stop);
+//! ```
+
+/// Tells the caller of a callback if it shall continue the iteration or stop it after the
+/// current element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CallbackProgression {
+    /// No further elements are visited.
+    Stop,
+    /// The next element is visited.
+    #[default]
+    Continue,
+}
@@ -14,8 +14,10 @@
 
 #[macro_use]
 pub mod enum_gen;
+pub mod alignment;
 pub mod allocator;
 pub mod bump_allocator;
+pub mod callback_progression;
 pub mod lazy_singleton;
 pub mod math;
 pub mod owning_pointer;
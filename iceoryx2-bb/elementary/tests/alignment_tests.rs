@@ -0,0 +1,36 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_elementary::alignment::Alignment;
+use iceoryx2_bb_testing::assert_that;
+
+#[test]
+fn alignment_accepts_powers_of_two() {
+    for i in 0..usize::BITS {
+        let value = 1usize << i;
+        assert_that!(Alignment::new(value).map(|a| a.value()), eq Some(value));
+    }
+}
+
+#[test]
+fn alignment_rejects_values_which_are_not_a_power_of_two() {
+    assert_that!(Alignment::new(0), is_none);
+    assert_that!(Alignment::new(3), is_none);
+    assert_that!(Alignment::new(48), is_none);
+    assert_that!(Alignment::new(usize::MAX), is_none);
+}
+
+#[test]
+fn alignment_of_type_is_its_natural_alignment() {
+    assert_that!(Alignment::of::<u8>().value(), eq 1);
+    assert_that!(Alignment::of::<u64>().value(), eq std::mem::align_of::<u64>());
+}
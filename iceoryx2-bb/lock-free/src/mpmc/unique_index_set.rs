@@ -356,7 +356,9 @@ impl UniqueIndexSet {
             }
 
             let new_head = *self.get_next_free_index(old_head);
-            let new_aba = old_aba + 1;
+            // the counter distinguishes a head that was acquired and released in the meantime
+            // from the loaded one, it must wrap around instead of overflowing
+            let new_aba = old_aba.wrapping_add(1);
             let new = Self::pack_from_head_and_aba(new_head, new_aba);
 
             (old_head, old_aba) =
@@ -401,7 +403,7 @@ impl UniqueIndexSet {
         loop {
             *self.get_next_free_index(index) = old_head;
             let new_head = index;
-            let new_aba = old_aba + 1;
+            let new_aba = old_aba.wrapping_add(1);
             let new = Self::pack_from_head_and_aba(new_head, new_aba);
 
             (old_head, old_aba) =
@@ -90,6 +90,12 @@ impl<PointerType: PointerTrait<UnsafeCell<usize>> + Debug> Consumer<'_, PointerT
     pub fn pop(&mut self) -> Option<usize> {
         unsafe { self.queue.pop() }
     }
+
+    /// Returns the value the next [`Consumer::pop()`] would acquire without removing it. If
+    /// the queue is empty it returns [`None`].
+    pub fn peek(&self) -> Option<usize> {
+        unsafe { self.queue.peek() }
+    }
 }
 
 impl<PointerType: PointerTrait<UnsafeCell<usize>>> Drop for Consumer<'_, PointerType> {
@@ -381,6 +387,40 @@ pub mod details {
             Some(value)
         }
 
+        /// Returns the index that [`SafelyOverflowingIndexQueue::pop()`] would acquire next
+        /// without removing it. If the queue is empty [`None`] is returned. An overflowing
+        /// [`SafelyOverflowingIndexQueue::push()`] can remove the index afterwards.
+        ///
+        /// # Safety
+        ///
+        ///  * [`SafelyOverflowingIndexQueue::peek()`] cannot be called concurrently with
+        ///    [`SafelyOverflowingIndexQueue::pop()`]. The user has to ensure that at most one
+        ///    thread accesses the consumer side.
+        ///  * It has to be ensured that the memory is initialized with
+        ///    [`SafelyOverflowingIndexQueue::init()`].
+        pub unsafe fn peek(&self) -> Option<usize> {
+            loop {
+                ////////////////
+                // SYNC POINT R
+                ////////////////
+                let read_position = self.read_position.load(Ordering::Acquire);
+                ////////////////
+                // SYNC POINT W
+                ////////////////
+                if read_position == self.write_position.load(Ordering::Acquire) {
+                    return None;
+                }
+
+                let value = unsafe { *self.at(read_position) };
+
+                // the value is overwritten only after an overflowing push moved the read
+                // position, when it did not move the value is still the oldest one
+                if self.read_position.load(Ordering::Acquire) == read_position {
+                    return Some(value);
+                }
+            }
+        }
+
         fn acquire_read_and_write_position(&self) -> (usize, usize) {
             loop {
                 let write_position = self.write_position.load(Ordering::Relaxed);
@@ -500,6 +540,17 @@ impl<const CAPACITY: usize> FixedSizeSafelyOverflowingIndexQueue<CAPACITY> {
         self.state.pop()
     }
 
+    /// See [`SafelyOverflowingIndexQueue::peek()`]
+    ///
+    /// # Safety
+    ///
+    /// * It must be ensured that no other thread/process calls this method or
+    ///   [`FixedSizeSafelyOverflowingIndexQueue::pop()`] concurrently
+    ///
+    pub unsafe fn peek(&self) -> Option<usize> {
+        self.state.peek()
+    }
+
     /// See [`SafelyOverflowingIndexQueue::capacity()`]
     pub const fn capacity(&self) -> usize {
         self.state.capacity()
@@ -86,6 +86,34 @@ fn spsc_safely_overflowing_index_queue_push_pop_alteration_works() {
     }
 }
 
+#[test]
+fn spsc_safely_overflowing_index_queue_peek_returns_next_value_without_removing_it() {
+    const CAPACITY: usize = 4;
+    let sut = FixedSizeSafelyOverflowingIndexQueue::<CAPACITY>::new();
+    let mut sut_producer = sut.acquire_producer().unwrap();
+    let mut sut_consumer = sut.acquire_consumer().unwrap();
+
+    assert_that!(sut_consumer.peek(), is_none);
+
+    for i in 0..CAPACITY {
+        assert_that!(sut_producer.push(i), is_none);
+    }
+
+    assert_that!(sut_consumer.peek(), eq Some(0));
+    assert_that!(sut_consumer.peek(), eq Some(0));
+    assert_that!(sut, len CAPACITY);
+
+    // the overflow removes the peeked value
+    assert_that!(sut_producer.push(CAPACITY), eq Some(0));
+    assert_that!(sut_consumer.peek(), eq Some(1));
+
+    for i in 1..=CAPACITY {
+        assert_that!(sut_consumer.peek(), eq Some(i));
+        assert_that!(sut_consumer.pop(), eq Some(i));
+    }
+    assert_that!(sut_consumer.peek(), is_none);
+}
+
 #[test]
 fn spsc_safely_overflowing_index_queue_get_consumer_twice_fails() {
     let sut = FixedSizeSafelyOverflowingIndexQueue::<1024>::new();
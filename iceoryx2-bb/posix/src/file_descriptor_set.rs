@@ -59,6 +59,8 @@ use iceoryx2_pal_posix::*;
 /// A trait which is implement by all objects which can be added to the [`FileDescriptorSet`].
 pub trait SynchronousMultiplexing: FileDescriptorBased {}
 
+impl SynchronousMultiplexing for FileDescriptor {}
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum FileDescriptorSetWaitError {
     Interrupt,
@@ -85,6 +87,7 @@ pub enum FileEvent {
     ReadWriteExceptional,
 }
 
+#[derive(Debug)]
 pub struct FileDescriptorSetGuard<'set, 'fd> {
     set: &'set FileDescriptorSet,
     fd: &'fd FileDescriptor,
@@ -68,7 +68,7 @@ use crate::file_descriptor::*;
 use crate::handle_errno;
 use crate::memory_lock::{MemoryLock, MemoryLockCreationError};
 use crate::signal::SignalHandler;
-use crate::system_configuration::Limit;
+use crate::system_configuration::{Limit, SystemInfo};
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_log::{error, fail, fatal_panic, trace};
@@ -78,6 +78,7 @@ use iceoryx2_bb_system_types::path::*;
 use iceoryx2_pal_configuration::PATH_SEPARATOR;
 use iceoryx2_pal_posix::posix::errno::Errno;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING;
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_HUGE_PAGES;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
 use iceoryx2_pal_posix::*;
 
@@ -102,6 +103,7 @@ enum_gen! { SharedMemoryCreationError
     AlreadyExist,
     DoesNotExist,
     UnableToMapAtEnforcedBaseAddress,
+    HugePagesUnavailable,
     UnknownError(i32)
   mapping:
     FileTruncateError,
@@ -126,6 +128,8 @@ pub struct SharedMemoryBuilder {
     permission: Permission,
     creation_mode: Option<CreationMode>,
     zero_memory: bool,
+    use_huge_pages: bool,
+    prefault: bool,
     access_mode: AccessMode,
     enforce_base_address: Option<u64>,
 }
@@ -141,12 +145,15 @@ impl SharedMemoryBuilder {
             has_ownership: true,
             creation_mode: None,
             zero_memory: true,
+            use_huge_pages: false,
+            prefault: false,
             enforce_base_address: None,
         }
     }
 
     /// Locks the shared memory into the heap. If this is enabled swapping of the
-    /// created shared memory segment is no longer possible.
+    /// created or opened shared memory segment is no longer possible. The lock applies only to
+    /// the mapping of the current process.
     pub fn is_memory_locked(mut self, value: bool) -> Self {
         self.is_memory_locked = value;
         self
@@ -187,7 +194,7 @@ impl SharedMemoryBuilder {
                 "{} since the memory was mapped at {:X} which is not enforced base address.", msg, base_address as u64);
         }
 
-        let shm = SharedMemory {
+        let mut shm = SharedMemory {
             name: self.name,
             base_address: base_address as *mut u8,
             size: actual_shm_size as usize,
@@ -196,6 +203,13 @@ impl SharedMemoryBuilder {
             file_descriptor: fd,
         };
 
+        if self.is_memory_locked {
+            shm.memory_lock = Some(
+                fail!(from self, when unsafe { MemoryLock::new(shm.base_address.cast(), shm.size) },
+                        "{} since the memory lock failed.", msg),
+            )
+        }
+
         trace!(from shm, "open");
         Ok(shm)
     }
@@ -230,6 +244,25 @@ impl SharedMemoryCreationBuilder {
         self
     }
 
+    /// Advises the operating system to back the shared memory with huge pages which reduces the
+    /// TLB pressure of large segments. On Linux transparent huge pages for shared memory must be
+    /// enabled, see `/sys/kernel/mm/transparent_hugepage/shmem_enabled`. The memory is faulted
+    /// in during the creation and when it is not backed by huge pages afterwards, for instance
+    /// since it is smaller than a huge page, the creation fails with
+    /// [`SharedMemoryCreationError::HugePagesUnavailable`].
+    pub fn use_huge_pages(mut self, value: bool) -> Self {
+        self.config.use_huge_pages = value;
+        self
+    }
+
+    /// Touches every page of the shared memory during creation so that no page fault occurs
+    /// when the memory is accessed for the first time. Zeroing the memory, see
+    /// [`SharedMemoryCreationBuilder::zero_memory()`], touches every page as well.
+    pub fn prefault(mut self, value: bool) -> Self {
+        self.config.prefault = value;
+        self
+    }
+
     /// The size of the shared memory.
     pub fn size(mut self, size: usize) -> Self {
         self.config.size = size;
@@ -302,6 +335,13 @@ impl SharedMemoryCreationBuilder {
                                     "{} since the memory could not be mapped.", msg)
                 as *mut u8;
 
+            if self.config.is_memory_locked {
+                shm.memory_lock = Some(
+                    fail!(from self.config, when unsafe { MemoryLock::new(shm.base_address.cast(), shm.size) },
+                            "{} since the memory lock failed.", msg),
+                )
+            }
+
             trace!(from shm, "open");
             return Ok(shm);
         }
@@ -328,6 +368,26 @@ impl SharedMemoryCreationBuilder {
         }
         shm.size = actual_shm_size as _;
 
+        // must be advised before the pages are faulted in by the memory lock or the zeroing
+        if self.config.use_huge_pages {
+            if !POSIX_SUPPORT_HUGE_PAGES {
+                fail!(from self.config, with SharedMemoryCreationError::HugePagesUnavailable,
+                    "{} since the platform does not support huge pages.", msg);
+            }
+
+            if unsafe {
+                posix::madvise(
+                    shm.base_address as *mut posix::void,
+                    shm.size,
+                    posix::MADV_HUGEPAGE,
+                )
+            } != 0
+            {
+                fail!(from self.config, with SharedMemoryCreationError::HugePagesUnavailable,
+                    "{} since the memory cannot be backed by huge pages ({}). Are transparent huge pages enabled?", msg, Errno::get());
+            }
+        }
+
         if self.config.is_memory_locked {
             shm.memory_lock = Some(
                 fail!(from self.config, when unsafe { MemoryLock::new(shm.base_address.cast(), shm.size) },
@@ -349,6 +409,29 @@ impl SharedMemoryCreationBuilder {
             } else {
                 unsafe { posix::memset(shm.base_address as *mut posix::void, 0, self.config.size) };
             }
+        } else if self.config.prefault || self.config.use_huge_pages {
+            // huge pages are faulted in as well since the kernel decides on the page size when
+            // the memory is accessed for the first time
+            let touch_pages = || {
+                let page_size = SystemInfo::PageSize.value();
+                for offset in (0..self.config.size).step_by(page_size) {
+                    unsafe { shm.base_address.add(offset).write_volatile(0) };
+                }
+            };
+
+            if POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING {
+                if let Some(v) = SignalHandler::call_and_fetch(touch_pages) {
+                    fail!(from self.config, with SharedMemoryCreationError::InsufficientMemory,
+                        "{} since a signal {} was raised while prefaulting the memory. Is enough memory available on the system?", msg, v);
+                }
+            } else {
+                touch_pages();
+            }
+        }
+
+        if self.config.use_huge_pages && !is_backed_by_huge_pages(shm.base_address) {
+            fail!(from self.config, with SharedMemoryCreationError::HugePagesUnavailable,
+                "{} since the kernel did not back the memory with huge pages. Are transparent huge pages for shared memory enabled and is the memory at least one huge page large?", msg);
         }
 
         trace!(from shm, "create");
@@ -356,6 +439,43 @@ impl SharedMemoryCreationBuilder {
     }
 }
 
+/// Returns true when the mapping that starts at `base_address` is at least partially backed by
+/// huge pages. Linux accepts the advice for transparent huge pages even when they are disabled
+/// for shared memory and silently falls back to the default page size, therefore the page size
+/// of the faulted in memory is looked up in `/proc/self/smaps`.
+#[cfg(target_os = "linux")]
+fn is_backed_by_huge_pages(base_address: *const u8) -> bool {
+    let smaps = match std::fs::read_to_string("/proc/self/smaps") {
+        Ok(smaps) => smaps,
+        Err(_) => return false,
+    };
+
+    let mapping = format!("{:08x}-", base_address as usize);
+    smaps
+        .lines()
+        .skip_while(|line| !line.starts_with(&mapping))
+        .skip(1)
+        .take_while(|line| {
+            line.split_whitespace()
+                .next()
+                .is_some_and(|key| key.ends_with(':'))
+        })
+        .filter_map(|line| line.strip_prefix("ShmemPmdMapped:"))
+        .any(|value| {
+            value
+                .trim()
+                .trim_end_matches("kB")
+                .trim()
+                .parse::<u64>()
+                .is_ok_and(|size| size > 0)
+        })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn is_backed_by_huge_pages(_base_address: *const u8) -> bool {
+    false
+}
+
 /// A POSIX shared memory object which is build by the [`SharedMemoryBuilder`].
 #[derive(Debug)]
 pub struct SharedMemory {
@@ -369,6 +489,9 @@ pub struct SharedMemory {
 
 impl Drop for SharedMemory {
     fn drop(&mut self) {
+        // the memory must be unlocked while it is still mapped
+        self.memory_lock = None;
+
         if !self.base_address.is_null() {
             if unsafe { posix::munmap(self.base_address as *mut posix::void, self.size) } != 0 {
                 fatal_panic!(from self, "This should never happen! Unable to unmap since the base address or range is invalid.");
@@ -213,6 +213,7 @@ pub enum ProcessResourceLimit {
     MaxNumberOfOpenFileDescriptors = posix::RLIMIT_NOFILE as _,
     MaxStackSize = posix::RLIMIT_STACK as _,
     MaxSizeOfTotalMemory = posix::RLIMIT_AS as _,
+    MaxLockedMemory = posix::RLIMIT_MEMLOCK as _,
 }
 
 impl ProcessResourceLimit {
@@ -61,7 +61,8 @@ enum_gen! { UniqueSystemIdCreationError
 /// same id. There will never be another process on the same system with the same id.
 /// The [`UniqueSystemId`] is generated by the processes current process id and the current system
 /// time using the [`ClockType::Monotonic`].
-#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Clone, Copy)]
+#[repr(transparent)]
 pub struct UniqueSystemId {
     value: u128,
 }
@@ -72,6 +73,14 @@ impl Display for UniqueSystemId {
     }
 }
 
+impl From<u128> for UniqueSystemId {
+    /// Restores a [`UniqueSystemId`] from its [`UniqueSystemId::value()`], for instance when
+    /// it was transferred to another process.
+    fn from(value: u128) -> Self {
+        Self { value }
+    }
+}
+
 impl UniqueSystemId {
     /// Creates a new system wide unique id
     pub fn new() -> Result<Self, UniqueSystemIdCreationError> {
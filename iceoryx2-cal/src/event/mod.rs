@@ -51,6 +51,8 @@ impl std::error::Error for NotifierCreateError {}
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ListenerWaitError {
     ContractViolation,
+    /// The wait was interrupted by a signal before a notification arrived.
+    InterruptSignal,
     InternalFailure,
 }
 
@@ -83,6 +85,7 @@ impl TriggerId for u64 {}
 impl TriggerId for u32 {}
 impl TriggerId for u16 {}
 impl TriggerId for u8 {}
+impl TriggerId for iceoryx2_core::event_id::EventId {}
 
 pub trait Notifier<Id: TriggerId>: NamedConcept + Debug {
     fn notify(&self, id: Id) -> Result<(), NotifierNotifyError>;
@@ -73,7 +73,10 @@ impl<Id: TriggerId + Copy> From<Configuration<Id>>
     for crate::communication_channel::unix_datagram::Configuration
 {
     fn from(value: Configuration<Id>) -> Self {
-        Self::default().suffix(value.suffix).path_hint(value.path)
+        Self::default()
+            .prefix(value.prefix)
+            .suffix(value.suffix)
+            .path_hint(value.path)
     }
 }
 
@@ -247,6 +250,10 @@ impl<Id: crate::event::TriggerId + Copy> Listener<Id> {
                 }
                 Ok(Some(unsafe { id_buffer.assume_init() }))
             }
+            Err(UnixDatagramReceiveError::Interrupt) => {
+                fail!(from self, with ListenerWaitError::InterruptSignal,
+                    "{} since an interrupt signal was received.", error_msg);
+            }
             Err(v) => {
                 fail!(from self, with ListenerWaitError::InternalFailure,
                     "{} due to an unknown failure ({:?}).", error_msg ,v);
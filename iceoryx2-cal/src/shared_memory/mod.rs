@@ -71,9 +71,19 @@ pub enum SharedMemoryCreateError {
     AlreadyExists,
     SizeIsZero,
     InsufficientPermissions,
+    UnableToLockMemory,
+    HugePagesUnavailable,
     InternalError,
 }
 
+impl std::fmt::Display for SharedMemoryCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for SharedMemoryCreateError {}
+
 /// Failure returned by [`SharedMemoryBuilder::open()`]
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum SharedMemoryOpenError {
@@ -85,6 +95,14 @@ pub enum SharedMemoryOpenError {
     InternalError,
 }
 
+impl std::fmt::Display for SharedMemoryOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for SharedMemoryOpenError {}
+
 /// Represents a pointer pointing to some [`SharedMemory`]. Consists of the actual data pointer and
 /// an [`PointerOffset`] which can be used in combination with a
 /// [`crate::zero_copy_connection::ZeroCopyConnection`]
@@ -94,6 +112,20 @@ pub struct ShmPointer {
     pub data_ptr: *mut u8,
 }
 
+/// Defines if the memory of a [`SharedMemory`] shall be backed by huge pages.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum HugePages {
+    /// The memory is backed by pages of the default page size.
+    #[default]
+    Disabled,
+    /// The memory is backed by huge pages when they are available, otherwise a warning is
+    /// emitted and the default page size is used.
+    IfAvailable,
+    /// The creation fails with [`SharedMemoryCreateError::HugePagesUnavailable`] when the memory
+    /// cannot be backed by huge pages.
+    Required,
+}
+
 /// Creates [`SharedMemory`].
 pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>>:
     NamedConceptBuilder<Shm>
@@ -101,6 +133,18 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
     /// Sets the size of the [`SharedMemory`]
     fn size(self, value: usize) -> Self;
 
+    /// Locks the memory of the newly created [`SharedMemory`] into RAM so that it cannot be
+    /// swapped out. The creation fails with [`SharedMemoryCreateError::UnableToLockMemory`] when
+    /// the memory lock limit of the process is insufficient.
+    fn lock_memory(self, value: bool) -> Self;
+
+    /// Defines if the newly created [`SharedMemory`] shall be backed by huge pages.
+    fn huge_pages(self, value: HugePages) -> Self;
+
+    /// Touches every page of the newly created [`SharedMemory`] so that the first access does
+    /// not cause a page fault.
+    fn prefault(self, value: bool) -> Self;
+
     /// Creates new [`SharedMemory`]. If it already exists the method will fail.
     fn create(
         self,
@@ -118,7 +162,9 @@ pub trait SharedMemory<Allocator: ShmAllocator>:
 {
     type Builder: SharedMemoryBuilder<Allocator, Self>;
 
-    /// Returns the size of the shared memory.
+    /// Returns the size of the memory that is managed by the allocator, it starts at
+    /// [`SharedMemory::allocator_data_start_address()`]. The management data of the allocator
+    /// is not part of it.
     fn size(&self) -> usize;
 
     /// Returns the max supported alignment.
@@ -17,10 +17,10 @@ use std::sync::atomic::{AtomicU64, Ordering};
 
 pub use crate::shared_memory::*;
 use iceoryx2_bb_elementary::allocator::DeallocationError;
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::{fail, warn};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
-use iceoryx2_bb_posix::shared_memory::{AccessMode, Permission};
-use iceoryx2_bb_posix::system_configuration::SystemInfo;
+use iceoryx2_bb_posix::shared_memory::{AccessMode, Permission, SharedMemoryCreationError};
+use iceoryx2_bb_posix::system_configuration::{ProcessResourceLimit, SystemInfo};
 use iceoryx2_bb_posix::unix_datagram_socket::CreationMode;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::path::Path;
@@ -104,6 +104,9 @@ impl<Allocator: ShmAllocator + Debug> NamedConceptConfiguration for Configuratio
 pub struct Builder<Allocator: ShmAllocator + Debug> {
     name: FileName,
     size: usize,
+    lock_memory: bool,
+    huge_pages: HugePages,
+    prefault: bool,
     config: Configuration<Allocator>,
     _phantom_allocator: PhantomData<Allocator>,
 }
@@ -118,6 +121,52 @@ impl<Allocator: ShmAllocator + Debug> Builder<Allocator> {
     fn allocator_size(&self, allocator_config: &Allocator::Configuration) -> usize {
         Self::allocator_details_size() + Allocator::management_size(self.size, allocator_config)
     }
+
+    fn create_shared_memory(
+        &self,
+        size: usize,
+        use_huge_pages: bool,
+    ) -> Result<iceoryx2_bb_posix::shared_memory::SharedMemory, SharedMemoryCreateError> {
+        let msg = "Unable to create shared memory";
+
+        match iceoryx2_bb_posix::shared_memory::SharedMemoryBuilder::new(unsafe {
+            &FileName::new_unchecked(self.config.path_for(&self.name).file_name())
+        })
+        .is_memory_locked(self.config.is_memory_locked || self.lock_memory)
+        .creation_mode(CreationMode::CreateExclusive)
+        .size(size)
+        .permission(self.config.permission)
+        .zero_memory(self.config.zero_memory)
+        .use_huge_pages(use_huge_pages)
+        .prefault(self.prefault)
+        .create()
+        {
+            Ok(s) => Ok(s),
+            Err(SharedMemoryCreationError::AlreadyExist) => {
+                fail!(from self, with SharedMemoryCreateError::AlreadyExists,
+                        "{} since a shared memory with that name already exists.", msg);
+            }
+            Err(SharedMemoryCreationError::InsufficientPermissions) => {
+                fail!(from self, with SharedMemoryCreateError::InsufficientPermissions,
+                        "{} due to insufficient permissions.", msg);
+            }
+            Err(SharedMemoryCreationError::MemoryLockCreationError(_))
+            | Err(SharedMemoryCreationError::InsufficientMemoryToBeMemoryLocked) => {
+                let limit = ProcessResourceLimit::MaxLockedMemory.soft_limit();
+                fail!(from self, with SharedMemoryCreateError::UnableToLockMemory,
+                        "{} since {} bytes could not be locked into memory. The memory lock limit (RLIMIT_MEMLOCK) of the process is {} bytes, increase it with \"ulimit -l\" or grant the CAP_IPC_LOCK capability.",
+                        msg, size, limit);
+            }
+            Err(SharedMemoryCreationError::HugePagesUnavailable) => {
+                fail!(from self, with SharedMemoryCreateError::HugePagesUnavailable,
+                        "{} since the memory cannot be backed by huge pages.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with SharedMemoryCreateError::InternalError,
+                        "{} since an unknown error has occurred ({:?})", msg, v);
+            }
+        }
+    }
 }
 
 impl<Allocator: ShmAllocator + Debug> NamedConceptBuilder<Memory<Allocator>>
@@ -128,6 +177,9 @@ impl<Allocator: ShmAllocator + Debug> NamedConceptBuilder<Memory<Allocator>>
             name: *name,
             config: Configuration::default(),
             size: 0,
+            lock_memory: false,
+            huge_pages: HugePages::Disabled,
+            prefault: false,
             _phantom_allocator: PhantomData,
         }
     }
@@ -146,6 +198,21 @@ impl<Allocator: ShmAllocator + Debug>
         self
     }
 
+    fn lock_memory(mut self, value: bool) -> Self {
+        self.lock_memory = value;
+        self
+    }
+
+    fn huge_pages(mut self, value: HugePages) -> Self {
+        self.huge_pages = value;
+        self
+    }
+
+    fn prefault(mut self, value: bool) -> Self {
+        self.prefault = value;
+        self
+    }
+
     fn create(
         self,
         allocator_config: &Allocator::Configuration,
@@ -159,31 +226,19 @@ impl<Allocator: ShmAllocator + Debug>
 
         let allocator_mgmt_size = self.allocator_size(allocator_config);
 
-        let shm = match iceoryx2_bb_posix::shared_memory::SharedMemoryBuilder::new(unsafe {
-            &FileName::new_unchecked(self.config.path_for(&self.name).file_name())
-        })
-        .is_memory_locked(self.config.is_memory_locked)
-        .creation_mode(CreationMode::CreateExclusive)
-        .size(self.size + allocator_mgmt_size)
-        .permission(self.config.permission)
-        .zero_memory(self.config.zero_memory)
-        .create()
-        {
-            Ok(s) => s,
-            Err(iceoryx2_bb_posix::shared_memory::SharedMemoryCreationError::AlreadyExist) => {
-                fail!(from self, with SharedMemoryCreateError::AlreadyExists,
-                        "{} since a shared memory with that name already exists.", msg);
-            }
-            Err(
-                iceoryx2_bb_posix::shared_memory::SharedMemoryCreationError::InsufficientPermissions,
-            ) => {
-                fail!(from self, with SharedMemoryCreateError::InsufficientPermissions,
-                        "{} due to insufficient permissions.", msg);
-            }
-            Err(v) => {
-                fail!(from self, with SharedMemoryCreateError::InternalError,
-                        "{} since an unknown error has occurred ({:?})", msg, v);
-            }
+        let shm_size = self.size + allocator_mgmt_size;
+        let shm = match self.huge_pages {
+            HugePages::Disabled => self.create_shared_memory(shm_size, false)?,
+            HugePages::Required => self.create_shared_memory(shm_size, true)?,
+            HugePages::IfAvailable => match self.create_shared_memory(shm_size, true) {
+                Ok(shm) => shm,
+                Err(SharedMemoryCreateError::HugePagesUnavailable) => {
+                    warn!(from self,
+                        "Huge pages are not available, the shared memory is backed by pages of the default size.");
+                    self.create_shared_memory(shm_size, false)?
+                }
+                Err(e) => return Err(e),
+            },
         };
 
         let allocator_addr = shm.base_address().as_ptr() as *mut AllocatorDetails<Allocator>;
@@ -244,17 +299,15 @@ impl<Allocator: ShmAllocator + Debug>
         .open_existing(AccessMode::ReadWrite)
         {
             Ok(s) => s,
-            Err(iceoryx2_bb_posix::shared_memory::SharedMemoryCreationError::DoesNotExist) => {
+            Err(SharedMemoryCreationError::DoesNotExist) => {
                 fail!(from self, with SharedMemoryOpenError::DoesNotExist,
                         "{} since a shared memory with that name does not exist.", msg);
             }
-            Err(iceoryx2_bb_posix::shared_memory::SharedMemoryCreationError::SizeDoesNotFit) => {
+            Err(SharedMemoryCreationError::SizeDoesNotFit) => {
                 fail!(from self, with SharedMemoryOpenError::SizeDoesNotFit,
                         "{} since the requested size is not equal the actual size of the shared memory.", msg);
             }
-            Err(
-                iceoryx2_bb_posix::shared_memory::SharedMemoryCreationError::InsufficientPermissions,
-            ) => {
+            Err(SharedMemoryCreationError::InsufficientPermissions) => {
                 fail!(from self, with SharedMemoryOpenError::InsufficientPermissions,
                         "{} due to insufficient permissions.", msg);
             }
@@ -13,11 +13,12 @@
 pub use crate::shared_memory::*;
 use crate::static_storage::file::NamedConceptConfiguration;
 use iceoryx2_bb_elementary::allocator::BaseAllocator;
-use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
 use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
+use iceoryx2_bb_posix::memory_lock::MemoryLock;
 use iceoryx2_bb_posix::mutex::*;
-use iceoryx2_bb_posix::system_configuration::SystemInfo;
+use iceoryx2_bb_posix::system_configuration::{ProcessResourceLimit, SystemInfo};
 use iceoryx2_bb_system_types::{file_path::FilePath, path::Path};
 use once_cell::sync::Lazy;
 use std::alloc::Layout;
@@ -35,11 +36,13 @@ struct SharedMemoryEntry {
     layout: Layout,
     size: usize,
     allocator: Option<Box<dyn Any + Send + Sync>>,
+    memory_lock: Option<MemoryLock>,
 }
 
 impl Drop for SharedMemoryEntry {
     fn drop(&mut self) {
         self.allocator = None;
+        self.memory_lock = None;
 
         unsafe {
             fatal_panic!(from self, when HeapAllocator::new()
@@ -132,6 +135,9 @@ impl<Allocator: ShmAllocator + Debug> NamedConceptConfiguration for Configuratio
 pub struct Builder<Allocator: ShmAllocator + Debug> {
     name: FileName,
     size: usize,
+    lock_memory: bool,
+    huge_pages: HugePages,
+    prefault: bool,
     config: Configuration<Allocator>,
     _phantom_allocator: PhantomData<Allocator>,
 }
@@ -143,6 +149,9 @@ impl<Allocator: ShmAllocator + Debug> NamedConceptBuilder<Memory<Allocator>>
         Self {
             name: *name,
             size: 0,
+            lock_memory: false,
+            huge_pages: HugePages::Disabled,
+            prefault: false,
             config: Configuration::default(),
             _phantom_allocator: PhantomData,
         }
@@ -162,6 +171,21 @@ impl<Allocator: ShmAllocator + Debug>
         self
     }
 
+    fn lock_memory(mut self, value: bool) -> Self {
+        self.lock_memory = value;
+        self
+    }
+
+    fn huge_pages(mut self, value: HugePages) -> Self {
+        self.huge_pages = value;
+        self
+    }
+
+    fn prefault(mut self, value: bool) -> Self {
+        self.prefault = value;
+        self
+    }
+
     fn create(
         self,
         allocator_config: &Allocator::Configuration,
@@ -173,6 +197,18 @@ impl<Allocator: ShmAllocator + Debug>
                     "{} since the size is zero.", msg);
         }
 
+        match self.huge_pages {
+            HugePages::Disabled => (),
+            HugePages::IfAvailable => {
+                warn!(from self,
+                    "Huge pages are not supported by the process local shared memory, it is backed by pages of the default size.");
+            }
+            HugePages::Required => {
+                fail!(from self, with SharedMemoryCreateError::HugePagesUnavailable,
+                    "{} since the process local shared memory cannot be backed by huge pages.", msg);
+            }
+        }
+
         let mut guard = fail!(from self, when PROCESS_LOCAL_STORAGE.lock(),
             with SharedMemoryCreateError::InternalError,
             "{} due to a failure while acquiring the lock.", msg);
@@ -184,10 +220,19 @@ impl<Allocator: ShmAllocator + Debug>
                 "{} since the shared memory does already exist.", msg);
         }
 
-        let layout = Layout::from_size_align(self.size, SystemInfo::PageSize.value()).unwrap();
-        let memory = fail!(from self, when  HeapAllocator::new().allocate(layout),
+        // the memory covers whole pages so that a memory lock does not affect other allocations
+        // that would otherwise share the last page
+        let page_size = SystemInfo::PageSize.value();
+        let layout = Layout::from_size_align(self.size, page_size)
+            .unwrap()
+            .pad_to_align();
+        // zeroed like the pages of a POSIX shared memory, the padding of a payload that is read
+        // as bytes is never uninitialized
+        let memory = fail!(from self, when  HeapAllocator::new().allocate_zeroed(layout),
                                         with SharedMemoryCreateError::InternalError,
                                         "{} since the memory could not be allocated.", msg);
+        // the allocator manages only the requested size, the padding is not used
+        let memory = NonNull::slice_from_raw_parts(memory.cast::<u8>(), self.size);
 
         let mgmt_layout = unsafe {
             Layout::from_size_align_unchecked(
@@ -209,6 +254,32 @@ impl<Allocator: ShmAllocator + Debug>
 
         let mgmt_memory = unsafe { NonNull::new_unchecked(mgmt_memory.as_ptr() as *mut u8) };
 
+        if self.prefault {
+            for offset in (0..self.size).step_by(page_size) {
+                unsafe { (memory.as_ptr() as *mut u8).add(offset).write_volatile(0) };
+            }
+        }
+
+        let memory_lock = if self.lock_memory {
+            match unsafe { MemoryLock::new(memory.as_ptr() as *const u8 as _, layout.size()) } {
+                Ok(lock) => Some(lock),
+                Err(e) => {
+                    fatal_panic!(from self,
+                        when unsafe { HeapAllocator::new().deallocate(NonNull::new_unchecked(memory.as_ptr() as *mut u8), layout) },
+                        "This should never happen! {} since the deallocation of the shared memory failed in the error path.", msg);
+                    fatal_panic!(from self,
+                        when unsafe { HeapAllocator::new().deallocate(mgmt_memory, mgmt_layout) },
+                        "This should never happen! {} since the deallocation of the allocators management memory failed in the error path.", msg);
+                    let limit = ProcessResourceLimit::MaxLockedMemory.soft_limit();
+                    fail!(from self, with SharedMemoryCreateError::UnableToLockMemory,
+                        "{} since {} bytes could not be locked into memory ({:?}). The memory lock limit (RLIMIT_MEMLOCK) of the process is {} bytes, increase it with \"ulimit -l\" or grant the CAP_IPC_LOCK capability.",
+                        msg, self.size, e, limit);
+                }
+            }
+        } else {
+            None
+        };
+
         guard.insert(
             full_path,
             Arc::new(SharedMemoryEntry {
@@ -219,6 +290,7 @@ impl<Allocator: ShmAllocator + Debug>
                 allocator: Some(Box::new(unsafe {
                     Allocator::new_uninit(SystemInfo::PageSize.value(), memory, allocator_config)
                 })),
+                memory_lock,
             }),
         );
 
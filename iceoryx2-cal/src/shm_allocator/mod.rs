@@ -23,6 +23,14 @@ use iceoryx2_bb_elementary::{
 
 pub trait ShmAllocatorConfig: Copy + Default {}
 
+/// The offset of a chunk relative to the start of its shared memory. It has the size of a
+/// pointer, therefore only processes with the same pointer width can exchange it.
+///
+/// It is not the only shared structure with a pointer sized layout. The indices of the
+/// lock-free queues of the zero copy connections, the management data of the shared memory
+/// allocators and the containers of the dynamic service config use `usize` as well. Instead
+/// of converting all of them, the pointer width is stored in the type details of every publish
+/// subscribe service and a process with another pointer width fails to open it.
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub struct PointerOffset(usize);
 
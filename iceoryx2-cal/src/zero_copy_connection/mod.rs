@@ -28,6 +28,7 @@ pub enum ZeroCopyCreationError {
     IncompatibleBufferSize,
     IncompatibleMaxBorrowedSampleSetting,
     IncompatibleOverflowSetting,
+    UnableToLockMemory,
 }
 
 impl std::fmt::Display for ZeroCopyCreationError {
@@ -97,6 +98,10 @@ pub trait ZeroCopyConnectionBuilder<C: ZeroCopyConnection>: NamedConceptBuilder<
     fn buffer_size(self, value: usize) -> Self;
     fn enable_safe_overflow(self, value: bool) -> Self;
     fn receiver_max_borrowed_samples(self, value: usize) -> Self;
+    /// Locks the memory of the connection that is mapped by the created port into RAM so that
+    /// it cannot be swapped out. Connections that are not backed by shared memory live in the
+    /// memory of the process and are not locked individually.
+    fn lock_memory(self, value: bool) -> Self;
 
     fn create_sender(self) -> Result<C::Sender, ZeroCopyCreationError>;
     fn create_receiver(self) -> Result<C::Receiver, ZeroCopyCreationError>;
@@ -116,9 +121,18 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
         -> Result<Option<PointerOffset>, ZeroCopySendError>;
 
     fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError>;
+
+    /// Returns the number of samples in the receive buffer that were not yet received by the
+    /// receiver
+    fn number_of_pending_samples(&self) -> usize;
 }
 
 pub trait ZeroCopyReceiver: Debug + ZeroCopyPortDetails + NamedConcept {
+    /// Returns true when the receive buffer contains at least one sample, it does not dequeue it
+    fn has_data(&self) -> bool;
+    /// Returns the sample the next [`ZeroCopyReceiver::receive()`] would acquire without
+    /// dequeuing it. A sender with safe overflow can replace it in the meantime.
+    fn peek(&self) -> Option<PointerOffset>;
     fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError>;
     fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError>;
 }
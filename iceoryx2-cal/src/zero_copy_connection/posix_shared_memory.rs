@@ -28,7 +28,10 @@ use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::creation_mode::CreationMode;
 use iceoryx2_bb_posix::permission::Permission;
-use iceoryx2_bb_posix::shared_memory::{SharedMemory, SharedMemoryBuilder};
+use iceoryx2_bb_posix::shared_memory::{
+    SharedMemory, SharedMemoryBuilder, SharedMemoryCreationError,
+};
+use iceoryx2_bb_posix::system_configuration::ProcessResourceLimit;
 
 const MAX_CREATION_DURATION: Duration = Duration::from_millis(10);
 const IS_INITIALIZED_STATE_VALUE: u64 = 0xbeefaffedeadbeef;
@@ -182,6 +185,7 @@ pub struct Builder {
     buffer_size: usize,
     enable_safe_overflow: bool,
     max_borrowed_samples: usize,
+    lock_memory: bool,
     config: Configuration,
 }
 
@@ -203,13 +207,25 @@ impl Builder {
         let msg = "Failed to acquire underlying shared memory";
         let full_name =
             unsafe { FileName::new_unchecked(self.config.path_for(&self.name).file_name()) };
-        let mut shm = fail!(from self, when SharedMemoryBuilder::new(&full_name)
-                                                .creation_mode(CreationMode::OpenOrCreate)
-                                                .size(shm_size)
-                                                .permission(Permission::OWNER_ALL)
-                                                .create(),
-                                       with ZeroCopyCreationError::InternalError,
-                            "{} since it could not be opened/created. This can be caused by incompatible builder settings.", msg);
+        let mut shm = match SharedMemoryBuilder::new(&full_name)
+            .is_memory_locked(self.lock_memory)
+            .creation_mode(CreationMode::OpenOrCreate)
+            .size(shm_size)
+            .permission(Permission::OWNER_ALL)
+            .create()
+        {
+            Ok(shm) => shm,
+            Err(SharedMemoryCreationError::MemoryLockCreationError(_))
+            | Err(SharedMemoryCreationError::InsufficientMemoryToBeMemoryLocked) => {
+                fail!(from self, with ZeroCopyCreationError::UnableToLockMemory,
+                    "{} since it could not be locked into memory. The memory lock limit (RLIMIT_MEMLOCK) of the process is {} bytes.",
+                    msg, ProcessResourceLimit::MaxLockedMemory.soft_limit());
+            }
+            Err(_) => {
+                fail!(from self, with ZeroCopyCreationError::InternalError,
+                    "{} since it could not be opened/created. This can be caused by incompatible builder settings.", msg);
+            }
+        };
 
         let mgmt_ptr = shm.base_address().as_ptr() as *mut SharedManagementData;
         match shm.has_ownership() {
@@ -324,6 +340,7 @@ impl NamedConceptBuilder<Connection> for Builder {
             buffer_size: DEFAULT_BUFFER_SIZE,
             enable_safe_overflow: DEFAULT_ENABLE_SAFE_OVERFLOW,
             max_borrowed_samples: DEFAULT_MAX_BORROWED_SAMPLES,
+            lock_memory: false,
             config: Configuration::default(),
         }
     }
@@ -350,6 +367,11 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
         self
     }
 
+    fn lock_memory(mut self, value: bool) -> Self {
+        self.lock_memory = value;
+        self
+    }
+
     fn create_sender(self) -> Result<Sender, ZeroCopyCreationError> {
         let msg = "Unable to create sender";
         let shm = fail!(from self, when self.create_or_open_shm(),
@@ -469,6 +491,10 @@ impl ZeroCopySender for Sender {
             Some(v) => Ok(Some(PointerOffset::new(v))),
         }
     }
+
+    fn number_of_pending_samples(&self) -> usize {
+        self.mgmt().receive_channel.len()
+    }
 }
 
 #[derive(Debug)]
@@ -524,6 +550,14 @@ impl ZeroCopyPortDetails for Receiver {
 }
 
 impl ZeroCopyReceiver for Receiver {
+    fn has_data(&self) -> bool {
+        !self.mgmt().receive_channel.is_empty()
+    }
+
+    fn peek(&self) -> Option<PointerOffset> {
+        unsafe { self.mgmt().receive_channel.peek() }.map(PointerOffset::new)
+    }
+
     fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
         if *self.borrow_counter() >= self.mgmt().max_borrowed_samples {
             fail!(from self, with ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue,
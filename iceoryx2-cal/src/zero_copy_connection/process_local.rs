@@ -178,6 +178,11 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
         self
     }
 
+    fn lock_memory(self, _value: bool) -> Self {
+        // the connection lives on the heap, it is locked when the process locks all its memory
+        self
+    }
+
     fn create_sender(self) -> Result<Sender, ZeroCopyCreationError> {
         let msg = "Unable to create sender";
         let mut guard = fail!(from self, when PROCESS_LOCAL_STORAGE.lock(),
@@ -355,6 +360,10 @@ impl ZeroCopySender for Sender {
             Some(v) => Ok(Some(PointerOffset::new(v))),
         }
     }
+
+    fn number_of_pending_samples(&self) -> usize {
+        self.mgmt.receive_channel.len()
+    }
 }
 
 #[derive(Debug)]
@@ -435,6 +444,14 @@ impl ZeroCopyPortDetails for Receiver {
 }
 
 impl ZeroCopyReceiver for Receiver {
+    fn has_data(&self) -> bool {
+        !self.mgmt.receive_channel.is_empty()
+    }
+
+    fn peek(&self) -> Option<crate::shared_memory::PointerOffset> {
+        unsafe { self.mgmt.receive_channel.peek() }.map(crate::shared_memory::PointerOffset::new)
+    }
+
     fn receive(
         &self,
     ) -> Result<Option<crate::shared_memory::PointerOffset>, super::ZeroCopyReceiveError> {
@@ -381,6 +381,52 @@ mod event {
         assert_that!(unsafe {<Sut as NamedConceptMgmt>::remove_cfg(&sut_name, &config_2)}, eq Ok(false));
     }
 
+    #[test]
+    fn custom_prefix_keeps_events_separated<Sut: Event<u64>>() {
+        let config_1 = <Sut as NamedConceptMgmt>::Configuration::default()
+            .prefix(unsafe { FileName::new_unchecked(b"prefix_1_") });
+        let config_2 = <Sut as NamedConceptMgmt>::Configuration::default()
+            .prefix(unsafe { FileName::new_unchecked(b"prefix_2_") });
+
+        let sut_name = generate_name();
+
+        assert_that!(<Sut as NamedConceptMgmt>::does_exist_cfg(&sut_name, &config_1), eq Ok(false));
+        assert_that!(<Sut as NamedConceptMgmt>::does_exist_cfg(&sut_name, &config_2), eq Ok(false));
+        assert_that!(<Sut as NamedConceptMgmt>::list_cfg(&config_1).unwrap(), len 0);
+        assert_that!(<Sut as NamedConceptMgmt>::list_cfg(&config_2).unwrap(), len 0);
+
+        let sut_1 = Sut::ListenerBuilder::new(&sut_name)
+            .config(&config_1)
+            .create()
+            .unwrap();
+
+        assert_that!(<Sut as NamedConceptMgmt>::does_exist_cfg(&sut_name, &config_1), eq Ok(true));
+        assert_that!(<Sut as NamedConceptMgmt>::does_exist_cfg(&sut_name, &config_2), eq Ok(false));
+        assert_that!(<Sut as NamedConceptMgmt>::list_cfg(&config_1).unwrap(), len 1);
+        assert_that!(<Sut as NamedConceptMgmt>::list_cfg(&config_2).unwrap(), len 0);
+
+        let sut_2 = Sut::ListenerBuilder::new(&sut_name)
+            .config(&config_2)
+            .create()
+            .unwrap();
+
+        assert_that!(<Sut as NamedConceptMgmt>::does_exist_cfg(&sut_name, &config_1), eq Ok(true));
+        assert_that!(<Sut as NamedConceptMgmt>::does_exist_cfg(&sut_name, &config_2), eq Ok(true));
+        assert_that!(<Sut as NamedConceptMgmt>::list_cfg(&config_1).unwrap(), len 1);
+        assert_that!(<Sut as NamedConceptMgmt>::list_cfg(&config_2).unwrap(), len 1);
+
+        assert_that!(<Sut as NamedConceptMgmt>::list_cfg(&config_1).unwrap()[0], eq sut_name);
+        assert_that!(<Sut as NamedConceptMgmt>::list_cfg(&config_2).unwrap()[0], eq sut_name);
+
+        std::mem::forget(sut_1);
+        std::mem::forget(sut_2);
+
+        assert_that!(unsafe {<Sut as NamedConceptMgmt>::remove_cfg(&sut_name, &config_1)}, eq Ok(true));
+        assert_that!(unsafe {<Sut as NamedConceptMgmt>::remove_cfg(&sut_name, &config_1)}, eq Ok(false));
+        assert_that!(unsafe {<Sut as NamedConceptMgmt>::remove_cfg(&sut_name, &config_2)}, eq Ok(true));
+        assert_that!(unsafe {<Sut as NamedConceptMgmt>::remove_cfg(&sut_name, &config_2)}, eq Ok(false));
+    }
+
     #[test]
     fn defaults_for_configuration_are_set_correctly<Sut: Event<u64>>() {
         let config = <Sut as NamedConceptMgmt>::Configuration::default();
@@ -213,6 +213,81 @@ mod zero_copy_connection {
         assert_that!(retrieval, is_none);
     }
 
+    #[test]
+    fn has_data_does_not_dequeue_samples<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name).create_sender().unwrap();
+        let sut_receiver = Sut::Builder::new(&name).create_receiver().unwrap();
+        assert_that!(sut_receiver.has_data(), eq false);
+
+        assert_that!(sut_sender.try_send(PointerOffset::new(42)), is_ok);
+        assert_that!(sut_receiver.has_data(), eq true);
+        assert_that!(sut_receiver.has_data(), eq true);
+
+        let sample = sut_receiver.receive().unwrap();
+        assert_that!(sample.unwrap().value(), eq 42);
+        assert_that!(sut_receiver.has_data(), eq false);
+    }
+
+    #[test]
+    fn sender_sees_the_number_of_samples_that_were_not_yet_received<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(3)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(3)
+            .create_receiver()
+            .unwrap();
+        assert_that!(sut_sender.number_of_pending_samples(), eq 0);
+
+        for n in 0..3 {
+            assert_that!(sut_sender.try_send(PointerOffset::new(n)), is_ok);
+            assert_that!(sut_sender.number_of_pending_samples(), eq n + 1);
+        }
+
+        // borrowed samples are no longer pending
+        let sample = sut_receiver.receive().unwrap().unwrap();
+        assert_that!(sut_sender.number_of_pending_samples(), eq 2);
+        assert_that!(sut_receiver.release(sample), is_ok);
+        assert_that!(sut_sender.number_of_pending_samples(), eq 2);
+    }
+
+    #[test]
+    fn peek_returns_next_sample_without_dequeuing_it<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(2)
+            .receiver_max_borrowed_samples(1)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(2)
+            .receiver_max_borrowed_samples(1)
+            .create_receiver()
+            .unwrap();
+        assert_that!(sut_receiver.peek(), is_none);
+
+        assert_that!(sut_sender.try_send(PointerOffset::new(42)), is_ok);
+        assert_that!(sut_sender.try_send(PointerOffset::new(43)), is_ok);
+        assert_that!(sut_receiver.peek().unwrap().value(), eq 42);
+        assert_that!(sut_receiver.peek().unwrap().value(), eq 42);
+
+        // peeking does not borrow the sample
+        let sample = sut_receiver.receive().unwrap().unwrap();
+        assert_that!(sample.value(), eq 42);
+        assert_that!(sut_receiver.peek().unwrap().value(), eq 43);
+        assert_that!(sut_receiver.release(sample), is_ok);
+
+        let sample = sut_receiver.receive().unwrap().unwrap();
+        assert_that!(sample.value(), eq 43);
+        assert_that!(sut_receiver.peek(), is_none);
+    }
+
     #[test]
     fn send_until_buffer_is_full_works<Sut: ZeroCopyConnection>() {
         let name = generate_name();
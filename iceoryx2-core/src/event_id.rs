@@ -0,0 +1,93 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2_core::event_id::EventId;
+//!
+//! let event_id = EventId::new(123);
+//! assert_eq!(event_id.as_u64(), 123);
+//! ```
+//!
+//! Application defined events are mapped to event ids with the [`EventIdEnum`](derive@EventIdEnum)
+//! derive.
+//!
+//! ```
+//! use iceoryx2_core::event_id::{EventId, EventIdConversionError, EventIdEnum};
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, EventIdEnum)]
+//! #[event_id(crate = iceoryx2_core, max_value = 16)]
+//! enum CameraEvent {
+//!     FrameReady = 1,
+//!     CameraFailure = 2,
+//! }
+//!
+//! let event_id: EventId = CameraEvent::CameraFailure.into();
+//! assert_eq!(event_id.as_u64(), 2);
+//! assert_eq!(CameraEvent::try_from(event_id), Ok(CameraEvent::CameraFailure));
+//! assert_eq!(
+//!     CameraEvent::try_from(EventId::new(7)),
+//!     Err(EventIdConversionError::UnknownEventId)
+//! );
+//! ```
+
+use crate::zero_copy_send::{layout_hash, ZeroCopySend};
+
+pub use iceoryx2_derive::EventIdEnum;
+
+/// Failures that can occur when an [`EventId`] is converted into an enum that derives
+/// [`EventIdEnum`](derive@EventIdEnum).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventIdConversionError {
+    /// The [`EventId`] does not belong to any variant of the enum.
+    UnknownEventId,
+}
+
+impl core::fmt::Display for EventIdConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::write!(f, "{}::{:?}", core::stringify!(Self), self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EventIdConversionError {}
+
+/// Id to identify the source in event based communication.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct EventId(u64);
+
+impl EventId {
+    /// Creates a new [`EventId`] from a given integer value.
+    pub const fn new(value: u64) -> Self {
+        EventId(value)
+    }
+
+    /// Returns the underlying integer value of the [`EventId`].
+    pub const fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for EventId {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+unsafe impl ZeroCopySend for EventId {
+    const LAYOUT_HASH: Option<u64> = Some(layout_hash::of_layout::<Self>(layout_hash::of_name(
+        "EventId",
+    )));
+}
@@ -0,0 +1,47 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The types of iceoryx2 that end up in a payload or describe a service, the
+//! [`zero_copy_send::ZeroCopySend`] trait with its derive, the [`static_vec::StaticVec`], the
+//! [`static_string::StaticString`], the [`event_id::EventId`] and the
+//! [`service_name::ServiceName`]. They depend only on `core` so that message definitions can
+//! be shared with `no_std` targets, like a microcontroller that talks to an iceoryx2 process
+//! over another transport. The `iceoryx2` crate re-exports all of them.
+//!
+//! The default feature `std` adds the [`std::error::Error`] implementations, without it the
+//! crate is `#![no_std]`. There is no separate `heapless` feature, none of the types allocates
+//! and the crate does not depend on `alloc`. The lengths of the containers are stored as `u32`
+//! so that their layout is the same on 32-bit and on 64-bit targets.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_core::static_string::StaticString;
+//! use iceoryx2_core::static_vec::StaticVec;
+//! use iceoryx2_core::zero_copy_send::ZeroCopySend;
+//!
+//! #[derive(Debug, ZeroCopySend)]
+//! #[zero_copy_send(crate = iceoryx2_core)]
+//! #[repr(C)]
+//! struct SensorReading {
+//!     sensor: StaticString<32>,
+//!     values: StaticVec<u32, 8>,
+//! }
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod event_id;
+pub mod service_name;
+pub mod static_string;
+pub mod static_vec;
+pub mod zero_copy_send;
@@ -0,0 +1,223 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2_core::service_name::ServiceName;
+//!
+//! # fn main() -> Result<(), iceoryx2_core::service_name::ServiceNameError> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+//! # Allowed Characters
+//!
+//! A [`ServiceName`] can contain every UTF-8 character except the ASCII control characters
+//! (`0x00..=0x1F` and `0x7F`), for instance a NUL or a newline. The `/` has no special meaning
+//! for the service itself but structures names hierarchically, see [`ServiceName::components()`]
+//! and the `ServiceNamePattern` of iceoryx2. A name must not be empty and must not be
+//! longer than [`ServiceName::max_len()`] bytes.
+
+use crate::static_string::StaticString;
+
+const SERVICE_NAME_LENGTH: usize = 255;
+
+/// Failures that can occur when a [`ServiceName`] is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ServiceNameError {
+    IsEmpty,
+    ExceedsMaximumLength,
+    InvalidCharacter,
+}
+
+impl core::fmt::Display for ServiceNameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::write!(f, "{}::{:?}", core::stringify!(Self), self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ServiceNameError {}
+
+/// The unique name of an iceoryx2 service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ServiceName {
+    value: StaticString<SERVICE_NAME_LENGTH>,
+}
+
+impl ServiceName {
+    /// Creates a new [`ServiceName`]. The name is not allowed to be empty, must not be longer
+    /// than [`ServiceName::max_len()`] and must not contain ASCII control characters.
+    pub fn new(name: &str) -> Result<Self, ServiceNameError> {
+        Self::verify(name.as_bytes())?;
+
+        Ok(Self {
+            value: StaticString::from_str_const(name),
+        })
+    }
+
+    /// Creates a new [`ServiceName`] in a const context so that it can be stored in a `const` or
+    /// `static`. An invalid name causes a panic which becomes a compile time error when it is
+    /// evaluated in a const context.
+    ///
+    /// ```
+    /// use iceoryx2_core::service_name::ServiceName;
+    ///
+    /// static SERVICE_NAME: ServiceName = ServiceName::from_static_str("My/Funk/ServiceName");
+    /// ```
+    pub const fn from_static_str(name: &'static str) -> Self {
+        match Self::verify(name.as_bytes()) {
+            Ok(()) => (),
+            Err(ServiceNameError::IsEmpty) => panic!("The service name must not be empty."),
+            Err(ServiceNameError::ExceedsMaximumLength) => {
+                panic!("The service name exceeds the maximum length.")
+            }
+            Err(ServiceNameError::InvalidCharacter) => {
+                panic!("The service name contains an invalid character.")
+            }
+        }
+
+        Self {
+            value: StaticString::from_str_const(name),
+        }
+    }
+
+    /// Returns the maximum length in bytes of a [`ServiceName`]
+    pub const fn max_len() -> usize {
+        SERVICE_NAME_LENGTH
+    }
+
+    /// Returns a str reference to the [`ServiceName`]
+    pub fn as_str(&self) -> &str {
+        self.value.as_str()
+    }
+
+    /// Returns an iterator over the segments of the [`ServiceName`] that are separated by `/`.
+    /// A leading, trailing or double `/` results in an empty segment.
+    ///
+    /// ```
+    /// use iceoryx2_core::service_name::ServiceName;
+    ///
+    /// # fn main() -> Result<(), iceoryx2_core::service_name::ServiceNameError> {
+    /// let service_name = ServiceName::new("vehicle/sensors/lidar")?;
+    /// let components: Vec<&str> = service_name.components().collect();
+    ///
+    /// assert_eq!(components, ["vehicle", "sensors", "lidar"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn components(&self) -> core::str::Split<'_, char> {
+        self.as_str().split('/')
+    }
+
+    const fn verify(name: &[u8]) -> Result<(), ServiceNameError> {
+        if name.is_empty() {
+            return Err(ServiceNameError::IsEmpty);
+        }
+
+        if name.len() > SERVICE_NAME_LENGTH {
+            return Err(ServiceNameError::ExceedsMaximumLength);
+        }
+
+        let mut i = 0;
+        while i < name.len() {
+            if matches!(name[i], 0x00..=0x1f | 0x7f) {
+                return Err(ServiceNameError::InvalidCharacter);
+            }
+            i += 1;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for ServiceName {
+    type Error = ServiceNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl core::fmt::Display for ServiceName {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::write!(f, "{}", self.value)
+    }
+}
+
+impl PartialEq<&str> for ServiceName {
+    fn eq(&self, other: &&str) -> bool {
+        *self.as_str() == **other
+    }
+}
+
+impl PartialEq<&str> for &ServiceName {
+    fn eq(&self, other: &&str) -> bool {
+        *self.as_str() == **other
+    }
+}
+
+impl core::ops::Deref for ServiceName {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "serde")]
+struct ServiceNameVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for ServiceNameVisitor {
+    type Value = ServiceName;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a string containing the service name")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match ServiceName::new(v) {
+            Ok(v) => Ok(v),
+            Err(e) => Err(E::custom(format!(
+                "invalid service name \"{}\" provided ({}).",
+                v, e
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ServiceName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ServiceNameVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ServiceName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
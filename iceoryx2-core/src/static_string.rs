@@ -0,0 +1,222 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An UTF-8 string with a compile time fixed capacity in bytes whose content is stored inline,
+//! it can be copied bytewise to another process or over any other transport.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_core::static_string::StaticString;
+//!
+//! let mut name = StaticString::<16>::try_from("lidar")?;
+//! name.push_str("/front")?;
+//!
+//! assert_eq!(name, "lidar/front");
+//! # Ok::<(), iceoryx2_core::static_string::StaticStringModificationError>(())
+//! ```
+
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+use core::ops::Deref;
+
+use crate::zero_copy_send::{layout_hash, ZeroCopySend};
+
+/// Failures that can occur when a [`StaticString`] is created or modified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StaticStringModificationError {
+    InsertWouldExceedCapacity,
+}
+
+impl core::fmt::Display for StaticStringModificationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::write!(f, "{}::{:?}", core::stringify!(Self), self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StaticStringModificationError {}
+
+/// UTF-8 string with a compile time fixed capacity, see the [module documentation](self).
+/// The length is stored as `u32` so that the layout does not depend on the pointer width,
+/// the capacity is therefore limited to [`u32::MAX`].
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct StaticString<const CAPACITY: usize> {
+    len: u32,
+    data: [u8; CAPACITY],
+}
+
+impl<const CAPACITY: usize> StaticString<CAPACITY> {
+    const CAPACITY_FITS_INTO_LEN: () = assert!(
+        CAPACITY <= u32::MAX as usize,
+        "The capacity of a StaticString must not exceed u32::MAX."
+    );
+
+    /// Creates a new empty [`StaticString`].
+    pub const fn new() -> Self {
+        // evaluates the capacity check when the type is instantiated
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::CAPACITY_FITS_INTO_LEN;
+        Self {
+            len: 0,
+            data: [0; CAPACITY],
+        }
+    }
+
+    /// Creates a new [`StaticString`] in a const context. It panics when the value does not
+    /// fit into the capacity which becomes a compile time error when it is evaluated in a const
+    /// context.
+    pub const fn from_str_const(value: &str) -> Self {
+        let bytes = value.as_bytes();
+        if bytes.len() > CAPACITY {
+            panic!("The value exceeds the capacity of the StaticString.");
+        }
+
+        let mut new_self = Self::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            new_self.data[i] = bytes[i];
+            i += 1;
+        }
+        new_self.len = bytes.len() as u32;
+        new_self
+    }
+
+    /// Returns the capacity of the string in bytes
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the length of the string in bytes
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns true if the string is empty, otherwise false
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the string is full, otherwise false
+    pub const fn is_full(&self) -> bool {
+        self.len() == CAPACITY
+    }
+
+    /// Returns the content as str
+    pub fn as_str(&self) -> &str {
+        // SAFETY: the content is only ever assigned from a `&str`
+        unsafe { core::str::from_utf8_unchecked(&self.data[..self.len()]) }
+    }
+
+    /// Returns the content as bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data[..self.len()]
+    }
+
+    /// Appends the value. If it does not fit into the string it returns an error and leaves
+    /// the string unchanged.
+    pub fn push_str(&mut self, value: &str) -> Result<(), StaticStringModificationError> {
+        let bytes = value.as_bytes();
+        let len = self.len();
+        if CAPACITY - len < bytes.len() {
+            return Err(StaticStringModificationError::InsertWouldExceedCapacity);
+        }
+
+        self.data[len..len + bytes.len()].copy_from_slice(bytes);
+        self.len = (len + bytes.len()) as u32;
+        Ok(())
+    }
+
+    /// Removes the content of the string
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const CAPACITY: usize> Default for StaticString<CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAPACITY: usize> Deref for StaticString<CAPACITY> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const CAPACITY: usize> Debug for StaticString<CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "StaticString<{}> {:?}", CAPACITY, self.as_str())
+    }
+}
+
+impl<const CAPACITY: usize> Display for StaticString<CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// only the content is compared and hashed, the unused bytes are ignored
+impl<const CAPACITY: usize, const OTHER_CAPACITY: usize> PartialEq<StaticString<OTHER_CAPACITY>>
+    for StaticString<CAPACITY>
+{
+    fn eq(&self, other: &StaticString<OTHER_CAPACITY>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const CAPACITY: usize> Eq for StaticString<CAPACITY> {}
+
+impl<const CAPACITY: usize> PartialEq<&str> for StaticString<CAPACITY> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const CAPACITY: usize> Hash for StaticString<CAPACITY> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const CAPACITY: usize> TryFrom<&str> for StaticString<CAPACITY> {
+    type Error = StaticStringModificationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut new_self = Self::new();
+        new_self.push_str(value)?;
+        Ok(new_self)
+    }
+}
+
+// a received string is valid when its length does not exceed the capacity and its content is
+// valid UTF-8
+unsafe impl<const CAPACITY: usize> ZeroCopySend for StaticString<CAPACITY> {
+    const LAYOUT_HASH: Option<u64> = Some(layout_hash::with_value(
+        layout_hash::of_layout::<Self>(layout_hash::of_name("StaticString")),
+        CAPACITY as u64,
+    ));
+
+    unsafe fn is_valid_representation(value: *const Self) -> bool {
+        let len = core::ptr::read_unaligned(core::ptr::addr_of!((*value).len)) as usize;
+        if len > CAPACITY {
+            return false;
+        }
+
+        let data = core::ptr::addr_of!((*value).data) as *const u8;
+        core::str::from_utf8(core::slice::from_raw_parts(data, len)).is_ok()
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A vector with a compile time fixed capacity whose elements are stored inline. It contains
+//! neither a pointer nor a relative pointer and can therefore be copied bytewise to another
+//! process or over any other transport.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_core::static_vec::StaticVec;
+//!
+//! let mut values = StaticVec::<u32, 4>::new();
+//! assert!(values.push(2));
+//! assert!(values.push(3));
+//!
+//! assert_eq!(values.as_slice(), [2, 3]);
+//! assert_eq!(values.capacity(), 4);
+//! ```
+
+use core::fmt::Debug;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+
+use crate::zero_copy_send::{layout_hash, ZeroCopySend};
+
+/// Failures that can occur when a [`StaticVec`] is created from a slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StaticVecCreateError {
+    ExceedsCapacity,
+}
+
+impl core::fmt::Display for StaticVecCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::write!(f, "{}::{:?}", core::stringify!(Self), self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StaticVecCreateError {}
+
+/// Vector with a compile time fixed capacity, see the [module documentation](self).
+/// The length is stored as `u32` so that the layout does not depend on the pointer width,
+/// the capacity is therefore limited to [`u32::MAX`].
+#[repr(C)]
+pub struct StaticVec<T, const CAPACITY: usize> {
+    len: u32,
+    data: [MaybeUninit<T>; CAPACITY],
+}
+
+impl<T, const CAPACITY: usize> StaticVec<T, CAPACITY> {
+    const CAPACITY_FITS_INTO_LEN: () = assert!(
+        CAPACITY <= u32::MAX as usize,
+        "The capacity of a StaticVec must not exceed u32::MAX."
+    );
+
+    /// Creates a new empty [`StaticVec`].
+    pub const fn new() -> Self {
+        // evaluates the capacity check when the type is instantiated
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::CAPACITY_FITS_INTO_LEN;
+        Self {
+            len: 0,
+            // SAFETY: an array of uninitialized elements does not require initialization
+            data: unsafe { MaybeUninit::uninit().assume_init() },
+        }
+    }
+
+    /// Returns the capacity of the vector
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the number of elements stored inside the vector
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns true if the vector is empty, otherwise false
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the vector is full, otherwise false
+    pub const fn is_full(&self) -> bool {
+        self.len() == CAPACITY
+    }
+
+    /// Adds an element at the end of the vector. If the vector is full it returns false,
+    /// otherwise true.
+    pub fn push(&mut self, value: T) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
+        self.data[self.len()].write(value);
+        self.len += 1;
+        true
+    }
+
+    /// Appends all elements of the slice. If they do not fit into the vector it returns false
+    /// and leaves the vector unchanged, otherwise true.
+    pub fn extend_from_slice(&mut self, other: &[T]) -> bool
+    where
+        T: Clone,
+    {
+        if CAPACITY - self.len() < other.len() {
+            return false;
+        }
+
+        for element in other {
+            self.push(element.clone());
+        }
+        true
+    }
+
+    /// Removes the last element of the vector and returns it. If the vector is empty it
+    /// returns [`None`].
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        // SAFETY: the element was initialized and is no longer part of the vector
+        Some(unsafe { self.data[self.len()].assume_init_read() })
+    }
+
+    /// Removes all elements from the vector
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+
+    /// Returns a slice of the elements
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first len elements are initialized
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr() as *const T, self.len()) }
+    }
+
+    /// Returns a mutable slice of the elements
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first len elements are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.len()) }
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for StaticVec<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const CAPACITY: usize> Drop for StaticVec<T, CAPACITY> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const CAPACITY: usize> Deref for StaticVec<T, CAPACITY> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const CAPACITY: usize> DerefMut for StaticVec<T, CAPACITY> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Debug, const CAPACITY: usize> Debug for StaticVec<T, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "StaticVec<{}> {:?}", CAPACITY, self.as_slice())
+    }
+}
+
+impl<T: PartialEq, const CAPACITY: usize> PartialEq for StaticVec<T, CAPACITY> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const CAPACITY: usize> Eq for StaticVec<T, CAPACITY> {}
+
+impl<T: Clone, const CAPACITY: usize> Clone for StaticVec<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        let mut new_self = Self::new();
+        new_self.extend_from_slice(self.as_slice());
+        new_self
+    }
+}
+
+impl<T: Clone, const CAPACITY: usize> TryFrom<&[T]> for StaticVec<T, CAPACITY> {
+    type Error = StaticVecCreateError;
+
+    fn try_from(value: &[T]) -> Result<Self, Self::Error> {
+        let mut new_self = Self::new();
+        match new_self.extend_from_slice(value) {
+            true => Ok(new_self),
+            false => Err(StaticVecCreateError::ExceedsCapacity),
+        }
+    }
+}
+
+// the elements are stored inline, a received vector is valid when its length does not exceed
+// the capacity and all of its elements are valid
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for StaticVec<T, CAPACITY> {
+    const LAYOUT_HASH: Option<u64> = layout_hash::combine(
+        layout_hash::with_value(
+            layout_hash::of_layout::<Self>(layout_hash::of_name("StaticVec")),
+            CAPACITY as u64,
+        ),
+        T::LAYOUT_HASH,
+    );
+
+    unsafe fn is_valid_representation(value: *const Self) -> bool {
+        let len = core::ptr::read_unaligned(core::ptr::addr_of!((*value).len)) as usize;
+        let first = core::ptr::addr_of!((*value).data) as *const T;
+        len <= CAPACITY && (0..len).all(|n| T::is_valid_representation(first.add(n)))
+    }
+}
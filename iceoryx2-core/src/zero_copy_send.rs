@@ -0,0 +1,229 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Payloads are shared between processes and must not refer to memory outside of the payload
+//! itself, a `String` or a `Vec` would hand over pointers into the heap of the sending process.
+//! Only types that implement [`ZeroCopySend`] can be used as payload of an iceoryx2 service.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_core::zero_copy_send::ZeroCopySend;
+//!
+//! #[derive(Debug, ZeroCopySend)]
+//! #[zero_copy_send(crate = iceoryx2_core)]
+//! #[repr(u8)]
+//! enum Gear {
+//!     Park,
+//!     Drive { speed: u32 },
+//! }
+//!
+//! #[derive(Debug, ZeroCopySend)]
+//! #[zero_copy_send(crate = iceoryx2_core)]
+//! #[repr(C)]
+//! struct TransmissionData {
+//!     x: i32,
+//!     y: i32,
+//!     funky: f64,
+//!     gear: Gear,
+//! }
+//! ```
+
+pub use iceoryx2_derive::ZeroCopySend;
+
+/// Marks a type whose values can be transferred to another process by copying its bytes. It
+/// is implemented for the primitive types, arrays and slices of them and the containers of
+/// iceoryx2, like the [`crate::static_vec::StaticVec`], and can be derived for `#[repr(C)]`
+/// structs and for enums with `#[repr(C)]` or a primitive representation like `#[repr(u8)]`
+/// whose fields implement [`ZeroCopySend`].
+///
+/// # Safety
+///
+///  * the type must not contain pointers or references, neither to the heap nor to the stack
+///    or any other process local memory
+///  * the type must have a defined memory layout, like `#[repr(C)]`, that is equal in every
+///    process
+///  * [`ZeroCopySend::is_valid_representation()`] must return `false` for every bit pattern that
+///    is not a valid value of the type, unless the type trusts the sender like the default
+///    implementation does
+pub unsafe trait ZeroCopySend {
+    /// A fingerprint of the memory layout of the type that does not depend on its name, see
+    /// [`layout_hash`]. Two types with the same size, alignment and fields at the same offsets
+    /// with the same layout hashes have the same fingerprint. It is [`None`] when the layout is
+    /// unknown, like for manual implementations that do not provide it, then only the size and
+    /// the alignment of the type can be compared.
+    const LAYOUT_HASH: Option<u64> = None;
+
+    /// Returns `false` when the bytes at `value`, that were written by another process, are no
+    /// valid value of the type, like a `bool` that is neither 0 nor 1 or an enum with an unknown
+    /// discriminant. A subscriber discards such a sample as corrupted instead of handing out a
+    /// reference to it. The default implementation accepts every bit pattern.
+    ///
+    /// # Safety
+    ///
+    ///  * `value` must point to initialized memory of the size of the type, it does not have to
+    ///    be aligned
+    #[doc(hidden)]
+    unsafe fn is_valid_representation(_value: *const Self) -> bool {
+        true
+    }
+}
+
+/// Building blocks of [`ZeroCopySend::LAYOUT_HASH`]. The fingerprint is a FNV-1a hash over
+/// the size, the alignment and the fields of a type that is computed at compile time, it is
+/// equal for every compiler version and process. The derive of [`ZeroCopySend`] emits it for
+/// structs and enums, a manual implementation can combine it from the hashes of its fields.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2_core::zero_copy_send::{layout_hash, ZeroCopySend};
+///
+/// #[repr(C)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// unsafe impl ZeroCopySend for Position {
+///     const LAYOUT_HASH: Option<u64> = layout_hash::combine_optional(
+///         layout_hash::combine(
+///             layout_hash::of_layout::<Self>(layout_hash::of_name("struct")),
+///             <f32 as ZeroCopySend>::LAYOUT_HASH,
+///         ),
+///         <f32 as ZeroCopySend>::LAYOUT_HASH,
+///     );
+/// }
+/// ```
+pub mod layout_hash {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    const fn hash_bytes(mut hash: u64, bytes: &[u8]) -> u64 {
+        let mut n = 0;
+        while n < bytes.len() {
+            hash ^= bytes[n] as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+            n += 1;
+        }
+        hash
+    }
+
+    /// Returns the hash of a name, it is the start of every fingerprint and identifies the kind
+    /// of the type, like `"struct"`, or a primitive type, like `"u32"`.
+    pub const fn of_name(name: &str) -> u64 {
+        hash_bytes(FNV_OFFSET_BASIS, name.as_bytes())
+    }
+
+    /// Extends `hash` by `value`.
+    pub const fn with_value(hash: u64, value: u64) -> u64 {
+        hash_bytes(hash, &value.to_le_bytes())
+    }
+
+    /// Extends `hash` by the size and the alignment of `T`.
+    pub const fn of_layout<T>(hash: u64) -> u64 {
+        with_value(
+            with_value(hash, core::mem::size_of::<T>() as u64),
+            core::mem::align_of::<T>() as u64,
+        )
+    }
+
+    /// Extends `hash` by the fingerprint of a field. Without the fingerprint of the field the
+    /// layout of the whole type is unknown and [`None`] is returned.
+    pub const fn combine(hash: u64, field: Option<u64>) -> Option<u64> {
+        match field {
+            Some(field) => Some(with_value(hash, field)),
+            None => None,
+        }
+    }
+
+    /// Like [`combine()`] for a `hash` that is already unknown.
+    pub const fn combine_optional(hash: Option<u64>, field: Option<u64>) -> Option<u64> {
+        match hash {
+            Some(hash) => combine(hash, field),
+            None => None,
+        }
+    }
+}
+
+macro_rules! impl_zero_copy_send {
+    ($($t:ty),*) => {
+        $(unsafe impl ZeroCopySend for $t {
+            const LAYOUT_HASH: Option<u64> = Some(layout_hash::of_layout::<$t>(
+                layout_hash::of_name(core::stringify!($t)),
+            ));
+        })*
+    };
+}
+
+impl_zero_copy_send!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    ()
+);
+
+unsafe impl ZeroCopySend for bool {
+    const LAYOUT_HASH: Option<u64> =
+        Some(layout_hash::of_layout::<bool>(layout_hash::of_name("bool")));
+
+    unsafe fn is_valid_representation(value: *const Self) -> bool {
+        core::ptr::read_unaligned(value as *const u8) <= 1
+    }
+}
+
+unsafe impl ZeroCopySend for char {
+    const LAYOUT_HASH: Option<u64> =
+        Some(layout_hash::of_layout::<char>(layout_hash::of_name("char")));
+
+    unsafe fn is_valid_representation(value: *const Self) -> bool {
+        char::from_u32(core::ptr::read_unaligned(value as *const u32)).is_some()
+    }
+}
+
+unsafe impl<T: ZeroCopySend, const N: usize> ZeroCopySend for [T; N] {
+    const LAYOUT_HASH: Option<u64> = layout_hash::combine(
+        layout_hash::with_value(layout_hash::of_name("array"), N as u64),
+        T::LAYOUT_HASH,
+    );
+
+    unsafe fn is_valid_representation(value: *const Self) -> bool {
+        let first = value as *const T;
+        (0..N).all(|n| T::is_valid_representation(first.add(n)))
+    }
+}
+
+unsafe impl<T: ZeroCopySend> ZeroCopySend for [T] {
+    const LAYOUT_HASH: Option<u64> =
+        layout_hash::combine(layout_hash::of_name("slice"), T::LAYOUT_HASH);
+
+    unsafe fn is_valid_representation(value: *const Self) -> bool {
+        // the length is read via a slice of `()` which has no elements that could be invalid
+        let len = (&*(value as *const [()])).len();
+        let first = value as *const T;
+        (0..len).all(|n| T::is_valid_representation(first.add(n)))
+    }
+}
+// `MaybeUninit<T>` is deliberately excluded, the payload of a loaned but uninitialized
+// `SampleMut<MaybeUninit<T>>` is not `ZeroCopySend` and therefore the sample does not
+// dereference to it
@@ -0,0 +1,163 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// the test harness itself requires std but the tests are restricted to `core`, run them with
+// `cargo test -p iceoryx2-core --no-default-features` to verify the `no_std` build
+#![no_std]
+
+// `assert_that!` of iceoryx2-bb-testing requires std, the core assertions are used instead
+use core::mem::MaybeUninit;
+
+use iceoryx2_core::event_id::EventId;
+use iceoryx2_core::service_name::{ServiceName, ServiceNameError};
+use iceoryx2_core::static_string::{StaticString, StaticStringModificationError};
+use iceoryx2_core::static_vec::{StaticVec, StaticVecCreateError};
+use iceoryx2_core::zero_copy_send::ZeroCopySend;
+
+#[derive(Debug, ZeroCopySend)]
+#[zero_copy_send(crate = iceoryx2_core)]
+#[repr(C)]
+struct SensorReading {
+    sensor: StaticString<16>,
+    values: StaticVec<u32, 4>,
+    event: EventId,
+}
+
+#[derive(Debug, ZeroCopySend)]
+#[zero_copy_send(crate = iceoryx2_core)]
+#[repr(C)]
+struct RenamedSensorReading {
+    sensor: StaticString<16>,
+    values: StaticVec<u32, 4>,
+    event: EventId,
+}
+
+#[derive(Debug, ZeroCopySend)]
+#[zero_copy_send(crate = iceoryx2_core)]
+#[repr(C)]
+struct ReorderedSensorReading {
+    sensor: StaticString<16>,
+    event: EventId,
+    values: StaticVec<u32, 4>,
+}
+
+#[test]
+fn static_vec_push_and_pop_work() {
+    let mut sut = StaticVec::<u64, 3>::new();
+    assert!(sut.is_empty());
+    assert_eq!(sut.capacity(), 3);
+
+    assert!(sut.push(1));
+    assert!(sut.push(2));
+    assert!(sut.push(3));
+    assert!(sut.is_full());
+    assert!(!sut.push(4));
+    assert_eq!(sut.as_slice(), &[1, 2, 3]);
+
+    assert_eq!(sut.pop(), Some(3));
+    assert_eq!(sut.len(), 2);
+    sut.clear();
+    assert!(sut.is_empty());
+    assert_eq!(sut.pop(), None);
+}
+
+#[test]
+fn static_vec_try_from_slice_respects_capacity() {
+    let sut = StaticVec::<u8, 4>::try_from([1u8, 2, 3].as_slice()).unwrap();
+    assert_eq!(&*sut, &[1, 2, 3]);
+
+    assert_eq!(
+        StaticVec::<u8, 2>::try_from([1u8, 2, 3].as_slice()).err(),
+        Some(StaticVecCreateError::ExceedsCapacity)
+    );
+}
+
+#[test]
+fn static_string_push_str_respects_capacity() {
+    let mut sut = StaticString::<8>::new();
+    assert!(sut.push_str("lidar").is_ok());
+    assert_eq!(sut, "lidar");
+    assert_eq!(
+        sut.push_str("/front"),
+        Err(StaticStringModificationError::InsertWouldExceedCapacity)
+    );
+    assert_eq!(sut.as_str(), "lidar");
+
+    const STATIC: StaticString<8> = StaticString::from_str_const("radar");
+    assert_eq!(STATIC.len(), 5);
+}
+
+#[test]
+fn static_containers_have_the_same_layout_on_every_pointer_width() {
+    assert_eq!(core::mem::size_of::<StaticString<16>>(), 20);
+    assert_eq!(core::mem::align_of::<StaticString<16>>(), 4);
+    assert_eq!(core::mem::size_of::<StaticVec<u32, 4>>(), 20);
+    assert_eq!(core::mem::align_of::<StaticVec<u32, 4>>(), 4);
+}
+
+#[test]
+fn service_name_can_be_created_without_std() {
+    let sut = ServiceName::new("vehicle/sensors/lidar").unwrap();
+    assert_eq!(sut.as_str(), "vehicle/sensors/lidar");
+    assert_eq!(sut.components().count(), 3);
+
+    assert_eq!(ServiceName::new("").err(), Some(ServiceNameError::IsEmpty));
+    assert_eq!(
+        ServiceName::new("vehicle\n").err(),
+        Some(ServiceNameError::InvalidCharacter)
+    );
+}
+
+#[test]
+fn event_id_can_be_created_without_std() {
+    assert_eq!(EventId::new(123).as_u64(), 123);
+    assert_eq!(EventId::default().as_u64(), 0);
+}
+
+#[test]
+fn derived_zero_copy_send_validates_the_static_containers() {
+    let mut sut = SensorReading {
+        sensor: StaticString::from_str_const("lidar"),
+        values: StaticVec::try_from([1u32, 2].as_slice()).unwrap(),
+        event: EventId::new(7),
+    };
+    assert!(unsafe { SensorReading::is_valid_representation(&sut) });
+
+    // a length beyond the capacity, written by a faulty sender, must be rejected
+    let mut corrupted = MaybeUninit::<SensorReading>::uninit();
+    unsafe {
+        core::ptr::copy_nonoverlapping(&sut, corrupted.as_mut_ptr(), 1);
+        let len = core::ptr::addr_of_mut!((*corrupted.as_mut_ptr()).values) as *mut usize;
+        len.write(5);
+        assert!(!SensorReading::is_valid_representation(corrupted.as_ptr()));
+    }
+
+    sut.values.clear();
+    assert!(unsafe { SensorReading::is_valid_representation(&sut) });
+}
+
+#[test]
+fn derived_layout_hash_depends_on_the_structure_only() {
+    assert!(SensorReading::LAYOUT_HASH.is_some());
+    assert_eq!(
+        SensorReading::LAYOUT_HASH,
+        RenamedSensorReading::LAYOUT_HASH
+    );
+    assert_ne!(
+        SensorReading::LAYOUT_HASH,
+        ReorderedSensorReading::LAYOUT_HASH
+    );
+    assert_ne!(
+        StaticVec::<u32, 4>::LAYOUT_HASH,
+        StaticVec::<u32, 5>::LAYOUT_HASH
+    );
+}
@@ -0,0 +1,755 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Derive macros for the payload types of iceoryx2. They are re-exported by the `iceoryx2`
+//! crate and should be used from there.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Literal};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DataEnum, DeriveInput, Error, Expr, Field, Fields,
+    GenericParam, Index, Member, Variant,
+};
+
+/// Implements `iceoryx2::zero_copy_send::ZeroCopySend` for a struct or an enum. It is rejected
+/// at compile time when the type has no defined memory layout or when one of its fields does not
+/// implement `ZeroCopySend` itself. Structs must be `#[repr(C)]` or `#[repr(transparent)]`,
+/// enums `#[repr(C)]` or have a primitive representation like `#[repr(u8)]` so that the size
+/// and the values of the discriminant are equal in C and C++.
+///
+/// A received value is validated before it is handed out, a struct is valid when all of its
+/// fields are valid. An enum with a primitive representation, like `#[repr(u8)]` or
+/// `#[repr(C, u32)]`, is valid when its discriminant belongs to a variant and the fields of
+/// this variant are valid. Only for an enum that is just `#[repr(C)]` the process that receives
+/// it must be able to trust the sender since the size of its discriminant is defined by the C
+/// compiler.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+///
+/// #[derive(Debug, ZeroCopySend)]
+/// #[repr(C)]
+/// struct TransmissionData {
+///     x: i32,
+///     y: i32,
+///     funky: f64,
+/// }
+/// ```
+///
+/// The implementation refers to the trait via the `iceoryx2` crate. Code that shares its
+/// payload types with `no_std` targets depends only on `iceoryx2-core` and names it with
+/// `#[zero_copy_send(crate = iceoryx2_core)]`, processes that use `iceoryx2` see the same trait.
+///
+/// ```
+/// use iceoryx2_core::zero_copy_send::ZeroCopySend;
+///
+/// #[derive(Debug, ZeroCopySend)]
+/// #[zero_copy_send(crate = iceoryx2_core)]
+/// #[repr(C)]
+/// struct TransmissionData {
+///     x: i32,
+///     y: i32,
+/// }
+/// ```
+#[proc_macro_derive(ZeroCopySend, attributes(zero_copy_send))]
+pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match zero_copy_send_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Implements `iceoryx2::tagged_payload::TaggedPayload` for an enum with a primitive
+/// representation like `#[repr(C, u32)]` so that it can be used as tagged union payload of a
+/// publish subscribe service. For every variant with exactly one unnamed field it implements
+/// `From` for the type of the field, so that the variant can be sent with
+/// `Publisher::send_variant()`. Variants whose field type is used by another variant as well
+/// have to be constructed explicitly.
+///
+/// `ZeroCopySend` has to be derived as well, it validates the discriminant of a received
+/// value.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy, ZeroCopySend)]
+/// #[repr(C)]
+/// struct Start {
+///     speed: u32,
+/// }
+///
+/// #[derive(Debug, Clone, Copy, ZeroCopySend)]
+/// #[repr(C)]
+/// struct Stop;
+///
+/// #[derive(Debug, Clone, Copy, ZeroCopySend, TaggedPayload)]
+/// #[repr(C, u32)]
+/// enum ControlMessage {
+///     Start(Start),
+///     Stop(Stop),
+/// }
+/// ```
+#[proc_macro_derive(TaggedPayload)]
+pub fn tagged_payload_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match tagged_payload_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Implements `From<Enum> for EventId` and `TryFrom<EventId> for Enum` for a fieldless enum
+/// so that application defined events can be used instead of raw event ids. Every variant
+/// requires an explicit discriminant, it is the value of the event id, so that reordering the
+/// variants does not change the ids that other processes expect. An event id that does not
+/// belong to a variant is rejected with `EventIdConversionError::UnknownEventId`.
+///
+/// With `#[event_id(max_value = N)]` it is checked at compile time that no discriminant is
+/// larger than the `event_id_max_value` of the event service. Negative discriminants are
+/// always rejected.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, EventIdEnum)]
+/// #[event_id(max_value = 16)]
+/// enum CameraEvent {
+///     FrameReady = 1,
+///     CameraFailure = 2,
+/// }
+///
+/// let event_id: EventId = CameraEvent::CameraFailure.into();
+/// assert_eq!(CameraEvent::try_from(event_id), Ok(CameraEvent::CameraFailure));
+/// ```
+///
+/// Like for `ZeroCopySend`, code that depends only on `iceoryx2-core` names it with
+/// `#[event_id(crate = iceoryx2_core)]`.
+#[proc_macro_derive(EventIdEnum, attributes(event_id))]
+pub fn event_id_enum_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match event_id_enum_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn zero_copy_send_impl(input: &DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let fields: Vec<&Field> = match &input.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "ZeroCopySend can only be derived for structs and enums, the active field of a union that was received from another process is unknown",
+            ))
+        }
+    };
+
+    let is_enum = matches!(input.data, Data::Enum(_));
+    let repr = Representation::parse(input, is_enum)?;
+    if !repr.has_defined_layout {
+        let msg = match is_enum {
+            true => format!(
+                "ZeroCopySend requires a defined memory layout, add #[repr(C)] or a primitive representation like #[repr(u8)] to \"{}\" so that every process sees the same discriminant",
+                input.ident
+            ),
+            false => format!(
+                "ZeroCopySend requires a defined memory layout, add #[repr(C)] to \"{}\" so that every process sees the same field order",
+                input.ident
+            ),
+        };
+        return Err(Error::new(input.ident.span(), msg));
+    }
+
+    let crate_path = zero_copy_send_crate(input)?;
+    let zero_copy_send = quote!(#crate_path::zero_copy_send::ZeroCopySend);
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    // the bounds point to the field, the compiler reports the field whose type does not
+    // implement ZeroCopySend
+    let field_bounds = fields.iter().map(|field| {
+        let ty = &field.ty;
+        quote_spanned!(ty.span()=> #ty: #zero_copy_send)
+    });
+    let predicates = where_clause
+        .map(|w| w.predicates.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+    let bounds = quote!(
+        where
+            #(#predicates,)*
+            #(#field_bounds,)*
+    );
+
+    let validation = match &input.data {
+        Data::Struct(data) => Some(struct_validation(&data.fields, &zero_copy_send)),
+        Data::Enum(data) => repr.primitive.as_ref().map(|primitive| {
+            enum_validation(input, data, primitive, repr.is_c, &bounds, &zero_copy_send)
+        }),
+        Data::Union(_) => None,
+    };
+
+    let layout_hash = layout_hash(input, &repr, &crate_path, &zero_copy_send);
+
+    let (helpers, validation) = match validation {
+        Some((helpers, body)) => (
+            helpers,
+            quote! {
+                #[allow(unused_unsafe, unused_variables)]
+                unsafe fn is_valid_representation(value: *const Self) -> bool {
+                    unsafe { #body }
+                }
+            },
+        ),
+        None => (quote!(), quote!()),
+    };
+
+    Ok(quote! {
+        const _: () = {
+            #helpers
+
+            unsafe impl #impl_generics #zero_copy_send for #name #type_generics
+            #bounds
+            {
+                #layout_hash
+
+                #validation
+            }
+        };
+    })
+}
+
+/// The fingerprint of a struct consists of its size, its alignment and the offset and the
+/// fingerprint of every field. The one of an enum consists of its size, its alignment, its
+/// representation and for every variant the discriminant and the fingerprints of the fields,
+/// their offsets are defined by the representation. Names are not part of it so that the same
+/// type can be defined in different crates.
+fn layout_hash(
+    input: &DeriveInput,
+    repr: &Representation,
+    crate_path: &proc_macro2::TokenStream,
+    zero_copy_send: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let hash = quote!(#crate_path::zero_copy_send::layout_hash);
+
+    let (kind, parts) = match &input.data {
+        Data::Struct(data) => {
+            let parts = data.fields.iter().enumerate().map(|(n, field)| {
+                let ty = &field.ty;
+                let member = match &field.ident {
+                    Some(ident) => Member::Named(ident.clone()),
+                    None => Member::Unnamed(Index::from(n)),
+                };
+                quote! {
+                    let offset = {
+                        let value = ::core::mem::MaybeUninit::<Self>::uninit();
+                        let base = value.as_ptr();
+                        #[allow(unused_unsafe)]
+                        unsafe {
+                            (::core::ptr::addr_of!((*base).#member) as *const u8)
+                                .offset_from(base as *const u8) as u64
+                        }
+                    };
+                    let hash = #hash::combine_optional(hash, Some(offset));
+                    let hash = #hash::combine_optional(hash, <#ty as #zero_copy_send>::LAYOUT_HASH);
+                }
+            });
+            ("struct".to_string(), parts.collect::<Vec<_>>())
+        }
+        Data::Enum(data) => {
+            let kind = match (&repr.primitive, repr.is_c) {
+                (Some(primitive), true) => format!("enum(C, {})", primitive),
+                (Some(primitive), false) => format!("enum({})", primitive),
+                (None, _) => "enum(C)".to_string(),
+            };
+            let discriminant_type = match &repr.primitive {
+                Some(primitive) => quote!(#primitive),
+                None => quote!(isize),
+            };
+            let parts = data
+                .variants
+                .iter()
+                .zip(discriminants(data))
+                .map(|(variant, discriminant)| {
+                    let number_of_fields = variant.fields.len() as u64;
+                    let fields = variant.fields.iter().map(|field| {
+                        let ty = &field.ty;
+                        quote!(let hash = #hash::combine_optional(hash, <#ty as #zero_copy_send>::LAYOUT_HASH);)
+                    });
+                    quote! {
+                        let discriminant: #discriminant_type = #discriminant;
+                        let hash = #hash::combine_optional(hash, Some(discriminant as u64));
+                        let hash = #hash::combine_optional(hash, Some(#number_of_fields));
+                        #(#fields)*
+                    }
+                });
+            (kind, parts.collect::<Vec<_>>())
+        }
+        Data::Union(_) => return quote!(),
+    };
+
+    quote! {
+        const LAYOUT_HASH: Option<u64> = {
+            let hash = Some(#hash::of_layout::<Self>(#hash::of_name(#kind)));
+            #(#parts)*
+            hash
+        };
+    }
+}
+
+/// Returns the discriminant of every variant, variants without an explicit discriminant
+/// continue from the previous one.
+fn discriminants(data: &DataEnum) -> Vec<proc_macro2::TokenStream> {
+    let mut last_explicit: Option<&Expr> = None;
+    let mut implicit_offset: u64 = 0;
+    data.variants
+        .iter()
+        .map(|variant| {
+            if let Some((_, expr)) = &variant.discriminant {
+                last_explicit = Some(expr);
+                implicit_offset = 0;
+            }
+            let offset = Literal::u64_unsuffixed(implicit_offset);
+            let discriminant = match last_explicit {
+                Some(expr) if implicit_offset == 0 => quote!((#expr)),
+                Some(expr) => quote!((#expr) + #offset),
+                None => quote!(#offset),
+            };
+            implicit_offset += 1;
+            discriminant
+        })
+        .collect()
+}
+
+/// A struct is valid when all of its fields are valid.
+fn struct_validation(
+    fields: &Fields,
+    zero_copy_send: &proc_macro2::TokenStream,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let checks = fields.iter().enumerate().map(|(n, field)| {
+        let ty = &field.ty;
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(n)),
+        };
+        quote!(&& <#ty as #zero_copy_send>::is_valid_representation(
+            ::core::ptr::addr_of!((*value).#member)
+        ))
+    });
+
+    (quote!(), quote!(true #(#checks)*))
+}
+
+/// An enum is valid when its discriminant belongs to a variant and the fields of this variant
+/// are valid. The fields are located with `#[repr(C)]` helper structs that mirror the layout
+/// of the variants, see the primitive representations of enums in the Rust reference.
+fn enum_validation(
+    input: &DeriveInput,
+    data: &DataEnum,
+    primitive: &Ident,
+    is_c: bool,
+    bounds: &proc_macro2::TokenStream,
+    zero_copy_send: &proc_macro2::TokenStream,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let (impl_generics, type_generics, _) = input.generics.split_for_impl();
+
+    // every generic parameter must be used by the helper structs
+    let phantom_types = input
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(t) => {
+                let ident = &t.ident;
+                Some(quote!(::core::marker::PhantomData<*const #ident>))
+            }
+            GenericParam::Lifetime(l) => {
+                let lifetime = &l.lifetime;
+                Some(quote!(::core::marker::PhantomData<&#lifetime ()>))
+            }
+            GenericParam::Const(_) => None,
+        });
+    let phantom = quote!(__phantom: (#(#phantom_types,)*),);
+
+    let helper_name = |n: usize| format_ident!("__{}Variant{}", input.ident, n);
+
+    let helpers = data.variants.iter().enumerate().map(|(n, variant)| {
+        let helper = helper_name(n);
+        let fields = variant.fields.iter().enumerate().map(|(m, field)| {
+            let ty = &field.ty;
+            let member = format_ident!("f{}", m);
+            quote!(#member: #ty,)
+        });
+        // with #[repr(C, primitive)] the fields of all variants are stored in a union behind the
+        // discriminant, with #[repr(primitive)] every variant starts with the discriminant
+        let tag = match is_c {
+            true => quote!(),
+            false => quote!(__tag: #primitive,),
+        };
+        quote! {
+            #[repr(C)]
+            #[allow(non_camel_case_types, dead_code)]
+            struct #helper #impl_generics #bounds {
+                #tag
+                #(#fields)*
+                #phantom
+            }
+        }
+    });
+
+    let payload_offset = match is_c {
+        true => {
+            let aligns = (0..data.variants.len()).map(|n| {
+                let helper = helper_name(n);
+                quote!(::core::mem::align_of::<#helper #type_generics>())
+            });
+            quote! {{
+                let align = [1usize #(, #aligns)*].into_iter().max().unwrap_or(1);
+                (::core::mem::size_of::<#primitive>() + align - 1) / align * align
+            }}
+        }
+        false => quote!(0usize),
+    };
+
+    let discriminants = discriminants(data);
+    let checks = data.variants.iter().enumerate().map(|(n, variant)| {
+        let discriminant = &discriminants[n];
+
+        let helper = helper_name(n);
+        let field_checks = variant.fields.iter().enumerate().map(|(m, field)| {
+            let ty = &field.ty;
+            let member = format_ident!("f{}", m);
+            quote! {
+                && <#ty as #zero_copy_send>::is_valid_representation({
+                    let offset = ::core::ptr::addr_of!((*layout).#member) as usize - layout as usize;
+                    (value as usize + payload_offset + offset) as *const #ty
+                })
+            }
+        });
+
+        quote! {
+            if discriminant == #discriminant {
+                let helper = ::core::mem::MaybeUninit::<#helper #type_generics>::uninit();
+                let layout = helper.as_ptr();
+                let _ = layout;
+                return true #(#field_checks)*;
+            }
+        }
+    });
+    let checks: Vec<_> = checks.collect();
+
+    (
+        quote!(#(#helpers)*),
+        quote! {
+            let discriminant: #primitive = ::core::ptr::read_unaligned(value as *const #primitive);
+            let payload_offset: usize = #payload_offset;
+            let _ = payload_offset;
+            #(#checks)*
+            false
+        },
+    )
+}
+
+fn tagged_payload_impl(input: &DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "TaggedPayload can only be derived for enums",
+            ))
+        }
+    };
+
+    let repr = Representation::parse(input, true)?;
+    let primitive = match repr.primitive {
+        Some(primitive) => primitive,
+        None => {
+            return Err(Error::new(
+                input.ident.span(),
+                format!(
+                    "TaggedPayload requires a primitive representation of the discriminant, add #[repr(C, u32)] or #[repr(u32)] to \"{}\" so that every process can validate it",
+                    input.ident
+                ),
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    // a type that is used by more than one variant does not identify the variant
+    let single_field_type = |variant: &Variant| match &variant.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first().cloned(),
+        _ => None,
+    };
+    let type_names: Vec<String> = data
+        .variants
+        .iter()
+        .filter_map(single_field_type)
+        .map(|field| field.ty.to_token_stream().to_string())
+        .collect();
+    let conversions = data.variants.iter().filter_map(|variant| {
+        let field = single_field_type(variant)?;
+        let type_name = field.ty.to_token_stream().to_string();
+        if type_names.iter().filter(|n| **n == type_name).count() != 1 {
+            return None;
+        }
+
+        let ty = &field.ty;
+        let variant_name = &variant.ident;
+        Some(quote! {
+            impl #impl_generics ::core::convert::From<#ty> for #name #type_generics #where_clause {
+                fn from(value: #ty) -> Self {
+                    Self::#variant_name(value)
+                }
+            }
+        })
+    });
+
+    Ok(quote! {
+        unsafe impl #impl_generics ::iceoryx2::tagged_payload::TaggedPayload for #name #type_generics #where_clause {
+            type Discriminant = #primitive;
+
+            fn discriminant(&self) -> Self::Discriminant {
+                // SAFETY: the primitive representation stores the discriminant at the start
+                unsafe { ::core::ptr::read(self as *const Self as *const #primitive) }
+            }
+        }
+
+        #(#conversions)*
+    })
+}
+
+fn event_id_enum_impl(input: &DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "EventIdEnum can only be derived for enums",
+            ))
+        }
+    };
+
+    let attributes = EventIdAttributes::parse(input)?;
+    let event_id_module = attributes.crate_path;
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    for variant in data.variants.iter() {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(
+                variant.span(),
+                format!(
+                    "EventIdEnum requires a fieldless enum, \"{}::{}\" has fields",
+                    name, variant.ident
+                ),
+            ));
+        }
+
+        if variant.discriminant.is_none() {
+            return Err(Error::new(
+                variant.span(),
+                format!(
+                    "EventIdEnum requires an explicit discriminant since it is the value of the event id, add \"{} = <id>\" to \"{}\"",
+                    variant.ident, name
+                ),
+            ));
+        }
+    }
+
+    // evaluated by the compiler so that the discriminants can be arbitrary constant expressions
+    let range_checks = data.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let negative_message = format!(
+            "the discriminant of \"{}::{}\" is negative and cannot be an event id",
+            name, variant_name
+        );
+        let max_value_check = attributes.max_value.map(|max_value| {
+            let message = format!(
+                "the discriminant of \"{}::{}\" exceeds the event_id max_value of {}",
+                name, variant_name, max_value
+            );
+            quote_spanned! {variant.span() =>
+                ::core::assert!((#name::#variant_name as i128) <= #max_value as i128, #message);
+            }
+        });
+
+        quote_spanned! {variant.span() =>
+            ::core::assert!((#name::#variant_name as i128) >= 0, #negative_message);
+            #max_value_check
+        }
+    });
+
+    let variants = data.variants.iter().map(|variant| &variant.ident);
+    let conversions = data.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        quote! {
+            value if value == #name::#variant_name as u64 => ::core::result::Result::Ok(#name::#variant_name),
+        }
+    });
+
+    Ok(quote! {
+        const _: () = {
+            #(#range_checks)*
+        };
+
+        impl #impl_generics ::core::convert::From<#name #type_generics> for #event_id_module::event_id::EventId #where_clause {
+            fn from(value: #name #type_generics) -> Self {
+                match value {
+                    #(#name::#variants => #event_id_module::event_id::EventId::new(#name::#variants as u64),)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<#event_id_module::event_id::EventId> for #name #type_generics #where_clause {
+            type Error = #event_id_module::event_id::EventIdConversionError;
+
+            fn try_from(value: #event_id_module::event_id::EventId) -> ::core::result::Result<Self, Self::Error> {
+                match value.as_u64() {
+                    #(#conversions)*
+                    _ => ::core::result::Result::Err(#event_id_module::event_id::EventIdConversionError::UnknownEventId),
+                }
+            }
+        }
+    })
+}
+
+/// The settings of `#[event_id(...)]`. The crate path names the crate that provides the
+/// `event_id` module, `::iceoryx2::port` unless it is overridden with `crate = path`.
+struct EventIdAttributes {
+    crate_path: proc_macro2::TokenStream,
+    max_value: Option<u64>,
+}
+
+impl EventIdAttributes {
+    fn parse(input: &DeriveInput) -> Result<Self, Error> {
+        let mut attributes = Self {
+            crate_path: quote!(::iceoryx2::port),
+            max_value: None,
+        };
+
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("event_id")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    let path: syn::Path = meta.value()?.parse()?;
+                    attributes.crate_path = path.to_token_stream();
+                    Ok(())
+                } else if meta.path.is_ident("max_value") {
+                    let value: syn::LitInt = meta.value()?.parse()?;
+                    attributes.max_value = Some(value.base10_parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "unsupported event_id attribute, expected `max_value = N` or `crate = path`",
+                    ))
+                }
+            })?;
+        }
+
+        Ok(attributes)
+    }
+}
+
+/// The crate that provides the `zero_copy_send` module, `::iceoryx2` unless it is overridden
+/// with `#[zero_copy_send(crate = path)]`.
+fn zero_copy_send_crate(input: &DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let mut crate_path = quote!(::iceoryx2);
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("zero_copy_send"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                let path: syn::Path = meta.value()?.parse()?;
+                crate_path = path.to_token_stream();
+                Ok(())
+            } else {
+                Err(meta.error("unsupported zero_copy_send attribute, expected `crate = path`"))
+            }
+        })?;
+    }
+
+    Ok(crate_path)
+}
+
+const PRIMITIVE_REPRESENTATIONS: [&str; 12] = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// The parts of the `#[repr(...)]` attributes that define the memory layout.
+struct Representation {
+    has_defined_layout: bool,
+    is_c: bool,
+    primitive: Option<Ident>,
+}
+
+impl Representation {
+    fn parse(input: &DeriveInput, is_enum: bool) -> Result<Self, Error> {
+        let mut repr = Self {
+            has_defined_layout: false,
+            is_c: false,
+            primitive: None,
+        };
+
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("C") {
+                    repr.has_defined_layout = true;
+                    repr.is_c = true;
+                }
+
+                if meta.path.is_ident("transparent") {
+                    repr.has_defined_layout = true;
+                }
+
+                if is_enum {
+                    if let Some(primitive) = PRIMITIVE_REPRESENTATIONS
+                        .iter()
+                        .find(|primitive| meta.path.is_ident(primitive))
+                    {
+                        repr.has_defined_layout = true;
+                        repr.primitive = Some(Ident::new(primitive, meta.path.span()));
+                    }
+                }
+
+                // arguments like the one of align(8) or packed(2) do not affect the field order
+                if meta.input.peek(syn::token::Paren) {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    content.parse::<proc_macro2::TokenStream>()?;
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(repr)
+    }
+}
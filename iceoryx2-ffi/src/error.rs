@@ -0,0 +1,259 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::*;
+use iceoryx2::service::service_name::ServiceNameError;
+
+/// The result of every function of the C API. Besides the generic failures of the C API every
+/// variant of the Rust error enums has its own error code, the code
+/// `IOX2_ERROR_<OPERATION>_<VARIANT>` corresponds to the Rust error `<Error>::<Variant>`.
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+pub enum iox2_error_e {
+    IOX2_OK = 0,
+    /// A pointer argument was null.
+    IOX2_ERROR_NULL_POINTER,
+    /// The handle does not exist, was already released or belongs to another thread.
+    IOX2_ERROR_INVALID_HANDLE,
+    /// The handle cannot be released since other handles still refer to it, e.g. a service
+    /// that has publishers or a subscriber with received samples.
+    IOX2_ERROR_HANDLE_IN_USE,
+    /// The service name is not valid UTF-8 or not a valid [`ServiceName`].
+    IOX2_ERROR_INVALID_SERVICE_NAME,
+    /// A failure that is not covered by any other error code.
+    IOX2_ERROR_INTERNAL,
+
+    // PublishSubscribeCreateError
+    IOX2_ERROR_SERVICE_CREATE_CORRUPTED,
+    IOX2_ERROR_SERVICE_CREATE_SUBSCRIBER_BUFFER_MUST_BE_LARGER_THAN_HISTORY_SIZE,
+    IOX2_ERROR_SERVICE_CREATE_ALREADY_EXISTS,
+    IOX2_ERROR_SERVICE_CREATE_PERMISSION_DENIED,
+    IOX2_ERROR_SERVICE_CREATE_INTERNAL_FAILURE,
+    IOX2_ERROR_SERVICE_CREATE_IS_BEING_CREATED_BY_ANOTHER_INSTANCE,
+    IOX2_ERROR_SERVICE_CREATE_UNABLE_TO_CREATE_STATIC_SERVICE_INFORMATION,
+    IOX2_ERROR_SERVICE_CREATE_INVALID_PAYLOAD_ALIGNMENT,
+
+    // PublishSubscribeOpenError
+    IOX2_ERROR_SERVICE_OPEN_DOES_NOT_EXIST,
+    IOX2_ERROR_SERVICE_OPEN_INTERNAL_FAILURE,
+    IOX2_ERROR_SERVICE_OPEN_INCOMPATIBLE_TYPES,
+    IOX2_ERROR_SERVICE_OPEN_INCOMPATIBLE_MESSAGING_PATTERN,
+    /// The incompatible settings are provided by the log only.
+    IOX2_ERROR_SERVICE_OPEN_INCOMPATIBLE_SETTINGS,
+    IOX2_ERROR_SERVICE_OPEN_INCOMPATIBLE_ATTRIBUTES,
+    IOX2_ERROR_SERVICE_OPEN_INACCESSIBLE,
+    IOX2_ERROR_SERVICE_OPEN_PERMISSION_DENIED,
+    IOX2_ERROR_SERVICE_OPEN_SERVICE_IN_CORRUPTED_STATE,
+    IOX2_ERROR_SERVICE_OPEN_HANGS_IN_CREATION,
+    IOX2_ERROR_SERVICE_OPEN_UNABLE_TO_OPEN_DYNAMIC_SERVICE_INFORMATION,
+
+    // PublisherCreateError
+    IOX2_ERROR_PUBLISHER_CREATE_EXCEEDS_MAX_SUPPORTED_PUBLISHERS,
+    IOX2_ERROR_PUBLISHER_CREATE_UNABLE_TO_CREATE_DATA_SEGMENT,
+    IOX2_ERROR_PUBLISHER_CREATE_INVALID_PAYLOAD_TYPE_DETAILS,
+    IOX2_ERROR_PUBLISHER_CREATE_INCOMPATIBLE_UNABLE_TO_DELIVER_STRATEGY,
+    IOX2_ERROR_PUBLISHER_CREATE_INCOMPATIBLE_ALLOCATION_STRATEGY,
+    IOX2_ERROR_PUBLISHER_CREATE_UNABLE_TO_CREATE_NOTIFICATION_LISTENER,
+    IOX2_ERROR_PUBLISHER_CREATE_UNABLE_TO_LOCK_MEMORY,
+    IOX2_ERROR_PUBLISHER_CREATE_HUGE_PAGES_UNAVAILABLE,
+
+    // PublisherLoanError
+    IOX2_ERROR_LOAN_OUT_OF_MEMORY,
+    IOX2_ERROR_LOAN_EXCEEDS_MAX_LOANED_SAMPLES,
+    IOX2_ERROR_LOAN_EXCEEDS_MAX_LOAN_SIZE,
+    IOX2_ERROR_LOAN_PAYLOAD_SIZE_IS_NOT_A_MULTIPLE_OF_TYPE_SIZE,
+    IOX2_ERROR_LOAN_INTERNAL_FAILURE,
+
+    // SubscriberCreateError
+    IOX2_ERROR_SUBSCRIBER_CREATE_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS,
+    IOX2_ERROR_SUBSCRIBER_CREATE_UNABLE_TO_CREATE_NOTIFICATION_LISTENER,
+    IOX2_ERROR_SUBSCRIBER_CREATE_INVALID_PAYLOAD_TYPE_DETAILS,
+
+    // PublisherSendError, a PublisherSendError::LoanError is reported by the LOAN error codes
+    IOX2_ERROR_SEND_CONNECTION_ERROR,
+    IOX2_ERROR_SEND_PARTIAL_DELIVERY,
+
+    // SubscriberReceiveError
+    IOX2_ERROR_RECEIVE_EXCEEDS_MAX_BORROWED_SAMPLES,
+    IOX2_ERROR_RECEIVE_CONNECTION_FAILURE,
+    IOX2_ERROR_RECEIVE_CORRUPTED_SAMPLE,
+    IOX2_ERROR_RECEIVE_INCOMPATIBLE_HEADER_VERSION,
+}
+
+impl From<ServiceNameError> for iox2_error_e {
+    fn from(_: ServiceNameError) -> Self {
+        iox2_error_e::IOX2_ERROR_INVALID_SERVICE_NAME
+    }
+}
+
+impl From<PublishSubscribeCreateError> for iox2_error_e {
+    fn from(value: PublishSubscribeCreateError) -> Self {
+        match value {
+            PublishSubscribeCreateError::Corrupted => iox2_error_e::IOX2_ERROR_SERVICE_CREATE_CORRUPTED,
+            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize => iox2_error_e::IOX2_ERROR_SERVICE_CREATE_SUBSCRIBER_BUFFER_MUST_BE_LARGER_THAN_HISTORY_SIZE,
+            PublishSubscribeCreateError::AlreadyExists => iox2_error_e::IOX2_ERROR_SERVICE_CREATE_ALREADY_EXISTS,
+            PublishSubscribeCreateError::PermissionDenied => iox2_error_e::IOX2_ERROR_SERVICE_CREATE_PERMISSION_DENIED,
+            PublishSubscribeCreateError::InternalFailure => iox2_error_e::IOX2_ERROR_SERVICE_CREATE_INTERNAL_FAILURE,
+            PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance => iox2_error_e::IOX2_ERROR_SERVICE_CREATE_IS_BEING_CREATED_BY_ANOTHER_INSTANCE,
+            PublishSubscribeCreateError::UnableToCreateStaticServiceInformation => iox2_error_e::IOX2_ERROR_SERVICE_CREATE_UNABLE_TO_CREATE_STATIC_SERVICE_INFORMATION,
+            PublishSubscribeCreateError::InvalidPayloadAlignment => iox2_error_e::IOX2_ERROR_SERVICE_CREATE_INVALID_PAYLOAD_ALIGNMENT,
+            _ => iox2_error_e::IOX2_ERROR_INTERNAL,
+        }
+    }
+}
+
+impl From<PublishSubscribeOpenError> for iox2_error_e {
+    fn from(value: PublishSubscribeOpenError) -> Self {
+        match value {
+            PublishSubscribeOpenError::DoesNotExist => {
+                iox2_error_e::IOX2_ERROR_SERVICE_OPEN_DOES_NOT_EXIST
+            }
+            PublishSubscribeOpenError::InternalFailure => {
+                iox2_error_e::IOX2_ERROR_SERVICE_OPEN_INTERNAL_FAILURE
+            }
+            PublishSubscribeOpenError::IncompatibleTypes { .. } => {
+                iox2_error_e::IOX2_ERROR_SERVICE_OPEN_INCOMPATIBLE_TYPES
+            }
+            PublishSubscribeOpenError::IncompatibleMessagingPattern => {
+                iox2_error_e::IOX2_ERROR_SERVICE_OPEN_INCOMPATIBLE_MESSAGING_PATTERN
+            }
+            PublishSubscribeOpenError::IncompatibleSettings(_) => {
+                iox2_error_e::IOX2_ERROR_SERVICE_OPEN_INCOMPATIBLE_SETTINGS
+            }
+            PublishSubscribeOpenError::IncompatibleAttributes(_) => {
+                iox2_error_e::IOX2_ERROR_SERVICE_OPEN_INCOMPATIBLE_ATTRIBUTES
+            }
+            PublishSubscribeOpenError::Inaccessible => {
+                iox2_error_e::IOX2_ERROR_SERVICE_OPEN_INACCESSIBLE
+            }
+            PublishSubscribeOpenError::PermissionDenied => {
+                iox2_error_e::IOX2_ERROR_SERVICE_OPEN_PERMISSION_DENIED
+            }
+            PublishSubscribeOpenError::ServiceInCorruptedState => {
+                iox2_error_e::IOX2_ERROR_SERVICE_OPEN_SERVICE_IN_CORRUPTED_STATE
+            }
+            PublishSubscribeOpenError::HangsInCreation => {
+                iox2_error_e::IOX2_ERROR_SERVICE_OPEN_HANGS_IN_CREATION
+            }
+            PublishSubscribeOpenError::UnableToOpenDynamicServiceInformation => {
+                iox2_error_e::IOX2_ERROR_SERVICE_OPEN_UNABLE_TO_OPEN_DYNAMIC_SERVICE_INFORMATION
+            }
+            _ => iox2_error_e::IOX2_ERROR_INTERNAL,
+        }
+    }
+}
+
+impl From<PublisherCreateError> for iox2_error_e {
+    fn from(value: PublisherCreateError) -> Self {
+        match value {
+            PublisherCreateError::ExceedsMaxSupportedPublishers => {
+                iox2_error_e::IOX2_ERROR_PUBLISHER_CREATE_EXCEEDS_MAX_SUPPORTED_PUBLISHERS
+            }
+            PublisherCreateError::UnableToCreateDataSegment => {
+                iox2_error_e::IOX2_ERROR_PUBLISHER_CREATE_UNABLE_TO_CREATE_DATA_SEGMENT
+            }
+            PublisherCreateError::InvalidPayloadTypeDetails => {
+                iox2_error_e::IOX2_ERROR_PUBLISHER_CREATE_INVALID_PAYLOAD_TYPE_DETAILS
+            }
+            PublisherCreateError::IncompatibleUnableToDeliverStrategy => {
+                iox2_error_e::IOX2_ERROR_PUBLISHER_CREATE_INCOMPATIBLE_UNABLE_TO_DELIVER_STRATEGY
+            }
+            PublisherCreateError::IncompatibleAllocationStrategy => {
+                iox2_error_e::IOX2_ERROR_PUBLISHER_CREATE_INCOMPATIBLE_ALLOCATION_STRATEGY
+            }
+            PublisherCreateError::UnableToCreateNotificationListener => {
+                iox2_error_e::IOX2_ERROR_PUBLISHER_CREATE_UNABLE_TO_CREATE_NOTIFICATION_LISTENER
+            }
+            PublisherCreateError::UnableToLockMemory => {
+                iox2_error_e::IOX2_ERROR_PUBLISHER_CREATE_UNABLE_TO_LOCK_MEMORY
+            }
+            PublisherCreateError::HugePagesUnavailable => {
+                iox2_error_e::IOX2_ERROR_PUBLISHER_CREATE_HUGE_PAGES_UNAVAILABLE
+            }
+            _ => iox2_error_e::IOX2_ERROR_INTERNAL,
+        }
+    }
+}
+
+impl From<PublisherLoanError> for iox2_error_e {
+    fn from(value: PublisherLoanError) -> Self {
+        match value {
+            PublisherLoanError::OutOfMemory | PublisherLoanError::BucketExhausted { .. } => {
+                iox2_error_e::IOX2_ERROR_LOAN_OUT_OF_MEMORY
+            }
+            PublisherLoanError::ExceedsMaxLoanedSamples => {
+                iox2_error_e::IOX2_ERROR_LOAN_EXCEEDS_MAX_LOANED_SAMPLES
+            }
+            PublisherLoanError::ExceedsMaxLoanSize => {
+                iox2_error_e::IOX2_ERROR_LOAN_EXCEEDS_MAX_LOAN_SIZE
+            }
+            PublisherLoanError::PayloadSizeIsNotAMultipleOfTypeSize => {
+                iox2_error_e::IOX2_ERROR_LOAN_PAYLOAD_SIZE_IS_NOT_A_MULTIPLE_OF_TYPE_SIZE
+            }
+            PublisherLoanError::InternalFailure => iox2_error_e::IOX2_ERROR_LOAN_INTERNAL_FAILURE,
+            _ => iox2_error_e::IOX2_ERROR_INTERNAL,
+        }
+    }
+}
+
+impl From<SubscriberCreateError> for iox2_error_e {
+    fn from(value: SubscriberCreateError) -> Self {
+        match value {
+            SubscriberCreateError::ExceedsMaxSupportedSubscribers => {
+                iox2_error_e::IOX2_ERROR_SUBSCRIBER_CREATE_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS
+            }
+            SubscriberCreateError::UnableToCreateNotificationListener => {
+                iox2_error_e::IOX2_ERROR_SUBSCRIBER_CREATE_UNABLE_TO_CREATE_NOTIFICATION_LISTENER
+            }
+            SubscriberCreateError::InvalidPayloadTypeDetails => {
+                iox2_error_e::IOX2_ERROR_SUBSCRIBER_CREATE_INVALID_PAYLOAD_TYPE_DETAILS
+            }
+            _ => iox2_error_e::IOX2_ERROR_INTERNAL,
+        }
+    }
+}
+
+impl From<PublisherSendError> for iox2_error_e {
+    fn from(value: PublisherSendError) -> Self {
+        match value {
+            PublisherSendError::LoanError(e) => e.into(),
+            PublisherSendError::ConnectionError(_) => {
+                iox2_error_e::IOX2_ERROR_SEND_CONNECTION_ERROR
+            }
+            PublisherSendError::PartialDelivery { .. } => {
+                iox2_error_e::IOX2_ERROR_SEND_PARTIAL_DELIVERY
+            }
+            _ => iox2_error_e::IOX2_ERROR_INTERNAL,
+        }
+    }
+}
+
+impl From<SubscriberReceiveError> for iox2_error_e {
+    fn from(value: SubscriberReceiveError) -> Self {
+        match value {
+            SubscriberReceiveError::ExceedsMaxBorrowedSamples => {
+                iox2_error_e::IOX2_ERROR_RECEIVE_EXCEEDS_MAX_BORROWED_SAMPLES
+            }
+            SubscriberReceiveError::ConnectionFailure(_) => {
+                iox2_error_e::IOX2_ERROR_RECEIVE_CONNECTION_FAILURE
+            }
+            SubscriberReceiveError::CorruptedSample => {
+                iox2_error_e::IOX2_ERROR_RECEIVE_CORRUPTED_SAMPLE
+            }
+            SubscriberReceiveError::IncompatibleHeaderVersion => {
+                iox2_error_e::IOX2_ERROR_RECEIVE_INCOMPATIBLE_HEADER_VERSION
+            }
+            _ => iox2_error_e::IOX2_ERROR_INTERNAL,
+        }
+    }
+}
@@ -0,0 +1,488 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! C bindings for the
+//! [`MessagingPattern::PublishSubscribe`](iceoryx2::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+//! of iceoryx2. The build script generates the header `iceoryx2.h` with cbindgen into the
+//! `OUT_DIR` of the crate, the library is built as static and as shared library.
+//!
+//! The bindings use the [`iceoryx2::service::zero_copy::Service`] and transfer the payload as
+//! bytes, services that are created with the C API have a payload of `[u8]` and can be opened
+//! by services with any payload type, see
+//! [`iceoryx2::service::builder::publish_subscribe::Builder::open_untyped()`].
+//!
+//! # Handles
+//!
+//! Every object is referred to by an opaque handle, [`IOX2_INVALID_HANDLE`] never refers to an
+//! object. A handle must be released with the corresponding release function, releasing an
+//! object that is still used by other objects fails with
+//! [`iox2_error_e::IOX2_ERROR_HANDLE_IN_USE`], e.g. a service that still has publishers. Handles
+//! that were already released or never existed are rejected with
+//! [`iox2_error_e::IOX2_ERROR_INVALID_HANDLE`], a double release is therefore harmless.
+//!
+//! The ports are not thread-safe, a handle can only be used in the thread that created it.
+//! The objects of a thread that were not released are released when the thread exits.
+//!
+//! # Example
+//!
+//! ```c
+//! iox2_service_h service;
+//! iox2_publisher_h publisher;
+//! iox2_sample_mut_h sample;
+//! void* payload;
+//! size_t payload_len;
+//!
+//! iox2_service_create("My/Funk/ServiceName", 64, &service);
+//! iox2_publisher_create(service, &publisher);
+//!
+//! iox2_publisher_loan(publisher, 8, &sample);
+//! iox2_sample_mut_payload(sample, &payload, &payload_len);
+//! memcpy(payload, "a sample", 8);
+//! iox2_sample_mut_send(sample, NULL);
+//!
+//! iox2_publisher_release(publisher);
+//! iox2_service_release(service);
+//! ```
+
+#![allow(non_camel_case_types)]
+
+use std::ffi::{c_char, c_void, CStr};
+
+use iceoryx2::prelude::*;
+use iceoryx2::service::header::publish_subscribe::ClockType;
+
+mod error;
+mod registry;
+
+pub use error::iox2_error_e;
+use registry::*;
+
+/// An opaque handle to an object of the C API.
+pub type iox2_handle_t = u64;
+/// The handle of a service, see [`iox2_service_create()`] and [`iox2_service_open()`].
+pub type iox2_service_h = iox2_handle_t;
+/// The handle of a publisher, see [`iox2_publisher_create()`].
+pub type iox2_publisher_h = iox2_handle_t;
+/// The handle of a subscriber, see [`iox2_subscriber_create()`].
+pub type iox2_subscriber_h = iox2_handle_t;
+/// The handle of a loaned sample, see [`iox2_publisher_loan()`].
+pub type iox2_sample_mut_h = iox2_handle_t;
+/// The handle of a received sample, see [`iox2_subscriber_receive()`].
+pub type iox2_sample_h = iox2_handle_t;
+
+/// Is never the handle of an object.
+pub const IOX2_INVALID_HANDLE: iox2_handle_t = 0;
+
+/// The clock of the time stamp of a sample, see [`ClockType`].
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum iox2_clock_type_e {
+    IOX2_CLOCK_TYPE_REALTIME = 0,
+    IOX2_CLOCK_TYPE_MONOTONIC = 1,
+}
+
+/// The header of a received sample, see
+/// [`iceoryx2::service::header::publish_subscribe::Header`].
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct iox2_header_t {
+    /// The id of the publisher that sent the sample in big-endian byte order.
+    pub publisher_id: [u8; 16],
+    pub time_stamp_seconds: u64,
+    pub time_stamp_nanoseconds: u32,
+    pub clock_type: iox2_clock_type_e,
+    pub number_of_elements: u64,
+    pub sequence_number: u64,
+}
+
+macro_rules! check_not_null {
+    ($($ptr:expr),*) => {
+        if $($ptr.is_null())||* {
+            return iox2_error_e::IOX2_ERROR_NULL_POINTER;
+        }
+    };
+}
+
+unsafe fn to_service_name(name: *const c_char) -> Result<ServiceName, iox2_error_e> {
+    let name = CStr::from_ptr(name)
+        .to_str()
+        .map_err(|_| iox2_error_e::IOX2_ERROR_INVALID_SERVICE_NAME)?;
+    Ok(ServiceName::new(name)?)
+}
+
+/// Creates a new service with a payload of up to `max_slice_len` bytes per sample and stores
+/// its handle in `service_handle`.
+///
+/// # Safety
+///
+///  * `service_name` must be a null-terminated string
+///  * `service_handle` must point to writable memory
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_create(
+    service_name: *const c_char,
+    max_slice_len: usize,
+    service_handle: *mut iox2_service_h,
+) -> iox2_error_e {
+    check_not_null!(service_name, service_handle);
+
+    let name = match to_service_name(service_name) {
+        Ok(name) => name,
+        Err(e) => return e,
+    };
+
+    match zero_copy::Service::new(&name)
+        .publish_subscribe()
+        .max_slice_len(max_slice_len)
+        .create::<[u8]>()
+    {
+        Ok(service) => {
+            *service_handle = with_registry(|r| insert(&mut r.services, service));
+            iox2_error_e::IOX2_OK
+        }
+        Err(e) => e.into(),
+    }
+}
+
+/// Opens an existing service with any payload type and stores its handle in
+/// `service_handle`.
+///
+/// # Safety
+///
+///  * `service_name` must be a null-terminated string
+///  * `service_handle` must point to writable memory
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_open(
+    service_name: *const c_char,
+    service_handle: *mut iox2_service_h,
+) -> iox2_error_e {
+    check_not_null!(service_name, service_handle);
+
+    let name = match to_service_name(service_name) {
+        Ok(name) => name,
+        Err(e) => return e,
+    };
+
+    match zero_copy::Service::new(&name)
+        .publish_subscribe()
+        .open_untyped()
+    {
+        Ok(service) => {
+            *service_handle = with_registry(|r| insert(&mut r.services, service));
+            iox2_error_e::IOX2_OK
+        }
+        Err(e) => e.into(),
+    }
+}
+
+/// Releases a service, all of its publishers and subscribers must be released before.
+#[no_mangle]
+pub extern "C" fn iox2_service_release(service_handle: iox2_service_h) -> iox2_error_e {
+    match with_registry(|r| take(&mut r.services, service_handle)) {
+        Ok(_) => iox2_error_e::IOX2_OK,
+        Err(e) => e,
+    }
+}
+
+/// Creates a new publisher for a service and stores its handle in `publisher_handle`.
+///
+/// # Safety
+///
+///  * `publisher_handle` must point to writable memory
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publisher_create(
+    service_handle: iox2_service_h,
+    publisher_handle: *mut iox2_publisher_h,
+) -> iox2_error_e {
+    check_not_null!(publisher_handle);
+
+    with_registry(|r| {
+        let service = match r.services.get(&service_handle) {
+            Some(entry) => entry.get(),
+            None => return iox2_error_e::IOX2_ERROR_INVALID_HANDLE,
+        };
+
+        match service.publisher().create_untyped() {
+            Ok(publisher) => {
+                *publisher_handle = insert_dependent(
+                    &mut r.publishers,
+                    &mut r.services,
+                    service_handle,
+                    publisher,
+                );
+                iox2_error_e::IOX2_OK
+            }
+            Err(e) => e.into(),
+        }
+    })
+}
+
+/// Releases a publisher, all of its loaned samples must be sent or released before.
+#[no_mangle]
+pub extern "C" fn iox2_publisher_release(publisher_handle: iox2_publisher_h) -> iox2_error_e {
+    match with_registry(|r| take_dependent(&mut r.publishers, &mut r.services, publisher_handle)) {
+        Ok(_) => iox2_error_e::IOX2_OK,
+        Err(e) => e,
+    }
+}
+
+/// Loans a sample with a payload of `number_of_bytes` uninitialized bytes and stores its
+/// handle in `sample_handle`. The number of bytes must be a multiple of the size of the
+/// payload type of the service.
+///
+/// # Safety
+///
+///  * `sample_handle` must point to writable memory
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publisher_loan(
+    publisher_handle: iox2_publisher_h,
+    number_of_bytes: usize,
+    sample_handle: *mut iox2_sample_mut_h,
+) -> iox2_error_e {
+    check_not_null!(sample_handle);
+
+    with_registry(|r| {
+        let publisher = match r.publishers.get(&publisher_handle) {
+            Some(entry) => entry.get(),
+            None => return iox2_error_e::IOX2_ERROR_INVALID_HANDLE,
+        };
+
+        match publisher.loan_slice_uninit(number_of_bytes) {
+            Ok(sample) => {
+                *sample_handle = insert_dependent(
+                    &mut r.samples_mut,
+                    &mut r.publishers,
+                    publisher_handle,
+                    sample,
+                );
+                iox2_error_e::IOX2_OK
+            }
+            Err(e) => e.into(),
+        }
+    })
+}
+
+/// Stores the address and the size of the payload of a loaned sample in `payload` and
+/// `payload_len`. The payload is valid until the sample is sent or released.
+///
+/// # Safety
+///
+///  * `payload` and `payload_len` must point to writable memory
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_mut_payload(
+    sample_handle: iox2_sample_mut_h,
+    payload: *mut *mut c_void,
+    payload_len: *mut usize,
+) -> iox2_error_e {
+    check_not_null!(payload, payload_len);
+
+    with_registry(|r| match r.samples_mut.get_mut(&sample_handle) {
+        Some(entry) => {
+            let sample = entry.get_mut().payload_mut();
+            *payload = sample.as_mut_ptr() as *mut c_void;
+            *payload_len = sample.len();
+            iox2_error_e::IOX2_OK
+        }
+        None => iox2_error_e::IOX2_ERROR_INVALID_HANDLE,
+    })
+}
+
+/// Sends a loaned sample and releases its handle, also when the send fails. When
+/// `number_of_recipients` is not null the number of subscribers that received the sample is
+/// stored in it.
+///
+/// # Safety
+///
+///  * the whole payload must be initialized, see [`iox2_sample_mut_payload()`]
+///  * `number_of_recipients` must be null or point to writable memory
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_mut_send(
+    sample_handle: iox2_sample_mut_h,
+    number_of_recipients: *mut usize,
+) -> iox2_error_e {
+    let sample = match with_registry(|r| {
+        take_dependent(&mut r.samples_mut, &mut r.publishers, sample_handle)
+    }) {
+        Ok(sample) => sample,
+        Err(e) => return e,
+    };
+
+    match sample.assume_init().send() {
+        Ok(n) => {
+            if !number_of_recipients.is_null() {
+                *number_of_recipients = n;
+            }
+            iox2_error_e::IOX2_OK
+        }
+        Err(e) => e.into(),
+    }
+}
+
+/// Returns a loaned sample to the publisher without sending it.
+#[no_mangle]
+pub extern "C" fn iox2_sample_mut_release(sample_handle: iox2_sample_mut_h) -> iox2_error_e {
+    match with_registry(|r| take_dependent(&mut r.samples_mut, &mut r.publishers, sample_handle)) {
+        Ok(sample) => {
+            sample.cancel();
+            iox2_error_e::IOX2_OK
+        }
+        Err(e) => e,
+    }
+}
+
+/// Creates a new subscriber for a service and stores its handle in `subscriber_handle`.
+///
+/// # Safety
+///
+///  * `subscriber_handle` must point to writable memory
+#[no_mangle]
+pub unsafe extern "C" fn iox2_subscriber_create(
+    service_handle: iox2_service_h,
+    subscriber_handle: *mut iox2_subscriber_h,
+) -> iox2_error_e {
+    check_not_null!(subscriber_handle);
+
+    with_registry(|r| {
+        let service = match r.services.get(&service_handle) {
+            Some(entry) => entry.get(),
+            None => return iox2_error_e::IOX2_ERROR_INVALID_HANDLE,
+        };
+
+        match service.subscriber().create_untyped() {
+            Ok(subscriber) => {
+                *subscriber_handle = insert_dependent(
+                    &mut r.subscribers,
+                    &mut r.services,
+                    service_handle,
+                    subscriber,
+                );
+                iox2_error_e::IOX2_OK
+            }
+            Err(e) => e.into(),
+        }
+    })
+}
+
+/// Releases a subscriber, all of its received samples must be released before.
+#[no_mangle]
+pub extern "C" fn iox2_subscriber_release(subscriber_handle: iox2_subscriber_h) -> iox2_error_e {
+    match with_registry(|r| take_dependent(&mut r.subscribers, &mut r.services, subscriber_handle))
+    {
+        Ok(_) => iox2_error_e::IOX2_OK,
+        Err(e) => e,
+    }
+}
+
+/// Receives a sample and stores its handle in `sample_handle`. When no sample is available
+/// [`IOX2_INVALID_HANDLE`] is stored.
+///
+/// # Safety
+///
+///  * `sample_handle` must point to writable memory
+#[no_mangle]
+pub unsafe extern "C" fn iox2_subscriber_receive(
+    subscriber_handle: iox2_subscriber_h,
+    sample_handle: *mut iox2_sample_h,
+) -> iox2_error_e {
+    check_not_null!(sample_handle);
+
+    with_registry(|r| {
+        let subscriber = match r.subscribers.get(&subscriber_handle) {
+            Some(entry) => entry.get(),
+            None => return iox2_error_e::IOX2_ERROR_INVALID_HANDLE,
+        };
+
+        match subscriber.receive() {
+            Ok(Some(sample)) => {
+                *sample_handle = insert_dependent(
+                    &mut r.samples,
+                    &mut r.subscribers,
+                    subscriber_handle,
+                    sample,
+                );
+                iox2_error_e::IOX2_OK
+            }
+            Ok(None) => {
+                *sample_handle = IOX2_INVALID_HANDLE;
+                iox2_error_e::IOX2_OK
+            }
+            Err(e) => e.into(),
+        }
+    })
+}
+
+/// Stores the address and the size of the payload of a received sample in `payload` and
+/// `payload_len`. The payload is valid until the sample is released.
+///
+/// # Safety
+///
+///  * `payload` and `payload_len` must point to writable memory
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_payload(
+    sample_handle: iox2_sample_h,
+    payload: *mut *const c_void,
+    payload_len: *mut usize,
+) -> iox2_error_e {
+    check_not_null!(payload, payload_len);
+
+    with_registry(|r| match r.samples.get(&sample_handle) {
+        Some(entry) => {
+            let sample = entry.get().payload();
+            *payload = sample.as_ptr() as *const c_void;
+            *payload_len = sample.len();
+            iox2_error_e::IOX2_OK
+        }
+        None => iox2_error_e::IOX2_ERROR_INVALID_HANDLE,
+    })
+}
+
+/// Copies the header of a received sample into `header`.
+///
+/// # Safety
+///
+///  * `header` must point to writable memory
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_header(
+    sample_handle: iox2_sample_h,
+    header: *mut iox2_header_t,
+) -> iox2_error_e {
+    check_not_null!(header);
+
+    with_registry(|r| match r.samples.get(&sample_handle) {
+        Some(entry) => {
+            let sample_header = entry.get().header();
+            let time_stamp = sample_header.time_stamp();
+            *header = iox2_header_t {
+                publisher_id: sample_header.publisher_id().to_bytes(),
+                time_stamp_seconds: time_stamp.seconds(),
+                time_stamp_nanoseconds: time_stamp.nanoseconds(),
+                clock_type: match sample_header.clock_type() {
+                    ClockType::Realtime => iox2_clock_type_e::IOX2_CLOCK_TYPE_REALTIME,
+                    ClockType::Monotonic => iox2_clock_type_e::IOX2_CLOCK_TYPE_MONOTONIC,
+                },
+                number_of_elements: sample_header.number_of_elements(),
+                sequence_number: sample_header.sequence_number(),
+            };
+            iox2_error_e::IOX2_OK
+        }
+        None => iox2_error_e::IOX2_ERROR_INVALID_HANDLE,
+    })
+}
+
+/// Releases a received sample, its payload must not be accessed afterwards.
+#[no_mangle]
+pub extern "C" fn iox2_sample_release(sample_handle: iox2_sample_h) -> iox2_error_e {
+    match with_registry(|r| take_dependent(&mut r.samples, &mut r.subscribers, sample_handle)) {
+        Ok(_) => iox2_error_e::IOX2_OK,
+        Err(e) => e,
+    }
+}
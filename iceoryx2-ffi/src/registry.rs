@@ -0,0 +1,164 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The objects behind the handles of the C API. The ports and samples borrow the object they
+//! were created from, the registry keeps every object at a stable heap address and refuses to
+//! release an object as long as another object refers to it. The ports are not [`Send`],
+//! therefore every thread has its own registry and a handle is valid only in the thread that
+//! created it.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use iceoryx2::port::publisher::Publisher;
+use iceoryx2::port::subscriber::Subscriber;
+use iceoryx2::prelude::*;
+use iceoryx2::sample::Sample;
+use iceoryx2::sample_mut::SampleMut;
+use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
+
+use crate::error::iox2_error_e;
+use crate::{iox2_handle_t, IOX2_INVALID_HANDLE};
+
+pub(crate) type ServiceType = zero_copy::Service<'static>;
+pub(crate) type ServiceEntry = PortFactory<'static, ServiceType, [u8]>;
+pub(crate) type PublisherEntry = Publisher<'static, 'static, ServiceType, [u8]>;
+pub(crate) type SubscriberEntry = Subscriber<'static, 'static, ServiceType, [u8]>;
+pub(crate) type SampleMutEntry = SampleMut<'static, [MaybeUninit<u8>]>;
+pub(crate) type SampleEntry = Sample<'static, [u8]>;
+
+// handles are unique across all threads and object kinds, a handle of another thread or of
+// another kind of object is never mistaken for a valid one
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(IOX2_INVALID_HANDLE + 1);
+
+pub(crate) struct Entry<T> {
+    value: NonNull<T>,
+    owner: iox2_handle_t,
+    number_of_dependents: usize,
+}
+
+impl<T> Entry<T> {
+    fn new(value: T, owner: iox2_handle_t) -> Self {
+        Self {
+            value: NonNull::from(Box::leak(Box::new(value))),
+            owner,
+            number_of_dependents: 0,
+        }
+    }
+
+    /// # Safety
+    ///
+    ///  * the reference must not be used after the entry was removed from the [`Registry`]
+    pub(crate) unsafe fn get(&self) -> &'static T {
+        &*self.value.as_ptr()
+    }
+
+    /// # Safety
+    ///
+    ///  * the reference must not be used after the entry was removed from the [`Registry`]
+    pub(crate) unsafe fn get_mut(&mut self) -> &'static mut T {
+        &mut *self.value.as_ptr()
+    }
+
+    fn into_inner(self) -> T {
+        let this = ManuallyDrop::new(self);
+        *unsafe { Box::from_raw(this.value.as_ptr()) }
+    }
+}
+
+impl<T> Drop for Entry<T> {
+    fn drop(&mut self) {
+        drop(unsafe { Box::from_raw(self.value.as_ptr()) });
+    }
+}
+
+pub(crate) type Entries<T> = HashMap<iox2_handle_t, Entry<T>>;
+
+#[derive(Default)]
+pub(crate) struct Registry {
+    // the fields are dropped in declaration order when the thread exits, the samples borrow
+    // the ports and the ports borrow the services
+    pub(crate) samples_mut: Entries<SampleMutEntry>,
+    pub(crate) samples: Entries<SampleEntry>,
+    pub(crate) publishers: Entries<PublisherEntry>,
+    pub(crate) subscribers: Entries<SubscriberEntry>,
+    pub(crate) services: Entries<ServiceEntry>,
+}
+
+thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::new(Registry::default());
+}
+
+pub(crate) fn with_registry<R, F: FnOnce(&mut Registry) -> R>(f: F) -> R {
+    REGISTRY.with(|registry| f(&mut registry.borrow_mut()))
+}
+
+fn next_handle() -> iox2_handle_t {
+    NEXT_HANDLE.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Adds an object that does not borrow any other object.
+pub(crate) fn insert<T>(entries: &mut Entries<T>, value: T) -> iox2_handle_t {
+    let handle = next_handle();
+    entries.insert(handle, Entry::new(value, IOX2_INVALID_HANDLE));
+    handle
+}
+
+/// Adds an object that borrows the object of `owner`, the owner cannot be released before the
+/// returned handle.
+pub(crate) fn insert_dependent<T, O>(
+    entries: &mut Entries<T>,
+    owners: &mut Entries<O>,
+    owner: iox2_handle_t,
+    value: T,
+) -> iox2_handle_t {
+    if let Some(entry) = owners.get_mut(&owner) {
+        entry.number_of_dependents += 1;
+    }
+
+    let handle = next_handle();
+    entries.insert(handle, Entry::new(value, owner));
+    handle
+}
+
+fn remove<T>(entries: &mut Entries<T>, handle: iox2_handle_t) -> Result<Entry<T>, iox2_error_e> {
+    match entries.get(&handle) {
+        None => Err(iox2_error_e::IOX2_ERROR_INVALID_HANDLE),
+        Some(entry) if entry.number_of_dependents != 0 => {
+            Err(iox2_error_e::IOX2_ERROR_HANDLE_IN_USE)
+        }
+        Some(_) => Ok(entries.remove(&handle).unwrap()),
+    }
+}
+
+/// Removes the object of `handle` and returns it, fails when other objects still borrow it.
+pub(crate) fn take<T>(entries: &mut Entries<T>, handle: iox2_handle_t) -> Result<T, iox2_error_e> {
+    Ok(remove(entries, handle)?.into_inner())
+}
+
+/// Like [`take()`] but for objects that were added with [`insert_dependent()`], the owner can
+/// be released afterwards.
+pub(crate) fn take_dependent<T, O>(
+    entries: &mut Entries<T>,
+    owners: &mut Entries<O>,
+    handle: iox2_handle_t,
+) -> Result<T, iox2_error_e> {
+    let entry = remove(entries, handle)?;
+    if let Some(owner) = owners.get_mut(&entry.owner) {
+        owner.number_of_dependents -= 1;
+    }
+
+    Ok(entry.into_inner())
+}
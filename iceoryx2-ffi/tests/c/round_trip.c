@@ -0,0 +1,115 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "iceoryx2.h"
+
+#include <stdio.h>
+#include <string.h>
+
+#define EXPECT_RESULT(expr, expected)                                                              \
+    do {                                                                                           \
+        iox2_error_e result = (expr);                                                              \
+        if (result != (expected)) {                                                                \
+            fprintf(stderr, "%s:%d: %s returned %d instead of %d\n", __FILE__, __LINE__, #expr,    \
+                    (int)result, (int)(expected));                                                 \
+            return 1;                                                                              \
+        }                                                                                          \
+    } while (0)
+
+#define EXPECT_OK(expr) EXPECT_RESULT(expr, IOX2_OK)
+
+#define EXPECT_TRUE(condition)                                                                     \
+    do {                                                                                           \
+        if (!(condition)) {                                                                        \
+            fprintf(stderr, "%s:%d: %s is false\n", __FILE__, __LINE__, #condition);               \
+            return 1;                                                                              \
+        }                                                                                          \
+    } while (0)
+
+int main(int argc, char** argv) {
+    if (argc != 2) {
+        fprintf(stderr, "usage: %s SERVICE_NAME\n", argv[0]);
+        return 1;
+    }
+
+    const char message[] = "hello from C";
+    iox2_service_h service = IOX2_INVALID_HANDLE;
+    iox2_publisher_h publisher = IOX2_INVALID_HANDLE;
+    iox2_subscriber_h subscriber = IOX2_INVALID_HANDLE;
+    iox2_sample_mut_h sample_mut = IOX2_INVALID_HANDLE;
+    iox2_sample_h sample = IOX2_INVALID_HANDLE;
+    size_t number_of_recipients = 0;
+
+    EXPECT_OK(iox2_service_create(argv[1], 128, &service));
+    EXPECT_RESULT(iox2_service_create(argv[1], 128, &service), IOX2_ERROR_SERVICE_CREATE_ALREADY_EXISTS);
+    EXPECT_OK(iox2_publisher_create(service, &publisher));
+    EXPECT_OK(iox2_subscriber_create(service, &subscriber));
+    EXPECT_RESULT(iox2_publisher_create(service, NULL), IOX2_ERROR_NULL_POINTER);
+
+    // send
+    void* payload_mut = NULL;
+    size_t payload_mut_len = 0;
+    EXPECT_OK(iox2_publisher_loan(publisher, sizeof(message), &sample_mut));
+    EXPECT_OK(iox2_sample_mut_payload(sample_mut, &payload_mut, &payload_mut_len));
+    EXPECT_TRUE(payload_mut_len == sizeof(message));
+    memcpy(payload_mut, message, sizeof(message));
+    EXPECT_OK(iox2_sample_mut_send(sample_mut, &number_of_recipients));
+    EXPECT_TRUE(number_of_recipients == 1);
+    EXPECT_RESULT(iox2_sample_mut_send(sample_mut, NULL), IOX2_ERROR_INVALID_HANDLE);
+
+    // receive
+    const void* payload = NULL;
+    size_t payload_len = 0;
+    iox2_header_t header;
+    EXPECT_OK(iox2_subscriber_receive(subscriber, &sample));
+    EXPECT_TRUE(sample != IOX2_INVALID_HANDLE);
+    EXPECT_OK(iox2_sample_payload(sample, &payload, &payload_len));
+    EXPECT_TRUE(payload_len == sizeof(message));
+    EXPECT_TRUE(memcmp(payload, message, sizeof(message)) == 0);
+    EXPECT_OK(iox2_sample_header(sample, &header));
+    EXPECT_TRUE(header.number_of_elements == sizeof(message));
+    EXPECT_TRUE(header.sequence_number == 0);
+    EXPECT_TRUE(header.clock_type == IOX2_CLOCK_TYPE_REALTIME);
+    EXPECT_TRUE(header.time_stamp_seconds != 0);
+
+    // objects that are in use cannot be released
+    EXPECT_RESULT(iox2_subscriber_release(subscriber), IOX2_ERROR_HANDLE_IN_USE);
+    EXPECT_RESULT(iox2_service_release(service), IOX2_ERROR_HANDLE_IN_USE);
+
+    // a double release is reported
+    EXPECT_OK(iox2_sample_release(sample));
+    EXPECT_RESULT(iox2_sample_release(sample), IOX2_ERROR_INVALID_HANDLE);
+    EXPECT_RESULT(iox2_sample_payload(sample, &payload, &payload_len), IOX2_ERROR_INVALID_HANDLE);
+
+    EXPECT_OK(iox2_subscriber_receive(subscriber, &sample));
+    EXPECT_TRUE(sample == IOX2_INVALID_HANDLE);
+
+    EXPECT_OK(iox2_publisher_loan(publisher, 1, &sample_mut));
+    EXPECT_OK(iox2_sample_mut_release(sample_mut));
+    EXPECT_RESULT(iox2_sample_mut_release(sample_mut), IOX2_ERROR_INVALID_HANDLE);
+    EXPECT_RESULT(iox2_publisher_loan(publisher, 129, &sample_mut), IOX2_ERROR_LOAN_EXCEEDS_MAX_LOAN_SIZE);
+
+    // the service can be opened by other participants
+    iox2_service_h opened_service = IOX2_INVALID_HANDLE;
+    EXPECT_OK(iox2_service_open(argv[1], &opened_service));
+    EXPECT_OK(iox2_service_release(opened_service));
+
+    EXPECT_OK(iox2_subscriber_release(subscriber));
+    EXPECT_OK(iox2_publisher_release(publisher));
+    EXPECT_RESULT(iox2_publisher_release(publisher), IOX2_ERROR_INVALID_HANDLE);
+    EXPECT_OK(iox2_service_release(service));
+    EXPECT_RESULT(iox2_service_release(service), IOX2_ERROR_INVALID_HANDLE);
+
+    EXPECT_RESULT(iox2_service_open(argv[1], &service), IOX2_ERROR_SERVICE_OPEN_DOES_NOT_EXIST);
+
+    return 0;
+}
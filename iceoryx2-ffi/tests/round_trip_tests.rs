@@ -0,0 +1,78 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// The C program is compiled with the header that is generated by the build script and linked
+// against the static library of this crate, it reports the first failed check on stderr and
+// exits with a non-zero exit code.
+#[cfg(target_os = "linux")]
+mod round_trip {
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn target_dir() -> PathBuf {
+        // the test executable resides in target/<profile>/deps, the libraries in
+        // target/<profile>
+        std::env::current_exe()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .to_path_buf()
+    }
+
+    fn compile(source: &Path, executable: &Path) {
+        let compiler = cc::Build::new()
+            .cargo_metadata(false)
+            .opt_level(0)
+            .host(env!("IOX2_FFI_HOST"))
+            .target(env!("IOX2_FFI_TARGET"))
+            .get_compiler();
+
+        let status = compiler
+            .to_command()
+            .arg(format!("-I{}", env!("OUT_DIR")))
+            .arg(source)
+            .arg("-o")
+            .arg(executable)
+            .arg(target_dir().join("libiceoryx2_ffi.a"))
+            .args(["-lpthread", "-ldl", "-lm", "-lrt", "-lgcc_s"])
+            .status()
+            .unwrap();
+        assert_that!(status.success(), eq true);
+    }
+
+    #[test]
+    fn c_program_sends_and_receives_a_sample() {
+        let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/c/round_trip.c");
+        let executable = target_dir().join(format!(
+            "iceoryx2_ffi_round_trip_{}",
+            UniqueSystemId::new().unwrap().value()
+        ));
+        compile(&source, &executable);
+
+        let service_name = format!(
+            "iceoryx2_ffi_round_trip_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        );
+        let status = Command::new(&executable)
+            .arg(service_name)
+            .status()
+            .unwrap();
+        std::fs::remove_file(&executable).unwrap();
+
+        assert_that!(status.success(), eq true);
+    }
+}
@@ -48,6 +48,7 @@ pub const PROT_EXEC: int = 8;
 pub const MCL_CURRENT: int = 16;
 pub const MCL_FUTURE: int = 32;
 pub const MAP_SHARED: int = 64;
+pub const MADV_HUGEPAGE: int = 14;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
 pub const MQ_INVALID: mqd_t = mqd_t::MAX;
 
@@ -0,0 +1,160 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The low level building blocks of the publish-subscribe ports for advanced users that
+//! implement custom ports, for instance a reader that maps the data segment of a
+//! [`Publisher`](crate::port::publisher::Publisher) to take snapshots of its samples.
+//!
+//! **The module is not covered by semantic versioning.** It exposes the memory layout of the
+//! samples and the addressing scheme of the data segments, both can change with every release
+//! of iceoryx2. Ports of different releases are detected with
+//! [`Header::LAYOUT_VERSION`] in the header of every sample.
+//!
+//! # Locating A Sample
+//!
+//!  * A [`Publisher`](crate::port::publisher::Publisher) stores its samples in data segments,
+//!    the current one is identified by
+//!    [`Publisher::segment_id()`](crate::port::publisher::Publisher::segment_id()) and can be
+//!    mapped with [`DataSegmentView::open()`] and
+//!    [`Publisher::data_segment_name()`](crate::port::publisher::Publisher::data_segment_name()).
+//!  * A sample is addressed by the [`PointerOffset`] that is sent to the subscribers, see
+//!    [`offset_of()`]. The [`SegmentOffset`] converted from it contains the id of the data
+//!    segment and the position of the sample inside of it.
+//!  * The sample starts with the [`Header`], followed by the user header and the payload at
+//!    [`payload_offset()`], see [`Message`].
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::advanced::{self, DataSegmentView, Header, RawSample, SegmentOffset};
+//! use iceoryx2::config::Config;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("advanced_doc_example")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//! let publisher = service.publisher().create()?;
+//!
+//! let data_segment = DataSegmentView::<zero_copy::Service>::open(
+//!     Config::get_global_config(),
+//!     &publisher.data_segment_name(),
+//! )?;
+//!
+//! let sample = publisher.loan_uninit()?.write_payload(1234);
+//! let offset = SegmentOffset::from(advanced::offset_of(&sample));
+//! assert_eq!(offset.segment_id(), publisher.segment_id());
+//!
+//! let address = data_segment.address_of(offset).unwrap();
+//! let payload_offset =
+//!     advanced::payload_offset::<()>(service.static_config().payload_alignment());
+//! // SAFETY: the sample is initialized, stays mapped and is not modified while it is read
+//! let snapshot = unsafe {
+//!     RawSample::<Header, (), u64>::from_header_and_payload_ptrs(
+//!         address as *const _,
+//!         (address + payload_offset) as *const u64,
+//!     )
+//! };
+//! assert_eq!(*snapshot.as_data_ref(), 1234);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::alloc::Layout;
+use std::fmt::Debug;
+
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder};
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+
+use crate::config;
+use crate::payload_mut::internal::PayloadMgmt;
+use crate::sample_mut::SampleMut;
+use crate::service;
+use crate::service::config_scheme::data_segment_config;
+
+pub use crate::message::Message;
+pub use crate::port::details::segment_offset::{SegmentOffset, MAX_NUMBER_OF_SEGMENTS};
+pub use crate::raw_sample::{RawSample, RawSampleMut};
+pub use crate::service::header::publish_subscribe::Header;
+pub use iceoryx2_cal::shared_memory::SharedMemoryOpenError;
+pub use iceoryx2_cal::zero_copy_connection::PointerOffset;
+
+/// Returns the distance of the payload to the start of a sample with the `UserHeader` whose
+/// payload is stored with `payload_alignment`, see
+/// [`crate::service::static_config::publish_subscribe::StaticConfig::payload_alignment()`].
+pub fn payload_offset<UserHeader>(payload_alignment: usize) -> usize {
+    Message::<Header, UserHeader, ()>::payload_offset(payload_alignment)
+}
+
+/// Returns the [`Layout`] of a sample with the `UserHeader` and a payload with
+/// `payload_layout` that is stored with `payload_alignment`.
+pub fn message_layout<UserHeader>(payload_layout: Layout, payload_alignment: usize) -> Layout {
+    Message::<Header, UserHeader, ()>::layout_with_payload(payload_layout, payload_alignment)
+}
+
+/// Returns the [`PointerOffset`] of a loaned sample that is sent to the subscribers when the
+/// sample is sent.
+pub fn offset_of<MessageType: Debug + ?Sized, UserHeader: Debug>(
+    sample: &SampleMut<'_, MessageType, UserHeader>,
+) -> PointerOffset {
+    sample.offset_to_chunk()
+}
+
+/// A data segment of a [`Publisher`](crate::port::publisher::Publisher) that is mapped into
+/// the process independently of the ports. The samples in it are owned by the publisher,
+/// they must only be read and can be reused as soon as the publisher reclaims them.
+#[derive(Debug)]
+pub struct DataSegmentView<'config, Service: service::Details<'config>> {
+    memory: <Service as service::Details<'config>>::SharedMemory,
+}
+
+impl<'config, Service: service::Details<'config>> DataSegmentView<'config, Service> {
+    /// Maps the data segment with the provided name, see
+    /// [`Publisher::data_segment_name()`](crate::port::publisher::Publisher::data_segment_name()).
+    /// The `config` must be the [`config::Config`] of the service.
+    pub fn open(config: &config::Config, name: &FileName) -> Result<Self, SharedMemoryOpenError> {
+        Ok(Self {
+            memory: <Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder::new(name)
+                .config(&data_segment_config::<Service>(config))
+                .open()?,
+        })
+    }
+
+    /// Returns the address at which the allocator data of the data segment starts in the
+    /// current process, the [`SegmentOffset::offset()`] is relative to it.
+    pub fn allocator_data_start_address(&self) -> usize {
+        self.memory.allocator_data_start_address()
+    }
+
+    /// Returns the size of the allocator data in bytes, the region in which the samples are
+    /// stored. It starts at [`DataSegmentView::allocator_data_start_address()`], the management
+    /// data of the allocator in front of it is not part of it.
+    pub fn allocator_data_size(&self) -> usize {
+        self.memory.size()
+    }
+
+    /// Returns the address of the sample at `offset` in the current process or [`None`] when
+    /// the [`Header`] of the sample does not fit into the allocator data. It does not verify
+    /// that the offset belongs to the data segment, see [`SegmentOffset::segment_id()`].
+    pub fn address_of(&self, offset: SegmentOffset) -> Option<usize> {
+        let end_of_header = offset
+            .offset()
+            .checked_add(core::mem::size_of::<Header>())?;
+        match end_of_header <= self.allocator_data_size() {
+            true => Some(offset.address_in(self.allocator_data_start_address())),
+            false => None,
+        }
+    }
+}
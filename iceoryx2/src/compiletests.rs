@@ -35,6 +35,8 @@ fn sending_uninitialized_sample_fails_to_compile() {}
 /// ```compile_fail
 /// use iceoryx2::prelude::*;
 ///
+/// #[derive(Debug, ZeroCopySend)]
+/// #[repr(C)]
 /// struct Wrapper(u64);
 ///
 /// fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -53,3 +55,288 @@ fn sending_uninitialized_sample_fails_to_compile() {}
 /// ```
 #[cfg(doctest)]
 fn loan_with_type_not_implementing_default_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+///
+/// let service = zero_copy::Service::new(&service_name)
+///     .publish_subscribe()
+///     .open_or_create::<Vec<u8>>()?; // should fail to compile since 'Vec<u8>' points into the heap of the process
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn payload_with_heap_memory_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+///
+/// #[derive(Debug, ZeroCopySend)] // should fail to compile since 'Data' is not '#[repr(C)]'
+/// struct Data {
+///     value: u64,
+/// }
+/// ```
+#[cfg(doctest)]
+fn deriving_zero_copy_send_without_repr_c_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+///
+/// #[derive(Debug, ZeroCopySend)]
+/// #[repr(C)]
+/// struct Data {
+///     id: u64,
+///     name: String, // should fail to compile since 'String' does not implement 'ZeroCopySend'
+/// }
+/// ```
+#[cfg(doctest)]
+fn deriving_zero_copy_send_with_non_zero_copy_send_field_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+///
+/// #[derive(Debug, ZeroCopySend)] // should fail to compile since the size of the discriminant is unspecified
+/// enum State {
+///     Idle,
+///     Running { speed: u32 },
+/// }
+/// ```
+#[cfg(doctest)]
+fn deriving_zero_copy_send_for_enum_without_repr_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+///
+/// #[derive(Debug, ZeroCopySend)] // should fail to compile since the active field of a union is unknown
+/// #[repr(C)]
+/// union Value {
+///     integer: u64,
+///     float: f64,
+/// }
+/// ```
+#[cfg(doctest)]
+fn deriving_zero_copy_send_for_union_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+///
+/// let service = zero_copy::Service::new(&service_name)
+///     .publish_subscribe()
+///     .open_or_create::<u64>()?;
+///
+/// let publisher = service.publisher().create()?;
+///
+/// let sample = publisher.loan_uninit()?;
+/// let value: u64 = *sample; // should fail to compile since an uninitialized sample does not dereference to its payload
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn dereferencing_uninitialized_sample_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+///
+/// let service = zero_copy::Service::new(&service_name)
+///     .publish_subscribe()
+///     .open_or_create::<u64>()?;
+///
+/// let publisher = service.publisher().create()?;
+///
+/// let mut sample = publisher.loan_uninit()?;
+/// *sample = core::mem::MaybeUninit::new(1234); // should fail to compile since an uninitialized sample does not dereference to its payload
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn mutably_dereferencing_uninitialized_sample_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+///
+/// let service = zero_copy::Service::new(&service_name)
+///     .publish_subscribe()
+///     .max_slice_len(16)
+///     .open_or_create::<[u64]>()?;
+///
+/// let publisher = service.publisher().create()?;
+///
+/// let sample = publisher.loan_slice_uninit(4)?;
+/// let len = sample.len(); // should fail to compile since an uninitialized slice sample does not dereference to its payload
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn dereferencing_uninitialized_slice_sample_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+/// use iceoryx2::service::attribute::AttributeSpecifier;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+///
+/// let service = zero_copy::Service::new(&service_name)
+///     .publish_subscribe::<u64>()
+///     .attributes(&AttributeSpecifier::new().define("owner", "vision_team")?)
+///     .open()?; // should fail to compile since the attributes are applied only on creation
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn opening_with_create_only_setting_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+///
+/// let service = zero_copy::Service::new(&service_name)
+///     .publish_subscribe::<u64>()
+///     .persistence(ServicePersistence::Persistent)
+///     .open()?; // should fail to compile since the persistence is defined only on creation
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn opening_with_persistence_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+/// use iceoryx2::service::attribute::AttributeVerifier;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+///
+/// let service = zero_copy::Service::new(&service_name)
+///     .publish_subscribe::<u64>()
+///     .required_attributes(&AttributeVerifier::new().require_key("owner"))
+///     .create()?; // should fail to compile since the requirements are verified only on opening
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn creating_with_open_only_requirement_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+///
+/// let service = zero_copy::Service::new(&service_name)
+///     .publish_subscribe::<u64>()
+///     .open::<u32>()?; // should fail to compile since the payload type was already defined as 'u64'
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn terminal_call_with_another_payload_type_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy, ZeroCopySend, TaggedPayload)]
+/// #[repr(C)] // should fail to compile since the discriminant has no defined primitive type
+/// enum Message {
+///     Start(u32),
+///     Stop,
+/// }
+///
+/// fn main() {}
+/// ```
+#[cfg(doctest)]
+fn tagged_payload_without_primitive_representation_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy, ZeroCopySend, TaggedPayload)]
+/// #[repr(C, u32)]
+/// enum Message {
+///     Start(u32),
+///     Stop,
+/// }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+///
+/// let service = zero_copy::Service::new(&service_name)
+///     .publish_subscribe::<Message>()
+///     .open_or_create()?;
+///
+/// let publisher = service.publisher().create()?;
+/// publisher.send_variant(1.5f32)?; // should fail to compile since 'f32' is no variant of 'Message'
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn sending_a_type_that_is_no_variant_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+///
+/// let service = zero_copy::Service::new(&service_name)
+///     .publish_subscribe()
+///     .open_or_create::<u64>()?;
+///
+/// let publisher = service.publisher().create()?;
+/// let subscriber = service.subscriber().create()?;
+///
+/// publisher.send_copy(1234)?;
+/// let sample = subscriber.receive()?.unwrap();
+///
+/// drop(subscriber); // should fail to compile since the sample borrows the subscriber
+/// println!("{}", *sample);
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn dropping_subscriber_while_holding_a_sample_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::port::DegrationAction;
+/// use iceoryx2::prelude::*;
+/// use std::rc::Rc;
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+///
+/// let service = zero_copy::Service::new(&service_name)
+///     .publish_subscribe()
+///     .open_or_create::<u64>()?;
+///
+/// let publisher = service.publisher().create()?.into_sync();
+///
+/// let counter = Rc::new(0);
+/// // should fail to compile since the callback captures an 'Rc' which is neither 'Send' nor 'Sync'
+/// publisher.set_degration_callback(Some(move |_, _, _| {
+///     let _ = Rc::clone(&counter);
+///     DegrationAction::Ignore
+/// }));
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn setting_non_thread_safe_degration_callback_on_sync_publisher_fails_to_compile() {}
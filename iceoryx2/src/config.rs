@@ -73,6 +73,22 @@
 //! # }
 //! ```
 //!
+//! ## Config File Search Order
+//!
+//! When no global config was set up explicitly, the first call to
+//! [`crate::config::Config::get_global_config()`] loads the first config file that exists in the
+//! following order:
+//!
+//!  1. [`DEFAULT_CONFIG_FILE`], relative to the current working directory
+//!  2. [`USER_CONFIG_FILE`], relative to the home directory of the user
+//!  3. [`SYSTEM_CONFIG_FILE`]
+//!
+//! If none exists or the file cannot be loaded, [`Config::default()`] is used.
+//!
+//! A config file only has to contain the entries that shall differ from [`Config::default()`].
+//! Unknown entries are ignored with a warning and an entry with a wrong type fails with an error
+//! message that contains the key and line of the entry.
+//!
 //! ## Generate Config From Custom File
 //!
 //! ```no_run
@@ -90,19 +106,33 @@
 use iceoryx2_bb_container::byte_string::FixedSizeByteString;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::lazy_singleton::*;
-use iceoryx2_bb_posix::{file::FileBuilder, shared_memory::AccessMode};
+use iceoryx2_bb_posix::file::{File, FileBuilder};
+use iceoryx2_bb_posix::shared_memory::AccessMode;
+use iceoryx2_bb_posix::user::User;
+use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_system_types::path::Path;
-use serde::{Deserialize, Serialize};
+use serde::{de::Unexpected, Deserialize, Deserializer, Serialize};
 use std::time::Duration;
 
 use iceoryx2_bb_log::{fail, fatal_panic, trace, warn};
 
-use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::header::publish_subscribe::ClockType;
+use crate::service::port_factory::publisher::{MemoryHygiene, UnableToDeliverStrategy};
 
 /// Path to the default config file
 pub const DEFAULT_CONFIG_FILE: &[u8] = b"config/iceoryx2.toml";
 
+/// Path to the config file of the user, relative to its home directory
+pub const USER_CONFIG_FILE: &[u8] = b".config/iceoryx2/iceoryx2.toml";
+
+/// Path to the system wide config file
+pub const SYSTEM_CONFIG_FILE: &[u8] = b"/etc/iceoryx2/iceoryx2.toml";
+
+/// Name of the environment variable that overrides the [`Global::prefix`] of the global config,
+/// see [`Config::get_global_config()`]
+pub const PREFIX_ENV_VARIABLE: &str = "IOX2_PREFIX";
+
 /// Failures occurring while creating a new [`Config`] object with [`Config::from_file()`] or
 /// [`Config::setup_global_config_from_file()`]
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -120,9 +150,22 @@ impl std::fmt::Display for ConfigCreationError {
 
 impl std::error::Error for ConfigCreationError {}
 
+// Rejects limits that are zero while the config is deserialized, otherwise they would cause a
+// failure much later when a service is created with them.
+fn deserialize_non_zero<'de, D: Deserializer<'de>>(deserializer: D) -> Result<usize, D::Error> {
+    match usize::deserialize(deserializer)? {
+        0 => Err(serde::de::Error::invalid_value(
+            Unexpected::Unsigned(0),
+            &"a value greater than zero",
+        )),
+        value => Ok(value),
+    }
+}
+
 /// All configurable settings of a [`crate::service::Service`].
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
 pub struct Service {
     /// The directory in which all service files are stored
     pub directory: String,
@@ -133,22 +176,57 @@ pub struct Service {
     /// The suffix of the dynamic config file
     pub dynamic_config_storage_suffix: String,
     /// Defines the time of how long another process will wait until the service creation is
-    /// finalized
+    /// finalized. A service that is still not finalized afterwards was abandoned by a crashed
+    /// creator and is removed when it is opened.
     pub creation_timeout: Duration,
     /// The suffix of a one-to-one connection
     pub connection_suffix: String,
+    /// The suffix of the event concept of a [`crate::port::listener::Listener`]
+    pub event_connection_suffix: String,
+    /// The suffix of the event concept of a
+    /// [`crate::service::discovery::ServiceDiscovery`]
+    pub discovery_event_suffix: String,
+    /// The suffix of the event concept of a
+    /// [`crate::port::connection_monitor::ConnectionMonitor`]
+    pub connection_monitor_event_suffix: String,
+    /// The maximum number of service instances, across all processes, that can be open at the
+    /// same time for a single service
+    #[serde(deserialize_with = "deserialize_non_zero")]
+    pub max_owners: usize,
+}
+
+/// All configurable settings of a [`crate::node::Node`].
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct Node {
+    /// The directory in which the details of all nodes are stored
+    pub directory: String,
+    /// The suffix of the node details file
+    pub static_config_suffix: String,
+    /// The maximum number of [`crate::node::Node`]s that can publish a heartbeat at the same
+    /// time, see [`crate::node::NodeBuilder::heartbeat()`]
+    pub heartbeat_max_nodes: usize,
+    /// The maximum number of [`crate::node::liveness::NodeLivenessMonitor`]s that can
+    /// receive the heartbeats at the same time
+    pub heartbeat_max_monitors: usize,
 }
 
 /// The global settings
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
 pub struct Global {
     root_path_unix: String,
     root_path_windows: String,
-    /// Prefix used for all files created during runtime
+    /// Prefix used for all files created during runtime. Services and nodes are only visible to
+    /// processes that use the same prefix, so different prefixes isolate multiple deployments on
+    /// the same machine from each other.
     pub prefix: String,
     /// [`crate::service::Service`] settings
     pub service: Service,
+    /// [`crate::node::Node`] settings
+    pub node: Node,
 }
 
 impl Global {
@@ -182,34 +260,47 @@ impl Global {
 /// Default settings. These values are used when the user in the code does not specify anything
 /// else.
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(default)]
 pub struct Defaults {
     /// Default settings for the messaging pattern publish-subscribe
     pub publish_subscribe: PublishSubscribe,
     /// Default settings for the messaging pattern event
     pub event: Event,
+    /// Default settings for the messaging pattern request-response
+    pub request_response: RequestResponse,
 }
 
 /// Default settings for the publish-subscribe messaging pattern. These settings are used unless
 /// the user specifies custom QoS or port settings.
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
 pub struct PublishSubscribe {
     /// The maximum amount of supported [`crate::port::subscriber::Subscriber`]
+    #[serde(deserialize_with = "deserialize_non_zero")]
     pub max_subscribers: usize,
     /// The maximum amount of supported [`crate::port::publisher::Publisher`]
+    #[serde(deserialize_with = "deserialize_non_zero")]
     pub max_publishers: usize,
     /// The maximum buffer size a [`crate::port::subscriber::Subscriber`] can have
+    #[serde(deserialize_with = "deserialize_non_zero")]
     pub subscriber_max_buffer_size: usize,
     /// The maximum amount of [`crate::sample::Sample`]s a [`crate::port::subscriber::Subscriber`] can
     /// hold in parallel.
+    #[serde(deserialize_with = "deserialize_non_zero")]
     pub subscriber_max_borrowed_samples: usize,
     /// The maximum amount of [`crate::sample_mut::SampleMut`]s a [`crate::port::publisher::Publisher`] can
     /// loan in parallel.
+    #[serde(deserialize_with = "deserialize_non_zero")]
     pub publisher_max_loaned_samples: usize,
     /// The maximum history size a [`crate::port::subscriber::Subscriber`] can request from a
     /// [`crate::port::publisher::Publisher`].
     pub publisher_history_size: usize,
+    /// Defines if a [`crate::port::publisher::Publisher`] zeroes the payload of its samples
+    /// when they are returned or before they are loaned, see
+    /// [`crate::service::port_factory::publisher::MemoryHygiene`].
+    pub publisher_memory_hygiene: MemoryHygiene,
     /// Defines how the [`crate::port::subscriber::Subscriber`] buffer behaves when it is
     /// full. When safe overflow is activated, the [`crate::port::publisher::Publisher`] will
     /// replace the oldest [`crate::sample::Sample`] with the newest one.
@@ -218,17 +309,59 @@ pub struct PublishSubscribe {
     /// [`crate::port::publisher::Publisher`] when the [`crate::port::subscriber::Subscriber`]s
     /// buffer is full.
     pub unable_to_deliver_strategy: UnableToDeliverStrategy,
+    /// The clock that is used for the time stamps in the header of every
+    /// [`crate::sample::Sample`].
+    pub clock_type: ClockType,
+    /// If enabled, a [`crate::port::publisher::Publisher`] delivers its samples to the
+    /// [`crate::port::subscriber::Subscriber`]s of its own process via process local queues
+    /// instead of the connections in shared memory. Subscribers of other processes are still
+    /// connected via shared memory. The samples are still identified by their offset in the
+    /// data segment, only the shared memory connection is bypassed. The setting is stored in
+    /// the service when it is created and can be deactivated to compare both paths in
+    /// benchmarks.
+    pub enable_intra_process_fast_path: bool,
 }
 
 /// Default settings for the event messaging pattern. These settings are used unless
 /// the user specifies custom QoS or port settings.
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
 pub struct Event {
     /// The maximum amount of supported [`crate::port::listener::Listener`]
+    #[serde(deserialize_with = "deserialize_non_zero")]
     pub max_listeners: usize,
     /// The maximum amount of supported [`crate::port::notifier::Notifier`]
+    #[serde(deserialize_with = "deserialize_non_zero")]
     pub max_notifiers: usize,
+    /// The largest value of an [`crate::port::event_id::EventId`] that can be sent
+    pub event_id_max_value: u64,
+}
+
+/// Default settings for the request-response messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct RequestResponse {
+    /// The maximum amount of supported [`crate::port::client::Client`]
+    #[serde(deserialize_with = "deserialize_non_zero")]
+    pub max_clients: usize,
+    /// The maximum amount of supported [`crate::port::server::Server`]
+    #[serde(deserialize_with = "deserialize_non_zero")]
+    pub max_servers: usize,
+    /// The maximum amount of requests a [`crate::port::client::Client`] can wait for in
+    /// parallel, see [`crate::port::client::PendingResponse`].
+    #[serde(deserialize_with = "deserialize_non_zero")]
+    pub max_active_requests: usize,
+    /// The maximum amount of responses a [`crate::port::server::Server`] can send for a single
+    /// request.
+    #[serde(deserialize_with = "deserialize_non_zero")]
+    pub max_responses_per_request: usize,
+    /// The maximum amount of [`crate::port::server::ResponseMut`]s a
+    /// [`crate::port::server::Server`] can loan in parallel.
+    #[serde(deserialize_with = "deserialize_non_zero")]
+    pub server_max_loaned_responses: usize,
 }
 
 /// Represents the configuration that Iceoryx2 will utilize. It is divided into two sections:
@@ -236,7 +369,8 @@ pub struct Event {
 /// join, and the [Defaults] for communication within that Iceoryx2 instance. The user has the
 /// flexibility to override both sections.
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(default)]
 pub struct Config {
     /// Global settings for the iceoryx2 instance
     pub global: Global,
@@ -246,42 +380,128 @@ pub struct Config {
 
 static ICEORYX2_CONFIG: LazySingleton<Config> = LazySingleton::<Config>::new();
 
-impl Default for Config {
+impl Default for Service {
+    fn default() -> Self {
+        Self {
+            directory: "services".to_string(),
+            publisher_data_segment_suffix: ".publisher_data".to_string(),
+            static_config_storage_suffix: ".service".to_string(),
+            dynamic_config_storage_suffix: ".dynamic".to_string(),
+            creation_timeout: Duration::from_millis(500),
+            connection_suffix: ".connection".to_string(),
+            event_connection_suffix: ".event".to_string(),
+            discovery_event_suffix: ".discovery".to_string(),
+            connection_monitor_event_suffix: ".monitor".to_string(),
+            max_owners: 64,
+        }
+    }
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Self {
+            directory: "nodes".to_string(),
+            static_config_suffix: ".node".to_string(),
+            heartbeat_max_nodes: 64,
+            heartbeat_max_monitors: 4,
+        }
+    }
+}
+
+impl Default for Global {
+    fn default() -> Self {
+        Self {
+            root_path_unix: "/tmp/iceoryx2/".to_string(),
+            prefix: "iox2_".to_string(),
+            root_path_windows: "C:\\Windows\\Temp\\iceoryx2\\".to_string(),
+            service: Service::default(),
+            node: Node::default(),
+        }
+    }
+}
+
+impl Default for PublishSubscribe {
+    fn default() -> Self {
+        Self {
+            max_subscribers: 8,
+            max_publishers: 2,
+            publisher_history_size: 1,
+            subscriber_max_buffer_size: 2,
+            subscriber_max_borrowed_samples: 2,
+            publisher_max_loaned_samples: 2,
+            publisher_memory_hygiene: MemoryHygiene::None,
+            enable_safe_overflow: true,
+            unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
+            clock_type: ClockType::Realtime,
+            enable_intra_process_fast_path: true,
+        }
+    }
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self {
+            max_listeners: 1,
+            max_notifiers: 16,
+            event_id_max_value: 65535,
+        }
+    }
+}
+
+impl Default for RequestResponse {
     fn default() -> Self {
         Self {
-            global: Global {
-                root_path_unix: "/tmp/iceoryx2/".to_string(),
-                prefix: "iox2_".to_string(),
-                root_path_windows: "C:\\Windows\\Temp\\iceoryx2\\".to_string(),
-                service: Service {
-                    directory: "services".to_string(),
-                    publisher_data_segment_suffix: ".publisher_data".to_string(),
-                    static_config_storage_suffix: ".service".to_string(),
-                    dynamic_config_storage_suffix: ".dynamic".to_string(),
-                    creation_timeout: Duration::from_millis(500),
-                    connection_suffix: ".connection".to_string(),
-                },
-            },
-            defaults: Defaults {
-                publish_subscribe: PublishSubscribe {
-                    max_subscribers: 8,
-                    max_publishers: 2,
-                    publisher_history_size: 1,
-                    subscriber_max_buffer_size: 2,
-                    subscriber_max_borrowed_samples: 2,
-                    publisher_max_loaned_samples: 2,
-                    enable_safe_overflow: true,
-                    unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
-                },
-                event: Event {
-                    max_listeners: 1,
-                    max_notifiers: 16,
-                },
-            },
+            max_clients: 8,
+            max_servers: 2,
+            max_active_requests: 4,
+            max_responses_per_request: 8,
+            server_max_loaned_responses: 2,
         }
     }
 }
 
+fn warn_about_unknown_entries(
+    known_entries: &toml::value::Table,
+    entries: &toml::value::Table,
+    key_prefix: &str,
+) {
+    for (key, value) in entries {
+        let full_key = match key_prefix.is_empty() {
+            true => key.clone(),
+            false => format!("{}.{}", key_prefix, key),
+        };
+
+        match (known_entries.get(key), value) {
+            (None, _) => {
+                warn!(from "Config::from_file()", "Ignoring the unknown config entry \"{}\".", full_key)
+            }
+            (Some(toml::Value::Table(known_entries)), toml::Value::Table(entries)) => {
+                warn_about_unknown_entries(known_entries, entries, &full_key)
+            }
+            _ => (),
+        }
+    }
+}
+
+fn config_file_search_order() -> Vec<FilePath> {
+    let mut config_files = vec![unsafe { FilePath::new_unchecked(DEFAULT_CONFIG_FILE) }];
+
+    if let Ok(user) = User::from_self() {
+        let mut user_config_file = user.home_dir().as_bytes().to_vec();
+        if !user_config_file.ends_with(b"/") {
+            user_config_file.push(b'/');
+        }
+        user_config_file.extend_from_slice(USER_CONFIG_FILE);
+
+        if let Ok(user_config_file) = FilePath::new(&user_config_file) {
+            config_files.push(user_config_file);
+        }
+    }
+
+    config_files.push(unsafe { FilePath::new_unchecked(SYSTEM_CONFIG_FILE) });
+    config_files
+}
+
 impl Config {
     /// Loads a configuration from a file. On success it returns a [`Config`] object otherwise a
     /// [`ConfigCreationError`] describing the failure.
@@ -306,6 +526,13 @@ impl Config {
             }
         }
 
+        if let (Ok(toml::Value::Table(known_entries)), Ok(toml::Value::Table(entries))) = (
+            toml::Value::try_from(Config::default()),
+            toml::from_str::<toml::Value>(&contents),
+        ) {
+            warn_about_unknown_entries(&known_entries, &entries, "");
+        }
+
         trace!(from new_config, "Loaded.");
         Ok(new_config)
     }
@@ -320,7 +547,9 @@ impl Config {
             return Ok(ICEORYX2_CONFIG.get());
         }
 
-        if !ICEORYX2_CONFIG.set_value(Config::from_file(config_file)?) {
+        if !ICEORYX2_CONFIG
+            .set_value(Config::from_file(config_file)?.with_prefix_from_environment())
+        {
             warn!(
                 from ICEORYX2_CONFIG.get(),
                 "Configuration already loaded and set up, cannot load another one. This may happen when this function is called from multiple threads."
@@ -332,20 +561,44 @@ impl Config {
         Ok(ICEORYX2_CONFIG.get())
     }
 
+    fn with_prefix_from_environment(mut self) -> Self {
+        if let Ok(prefix) = std::env::var(PREFIX_ENV_VARIABLE) {
+            match FileName::new(prefix.as_bytes()) {
+                Ok(_) => self.global.prefix = prefix,
+                Err(_) => {
+                    warn!(from self, "Ignoring the prefix \"{}\" of the environment variable {} since it is not a valid file name.",
+                        prefix, PREFIX_ENV_VARIABLE);
+                }
+            }
+        }
+
+        self
+    }
+
     /// Returns the global configuration. If the global configuration was not
-    /// [`Config::setup_global_config_from_file()`] it will load a default config. If
+    /// [`Config::setup_global_config_from_file()`] it will load the first config file it finds
+    /// in the documented search order or a default config when there is none. If
     /// [`Config::setup_global_config_from_file()`]
     /// is called after this function was called, no file will be loaded since the global default
     /// config was already populated.
+    ///
+    /// When the environment variable [`PREFIX_ENV_VARIABLE`] is set, its value replaces the
+    /// [`Global::prefix`] of the loaded config.
     pub fn get_global_config() -> &'static Config {
-        if !ICEORYX2_CONFIG.is_initialized()
-            && Config::setup_global_config_from_file(unsafe {
-                &FilePath::new_unchecked(DEFAULT_CONFIG_FILE)
-            })
-            .is_err()
-        {
-            warn!(from "Config::get_global_config()", "Unable to load default config file, populate config with default values.");
-            ICEORYX2_CONFIG.set_value(Config::default());
+        if !ICEORYX2_CONFIG.is_initialized() {
+            let config_file = config_file_search_order()
+                .into_iter()
+                .find(|config_file| matches!(File::does_exist(config_file), Ok(true)));
+
+            let is_loaded = match config_file {
+                Some(config_file) => Config::setup_global_config_from_file(&config_file).is_ok(),
+                None => false,
+            };
+
+            if !is_loaded {
+                warn!(from "Config::get_global_config()", "Unable to load a config file, populate config with default values.");
+                ICEORYX2_CONFIG.set_value(Config::default().with_prefix_from_environment());
+            }
         }
 
         ICEORYX2_CONFIG.get()
@@ -91,7 +91,13 @@ impl Iox2 {
                     Iox2Event::Tick
                 }
             }
-            Err(NanosleepError::InterruptedBySignal(_)) => Iox2Event::InterruptSignal,
+            Err(NanosleepError::InterruptedBySignal(_)) => {
+                if SignalHandler::termination_requested() {
+                    Iox2Event::TerminationRequest
+                } else {
+                    Iox2Event::InterruptSignal
+                }
+            }
             Err(v) => {
                 fatal_panic!(from self,
                     "Failed to wait with cycle time {:?} in main event look, caused by ({:?}).",
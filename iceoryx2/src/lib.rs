@@ -157,10 +157,11 @@
 //!     .open_or_create()?;
 //!
 //! let notifier = event.notifier().create()?;
+//! let max_event_id = event.static_config().event_id_max_value();
 //!
 //! let mut counter: u64 = 0;
 //! while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
-//!     counter += 1;
+//!     counter = counter % max_event_id + 1;
 //!     notifier.notify_with_custom_event_id(EventId::new(counter))?;
 //!
 //!     println!("Trigger event with id {} ...", counter);
@@ -259,9 +260,17 @@
 //!
 //! # Feature Flags
 //!
-//!  * `logger_log` - Uses the [log crate](https://crates.io/crates/log) as default log backend
+//!  * `logger_log` - Uses the [log crate](https://crates.io/crates/log) as default log backend,
+//!    see [`log`]
 //!  * `logger_tracing` - Uses the [tracing crate](https://crates.io/crates/tracing) as default log
-//!     backend
+//!    backend, see [`log`]
+//!  * `serde` - Implements [`serde::Serialize`] and [`serde::Deserialize`] for
+//!    [`service::ServiceDetails`], [`port::event_id::EventId`], the unique port ids and the
+//!    [`prelude::FixedSizeVec`] and [`prelude::FixedSizeByteString`] payload containers. The
+//!    [`config::Config`], [`service::static_config::StaticConfig`] and
+//!    [`service::service_name::ServiceName`] are always serializable. The field names of all
+//!    serialized types are considered a stable schema, they change only with a major release.
+//!    Unique port ids are serialized as 32 digit hexadecimal strings.
 //!
 //! # Custom Configuration
 //!
@@ -274,14 +283,23 @@
 #[cfg(doctest)]
 mod compiletests;
 
+/// Low level building blocks for custom ports, they are not covered by semantic versioning.
+pub mod advanced;
+
 /// Handles iceoryx2s global configuration
 pub mod config;
 
 /// Central instance that handles all incoming events, the event loop
 pub mod iox2;
 
+/// Routes the log messages of iceoryx2 to a custom logger or to the `log` or `tracing` crate
+pub mod log;
+
 pub(crate) mod message;
 
+/// Groups the services of an application under a named node that is registered in the system
+pub mod node;
+
 /// The ports or communication endpoints of iceoryx2
 pub mod port;
 
@@ -296,6 +314,9 @@ pub mod sample;
 /// The interface for the payload that is sent by a [`crate::port::publisher::Publisher`].
 pub mod payload_mut;
 
+/// Describes which types can be used as payload, sized types as well as slices.
+pub mod payload_type;
+
 /// The payload that is sent by a [`crate::port::publisher::Publisher`].
 pub mod sample_mut;
 
@@ -303,5 +324,21 @@ pub mod sample_mut;
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
 
+/// Tagged unions that carry different message types over one service.
+pub mod tagged_payload;
+
+/// Helpers to isolate the resources of tests that run in parallel
+pub mod testing;
+
+/// Records the samples of a service and replays them with their original timing.
+pub mod tooling;
+
+/// Waits on multiple [`crate::port::listener::Listener`]s, external file descriptors and
+/// interval timers in a single blocking call.
+pub mod waitset;
+
+/// Marks the types that can be shared with other processes as payload.
+pub mod zero_copy_send;
+
 /// Loads a meaninful subset to cover 90% of the iceoryx2 communication use cases.
 pub mod prelude;
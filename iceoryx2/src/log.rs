@@ -0,0 +1,58 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Every diagnostic of iceoryx2 - failed service and port creations, connection failures,
+//! removed stale resources and adjusted settings - is forwarded to one global [`Logger`]. The
+//! messages of a service contain its name, the messages of a port the name of the service and
+//! the id of the port.
+//!
+//! By default the messages are printed to the console and can be filtered with
+//! [`set_log_level()`]. The feature flags `logger_log` and `logger_tracing` replace the
+//! default with a [`Logger`] that forwards the messages to the
+//! [log crate](https://crates.io/crates/log) or the
+//! [tracing crate](https://crates.io/crates/tracing), those are filtered by the settings of
+//! the corresponding framework. A custom [`Logger`] can be installed with [`set_logger()`]
+//! before the first message is logged.
+//!
+//! Failures that are reported to the caller as error, like opening a service that does not
+//! exist, are logged with [`LogLevel::Debug`]. Failures the caller most likely did not expect,
+//! like opening a service with another payload type than the one it was created with, are
+//! logged with [`LogLevel::Warn`] or higher.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::log::{set_log_level, set_logger, LogLevel, Logger};
+//!
+//! struct StderrLogger;
+//!
+//! impl Logger for StderrLogger {
+//!     fn log(
+//!         &self,
+//!         log_level: LogLevel,
+//!         origin: std::fmt::Arguments,
+//!         formatted_message: std::fmt::Arguments,
+//!     ) {
+//!         if log_level >= LogLevel::Warn {
+//!             eprintln!("[{:?}] {}: {}", log_level, origin, formatted_message);
+//!         }
+//!     }
+//! }
+//!
+//! static LOGGER: StderrLogger = StderrLogger;
+//!
+//! set_log_level(LogLevel::Warn);
+//! assert!(set_logger(&LOGGER));
+//! ```
+
+pub use iceoryx2_bb_log::logger::{buffer, console, Logger};
+pub use iceoryx2_bb_log::{get_log_level, get_logger, set_log_level, set_logger, LogLevel};
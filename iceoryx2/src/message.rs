@@ -11,18 +11,60 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use core::fmt;
+use std::alloc::Layout;
 
+use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_log::fatal_panic;
+
+/// The layout of a sample in the data segment, the [`Header`](crate::service::header) and the
+/// user header are followed by the payload. Use [`crate::advanced::payload_offset()`] to
+/// acquire the offset of a payload that is stored with a larger alignment than its own.
 #[repr(C)]
-pub(crate) struct Message<Header, Data> {
+pub struct Message<Header, UserHeader, Data: ?Sized> {
     pub(crate) header: Header,
+    pub(crate) user_header: UserHeader,
     pub(crate) data: Data,
 }
 
-impl<Header: fmt::Debug, Data: fmt::Debug> fmt::Debug for Message<Header, Data> {
+impl<Header: fmt::Debug, UserHeader: fmt::Debug, Data: fmt::Debug + ?Sized> fmt::Debug
+    for Message<Header, UserHeader, Data>
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Message<Header, Data>")
+        f.debug_struct("Message<Header, UserHeader, Data>")
             .field("header", &self.header)
-            .field("data", &self.data)
+            .field("user_header", &self.user_header)
+            .field("data", &&self.data)
             .finish()
     }
 }
+
+impl<Header, UserHeader> Message<Header, UserHeader, ()> {
+    fn headers_layout() -> Layout {
+        fatal_panic!(from "Message::headers_layout()",
+            when Layout::new::<Header>().extend(Layout::new::<UserHeader>()),
+            "The layout of the header combined with the user header \"{}\" exceeds the supported memory size.",
+            std::any::type_name::<UserHeader>())
+        .0
+    }
+
+    /// Returns the offset of the payload from the start of the message when the payload is
+    /// stored with `payload_alignment`. When it is the alignment of the payload type the offset
+    /// is equal to the offset of the `data` field of the `#[repr(C)]` message.
+    pub(crate) fn payload_offset(payload_alignment: usize) -> usize {
+        align(Self::headers_layout().size(), payload_alignment)
+    }
+
+    /// Returns the [`Layout`] of a message whose payload has `payload_layout` and is stored with
+    /// `payload_alignment`.
+    pub(crate) fn layout_with_payload(payload_layout: Layout, payload_alignment: usize) -> Layout {
+        let origin = "Message::layout_with_payload()";
+        let payload_layout = fatal_panic!(from origin,
+            when payload_layout.align_to(payload_alignment),
+            "The payload alignment {} is not supported.", payload_alignment);
+        let (layout, _) = fatal_panic!(from origin,
+            when Self::headers_layout().extend(payload_layout),
+            "The layout of a payload with a size of {} bytes exceeds the supported memory size.",
+            payload_layout.size());
+        layout.pad_to_align()
+    }
+}
@@ -0,0 +1,564 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`Node`](crate::node::Node) that is created with
+//! [`NodeBuilder::heartbeat()`](crate::node::NodeBuilder::heartbeat()) publishes a heartbeat
+//! with the given period from a background thread on the internal service
+//! [`HEARTBEAT_SERVICE_NAME`]. A [`NodeLivenessMonitor`] receives the heartbeats and reports a
+//! node as [`NodeLiveness::Dead`] when its process does no longer exist or when it missed
+//! more heartbeats than tolerated, for instance since it hangs. Nodes without a heartbeat can
+//! only be checked for the existence of their process and are reported as
+//! [`NodeLiveness::Unmonitored`].
+//!
+//! # Example
+//!
+//! ```
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::liveness::{NodeLiveness, NodeLivenessMonitorBuilder};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new()
+//!     .name(&NodeName::new("sensor_fusion")?)
+//!     .heartbeat(Duration::from_millis(100))
+//!     .create::<zero_copy::Service>()?;
+//!
+//! let monitor = NodeLivenessMonitorBuilder::new()
+//!     .check_interval(Duration::from_millis(50))
+//!     .missed_heartbeats_until_dead(3)
+//!     .on_transition(|details, previous, current| {
+//!         println!("node {:?} changed from {:?} to {:?}", details.name(), previous, current);
+//!     })
+//!     .create::<zero_copy::Service>()?;
+//!
+//! for (details, liveness) in monitor.nodes() {
+//!     if let NodeLiveness::Dead { last_seen } = liveness {
+//!         println!("node {:?} is dead, last heartbeat: {:?}", details.name(), last_seen);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::time::Duration;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, SyncSender};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use iceoryx2_bb_log::{fail, warn};
+use tiny_fn::tiny_fn;
+
+use crate::config;
+use crate::port::publish::SendCopy;
+use crate::port::subscribe::Subscribe;
+use crate::service::builder::publish_subscribe::PublishSubscribeOpenOrCreateError;
+use crate::service::port_factory::publish_subscribe::PortFactory;
+use crate::service::service_name::ServiceName;
+use crate::service::Service;
+use crate::zero_copy_send::ZeroCopySend;
+
+use super::{Node, NodeDetails, NodeId, NodeState};
+
+/// The name of the internal service on which all [`Node`]s publish their heartbeat.
+pub const HEARTBEAT_SERVICE_NAME: ServiceName =
+    ServiceName::from_static_str("iox2/internal/node_heartbeat");
+
+const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+const DEFAULT_MISSED_HEARTBEATS_UNTIL_DEAD: u32 = 3;
+
+/// Failures that can occur when a [`NodeLivenessMonitor`] is created with
+/// [`NodeLivenessMonitorBuilder::create()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeLivenessMonitorCreationFailure {
+    UnableToOpenHeartbeatService,
+    UnableToCreateSubscriber,
+    InternalError,
+}
+
+impl std::fmt::Display for NodeLivenessMonitorCreationFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for NodeLivenessMonitorCreationFailure {}
+
+/// The liveness of a [`Node`] that is registered in the system, reported by the
+/// [`NodeLivenessMonitor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeLiveness {
+    /// The [`Node`] published its heartbeat in time.
+    Alive,
+    /// The process of the [`Node`] does no longer exist or the [`Node`] did not publish its
+    /// heartbeat in time. `last_seen` is the time of the last received heartbeat, it is
+    /// [`None`] when the [`NodeLivenessMonitor`] never received one.
+    Dead { last_seen: Option<SystemTime> },
+    /// The process of the [`Node`] exists but the [`Node`] publishes no heartbeat.
+    Unmonitored,
+}
+
+tiny_fn! {
+    /// Is called by the [`NodeLivenessMonitor`] whenever the [`NodeLiveness`] of a [`Node`]
+    /// changes. It receives the [`NodeDetails`], the previous and the current [`NodeLiveness`].
+    pub struct LivenessTransitionCallback = FnMut(details: NodeDetails, previous: NodeLiveness, current: NodeLiveness) | + Send;
+}
+
+impl<'a> Debug for LivenessTransitionCallback<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+/// The sample that is published on the [`HEARTBEAT_SERVICE_NAME`] service.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+struct Heartbeat {
+    node_id: u128,
+    timestamp_in_ns: u64,
+}
+
+unsafe impl ZeroCopySend for Heartbeat {}
+
+impl Heartbeat {
+    fn timestamp(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_nanos(self.timestamp_in_ns)
+    }
+}
+
+fn open_or_create_heartbeat_service<S: Service>(
+    config: &config::Config,
+) -> Result<PortFactory<'_, S::Type<'_>, Heartbeat>, PublishSubscribeOpenOrCreateError> {
+    // every node and monitor uses the same settings so that the first one creates the service
+    // and all others are able to open it
+    S::new(&HEARTBEAT_SERVICE_NAME)
+        .publish_subscribe_with_custom_config::<Heartbeat>(config)
+        .max_publishers(config.global.node.heartbeat_max_nodes)
+        .max_subscribers(config.global.node.heartbeat_max_monitors)
+        .history_size(1)
+        .subscriber_max_buffer_size(1)
+        .subscriber_max_borrowed_samples(1)
+        .enable_safe_overflow(true)
+        .open_or_create()
+}
+
+/// A thread that runs until the [`BackgroundThread`] goes out of scope. The stop request is
+/// the disconnect of the channel that the thread waits on between its cycles.
+#[derive(Debug)]
+pub(crate) struct BackgroundThread {
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl BackgroundThread {
+    fn spawn<F: FnOnce(Receiver<()>) + Send + 'static>(name: &str, f: F) -> std::io::Result<Self> {
+        let (stop, stop_request) = mpsc::channel();
+        let handle = std::thread::Builder::new()
+            .name(name.to_string())
+            .spawn(move || f(stop_request))?;
+
+        Ok(Self {
+            stop: Some(stop),
+            handle: Some(handle),
+        })
+    }
+}
+
+impl Drop for BackgroundThread {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(handle) = self.handle.take() {
+            if handle.join().is_err() {
+                warn!(from self, "The background thread terminated with a panic.");
+            }
+        }
+    }
+}
+
+/// Returns `true` when the stop was requested, otherwise it returns after `timeout`.
+fn wait_for_stop_request(stop_request: &Receiver<()>, timeout: Duration) -> bool {
+    !matches!(
+        stop_request.recv_timeout(timeout),
+        Err(RecvTimeoutError::Timeout)
+    )
+}
+
+/// Starts the thread that publishes the heartbeat of the node with the given [`NodeId`]. It
+/// returns when the first heartbeat was sent.
+pub(crate) fn start_heartbeat<S: Service>(
+    config: &config::Config,
+    node_id: NodeId,
+    period: Duration,
+) -> Option<BackgroundThread> {
+    let origin = "start_heartbeat()";
+    let config = config.clone();
+    let (ready, is_ready) = mpsc::sync_channel(1);
+
+    let thread = match BackgroundThread::spawn("iox2-heartbeat", move |stop_request| {
+        publish_heartbeat::<S>(&config, node_id, period, ready, stop_request)
+    }) {
+        Ok(thread) => thread,
+        Err(e) => {
+            warn!(from origin, "Unable to spawn the heartbeat thread ({:?}).", e);
+            return None;
+        }
+    };
+
+    match is_ready.recv() {
+        Ok(true) => Some(thread),
+        _ => None,
+    }
+}
+
+fn publish_heartbeat<S: Service>(
+    config: &config::Config,
+    node_id: NodeId,
+    period: Duration,
+    ready: SyncSender<bool>,
+    stop_request: Receiver<()>,
+) {
+    let origin = "publish_heartbeat()";
+    let msg = "Unable to publish the heartbeat";
+
+    let service = match open_or_create_heartbeat_service::<S>(config) {
+        Ok(service) => service,
+        Err(e) => {
+            warn!(from origin, "{} of node {:?} since the heartbeat service could not be opened ({:?}).",
+                msg, node_id, e);
+            let _ = ready.send(false);
+            return;
+        }
+    };
+
+    let publisher = match service.publisher().create() {
+        Ok(publisher) => publisher,
+        Err(e) => {
+            warn!(from origin, "{} of node {:?} since the publisher could not be created ({:?}).",
+                msg, node_id, e);
+            let _ = ready.send(false);
+            return;
+        }
+    };
+
+    let mut is_first_heartbeat = true;
+    loop {
+        let heartbeat = Heartbeat {
+            node_id: node_id.value(),
+            timestamp_in_ns: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos() as u64,
+        };
+
+        if let Err(e) = publisher.send_copy(heartbeat) {
+            warn!(from origin, "{} of node {:?} ({:?}).", msg, node_id, e);
+        }
+
+        if is_first_heartbeat {
+            is_first_heartbeat = false;
+            let _ = ready.send(true);
+        }
+
+        if wait_for_stop_request(&stop_request, period) {
+            return;
+        }
+    }
+}
+
+/// Creates a [`NodeLivenessMonitor`].
+///
+/// # Example
+///
+/// See [`crate::node::liveness`]
+#[derive(Debug)]
+pub struct NodeLivenessMonitorBuilder {
+    config: Option<config::Config>,
+    check_interval: Duration,
+    missed_heartbeats_until_dead: u32,
+    callback: Option<LivenessTransitionCallback<'static>>,
+}
+
+impl Default for NodeLivenessMonitorBuilder {
+    fn default() -> Self {
+        Self {
+            config: None,
+            check_interval: DEFAULT_CHECK_INTERVAL,
+            missed_heartbeats_until_dead: DEFAULT_MISSED_HEARTBEATS_UNTIL_DEAD,
+            callback: None,
+        }
+    }
+}
+
+impl NodeLivenessMonitorBuilder {
+    /// Creates a new [`NodeLivenessMonitorBuilder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`config::Config`] under which the [`Node`]s are monitored. If it is not set,
+    /// the global config [`config::Config::get_global_config()`] is used.
+    pub fn config(mut self, value: &config::Config) -> Self {
+        self.config = Some(value.clone());
+        self
+    }
+
+    /// Defines how often the [`NodeLivenessMonitor`] collects the heartbeats and updates the
+    /// [`NodeLiveness`] of all [`Node`]s.
+    pub fn check_interval(mut self, value: Duration) -> Self {
+        self.check_interval = value;
+        self
+    }
+
+    /// A [`Node`] is reported as [`NodeLiveness::Dead`] when it did not publish a heartbeat
+    /// within `value` times its heartbeat period.
+    pub fn missed_heartbeats_until_dead(mut self, value: u32) -> Self {
+        self.missed_heartbeats_until_dead = value.max(1);
+        self
+    }
+
+    /// Sets the callback that is called from the thread of the [`NodeLivenessMonitor`]
+    /// whenever the [`NodeLiveness`] of a known [`Node`] changes. [`Node`]s that appear or
+    /// that are removed from the system are not reported.
+    pub fn on_transition<F: FnMut(NodeDetails, NodeLiveness, NodeLiveness) + Send + 'static>(
+        mut self,
+        callback: F,
+    ) -> Self {
+        self.callback = Some(LivenessTransitionCallback::new(callback));
+        self
+    }
+
+    /// Creates the [`NodeLivenessMonitor`] and starts its thread. When it returns, the
+    /// [`NodeLiveness`] of all registered [`Node`]s was evaluated already.
+    pub fn create<S: Service>(
+        self,
+    ) -> Result<NodeLivenessMonitor<S>, NodeLivenessMonitorCreationFailure> {
+        let msg = "Unable to create node liveness monitor";
+        let config = match &self.config {
+            Some(config) => config.clone(),
+            None => config::Config::get_global_config().clone(),
+        };
+
+        let nodes = Arc::new(Mutex::new(vec![]));
+        let (ready, is_ready) = mpsc::sync_channel(1);
+        let settings = MonitorSettings {
+            config,
+            check_interval: self.check_interval,
+            missed_heartbeats_until_dead: self.missed_heartbeats_until_dead,
+            callback: self.callback,
+            nodes: nodes.clone(),
+        };
+
+        let thread = fail!(from "NodeLivenessMonitorBuilder::create()",
+            when BackgroundThread::spawn("iox2-liveness", move |stop_request| {
+                monitor_liveness::<S>(settings, ready, stop_request)
+            }),
+            with NodeLivenessMonitorCreationFailure::InternalError,
+            "{} since the monitor thread could not be spawned.", msg);
+
+        match is_ready.recv() {
+            Ok(Ok(())) => Ok(NodeLivenessMonitor {
+                nodes,
+                _thread: thread,
+                _phantom_service: PhantomData,
+            }),
+            Ok(Err(e)) => {
+                fail!(from "NodeLivenessMonitorBuilder::create()", with e,
+                    "{} since the heartbeats cannot be received ({:?}).", msg, e);
+            }
+            Err(_) => {
+                fail!(from "NodeLivenessMonitorBuilder::create()",
+                    with NodeLivenessMonitorCreationFailure::InternalError,
+                    "{} since the monitor thread terminated unexpectedly.", msg);
+            }
+        }
+    }
+}
+
+struct MonitorSettings {
+    config: config::Config,
+    check_interval: Duration,
+    missed_heartbeats_until_dead: u32,
+    callback: Option<LivenessTransitionCallback<'static>>,
+    nodes: Arc<Mutex<Vec<(NodeDetails, NodeLiveness)>>>,
+}
+
+fn lock<T>(value: &Mutex<T>) -> MutexGuard<'_, T> {
+    // the monitor thread does not panic while it holds the lock, a poisoned value is still
+    // consistent
+    match value.lock() {
+        Ok(guard) => guard,
+        Err(e) => e.into_inner(),
+    }
+}
+
+fn monitor_liveness<S: Service>(
+    mut settings: MonitorSettings,
+    ready: SyncSender<Result<(), NodeLivenessMonitorCreationFailure>>,
+    stop_request: Receiver<()>,
+) {
+    let origin = "monitor_liveness()";
+
+    let service = match open_or_create_heartbeat_service::<S>(&settings.config) {
+        Ok(service) => service,
+        Err(e) => {
+            warn!(from origin, "Unable to open the heartbeat service ({:?}).", e);
+            let _ = ready.send(Err(
+                NodeLivenessMonitorCreationFailure::UnableToOpenHeartbeatService,
+            ));
+            return;
+        }
+    };
+
+    let subscriber = match service.subscriber().create() {
+        Ok(subscriber) => subscriber,
+        Err(e) => {
+            warn!(from origin, "Unable to create the heartbeat subscriber ({:?}).", e);
+            let _ = ready.send(Err(
+                NodeLivenessMonitorCreationFailure::UnableToCreateSubscriber,
+            ));
+            return;
+        }
+    };
+
+    // nodes whose heartbeat was not received yet get the timeout starting from the creation of
+    // the monitor since the history of their publisher is delivered with their next heartbeat
+    let monitoring_since = SystemTime::now();
+    let mut last_heartbeats = HashMap::<NodeId, SystemTime>::new();
+    let mut is_first_check = true;
+
+    loop {
+        loop {
+            match subscriber.receive() {
+                Ok(Some(heartbeat)) => {
+                    let node_id = NodeId(heartbeat.node_id);
+                    let timestamp = heartbeat.timestamp();
+                    let last_seen = last_heartbeats.entry(node_id).or_insert(timestamp);
+                    *last_seen = (*last_seen).max(timestamp);
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    warn!(from origin, "Unable to receive the heartbeats ({:?}).", e);
+                    break;
+                }
+            }
+        }
+
+        match Node::<S>::list_with_custom_config(&settings.config) {
+            Ok(nodes) => {
+                let now = SystemTime::now();
+                let current: Vec<(NodeDetails, NodeLiveness)> = nodes
+                    .iter()
+                    .map(|node| {
+                        let last_seen = last_heartbeats.get(&node.details().id()).copied();
+                        (
+                            *node.details(),
+                            evaluate_liveness(
+                                node,
+                                last_seen,
+                                monitoring_since,
+                                now,
+                                settings.missed_heartbeats_until_dead,
+                            ),
+                        )
+                    })
+                    .collect();
+
+                last_heartbeats.retain(|id, _| nodes.iter().any(|n| n.details().id() == *id));
+
+                let previous = std::mem::replace(&mut *lock(&settings.nodes), current.clone());
+                if let Some(callback) = &mut settings.callback {
+                    for (details, liveness) in &current {
+                        if let Some((_, previous_liveness)) =
+                            previous.iter().find(|(d, _)| d.id() == details.id())
+                        {
+                            if previous_liveness != liveness {
+                                callback.call(*details, *previous_liveness, *liveness);
+                            }
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                warn!(from origin, "Unable to list the nodes ({:?}).", e);
+            }
+        }
+
+        if is_first_check {
+            is_first_check = false;
+            let _ = ready.send(Ok(()));
+        }
+
+        if wait_for_stop_request(&stop_request, settings.check_interval) {
+            return;
+        }
+    }
+}
+
+fn evaluate_liveness(
+    node: &NodeState,
+    last_seen: Option<SystemTime>,
+    monitoring_since: SystemTime,
+    now: SystemTime,
+    missed_heartbeats_until_dead: u32,
+) -> NodeLiveness {
+    let details = match node {
+        NodeState::Dead(_) => return NodeLiveness::Dead { last_seen },
+        NodeState::Alive(details) => details,
+    };
+
+    let period = match details.heartbeat_period() {
+        Some(period) => period,
+        None => return NodeLiveness::Unmonitored,
+    };
+
+    let age = now
+        .duration_since(last_seen.unwrap_or(monitoring_since))
+        .unwrap_or_default();
+
+    match age > period * missed_heartbeats_until_dead {
+        true => NodeLiveness::Dead { last_seen },
+        false => NodeLiveness::Alive,
+    }
+}
+
+/// Monitors the [`NodeLiveness`] of all [`Node`]s under a [`config::Config`] in a background
+/// thread that is stopped when the [`NodeLivenessMonitor`] goes out of scope.
+///
+/// # Example
+///
+/// See [`crate::node::liveness`]
+#[derive(Debug)]
+pub struct NodeLivenessMonitor<S: Service> {
+    nodes: Arc<Mutex<Vec<(NodeDetails, NodeLiveness)>>>,
+    _thread: BackgroundThread,
+    _phantom_service: PhantomData<S>,
+}
+
+impl<S: Service> NodeLivenessMonitor<S> {
+    /// Returns the [`NodeDetails`] and the [`NodeLiveness`] of all [`Node`]s that are
+    /// registered in the system, as evaluated in the last check.
+    pub fn nodes(&self) -> Vec<(NodeDetails, NodeLiveness)> {
+        lock(&self.nodes).clone()
+    }
+
+    /// Returns the [`NodeLiveness`] of a specific [`Node`] as evaluated in the last check. If
+    /// the [`Node`] is not registered, it returns [`None`].
+    pub fn liveness(&self, node_id: NodeId) -> Option<NodeLiveness> {
+        lock(&self.nodes)
+            .iter()
+            .find(|(details, _)| details.id() == node_id)
+            .map(|(_, liveness)| *liveness)
+    }
+}
@@ -0,0 +1,572 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ## Create Services Via A Node
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new()
+//!     .name(&NodeName::new("sensor_fusion")?)
+//!     .create::<zero_copy::Service>()?;
+//!
+//! let service = node
+//!     .service_builder(&ServiceName::new("My/Funk/ServiceName")?)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//!
+//! let publisher = service.publisher().create()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Main Loop
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! const CYCLE_TIME: Duration = Duration::from_secs(1);
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! while let Iox2Event::Tick = node.wait(CYCLE_TIME) {
+//!     // your algorithm in here
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## List All Nodes
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::{Node, NodeState};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! for node in Node::<zero_copy::Service>::list()? {
+//!     match node {
+//!         NodeState::Alive(details) => println!("alive: {:?}", details),
+//!         NodeState::Dead(details) => println!("dead: {:?}", details),
+//!     }
+//! }
+//!
+//! // removes the registrations of all nodes whose process does no longer exist
+//! Node::<zero_copy::Service>::cleanup_dead_nodes()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Monitor The Liveness Of Nodes
+//!
+//! See [`crate::node::liveness`]
+
+/// Heartbeats of [`Node`]s and the monitoring of their liveness
+pub mod liveness;
+/// The name of a [`Node`]
+pub mod node_name;
+
+use core::time::Duration;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::process::{Process, ProcessId};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::named_concept::{
+    NamedConceptBuilder, NamedConceptListError, NamedConceptMgmt, NamedConceptRemoveError,
+};
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::static_storage::{
+    StaticStorage, StaticStorageBuilder, StaticStorageCreateError, StaticStorageOpenError,
+};
+use serde::{Deserialize, Serialize as SerdeSerialize};
+
+use crate::config;
+use crate::iox2::{Iox2, Iox2Event};
+use crate::service::config_scheme::node_details_config;
+use crate::service::naming_scheme::node_details_storage_name;
+use crate::service::service_name::ServiceName;
+use crate::service::{builder, Details, Service};
+use crate::zero_copy_send::ZeroCopySend;
+
+use self::liveness::BackgroundThread;
+use self::node_name::NodeName;
+
+type NodeStorage<S> = <<S as Service>::Type<'static> as Details<'static>>::StaticStorage;
+type NodeSerializer<S> = <<S as Service>::Type<'static> as Details<'static>>::ConfigSerializer;
+
+/// Failures that can occur when a [`Node`] is created with [`NodeBuilder::create()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeCreationFailure {
+    InsufficientPermissions,
+    UnableToStartHeartbeat,
+    HeartbeatPeriodIsZero,
+    InternalError,
+}
+
+impl std::fmt::Display for NodeCreationFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for NodeCreationFailure {}
+
+/// Failures that can occur when all [`Node`]s are listed with [`Node::list()`] or
+/// [`Node::list_with_custom_config()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeListFailure {
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for NodeListFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for NodeListFailure {}
+
+/// Failures that can occur when the dead [`Node`]s are removed with
+/// [`Node::cleanup_dead_nodes()`] or [`Node::cleanup_dead_nodes_with_custom_config()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeCleanupFailure {
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for NodeCleanupFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for NodeCleanupFailure {}
+
+/// The system-wide unique id of a [`Node`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(pub(crate) u128);
+
+impl NodeId {
+    /// Returns the underlying value of the [`NodeId`]
+    pub fn value(&self) -> u128 {
+        self.0
+    }
+}
+
+/// The content of the node details storage.
+#[derive(Debug, SerdeSerialize, Deserialize)]
+struct StoredNodeDetails {
+    name: NodeName,
+    pid: i32,
+    #[serde(default)]
+    heartbeat_period: Option<Duration>,
+}
+
+/// Describes a [`Node`] that is registered in the system, acquired with [`Node::list()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeDetails {
+    id: NodeId,
+    name: NodeName,
+    pid: ProcessId,
+    heartbeat_period: Option<Duration>,
+}
+
+impl NodeDetails {
+    /// Returns the [`NodeId`] of the [`Node`]
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    /// Returns the [`NodeName`] of the [`Node`]
+    pub fn name(&self) -> &NodeName {
+        &self.name
+    }
+
+    /// Returns the [`ProcessId`] of the process that owns the [`Node`]
+    pub fn pid(&self) -> ProcessId {
+        self.pid
+    }
+
+    /// Returns the period in which the [`Node`] publishes its heartbeat. If the [`Node`]
+    /// publishes no heartbeat, it returns [`None`].
+    pub fn heartbeat_period(&self) -> Option<Duration> {
+        self.heartbeat_period
+    }
+}
+
+/// The state of a [`Node`] that is registered in the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeState {
+    /// The process that owns the [`Node`] is running.
+    Alive(NodeDetails),
+    /// The process that owns the [`Node`] does no longer exist. Its registration can be removed
+    /// with [`Node::cleanup_dead_nodes()`].
+    Dead(NodeDetails),
+}
+
+impl NodeState {
+    /// Returns the [`NodeDetails`] of the [`Node`]
+    pub fn details(&self) -> &NodeDetails {
+        match self {
+            NodeState::Alive(details) => details,
+            NodeState::Dead(details) => details,
+        }
+    }
+}
+
+/// Creates a [`Node`].
+///
+/// # Example
+///
+/// See [`crate::node`]
+#[derive(Debug, Default)]
+pub struct NodeBuilder {
+    name: NodeName,
+    config: Option<config::Config>,
+    heartbeat_period: Option<Duration>,
+}
+
+impl NodeBuilder {
+    /// Creates a new [`NodeBuilder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`NodeName`] of the [`Node`]. If it is not set, the name is empty.
+    pub fn name(mut self, value: &NodeName) -> Self {
+        self.name = *value;
+        self
+    }
+
+    /// Sets the [`config::Config`] the [`Node`] and all the [`Service`]s created with it use.
+    /// If it is not set, the global config [`config::Config::get_global_config()`] is used.
+    pub fn config(mut self, value: &config::Config) -> Self {
+        self.config = Some(value.clone());
+        self
+    }
+
+    /// Lets the [`Node`] publish a heartbeat with the given period from a background thread,
+    /// so that a [`liveness::NodeLivenessMonitor`] detects when it hangs. The thread is stopped
+    /// when the [`Node`] goes out of scope. By default, no heartbeat is published. A period of
+    /// zero lets the creation fail with [`NodeCreationFailure::HeartbeatPeriodIsZero`].
+    pub fn heartbeat(mut self, period: Duration) -> Self {
+        self.heartbeat_period = Some(period);
+        self
+    }
+
+    /// Creates the [`Node`] and registers it in the system so that other processes can
+    /// discover it with [`Node::list()`].
+    pub fn create<S: Service>(self) -> Result<Node<S>, NodeCreationFailure> {
+        let msg = "Unable to create node";
+        let config = match &self.config {
+            Some(config) => config.clone(),
+            None => config::Config::get_global_config().clone(),
+        };
+
+        if self.heartbeat_period == Some(Duration::ZERO) {
+            fail!(from self, with NodeCreationFailure::HeartbeatPeriodIsZero,
+                "{} since the heartbeat period is zero, the heartbeat would be published continuously.", msg);
+        }
+
+        let id = NodeId(
+            fail!(from self, when UniqueSystemId::new(),
+                with NodeCreationFailure::InternalError,
+                "{} since the unique node id could not be generated.", msg)
+            .value(),
+        );
+
+        let content = fail!(from self, when <NodeSerializer<S> as Serialize>::serialize(&StoredNodeDetails {
+                                name: self.name,
+                                pid: Process::from_self().id().value(),
+                                heartbeat_period: self.heartbeat_period,
+                            }),
+                            with NodeCreationFailure::InternalError,
+                            "{} since the node details could not be serialized.", msg);
+
+        let details_storage =
+            match <<NodeStorage<S> as StaticStorage>::Builder as NamedConceptBuilder<
+                NodeStorage<S>,
+            >>::new(&node_details_storage_name(&id))
+            .config(&node_details_config::<S::Type<'static>>(&config))
+            .has_ownership(true)
+            .create(&content)
+            {
+                Ok(storage) => storage,
+                Err(StaticStorageCreateError::InsufficientPermissions) => {
+                    fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                    "{} due to insufficient permissions to register the node.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with NodeCreationFailure::InternalError,
+                    "{} due to an internal failure ({:?}) while registering the node.", msg, e);
+                }
+            };
+
+        // the node is registered before its first heartbeat so that a monitor never receives
+        // the heartbeat of an unknown node
+        let heartbeat = match self.heartbeat_period {
+            Some(period) => match liveness::start_heartbeat::<S>(&config, id, period) {
+                Some(heartbeat) => Some(heartbeat),
+                None => {
+                    fail!(from self, with NodeCreationFailure::UnableToStartHeartbeat,
+                        "{} since the heartbeat could not be started.", msg);
+                }
+            },
+            None => None,
+        };
+
+        Ok(Node {
+            name: self.name,
+            id,
+            config,
+            _heartbeat: heartbeat,
+            _details_storage: details_storage,
+            _phantom_service: PhantomData,
+        })
+    }
+}
+
+/// Creates [`Service`]s which use the [`config::Config`] of the [`Node`], acquired with
+/// [`Node::service_builder()`].
+#[derive(Debug)]
+pub struct ServiceBuilder<'node, S: Service> {
+    builder: builder::Builder<S>,
+    config: &'node config::Config,
+}
+
+impl<'node, S: Service> ServiceBuilder<'node, S> {
+    /// Create a new builder to create a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`]
+    /// with the payload type `Payload`. When it is omitted, it is inferred from the terminal
+    /// call of the [`builder::publish_subscribe::Builder`].
+    pub fn publish_subscribe<Payload: Debug + ?Sized>(
+        self,
+    ) -> builder::publish_subscribe::Builder<'node, S::Type<'node>, Payload> {
+        self.builder
+            .publish_subscribe_with_custom_config(self.config)
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
+    pub fn event(self) -> builder::event::Builder<'node, S::Type<'node>> {
+        self.builder.event_with_custom_config(self.config)
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
+    pub fn request_response<
+        RequestPayload: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend,
+    >(
+        self,
+    ) -> builder::request_response::Builder<'node, S::Type<'node>, RequestPayload, ResponsePayload>
+    {
+        self.builder
+            .request_response_with_custom_config(self.config)
+    }
+}
+
+/// Groups the [`Service`]s of an application under a common name and owns the
+/// [`config::Config`] they use. As long as the [`Node`] exists it is registered in the system
+/// and can be discovered by other processes with [`Node::list()`], the registration is removed
+/// when it goes out of scope.
+///
+/// # Example
+///
+/// See [`crate::node`]
+#[derive(Debug)]
+pub struct Node<S: Service> {
+    name: NodeName,
+    id: NodeId,
+    config: config::Config,
+    // stops publishing the heartbeat before the registration is removed
+    _heartbeat: Option<BackgroundThread>,
+    _details_storage: NodeStorage<S>,
+    _phantom_service: PhantomData<S>,
+}
+
+impl<S: Service> Node<S> {
+    /// Returns the [`NodeName`] of the [`Node`]
+    pub fn name(&self) -> &NodeName {
+        &self.name
+    }
+
+    /// Returns the system-wide unique [`NodeId`] of the [`Node`]
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    /// Returns the [`config::Config`] the [`Node`] and all its [`Service`]s use
+    pub fn config(&self) -> &config::Config {
+        &self.config
+    }
+
+    /// Returns a [`ServiceBuilder`] to create or open a [`Service`] with the
+    /// [`config::Config`] of the [`Node`]
+    pub fn service_builder(&self, name: &ServiceName) -> ServiceBuilder<'_, S> {
+        ServiceBuilder {
+            builder: S::new(name).node_id(self.id),
+            config: &self.config,
+        }
+    }
+
+    /// Waits for the provided `cycle_time` and returns [`Iox2Event::Tick`]. When the process
+    /// receives [`iceoryx2_bb_posix::signal::FetchableSignal::Interrupt`] or
+    /// [`iceoryx2_bb_posix::signal::FetchableSignal::Terminate`], for instance by pressing
+    /// CTRL+c, it returns early with [`Iox2Event::TerminationRequest`] so that the main loop
+    /// can terminate cleanly. See [`Iox2::wait()`].
+    pub fn wait(&self, cycle_time: Duration) -> Iox2Event {
+        Iox2::wait(cycle_time)
+    }
+
+    /// Returns the [`NodeState`] of all [`Node`]s that are registered in the system.
+    pub fn list() -> Result<Vec<NodeState>, NodeListFailure> {
+        Self::list_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Returns the [`NodeState`] of all [`Node`]s that are registered under a given
+    /// [`config::Config`]. Nodes whose details are corrupted or that are currently being
+    /// created or removed are skipped.
+    pub fn list_with_custom_config(
+        config: &config::Config,
+    ) -> Result<Vec<NodeState>, NodeListFailure> {
+        let origin = "Node::list_with_custom_config()";
+        let msg = "Unable to list all nodes";
+        let storage_config = node_details_config::<S::Type<'static>>(config);
+
+        let node_storages = fail!(from origin,
+                when <NodeStorage<S> as NamedConceptMgmt>::list_cfg(&storage_config),
+                map NamedConceptListError::InsufficientPermissions => NodeListFailure::InsufficientPermissions,
+                unmatched NodeListFailure::InternalError,
+                "{} due to a failure while collecting all node details for config: {:?}", msg, config);
+
+        let mut nodes = vec![];
+        for node_storage in node_storages {
+            let id = match core::str::from_utf8(node_storage.as_bytes())
+                .ok()
+                .and_then(|id| id.parse::<u128>().ok())
+            {
+                Some(id) => NodeId(id),
+                None => {
+                    warn!(from origin, "Skipping node details \"{}\" since its name is not a valid node id.",
+                        node_storage);
+                    continue;
+                }
+            };
+
+            let reader = match <<NodeStorage<S> as StaticStorage>::Builder as NamedConceptBuilder<
+                NodeStorage<S>,
+            >>::new(&node_storage)
+            .config(&storage_config)
+            .has_ownership(false)
+            .open()
+            {
+                Ok(reader) => reader,
+                // the node was removed in the meantime
+                Err(StaticStorageOpenError::DoesNotExist) => continue,
+                Err(e) => {
+                    warn!(from origin, "Unable to open node details \"{}\" for reading ({:?}).",
+                        node_storage, e);
+                    continue;
+                }
+            };
+
+            let mut content = vec![0u8; reader.len() as usize];
+            if let Err(e) = reader.read(content.as_mut_slice()) {
+                warn!(from origin, "Unable to read node details \"{}\" ({:?}).", node_storage, e);
+                continue;
+            }
+
+            let stored_details = match <NodeSerializer<S> as Serialize>::deserialize::<
+                StoredNodeDetails,
+            >(content.as_slice())
+            {
+                Ok(details) => details,
+                Err(e) => {
+                    warn!(from origin, "Unable to deserialize node details \"{}\" ({:?}).",
+                        node_storage, e);
+                    continue;
+                }
+            };
+
+            let details = NodeDetails {
+                id,
+                name: stored_details.name,
+                pid: ProcessId::new(stored_details.pid),
+                heartbeat_period: stored_details.heartbeat_period,
+            };
+
+            nodes.push(match Process::from_pid(details.pid).is_alive() {
+                true => NodeState::Alive(details),
+                false => NodeState::Dead(details),
+            });
+        }
+
+        Ok(nodes)
+    }
+
+    /// Removes the registrations of all [`Node`]s whose process does no longer exist and
+    /// returns how many were removed.
+    pub fn cleanup_dead_nodes() -> Result<usize, NodeCleanupFailure> {
+        Self::cleanup_dead_nodes_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Removes the registrations of all [`Node`]s under a given [`config::Config`] whose process
+    /// does no longer exist and returns how many were removed.
+    pub fn cleanup_dead_nodes_with_custom_config(
+        config: &config::Config,
+    ) -> Result<usize, NodeCleanupFailure> {
+        let origin = "Node::cleanup_dead_nodes_with_custom_config()";
+        let msg = "Unable to cleanup dead nodes";
+        let storage_config = node_details_config::<S::Type<'static>>(config);
+
+        let nodes = fail!(from origin, when Self::list_with_custom_config(config),
+                map NodeListFailure::InsufficientPermissions => NodeCleanupFailure::InsufficientPermissions,
+                unmatched NodeCleanupFailure::InternalError,
+                "{} since the nodes could not be listed.", msg);
+
+        let mut number_of_removed_nodes = 0;
+        for node in nodes {
+            if let NodeState::Dead(details) = node {
+                match unsafe {
+                    <NodeStorage<S> as NamedConceptMgmt>::remove_cfg(
+                        &node_details_storage_name(&details.id),
+                        &storage_config,
+                    )
+                } {
+                    Ok(true) => number_of_removed_nodes += 1,
+                    // another process cleaned up the node in the meantime
+                    Ok(false) => (),
+                    Err(NamedConceptRemoveError::InsufficientPermissions) => {
+                        fail!(from origin, with NodeCleanupFailure::InsufficientPermissions,
+                            "{} due to insufficient permissions to remove the details of node {:?}.",
+                            msg, details);
+                    }
+                    Err(e) => {
+                        fail!(from origin, with NodeCleanupFailure::InternalError,
+                            "{} due to an internal failure ({:?}) while removing the details of node {:?}.",
+                            msg, e, details);
+                    }
+                }
+            }
+        }
+
+        Ok(number_of_removed_nodes)
+    }
+}
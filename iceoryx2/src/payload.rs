@@ -41,7 +41,7 @@ use crate::service::header::publish_subscribe::Header;
 /// It stores the payload and is acquired by the [`crate::port::subscriber::Subscriber`] whenever
 /// it receives new data from a [`crate::port::publisher::Publisher`] via
 /// [`crate::port::subscribe::Subscribe::receive()`].
-pub trait Payload<MessageType> {
+pub trait Payload<MessageType: ?Sized> {
     /// Returns a reference to the payload of the sample
     fn payload(&self) -> &MessageType;
 
@@ -40,9 +40,7 @@
 //! ```
 //! See also, [`crate::sample_mut::SampleMut`].
 
-use crate::{
-    port::update_connections::ConnectionFailure, service::header::publish_subscribe::Header,
-};
+use crate::{port::publish::PublisherSendError, service::header::publish_subscribe::Header};
 
 pub(crate) mod internal {
     use iceoryx2_cal::zero_copy_connection::PointerOffset;
@@ -56,7 +54,7 @@ pub(crate) mod internal {
 /// [`crate::port::publish::DefaultLoan::loan()`]. It stores the payload that will be sent
 /// to all connected [`crate::port::subscriber::Subscriber`]s. If the [`PayloadMut`] is not sent
 /// it will release the loaned memory when going out of scope.
-pub trait PayloadMut<MessageType>: internal::PayloadMgmt {
+pub trait PayloadMut<MessageType: ?Sized>: internal::PayloadMgmt {
     /// Returns a reference to the header of the sample.
     ///
     /// # Example
@@ -145,8 +143,10 @@ pub trait PayloadMut<MessageType>: internal::PayloadMgmt {
     /// for more details.
     ///
     /// On success the number of [`crate::port::subscriber::Subscriber`]s that received
-    /// the data is returned, otherwise a [`ConnectionFailure`] describing the failure.
-    fn send(self) -> Result<usize, ConnectionFailure>;
+    /// the data is returned, otherwise a [`PublisherSendError`] describing the failure. When
+    /// only some subscribers could not be reached the sample is still delivered to all others
+    /// and [`PublisherSendError::PartialDelivery`] lists the subscribers that were missed.
+    fn send(self) -> Result<usize, PublisherSendError>;
 }
 
 /// Acquired by a [`crate::port::publisher::Publisher`] via
@@ -183,6 +183,57 @@ pub trait UninitPayloadMut<MessageType> {
     /// ```
     fn write_payload(self, value: MessageType) -> Self::InitializedSample;
 
+    /// Initializes the payload in place with the provided `initializer` and labels the sample
+    /// as initialized. In contrast to [`UninitPayloadMut::write_payload()`] the payload is never
+    /// constructed on the stack, therefore large payloads can be written directly into the
+    /// shared memory.
+    ///
+    /// If the `initializer` panics the loaned memory is released when the sample is dropped
+    /// during unwinding, the partially initialized payload is never labeled as initialized and
+    /// can therefore not be sent.
+    ///
+    /// # Safety
+    ///
+    /// The `initializer` must fully initialize the provided [`core::mem::MaybeUninit<MessageType>`].
+    /// Sending or reading a sample whose payload was not fully initialized causes undefined
+    /// behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/LargePayload").unwrap();
+    /// #
+    /// const PAYLOAD_SIZE: usize = 4 * 1024 * 1024;
+    ///
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .max_subscribers(1)
+    ///     .history_size(0)
+    ///     .subscriber_max_buffer_size(1)
+    ///     .subscriber_max_borrowed_samples(1)
+    ///     .open_or_create::<[u8; PAYLOAD_SIZE]>()?;
+    ///
+    /// let publisher = service.publisher().max_loaned_samples(1).create()?;
+    ///
+    /// let sample = publisher.loan_uninit()?;
+    /// let sample = unsafe {
+    ///     sample.write_from_fn(|payload| {
+    ///         payload.as_mut_ptr().cast::<u8>().write_bytes(0xff, PAYLOAD_SIZE)
+    ///     })
+    /// };
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    unsafe fn write_from_fn<F: FnOnce(&mut core::mem::MaybeUninit<MessageType>)>(
+        self,
+        initializer: F,
+    ) -> Self::InitializedSample;
+
     /// Extracts the value of the [`core::mem::MaybeUninit<MessageType>`] container and labels the sample as initialized
     ///
     /// # Safety
@@ -0,0 +1,100 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ## Slice Payload
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/SlicePayload").unwrap();
+//! #
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .max_slice_len(128)
+//!     .open_or_create::<[u8]>()?;
+//!
+//! let publisher = service.publisher().create()?;
+//! let subscriber = service.subscriber().create()?;
+//!
+//! let sample = publisher.loan_slice_uninit(3)?;
+//! let sample = sample.write_from_slice(&[1, 2, 3]);
+//! sample.send()?;
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     println!("received: {:?}", sample.payload());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{alloc::Layout, fmt::Debug};
+
+use iceoryx2_bb_log::fatal_panic;
+
+use crate::zero_copy_send::ZeroCopySend;
+
+/// Describes the memory representation of a payload that can be transmitted with a
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based service. It is implemented for every sized type and for slices `[T]` of sized types,
+/// whose number of elements is defined at runtime when the sample is loaned, that implement
+/// [`ZeroCopySend`].
+pub trait PayloadType: Debug + ZeroCopySend {
+    /// Returns the [`Layout`] of a payload that contains at most `max_number_of_elements`
+    /// elements. Sized types ignore the argument.
+    #[doc(hidden)]
+    fn payload_layout(max_number_of_elements: usize) -> Layout;
+
+    /// Returns a pointer with the address `address` whose metadata describes a payload with
+    /// `number_of_elements` elements. Sized types ignore the number of elements.
+    #[doc(hidden)]
+    fn pointer_with_metadata(address: *const u8, number_of_elements: usize) -> *const Self;
+
+    /// Returns the size in bytes of a payload with `number_of_elements` elements or [`None`]
+    /// when it exceeds the address space. Sized types ignore the number of elements.
+    #[doc(hidden)]
+    fn payload_size(number_of_elements: usize) -> Option<usize>;
+}
+
+impl<T: Debug + ZeroCopySend> PayloadType for T {
+    fn payload_layout(_max_number_of_elements: usize) -> Layout {
+        Layout::new::<T>()
+    }
+
+    fn pointer_with_metadata(address: *const u8, _number_of_elements: usize) -> *const Self {
+        address as *const T
+    }
+
+    fn payload_size(_number_of_elements: usize) -> Option<usize> {
+        Some(core::mem::size_of::<T>())
+    }
+}
+
+impl<T: Debug + ZeroCopySend> PayloadType for [T] {
+    fn payload_layout(max_number_of_elements: usize) -> Layout {
+        fatal_panic!(from "PayloadType::payload_layout()",
+            when Layout::array::<T>(max_number_of_elements),
+            "The layout of a slice with {} elements of type \"{}\" exceeds the supported memory size.",
+            max_number_of_elements, std::any::type_name::<T>())
+    }
+
+    fn pointer_with_metadata(address: *const u8, number_of_elements: usize) -> *const Self {
+        core::ptr::slice_from_raw_parts(address as *const T, number_of_elements)
+    }
+
+    fn payload_size(number_of_elements: usize) -> Option<usize> {
+        core::mem::size_of::<T>().checked_mul(number_of_elements)
+    }
+}
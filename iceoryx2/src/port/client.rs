@@ -0,0 +1,824 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/Calculator")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client().create()?;
+//!
+//! // loan some uninitialized memory and send it as request
+//! let request = client.loan_uninit()?;
+//! let request = request.write_payload(6);
+//! let pending_response = request.send()?;
+//!
+//! // send a copy of the value as request
+//! let another_pending_response = client.send_copy(7)?;
+//!
+//! // receive the responses that the servers have sent so far
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("received response {} for request {}", *response, *pending_response);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::alloc::Layout;
+use std::cell::{Cell, RefCell, UnsafeCell};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::Ordering;
+
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
+use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::shared_memory::SharedMemory;
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::*;
+
+use crate::message::Message;
+use crate::port::details::data_segment::DataSegment;
+use crate::port::details::server_connections::ServerConnections;
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::raw_sample::{RawSample, RawSampleMut};
+use crate::service;
+use crate::service::config_scheme::data_segment_config;
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+use crate::service::naming_scheme::client_data_segment_name;
+use crate::service::stale_resources::add_port_to_dynamic_config;
+use crate::service::static_config::request_response::StaticConfig;
+
+/// Describes the failures when a new [`Client`] is created via the
+/// [`crate::service::port_factory::client::PortFactoryClient`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ClientCreateError {
+    ExceedsMaxSupportedClients,
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ClientCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ClientCreateError {}
+
+/// Defines the failures that can occur when a request is loaned with [`Client::loan_uninit()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum ClientLoanError {
+    ExceedsMaxActiveRequests,
+    OutOfMemory,
+    InternalFailure,
+}
+
+impl std::fmt::Display for ClientLoanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ClientLoanError {}
+
+enum_gen! {
+    /// Defines the failures that can occur when a request is sent with [`RequestMut::send()`]
+    /// or [`Client::send_copy()`].
+    RequestSendError
+  mapping:
+    ClientLoanError,
+    ConnectionFailure
+}
+
+impl std::fmt::Display for RequestSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestSendError {}
+
+/// Defines the failures that can occur when a response is received with
+/// [`PendingResponse::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ResponseReceiveError {
+    ExceedsMaxBorrowedResponses,
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl std::fmt::Display for ResponseReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ResponseReceiveError {}
+
+/// The interface the requests and responses use to return their memory to the [`Client`].
+trait ClientMgmt: Debug {
+    fn send_request(&self, offset: PointerOffset) -> Result<usize, ConnectionFailure>;
+    fn release_request(&self, offset: PointerOffset);
+    fn finish_request(&self, request_id: u64);
+    fn receive_response(
+        &self,
+        request_id: u64,
+    ) -> Result<Option<StashedResponse>, ResponseReceiveError>;
+    fn release_response(&self, channel_id: usize, offset: PointerOffset);
+}
+
+/// A response that was received from a server connection and waits until the
+/// [`PendingResponse`] of its request picks it up.
+#[derive(Debug, Clone, Copy)]
+struct StashedResponse {
+    request_id: u64,
+    channel_id: usize,
+    offset: PointerOffset,
+    address: usize,
+}
+
+/// The requesting endpoint of a request-response communication. Every request it sends is
+/// delivered to all connected [`crate::port::server::Server`]s and the responses are received
+/// via the [`PendingResponse`] that is returned on send.
+#[derive(Debug)]
+pub struct Client<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    RequestPayload: Debug,
+    ResponsePayload: Debug,
+> {
+    server_connections: ServerConnections<'config, Service>,
+    server_list_state: UnsafeCell<ContainerState<'a, UniqueServerId>>,
+    data_segment: DataSegment<Service::SharedMemory>,
+    service: &'a Service,
+    request_id_counter: Cell<u64>,
+    // loaned and pending requests, the responses of all other requests are discarded
+    active_request_ids: RefCell<Vec<u64>>,
+    stashed_responses: RefCell<Vec<StashedResponse>>,
+    _dynamic_config_guard: UniqueIndex<'a>,
+    _phantom_request_payload: PhantomData<RequestPayload>,
+    _phantom_response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        RequestPayload: Debug,
+        ResponsePayload: Debug,
+    > Client<'a, 'config, Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        service: &'a Service,
+        static_config: &StaticConfig,
+    ) -> Result<Self, ClientCreateError> {
+        let msg = "Unable to create Client port";
+        let origin = "Client::new()";
+        let port_id = UniqueClientId::new();
+        let server_list = &service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .servers;
+
+        let data_segment = fail!(from origin,
+                when DataSegment::create(
+                    &client_data_segment_name(port_id),
+                    &data_segment_config::<Service>(service.state().global_config),
+                    1,
+                    Layout::new::<Message<RequestHeader, (), RequestPayload>>(),
+                    static_config.required_amount_of_requests_per_data_segment()),
+                with ClientCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        // !MUST! be the last task otherwise a client is added to the dynamic config without the
+        // creation of all required resources
+        let _dynamic_config_guard = match add_port_to_dynamic_config::<Service, _>(
+            service.state().dynamic_storage.get(),
+            service.state().global_config,
+            || {
+                service
+                    .state()
+                    .dynamic_storage
+                    .get()
+                    .request_response()
+                    .add_client_id(port_id)
+            },
+        ) {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with ClientCreateError::ExceedsMaxSupportedClients,
+                            "{} since it would exceed the maximum supported amount of clients of {}.",
+                            msg, static_config.max_clients);
+            }
+        };
+
+        let new_self = Self {
+            server_connections: ServerConnections::new(
+                server_list.capacity(),
+                port_id,
+                service.state().global_config,
+                static_config,
+            ),
+            server_list_state: UnsafeCell::new(unsafe { server_list.get_state() }),
+            data_segment,
+            service,
+            request_id_counter: Cell::new(0),
+            active_request_ids: RefCell::new(vec![]),
+            stashed_responses: RefCell::new(vec![]),
+            _dynamic_config_guard,
+            _phantom_request_payload: PhantomData,
+            _phantom_response_payload: PhantomData,
+        };
+
+        if let Err(e) = new_self.populate_server_channels() {
+            warn!(from new_self, "The new Client port is unable to connect to every Server port, caused by {:?}.", e);
+        }
+
+        Ok(new_self)
+    }
+
+    fn populate_server_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.server_connections.capacity(), None);
+
+        unsafe {
+            (*self.server_list_state.get()).for_each(|index, server_id| {
+                visited_indices[index as usize] = Some(*server_id);
+            })
+        };
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(server_id) => match self.server_connections.create(i, *server_id) {
+                    // the responses of the previous server of the slot are gone with its
+                    // connection
+                    Ok(true) => self
+                        .stashed_responses
+                        .borrow_mut()
+                        .retain(|response| response.channel_id != i),
+                    Ok(false) => (),
+                    Err(e) => {
+                        warn!(from self, "Unable to establish connection to new server {:?}.", server_id);
+                        result = Err(e.into());
+                    }
+                },
+                None => self.server_connections.disconnect(i),
+            }
+        }
+
+        result
+    }
+
+    fn reference_counter(&self, offset: PointerOffset) -> &std::sync::atomic::AtomicU64 {
+        self.data_segment.reference_counter(offset.value())
+    }
+
+    fn retrieve_returned_requests(&self) {
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                loop {
+                    match connection.request_sender.reclaim() {
+                        Ok(Some(offset)) => self.release_request(offset),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim requests from connection {:?} due to {:?}. This may lead to a situation where no more requests will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_active_request(&self, request_id: u64) -> bool {
+        self.active_request_ids.borrow().contains(&request_id)
+    }
+
+    /// Moves all responses that are in the buffers of the connections into the stash, the
+    /// responses of requests that are no longer active are returned right away.
+    fn receive_from_connections(&self) -> Result<(), ResponseReceiveError> {
+        let msg = "Unable to receive another response";
+        for id in 0..self.server_connections.len() {
+            let connection = match self.server_connections.get_mut(id) {
+                Some(connection) => connection,
+                None => continue,
+            };
+
+            loop {
+                match connection.response_receiver.receive() {
+                    Ok(None) => break,
+                    Ok(Some(offset)) => {
+                        let data_segment_start = match connection.data_segment() {
+                            Ok(data_segment) => data_segment.allocator_data_start_address(),
+                            Err(e) => {
+                                // the server has to be able to reclaim the response
+                                if let Err(e) = connection.response_receiver.release(offset) {
+                                    warn!(from self, "Unable to return the response of the unmapped data segment to the server ({:?}).", e);
+                                }
+                                fail!(from self, with ResponseReceiveError::ConnectionFailure(e.into()),
+                                    "{} since the data segment of the server could not be mapped.", msg);
+                            }
+                        };
+
+                        let address = data_segment_start + offset.value();
+                        let header = unsafe { &*(address as *const ResponseHeader) };
+                        if self.is_active_request(header.request_id()) {
+                            self.stashed_responses.borrow_mut().push(StashedResponse {
+                                request_id: header.request_id(),
+                                channel_id: id,
+                                offset,
+                                address,
+                            });
+                        } else if let Err(e) = connection.response_receiver.release(offset) {
+                            warn!(from self, "Unable to return the response of an inactive request to the server ({:?}).", e);
+                        }
+                    }
+                    Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                        fail!(from self, with ResponseReceiveError::ExceedsMaxBorrowedResponses,
+                            "{} since it would exceed the maximum {} of borrowed responses.",
+                            msg, connection.response_receiver.max_borrowed_samples());
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`Client`]
+    pub fn id(&self) -> UniqueClientId {
+        self.server_connections.client_id()
+    }
+
+    /// Returns the instance uuid of the service of the [`Client`], see
+    /// [`crate::service::static_config::StaticConfig::instance_uuid()`].
+    pub fn service_instance_uuid(&self) -> &str {
+        self.service.state().static_config.instance_uuid()
+    }
+
+    /// Returns the number of requests that are currently active, loaned requests and
+    /// [`PendingResponse`]s are both counted. When it reaches
+    /// [`StaticConfig::max_active_requests()`] no further request can be loaned.
+    pub fn number_of_active_requests(&self) -> usize {
+        self.active_request_ids.borrow().len()
+    }
+
+    /// Loans an uninitialized request that can be written and sent to all connected
+    /// [`crate::port::server::Server`]s. Fails when it would exceed the maximum of
+    /// [`StaticConfig::max_active_requests()`].
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<RequestMut<'_, MaybeUninit<RequestPayload>, ResponsePayload>, ClientLoanError> {
+        self.retrieve_returned_requests();
+        let msg = "Unable to loan request";
+
+        let max_active_requests = self
+            .service
+            .state()
+            .static_config
+            .request_response()
+            .max_active_requests;
+        if self.number_of_active_requests() >= max_active_requests {
+            fail!(from self, with ClientLoanError::ExceedsMaxActiveRequests,
+                "{} since already {} requests are active and it would exceed the maximum of {} active requests. Drop a loaned request or a pending response to loan another request.",
+                msg, self.number_of_active_requests(), max_active_requests);
+        }
+
+        let chunk = match self
+            .data_segment
+            .memory
+            .allocate(self.data_segment.sample_layout)
+        {
+            Ok(chunk) => chunk,
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with ClientLoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ClientLoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        };
+
+        if self
+            .reference_counter(chunk.offset)
+            .fetch_add(1, Ordering::Relaxed)
+            != 0
+        {
+            fatal_panic!(from self,
+                "{} since the allocated request is already in use! This should never happen!", msg);
+        }
+
+        let request_id = self.request_id_counter.get();
+        self.request_id_counter.set(request_id.wrapping_add(1));
+
+        let message =
+            chunk.data_ptr as *mut Message<RequestHeader, (), MaybeUninit<RequestPayload>>;
+        unsafe {
+            core::ptr::addr_of_mut!((*message).header)
+                .write(RequestHeader::new(self.id(), request_id))
+        };
+        self.active_request_ids.borrow_mut().push(request_id);
+
+        Ok(RequestMut {
+            client: self,
+            offset: chunk.offset,
+            ptr: unsafe { RawSampleMut::new_unchecked(message) },
+            _phantom_response_payload: PhantomData,
+        })
+    }
+
+    /// Copies the value into a loaned request and sends it to all connected
+    /// [`crate::port::server::Server`]s.
+    pub fn send_copy(
+        &self,
+        value: RequestPayload,
+    ) -> Result<PendingResponse<'_, RequestPayload, ResponsePayload>, RequestSendError> {
+        let msg = "Unable to send copy of request";
+        let request = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a request failed.", msg);
+
+        request.write_payload(value).send()
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        RequestPayload: Debug,
+        ResponsePayload: Debug,
+    > UpdateConnections for Client<'a, 'config, Service, RequestPayload, ResponsePayload>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe { (*self.server_list_state.get()).update() } {
+            fail!(from self, when self.populate_server_channels(),
+                "Connections were updated only partially since at least one connection to a server failed.");
+        }
+
+        Ok(())
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        RequestPayload: Debug,
+        ResponsePayload: Debug,
+    > ClientMgmt for Client<'a, 'config, Service, RequestPayload, ResponsePayload>
+{
+    fn send_request(&self, offset: PointerOffset) -> Result<usize, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+            "Unable to send request since the connections could not be updated.");
+        self.retrieve_returned_requests();
+
+        let mut number_of_recipients = 0;
+        for i in 0..self.server_connections.len() {
+            let connection = match self.server_connections.get(i) {
+                Some(connection) if !connection.is_server_disconnected => connection,
+                _ => continue,
+            };
+
+            match connection.request_sender.try_send(offset) {
+                Ok(overflow) => {
+                    self.reference_counter(offset)
+                        .fetch_add(1, Ordering::Relaxed);
+                    number_of_recipients += 1;
+
+                    if let Some(old) = overflow {
+                        self.release_request(old)
+                    }
+                }
+                Err(ZeroCopySendError::ReceiveBufferFull) => {
+                    warn!(from self, "Unable to deliver the request to server {:?} since its buffer is full.", connection.server_id);
+                }
+                Err(ZeroCopySendError::ClearRetrieveChannelBeforeSend) => {
+                    warn!(from self, "Unable to deliver the request to server {:?} since the retrieve buffer is full. This can be caused by a corrupted retrieve channel.", connection.server_id);
+                }
+            }
+        }
+
+        Ok(number_of_recipients)
+    }
+
+    fn release_request(&self, offset: PointerOffset) {
+        if self
+            .reference_counter(offset)
+            .fetch_sub(1, Ordering::Relaxed)
+            == 1
+        {
+            unsafe {
+                fatal_panic!(from self, when self.data_segment.memory.deallocate(offset, self.data_segment.sample_layout),
+                    "Internal logic error. The request should always contain a valid memory chunk from the provided allocator.");
+            };
+        }
+    }
+
+    fn finish_request(&self, request_id: u64) {
+        self.active_request_ids
+            .borrow_mut()
+            .retain(|id| *id != request_id);
+
+        let mut stashed_responses = self.stashed_responses.borrow_mut();
+        for response in stashed_responses
+            .iter()
+            .filter(|response| response.request_id == request_id)
+        {
+            self.release_response(response.channel_id, response.offset);
+        }
+        stashed_responses.retain(|response| response.request_id != request_id);
+    }
+
+    fn receive_response(
+        &self,
+        request_id: u64,
+    ) -> Result<Option<StashedResponse>, ResponseReceiveError> {
+        if let Err(e) = self.update_connections() {
+            fail!(from self, with ResponseReceiveError::ConnectionFailure(e),
+                "Some responses are not being received since not all connections to servers could be established.");
+        }
+
+        self.receive_from_connections()?;
+
+        let mut stashed_responses = self.stashed_responses.borrow_mut();
+        Ok(stashed_responses
+            .iter()
+            .position(|response| response.request_id == request_id)
+            .map(|index| stashed_responses.remove(index)))
+    }
+
+    fn release_response(&self, channel_id: usize, offset: PointerOffset) {
+        match self.server_connections.get(channel_id) {
+            Some(c) => match c.response_receiver.release(offset) {
+                Ok(()) => (),
+                Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                    fatal_panic!(from self, when c.response_receiver.release(offset),
+                        "This should never happen! The servers retrieve channel is full and the response cannot be returned.");
+                }
+            },
+            None => {
+                warn!(from self, "Unable to release response since the connection is broken. The response will be discarded and has to be reclaimed manually by the server.");
+            }
+        }
+    }
+}
+
+/// A request that was loaned with [`Client::loan_uninit()`]. Its payload has to be written
+/// before it can be sent with [`RequestMut::send()`]. When it goes out of scope without being
+/// sent its memory is returned to the [`Client`].
+#[derive(Debug)]
+pub struct RequestMut<'client, RequestPayload: Debug, ResponsePayload: Debug> {
+    client: &'client dyn ClientMgmt,
+    offset: PointerOffset,
+    ptr: RawSampleMut<RequestHeader, (), RequestPayload>,
+    _phantom_response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<RequestPayload: Debug, ResponsePayload: Debug> Drop
+    for RequestMut<'_, RequestPayload, ResponsePayload>
+{
+    fn drop(&mut self) {
+        self.client
+            .finish_request(self.ptr.as_header_ref().request_id());
+        self.client.release_request(self.offset);
+    }
+}
+
+impl<RequestPayload: Debug, ResponsePayload: Debug>
+    RequestMut<'_, RequestPayload, ResponsePayload>
+{
+    /// Returns a reference to the [`RequestHeader`] of the request
+    pub fn header(&self) -> &RequestHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the request
+    pub fn payload_mut(&mut self) -> &mut RequestPayload {
+        self.ptr.as_data_mut()
+    }
+}
+
+impl<'client, RequestPayload: Debug, ResponsePayload: Debug>
+    RequestMut<'client, MaybeUninit<RequestPayload>, ResponsePayload>
+{
+    /// Writes the payload into the request and returns the initialized request.
+    pub fn write_payload(
+        mut self,
+        value: RequestPayload,
+    ) -> RequestMut<'client, RequestPayload, ResponsePayload> {
+        self.payload_mut().write(value);
+        // SAFETY: the payload was initialized in the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Converts the request into an initialized request.
+    ///
+    /// # Safety
+    ///
+    /// The payload must be initialized with [`RequestMut::payload_mut()`] beforehand.
+    pub unsafe fn assume_init(self) -> RequestMut<'client, RequestPayload, ResponsePayload> {
+        let request = RequestMut {
+            client: self.client,
+            offset: self.offset,
+            ptr: RawSampleMut::from_header_and_payload_ptrs(
+                self.ptr.as_headers_mut_ptr(),
+                self.ptr.as_data_mut_ptr() as *mut RequestPayload,
+            ),
+            _phantom_response_payload: PhantomData,
+        };
+        // the ownership of the memory chunk is transferred to the initialized request
+        std::mem::forget(self);
+        request
+    }
+}
+
+impl<'client, RequestPayload: Debug, ResponsePayload: Debug>
+    RequestMut<'client, RequestPayload, ResponsePayload>
+{
+    /// Sends the request to all connected [`crate::port::server::Server`]s and returns a
+    /// [`PendingResponse`] to receive their responses.
+    pub fn send(
+        self,
+    ) -> Result<PendingResponse<'client, RequestPayload, ResponsePayload>, RequestSendError> {
+        let number_of_server_connections = fail!(from self, when self.client.send_request(self.offset),
+            "Unable to send the request.");
+
+        let pending_response = PendingResponse {
+            client: self.client,
+            offset: self.offset,
+            ptr: unsafe {
+                RawSample::from_header_and_payload_ptrs(
+                    self.ptr.as_headers_mut_ptr(),
+                    self.ptr.as_data_mut_ptr(),
+                )
+            },
+            number_of_server_connections,
+            _phantom_response_payload: PhantomData,
+        };
+        // the request stays active and its memory chunk is owned by the pending response
+        std::mem::forget(self);
+        Ok(pending_response)
+    }
+}
+
+impl<RequestPayload: Debug, ResponsePayload: Debug> Deref
+    for RequestMut<'_, RequestPayload, ResponsePayload>
+{
+    type Target = RequestPayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
+
+impl<RequestPayload: Debug, ResponsePayload: Debug> DerefMut
+    for RequestMut<'_, RequestPayload, ResponsePayload>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.ptr.as_data_mut()
+    }
+}
+
+/// Represents a sent request and receives the responses of all
+/// [`crate::port::server::Server`]s for it. As long as it exists the request stays active,
+/// when it goes out of scope the servers stop to deliver responses to it and responses that
+/// were not received yet are discarded.
+#[derive(Debug)]
+pub struct PendingResponse<'client, RequestPayload: Debug, ResponsePayload: Debug> {
+    client: &'client dyn ClientMgmt,
+    offset: PointerOffset,
+    ptr: RawSample<RequestHeader, (), RequestPayload>,
+    number_of_server_connections: usize,
+    _phantom_response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<RequestPayload: Debug, ResponsePayload: Debug> Drop
+    for PendingResponse<'_, RequestPayload, ResponsePayload>
+{
+    fn drop(&mut self) {
+        self.ptr.as_header_ref().deactivate();
+        self.client
+            .finish_request(self.ptr.as_header_ref().request_id());
+        self.client.release_request(self.offset);
+    }
+}
+
+impl<'client, RequestPayload: Debug, ResponsePayload: Debug>
+    PendingResponse<'client, RequestPayload, ResponsePayload>
+{
+    /// Returns the id of the request, the [`ResponseHeader::request_id()`] of all responses
+    /// that are received for it have the same id.
+    pub fn request_id(&self) -> u64 {
+        self.header().request_id()
+    }
+
+    /// Returns a reference to the [`RequestHeader`] of the sent request
+    pub fn header(&self) -> &RequestHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the payload of the sent request
+    pub fn payload(&self) -> &RequestPayload {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns the number of [`crate::port::server::Server`]s the request was delivered to
+    pub fn number_of_server_connections(&self) -> usize {
+        self.number_of_server_connections
+    }
+
+    /// Receives the next [`Response`] to the request. If currently no response is available
+    /// [`None`] is returned. The responses of one [`crate::port::server::Server`] are
+    /// received in the order they were sent.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<Response<'client, ResponsePayload>>, ResponseReceiveError> {
+        let response = fail!(from self, when self.client.receive_response(self.request_id()),
+            "Unable to receive a response for the request {}.", self.request_id());
+
+        Ok(response.map(|response| Response {
+            client: self.client,
+            channel_id: response.channel_id,
+            offset: response.offset,
+            ptr: unsafe {
+                RawSample::new_unchecked(
+                    response.address as *const Message<ResponseHeader, (), ResponsePayload>,
+                )
+            },
+        }))
+    }
+}
+
+impl<RequestPayload: Debug, ResponsePayload: Debug> Deref
+    for PendingResponse<'_, RequestPayload, ResponsePayload>
+{
+    type Target = RequestPayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
+
+/// A response of a [`crate::port::server::Server`] that was received via
+/// [`PendingResponse::receive()`]. The memory is returned to the server when it goes out of
+/// scope.
+#[derive(Debug)]
+pub struct Response<'client, ResponsePayload: Debug> {
+    client: &'client dyn ClientMgmt,
+    channel_id: usize,
+    offset: PointerOffset,
+    ptr: RawSample<ResponseHeader, (), ResponsePayload>,
+}
+
+impl<ResponsePayload: Debug> Drop for Response<'_, ResponsePayload> {
+    fn drop(&mut self) {
+        self.client.release_response(self.channel_id, self.offset);
+    }
+}
+
+impl<ResponsePayload: Debug> Response<'_, ResponsePayload> {
+    /// Returns a reference to the [`ResponseHeader`] of the response
+    pub fn header(&self) -> &ResponseHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the payload of the response
+    pub fn payload(&self) -> &ResponsePayload {
+        self.ptr.as_data_ref()
+    }
+}
+
+impl<ResponsePayload: Debug> Deref for Response<'_, ResponsePayload> {
+    type Target = ResponsePayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
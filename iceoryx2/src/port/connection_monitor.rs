@@ -0,0 +1,389 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::connection_monitor::ConnectionTransition;
+//! use iceoryx2::waitset::WaitSetBuilder;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service = zero_copy::Service::new(&ServiceName::new("My/Funk/ServiceName")?)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//!
+//! let publisher = service.publisher().create()?;
+//! let mut monitor = publisher.connection_monitor()?;
+//!
+//! let waitset = WaitSetBuilder::new().create()?;
+//! let _monitor_guard = waitset.attach(&monitor)?;
+//!
+//! let subscriber = service.subscriber().create()?;
+//!
+//! waitset.wait_and_process(|_| {
+//!     if monitor.try_wait() == Ok(Some(ConnectionTransition::Connected)) {
+//!         // someone is listening, the expensive sample is produced
+//!         publisher.send_copy(1234).expect("send successful");
+//!     }
+//! })?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See also [`ConnectionMonitor`]
+
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, trace};
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_bb_posix::signal::SignalHandler;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::event::{
+    Event, Listener, ListenerBuilder, ListenerWaitError, Notifier, NotifierBuilder,
+};
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptMgmt};
+
+use crate::config;
+use crate::port::event_id::EventId;
+use crate::service::config_scheme::connection_monitor_event_config;
+use crate::service::naming_scheme::connection_monitor_event_concept_name;
+use crate::service::Details;
+
+/// Describes how the number of connected counterparts of a port changed, see
+/// [`ConnectionMonitor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConnectionTransition {
+    /// The first counterpart connected, the number of counterparts went from zero to non-zero.
+    Connected,
+    /// The last counterpart disconnected, the number of counterparts went from non-zero to zero.
+    Disconnected,
+}
+
+enum_gen! {
+    /// Defines the failures that can occur when a [`ConnectionMonitor`] is created with
+    /// [`crate::port::publisher::Publisher::connection_monitor()`] or
+    /// [`crate::port::subscriber::Subscriber::connection_monitor()`].
+    ConnectionMonitorCreateError
+  entry:
+    UnableToCreateNotificationListener,
+    UnableToAcquireSystemId
+}
+
+impl std::fmt::Display for ConnectionMonitorCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ConnectionMonitorCreateError {}
+
+/// Defines the failures that can occur while a [`ConnectionMonitor`] waits for a
+/// [`ConnectionTransition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConnectionMonitorWaitError {
+    InternalFailure,
+}
+
+impl std::fmt::Display for ConnectionMonitorWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ConnectionMonitorWaitError {}
+
+/// Defines the failures that can occur while a port waits for a minimum number of
+/// counterparts with [`crate::port::publisher::Publisher::wait_for_subscribers()`] or
+/// [`crate::port::subscriber::Subscriber::wait_for_publishers()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WaitForConnectionsError {
+    /// The minimum number of counterparts was not reached before the timeout has passed.
+    Timeout,
+    /// The process of a counterpart died while waiting, its port is no longer counted.
+    CounterpartDied,
+    /// The termination of the process was requested by a signal while waiting.
+    InterruptSignal,
+    /// The [`ConnectionMonitor`] the wait is based on could not be created or failed.
+    InternalFailure,
+}
+
+impl std::fmt::Display for WaitForConnectionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitForConnectionsError {}
+
+/// Reports when the number of counterparts of a port, the
+/// [`crate::port::subscriber::Subscriber`]s of a [`crate::port::publisher::Publisher`] or the
+/// [`crate::port::publisher::Publisher`]s of a [`crate::port::subscriber::Subscriber`],
+/// transitions between zero and non-zero.
+///
+/// Every port of the service wakes the monitor up when it is created or removed, in this or
+/// in any other process, and so does the stale resource cleanup when it removes the ports of
+/// a dead process. The monitor can be attached to a [`crate::waitset::WaitSet`] whenever
+/// the underlying event concept is based on a file descriptor, like the one of
+/// [`crate::service::zero_copy::Service`].
+///
+/// A process that dies cannot announce the removal of its ports, they are no longer counted
+/// as soon as the process is gone but the monitor recognizes it only with the next wake up or
+/// the next call of [`ConnectionMonitor::try_wait()`]. Use [`ConnectionMonitor::timed_wait()`]
+/// or an interval of the [`crate::waitset::WaitSet`] to detect it in time.
+pub struct ConnectionMonitor<'port, 'config, Service: Details<'config>> {
+    listener: <Service::Event as Event<EventId>>::Listener,
+    number_of_connections: Box<dyn Fn() -> usize + 'port>,
+    is_connected: bool,
+}
+
+impl<'config, Service: Details<'config>> Debug for ConnectionMonitor<'_, 'config, Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ConnectionMonitor<{}> {{ listener: {:?}, is_connected: {} }}",
+            core::any::type_name::<Service>(),
+            self.listener,
+            self.is_connected
+        )
+    }
+}
+
+impl<'port, 'config, Service: Details<'config>> ConnectionMonitor<'port, 'config, Service> {
+    pub(crate) fn new<F: Fn() -> usize + 'port>(
+        config: &config::Config,
+        service_uuid: &str,
+        number_of_connections: F,
+    ) -> Result<Self, ConnectionMonitorCreateError> {
+        let msg = "Unable to create the connection monitor";
+        let origin = "ConnectionMonitor::new()";
+
+        let id = fail!(from origin, when UniqueSystemId::new(),
+            with ConnectionMonitorCreateError::UnableToAcquireSystemId,
+            "{} since no unique system id could be acquired.", msg);
+
+        let event_name = connection_monitor_event_concept_name(service_uuid, id);
+        let listener = fail!(from origin,
+            when <Service::Event as Event<EventId>>::ListenerBuilder::new(&event_name)
+                .config(&connection_monitor_event_config::<Service>(config))
+                .create(),
+            with ConnectionMonitorCreateError::UnableToCreateNotificationListener,
+            "{} since the underlying event concept \"{}\" could not be created.", msg, event_name);
+
+        // the listener exists before the connections are counted the first time, every change
+        // afterwards is announced to it
+        let is_connected = number_of_connections() > 0;
+
+        Ok(Self {
+            listener,
+            number_of_connections: Box::new(number_of_connections),
+            is_connected,
+        })
+    }
+
+    /// Returns true when at least one counterpart was connected when the
+    /// [`ConnectionMonitor`] was created or, afterwards, when it was checked the last time.
+    pub fn is_connected(&self) -> bool {
+        self.is_connected
+    }
+
+    /// Returns the [`ConnectionTransition`] since the last call without waiting. When the
+    /// number of counterparts did not transition between zero and non-zero it returns
+    /// [`None`].
+    pub fn try_wait(&mut self) -> Result<Option<ConnectionTransition>, ConnectionMonitorWaitError> {
+        while let Ok(Some(_)) = self.listener.try_wait() {}
+
+        let is_connected = (self.number_of_connections)() > 0;
+        if is_connected == self.is_connected {
+            return Ok(None);
+        }
+
+        self.is_connected = is_connected;
+        match is_connected {
+            true => Ok(Some(ConnectionTransition::Connected)),
+            false => Ok(Some(ConnectionTransition::Disconnected)),
+        }
+    }
+
+    /// Waits until the number of counterparts transitioned between zero and non-zero or the
+    /// timeout has passed. On timeout it returns [`None`].
+    pub fn timed_wait(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<Option<ConnectionTransition>, ConnectionMonitorWaitError> {
+        self.wait(Some(Instant::now() + timeout))
+    }
+
+    /// Waits until the number of counterparts transitioned between zero and non-zero.
+    pub fn blocking_wait(&mut self) -> Result<ConnectionTransition, ConnectionMonitorWaitError> {
+        match self.wait(None)? {
+            Some(transition) => Ok(transition),
+            None => {
+                fail!(from self, with ConnectionMonitorWaitError::InternalFailure,
+                    "Unable to wait for a connection transition since the wait returned without a transition.");
+            }
+        }
+    }
+
+    /// Waits until at least `min` counterparts are connected and returns their number.
+    /// `counterparts` returns the ids of all counterparts registered in the service. A
+    /// counterpart that was alive while waiting died when its process is gone afterwards, even
+    /// when its port was already removed by the stale resource cleanup.
+    ///
+    /// The wait is only woken up by the ports of the service and by the stale resource cleanup
+    /// when it removes the ports of a dead process. A dead process that is not yet cleaned up is
+    /// recognized with the next wake up, at the latest when the timeout has passed.
+    pub(crate) fn wait_for_connections<F: Fn() -> Vec<UniqueSystemId>>(
+        &mut self,
+        min: usize,
+        timeout: Duration,
+        counterparts: F,
+    ) -> Result<usize, WaitForConnectionsError> {
+        let msg = "Unable to wait for the connections";
+        let deadline = Instant::now() + timeout;
+        let is_alive = |id: &UniqueSystemId| Process::from_pid(id.pid()).is_alive();
+        let mut live_counterparts: Vec<UniqueSystemId> = vec![];
+
+        loop {
+            for id in counterparts() {
+                if !live_counterparts.contains(&id) && is_alive(&id) {
+                    live_counterparts.push(id);
+                }
+            }
+
+            let number_of_connections = (self.number_of_connections)();
+            if number_of_connections >= min {
+                return Ok(number_of_connections);
+            }
+
+            if live_counterparts.iter().any(|id| !is_alive(id)) {
+                fail!(from self, with WaitForConnectionsError::CounterpartDied,
+                    "{} since the process of a counterpart died while waiting for {} connections.", msg, min);
+            }
+
+            let remaining = match deadline.checked_duration_since(Instant::now()) {
+                Some(remaining) if !remaining.is_zero() => remaining,
+                _ => {
+                    fail!(from self, with WaitForConnectionsError::Timeout,
+                        "{} since only {} of {} counterparts connected within the timeout of {:?}.",
+                        msg, number_of_connections, min, timeout);
+                }
+            };
+
+            // a signal that does not request the termination, like the one of a terminated child
+            // process, is handled like any other wake up
+            match self.listener.timed_wait(remaining) {
+                Ok(_) => (),
+                Err(ListenerWaitError::InterruptSignal)
+                    if !SignalHandler::termination_requested() => {}
+                Err(ListenerWaitError::InterruptSignal) => {
+                    fail!(from self, with WaitForConnectionsError::InterruptSignal,
+                        "{} since the termination was requested by a signal.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with WaitForConnectionsError::InternalFailure,
+                        "{} since the underlying listener failed ({:?}).", msg, e);
+                }
+            }
+            while let Ok(Some(_)) = self.listener.try_wait() {}
+        }
+    }
+
+    fn wait(
+        &mut self,
+        deadline: Option<Instant>,
+    ) -> Result<Option<ConnectionTransition>, ConnectionMonitorWaitError> {
+        let msg = "Unable to wait for a connection transition";
+
+        loop {
+            if let Some(transition) = self.try_wait()? {
+                return Ok(Some(transition));
+            }
+
+            let wait_result = match deadline {
+                None => self.listener.blocking_wait(),
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(timeout) if !timeout.is_zero() => self.listener.timed_wait(timeout),
+                    _ => return Ok(None),
+                },
+            };
+
+            if let Err(e) = wait_result {
+                fail!(from self, with ConnectionMonitorWaitError::InternalFailure,
+                    "{} since the underlying listener failed ({:?}).", msg, e);
+            }
+        }
+    }
+}
+
+impl<'config, Service: Details<'config>> FileDescriptorBased
+    for ConnectionMonitor<'_, 'config, Service>
+where
+    <Service::Event as Event<EventId>>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+// A connection monitor can be attached to a [`crate::waitset::WaitSet`] whenever the underlying
+// event concept is based on a file descriptor.
+impl<'config, Service: Details<'config>> SynchronousMultiplexing
+    for ConnectionMonitor<'_, 'config, Service>
+where
+    <Service::Event as Event<EventId>>::Listener: SynchronousMultiplexing,
+{
+}
+
+/// Wakes up every [`ConnectionMonitor`] of the service with the given uuid. It is called
+/// whenever a publisher or subscriber is added to or removed from the service.
+pub(crate) fn announce_connection_change<'config, Service: Details<'config>>(
+    config: &config::Config,
+    service_uuid: &str,
+) {
+    let origin = "announce_connection_change()";
+    let event_config = connection_monitor_event_config::<Service>(config);
+
+    let monitors = match <Service::Event as NamedConceptMgmt>::list_cfg(&event_config) {
+        Ok(monitors) => monitors,
+        Err(e) => {
+            trace!(from origin, "Unable to list the connection monitors ({:?}).", e);
+            return;
+        }
+    };
+
+    for monitor in monitors
+        .iter()
+        .filter(|m| m.as_bytes().starts_with(service_uuid.as_bytes()))
+    {
+        // a monitor that is removed in the meantime does not have to be informed
+        match <Service::Event as Event<EventId>>::NotifierBuilder::new(monitor)
+            .config(&event_config)
+            .open()
+        {
+            Ok(notifier) => {
+                if let Err(e) = notifier.notify(EventId::new(0)) {
+                    trace!(from origin, "Unable to wake up the connection monitor \"{}\" ({:?}).", monitor, e);
+                }
+            }
+            Err(e) => {
+                trace!(from origin, "Unable to open the connection monitor \"{}\" ({:?}).", monitor, e);
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Detects the misuse of the chunks of a publisher in debug builds. Every chunk contains a
+//! canary word in front of the sample and one directly behind its payload, they are verified
+//! when the chunk is returned to the allocator. Afterwards the sample is overwritten with
+//! [`POISON_PATTERN`] so that a sample that is used after its release does not contain
+//! plausible data. The [`UsedChunkList`] tracks the loaned chunks and detects chunks that are
+//! sent or returned twice.
+//!
+//! In release builds the chunk contains only the sample and all checks compile to nothing.
+//! The offsets the subscribers receive always point to the sample, therefore processes of
+//! both build types can communicate with each other.
+
+use std::alloc::Layout;
+use std::fmt::Debug;
+
+#[cfg(debug_assertions)]
+use std::cell::RefCell;
+
+#[cfg(debug_assertions)]
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_cal::zero_copy_connection::PointerOffset;
+
+/// The pattern a sample is overwritten with when its chunk is returned to the allocator in
+/// debug builds.
+#[cfg(debug_assertions)]
+pub(crate) const POISON_PATTERN: u8 = 0xDE;
+
+#[cfg(debug_assertions)]
+const CANARY: u64 = 0x1CE0_C0DE_CAFE_F00D;
+#[cfg(debug_assertions)]
+const CANARY_SIZE: usize = core::mem::size_of::<u64>();
+
+/// Returns the layout of a chunk that holds a sample with the given layout. In debug builds
+/// the chunk is extended by the canary in front of the sample and the one behind the payload.
+pub(crate) fn chunk_layout(sample_layout: Layout) -> Layout {
+    #[cfg(debug_assertions)]
+    {
+        let size = sample_offset(sample_layout.align()) + sample_layout.size() + CANARY_SIZE;
+        match Layout::from_size_align(size, sample_layout.align()) {
+            Ok(layout) => layout.pad_to_align(),
+            Err(_) => {
+                fatal_panic!(from "chunk_guard::chunk_layout()",
+                    "This should never happen! The sample layout {:?} cannot be extended by the canaries.",
+                    sample_layout)
+            }
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    {
+        sample_layout
+    }
+}
+
+/// Returns the distance from the start of a chunk to its sample. The front canary is
+/// placed in front of the sample, the distance is a multiple of the alignment so that the
+/// sample stays aligned.
+pub(crate) fn sample_offset(_alignment: usize) -> usize {
+    #[cfg(debug_assertions)]
+    {
+        CANARY_SIZE.max(_alignment)
+    }
+
+    #[cfg(not(debug_assertions))]
+    {
+        0
+    }
+}
+
+/// Writes the canaries of a freshly loaned sample.
+///
+/// # Safety
+///
+///  * `sample` must point to the sample of a chunk with the [`chunk_layout()`]
+///  * `payload_end` is the distance from the sample to the end of its payload and must not
+///    exceed the size of the sample
+pub(crate) unsafe fn arm(_sample: *mut u8, _payload_end: usize) {
+    #[cfg(debug_assertions)]
+    {
+        (_sample.sub(CANARY_SIZE) as *mut u64).write_unaligned(CANARY);
+        (_sample.add(_payload_end) as *mut u64).write_unaligned(CANARY);
+    }
+}
+
+/// Verifies the canaries of a sample before its chunk is returned to the allocator and
+/// overwrites the sample with the [`POISON_PATTERN`]. It panics with the offset of the chunk
+/// when a canary was overwritten.
+///
+/// # Safety
+///
+///  * the sample must have been armed with [`arm()`] and the same `payload_end`
+///  * `sample_size` must be the size of the sample the chunk was created for
+///  * the sample must not be referenced by anyone anymore
+pub(crate) unsafe fn verify_and_poison<T: Debug>(
+    _sample: *mut u8,
+    _payload_end: usize,
+    _sample_size: usize,
+    _offset: PointerOffset,
+    _origin: &T,
+) {
+    #[cfg(debug_assertions)]
+    {
+        if (_sample.sub(CANARY_SIZE) as *const u64).read_unaligned() != CANARY {
+            fatal_panic!(from _origin,
+                "The canary in front of the sample {:?} is corrupted, the memory in front of the sample was overwritten.",
+                _offset);
+        }
+
+        if (_sample.add(_payload_end) as *const u64).read_unaligned() != CANARY {
+            fatal_panic!(from _origin,
+                "The canary behind the payload of the sample {:?} is corrupted, the payload was overrun.",
+                _offset);
+        }
+
+        core::ptr::write_bytes(_sample, POISON_PATTERN, _sample_size);
+    }
+}
+
+/// Tracks the chunks a publisher has loaned to the user in debug builds. A chunk that is
+/// sent or returned although it is not loaned, since it was already returned before, causes
+/// a panic with its offset. In release builds it is empty and every call compiles to nothing.
+#[derive(Debug)]
+pub(crate) struct UsedChunkList {
+    #[cfg(debug_assertions)]
+    chunks: RefCell<Vec<usize>>,
+}
+
+impl UsedChunkList {
+    pub(crate) fn new(_capacity: usize) -> Self {
+        Self {
+            #[cfg(debug_assertions)]
+            chunks: RefCell::new(Vec::with_capacity(_capacity)),
+        }
+    }
+
+    /// Adds a loaned chunk.
+    pub(crate) fn insert<T: Debug>(&self, _offset: PointerOffset, _origin: &T) {
+        #[cfg(debug_assertions)]
+        {
+            let mut chunks = self.chunks.borrow_mut();
+            if chunks.contains(&_offset.value()) {
+                fatal_panic!(from _origin,
+                    "The chunk {:?} is loaned although it is already in use.", _offset);
+            }
+            chunks.push(_offset.value());
+        }
+    }
+
+    /// Panics when the chunk is not loaned.
+    pub(crate) fn verify_contains<T: Debug>(&self, _offset: PointerOffset, _origin: &T) {
+        #[cfg(debug_assertions)]
+        if !self.chunks.borrow().contains(&_offset.value()) {
+            fatal_panic!(from _origin,
+                "The sample {:?} is sent although it is not loaned, it was already sent or returned.",
+                _offset);
+        }
+    }
+
+    /// Removes a loaned chunk when it is returned, panics when the chunk is not loaned.
+    /// Returns false when the chunk is not loaned while the thread unwinds, the chunk must not
+    /// be released then.
+    pub(crate) fn remove<T: Debug>(&self, _offset: PointerOffset, _origin: &T) -> bool {
+        #[cfg(debug_assertions)]
+        {
+            let mut chunks = self.chunks.borrow_mut();
+            match chunks.iter().position(|chunk| *chunk == _offset.value()) {
+                Some(index) => {
+                    chunks.swap_remove(index);
+                }
+                // a sample that is dropped while its thread unwinds from a detected misuse must
+                // not panic again, it would abort the process
+                None if std::thread::panicking() => return false,
+                None => {
+                    fatal_panic!(from _origin,
+                        "The sample {:?} is returned twice, it is no longer loaned.", _offset);
+                }
+            }
+        }
+        true
+    }
+}
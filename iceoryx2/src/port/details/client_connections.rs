@@ -0,0 +1,179 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::cell::UnsafeCell;
+
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder, SharedMemoryOpenError};
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
+};
+
+use crate::config;
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+use crate::service;
+use crate::service::config_scheme::{connection_config, data_segment_config};
+use crate::service::naming_scheme::{
+    client_data_segment_name, request_connection_name, response_connection_name,
+};
+use crate::service::static_config::request_response::StaticConfig;
+
+/// The connection of a [`crate::port::server::Server`] to a [`crate::port::client::Client`].
+/// Requests of the client arrive on the request channel and the responses are sent back via the
+/// response channel.
+#[derive(Debug)]
+pub(crate) struct Connection<'config, Service: service::Details<'config>> {
+    pub(crate) request_receiver:
+        <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) response_sender:
+        <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender,
+    // the data segment of the client is mapped when its first request is received
+    data_segment: Option<Service::SharedMemory>,
+    data_segment_config: <Service::SharedMemory as NamedConceptMgmt>::Configuration,
+    pub(crate) client_id: UniqueClientId,
+    pub(crate) is_client_disconnected: bool,
+}
+
+impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
+    fn new(
+        this: &ClientConnections<'config, Service>,
+        client_id: UniqueClientId,
+    ) -> Result<Self, ZeroCopyCreationError> {
+        let msg = format!(
+            "Unable to establish connection to client {:?} from server {:?}",
+            client_id, this.server_id
+        );
+
+        let request_receiver = fail!(from this, when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
+                        Builder::new(&request_connection_name(client_id, this.server_id))
+                                .config(&connection_config::<Service>(this.config))
+                                .buffer_size(this.static_config.max_active_requests)
+                                .receiver_max_borrowed_samples(this.static_config.max_active_requests)
+                                .enable_safe_overflow(false)
+                                .create_receiver(),
+                        "{} since the request channel could not be created.", msg);
+
+        let response_sender = fail!(from this, when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
+                        Builder::new(&response_connection_name(this.server_id, client_id))
+                                .config(&connection_config::<Service>(this.config))
+                                .buffer_size(this.static_config.client_response_buffer_size())
+                                .receiver_max_borrowed_samples(this.static_config.client_response_buffer_size())
+                                .enable_safe_overflow(false)
+                                .create_sender(),
+                        "{} since the response channel could not be created.", msg);
+
+        Ok(Self {
+            request_receiver,
+            response_sender,
+            data_segment: None,
+            data_segment_config: data_segment_config::<Service>(this.config),
+            client_id,
+            is_client_disconnected: false,
+        })
+    }
+
+    /// Returns the data segment of the client and maps it into the process when it is accessed
+    /// for the first time.
+    pub(crate) fn data_segment(&mut self) -> Result<&Service::SharedMemory, SharedMemoryOpenError> {
+        if self.data_segment.is_none() {
+            self.data_segment = Some(fail!(from "Connection::data_segment()",
+                when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
+                    Builder::new(&client_data_segment_name(self.client_id))
+                    .config(&self.data_segment_config)
+                    .open(),
+                "Unable to map the data segment of the client {:?} into the process.",
+                self.client_id));
+        }
+
+        match &self.data_segment {
+            Some(data_segment) => Ok(data_segment),
+            None => {
+                fatal_panic!(from "Connection::data_segment()",
+                    "This should never happen! The previously mapped data segment is not available.")
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ClientConnections<'config, Service: service::Details<'config>> {
+    connections: Vec<UnsafeCell<Option<Connection<'config, Service>>>>,
+    server_id: UniqueServerId,
+    config: &'config config::Config,
+    static_config: StaticConfig,
+}
+
+impl<'config, Service: service::Details<'config>> ClientConnections<'config, Service> {
+    pub(crate) fn new(
+        capacity: usize,
+        server_id: UniqueServerId,
+        config: &'config config::Config,
+        static_config: &StaticConfig,
+    ) -> Self {
+        Self {
+            connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            server_id,
+            config,
+            static_config: static_config.clone(),
+        }
+    }
+
+    pub(crate) fn server_id(&self) -> UniqueServerId {
+        self.server_id
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &Option<Connection<'config, Service>> {
+        unsafe { &*self.connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) fn get_mut(&self, index: usize) -> &mut Option<Connection<'config, Service>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.connections[index].get()
+        }
+    }
+
+    pub(crate) fn create(
+        &self,
+        index: usize,
+        client_id: UniqueClientId,
+    ) -> Result<bool, ZeroCopyCreationError> {
+        match self.get(index) {
+            Some(connection) if connection.client_id == client_id => Ok(false),
+            // the slot was either empty or is now occupied by a new client
+            _ => {
+                *self.get_mut(index) = Some(Connection::new(self, client_id)?);
+                Ok(true)
+            }
+        }
+    }
+
+    /// The connection is kept so that the requests which are still in use can be accessed. It
+    /// is replaced as soon as a new client takes over the slot.
+    pub(crate) fn disconnect(&self, index: usize) {
+        if let Some(connection) = self.get_mut(index) {
+            connection.is_client_disconnected = true;
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.connections.capacity()
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::alloc::Layout;
+use std::sync::atomic::{AtomicU64, AtomicUsize};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_cal::shared_memory::{
+    HugePages, SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError,
+};
+use iceoryx2_cal::shm_allocator::{self, pool_allocator::PoolAllocator};
+
+/// Defines how the memory of a [`DataSegment`] is acquired.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct MemoryOptions {
+    pub(crate) lock_memory: bool,
+    pub(crate) huge_pages: HugePages,
+    pub(crate) prefault: bool,
+}
+
+/// A shared memory pool of a sending port whose samples can hold up to `max_slice_len`
+/// elements.
+#[derive(Debug)]
+pub(crate) struct DataSegment<Memory: SharedMemory<PoolAllocator>> {
+    pub(crate) memory: Memory,
+    pub(crate) sample_layout: Layout,
+    pub(crate) max_slice_len: usize,
+    pub(crate) number_of_samples: usize,
+    pub(crate) sample_reference_counter: Vec<AtomicU64>,
+    pub(crate) number_of_allocated_samples: AtomicUsize,
+}
+
+impl<Memory: SharedMemory<PoolAllocator>> DataSegment<Memory> {
+    pub(crate) fn create(
+        name: &FileName,
+        config: &<Memory as NamedConceptMgmt>::Configuration,
+        max_slice_len: usize,
+        sample_layout: Layout,
+        number_of_samples: usize,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        Self::create_with_options(
+            name,
+            config,
+            max_slice_len,
+            sample_layout,
+            number_of_samples,
+            &MemoryOptions::default(),
+        )
+    }
+
+    pub(crate) fn create_with_options(
+        name: &FileName,
+        config: &<Memory as NamedConceptMgmt>::Configuration,
+        max_slice_len: usize,
+        sample_layout: Layout,
+        number_of_samples: usize,
+        options: &MemoryOptions,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        let allocator_config = shm_allocator::pool_allocator::Config {
+            bucket_layout: sample_layout,
+        };
+        let chunk_size = allocator_config.bucket_layout.size();
+        let chunk_align = allocator_config.bucket_layout.align();
+
+        let memory = fail!(from "DataSegment::create()",
+            when <<Memory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<Memory>>::new(name)
+                .config(config)
+                .size(chunk_size * number_of_samples + chunk_align - 1)
+                .lock_memory(options.lock_memory)
+                .huge_pages(options.huge_pages)
+                .prefault(options.prefault)
+                .create(&allocator_config),
+            "Unable to create the data segment.");
+
+        Ok(Self {
+            memory,
+            sample_layout,
+            max_slice_len,
+            number_of_samples,
+            sample_reference_counter: (0..number_of_samples).map(|_| AtomicU64::new(0)).collect(),
+            number_of_allocated_samples: AtomicUsize::new(0),
+        })
+    }
+
+    pub(crate) fn reference_counter(&self, offset: usize) -> &AtomicU64 {
+        &self.sample_reference_counter[offset / self.sample_layout.size()]
+    }
+}
@@ -10,5 +10,13 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub(crate) mod chunk_guard;
+pub(crate) mod client_connections;
+pub(crate) mod data_segment;
 pub(crate) mod publisher_connections;
+#[cfg(feature = "async")]
+pub(crate) mod readiness;
+pub(crate) mod segment_offset;
+pub(crate) mod server_connections;
 pub(crate) mod subscriber_connections;
+pub(crate) mod zero_copy_channel;
@@ -11,39 +11,82 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use std::cell::UnsafeCell;
+use std::time::Duration;
 
 use crate::{
     config,
+    port::event_id::EventId,
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
     service::{
         self,
-        config_scheme::{connection_config, data_segment_config},
+        config_scheme::{connection_config, data_segment_config, event_config},
     },
     service::{
-        naming_scheme::{connection_name, data_segment_name},
+        dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails},
+        naming_scheme::{connection_name, data_segment_name, publisher_event_concept_name},
         static_config::publish_subscribe::StaticConfig,
     },
 };
 
+use crate::port::details::segment_offset::MAX_NUMBER_OF_SEGMENTS;
+use crate::port::details::zero_copy_channel::{is_intra_process, ChannelDetails, ChannelReceiver};
 use crate::port::update_connections::ConnectionFailure;
-use iceoryx2_bb_log::fail;
-use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_bb_log::{debug, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_cal::event::NotifierBuilder;
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_cal::shm_allocator::PointerOffset;
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyCreationError, ZeroCopyReceiveError, ZeroCopyReceiver,
+};
 use iceoryx2_cal::{
     shared_memory::SharedMemory, shared_memory::SharedMemoryBuilder,
-    shm_allocator::pool_allocator::PoolAllocator, zero_copy_connection::*,
+    shared_memory::SharedMemoryOpenError, shm_allocator::pool_allocator::PoolAllocator,
 };
 
 #[derive(Debug)]
 pub(crate) struct Connection<'config, Service: service::Details<'config>> {
-    pub(crate) receiver:
-        <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Receiver,
-    pub(crate) data_segment: Service::SharedMemory,
+    pub(crate) receiver: ChannelReceiver<<Service as service::Details<'config>>::Connection>,
+    // the initial data segment is mapped with the connection since the publisher can be gone
+    // before its samples are received, the segments it adds later are mapped when the first
+    // sample of them is received
+    data_segments: Vec<Option<Service::SharedMemory>>,
+    data_segment_config: <Service::SharedMemory as NamedConceptMgmt>::Configuration,
+    pub(crate) publisher_id: UniquePublisherId,
+    pub(crate) last_sequence_number: Option<u64>,
+    pub(crate) number_of_lost_samples: u64,
+    pub(crate) last_activity: Duration,
+    pub(crate) is_deadline_miss_reported: bool,
+    pub(crate) is_publisher_disconnected: bool,
+    // the samples of the connection that are held by the user, the connection must outlive them
+    // since they point into its data segments
+    pub(crate) borrowed_samples: usize,
+    // a sample that was dequeued while the subscriber only peeked, since an overflowing
+    // publisher replaced the inspected one in the meantime, it precedes the buffered samples
+    dequeued_sample: Option<PointerOffset>,
+    // wakes up the publisher when it waits for a free sample, only available when the service
+    // has notifications enabled
+    pub(crate) notifier: Option<<Service::Event as iceoryx2_cal::event::Event<EventId>>::Notifier>,
+}
+
+fn open_data_segment<'config, Service: service::Details<'config>>(
+    publisher_id: UniquePublisherId,
+    segment_id: usize,
+    config: &<Service::SharedMemory as NamedConceptMgmt>::Configuration,
+) -> Result<Service::SharedMemory, SharedMemoryOpenError> {
+    <Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder::new(&data_segment_name(
+        publisher_id,
+        segment_id,
+    ))
+    .config(config)
+    .open()
 }
 
 impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
     fn new(
         this: &PublisherConnections<'config, Service>,
         publisher_id: UniquePublisherId,
+        buffer_size: usize,
     ) -> Result<Self, ConnectionFailure> {
         let msg = format!(
             "Unable to establish connection to publisher {:?} from subscriber {:?}.",
@@ -51,32 +94,123 @@ impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
         );
 
         let receiver = fail!(from this,
-                        when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
-                            Builder::new( &connection_name(publisher_id, this.subscriber_id))
-                                    .config(&connection_config::<Service>(this.config))
-                                    .buffer_size(this.static_config.subscriber_max_buffer_size)
-                                    .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
-                                    .enable_safe_overflow(this.static_config.enable_safe_overflow)
-                                    .create_receiver(),
+                        when ChannelReceiver::create(
+                            &connection_name(publisher_id, this.subscriber_id),
+                            is_intra_process(&this.static_config, publisher_id, this.subscriber_id),
+                            this.config,
+                            &connection_config::<Service>(this.config),
+                            &ChannelDetails {
+                                buffer_size,
+                                max_borrowed_samples: this.static_config.subscriber_max_borrowed_samples,
+                                enable_safe_overflow: this.static_config.enable_safe_overflow,
+                                lock_memory: false,
+                            }),
                         "{} since the zero copy connection could not be established.", msg);
 
-        let data_segment = fail!(from this,
-                            when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
-                                Builder::new(&data_segment_name(publisher_id))
-                                .config(&data_segment_config::<Service>(this.config))
-                                .open(),
-                            "{} since the publishers data segment could not be mapped into the process.", msg);
+        // the first deadline window starts when the connection is established
+        let now = fatal_panic!(from this, when Time::now_with_clock(this.static_config.clock_type.into()),
+                    "{} since the current time could not be acquired.", msg);
+
+        // a missing notifier only delays a waiting publisher until its timeout, the released
+        // samples are still returned
+        let notifier = match this.static_config.enable_notifications {
+            false => None,
+            true => {
+                match <Service::Event as iceoryx2_cal::event::Event<EventId>>::NotifierBuilder::new(
+                    &publisher_event_concept_name(&publisher_id),
+                )
+                .config(&event_config::<Service>(this.config))
+                .open()
+                {
+                    Ok(notifier) => Some(notifier),
+                    Err(e) => {
+                        warn!(from this, "Unable to open the notifier of publisher {:?} ({:?}), the publisher will not be woken up by subscriber {:?}.",
+                            publisher_id, e, this.subscriber_id);
+                        None
+                    }
+                }
+            }
+        };
+
+        let data_segment_config = data_segment_config::<Service>(this.config);
+        let mut data_segments: Vec<_> = (0..MAX_NUMBER_OF_SEGMENTS).map(|_| None).collect();
+        data_segments[0] = Some(fail!(from this,
+                        when open_data_segment::<Service>(publisher_id, 0, &data_segment_config),
+                        "{} since the data segment of the publisher could not be mapped.", msg));
 
         Ok(Self {
             receiver,
-            data_segment,
+            data_segments,
+            data_segment_config,
+            publisher_id,
+            last_sequence_number: None,
+            number_of_lost_samples: 0,
+            last_activity: now.as_duration(),
+            is_deadline_miss_reported: false,
+            is_publisher_disconnected: false,
+            borrowed_samples: 0,
+            dequeued_sample: None,
+            notifier,
         })
     }
 }
+impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
+    /// Returns the next sample of the publisher without dequeuing it.
+    pub(crate) fn peek_sample(&self) -> Option<PointerOffset> {
+        self.dequeued_sample.or_else(|| self.receiver.peek())
+    }
+
+    /// Dequeues the next sample of the publisher.
+    pub(crate) fn receive_sample(&mut self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
+        match self.dequeued_sample.take() {
+            Some(relative_addr) => Ok(Some(relative_addr)),
+            None => self.receiver.receive(),
+        }
+    }
+
+    /// Keeps a sample that was dequeued but not delivered, it is returned by the next
+    /// [`Connection::peek_sample()`] and [`Connection::receive_sample()`].
+    pub(crate) fn keep_dequeued_sample(&mut self, relative_addr: PointerOffset) {
+        self.dequeued_sample = Some(relative_addr);
+    }
+
+    /// Returns true when the publisher has samples that were not yet received.
+    pub(crate) fn has_samples(&self) -> bool {
+        self.dequeued_sample.is_some() || self.receiver.has_data()
+    }
+
+    /// Returns the data segment of the publisher with the provided id and maps it into the
+    /// process when it is accessed for the first time.
+    pub(crate) fn data_segment(
+        &mut self,
+        segment_id: usize,
+    ) -> Result<&Service::SharedMemory, SharedMemoryOpenError> {
+        let data_segment = &mut self.data_segments[segment_id];
+        if data_segment.is_none() {
+            *data_segment = Some(fail!(from "Connection::data_segment()",
+                when open_data_segment::<Service>(self.publisher_id, segment_id, &self.data_segment_config),
+                "Unable to map the data segment {} of the publisher {:?} into the process.",
+                segment_id, self.publisher_id));
+        }
+
+        match data_segment {
+            Some(data_segment) => Ok(data_segment),
+            None => {
+                fatal_panic!(from "Connection::data_segment()",
+                    "This should never happen! The previously mapped data segment is not available.")
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct PublisherConnections<'config, Service: service::Details<'config>> {
     connections: Vec<UnsafeCell<Option<Connection<'config, Service>>>>,
+    // connections of replaced publishers whose samples are still borrowed
+    retired_connections: UnsafeCell<Vec<Connection<'config, Service>>>,
     subscriber_id: UniqueSubscriberId,
+    buffer_size: usize,
+    required_min_buffer_size: usize,
     config: &'config config::Config,
     static_config: StaticConfig,
 }
@@ -85,12 +219,17 @@ impl<'config, Service: service::Details<'config>> PublisherConnections<'config,
     pub(crate) fn new(
         capacity: usize,
         subscriber_id: UniqueSubscriberId,
+        buffer_size: usize,
+        required_min_buffer_size: usize,
         config: &'config config::Config,
         static_config: &StaticConfig,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            retired_connections: UnsafeCell::new(vec![]),
             subscriber_id,
+            buffer_size,
+            required_min_buffer_size,
             config,
             static_config: static_config.clone(),
         }
@@ -100,6 +239,27 @@ impl<'config, Service: service::Details<'config>> PublisherConnections<'config,
         self.subscriber_id
     }
 
+    pub(crate) fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    pub(crate) fn required_min_buffer_size(&self) -> usize {
+        self.required_min_buffer_size
+    }
+
+    pub(crate) fn static_config(&self) -> &StaticConfig {
+        &self.static_config
+    }
+
+    /// Returns the [`SubscriberDetails`] the publishers see in the dynamic config.
+    pub(crate) fn details(&self) -> SubscriberDetails {
+        SubscriberDetails {
+            subscriber_id: self.subscriber_id,
+            buffer_size: self.buffer_size,
+            required_min_buffer_size: self.required_min_buffer_size,
+        }
+    }
+
     pub(crate) fn get(&self, index: usize) -> &Option<Connection<'config, Service>> {
         unsafe { &*self.connections[index].get() }
     }
@@ -113,20 +273,84 @@ impl<'config, Service: service::Details<'config>> PublisherConnections<'config,
         }
     }
 
+    /// Establishes the connection to the publisher with the buffer size both sides agree on,
+    /// see [`SubscriberDetails::negotiate_buffer_size()`].
     pub(crate) fn create(
         &self,
         index: usize,
-        publisher_id: UniquePublisherId,
+        publisher: &PublisherDetails,
     ) -> Result<(), ConnectionFailure> {
-        if self.get(index).is_none() {
-            *self.get_mut(index) = Some(Connection::new(self, publisher_id)?);
+        let publisher_id = publisher.publisher_id;
+        match self.get(index) {
+            Some(connection) if connection.publisher_id == publisher_id => (),
+            // the slot was either empty or is now occupied by a new publisher
+            _ => {
+                let buffer_size = match self.details().negotiate_buffer_size(publisher) {
+                    Some(buffer_size) => buffer_size,
+                    None => {
+                        fail!(from self, with ConnectionFailure::FailedToEstablishConnection(ZeroCopyCreationError::IncompatibleBufferSize),
+                            "Refuse the connection to publisher {:?} since it provides a buffer size of at most {} but the subscriber {:?} requires at least {}.",
+                            publisher_id, publisher.max_buffer_size, self.subscriber_id, self.required_min_buffer_size);
+                    }
+                };
+                let new_connection = Connection::new(self, publisher_id, buffer_size)?;
+                if let Some(previous) = self.get_mut(index).replace(new_connection) {
+                    self.retire(previous);
+                }
+            }
         }
 
         Ok(())
     }
 
-    pub(crate) fn remove(&self, index: usize) {
-        *self.get_mut(index) = None;
+    /// Keeps the connection of a replaced publisher, and with it its mapped data segments,
+    /// alive until the user has returned all of its borrowed samples.
+    fn retire(&self, connection: Connection<'config, Service>) {
+        if connection.borrowed_samples == 0 {
+            return;
+        }
+
+        debug!(from self, "The publisher {:?} was replaced while {} of its samples are still borrowed, its connection is retired until they are released.",
+            connection.publisher_id, connection.borrowed_samples);
+        // SAFETY: the subscriber is not Sync, only the receiving thread accesses the connections
+        unsafe { &mut *self.retired_connections.get() }.push(connection);
+    }
+
+    /// Returns the connection from which the sample of the publisher with `publisher_id` was
+    /// received via the connection at `index`, it is retired when the publisher was replaced
+    /// in the meantime.
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) fn origin_of_sample(
+        &self,
+        index: usize,
+        publisher_id: UniquePublisherId,
+    ) -> Option<&mut Connection<'config, Service>> {
+        match self.get_mut(index) {
+            Some(connection) if connection.publisher_id == publisher_id => Some(connection),
+            _ => unsafe { &mut *self.retired_connections.get() }
+                .iter_mut()
+                .find(|connection| connection.publisher_id == publisher_id),
+        }
+    }
+
+    /// Drops the retired connections whose samples were all released. Must not be called
+    /// while a reference to a retired connection exists.
+    pub(crate) fn remove_drained_connections(&self) {
+        // SAFETY: the subscriber is not Sync, only the receiving thread accesses the connections
+        let retired_connections = unsafe { &mut *self.retired_connections.get() };
+        if !retired_connections.is_empty() {
+            retired_connections.retain(|connection| connection.borrowed_samples != 0);
+        }
+    }
+
+    /// The connection is kept so that the samples which are still in the buffer can be
+    /// received. It is replaced as soon as a new publisher takes over the slot, when samples
+    /// of it are still borrowed it is retired until they are released.
+    pub(crate) fn disconnect(&self, index: usize) {
+        if let Some(connection) = self.get_mut(index) {
+            connection.is_publisher_disconnected = true;
+        }
     }
 
     pub(crate) fn len(&self) -> usize {
@@ -0,0 +1,55 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Suspends an async task until the file descriptor of an event listener becomes readable. On
+//! unix the file descriptor is registered in the reactor of the tokio runtime. Everywhere else
+//! the listener is checked periodically, the file descriptors are emulated there and cannot be
+//! registered. A blocking wait in `tokio::task::spawn_blocking()` is no alternative since the
+//! ports are bound to the thread that created them.
+
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+
+/// The interval in which the listener is checked on platforms without a reactor integration.
+#[cfg(not(unix))]
+pub(crate) const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(1);
+
+#[cfg(unix)]
+struct ListenerFd(std::os::unix::io::RawFd);
+
+#[cfg(unix)]
+impl std::os::unix::io::AsRawFd for ListenerFd {
+    fn as_raw_fd(&self) -> std::os::unix::io::RawFd {
+        self.0
+    }
+}
+
+/// Returns when the file descriptor may be readable, spurious wake ups are possible. It does
+/// not read from the file descriptor, dropping the returned future never loses a
+/// notification.
+#[cfg(unix)]
+pub(crate) async fn readable<T: FileDescriptorBased>(value: &T) -> std::io::Result<()> {
+    // the registration is removed when the AsyncFd is dropped, the file descriptor itself
+    // stays owned by the listener
+    let fd = tokio::io::unix::AsyncFd::with_interest(
+        ListenerFd(unsafe { value.file_descriptor().native_handle() }),
+        tokio::io::Interest::READABLE,
+    )?;
+    let _guard = fd.readable().await?;
+    Ok(())
+}
+
+/// Returns after [`POLL_INTERVAL`], the caller checks the listener again.
+#[cfg(not(unix))]
+pub(crate) async fn readable<T: FileDescriptorBased>(_value: &T) -> std::io::Result<()> {
+    tokio::time::sleep(POLL_INTERVAL).await;
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+// A publisher can own multiple data segments, the upper bits of the offset that is sent to the
+// subscribers identify the segment and the remaining bits the position of the sample inside of
+// it.
+const SEGMENT_ID_BITS: u32 = 4;
+const SEGMENT_ID_SHIFT: u32 = usize::BITS - SEGMENT_ID_BITS;
+const OFFSET_MASK: usize = (1 << SEGMENT_ID_SHIFT) - 1;
+
+/// The maximum number of data segments a publisher can create during its lifetime.
+pub const MAX_NUMBER_OF_SEGMENTS: usize = 1 << SEGMENT_ID_BITS;
+
+/// The position of a sample in the data segments of a publisher, it is converted from and
+/// into the [`PointerOffset`] that is sent to the subscribers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SegmentOffset {
+    segment_id: usize,
+    offset: usize,
+}
+
+impl SegmentOffset {
+    /// Creates a new [`SegmentOffset`], the `segment_id` must be smaller than
+    /// [`MAX_NUMBER_OF_SEGMENTS`].
+    pub fn new(segment_id: usize, offset: usize) -> Self {
+        debug_assert!(segment_id < MAX_NUMBER_OF_SEGMENTS);
+        debug_assert!(offset <= OFFSET_MASK);
+
+        Self { segment_id, offset }
+    }
+
+    /// Returns the id of the data segment that contains the sample.
+    pub fn segment_id(&self) -> usize {
+        self.segment_id
+    }
+
+    /// Returns the distance of the sample to the start of the allocator data of its data
+    /// segment.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the address of the sample in the data segment whose allocator data starts at
+    /// `allocator_data_start_address` in the current process.
+    pub fn address_in(&self, allocator_data_start_address: usize) -> usize {
+        allocator_data_start_address + self.offset
+    }
+}
+
+impl From<PointerOffset> for SegmentOffset {
+    fn from(value: PointerOffset) -> Self {
+        Self {
+            segment_id: value.value() >> SEGMENT_ID_SHIFT,
+            offset: value.value() & OFFSET_MASK,
+        }
+    }
+}
+
+impl From<SegmentOffset> for PointerOffset {
+    fn from(value: SegmentOffset) -> Self {
+        PointerOffset::new((value.segment_id << SEGMENT_ID_SHIFT) | value.offset)
+    }
+}
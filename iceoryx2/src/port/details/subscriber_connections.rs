@@ -12,13 +12,16 @@
 
 use std::cell::UnsafeCell;
 
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_cal::event::NotifierBuilder;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
-use iceoryx2_cal::zero_copy_connection::{
-    ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
-};
+use iceoryx2_cal::zero_copy_connection::ZeroCopyCreationError;
 
-use crate::service::config_scheme::connection_config;
+use crate::port::details::zero_copy_channel::{is_intra_process, ChannelDetails, ChannelSender};
+use crate::port::event_id::EventId;
+use crate::service::config_scheme::{connection_config, event_config};
+use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
+use crate::service::naming_scheme::subscriber_event_concept_name;
 use crate::{
     config,
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
@@ -28,26 +31,38 @@ use crate::{
 
 #[derive(Debug)]
 pub(crate) struct Connection<'config, Service: service::Details<'config>> {
-    pub(crate) sender:
-        <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) sender: ChannelSender<<Service as service::Details<'config>>::Connection>,
+    pub(crate) subscriber_id: UniqueSubscriberId,
+    // wakes up the subscriber when it waits for a sample, only available when the service has
+    // notifications enabled
+    pub(crate) notifier: Option<<Service::Event as iceoryx2_cal::event::Event<EventId>>::Notifier>,
 }
 
 impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
     fn new(
         this: &SubscriberConnections<'config, Service>,
         subscriber_id: UniqueSubscriberId,
+        buffer_size: usize,
     ) -> Result<Self, ZeroCopyCreationError> {
-        let sender = fail!(from this, when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
-                        Builder::new( &connection_name(this.port_id, subscriber_id))
-                                .config(&connection_config::<Service>(this.config))
-                                .buffer_size(this.static_config.subscriber_max_buffer_size)
-                                .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
-                                .enable_safe_overflow(this.static_config.enable_safe_overflow)
-                                .create_sender(),
+        let sender = fail!(from this, when ChannelSender::create(
+                            &connection_name(this.port_id, subscriber_id),
+                            is_intra_process(&this.static_config, this.port_id, subscriber_id),
+                            this.config,
+                            &connection_config::<Service>(this.config),
+                            &ChannelDetails {
+                                buffer_size,
+                                max_borrowed_samples: this.static_config.subscriber_max_borrowed_samples,
+                                enable_safe_overflow: this.static_config.enable_safe_overflow,
+                                lock_memory: this.lock_memory,
+                            }),
                         "Unable to establish connection to subscriber {:?} from publisher {:?}.",
                         subscriber_id, this.port_id);
 
-        Ok(Self { sender })
+        Ok(Self {
+            sender,
+            subscriber_id,
+            notifier: this.open_notifier(subscriber_id),
+        })
     }
 }
 
@@ -55,6 +70,8 @@ impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
 pub(crate) struct SubscriberConnections<'config, Service: service::Details<'config>> {
     connections: Vec<UnsafeCell<Option<Connection<'config, Service>>>>,
     port_id: UniquePublisherId,
+    max_buffer_size: usize,
+    lock_memory: bool,
     config: &'config config::Config,
     static_config: StaticConfig,
 }
@@ -64,12 +81,16 @@ impl<'config, Service: service::Details<'config>> SubscriberConnections<'config,
         capacity: usize,
         config: &'config config::Config,
         port_id: UniquePublisherId,
+        max_buffer_size: usize,
+        lock_memory: bool,
         static_config: &StaticConfig,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
             config,
             port_id,
+            max_buffer_size,
+            lock_memory,
             static_config: static_config.clone(),
         }
     }
@@ -91,16 +112,67 @@ impl<'config, Service: service::Details<'config>> SubscriberConnections<'config,
         *self.get_mut(index) = None
     }
 
+    /// Opens the notifier that wakes up the subscriber when it waits for a sample, [`None`]
+    /// when the service has no notifications enabled.
+    pub(crate) fn open_notifier(
+        &self,
+        subscriber_id: UniqueSubscriberId,
+    ) -> Option<<Service::Event as iceoryx2_cal::event::Event<EventId>>::Notifier> {
+        // a missing notifier only delays a waiting subscriber until its timeout, the samples
+        // are still delivered
+        match self.static_config.enable_notifications {
+            false => None,
+            true => {
+                match <Service::Event as iceoryx2_cal::event::Event<EventId>>::NotifierBuilder::new(
+                    &subscriber_event_concept_name(&subscriber_id),
+                )
+                .config(&event_config::<Service>(self.config))
+                .open()
+                {
+                    Ok(notifier) => Some(notifier),
+                    Err(e) => {
+                        warn!(from self, "Unable to open the notifier of subscriber {:?} ({:?}), the subscriber will not be woken up by publisher {:?}.",
+                            subscriber_id, e, self.port_id);
+                        None
+                    }
+                }
+            }
+        }
+    }
+
+    /// Establishes the connection to the subscriber with the buffer size both sides agree
+    /// on, see [`SubscriberDetails::negotiate_buffer_size()`]. Returns true when a new
+    /// connection was established.
     pub(crate) fn create(
         &self,
         index: usize,
-        subscriber_id: UniqueSubscriberId,
+        subscriber: &SubscriberDetails,
     ) -> Result<bool, ZeroCopyCreationError> {
-        if self.get(index).is_none() {
-            *self.get_mut(index) = Some(Connection::new(self, subscriber_id)?);
-            Ok(true)
-        } else {
-            Ok(false)
+        let subscriber_id = subscriber.subscriber_id;
+        match self.get(index) {
+            Some(connection) if connection.subscriber_id == subscriber_id => Ok(false),
+            // the slot was either empty or is now occupied by a new subscriber
+            _ => {
+                self.remove(index);
+                let buffer_size = match subscriber.negotiate_buffer_size(&self.details()) {
+                    Some(buffer_size) => buffer_size,
+                    None => {
+                        fail!(from self, with ZeroCopyCreationError::IncompatibleBufferSize,
+                            "Refuse the connection to subscriber {:?} since it requires a buffer size of at least {} but the publisher {:?} provides at most {}.",
+                            subscriber_id, subscriber.required_min_buffer_size, self.port_id, self.max_buffer_size);
+                    }
+                };
+                *self.get_mut(index) = Some(Connection::new(self, subscriber_id, buffer_size)?);
+                Ok(true)
+            }
+        }
+    }
+
+    /// Returns the [`PublisherDetails`] the subscribers see in the dynamic config.
+    pub(crate) fn details(&self) -> PublisherDetails {
+        PublisherDetails {
+            publisher_id: self.port_id,
+            max_buffer_size: self.max_buffer_size,
         }
     }
 
@@ -111,4 +183,8 @@ impl<'config, Service: service::Details<'config>> SubscriberConnections<'config,
     pub(crate) fn capacity(&self) -> usize {
         self.connections.capacity()
     }
+
+    pub(crate) fn number_of_connections(&self) -> usize {
+        (0..self.len()).filter(|i| self.get(*i).is_some()).count()
+    }
 }
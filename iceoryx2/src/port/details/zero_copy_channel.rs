@@ -0,0 +1,292 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The channel between a [`crate::port::publisher::Publisher`] and a
+//! [`crate::port::subscriber::Subscriber`]. When both ports belong to the same process and the
+//! service has the intra process fast path enabled, the offsets of the samples are exchanged
+//! via process local lock-free queues instead of the zero copy connection of the service which
+//! resides in shared memory. Both ends decide independently which kind of channel is used,
+//! therefore the decision depends only on the port ids and the static config of the service.
+//!
+//! The queues transport the offset of a sample and not a pointer to its chunk. The subscriber
+//! maps the data segment of the publisher on its own, even in the same process at another
+//! address, and the offset is translated into a pointer with a single addition to the base
+//! address of that mapping. The chunk is returned by its offset as well, so that the release,
+//! the history and the reclaiming of the samples work the same way for both kinds of channels.
+
+use std::fmt::Debug;
+
+use iceoryx2_cal::named_concept::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_cal::zero_copy_connection::{
+    process_local, FileName, PointerOffset, ZeroCopyConnection, ZeroCopyConnectionBuilder,
+    ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopyReceiveError, ZeroCopyReceiver,
+    ZeroCopyReclaimError, ZeroCopyReleaseError, ZeroCopySendError, ZeroCopySender,
+};
+
+use crate::config;
+use crate::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::service::config_scheme::intra_process_connection_config;
+use crate::service::static_config::publish_subscribe::StaticConfig;
+
+/// Returns true when the samples from the publisher to the subscriber are delivered via the
+/// process local channel.
+pub(crate) fn is_intra_process(
+    static_config: &StaticConfig,
+    publisher_id: UniquePublisherId,
+    subscriber_id: UniqueSubscriberId,
+) -> bool {
+    static_config.enable_intra_process_fast_path && publisher_id.0.pid() == subscriber_id.0.pid()
+}
+
+/// The settings that both ends of the channel must agree on.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ChannelDetails {
+    pub(crate) buffer_size: usize,
+    pub(crate) max_borrowed_samples: usize,
+    pub(crate) enable_safe_overflow: bool,
+    pub(crate) lock_memory: bool,
+}
+
+fn builder<C: ZeroCopyConnection>(
+    name: &FileName,
+    config: &<C as NamedConceptMgmt>::Configuration,
+    details: &ChannelDetails,
+) -> C::Builder {
+    C::Builder::new(name)
+        .config(config)
+        .buffer_size(details.buffer_size)
+        .receiver_max_borrowed_samples(details.max_borrowed_samples)
+        .enable_safe_overflow(details.enable_safe_overflow)
+        .lock_memory(details.lock_memory)
+}
+
+// stored inline like before the process local variant existed, boxing the inter process
+// variant would add an indirection to every access
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum ChannelSender<C: ZeroCopyConnection> {
+    InterProcess(C::Sender),
+    IntraProcess(process_local::Sender),
+}
+
+impl<C: ZeroCopyConnection> Debug for ChannelSender<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InterProcess(sender) => f.debug_tuple("InterProcess").field(sender).finish(),
+            Self::IntraProcess(sender) => f.debug_tuple("IntraProcess").field(sender).finish(),
+        }
+    }
+}
+
+impl<C: ZeroCopyConnection> ChannelSender<C> {
+    pub(crate) fn create(
+        name: &FileName,
+        is_intra_process: bool,
+        global_config: &config::Config,
+        config: &<C as NamedConceptMgmt>::Configuration,
+        details: &ChannelDetails,
+    ) -> Result<Self, ZeroCopyCreationError> {
+        match is_intra_process {
+            true => Ok(Self::IntraProcess(
+                builder::<process_local::Connection>(
+                    name,
+                    &intra_process_connection_config(global_config),
+                    details,
+                )
+                .create_sender()?,
+            )),
+            false => Ok(Self::InterProcess(
+                builder::<C>(name, config, details).create_sender()?,
+            )),
+        }
+    }
+}
+
+impl<C: ZeroCopyConnection> NamedConcept for ChannelSender<C> {
+    fn name(&self) -> &FileName {
+        match self {
+            Self::InterProcess(sender) => sender.name(),
+            Self::IntraProcess(sender) => sender.name(),
+        }
+    }
+}
+
+impl<C: ZeroCopyConnection> ZeroCopyPortDetails for ChannelSender<C> {
+    fn buffer_size(&self) -> usize {
+        match self {
+            Self::InterProcess(sender) => sender.buffer_size(),
+            Self::IntraProcess(sender) => sender.buffer_size(),
+        }
+    }
+
+    fn has_enabled_safe_overflow(&self) -> bool {
+        match self {
+            Self::InterProcess(sender) => sender.has_enabled_safe_overflow(),
+            Self::IntraProcess(sender) => sender.has_enabled_safe_overflow(),
+        }
+    }
+
+    fn max_borrowed_samples(&self) -> usize {
+        match self {
+            Self::InterProcess(sender) => sender.max_borrowed_samples(),
+            Self::IntraProcess(sender) => sender.max_borrowed_samples(),
+        }
+    }
+
+    fn is_connected(&self) -> bool {
+        match self {
+            Self::InterProcess(sender) => sender.is_connected(),
+            Self::IntraProcess(sender) => sender.is_connected(),
+        }
+    }
+}
+
+impl<C: ZeroCopyConnection> ZeroCopySender for ChannelSender<C> {
+    fn try_send(&self, ptr: PointerOffset) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        match self {
+            Self::InterProcess(sender) => sender.try_send(ptr),
+            Self::IntraProcess(sender) => sender.try_send(ptr),
+        }
+    }
+
+    fn blocking_send(
+        &self,
+        ptr: PointerOffset,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        match self {
+            Self::InterProcess(sender) => sender.blocking_send(ptr),
+            Self::IntraProcess(sender) => sender.blocking_send(ptr),
+        }
+    }
+
+    fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
+        match self {
+            Self::InterProcess(sender) => sender.reclaim(),
+            Self::IntraProcess(sender) => sender.reclaim(),
+        }
+    }
+
+    fn number_of_pending_samples(&self) -> usize {
+        match self {
+            Self::InterProcess(sender) => sender.number_of_pending_samples(),
+            Self::IntraProcess(sender) => sender.number_of_pending_samples(),
+        }
+    }
+}
+
+// stored inline for the same reason as the ChannelSender
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum ChannelReceiver<C: ZeroCopyConnection> {
+    InterProcess(C::Receiver),
+    IntraProcess(process_local::Receiver),
+}
+
+impl<C: ZeroCopyConnection> Debug for ChannelReceiver<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InterProcess(receiver) => f.debug_tuple("InterProcess").field(receiver).finish(),
+            Self::IntraProcess(receiver) => f.debug_tuple("IntraProcess").field(receiver).finish(),
+        }
+    }
+}
+
+impl<C: ZeroCopyConnection> ChannelReceiver<C> {
+    pub(crate) fn create(
+        name: &FileName,
+        is_intra_process: bool,
+        global_config: &config::Config,
+        config: &<C as NamedConceptMgmt>::Configuration,
+        details: &ChannelDetails,
+    ) -> Result<Self, ZeroCopyCreationError> {
+        match is_intra_process {
+            true => Ok(Self::IntraProcess(
+                builder::<process_local::Connection>(
+                    name,
+                    &intra_process_connection_config(global_config),
+                    details,
+                )
+                .create_receiver()?,
+            )),
+            false => Ok(Self::InterProcess(
+                builder::<C>(name, config, details).create_receiver()?,
+            )),
+        }
+    }
+}
+
+impl<C: ZeroCopyConnection> NamedConcept for ChannelReceiver<C> {
+    fn name(&self) -> &FileName {
+        match self {
+            Self::InterProcess(receiver) => receiver.name(),
+            Self::IntraProcess(receiver) => receiver.name(),
+        }
+    }
+}
+
+impl<C: ZeroCopyConnection> ZeroCopyPortDetails for ChannelReceiver<C> {
+    fn buffer_size(&self) -> usize {
+        match self {
+            Self::InterProcess(receiver) => receiver.buffer_size(),
+            Self::IntraProcess(receiver) => receiver.buffer_size(),
+        }
+    }
+
+    fn has_enabled_safe_overflow(&self) -> bool {
+        match self {
+            Self::InterProcess(receiver) => receiver.has_enabled_safe_overflow(),
+            Self::IntraProcess(receiver) => receiver.has_enabled_safe_overflow(),
+        }
+    }
+
+    fn max_borrowed_samples(&self) -> usize {
+        match self {
+            Self::InterProcess(receiver) => receiver.max_borrowed_samples(),
+            Self::IntraProcess(receiver) => receiver.max_borrowed_samples(),
+        }
+    }
+
+    fn is_connected(&self) -> bool {
+        match self {
+            Self::InterProcess(receiver) => receiver.is_connected(),
+            Self::IntraProcess(receiver) => receiver.is_connected(),
+        }
+    }
+}
+
+impl<C: ZeroCopyConnection> ZeroCopyReceiver for ChannelReceiver<C> {
+    fn has_data(&self) -> bool {
+        match self {
+            Self::InterProcess(receiver) => receiver.has_data(),
+            Self::IntraProcess(receiver) => receiver.has_data(),
+        }
+    }
+
+    fn peek(&self) -> Option<PointerOffset> {
+        match self {
+            Self::InterProcess(receiver) => receiver.peek(),
+            Self::IntraProcess(receiver) => receiver.peek(),
+        }
+    }
+
+    fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
+        match self {
+            Self::InterProcess(receiver) => receiver.receive(),
+            Self::IntraProcess(receiver) => receiver.receive(),
+        }
+    }
+
+    fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError> {
+        match self {
+            Self::InterProcess(receiver) => receiver.release(ptr),
+            Self::IntraProcess(receiver) => receiver.release(ptr),
+        }
+    }
+}
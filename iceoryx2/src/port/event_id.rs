@@ -37,29 +37,41 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! The [`EventId`] is defined in the `no_std` capable `iceoryx2-core` crate so that it can be
+//! shared with targets without std.
+//!
+//! # Typed Event Ids
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let event_name = ServiceName::new("MyTypedEventName")?;
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, EventIdEnum)]
+//! #[event_id(max_value = 16)]
+//! enum CameraEvent {
+//!     FrameReady = 1,
+//!     CameraFailure = 2,
+//! }
+//!
+//! let event = zero_copy::Service::new(&event_name)
+//!     .event()
+//!     .event_id_max_value(16)
+//!     .open_or_create()?;
+//!
+//! let mut listener = event.listener().create()?;
+//! let notifier = event.notifier().create()?;
+//!
+//! notifier.notify_with_custom_event_id(CameraEvent::CameraFailure.into())?;
+//!
+//! for event_id in listener.try_wait()? {
+//!     match CameraEvent::try_from(*event_id) {
+//!         Ok(event) => println!("camera event: {:?}", event),
+//!         Err(e) => println!("unknown event id {:?}: {}", event_id, e),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
 
-use iceoryx2_cal::event::TriggerId;
-
-/// Id to identify the source in event based communication.
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub struct EventId(u64);
-
-impl EventId {
-    /// Creates a new [`EventId`] from a given integer value.
-    pub const fn new(value: u64) -> Self {
-        EventId(value)
-    }
-
-    /// Returns the underlying integer value of the [`EventId`].
-    pub const fn as_u64(&self) -> u64 {
-        self.0
-    }
-}
-
-impl Default for EventId {
-    fn default() -> Self {
-        Self::new(0)
-    }
-}
-
-impl TriggerId for EventId {}
+pub use iceoryx2_core::event_id::{EventId, EventIdConversionError, EventIdEnum};
@@ -30,32 +30,91 @@
 //! # }
 //! ```
 //!
+//! When the service was created with an event payload, see
+//! [`crate::service::builder::event::Builder::event_payload()`], the payload that was attached
+//! to the notification is returned together with the [`EventId`].
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let event_name = ServiceName::new("MyPayloadEventName")?;
+//! let event = zero_copy::Service::new(&event_name)
+//!     .event()
+//!     .event_id_max_value(128)
+//!     .event_payload::<u64>()
+//!     .open_or_create()?;
+//!
+//! let mut listener = event.listener().create()?;
+//!
+//! for (event_id, payload) in listener.try_wait_with_payload()? {
+//!     println!("event was triggered with id: {:?} and payload {:?}", event_id, payload);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! See also [`crate::port::listener::Listener`]
 
 use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::{ListenerBuilder, ListenerWaitError};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 
+use crate::service::config_scheme::event_config;
 use crate::service::naming_scheme::event_concept_name;
+use crate::service::stale_resources::add_port_to_dynamic_config;
+use crate::zero_copy_send::ZeroCopySend;
 use crate::{port::port_identifiers::UniqueListenerId, service};
-use std::{marker::PhantomData, time::Duration};
+use std::{fmt::Debug, marker::PhantomData, mem::MaybeUninit, time::Duration};
 
 use super::event_id::EventId;
 use super::listen::{Listen, ListenerCreateError};
 
-/// Represents the receiving endpoint of an event based communication.
+const BITS_PER_ENTRY: u64 = u64::BITS as u64;
+
+/// Represents the receiving endpoint of an event based communication. Multiple notifications
+/// with the same [`EventId`] that arrive before the next wait call are coalesced into a single
+/// entry.
+///
+/// When the service has an event payload, the payload of an [`EventId`] is read when the wait
+/// call returns. The last writer wins, when multiple notifications with the same [`EventId`]
+/// were coalesced only the payload of the latest notification is returned.
+///
+/// # Integration into an external event loop
+///
+/// When the underlying event concept is based on a file descriptor, like the one of
+/// [`crate::service::zero_copy::Service`], the [`Listener`] implements [`FileDescriptorBased`]
+/// and on unix [`std::os::unix::io::AsRawFd`]. The file descriptor can be registered in an
+/// external event loop like epoll, mio or tokio instead of blocking a thread in
+/// [`Listen::blocking_wait()`]. It becomes readable as soon as a notification arrives and stays
+/// readable as long as notifications are pending (level triggered). [`Listen::try_wait()`]
+/// consumes all pending notifications, therefore it must be called whenever the file
+/// descriptor is reported as readable, which makes it suitable for edge triggered event loops
+/// as well. The file descriptor is owned by the [`Listener`] and closed when it goes out of
+/// scope, it must be removed from the event loop before the [`Listener`] is dropped.
+///
+/// Services whose event concept has no file descriptor, like
+/// [`crate::service::process_local::Service`], do not provide these traits.
 #[derive(Debug)]
-pub struct Listener<'a, 'config: 'a, Service: service::Details<'config>> {
+pub struct Listener<'a, 'config: 'a, Service: service::Details<'config>, EventPayload: Debug = ()> {
     _dynamic_config_guard: Option<UniqueIndex<'a>>,
     listener: <Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener,
     cache: Vec<EventId>,
-    _phantom_a: PhantomData<&'a Service>,
+    cached_ids: Vec<u64>,
+    payload_cache: Vec<(EventId, Option<EventPayload>)>,
+    event_id_max_value: u64,
+    has_event_payload: bool,
+    service: &'a Service,
     _phantom_b: PhantomData<&'config ()>,
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config, Service> {
+impl<'a, 'config: 'a, Service: service::Details<'config>, EventPayload: Debug>
+    Listener<'a, 'config, Service, EventPayload>
+{
     pub(crate) fn new(service: &'a Service) -> Result<Self, ListenerCreateError> {
         let msg = "Failed to create listener";
         let origin = "Listener::new()";
@@ -63,28 +122,41 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config,
 
         let event_name = event_concept_name(&port_id);
         let listener = fail!(from origin,
-                             when <Service::Event as iceoryx2_cal::event::Event<EventId>>::ListenerBuilder::new(&event_name).create(),
+                             when <Service::Event as iceoryx2_cal::event::Event<EventId>>::ListenerBuilder::new(&event_name)
+                                    .config(&event_config::<Service>(service.state().global_config))
+                                    .create(),
                              with ListenerCreateError::ResourceCreationFailed,
                              "{} since the underlying event concept \"{}\" could not be created.", msg, event_name);
 
+        let event_id_max_value = service.state().static_config.event().event_id_max_value;
+
         let mut new_self = Self {
             _dynamic_config_guard: None,
             listener,
             cache: vec![],
-            _phantom_a: PhantomData,
+            cached_ids: vec![0; (event_id_max_value / BITS_PER_ENTRY + 1) as usize],
+            payload_cache: vec![],
+            event_id_max_value,
+            has_event_payload: service.state().static_config.event().has_event_payload(),
+            service,
             _phantom_b: PhantomData,
         };
 
         // !MUST! be the last task otherwise a listener is added to the dynamic config without
         // the creation of all required channels
         new_self._dynamic_config_guard = Some(
-            match service
-                .state()
-                .dynamic_storage
-                .get()
-                .event()
-                .add_listener_id(port_id)
-            {
+            match add_port_to_dynamic_config::<Service, _>(
+                service.state().dynamic_storage.get(),
+                service.state().global_config,
+                || {
+                    service
+                        .state()
+                        .dynamic_storage
+                        .get()
+                        .event()
+                        .add_listener_id(port_id)
+                },
+            ) {
                 Some(unique_index) => unique_index,
                 None => {
                     fail!(from origin, with ListenerCreateError::ExceedsMaxSupportedListeners,
@@ -103,22 +175,208 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config,
                 when self.listener.try_wait(),
                 "Failed to try_wait on Listener port since the underlying Listener concept failed.")
         {
-            self.cache.push(id);
+            self.add_to_cache(id);
         }
 
         Ok(())
     }
+
+    fn add_to_cache(&mut self, id: EventId) {
+        if self.event_id_max_value < id.as_u64() {
+            warn!(from self, "Discarding the event id {:?} since it exceeds the maximum supported event id value of {}.",
+                id, self.event_id_max_value);
+            return;
+        }
+
+        let entry = &mut self.cached_ids[(id.as_u64() / BITS_PER_ENTRY) as usize];
+        let bit = 1 << (id.as_u64() % BITS_PER_ENTRY);
+
+        if *entry & bit == 0 {
+            *entry |= bit;
+            self.cache.push(id);
+        }
+    }
+
+    fn clear_cache(&mut self) {
+        for id in &self.cache {
+            self.cached_ids[(id.as_u64() / BITS_PER_ENTRY) as usize] = 0;
+        }
+        self.cache.clear();
+    }
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>> Listen
-    for Listener<'a, 'config, Service>
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        EventPayload: Debug + ZeroCopySend + Copy,
+    > Listener<'a, 'config, Service, EventPayload>
+{
+    fn fill_payload_cache(&mut self) -> &[(EventId, Option<EventPayload>)] {
+        self.payload_cache.clear();
+
+        for id in &self.cache {
+            let payload = match self.has_event_payload {
+                false => None,
+                true => {
+                    let mut payload = MaybeUninit::<EventPayload>::uninit();
+                    let payload_bytes = unsafe {
+                        core::slice::from_raw_parts_mut(
+                            payload.as_mut_ptr() as *mut u8,
+                            core::mem::size_of::<EventPayload>(),
+                        )
+                    };
+
+                    let slot = self
+                        .service
+                        .state()
+                        .dynamic_storage
+                        .get()
+                        .event()
+                        .payload_slot(id.as_u64());
+                    match slot.load(payload_bytes) {
+                        true => Some(unsafe { payload.assume_init() }),
+                        false => None,
+                    }
+                }
+            };
+
+            self.payload_cache.push((*id, payload));
+        }
+
+        &self.payload_cache
+    }
+
+    /// Returns the [`EventId`]s and payloads of the last wait call, see [`Listen::cache()`].
+    pub fn payload_cache(&self) -> &[(EventId, Option<EventPayload>)] {
+        &self.payload_cache
+    }
+
+    /// Like [`Listen::try_wait()`] but returns every [`EventId`] together with the payload of
+    /// its latest notification. When the latest notification had no payload, [`None`] is
+    /// returned.
+    pub fn try_wait_with_payload(
+        &mut self,
+    ) -> Result<&[(EventId, Option<EventPayload>)], ListenerWaitError> {
+        self.try_wait()?;
+        Ok(self.fill_payload_cache())
+    }
+
+    /// Like [`Listen::timed_wait()`] but returns every [`EventId`] together with the payload
+    /// of its latest notification. When the latest notification had no payload, [`None`] is
+    /// returned.
+    pub fn timed_wait_with_payload(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<&[(EventId, Option<EventPayload>)], ListenerWaitError> {
+        self.timed_wait(timeout)?;
+        Ok(self.fill_payload_cache())
+    }
+
+    /// Like [`Listen::blocking_wait()`] but returns every [`EventId`] together with the
+    /// payload of its latest notification. When the latest notification had no payload,
+    /// [`None`] is returned.
+    pub fn blocking_wait_with_payload(
+        &mut self,
+    ) -> Result<&[(EventId, Option<EventPayload>)], ListenerWaitError> {
+        self.blocking_wait()?;
+        Ok(self.fill_payload_cache())
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, EventPayload: Debug> FileDescriptorBased
+    for Listener<'a, 'config, Service, EventPayload>
+where
+    <Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a, 'config: 'a, Service: service::Details<'config>, EventPayload: Debug>
+    Listener<'a, 'config, Service, EventPayload>
+where
+    <Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener: FileDescriptorBased,
+{
+    /// Waits like [`Listen::blocking_wait()`] without blocking the thread, the task is
+    /// suspended until at least one [`EventId`] was received. Requires the `async` feature and
+    /// a running tokio runtime. On unix the file descriptor of the [`Listener`] is registered
+    /// in the reactor of the runtime, on all other platforms the [`Listener`] is checked
+    /// every millisecond since the ports cannot be moved into `tokio::task::spawn_blocking()`.
+    ///
+    /// The future is cancellation safe. The notifications are only consumed when the future
+    /// completes, when it is dropped while it waits, the pending [`EventId`]s are returned by
+    /// the next wait call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+    /// # runtime.block_on(async {
+    /// let event_name = ServiceName::new("MyAsyncEventName")?;
+    /// let event = zero_copy::Service::new(&event_name)
+    ///     .event()
+    ///     .open_or_create()?;
+    ///
+    /// let mut listener = event.listener().create()?;
+    /// let notifier = event.notifier().create()?;
+    /// notifier.notify_with_custom_event_id(EventId::new(7))?;
+    ///
+    /// for event_id in listener.wait_async().await? {
+    ///     println!("event was triggered with id: {:?}", event_id);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_async(&mut self) -> Result<&[EventId], ListenerWaitError> {
+        loop {
+            if !self.try_wait()?.is_empty() {
+                return Ok(self.cache());
+            }
+
+            if let Err(e) = crate::port::details::readiness::readable(&self.listener).await {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "Failed to wait_async on Listener port since the file descriptor could not be registered in the async runtime ({:?}).", e);
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+impl<'a, 'config: 'a, Service: service::Details<'config>, EventPayload: Debug>
+    std::os::unix::io::AsRawFd for Listener<'a, 'config, Service, EventPayload>
+where
+    <Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener: FileDescriptorBased,
+{
+    fn as_raw_fd(&self) -> std::os::unix::io::RawFd {
+        unsafe { self.listener.file_descriptor().native_handle() }
+    }
+}
+
+// A listener can be attached to a [`crate::waitset::WaitSet`] whenever the underlying event
+// concept is based on a file descriptor.
+impl<'a, 'config: 'a, Service: service::Details<'config>, EventPayload: Debug>
+    SynchronousMultiplexing for Listener<'a, 'config, Service, EventPayload>
+where
+    <Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener: SynchronousMultiplexing,
+{
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, EventPayload: Debug> Listen
+    for Listener<'a, 'config, Service, EventPayload>
 {
     fn cache(&self) -> &[EventId] {
         &self.cache
     }
 
     fn try_wait(&mut self) -> Result<&[EventId], ListenerWaitError> {
-        self.cache.clear();
+        self.clear_cache();
         self.fill_cache()?;
 
         Ok(self.cache())
@@ -126,13 +384,13 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Listen
 
     fn timed_wait(&mut self, timeout: Duration) -> Result<&[EventId], ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        self.cache.clear();
+        self.clear_cache();
 
         if let Some(id) = fail!(from self,
             when self.listener.timed_wait(timeout),
             "Failed to timed_wait with timeout {:?} on Listener port since the underlying Listener concept failed.", timeout)
         {
-            self.cache.push(id);
+            self.add_to_cache(id);
             self.fill_cache()?;
         }
 
@@ -141,13 +399,13 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Listen
 
     fn blocking_wait(&mut self) -> Result<&[EventId], ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        self.cache.clear();
+        self.clear_cache();
 
         if let Some(id) = fail!(from self,
             when self.listener.blocking_wait(),
             "Failed to blocking_wait on Listener port since the underlying Listener concept failed.")
         {
-            self.cache.push(id);
+            self.add_to_cache(id);
             self.fill_cache()?;
         }
 
@@ -16,6 +16,10 @@ use tiny_fn::tiny_fn;
 
 pub(crate) mod details;
 
+/// Requesting endpoint (port) for request-response based communication
+pub mod client;
+/// Reports when the counterparts of a publish-subscribe port connect or disconnect
+pub mod connection_monitor;
 /// Defines the event id used to identify the source of an event.
 pub mod event_id;
 /// The interface of the receiving endpoint for event based communication
@@ -32,10 +36,17 @@ pub mod port_identifiers;
 pub mod publish;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
+/// Responding endpoint (port) for request-response based communication
+pub mod server;
 /// The interface of the receiving endpoint for publish-subscribe based communication
 pub mod subscribe;
 /// Receiving endpoint (port) for publish-subscribe based communication
 pub mod subscriber;
+/// Thread-safe sending endpoint (port) for publish-subscribe based communication
+pub mod sync_publisher;
+/// Per port callbacks at the stations of the lifetime of a sample, for instance to instrument
+/// the latency with a tracing framework
+pub mod tracing_hooks;
 /// Interface to perform cyclic updates to the ports. Required to deliver history to new
 /// participants or to perform other management tasks.
 pub mod update_connections;
@@ -51,6 +62,7 @@ use crate::service;
 ///
 /// * [`publisher::Publisher::set_degration_callback()`]
 /// * [`subscriber::Subscriber::set_degration_callback()`]
+/// * [`sync_publisher::SyncPublisher::set_degration_callback()`]
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum DegrationAction {
     /// Ignore the degration completely
@@ -35,21 +35,46 @@
 //! # }
 //! ```
 //!
+//! When the service was created with an event payload, see
+//! [`crate::service::builder::event::Builder::event_payload()`], a payload can be attached to
+//! the notification.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let event_name = ServiceName::new("MyPayloadEventName")?;
+//! let event = zero_copy::Service::new(&event_name)
+//!     .event()
+//!     .event_id_max_value(128)
+//!     .event_payload::<u64>()
+//!     .open_or_create()?;
+//!
+//! let notifier = event.notifier().create()?;
+//! notifier.notify_with_payload(EventId::new(12), 8912)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! See also [`crate::port::notifier::Notifier`]
 
 use crate::{
     port::port_identifiers::UniqueNotifierId,
-    service::{self, naming_scheme::event_concept_name},
+    service::{
+        self, config_scheme::event_config, naming_scheme::event_concept_name,
+        stale_resources::add_port_to_dynamic_config,
+    },
+    zero_copy_send::ZeroCopySend,
 };
 use iceoryx2_bb_lock_free::mpmc::{container::ContainerState, unique_index_set::UniqueIndex};
 use iceoryx2_bb_log::{fail, warn};
-use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptMgmt};
 use iceoryx2_cal::{dynamic_storage::DynamicStorage, event::NotifierBuilder};
-use std::{cell::UnsafeCell, marker::PhantomData};
+use std::{cell::UnsafeCell, fmt::Debug, marker::PhantomData};
 
 use super::{
     event_id::EventId,
-    notify::{NotifierConnectionUpdateFailure, NotifierCreateError, Notify},
+    notify::{NotifierConnectionUpdateFailure, NotifierCreateError, NotifierNotifyError, Notify},
     port_identifiers::UniqueListenerId,
 };
 
@@ -58,12 +83,14 @@ struct ListenerConnections<'config, Service: service::Details<'config>> {
     #[allow(clippy::type_complexity)]
     connections:
         Vec<UnsafeCell<Option<<Service::Event as iceoryx2_cal::event::Event<EventId>>::Notifier>>>,
+    event_config: <Service::Event as NamedConceptMgmt>::Configuration,
 }
 
 impl<'config, Service: service::Details<'config>> ListenerConnections<'config, Service> {
-    fn new(size: usize) -> Self {
+    fn new(size: usize, event_config: <Service::Event as NamedConceptMgmt>::Configuration) -> Self {
         let mut new_self = Self {
             connections: vec![],
+            event_config,
         };
 
         new_self.connections.reserve(size);
@@ -77,7 +104,7 @@ impl<'config, Service: service::Details<'config>> ListenerConnections<'config, S
     fn create(&self, index: usize, listener_id: UniqueListenerId) -> Result<(), ()> {
         let event_name = event_concept_name(&listener_id);
         if self.get(index).is_none() {
-            let notifier = fail!(from self, when <Service::Event as iceoryx2_cal::event::Event<EventId>>::NotifierBuilder::new(&event_name).open(),
+            let notifier = fail!(from self, when <Service::Event as iceoryx2_cal::event::Event<EventId>>::NotifierBuilder::new(&event_name).config(&self.event_config).open(),
                                     with (),
                                     "Unable to establish a connection to Listener port {:?}.", listener_id);
             *self.get_mut(index) = Some(notifier);
@@ -112,16 +139,21 @@ impl<'config, Service: service::Details<'config>> ListenerConnections<'config, S
 
 /// Represents the sending endpoint of an event based communication.
 #[derive(Debug)]
-pub struct Notifier<'a, 'config: 'a, Service: service::Details<'config>> {
+pub struct Notifier<'a, 'config: 'a, Service: service::Details<'config>, EventPayload: Debug = ()> {
     listener_connections: ListenerConnections<'config, Service>,
     listener_list_state: UnsafeCell<ContainerState<'a, UniqueListenerId>>,
     default_event_id: EventId,
+    event_id_max_value: u64,
+    has_event_payload: bool,
+    service: &'a Service,
     _dynamic_config_guard: Option<UniqueIndex<'a>>,
-    _phantom_a: PhantomData<&'a Service>,
+    _phantom_event_payload: PhantomData<EventPayload>,
     _phantom_b: PhantomData<&'config ()>,
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>> Notifier<'a, 'config, Service> {
+impl<'a, 'config: 'a, Service: service::Details<'config>, EventPayload: Debug>
+    Notifier<'a, 'config, Service, EventPayload>
+{
     pub(crate) fn new(
         service: &'a Service,
         default_event_id: EventId,
@@ -133,23 +165,34 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Notifier<'a, 'config,
         let listener_list = &service.state().dynamic_storage.get().event().listeners;
 
         let mut new_self = Self {
-            listener_connections: ListenerConnections::new(listener_list.capacity()),
+            listener_connections: ListenerConnections::new(
+                listener_list.capacity(),
+                event_config::<Service>(service.state().global_config),
+            ),
             default_event_id,
+            event_id_max_value: service.state().static_config.event().event_id_max_value,
+            has_event_payload: service.state().static_config.event().has_event_payload(),
+            service,
             listener_list_state: unsafe { UnsafeCell::new(listener_list.get_state()) },
             _dynamic_config_guard: None,
-            _phantom_a: PhantomData,
+            _phantom_event_payload: PhantomData,
             _phantom_b: PhantomData,
         };
 
         // !MUST! be the last task otherwise a publisher is added to the dynamic config without the
         // creation of all required resources
-        let _dynamic_config_guard = match service
-            .state()
-            .dynamic_storage
-            .get()
-            .event()
-            .add_notifier_id(port_id)
-        {
+        let _dynamic_config_guard = match add_port_to_dynamic_config::<Service, _>(
+            service.state().dynamic_storage.get(),
+            service.state().global_config,
+            || {
+                service
+                    .state()
+                    .dynamic_storage
+                    .get()
+                    .event()
+                    .add_notifier_id(port_id)
+            },
+        ) {
             Some(unique_index) => unique_index,
             None => {
                 fail!(from origin, with NotifierCreateError::ExceedsMaxSupportedNotifiers,
@@ -202,19 +245,30 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Notifier<'a, 'config,
 
         Ok(())
     }
-}
 
-impl<'a, 'config: 'a, Service: service::Details<'config>> Notify
-    for Notifier<'a, 'config, Service>
-{
-    fn notify(&self) -> Result<usize, NotifierConnectionUpdateFailure> {
-        self.notify_with_custom_event_id(self.default_event_id)
+    fn store_payload(&self, value: EventId, payload: Option<&[u8]>) {
+        if self.has_event_payload {
+            self.service
+                .state()
+                .dynamic_storage
+                .get()
+                .event()
+                .payload_slot(value.as_u64())
+                .store(payload);
+        }
     }
 
-    fn notify_with_custom_event_id(
-        &self,
-        value: EventId,
-    ) -> Result<usize, NotifierConnectionUpdateFailure> {
+    fn verify_event_id(&self, value: EventId) -> Result<(), NotifierNotifyError> {
+        if self.event_id_max_value < value.as_u64() {
+            fail!(from self, with NotifierNotifyError::EventIdOutOfBounds,
+                "Unable to notify event since the event id {:?} exceeds the maximum supported event id value of {}.",
+                value, self.event_id_max_value);
+        }
+
+        Ok(())
+    }
+
+    fn trigger(&self, value: EventId) -> Result<usize, NotifierNotifyError> {
         fail!(from self, when self.update_connections(),
             "Unable to notify event since the connections could not be updated.");
 
@@ -238,3 +292,51 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Notify
         Ok(number_of_triggered_listeners)
     }
 }
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        EventPayload: Debug + ZeroCopySend + Copy,
+    > Notifier<'a, 'config, Service, EventPayload>
+{
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with the
+    /// provided [`EventId`] and attaches the payload to it. The service stores one payload per
+    /// [`EventId`], when it is notified multiple times before a
+    /// [`crate::port::listener::Listener`] waits on it again, the listener receives only the
+    /// payload of the last notification. On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify_with_payload(
+        &self,
+        value: EventId,
+        payload: EventPayload,
+    ) -> Result<usize, NotifierNotifyError> {
+        self.verify_event_id(value)?;
+
+        let payload = unsafe {
+            core::slice::from_raw_parts(
+                (&payload as *const EventPayload) as *const u8,
+                core::mem::size_of::<EventPayload>(),
+            )
+        };
+        self.store_payload(value, Some(payload));
+        self.trigger(value)
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, EventPayload: Debug> Notify
+    for Notifier<'a, 'config, Service, EventPayload>
+{
+    fn notify(&self) -> Result<usize, NotifierNotifyError> {
+        self.notify_with_custom_event_id(self.default_event_id)
+    }
+
+    fn notify_with_custom_event_id(&self, value: EventId) -> Result<usize, NotifierNotifyError> {
+        self.verify_event_id(value)?;
+
+        // a notification without payload replaces the payload of a previous notification
+        self.store_payload(value, None);
+        self.trigger(value)
+    }
+}
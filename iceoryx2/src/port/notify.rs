@@ -40,6 +40,8 @@
 //!
 //! See also [`crate::port::notifier::Notifier`]
 
+use iceoryx2_bb_elementary::enum_gen;
+
 use super::event_id::EventId;
 
 /// Failures that can occur when a new [`Notify`]er is created with the
@@ -57,8 +59,8 @@ impl std::fmt::Display for NotifierCreateError {
 
 impl std::error::Error for NotifierCreateError {}
 
-/// Defines the failures that can occur while a [`Notify::notify()`] call.
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+/// Defines the failures that can occur while the connections of a [`Notify`]er are updated.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub enum NotifierConnectionUpdateFailure {
     OnlyPartialUpdate,
 }
@@ -71,22 +73,38 @@ impl std::fmt::Display for NotifierConnectionUpdateFailure {
 
 impl std::error::Error for NotifierConnectionUpdateFailure {}
 
+enum_gen! {
+    /// Defines the failures that can occur while a [`Notify::notify()`] call.
+    NotifierNotifyError
+  entry:
+    EventIdOutOfBounds
+  mapping:
+    NotifierConnectionUpdateFailure to ConnectionUpdateFailure
+}
+
+impl std::fmt::Display for NotifierNotifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for NotifierNotifyError {}
+
 /// The interface of the sending endpoint of an event based communication.
 pub trait Notify {
     /// Notifies all [`crate::port::listener::Listener`] connected to the service with the default
     /// event id provided on creation.
     /// On success the number of
     /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
-    /// [`NotifierConnectionUpdateFailure`].
-    fn notify(&self) -> Result<usize, NotifierConnectionUpdateFailure>;
+    /// [`NotifierNotifyError`].
+    fn notify(&self) -> Result<usize, NotifierNotifyError>;
 
     /// Notifies all [`crate::port::listener::Listener`] connected to the service with a custom
-    /// [`EventId`].
+    /// [`EventId`]. The value of the [`EventId`] must not exceed
+    /// [`crate::service::static_config::event::StaticConfig::event_id_max_value()`], otherwise
+    /// [`NotifierNotifyError::EventIdOutOfBounds`] is returned.
     /// On success the number of
     /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
-    /// [`NotifierConnectionUpdateFailure`].
-    fn notify_with_custom_event_id(
-        &self,
-        value: EventId,
-    ) -> Result<usize, NotifierConnectionUpdateFailure>;
+    /// [`NotifierNotifyError`].
+    fn notify_with_custom_event_id(&self, value: EventId) -> Result<usize, NotifierNotifyError>;
 }
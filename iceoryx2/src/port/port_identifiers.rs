@@ -15,7 +15,15 @@ use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 
 macro_rules! generate_id {
     { $id_name:ident } => {
-        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        /// A system wide unique id that contains the process id of its creator. Its textual
+        /// representation and its integer and byte representations are stable and can be
+        /// used to correlate ports across processes.
+        ///
+        /// The id is not reused until the system is rebooted. It combines the process id with
+        /// the creation time of the monotonic clock, and ids that are created in the same
+        /// nanosecond are postponed to the next one.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(transparent)]
         pub struct $id_name(pub(crate) UniqueSystemId);
 
         impl Default for $id_name {
@@ -31,6 +39,84 @@ macro_rules! generate_id {
             pub fn new() -> Self {
                 Self::default()
             }
+
+            /// Returns the underlying integer representation of the id.
+            pub fn value(&self) -> u128 {
+                self.0.value()
+            }
+
+            /// Returns the big endian byte representation of the id.
+            pub fn to_bytes(&self) -> [u8; 16] {
+                self.value().to_be_bytes()
+            }
+
+            /// Restores the id from the byte representation that was acquired with
+            /// `to_bytes()`.
+            pub fn from_bytes(bytes: [u8; 16]) -> Self {
+                Self::from(u128::from_be_bytes(bytes))
+            }
+        }
+
+        impl From<u128> for $id_name {
+            fn from(value: u128) -> Self {
+                Self(UniqueSystemId::from(value))
+            }
+        }
+
+        impl From<$id_name> for u128 {
+            fn from(value: $id_name) -> Self {
+                value.value()
+            }
+        }
+
+        impl std::fmt::Display for $id_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::write!(f, "{:032x}", self.value())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $id_name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(&self.to_string())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $id_name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct IdVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for IdVisitor {
+                    type Value = $id_name;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                        formatter.write_str("a string containing the 32 digit hexadecimal id")
+                    }
+
+                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match u128::from_str_radix(v, 16) {
+                            Ok(value) if v.len() == 32 => Ok($id_name::from(value)),
+                            _ => Err(E::custom(format!(
+                                "invalid {} provided \"{}\".",
+                                stringify!($id_name),
+                                v
+                            ))),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_str(IdVisitor)
+            }
         }
     };
 }
@@ -39,3 +125,5 @@ generate_id! { UniquePublisherId }
 generate_id! { UniqueSubscriberId }
 generate_id! { UniqueNotifierId }
 generate_id! { UniqueListenerId }
+generate_id! { UniqueClientId }
+generate_id! { UniqueServerId }
@@ -45,76 +45,397 @@ use crate::port::update_connections::UpdateConnections;
 use std::{fmt::Debug, mem::MaybeUninit};
 
 use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_cal::zero_copy_connection::ZeroCopyCreationError;
 
 use crate::sample_mut::SampleMut;
+use crate::service::service_name::ServiceName;
 
+use super::port_identifiers::UniqueSubscriberId;
 use super::update_connections::ConnectionFailure;
 
 /// Defines a failure that can occur when a [`Publish`] is created with
 /// [`crate::service::port_factory::publisher::PortFactoryPublisher`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[non_exhaustive]
 pub enum PublisherCreateError {
     ExceedsMaxSupportedPublishers,
     UnableToCreateDataSegment,
+    InvalidPayloadTypeDetails,
+    IncompatibleUnableToDeliverStrategy,
+    IncompatibleAllocationStrategy,
+    UnableToCreateNotificationListener,
+    InvalidBucketConfig,
+    UnableToLockMemory,
+    HugePagesUnavailable,
 }
 
 impl std::fmt::Display for PublisherCreateError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+        let hint = match self {
+            PublisherCreateError::ExceedsMaxSupportedPublishers => "the service already has the maximum number of publishers, drop another publisher or create the service with a larger max_publishers",
+            PublisherCreateError::UnableToCreateDataSegment => "the shared memory for the samples could not be created, check the permissions and the available memory of the system",
+            PublisherCreateError::InvalidPayloadTypeDetails => "the payload type details of the service cannot be used for an untyped port",
+            PublisherCreateError::IncompatibleUnableToDeliverStrategy => "the service discards samples that cannot be delivered, a publisher of it cannot block",
+            PublisherCreateError::IncompatibleAllocationStrategy => "the service has deterministic memory or the publisher uses a BucketConfig, the data segments cannot be extended and the publisher must use AllocationStrategy::Static",
+            PublisherCreateError::UnableToCreateNotificationListener => "the listener that is woken up by the subscribers could not be created, check the permissions of the service directory",
+            PublisherCreateError::InvalidBucketConfig => "the BucketConfig must consist of 1 to MAX_NUMBER_OF_BUCKETS non-empty buckets with distinct payload sizes that hold at least one element of the payload type",
+            PublisherCreateError::UnableToLockMemory => "the data segment exceeds the memory lock limit of the process, raise RLIMIT_MEMLOCK with \"ulimit -l\" or grant the CAP_IPC_LOCK capability",
+            PublisherCreateError::HugePagesUnavailable => "the data segment cannot be backed by huge pages, enable transparent huge pages for shared memory or disable strict huge pages",
+        };
+        std::write!(f, "{}::{:?}: {}", std::stringify!(Self), self, hint)
     }
 }
 
 impl std::error::Error for PublisherCreateError {}
 
-/// Defines a failure that can occur in [`DefaultLoan::loan()`] and [`UninitLoan::loan_uninit()`]
-/// or is part of [`PublisherSendError`] emitted in [`SendCopy::send_copy()`].
+/// Defines a failure that can occur in [`DefaultLoan::loan()`], [`UninitLoan::loan_uninit()`] and
+/// [`crate::port::publisher::Publisher::loan_slice_uninit()`] or is part of [`PublisherSendError`]
+/// emitted in [`SendCopy::send_copy()`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+#[non_exhaustive]
 pub enum PublisherLoanError {
+    /// The data segment has currently no free sample. It is a transient condition, the loan
+    /// succeeds again as soon as a subscriber releases a sample it holds. See
+    /// [`crate::port::publisher::Publisher::timed_loan_uninit()`].
     OutOfMemory,
-    ExceedsMaxLoanedChunks,
+    /// The [`crate::port::publisher::Publisher`] itself already holds the configured maximum
+    /// of loaned samples. Waiting does not resolve it, the caller has to send or drop one of its
+    /// loans first.
+    ExceedsMaxLoanedSamples,
+    /// The bucket of the [`crate::service::port_factory::publisher::BucketConfig`] that serves
+    /// the requested payload size has currently no free sample, the payload size of the bucket
+    /// is `bucket_size`. Like [`PublisherLoanError::OutOfMemory`] it is a transient condition,
+    /// larger buckets are not used as fallback.
+    BucketExhausted {
+        bucket_size: usize,
+    },
+    ExceedsMaxLoanSize,
+    PayloadSizeIsNotAMultipleOfTypeSize,
     InternalFailure,
 }
 
 impl std::fmt::Display for PublisherLoanError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+        let hint = match self {
+            PublisherLoanError::OutOfMemory => "the data segment has currently no free sample, retry or wait with timed_loan_uninit until the subscribers release the samples they hold or configure a larger data segment",
+            PublisherLoanError::ExceedsMaxLoanedSamples => "the publisher already holds the maximum number of loaned samples, retrying does not help, send or drop one of them or increase max_loaned_samples",
+            PublisherLoanError::BucketExhausted { .. } => "the bucket of the requested payload size has currently no free sample, retry or wait until the subscribers release the samples they hold or configure more samples for the bucket",
+            PublisherLoanError::ExceedsMaxLoanSize => "the requested slice is larger than supported, create the publisher with a larger max_slice_len",
+            PublisherLoanError::PayloadSizeIsNotAMultipleOfTypeSize => "the number of bytes must be a multiple of the size of the payload type of the service",
+            PublisherLoanError::InternalFailure => "an internal failure occurred, check the log output for details",
+        };
+        std::write!(f, "{}::{:?}: {}", std::stringify!(Self), self, hint)
     }
 }
 
 impl std::error::Error for PublisherLoanError {}
 
 enum_gen! {
-    /// Failure that can be emitted when a [`crate::sample::Sample`] is sent via [`Publisher::send()`].
-    PublisherSendError
+    /// Defines the failures that can occur while a [`crate::port::publisher::Publisher`]
+    /// waits for a free sample with
+    /// [`crate::port::publisher::Publisher::timed_loan_uninit()`] or
+    /// [`crate::port::publisher::Publisher::blocking_loan_uninit()`] or until a sample was
+    /// consumed with [`crate::port::publisher::Publisher::wait_consumed()`].
+    PublisherWaitError
+  entry:
+    NotificationsNotEnabled,
+    ConsumptionTrackingNotEnabled,
+    InternalFailure
   mapping:
-    PublisherLoanError to LoanError,
-    ConnectionFailure to ConnectionError
+    PublisherLoanError to LoanError
 }
 
-impl std::fmt::Display for PublisherSendError {
+impl std::fmt::Display for PublisherWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for PublisherWaitError {}
+
+/// Describes why a sample could not be delivered to a [`crate::port::subscriber::Subscriber`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum DeliveryFailureReason {
+    /// The connection to the subscriber could not be established. The
+    /// [`crate::port::publisher::Publisher`] retries to establish it on every send.
+    UnableToEstablishConnection(ZeroCopyCreationError),
+    /// The subscriber did not return the samples it received and the channel that returns them
+    /// to the publisher is full. This can be caused by a corrupted retrieve channel.
+    RetrieveChannelFull,
+}
+
+impl std::fmt::Display for DeliveryFailureReason {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::write!(f, "{}::{:?}", std::stringify!(Self), self)
     }
 }
 
-impl std::error::Error for PublisherSendError {}
+/// A [`crate::port::subscriber::Subscriber`] that did not receive a sample together with the
+/// operation that failed and the reason of the failure.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct DeliveryFailure {
+    subscriber_id: UniqueSubscriberId,
+    operation: ConnectionOperation,
+    reason: DeliveryFailureReason,
+}
+
+impl DeliveryFailure {
+    pub(crate) fn new(
+        subscriber_id: UniqueSubscriberId,
+        operation: ConnectionOperation,
+        reason: DeliveryFailureReason,
+    ) -> Self {
+        Self {
+            subscriber_id,
+            operation,
+            reason,
+        }
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the subscriber that did not receive the sample.
+    pub fn subscriber_id(&self) -> UniqueSubscriberId {
+        self.subscriber_id
+    }
+
+    /// Returns the [`ConnectionOperation`] that failed.
+    pub fn operation(&self) -> ConnectionOperation {
+        self.operation
+    }
+
+    /// Returns why the sample could not be delivered.
+    pub fn reason(&self) -> DeliveryFailureReason {
+        self.reason
+    }
+}
+
+impl std::fmt::Display for DeliveryFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(
+            f,
+            "{}: the sample was not delivered to subscriber {} since {:?} failed with {}",
+            std::stringify!(Self),
+            self.subscriber_id,
+            self.operation,
+            self.reason
+        )
+    }
+}
+
+impl std::error::Error for DeliveryFailure {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.reason {
+            DeliveryFailureReason::UnableToEstablishConnection(e) => Some(e),
+            DeliveryFailureReason::RetrieveChannelFull => None,
+        }
+    }
+}
+
+/// Identifies a sample that was sent with [`crate::sample_mut::SampleMut::send_with_token()`]
+/// by a [`crate::port::publisher::Publisher`] with consumption tracking. It is used to wait
+/// with [`crate::port::publisher::Publisher::wait_consumed()`] until the sample was consumed.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, PartialOrd, Ord)]
+pub struct SampleToken {
+    sequence_number: u64,
+}
+
+impl SampleToken {
+    pub(crate) fn new(sequence_number: u64) -> Self {
+        Self { sequence_number }
+    }
+
+    /// Returns the sequence number of the sample, see
+    /// [`crate::service::header::publish_subscribe::Header::sequence_number()`].
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+}
+
+/// The operation that failed when a [`crate::port::publisher::Publisher`] connected or delivered
+/// a sample to a [`crate::port::subscriber::Subscriber`], see
+/// [`SubscriberConnectionError::operation()`] and [`DeliveryFailure::operation()`].
+///
+/// The creation of a channel includes the creation and mapping of its shared memory segment.
+/// The data segments of the [`crate::port::publisher::Publisher`] are mapped by the
+/// subscribers and the files of a channel are removed when it is dropped, both happen outside
+/// of the publish path and failures are logged on that side.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+#[non_exhaustive]
+pub enum ConnectionOperation {
+    /// The zero copy channel in shared memory to a
+    /// [`crate::port::subscriber::Subscriber`] of another process could not be created.
+    CreateChannel,
+    /// The channel of the intra process fast path to a
+    /// [`crate::port::subscriber::Subscriber`] of the same process could not be created.
+    CreateIntraProcessChannel,
+    /// The sample could not be pushed into the queue of the channel to the
+    /// [`crate::port::subscriber::Subscriber`].
+    PushSample,
+}
+
+/// Describes why a [`crate::port::publisher::Publisher`] could not connect to a
+/// [`crate::port::subscriber::Subscriber`]. The underlying error is available via
+/// [`std::error::Error::source()`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SubscriberConnectionError {
+    service_name: Box<ServiceName>,
+    subscriber_id: UniqueSubscriberId,
+    operation: ConnectionOperation,
+    source: ZeroCopyCreationError,
+}
+
+impl SubscriberConnectionError {
+    pub(crate) fn new(
+        service_name: ServiceName,
+        subscriber_id: UniqueSubscriberId,
+        operation: ConnectionOperation,
+        source: ZeroCopyCreationError,
+    ) -> Self {
+        Self {
+            service_name: Box::new(service_name),
+            subscriber_id,
+            operation,
+            source,
+        }
+    }
+
+    /// Returns the [`ServiceName`] of the service of the connection.
+    pub fn service_name(&self) -> &ServiceName {
+        &self.service_name
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the subscriber that could not be connected.
+    pub fn subscriber_id(&self) -> UniqueSubscriberId {
+        self.subscriber_id
+    }
+
+    /// Returns the [`ConnectionOperation`] that failed.
+    pub fn operation(&self) -> ConnectionOperation {
+        self.operation
+    }
+
+    /// Returns the [`ConnectionFailure`] that is reported by
+    /// [`UpdateConnections::update_connections()`] for this error.
+    pub fn failure(&self) -> ConnectionFailure {
+        ConnectionFailure::FailedToEstablishConnection(self.source)
+    }
+}
+
+impl std::fmt::Display for SubscriberConnectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(
+            f,
+            "{}: unable to connect to subscriber {} of service \"{}\" since {:?} failed with {}, the connection is retried with the next update",
+            std::stringify!(Self),
+            self.subscriber_id,
+            self.service_name,
+            self.operation,
+            self.source
+        )
+    }
+}
+
+impl std::error::Error for SubscriberConnectionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Failure that can be emitted when a [`crate::sample::Sample`] is sent via
+/// [`crate::payload_mut::PayloadMut::send()`] or [`SendCopy::send_copy()`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum PublisherSendError {
+    LoanError(PublisherLoanError),
+    ConnectionError(SubscriberConnectionError),
+    /// The sample was sent but not every [`crate::port::subscriber::Subscriber`] received it.
+    /// Contains the number of subscribers that received the sample and a [`DeliveryFailure`] for
+    /// every subscriber that did not.
+    PartialDelivery {
+        number_of_recipients: usize,
+        failures: Vec<DeliveryFailure>,
+    },
+}
+
+impl From<PublisherLoanError> for PublisherSendError {
+    fn from(value: PublisherLoanError) -> Self {
+        PublisherSendError::LoanError(value)
+    }
+}
+
+impl From<SubscriberConnectionError> for PublisherSendError {
+    fn from(value: SubscriberConnectionError) -> Self {
+        PublisherSendError::ConnectionError(value)
+    }
+}
+
+impl std::fmt::Display for PublisherSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PublisherSendError::LoanError(e) => std::write!(f, "{}::LoanError({})", std::stringify!(Self), e),
+            PublisherSendError::ConnectionError(e) => {
+                std::write!(f, "{}::ConnectionError({})", std::stringify!(Self), e)
+            }
+            PublisherSendError::PartialDelivery {
+                number_of_recipients,
+                failures,
+            } => std::write!(
+                f,
+                "{}::PartialDelivery: the sample was delivered to {} subscribers but {} did not receive it, inspect the delivery failures for the reasons",
+                std::stringify!(Self),
+                number_of_recipients,
+                failures.len()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PublisherSendError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PublisherSendError::LoanError(e) => Some(e),
+            PublisherSendError::ConnectionError(e) => Some(e),
+            PublisherSendError::PartialDelivery { .. } => None,
+        }
+    }
+}
 
 pub(crate) mod internal {
     use std::fmt::Debug;
 
     use iceoryx2_cal::zero_copy_connection::PointerOffset;
 
-    use crate::port::update_connections::ConnectionFailure;
+    use super::{PublisherSendError, SampleToken};
+
+    /// Defines how the loan of a [`crate::sample_mut::SampleMut`] ended.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum LoanRelease {
+        Sent,
+        Cancelled,
+        Dropped,
+    }
 
     pub(crate) trait PublishMgmt: Debug {
-        fn return_loaned_sample(&self, distance_to_chunk: PointerOffset);
-        fn send_impl(&self, address_to_chunk: usize) -> Result<usize, ConnectionFailure>;
+        fn return_loaned_sample(&self, distance_to_chunk: PointerOffset, release: LoanRelease);
+        fn allows_user_time_stamps(&self) -> bool;
+        fn send_impl(
+            &self,
+            address_to_chunk: usize,
+            keep_time_stamp: bool,
+        ) -> Result<usize, PublisherSendError>;
+        fn send_with_token_impl(
+            &self,
+            address_to_chunk: usize,
+            keep_time_stamp: bool,
+        ) -> Result<SampleToken, PublisherSendError>;
     }
 }
 
 /// Interface of the sending endpoint of a publish-subscriber based communication.
-pub trait Publish<MessageType: Debug + Default>:
-    DefaultLoan<MessageType> + UninitLoan<MessageType> + UpdateConnections + SendCopy<MessageType>
+pub trait Publish<MessageType: Debug + Default, UserHeader: Debug = ()>:
+    DefaultLoan<MessageType, UserHeader>
+    + UninitLoan<MessageType, UserHeader>
+    + UpdateConnections
+    + SendCopy<MessageType>
 {
 }
 
@@ -145,7 +466,7 @@ pub trait SendCopy<MessageType: Debug> {
 }
 
 /// Allows loaning of uninitialized shared memory that can be used for storing the payload of the message.
-pub trait UninitLoan<MessageType: Debug> {
+pub trait UninitLoan<MessageType: Debug, UserHeader: Debug = ()> {
     /// Loans/allocates a [`crate::sample_mut::SampleMut`] from the underlying data segment of the [`Publish`]er.
     /// The user has to initialize the payload before it can be sent.
     ///
@@ -172,11 +493,13 @@ pub trait UninitLoan<MessageType: Debug> {
     /// # Ok(())
     /// # }
     /// ```
-    fn loan_uninit(&self) -> Result<SampleMut<MaybeUninit<MessageType>>, PublisherLoanError>;
+    fn loan_uninit(
+        &self,
+    ) -> Result<SampleMut<MaybeUninit<MessageType>, UserHeader>, PublisherLoanError>;
 }
 
 /// Allows loaning shared memory that can be used for storing the payload of the message.
-pub trait DefaultLoan<MessageType: Debug + Default> {
+pub trait DefaultLoan<MessageType: Debug + Default, UserHeader: Debug = ()> {
     /// Loans/allocates a [`crate::sample_mut::SampleMut`] from the underlying data segment of the [`Publish`]
     /// and initialize it with the default value. This can be a performance hit and [`UninitLoan::loan_uninit`]
     /// can be used to loan a [`core::mem::MaybeUninit<MessageType>`].
@@ -204,5 +527,5 @@ pub trait DefaultLoan<MessageType: Debug + Default> {
     /// # Ok(())
     /// # }
     /// ```
-    fn loan(&self) -> Result<SampleMut<MessageType>, PublisherLoanError>;
+    fn loan(&self) -> Result<SampleMut<MessageType, UserHeader>, PublisherLoanError>;
 }
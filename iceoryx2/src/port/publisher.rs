@@ -55,73 +55,263 @@
 //!
 //! See also, [`crate::port::publisher::Publisher`]
 
-use std::cell::UnsafeCell;
+use std::cell::{Cell, UnsafeCell};
+use std::collections::VecDeque;
 use std::fmt::Debug;
-use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::atomic::{fence, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
 
+use super::connection_monitor::{
+    announce_connection_change, ConnectionMonitor, ConnectionMonitorCreateError,
+    WaitForConnectionsError,
+};
 use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
-use super::publish::internal::PublishMgmt;
+use super::publish::internal::{LoanRelease, PublishMgmt};
 use super::publish::{
-    DefaultLoan, Publish, PublisherCreateError, PublisherLoanError, PublisherSendError, SendCopy,
-    UninitLoan,
+    ConnectionOperation, DefaultLoan, DeliveryFailure, DeliveryFailureReason, Publish,
+    PublisherCreateError, PublisherLoanError, PublisherSendError, PublisherWaitError, SampleToken,
+    SendCopy, SubscriberConnectionError, UninitLoan,
 };
 use crate::message::Message;
-use crate::payload_mut::{internal::PayloadMgmt, PayloadMut, UninitPayloadMut};
+use crate::payload_mut::{PayloadMut, UninitPayloadMut};
+use crate::payload_type::PayloadType;
+use crate::port::details::chunk_guard::{self, UsedChunkList};
+use crate::port::details::data_segment::DataSegment;
+use crate::port::details::segment_offset::{SegmentOffset, MAX_NUMBER_OF_SEGMENTS};
 use crate::port::details::subscriber_connections::*;
+use crate::port::details::zero_copy_channel::{is_intra_process, ChannelSender};
+use crate::port::event_id::EventId;
+use crate::port::sync_publisher::SyncPublisher;
+use crate::port::tracing_hooks::TracingHooks;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
 use crate::port::{DegrationAction, DegrationCallback};
 use crate::raw_sample::RawSampleMut;
 use crate::service;
-use crate::service::config_scheme::data_segment_config;
+use crate::service::config_scheme::{data_segment_config, event_config};
+use crate::service::dynamic_config::publish_subscribe::{
+    BucketCounters, DynamicConfig, PublisherCounters, PublisherStatistics, SubscriberDetails,
+};
 use crate::service::header::publish_subscribe::Header;
-use crate::service::naming_scheme::data_segment_name;
-use crate::service::port_factory::publisher::{LocalPublisherConfig, UnableToDeliverStrategy};
-use crate::service::static_config::publish_subscribe;
+use crate::service::naming_scheme::{data_segment_name, publisher_event_concept_name};
+use crate::service::port_factory::publisher::{
+    AllocationStrategy, LocalPublisherConfig, MemoryHygiene, UnableToDeliverStrategy,
+};
+use crate::service::stale_resources::add_port_to_dynamic_config;
+use crate::service::static_config::publish_subscribe::{self, TypeDetails};
+use crate::tagged_payload::TaggedPayload;
+use crate::testing::{abort_port_creation_if_requested, PortCreationStep};
+use crate::zero_copy_send::ZeroCopySend;
 use crate::{config, sample_mut::SampleMut};
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::allocator::AllocationError;
 use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
 use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::ListenerBuilder;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
-use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError};
-use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
-use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
-use iceoryx2_cal::zero_copy_connection::{
-    ZeroCopyConnection, ZeroCopyCreationError, ZeroCopySendError, ZeroCopySender,
-};
+use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryCreateError, ShmPointer};
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{ZeroCopyPortDetails, ZeroCopySendError, ZeroCopySender};
+
+/// Announces a [`Publisher`] as waiting for a free sample or for drained buffers as long as it
+/// exists so that the subscribers notify it when they release a sample or empty their buffer.
+struct WaitingAnnouncement<'a> {
+    dynamic_config: &'a DynamicConfig,
+}
+
+impl<'a> WaitingAnnouncement<'a> {
+    fn new(dynamic_config: &'a DynamicConfig) -> Self {
+        dynamic_config.announce_waiting_publisher();
+        Self { dynamic_config }
+    }
+}
+
+impl Drop for WaitingAnnouncement<'_> {
+    fn drop(&mut self) {
+        self.dynamic_config.withdraw_waiting_publisher();
+    }
+}
+
+/// Describes how the connections of a [`Publisher`] to the
+/// [`crate::port::subscriber::Subscriber`]s changed in [`Publisher::update_connections()`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectionDiff {
+    established_connections: Vec<UniqueSubscriberId>,
+    removed_connections: Vec<UniqueSubscriberId>,
+}
+
+impl ConnectionDiff {
+    /// Returns the [`UniqueSubscriberId`]s of the subscribers to which a connection was
+    /// established.
+    pub fn established_connections(&self) -> &[UniqueSubscriberId] {
+        &self.established_connections
+    }
+
+    /// Returns the [`UniqueSubscriberId`]s of the subscribers whose connection was removed
+    /// since they left the service.
+    pub fn removed_connections(&self) -> &[UniqueSubscriberId] {
+        &self.removed_connections
+    }
+
+    /// Returns true when no connection was established or removed.
+    pub fn is_empty(&self) -> bool {
+        self.established_connections.is_empty() && self.removed_connections.is_empty()
+    }
+}
+
+/// A connection to a subscriber that could not be established. It is retried on every update
+/// of the connections.
+#[derive(Debug, Clone, Copy)]
+struct BrokenConnection {
+    failure: DeliveryFailure,
+    // failures that the degration callback ignores are not reported to the user
+    is_reported: bool,
+}
 
 /// Sending endpoint of a publish-subscriber based communication.
 #[derive(Debug)]
-pub struct Publisher<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> {
+pub struct Publisher<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    MessageType: Debug + ?Sized,
+    UserHeader: Debug = (),
+> {
     port_id: UniquePublisherId,
-    pub(crate) sample_reference_counter: Vec<AtomicU64>,
-    pub(crate) data_segment: Service::SharedMemory,
+    // only the current data segment serves new loans, the previous ones are kept until all of
+    // their samples are released
+    data_segments: Vec<UnsafeCell<Option<DataSegment<Service::SharedMemory>>>>,
+    current_data_segment_id: Cell<usize>,
     config: LocalPublisherConfig,
 
     subscriber_connections: SubscriberConnections<'config, Service>,
-    subscriber_list_state: UnsafeCell<ContainerState<'a, UniqueSubscriberId>>,
+    subscriber_list_state: UnsafeCell<ContainerState<'a, SubscriberDetails>>,
+    broken_connections: UnsafeCell<Vec<BrokenConnection>>,
     history: Option<UnsafeCell<Queue<usize>>>,
     service: &'a Service,
     degration_callback: Option<DegrationCallback<'a>>,
+    tracing_hooks: Option<Box<dyn TracingHooks<UserHeader> + 'a>>,
+    // woken up by the subscribers when they release a sample, only available when the service
+    // has notifications enabled
+    listener: Option<<Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener>,
     loan_counter: AtomicUsize,
-    _dynamic_config_guard: UniqueIndex<'a>,
+    // the loaned chunks, only tracked in debug builds to detect samples that are returned twice
+    used_chunks: UsedChunkList,
+    sequence_number: AtomicU64,
+    // the chunks and sequence numbers of the sent samples that were not yet consumed, ordered
+    // by their sequence number, only available with consumption tracking
+    unconsumed_samples: Option<UnsafeCell<VecDeque<(usize, u64)>>>,
+    dynamic_config_guard: Option<UniqueIndex<'a>>,
+    // the slot of the publisher in the dynamic config, it addresses its statistics
+    dynamic_config_index: u32,
+    // distance from the start of a sample to its payload, it depends on the payload alignment
+    payload_offset: usize,
+    // how many payload elements of the publisher form one element of the service payload, it is
+    // the size of the payload type for untyped publishers and 1 otherwise
+    element_size: usize,
     _phantom_message_type: PhantomData<MessageType>,
+    _phantom_user_header: PhantomData<UserHeader>,
+}
+
+fn number_of_connected_subscribers<'config, Service: service::Details<'config>>(
+    service: &Service,
+) -> usize {
+    service
+        .state()
+        .dynamic_storage
+        .get()
+        .publish_subscribe()
+        .number_of_live_subscribers()
+}
+
+/// Implements [`Publisher::wait_for_subscribers()`]. Only the service is read, the
+/// [`SyncPublisher`] therefore waits without holding its lock.
+pub(crate) fn wait_for_subscribers<'config, Service: service::Details<'config>>(
+    service: &Service,
+    min: usize,
+    timeout: Duration,
+) -> Result<usize, WaitForConnectionsError> {
+    let origin = "Publisher::wait_for_subscribers()";
+    let number_of_connections = number_of_connected_subscribers(service);
+    if number_of_connections >= min {
+        return Ok(number_of_connections);
+    }
+
+    let mut monitor = fail!(from origin,
+        when ConnectionMonitor::<Service>::new(
+            service.state().global_config,
+            service.state().static_config.uuid(),
+            || number_of_connected_subscribers(service),
+        ),
+        with WaitForConnectionsError::InternalFailure,
+        "Unable to wait for {} subscribers since the connection monitor could not be created.", min);
+
+    monitor.wait_for_connections(min, timeout, || {
+        service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .subscriber_system_ids()
+    })
+}
+
+/// Returns a chunk that was already counted as loaned to the [`Publisher`] when the loan is
+/// aborted by a panic before the [`SampleMut`] is handed out.
+struct AbortedLoanGuard<'publisher> {
+    publisher: &'publisher dyn PublishMgmt,
+    offset: PointerOffset,
+}
+
+impl Drop for AbortedLoanGuard<'_> {
+    fn drop(&mut self) {
+        self.publisher
+            .return_loaned_sample(self.offset, LoanRelease::Cancelled);
+    }
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
-    Publisher<'a, 'config, Service, MessageType>
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug + Default,
+    > Publisher<'a, 'config, Service, MessageType, UserHeader>
 {
     pub(crate) fn new(
         service: &'a Service,
         static_config: &publish_subscribe::StaticConfig,
         config: &LocalPublisherConfig,
+        element_size: usize,
     ) -> Result<Self, PublisherCreateError> {
         let msg = "Unable to create Publisher port";
-        let origin = "Publisher::new()";
         let port_id = UniquePublisherId::new();
+        let origin = format!(
+            "Publisher::new(service: {}, port id: {})",
+            service.state().static_config.service_name(),
+            port_id
+        );
+
+        if static_config.enable_deterministic_memory
+            && config.allocation_strategy != AllocationStrategy::Static
+        {
+            fail!(from origin, with PublisherCreateError::IncompatibleAllocationStrategy,
+                "{} since the allocation strategy {:?} would extend the data segment but the service has deterministic memory.",
+                msg, config.allocation_strategy);
+        }
+
+        if !config
+            .unable_to_deliver_strategy
+            .is_compatible_with(static_config.unable_to_deliver_strategy)
+        {
+            fail!(from origin, with PublisherCreateError::IncompatibleUnableToDeliverStrategy,
+                "{} since the unable to deliver strategy {:?} is stronger than the strategy {:?} of the service.",
+                msg, config.unable_to_deliver_strategy, static_config.unable_to_deliver_strategy);
+        }
+
         let subscriber_list = &service
             .state()
             .dynamic_storage
@@ -135,19 +325,108 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             .messaging_pattern
             .required_amount_of_samples_per_data_segment(config.max_loaned_samples);
 
-        let data_segment = fail!(from origin, when Self::create_data_segment(port_id, service.state().global_config, number_of_samples),
-                with PublisherCreateError::UnableToCreateDataSegment,
-                "{} since the data segment could not be acquired.", msg);
+        let mut data_segments: Vec<_> = (0..MAX_NUMBER_OF_SEGMENTS)
+            .map(|_| UnsafeCell::new(None))
+            .collect();
+        match config.bucket_config {
+            None => {
+                let data_segment = fail!(from origin, when Self::create_data_segment(port_id, 0, service.state().global_config, static_config, config, static_config.max_slice_len * element_size, number_of_samples),
+                    map SharedMemoryCreateError::UnableToLockMemory => PublisherCreateError::UnableToLockMemory;
+                        SharedMemoryCreateError::HugePagesUnavailable => PublisherCreateError::HugePagesUnavailable,
+                    unmatched PublisherCreateError::UnableToCreateDataSegment,
+                    "{} since the data segment could not be acquired.", msg);
+                *data_segments[0].get_mut() = Some(data_segment);
+            }
+            Some(bucket_config) => {
+                if config.allocation_strategy != AllocationStrategy::Static {
+                    fail!(from origin, with PublisherCreateError::IncompatibleAllocationStrategy,
+                        "{} since the allocation strategy {:?} would extend the data segment but the buckets of a BucketConfig cannot grow.",
+                        msg, config.allocation_strategy);
+                }
+
+                if !bucket_config.is_valid() {
+                    fail!(from origin, with PublisherCreateError::InvalidBucketConfig,
+                        "{} since the bucket config {:?} is invalid.", msg, bucket_config);
+                }
+
+                // the history keeps its samples until newer ones replace them, a bucket that
+                // it can fill completely would never provide a sample again to replace them
+                let history_size = static_config.history_size;
+                if let Some(bucket) = bucket_config
+                    .buckets()
+                    .iter()
+                    .find(|bucket| bucket.number_of_samples() <= history_size)
+                {
+                    fail!(from origin, with PublisherCreateError::InvalidBucketConfig,
+                        "{} since the bucket with a payload size of {} bytes provides {} samples but the history of the service can hold {} of them.",
+                        msg, bucket.payload_size(), bucket.number_of_samples(), history_size);
+                }
+
+                // every bucket is a data segment of its own, the segment id is the index of
+                // the bucket
+                let element_payload_size = MessageType::payload_size(1).unwrap_or(1).max(1);
+                for (segment_id, bucket) in bucket_config.buckets().iter().enumerate() {
+                    let max_slice_len = bucket.payload_size() / element_payload_size;
+                    if max_slice_len == 0 {
+                        fail!(from origin, with PublisherCreateError::InvalidBucketConfig,
+                            "{} since the bucket with a payload size of {} bytes cannot hold a single element of {} bytes.",
+                            msg, bucket.payload_size(), element_payload_size);
+                    }
+
+                    let data_segment = fail!(from origin, when Self::create_data_segment(port_id, segment_id, service.state().global_config, static_config, config, max_slice_len, bucket.number_of_samples()),
+                        map SharedMemoryCreateError::UnableToLockMemory => PublisherCreateError::UnableToLockMemory;
+                            SharedMemoryCreateError::HugePagesUnavailable => PublisherCreateError::HugePagesUnavailable,
+                        unmatched PublisherCreateError::UnableToCreateDataSegment,
+                        "{} since the data segment of the bucket with a payload size of {} bytes could not be acquired.", msg, bucket.payload_size());
+                    *data_segments[segment_id].get_mut() = Some(data_segment);
+                }
+            }
+        }
+
+        let listener = match static_config.enable_notifications {
+            false => None,
+            true => {
+                let event_name = publisher_event_concept_name(&port_id);
+                Some(fail!(from origin,
+                    when <Service::Event as iceoryx2_cal::event::Event<EventId>>::ListenerBuilder::new(&event_name)
+                        .config(&event_config::<Service>(service.state().global_config))
+                        .create(),
+                    with PublisherCreateError::UnableToCreateNotificationListener,
+                    "{} since the underlying event concept \"{}\" could not be created.", msg, event_name))
+            }
+        };
+
+        let subscriber_max_buffer_size = config.effective_subscriber_max_buffer_size(static_config);
+        if config.subscriber_max_buffer_size != subscriber_max_buffer_size {
+            warn!(from origin,
+                "The requested subscriber max buffer size of {} is not supported by the service. Adjust it to {}.",
+                config.subscriber_max_buffer_size, subscriber_max_buffer_size);
+        }
+        let subscriber_connections = SubscriberConnections::new(
+            subscriber_list.capacity(),
+            service.state().global_config,
+            port_id,
+            subscriber_max_buffer_size,
+            config.lock_memory,
+            static_config,
+        );
+
+        abort_port_creation_if_requested(PortCreationStep::ResourcesCreated);
 
         // !MUST! be the last task otherwise a publisher is added to the dynamic config without the
         // creation of all required resources
-        let _dynamic_config_guard = match service
-            .state()
-            .dynamic_storage
-            .get()
-            .publish_subscribe()
-            .add_publisher_id(port_id)
-        {
+        let dynamic_config_guard = match add_port_to_dynamic_config::<Service, _>(
+            service.state().dynamic_storage.get(),
+            service.state().global_config,
+            || {
+                service
+                    .state()
+                    .dynamic_storage
+                    .get()
+                    .publish_subscribe()
+                    .add_publisher(subscriber_connections.details())
+            },
+        ) {
             Some(unique_index) => unique_index,
             None => {
                 fail!(from origin, with PublisherCreateError::ExceedsMaxSupportedPublishers,
@@ -155,107 +434,213 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                             msg, service.state().static_config.publish_subscribe().max_publishers);
             }
         };
+        abort_port_creation_if_requested(PortCreationStep::AddedToDynamicConfig);
 
         let new_self = Self {
             port_id,
-            subscriber_connections: SubscriberConnections::new(
-                subscriber_list.capacity(),
-                service.state().global_config,
-                port_id,
-                static_config,
-            ),
-            data_segment,
+            subscriber_connections,
+            data_segments,
+            current_data_segment_id: Cell::new(0),
             config: *config,
-            sample_reference_counter: {
-                let mut v = Vec::with_capacity(number_of_samples);
-                for _ in 0..number_of_samples {
-                    v.push(AtomicU64::new(0));
-                }
-                v
-            },
             subscriber_list_state: unsafe { UnsafeCell::new(subscriber_list.get_state()) },
+            broken_connections: UnsafeCell::new(Vec::with_capacity(subscriber_list.capacity())),
             history: match static_config.history_size == 0 {
                 true => None,
                 false => Some(UnsafeCell::new(Queue::new(static_config.history_size))),
             },
             service,
             degration_callback: None,
+            tracing_hooks: None,
+            listener,
             loan_counter: AtomicUsize::new(0),
-            _dynamic_config_guard,
+            used_chunks: UsedChunkList::new(config.max_loaned_samples),
+            sequence_number: AtomicU64::new(0),
+            unconsumed_samples: match config.enable_consumption_tracking {
+                true => Some(UnsafeCell::new(VecDeque::with_capacity(number_of_samples))),
+                false => None,
+            },
+            dynamic_config_index: dynamic_config_guard.value(),
+            dynamic_config_guard: Some(dynamic_config_guard),
+            payload_offset: Message::<Header, UserHeader, ()>::payload_offset(
+                static_config.payload_alignment,
+            ),
+            element_size,
             _phantom_message_type: PhantomData,
+            _phantom_user_header: PhantomData,
         };
 
+        if let Some(bucket_config) = config.bucket_config {
+            let counters = new_self.counters();
+            for (segment_id, bucket) in bucket_config.buckets().iter().enumerate() {
+                let bucket_counters = &counters.buckets[segment_id];
+                bucket_counters
+                    .payload_size
+                    .store(bucket.payload_size(), Ordering::Relaxed);
+                bucket_counters.chunk_size.store(
+                    new_self
+                        .mapped_data_segment(SegmentOffset::new(segment_id, 0))
+                        .sample_layout
+                        .size(),
+                    Ordering::Relaxed,
+                );
+                bucket_counters
+                    .number_of_samples
+                    .store(bucket.number_of_samples(), Ordering::Relaxed);
+            }
+            counters
+                .number_of_buckets
+                .store(bucket_config.buckets().len(), Ordering::Relaxed);
+        }
+
         if let Err(e) = new_self.populate_subscriber_channels() {
             warn!(from new_self, "The new Publisher port is unable to connect to every Subscriber port, caused by {:?}.", e);
         }
 
+        announce_connection_change::<Service>(
+            service.state().global_config,
+            service.state().static_config.uuid(),
+        );
+
         Ok(new_self)
     }
 
-    fn populate_subscriber_channels(&self) -> Result<(), ZeroCopyCreationError> {
+    fn populate_subscriber_channels(&self) -> Result<ConnectionDiff, SubscriberConnectionError> {
         let mut visited_indices = vec![];
         visited_indices.resize(self.subscriber_connections.capacity(), None);
 
         unsafe {
-            (*self.subscriber_list_state.get()).for_each(|index, subscriber_id| {
-                visited_indices[index as usize] = Some(*subscriber_id);
+            (*self.subscriber_list_state.get()).for_each(|index, details| {
+                visited_indices[index as usize] = Some(*details);
             })
         };
 
         // retrieve samples before destroying channel
         self.retrieve_returned_samples();
 
+        // the remaining connections are established even when one of them fails, broken
+        // connections are retried with the next update
+        let broken_connections = unsafe { &mut *self.broken_connections.get() };
+        broken_connections.clear();
+        let mut failure = None;
+        let mut diff = ConnectionDiff::default();
+
         for (i, index) in visited_indices.iter().enumerate() {
             match index {
-                Some(subscriber_id) => {
-                    match self.subscriber_connections.create(i, *subscriber_id) {
+                Some(details) => {
+                    let subscriber_id = &details.subscriber_id;
+                    // the subscriber took over the slot of a subscriber that left the service
+                    if let Some(connection) = self.subscriber_connections.get(i) {
+                        if connection.subscriber_id != *subscriber_id {
+                            diff.removed_connections.push(connection.subscriber_id);
+                            self.subscriber_connections.remove(i);
+                        }
+                    }
+
+                    match self.subscriber_connections.create(i, details) {
                         Ok(false) => (),
                         Ok(true) => match &self.subscriber_connections.get(i) {
-                            Some(connection) => self.deliver_history(connection),
+                            Some(connection) => {
+                                diff.established_connections.push(*subscriber_id);
+                                self.deliver_history(i, connection)
+                            }
                             None => {
                                 fatal_panic!(from self, "This should never happen! Unable to acquire previously created subscriber connection.")
                             }
                         },
-                        Err(e) => match &self.degration_callback {
-                            Some(c) => match c.call(
-                                self.service.state().static_config.clone(),
-                                self.port_id,
-                                *subscriber_id,
-                            ) {
-                                DegrationAction::Ignore => (),
-                                DegrationAction::Warn => {
-                                    warn!(from self, "Unable to establish connection to new subscriber {:?}.", subscriber_id )
-                                }
-                                DegrationAction::Fail => {
-                                    fail!(from self, with e,
-                                           "Unable to establish connection to new subscriber {:?}.", subscriber_id );
+                        Err(e) => {
+                            let is_reported = match &self.degration_callback {
+                                Some(c) => match c.call(
+                                    self.service.state().static_config.clone(),
+                                    self.port_id,
+                                    *subscriber_id,
+                                ) {
+                                    DegrationAction::Ignore => false,
+                                    DegrationAction::Warn => {
+                                        warn!(from self, "Unable to establish connection to new subscriber {:?}.", subscriber_id );
+                                        true
+                                    }
+                                    DegrationAction::Fail => {
+                                        if failure.is_none() {
+                                            failure = Some((*subscriber_id, e));
+                                        }
+                                        true
+                                    }
+                                },
+                                None => {
+                                    warn!(from self, "Unable to establish connection to new subscriber {:?}.", subscriber_id );
+                                    true
                                 }
-                            },
-                            None => {
-                                warn!(from self, "Unable to establish connection to new subscriber {:?}.", subscriber_id )
-                            }
-                        },
+                            };
+
+                            broken_connections.push(BrokenConnection {
+                                failure: DeliveryFailure::new(
+                                    *subscriber_id,
+                                    self.channel_creation(*subscriber_id),
+                                    DeliveryFailureReason::UnableToEstablishConnection(e),
+                                ),
+                                is_reported,
+                            });
+                        }
+                    }
+                }
+                None => {
+                    if let Some(connection) = self.subscriber_connections.get(i) {
+                        diff.removed_connections.push(connection.subscriber_id);
                     }
+                    self.subscriber_connections.remove(i)
                 }
-                None => self.subscriber_connections.remove(i),
             }
         }
 
-        Ok(())
+        self.counters().active_connections.store(
+            self.subscriber_connections.number_of_connections(),
+            Ordering::Relaxed,
+        );
+
+        if let Some((subscriber_id, e)) = failure {
+            let service_name = *self.service.state().static_config.service_name();
+            let operation = self.channel_creation(subscriber_id);
+            fail!(from self, with SubscriberConnectionError::new(service_name, subscriber_id, operation, e),
+                "Unable to establish connection to new subscriber {:?}.", subscriber_id);
+        }
+
+        Ok(diff)
     }
 
-    fn deliver_history(&self, connection: &Connection<'config, Service>) {
+    /// Returns the [`ConnectionOperation`] that creates the channel to the subscriber.
+    fn channel_creation(&self, subscriber_id: UniqueSubscriberId) -> ConnectionOperation {
+        match is_intra_process(
+            self.service.state().static_config.publish_subscribe(),
+            self.port_id,
+            subscriber_id,
+        ) {
+            true => ConnectionOperation::CreateIntraProcessChannel,
+            false => ConnectionOperation::CreateChannel,
+        }
+    }
+
+    fn deliver_history(&self, index: usize, connection: &Connection<'config, Service>) {
         match &self.history {
             None => (),
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
-                for i in 0..history.len() {
+                // when the subscriber buffer is smaller than the history only the newest samples
+                // are delivered, the older ones would be overridden anyway
+                let start = history
+                    .len()
+                    .saturating_sub(connection.sender.buffer_size());
+                for i in start..history.len() {
                     let ptr_distance = unsafe { history.get_unchecked(i) };
 
                     match connection.sender.try_send(PointerOffset::new(ptr_distance)) {
-                        Ok(_) => {
-                            self.sample_reference_counter[Self::sample_index(ptr_distance)]
+                        Ok(overflow) => {
+                            self.reference_counter(PointerOffset::new(ptr_distance))
                                 .fetch_add(1, Ordering::Relaxed);
+
+                            match overflow {
+                                Some(old) => self.release_sample(old),
+                                None => self.count_delivery(index),
+                            }
                         }
                         Err(e) => {
                             warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
@@ -266,29 +651,123 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         }
     }
 
-    fn sample_index(distance_to_chunk: usize) -> usize {
-        distance_to_chunk / std::mem::size_of::<Message<Header, MessageType>>()
+    fn data_segment(&self, segment_id: usize) -> &Option<DataSegment<Service::SharedMemory>> {
+        unsafe { &*self.data_segments[segment_id].get() }
+    }
+
+    fn current_data_segment(&self) -> &DataSegment<Service::SharedMemory> {
+        match self.data_segment(self.current_data_segment_id.get()) {
+            Some(segment) => segment,
+            None => {
+                fatal_panic!(from self, "This should never happen! The current data segment is not mapped.")
+            }
+        }
+    }
+
+    fn mapped_data_segment(&self, offset: SegmentOffset) -> &DataSegment<Service::SharedMemory> {
+        match self.data_segment(offset.segment_id()) {
+            Some(segment) => segment,
+            None => {
+                fatal_panic!(from self, "This should never happen! The sample refers to the data segment {} which is no longer mapped.",
+                    offset.segment_id())
+            }
+        }
+    }
+
+    fn reference_counter(&self, distance_to_chunk: PointerOffset) -> &AtomicU64 {
+        let offset = SegmentOffset::from(distance_to_chunk);
+        self.mapped_data_segment(offset)
+            .reference_counter(offset.offset())
+    }
+
+    fn chunk_address(&self, distance_to_chunk: PointerOffset) -> usize {
+        let offset = SegmentOffset::from(distance_to_chunk);
+        self.mapped_data_segment(offset)
+            .memory
+            .allocator_data_start_address()
+            + offset.offset()
+    }
+
+    fn remove_data_segment(&self, segment_id: usize) {
+        unsafe { *self.data_segments[segment_id].get() = None };
+    }
+
+    /// The layout of a [`Message`] with the [`Header`] and the user header followed by a payload
+    /// that has at most `max_slice_len` elements and is aligned to the payload alignment of the
+    /// service. Without a custom payload alignment it is equal to the layout of the `#[repr(C)]`
+    /// message.
+    fn sample_layout(
+        static_config: &publish_subscribe::StaticConfig,
+        max_slice_len: usize,
+    ) -> Layout {
+        Message::<Header, UserHeader, ()>::layout_with_payload(
+            MessageType::payload_layout(max_slice_len),
+            static_config.payload_alignment,
+        )
     }
 
     fn create_data_segment(
         port_id: UniquePublisherId,
+        segment_id: usize,
         global_config: &'config config::Config,
+        static_config: &publish_subscribe::StaticConfig,
+        config: &LocalPublisherConfig,
+        max_slice_len: usize,
+        number_of_samples: usize,
+    ) -> Result<DataSegment<Service::SharedMemory>, SharedMemoryCreateError> {
+        let sample_layout =
+            chunk_guard::chunk_layout(Self::sample_layout(static_config, max_slice_len));
+        DataSegment::create_with_options(
+            &data_segment_name(port_id, segment_id),
+            &data_segment_config::<Service>(global_config),
+            max_slice_len,
+            sample_layout,
+            number_of_samples,
+            &config.memory_options(sample_layout.size() * number_of_samples),
+        )
+    }
+
+    /// Creates a new data segment whose samples hold up to `max_slice_len` elements, adjusted by
+    /// the [`AllocationStrategy`], and uses it for all further loans.
+    fn grow_data_segment(
+        &self,
+        max_slice_len: usize,
         number_of_samples: usize,
-    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
-        let allocator_config = shm_allocator::pool_allocator::Config {
-            bucket_layout: Layout::new::<Message<Header, MessageType>>(),
+    ) -> Result<(), PublisherLoanError> {
+        let msg = "Unable to grow the data segment";
+        let max_slice_len = match self.config.allocation_strategy {
+            AllocationStrategy::PowerOfTwo => max_slice_len
+                .checked_next_power_of_two()
+                .unwrap_or(max_slice_len),
+            AllocationStrategy::Static | AllocationStrategy::BestFit => max_slice_len,
         };
-        let chunk_size = allocator_config.bucket_layout.size();
-        let chunk_align = allocator_config.bucket_layout.align();
 
-        Ok(fail!(from "Publisher::create_data_segment()",
-            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
-            Service::SharedMemory,
-                >>::new(&data_segment_name(port_id))
-                .config(&data_segment_config::<Service>(global_config))
-                .size(chunk_size * number_of_samples + chunk_align - 1)
-                .create(&allocator_config),
-            "Unable to create the data segment."))
+        let previous_segment_id = self.current_data_segment_id.get();
+        let segment_id = previous_segment_id + 1;
+        if segment_id == MAX_NUMBER_OF_SEGMENTS {
+            fail!(from self, with PublisherLoanError::OutOfMemory,
+                "{} since the publisher already created the maximum of {} data segments.",
+                msg, MAX_NUMBER_OF_SEGMENTS);
+        }
+
+        let data_segment = fail!(from self,
+            when Self::create_data_segment(self.port_id, segment_id, self.service.state().global_config, self.service.state().static_config.publish_subscribe(), &self.config, max_slice_len, number_of_samples),
+            with PublisherLoanError::OutOfMemory,
+            "{} since a data segment with {} samples of up to {} elements could not be created.",
+            msg, number_of_samples, max_slice_len);
+
+        unsafe { *self.data_segments[segment_id].get() = Some(data_segment) };
+        self.current_data_segment_id.set(segment_id);
+
+        // the previous segment serves no new loans, when none of its samples is in use it will
+        // never be accessed again
+        if let Some(previous) = self.data_segment(previous_segment_id) {
+            if previous.number_of_allocated_samples.load(Ordering::Relaxed) == 0 {
+                self.remove_data_segment(previous_segment_id);
+            }
+        }
+
+        Ok(())
     }
 
     fn add_to_history(&self, address_to_chunk: usize) {
@@ -296,7 +775,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             None => (),
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
-                self.sample_reference_counter[Self::sample_index(address_to_chunk)]
+                self.reference_counter(PointerOffset::new(address_to_chunk))
                     .fetch_add(1, Ordering::Relaxed);
                 match unsafe { history.push_with_overflow(address_to_chunk) } {
                     None => (),
@@ -306,10 +785,18 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         }
     }
 
-    fn deliver_sample(&self, address_to_chunk: usize) -> usize {
+    fn deliver_sample(
+        &self,
+        address_to_chunk: usize,
+        failures: &mut Vec<DeliveryFailure>,
+    ) -> usize {
         let deliver_call = match self.config.unable_to_deliver_strategy {
-            UnableToDeliverStrategy::Block => <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender::blocking_send,
-            UnableToDeliverStrategy::DiscardSample => <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender::try_send,
+            UnableToDeliverStrategy::Block => {
+                ChannelSender::<<Service as service::Details<'config>>::Connection>::blocking_send
+            }
+            UnableToDeliverStrategy::DiscardSample => {
+                ChannelSender::<<Service as service::Details<'config>>::Connection>::try_send
+            }
         };
 
         let mut number_of_recipients = 0;
@@ -325,14 +812,22 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                         }
                         Err(ZeroCopySendError::ClearRetrieveChannelBeforeSend) => {
                             warn!(from self, "Unable to send sample via connection {:?} since the retrieve buffer is full. This can be caused by a corrupted retrieve channel.", connection);
+                            failures.push(DeliveryFailure::new(
+                                connection.subscriber_id,
+                                ConnectionOperation::PushSample,
+                                DeliveryFailureReason::RetrieveChannelFull,
+                            ));
                         }
                         Ok(overflow) => {
-                            self.sample_reference_counter[Self::sample_index(address_to_chunk)]
+                            self.reference_counter(PointerOffset::new(address_to_chunk))
                                 .fetch_add(1, Ordering::Relaxed);
                             number_of_recipients += 1;
 
-                            if let Some(old) = overflow {
-                                self.release_sample(old)
+                            // an overflow replaces a pending sample of the subscriber, the
+                            // number of pending samples stays the same
+                            match overflow {
+                                Some(old) => self.release_sample(old),
+                                None => self.count_delivery(i),
                             }
                         }
                     }
@@ -343,18 +838,137 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         number_of_recipients
     }
 
+    fn send_sample(
+        &self,
+        address_to_chunk: usize,
+        keep_time_stamp: bool,
+    ) -> Result<(usize, SampleToken), PublisherSendError> {
+        self.used_chunks
+            .verify_contains(PointerOffset::new(address_to_chunk), self);
+        if self.config.update_connections_on_send {
+            fail!(from self, when Publisher::update_connections(self),
+                "Unable to send sample since the connections could not be updated.");
+        }
+
+        // the sequence number is assigned when the sample is sent so that dropped samples
+        // do not cause a gap, the time stamp so that reused samples are not stale unless the
+        // user has set it explicitly
+        let header = self.chunk_address(PointerOffset::new(address_to_chunk)) as *mut Header;
+        let sequence_number = self.sequence_number.fetch_add(1, Ordering::Relaxed);
+        unsafe {
+            (*header).set_sequence_number(sequence_number);
+            if !keep_time_stamp {
+                (*header).update_time_stamp();
+                let time_stamp = self
+                    .service
+                    .state()
+                    .dynamic_storage
+                    .get()
+                    .publish_subscribe()
+                    .next_time_stamp((*header).time_stamp());
+                (*header).set_time_stamp(time_stamp);
+            }
+            if let Some(hooks) = &self.tracing_hooks {
+                let message = header as *const Message<Header, UserHeader, ()>;
+                hooks.on_send(&(*message).header, &(*message).user_header);
+            }
+        };
+
+        // tracked before the sample is delivered since a subscriber can release it immediately
+        if let Some(unconsumed_samples) = &self.unconsumed_samples {
+            unsafe { &mut *unconsumed_samples.get() }
+                .push_back((address_to_chunk, sequence_number));
+        }
+
+        self.add_to_history(address_to_chunk);
+
+        let mut failures: Vec<DeliveryFailure> = unsafe { &*self.broken_connections.get() }
+            .iter()
+            .filter(|connection| connection.is_reported)
+            .map(|connection| connection.failure)
+            .collect();
+        let number_of_recipients = self.deliver_sample(address_to_chunk, &mut failures);
+        self.notify_waiting_subscribers();
+        self.counters().sent_samples.fetch_add(1, Ordering::Relaxed);
+
+        if !failures.is_empty() {
+            fail!(from self, with PublisherSendError::PartialDelivery {
+                    number_of_recipients,
+                    failures,
+                },
+                "The sample was delivered to {} subscribers but could not be delivered to {} subscribers.",
+                number_of_recipients, failures.len());
+        }
+
+        Ok((number_of_recipients, SampleToken::new(sequence_number)))
+    }
+
     fn release_sample(&self, distance_to_chunk: PointerOffset) {
-        if self.sample_reference_counter[Self::sample_index(distance_to_chunk.value())]
-            .fetch_sub(1, Ordering::Relaxed)
-            == 1
-        {
+        let previous_references = self
+            .reference_counter(distance_to_chunk)
+            .fetch_sub(1, Ordering::Relaxed);
+
+        #[cfg(debug_assertions)]
+        if previous_references == 0 {
+            fatal_panic!(from self,
+                "The sample {:?} is released more often than it was referenced, it was already returned to the allocator.",
+                distance_to_chunk);
+        }
+
+        if previous_references == 1 {
+            self.mark_as_consumed(distance_to_chunk);
+            let offset = SegmentOffset::from(distance_to_chunk);
+            let data_segment = self.mapped_data_segment(offset);
+            let sample_offset = chunk_guard::sample_offset(data_segment.sample_layout.align());
             unsafe {
-                fatal_panic!(from self, when self.data_segment
-                .deallocate(
+                let sample = self.chunk_address(distance_to_chunk) as *mut u8;
+                let payload_size = (*(sample as *const Header)).payload_size_bytes() as usize;
+                chunk_guard::verify_and_poison(
+                    sample,
+                    self.payload_offset + payload_size,
+                    data_segment.sample_layout.size() - sample_offset,
                     distance_to_chunk,
-                    Layout::new::<MessageType>(),
+                    self,
+                );
+
+                if self.config.memory_hygiene == MemoryHygiene::ZeroOnRelease {
+                    core::ptr::write_bytes(sample.add(self.payload_offset), 0, payload_size);
+                }
+
+                fatal_panic!(from self, when data_segment.memory
+                .deallocate(
+                    PointerOffset::new(offset.offset() - sample_offset),
+                    data_segment.sample_layout,
                 ), "Internal logic error. The sample should always contain a valid memory chunk from the provided allocator.");
             };
+
+            if let Some(bucket_counters) = self.bucket_counters(offset.segment_id()) {
+                bucket_counters.used_samples.fetch_sub(1, Ordering::Relaxed);
+            }
+
+            // the buckets of a bucket config serve loans for the whole lifetime of the publisher
+            if data_segment
+                .number_of_allocated_samples
+                .fetch_sub(1, Ordering::Relaxed)
+                == 1
+                && offset.segment_id() != self.current_data_segment_id.get()
+                && self.config.bucket_config.is_none()
+            {
+                self.remove_data_segment(offset.segment_id());
+            }
+        }
+    }
+
+    fn mark_as_consumed(&self, distance_to_chunk: PointerOffset) {
+        if let Some(unconsumed_samples) = &self.unconsumed_samples {
+            let unconsumed_samples = unsafe { &mut *unconsumed_samples.get() };
+            // loans that were cancelled were never sent and are not contained
+            if let Some(index) = unconsumed_samples
+                .iter()
+                .position(|(chunk, _)| *chunk == distance_to_chunk.value())
+            {
+                unconsumed_samples.remove(index);
+            }
         }
     }
 
@@ -363,22 +977,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             match self.subscriber_connections.get(i) {
                 Some(ref connection) => loop {
                     match connection.sender.reclaim() {
-                        Ok(Some(ptr_dist)) => {
-                            let sample_index = Self::sample_index(ptr_dist.value());
-
-                            if self.sample_reference_counter[sample_index]
-                                .fetch_sub(1, Ordering::Relaxed)
-                                == 1
-                            {
-                                unsafe {
-                                    fatal_panic!(from self, when self.data_segment
-                                    .deallocate(
-                                        ptr_dist,
-                                        Layout::new::<Message<Header, MessageType>>(),
-                                    ), "This should never happen! Failed to deallocate the reclaimed ptr. Either the data was corrupted or an invalid ptr was returned.")
-                                };
-                            }
-                        }
+                        Ok(Some(ptr_dist)) => self.release_sample(ptr_dist),
                         Ok(None) => break,
                         Err(e) => {
                             warn!(from self, "Unable to reclaim samples from connection {:?} due to {:?}. This may lead to a situation where no more samples will be delivered to this connection.", connection, e)
@@ -390,6 +989,721 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         }
     }
 
+    /// Returns the id of the data segment of the smallest bucket whose samples hold
+    /// `number_of_elements`, the segments of the buckets are ordered by their size.
+    fn bucket_for(&self, number_of_elements: usize) -> Option<usize> {
+        (0..MAX_NUMBER_OF_SEGMENTS).find(|segment_id| {
+            self.data_segment(*segment_id)
+                .as_ref()
+                .is_some_and(|segment| segment.max_slice_len >= number_of_elements)
+        })
+    }
+
+    fn allocate_from_current_data_segment(&self) -> Result<ShmPointer, ShmAllocationError> {
+        self.allocate_from_data_segment(self.current_data_segment_id.get())
+    }
+
+    fn allocate_from_data_segment(
+        &self,
+        segment_id: usize,
+    ) -> Result<ShmPointer, ShmAllocationError> {
+        let data_segment = self.mapped_data_segment(SegmentOffset::new(segment_id, 0));
+        let chunk = data_segment.memory.allocate(data_segment.sample_layout)?;
+
+        data_segment
+            .number_of_allocated_samples
+            .fetch_add(1, Ordering::Relaxed);
+        if let Some(bucket_counters) = self.bucket_counters(segment_id) {
+            bucket_counters.used_samples.fetch_add(1, Ordering::Relaxed);
+        }
+
+        // in debug builds the sample is preceded by a canary, every offset that leaves the
+        // publisher points to the sample
+        let sample_offset = chunk_guard::sample_offset(data_segment.sample_layout.align());
+        Ok(ShmPointer {
+            offset: SegmentOffset::new(segment_id, chunk.offset.value() + sample_offset).into(),
+            data_ptr: unsafe { chunk.data_ptr.add(sample_offset) },
+        })
+    }
+
+    fn allocate_sample(&self, number_of_elements: usize) -> Result<ShmPointer, PublisherLoanError> {
+        self.retrieve_returned_samples();
+        let msg = "Unable to loan Sample";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_samples {
+            fail!(from self, with PublisherLoanError::ExceedsMaxLoanedSamples,
+                "{} since already {} samples were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned sample to loan another sample.",
+                msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_samples);
+        }
+
+        let allocation = match self.config.bucket_config {
+            Some(bucket_config) => {
+                // the smallest bucket that fits serves the loan, the larger ones are no fallback
+                let segment_id = match self.bucket_for(number_of_elements) {
+                    Some(segment_id) => segment_id,
+                    None => {
+                        fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                            "{} since a slice with {} elements exceeds the largest bucket.", msg, number_of_elements);
+                    }
+                };
+
+                match self.allocate_from_data_segment(segment_id) {
+                    Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                        let bucket_size = bucket_config.buckets()[segment_id].payload_size();
+                        fail!(from self, with PublisherLoanError::BucketExhausted { bucket_size },
+                            "{} since the bucket with a payload size of {} bytes has no free sample.", msg, bucket_size);
+                    }
+                    allocation => allocation,
+                }
+            }
+            None => {
+                if number_of_elements > self.current_data_segment().max_slice_len {
+                    // the slice length is verified for static allocations when the slice is loaned
+                    fail!(from self, when self.grow_data_segment(number_of_elements, self.current_data_segment().number_of_samples),
+                        "{} since no data segment for a slice with {} elements could be created.", msg, number_of_elements);
+                }
+
+                match self.allocate_from_current_data_segment() {
+                    Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory))
+                        if self.config.allocation_strategy != AllocationStrategy::Static =>
+                    {
+                        let data_segment = self.current_data_segment();
+                        let number_of_samples = match self.config.allocation_strategy {
+                            AllocationStrategy::PowerOfTwo => {
+                                data_segment.number_of_samples.saturating_mul(2)
+                            }
+                            AllocationStrategy::Static | AllocationStrategy::BestFit => {
+                                data_segment.number_of_samples
+                            }
+                        };
+                        fail!(from self, when self.grow_data_segment(data_segment.max_slice_len, number_of_samples),
+                            "{} since the data segment is out of memory and could not be extended.", msg);
+                        self.allocate_from_current_data_segment()
+                    }
+                    allocation => allocation,
+                }
+            }
+        };
+
+        match allocation {
+            Ok(chunk) => {
+                if self
+                    .reference_counter(chunk.offset)
+                    .fetch_add(1, Ordering::Relaxed)
+                    != 0
+                {
+                    fatal_panic!(from self,
+                                "{} since the allocated sample is already in use! This should never happen!", msg);
+                }
+
+                // only the headers are written, constructing the whole message would place the
+                // uninitialized payload on the stack which overflows it for large types. The
+                // offsets of the headers do not depend on the payload type.
+                let message = chunk.data_ptr as *mut Message<Header, UserHeader, ()>;
+                let static_config = self.service.state().static_config.publish_subscribe();
+                let number_of_service_elements = number_of_elements / self.element_size;
+                unsafe {
+                    core::ptr::addr_of_mut!((*message).header).write(Header::new(
+                        self.port_id,
+                        self.service.state().node_id,
+                        number_of_service_elements,
+                        number_of_service_elements * static_config.payload_type_details.size,
+                        static_config.clock_type,
+                    ));
+                    if self.config.memory_hygiene == MemoryHygiene::ZeroOnAcquire {
+                        core::ptr::write_bytes(
+                            chunk.data_ptr.add(self.payload_offset),
+                            0,
+                            number_of_service_elements * static_config.payload_type_details.size,
+                        );
+                    }
+                    chunk_guard::arm(
+                        chunk.data_ptr,
+                        self.payload_offset
+                            + number_of_service_elements * static_config.payload_type_details.size,
+                    );
+                };
+                self.used_chunks.insert(chunk.offset, self);
+
+                self.loan_counter.fetch_add(1, Ordering::Relaxed);
+                self.counters()
+                    .loaned_samples
+                    .fetch_add(1, Ordering::Relaxed);
+
+                // the user header is constructed and the hooks are called when the chunk is
+                // already counted as loaned, a panic in user code cancels the loan
+                let loan = AbortedLoanGuard {
+                    publisher: self,
+                    offset: chunk.offset,
+                };
+                unsafe {
+                    core::ptr::addr_of_mut!((*message).user_header).write(UserHeader::default());
+                    if let Some(hooks) = &self.tracing_hooks {
+                        hooks.on_loan(&(*message).header, &mut (*message).user_header);
+                    }
+                }
+                core::mem::forget(loan);
+
+                Ok(chunk)
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with PublisherLoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with PublisherLoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+
+    pub(crate) fn service(&self) -> &'a Service {
+        self.service
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.port_id
+    }
+
+    /// Returns the largest buffer of a [`crate::port::subscriber::Subscriber`] connection the
+    /// [`Publisher`] serves, see
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::subscriber_max_buffer_size()`].
+    pub fn subscriber_max_buffer_size(&self) -> usize {
+        self.subscriber_connections.details().max_buffer_size
+    }
+
+    /// Returns the buffer size of the connection to the [`crate::port::subscriber::Subscriber`]
+    /// with the provided id, the smaller of the buffer size the subscriber requested and
+    /// [`Publisher::subscriber_max_buffer_size()`]. Returns [`None`] when no connection to the
+    /// subscriber is established, for instance since it requires a larger buffer. A sent sample
+    /// reports a refused connection with [`DeliveryFailureReason::UnableToEstablishConnection`]
+    /// and
+    /// [`iceoryx2_cal::zero_copy_connection::ZeroCopyCreationError::IncompatibleBufferSize`],
+    /// see [`PublisherSendError::PartialDelivery`].
+    pub fn effective_buffer_size(&self, subscriber_id: UniqueSubscriberId) -> Option<usize> {
+        (0..self.subscriber_connections.len()).find_map(|i| {
+            match self.subscriber_connections.get(i) {
+                Some(connection) if connection.subscriber_id == subscriber_id => {
+                    Some(connection.sender.buffer_size())
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns the [`UnableToDeliverStrategy`] the [`Publisher`] pursues when the buffer of a
+    /// [`crate::port::subscriber::Subscriber`] is full.
+    pub fn unable_to_deliver_strategy(&self) -> UnableToDeliverStrategy {
+        self.config.unable_to_deliver_strategy
+    }
+
+    /// Returns the instance uuid of the service the [`Publisher`] belongs to, see
+    /// [`crate::service::static_config::StaticConfig::instance_uuid()`]. Ports of a service that
+    /// was removed and created again under the same name can be detected by comparing it.
+    pub fn service_instance_uuid(&self) -> &str {
+        self.service.state().static_config.instance_uuid()
+    }
+
+    /// Returns the [`TypeDetails`] of the payload type of the service. Untyped publishers can
+    /// use them to describe the bytes they send.
+    pub fn payload_type_details(&self) -> &TypeDetails {
+        self.service
+            .state()
+            .static_config
+            .publish_subscribe()
+            .payload_type_details()
+    }
+
+    /// Returns the id of the data segment the [`Publisher`] currently loans its samples from.
+    /// A [`Publisher`] with an [`AllocationStrategy`] other than
+    /// [`AllocationStrategy::Static`] moves to a new data segment when the current one is
+    /// exhausted, see [`crate::advanced::SegmentOffset`]. With a
+    /// [`crate::service::port_factory::publisher::BucketConfig`] every bucket is a data segment
+    /// whose id is the index of the bucket, the method returns the id of the smallest one.
+    pub fn segment_id(&self) -> usize {
+        self.current_data_segment_id.get()
+    }
+
+    /// Returns the name of the data segment with the [`Publisher::segment_id()`]. Custom ports
+    /// can map it with [`crate::advanced::DataSegmentView`].
+    pub fn data_segment_name(&self) -> FileName {
+        data_segment_name(self.port_id, self.segment_id())
+    }
+
+    /// Returns how many [`crate::port::subscriber::Subscriber`]s, of this and of other
+    /// processes, are attached to the service. It reads the dynamic config of the service
+    /// and does not depend on the connections of the [`Publisher`], subscribers of processes
+    /// that died are not counted. The liveness of the process of every attached
+    /// [`crate::port::subscriber::Subscriber`] is checked with a system call, a frequent
+    /// caller shall prefer a [`Publisher::connection_monitor()`] which reports the changes
+    /// instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let publisher = service.publisher().create()?;
+    /// let _subscriber = service.subscriber().create()?;
+    ///
+    /// println!("{} subscribers are attached", publisher.number_of_connected_subscribers());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn number_of_connected_subscribers(&self) -> usize {
+        number_of_connected_subscribers(self.service)
+    }
+
+    /// Returns true when at least one [`crate::port::subscriber::Subscriber`] is attached to
+    /// the service, see [`Publisher::number_of_connected_subscribers()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let publisher = service.publisher().create()?;
+    ///
+    /// // the expensive sample is only produced when someone receives it
+    /// if publisher.has_subscribers() {
+    ///     publisher.send_copy(1234)?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn has_subscribers(&self) -> bool {
+        self.number_of_connected_subscribers() > 0
+    }
+
+    /// Creates a [`ConnectionMonitor`] that reports when the number of
+    /// [`Publisher::number_of_connected_subscribers()`] transitions between zero and non-zero,
+    /// see [`crate::port::connection_monitor`].
+    pub fn connection_monitor(
+        &self,
+    ) -> Result<ConnectionMonitor<'_, 'config, Service>, ConnectionMonitorCreateError> {
+        ConnectionMonitor::new(
+            self.service.state().global_config,
+            self.service.state().static_config.uuid(),
+            move || self.number_of_connected_subscribers(),
+        )
+    }
+
+    /// Blocks until at least `min` [`crate::port::subscriber::Subscriber`]s are attached to the
+    /// service, see [`Publisher::number_of_connected_subscribers()`], and returns their number.
+    /// It returns immediately when the condition already holds. The wait is woken up by every
+    /// port that is added to or removed from the service instead of polling the service.
+    ///
+    /// Returns [`WaitForConnectionsError::Timeout`] when the number was not reached within the
+    /// `timeout` and [`WaitForConnectionsError::CounterpartDied`] when the process of an
+    /// attached [`crate::port::subscriber::Subscriber`] dies while waiting, so that the caller
+    /// does not wait for a counterpart that will never come back. A dead process cannot wake
+    /// the wait up, its death is recognized with the next wake up by another port or by
+    /// [`crate::service::Details::remove_stale_resources()`], at the latest after the `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service = zero_copy::Service::new(&ServiceName::new("My/Funk/ServiceName")?)
+    ///     .publish_subscribe()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let publisher = service.publisher().create()?;
+    /// # let _subscriber = service.subscriber().create()?;
+    ///
+    /// // the first sample is not sent before someone receives it
+    /// publisher.wait_for_subscribers(1, Duration::from_secs(5))?;
+    /// publisher.send_copy(1234)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_subscribers(
+        &self,
+        min: usize,
+        timeout: Duration,
+    ) -> Result<usize, WaitForConnectionsError> {
+        wait_for_subscribers(self.service, min, timeout)
+    }
+
+    /// Explicitly updates all connections to the [`crate::port::subscriber::Subscriber`]s and
+    /// delivers the history to new subscribers. Calling it in an idle phase establishes the
+    /// connections ahead of time so that the next send does not have to. Connections that could
+    /// not be established previously are retried. On success it returns the [`ConnectionDiff`]
+    /// since the last update, otherwise a [`SubscriberConnectionError`] that describes the
+    /// first connection that failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let publisher = service.publisher().create()?;
+    /// let subscriber = service.subscriber().create()?;
+    ///
+    /// let diff = publisher.update_connections()?;
+    /// for subscriber_id in diff.established_connections() {
+    ///     println!("connected to subscriber {:?}", subscriber_id);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_connections(&self) -> Result<ConnectionDiff, SubscriberConnectionError> {
+        // the update of the subscriber list state must always be performed, therefore it comes
+        // first
+        if unsafe { (*self.subscriber_list_state.get()).update() }
+            || !unsafe { &*self.broken_connections.get() }.is_empty()
+        {
+            return Ok(fail!(from self, when self.populate_subscriber_channels(),
+                "Connections were updated only partially since at least one connection to a Subscriber port failed."));
+        }
+
+        Ok(ConnectionDiff::default())
+    }
+
+    /// Returns the number of bytes of shared memory the [`Publisher`] has mapped for its data
+    /// segments. With [`AllocationStrategy::Static`] the value is fixed when the [`Publisher`]
+    /// is created, otherwise it grows whenever a loan requires an additional data segment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let publisher = service.publisher().create()?;
+    /// println!("the publisher reserved {} bytes", publisher.memory_budget());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn memory_budget(&self) -> usize {
+        (0..MAX_NUMBER_OF_SEGMENTS)
+            .filter_map(|segment_id| self.data_segment(segment_id).as_ref())
+            .map(|segment| segment.memory.size())
+            .sum()
+    }
+
+    /// Returns the [`PublisherStatistics`] of the [`Publisher`]. The same values are available
+    /// to other processes via
+    /// [`crate::service::dynamic_config::publish_subscribe::DynamicConfig::publisher_statistics()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let publisher = service.publisher().create()?;
+    /// publisher.send_copy(1234)?;
+    ///
+    /// let statistics = publisher.statistics();
+    /// println!("sent samples: {}, loaned samples: {}, connections: {}",
+    ///     statistics.sent_samples(), statistics.loaned_samples(),
+    ///     statistics.active_connections());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn statistics(&self) -> PublisherStatistics {
+        self.service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .publisher_statistics_of(self.dynamic_config_index)
+    }
+
+    /// Returns the number of samples in the buffer of every connected
+    /// [`crate::port::subscriber::Subscriber`] that were delivered but not yet received.
+    /// Subscribers that left the service are not contained.
+    pub fn pending_samples_per_subscriber(&self) -> Vec<(UniqueSubscriberId, usize)> {
+        (0..self.subscriber_connections.len())
+            .filter_map(|i| self.subscriber_connections.get(i).as_ref())
+            .filter(|connection| connection.sender.is_connected())
+            .map(|connection| {
+                (
+                    connection.subscriber_id,
+                    connection.sender.number_of_pending_samples(),
+                )
+            })
+            .collect()
+    }
+
+    fn number_of_pending_samples(&self) -> usize {
+        self.pending_samples_per_subscriber()
+            .iter()
+            .map(|(_, pending_samples)| pending_samples)
+            .sum()
+    }
+
+    /// Blocks until every connected [`crate::port::subscriber::Subscriber`] has received the
+    /// samples in its buffer, see [`Publisher::pending_samples_per_subscriber()`], or the
+    /// timeout has passed. Returns the number of samples that are still pending, it is zero
+    /// when all buffers were drained. The subscribers wake the [`Publisher`] up when their
+    /// buffer becomes empty. Requires a service with notifications, see
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notifications()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .enable_notifications(true)
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let publisher = service.publisher().create()?;
+    /// publisher.send_copy(1234)?;
+    ///
+    /// // give the subscribers the chance to receive the last samples before shutting down
+    /// let remaining = publisher.flush(Duration::from_millis(100))?;
+    /// if remaining != 0 {
+    ///     println!("{} samples were not received", remaining);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flush(&self, timeout: Duration) -> Result<usize, PublisherWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let msg = "Unable to flush the samples";
+
+        let listener = match self.listener {
+            Some(ref listener) => listener,
+            None => {
+                fail!(from self, with PublisherWaitError::NotificationsNotEnabled,
+                    "{} since the service does not have notifications enabled.", msg);
+            }
+        };
+        let dynamic_config = self
+            .service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe();
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            // the publisher is announced before it checks the buffers the last time, a
+            // subscriber that empties its buffer afterwards sees the announcement
+            let _announcement = WaitingAnnouncement::new(dynamic_config);
+
+            let pending_samples = self.number_of_pending_samples();
+            if pending_samples == 0 {
+                return Ok(0);
+            }
+
+            let wait_result = match deadline.checked_duration_since(Instant::now()) {
+                Some(timeout) if !timeout.is_zero() => listener.timed_wait(timeout),
+                _ => return Ok(pending_samples),
+            };
+
+            if let Err(e) = wait_result {
+                fail!(from self, with PublisherWaitError::InternalFailure,
+                    "{} since the underlying listener failed ({:?}).", msg, e);
+            }
+
+            // releases wake the publisher up as well, the buffers are checked again
+            while let Ok(Some(_)) = listener.try_wait() {}
+        }
+    }
+
+    /// Returns the largest sequence number up to which all samples the [`Publisher`] sent were
+    /// consumed or [`None`] when none was consumed yet. A sample is consumed when every
+    /// [`crate::port::subscriber::Subscriber`] that received it has released it and it left the
+    /// history of the [`Publisher`]. The progress is reported independently of the
+    /// [`SampleToken`]s and requires a [`Publisher`] that was created with
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::enable_consumption_tracking()`],
+    /// [`None`] is returned otherwise.
+    ///
+    /// The progress is not reported per [`crate::port::subscriber::Subscriber`]. A chunk is
+    /// shared by all subscribers and returns to the [`Publisher`] when its reference counter
+    /// drops to zero, a release of a single subscriber is not observable without recording
+    /// for every connection which samples were delivered to it while sending.
+    pub fn consumed_up_to(&self) -> Option<u64> {
+        let unconsumed_samples = self.unconsumed_samples.as_ref()?;
+        self.retrieve_returned_samples();
+
+        let next_sequence_number = match unsafe { &*unconsumed_samples.get() }.front() {
+            Some((_, sequence_number)) => *sequence_number,
+            None => self.sequence_number.load(Ordering::Relaxed),
+        };
+        next_sequence_number.checked_sub(1)
+    }
+
+    fn is_consumed(&self, token: SampleToken) -> bool {
+        self.retrieve_returned_samples();
+        match &self.unconsumed_samples {
+            Some(unconsumed_samples) => !unsafe { &*unconsumed_samples.get() }
+                .iter()
+                .any(|(_, sequence_number)| *sequence_number == token.sequence_number()),
+            None => false,
+        }
+    }
+
+    /// Blocks until the sample of the [`SampleToken`] was consumed or the timeout has passed,
+    /// see [`Publisher::consumed_up_to()`]. Returns true when it was consumed, for instance
+    /// to recycle an external resource that is registered for the sample. The token must
+    /// belong to a sample of this [`Publisher`]. The subscribers wake the [`Publisher`] up
+    /// when they release a sample, it requires a service with notifications, see
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notifications()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .enable_notifications(true)
+    /// #     .history_size(0)
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let publisher = service.publisher().enable_consumption_tracking(true).create()?;
+    /// let subscriber = service.subscriber().create()?;
+    ///
+    /// let token = publisher.loan_uninit()?.write_payload(1234).send_with_token()?;
+    /// let sample = subscriber.receive()?;
+    /// assert!(!publisher.wait_consumed(token, Duration::from_millis(1))?);
+    ///
+    /// drop(sample);
+    /// assert!(publisher.wait_consumed(token, Duration::from_millis(100))?);
+    /// assert_eq!(publisher.consumed_up_to(), Some(token.sequence_number()));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_consumed(
+        &self,
+        token: SampleToken,
+        timeout: Duration,
+    ) -> Result<bool, PublisherWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let msg = "Unable to wait until the sample was consumed";
+
+        if self.unconsumed_samples.is_none() {
+            fail!(from self, with PublisherWaitError::ConsumptionTrackingNotEnabled,
+                "{} since the publisher was created without consumption tracking.", msg);
+        }
+
+        let listener = match self.listener {
+            Some(ref listener) => listener,
+            None => {
+                fail!(from self, with PublisherWaitError::NotificationsNotEnabled,
+                    "{} since the service does not have notifications enabled.", msg);
+            }
+        };
+        let dynamic_config = self
+            .service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe();
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            // the publisher is announced before it reclaims the samples the last time, a
+            // subscriber that releases the sample afterwards sees the announcement
+            let _announcement = WaitingAnnouncement::new(dynamic_config);
+
+            if self.is_consumed(token) {
+                return Ok(true);
+            }
+
+            let wait_result = match deadline.checked_duration_since(Instant::now()) {
+                Some(timeout) if !timeout.is_zero() => listener.timed_wait(timeout),
+                _ => return Ok(false),
+            };
+
+            if let Err(e) = wait_result {
+                fail!(from self, with PublisherWaitError::InternalFailure,
+                    "{} since the underlying listener failed ({:?}).", msg, e);
+            }
+
+            while let Ok(Some(_)) = listener.try_wait() {}
+        }
+    }
+
+    /// Converts the [`Publisher`] into a [`SyncPublisher`] that can be shared between threads.
+    /// The [`crate::port::sync_publisher::SyncSampleMut`]s it loans implement [`Send`] so that
+    /// they can be loaned, written and sent in different threads. A [`DegrationCallback`] is
+    /// removed since it is not required to be thread-safe, a thread-safe one can be set with
+    /// [`SyncPublisher::set_degration_callback()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let publisher = service.publisher().create()?.into_sync();
+    ///
+    /// let sample = publisher.loan_uninit()?;
+    /// let sample = std::thread::scope(|s| {
+    ///     s.spawn(move || sample.write_payload(1234)).join().unwrap()
+    /// });
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_sync(mut self) -> SyncPublisher<'a, 'config, Service, MessageType, UserHeader> {
+        if self.degration_callback.take().is_some() {
+            warn!(from self,
+                "The degration callback is removed since it is not required to be thread-safe. Use SyncPublisher::set_degration_callback() to set a thread-safe one.");
+        }
+        SyncPublisher::new(self)
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Publisher`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or a seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -410,120 +1724,624 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             None => self.degration_callback = None,
         }
     }
+
+    /// Sets the [`TracingHooks`] of the [`Publisher`] that are called whenever a sample is
+    /// loaned or sent. [`None`] removes them.
+    pub fn set_tracing_hooks<H: TracingHooks<UserHeader> + 'a>(&mut self, hooks: Option<H>) {
+        self.tracing_hooks =
+            hooks.map(|hooks| Box::new(hooks) as Box<dyn TracingHooks<UserHeader> + 'a>);
+    }
 }
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug + Default>
-    Publish<MessageType> for Publisher<'a, 'config, Service, MessageType>
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ?Sized,
+        UserHeader: Debug,
+    > Publisher<'a, 'config, Service, MessageType, UserHeader>
 {
+    fn counters(&self) -> &PublisherCounters {
+        self.service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .publisher_counters(self.dynamic_config_index)
+    }
+
+    fn bucket_counters(&self, segment_id: usize) -> Option<&BucketCounters> {
+        match self.config.bucket_config {
+            Some(_) => Some(&self.counters().buckets[segment_id]),
+            None => None,
+        }
+    }
+
+    fn count_delivery(&self, subscriber_index: usize) {
+        self.service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .subscriber_counters(subscriber_index as u32)
+            .delivered_samples
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Wakes up the [`crate::port::subscriber::Subscriber`]s that wait for a sample, it costs a
+    /// single atomic load when no subscriber is waiting.
+    fn notify_waiting_subscribers(&self) {
+        use iceoryx2_cal::event::Notifier;
+        if !self.has_waiting_subscribers() {
+            return;
+        }
+
+        for i in 0..self.subscriber_connections.len() {
+            if let Some(Connection {
+                notifier: Some(ref notifier),
+                subscriber_id,
+                ..
+            }) = self.subscriber_connections.get(i)
+            {
+                if let Err(e) = notifier.notify(EventId::new(0)) {
+                    warn!(from self, "Unable to wake up subscriber {:?} ({:?}).", subscriber_id, e);
+                }
+            }
+        }
+    }
+
+    /// Wakes up all waiting [`crate::port::subscriber::Subscriber`]s of the service, also the
+    /// ones that joined after the connections were updated the last time. Used when the
+    /// [`Publisher`] leaves so that no subscriber waits for it forever.
+    fn notify_all_waiting_subscribers(&self) {
+        use iceoryx2_cal::event::Notifier;
+        if !self.has_waiting_subscribers() {
+            return;
+        }
+
+        // the list is refreshed without establishing connections, a leaving publisher does
+        // not deliver its history anymore
+        let subscriber_list_state = unsafe { &mut *self.subscriber_list_state.get() };
+        subscriber_list_state.update();
+        subscriber_list_state.for_each(|index, details| {
+            let subscriber_id = details.subscriber_id;
+            let result = match self.subscriber_connections.get(index as usize) {
+                Some(Connection {
+                    notifier: Some(ref notifier),
+                    subscriber_id: connected_id,
+                    ..
+                }) if *connected_id == subscriber_id => notifier.notify(EventId::new(0)),
+                _ => match self.subscriber_connections.open_notifier(subscriber_id) {
+                    Some(notifier) => notifier.notify(EventId::new(0)),
+                    None => return,
+                },
+            };
+
+            if let Err(e) = result {
+                warn!(from self, "Unable to wake up subscriber {:?} ({:?}).", subscriber_id, e);
+            }
+        });
+    }
+
+    fn has_waiting_subscribers(&self) -> bool {
+        if !self
+            .service
+            .state()
+            .static_config
+            .publish_subscribe()
+            .enable_notifications
+        {
+            return false;
+        }
+
+        // a subscriber announces that it waits before it checks for a sample the last time, the
+        // delivery of the sample must be visible before the announcement is read
+        fence(Ordering::SeqCst);
+        self.service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .number_of_waiting_subscribers()
+            != 0
+    }
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> UpdateConnections
-    for Publisher<'a, 'config, Service, MessageType>
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ?Sized,
+        UserHeader: Debug,
+    > Drop for Publisher<'a, 'config, Service, MessageType, UserHeader>
 {
-    fn update_connections(&self) -> Result<(), ConnectionFailure> {
-        if unsafe { (*self.subscriber_list_state.get()).update() } {
-            fail!(from self, when self.populate_subscriber_channels(),
-                "Connections were updated only partially since at least one connection to a Subscriber port failed.");
-        }
+    fn drop(&mut self) {
+        // waiting subscribers must not block forever when the last publisher leaves, therefore
+        // they are woken up after the publisher is removed from the service
+        self.service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .reset_publisher_counters(self.dynamic_config_index);
+        self.dynamic_config_guard.take();
+        self.notify_all_waiting_subscribers();
+        announce_connection_change::<Service>(
+            self.service.state().global_config,
+            self.service.state().static_config.uuid(),
+        );
+    }
+}
 
-        Ok(())
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ZeroCopySend + Default,
+        UserHeader: Debug + Default,
+    > Publish<MessageType, UserHeader>
+    for Publisher<'a, 'config, Service, MessageType, UserHeader>
+{
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug + Default,
+    > UpdateConnections for Publisher<'a, 'config, Service, MessageType, UserHeader>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        Publisher::update_connections(self)
+            .map(|_| ())
+            .map_err(|e| e.failure())
     }
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> SendCopy<MessageType>
-    for Publisher<'a, 'config, Service, MessageType>
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ZeroCopySend,
+        UserHeader: Debug + Default,
+    > SendCopy<MessageType> for Publisher<'a, 'config, Service, MessageType, UserHeader>
 {
     fn send_copy(&self, value: MessageType) -> Result<usize, PublisherSendError> {
         let msg = "Unable to send copy of message";
-        let mut sample = fail!(from self, when self.loan_uninit(),
+        let sample = fail!(from self, when self.loan_uninit(),
                                     "{} since the loan of a sample failed.", msg);
 
-        sample.payload_mut().write(value);
-        Ok(
-            fail!(from self, when self.send_impl(sample.offset_to_chunk().value()),
-            "{} since the underlying send operation failed.", msg),
-        )
+        // sent via the sample so that it is released as sent and not counted as cancelled
+        Ok(fail!(from self, when sample.write_payload(value).send(),
+            "{} since the underlying send operation failed.", msg))
     }
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
-    UninitLoan<MessageType> for Publisher<'a, 'config, Service, MessageType>
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: TaggedPayload,
+        UserHeader: Debug + Default,
+    > Publisher<'a, 'config, Service, MessageType, UserHeader>
 {
-    fn loan_uninit(&self) -> Result<SampleMut<MaybeUninit<MessageType>>, PublisherLoanError> {
-        self.retrieve_returned_samples();
-        let msg = "Unable to loan Sample";
+    /// Sends a copy of a variant of a tagged union payload, see
+    /// [`crate::tagged_payload`]. The variant can be provided as the type of its field when the
+    /// payload derives [`TaggedPayload`](derive@crate::tagged_payload::TaggedPayload), or as
+    /// the payload itself.
+    ///
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that
+    /// received the sample, otherwise a [`PublisherSendError`].
+    pub fn send_variant<Variant>(&self, variant: Variant) -> Result<usize, PublisherSendError>
+    where
+        MessageType: From<Variant>,
+    {
+        self.send_copy(MessageType::from(variant))
+    }
+}
 
-        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_samples {
-            fail!(from self, with PublisherLoanError::ExceedsMaxLoanedChunks,
-                "{} since already {} samples were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned sample to loan another sample.",
-                msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_samples);
-        }
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ZeroCopySend,
+        UserHeader: Debug + Default,
+    > UninitLoan<MessageType, UserHeader>
+    for Publisher<'a, 'config, Service, MessageType, UserHeader>
+{
+    fn loan_uninit(
+        &self,
+    ) -> Result<SampleMut<MaybeUninit<MessageType>, UserHeader>, PublisherLoanError> {
+        self.loan_uninit_for(self)
+    }
+}
 
-        match self
-            .data_segment
-            .allocate(Layout::new::<Message<Header, MessageType>>())
-        {
-            Ok(chunk) => {
-                if self.sample_reference_counter[Self::sample_index(chunk.offset.value())]
-                    .fetch_add(1, Ordering::Relaxed)
-                    != 0
-                {
-                    fatal_panic!(from self,
-                                "{} since the allocated sample is already in use! This should never happen!", msg);
-                }
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ZeroCopySend,
+        UserHeader: Debug + Default,
+    > Publisher<'a, 'config, Service, MessageType, UserHeader>
+{
+    /// Loans/allocates a [`SampleMut`] from the underlying data segment of the [`Publisher`]
+    /// and sets all bytes of the payload to zero. In contrast to
+    /// [`crate::port::publish::DefaultLoan::loan()`] the `MessageType` does not have to
+    /// implement [`Default`] which makes it usable for generated FFI types. The
+    /// [`Header`] and the user header of the sample are not affected.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the all-zero bit pattern is a valid value of `MessageType`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// #[derive(Debug, ZeroCopySend)]
+    /// #[repr(C)]
+    /// struct FfiStruct {
+    ///     id: u32,
+    ///     values: [f32; 16],
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ZeroedPayload")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<FfiStruct>()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// // SAFETY: all fields of FfiStruct are valid when they are zero
+    /// let mut sample = unsafe { publisher.loan_zeroed()? };
+    /// sample.payload_mut().id = 42;
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn loan_zeroed(
+        &self,
+    ) -> Result<SampleMut<MessageType, UserHeader>, PublisherLoanError> {
+        let mut sample = self.loan_uninit()?;
+        sample.payload_mut().as_mut_ptr().write_bytes(0, 1);
+        Ok(sample.assume_init())
+    }
 
-                let message =
-                    chunk.data_ptr as *mut MaybeUninit<Message<Header, MaybeUninit<MessageType>>>;
-
-                let sample = unsafe {
-                    (*message).write(Message {
-                        header: Header::new(self.port_id),
-                        data: MaybeUninit::uninit(),
-                    });
-                    RawSampleMut::new_unchecked(
-                        message as *mut Message<Header, MaybeUninit<MessageType>>,
-                    )
-                };
+    /// Loans a [`SampleMut`] like [`UninitLoan::loan_uninit()`] but when the data segment is
+    /// out of memory it sleeps until a [`crate::port::subscriber::Subscriber`] releases a
+    /// sample or the timeout has passed. Returns [`None`] on timeout. Failures that waiting
+    /// cannot resolve, like [`PublisherLoanError::ExceedsMaxLoanedSamples`], are returned
+    /// right away. Requires a service with notifications, see
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notifications()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .enable_notifications(true)
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let publisher = service.publisher().create()?;
+    ///
+    /// if let Some(sample) = publisher.timed_loan_uninit(Duration::from_millis(100))? {
+    ///     sample.write_payload(1234).send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn timed_loan_uninit(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<SampleMut<MaybeUninit<MessageType>, UserHeader>>, PublisherWaitError> {
+        self.wait_for_free_sample(Some(Instant::now() + timeout))
+    }
 
-                self.loan_counter.fetch_add(1, Ordering::Relaxed);
-                Ok(SampleMut::new(self, sample, chunk.offset))
+    /// Loans a [`SampleMut`] like [`UninitLoan::loan_uninit()`] but when the data segment is
+    /// out of memory it sleeps until a [`crate::port::subscriber::Subscriber`] releases a
+    /// sample. Failures that waiting cannot resolve, like
+    /// [`PublisherLoanError::ExceedsMaxLoanedSamples`], are returned right away. Requires a
+    /// service with notifications, see
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notifications()`].
+    pub fn blocking_loan_uninit(
+        &self,
+    ) -> Result<SampleMut<MaybeUninit<MessageType>, UserHeader>, PublisherWaitError> {
+        match self.wait_for_free_sample(None)? {
+            Some(sample) => Ok(sample),
+            None => {
+                fatal_panic!(from self,
+                    "This should never happen! The blocking loan returned without a sample.")
             }
-            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
-                fail!(from self, with PublisherLoanError::OutOfMemory,
-                    "{} since the underlying shared memory is out of memory.", msg);
+        }
+    }
+
+    fn wait_for_free_sample(
+        &self,
+        deadline: Option<Instant>,
+    ) -> Result<Option<SampleMut<MaybeUninit<MessageType>, UserHeader>>, PublisherWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let msg = "Unable to wait for a free sample";
+
+        let listener = match self.listener {
+            Some(ref listener) => listener,
+            None => {
+                fail!(from self, with PublisherWaitError::NotificationsNotEnabled,
+                    "{} since the service does not have notifications enabled.", msg);
             }
-            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
-            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
-                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+        };
+        let dynamic_config = self
+            .service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe();
+
+        loop {
+            // the publisher is announced before it tries to loan the last time, a subscriber
+            // that releases a sample afterwards sees the announcement and wakes it up
+            let _announcement = WaitingAnnouncement::new(dynamic_config);
+
+            match self.loan_uninit() {
+                Ok(sample) => return Ok(Some(sample)),
+                Err(
+                    PublisherLoanError::OutOfMemory | PublisherLoanError::BucketExhausted { .. },
+                ) => {}
+                Err(e) => return Err(e.into()),
             }
-            Err(v) => {
-                fail!(from self, with PublisherLoanError::InternalFailure,
-                    "{} since an internal failure occurred ({:?}).", msg, v);
+
+            let wait_result = match deadline {
+                None => listener.blocking_wait(),
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(timeout) if !timeout.is_zero() => listener.timed_wait(timeout),
+                    _ => return Ok(None),
+                },
+            };
+
+            if let Err(e) = wait_result {
+                fail!(from self, with PublisherWaitError::InternalFailure,
+                    "{} since the underlying listener failed ({:?}).", msg, e);
             }
+
+            // every subscriber notifies on its own, the released samples of all of them are
+            // reclaimed by the next loan, spurious wake ups end up there as well
+            while let Ok(Some(_)) = listener.try_wait() {}
         }
     }
+
+    /// Loans a sample whose release and send operations are performed by `owner`. It is either
+    /// the [`Publisher`] itself or a [`SyncPublisher`] that guards the [`Publisher`].
+    pub(crate) fn loan_uninit_for<'owner>(
+        &self,
+        owner: &'owner dyn PublishMgmt,
+    ) -> Result<SampleMut<'owner, MaybeUninit<MessageType>, UserHeader>, PublisherLoanError> {
+        let chunk = self.allocate_sample(1)?;
+        let sample = unsafe {
+            RawSampleMut::from_header_and_payload_ptrs(
+                chunk.data_ptr as *mut Message<Header, UserHeader, ()>,
+                chunk.data_ptr.add(self.payload_offset) as *mut MaybeUninit<MessageType>,
+            )
+        };
+
+        Ok(SampleMut::new(owner, sample, chunk.offset))
+    }
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> PublishMgmt
-    for Publisher<'a, 'config, Service, MessageType>
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ZeroCopySend,
+        UserHeader: Debug + Default,
+    > Publisher<'a, 'config, Service, [MessageType], UserHeader>
 {
-    fn return_loaned_sample(&self, distance_to_chunk: PointerOffset) {
+    /// Loans/allocates a [`SampleMut`] with a slice of `number_of_elements` uninitialized
+    /// elements from the underlying data segment of the [`Publisher`]. The user has to
+    /// initialize the payload before it can be sent. The number of elements must not exceed the
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::max_slice_len()`] of
+    /// the service unless the [`Publisher`] was created with an
+    /// [`AllocationStrategy`] that creates a new data segment for larger slices.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/EncodedFrames").unwrap();
+    /// #
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .max_slice_len(1024)
+    ///     .open_or_create::<[u8]>()?;
+    ///
+    /// let publisher = service.publisher().create()?;
+    ///
+    /// let frame = [0x0a, 0x03, 0x66, 0x6f, 0x6f];
+    /// let sample = publisher.loan_slice_uninit(frame.len())?;
+    /// let sample = sample.write_from_slice(&frame);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_slice_uninit(
+        &self,
+        number_of_elements: usize,
+    ) -> Result<SampleMut<[MaybeUninit<MessageType>], UserHeader>, PublisherLoanError> {
+        self.loan_slice_uninit_for(self, number_of_elements)
+    }
+
+    pub(crate) fn loan_slice_uninit_for<'owner>(
+        &self,
+        owner: &'owner dyn PublishMgmt,
+        number_of_elements: usize,
+    ) -> Result<SampleMut<'owner, [MaybeUninit<MessageType>], UserHeader>, PublisherLoanError> {
+        let max_slice_len = self
+            .service
+            .state()
+            .static_config
+            .publish_subscribe()
+            .max_slice_len
+            * self.element_size;
+
+        if self.config.allocation_strategy == AllocationStrategy::Static
+            && number_of_elements > max_slice_len
+        {
+            fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                "Unable to loan a slice with {} elements since the service supports only slices with up to {} elements.",
+                number_of_elements, max_slice_len);
+        }
+
+        if number_of_elements % self.element_size != 0 {
+            fail!(from self, with PublisherLoanError::PayloadSizeIsNotAMultipleOfTypeSize,
+                "Unable to loan an untyped payload with {} bytes since it is not a multiple of the payload type size of {} bytes.",
+                number_of_elements, self.element_size);
+        }
+
+        if Layout::array::<MessageType>(number_of_elements).is_err() {
+            fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                "Unable to loan a slice with {} elements since its size exceeds the supported memory size.",
+                number_of_elements);
+        }
+
+        let chunk = self.allocate_sample(number_of_elements)?;
+        let sample = unsafe {
+            RawSampleMut::from_header_and_payload_ptrs(
+                chunk.data_ptr as *mut Message<Header, UserHeader, ()>,
+                core::ptr::slice_from_raw_parts_mut(
+                    chunk.data_ptr.add(self.payload_offset) as *mut MaybeUninit<MessageType>,
+                    number_of_elements,
+                ),
+            )
+        };
+
+        Ok(SampleMut::new(owner, sample, chunk.offset))
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, UserHeader: Debug + Default>
+    Publisher<'a, 'config, Service, [u8], UserHeader>
+{
+    /// Loans the bytes of a single payload element of the service, its size is the
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::type_size()`]. Slices
+    /// with multiple elements can be loaned with [`Publisher::loan_slice_uninit()`], their size
+    /// must be a multiple of the type size. It is intended for publishers that are created with
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::create_untyped()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let publisher = service.publisher().create_untyped()?;
+    /// let sample = publisher.loan_untyped()?;
+    /// let sample = sample.write_from_slice(&1234u64.to_ne_bytes());
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_untyped(
+        &self,
+    ) -> Result<SampleMut<[MaybeUninit<u8>], UserHeader>, PublisherLoanError> {
+        self.loan_slice_uninit(self.element_size)
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug + Default,
+    > PublishMgmt for Publisher<'a, 'config, Service, MessageType, UserHeader>
+{
+    fn return_loaned_sample(&self, distance_to_chunk: PointerOffset, release: LoanRelease) {
+        // the chunk was already released when it is returned again
+        if !self.used_chunks.remove(distance_to_chunk, self) {
+            return;
+        }
+
+        match release {
+            LoanRelease::Sent => (),
+            LoanRelease::Cancelled => {
+                self.counters()
+                    .cancelled_samples
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+            LoanRelease::Dropped => {
+                self.counters()
+                    .cancelled_samples
+                    .fetch_add(1, Ordering::Relaxed);
+                #[cfg(debug_assertions)]
+                warn!(from self,
+                    "A loaned sample was dropped without being sent. Use SampleMut::cancel() when the sample shall be discarded.");
+            }
+        }
+
         self.release_sample(distance_to_chunk);
         self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+        self.counters()
+            .loaned_samples
+            .fetch_sub(1, Ordering::Relaxed);
     }
 
-    fn send_impl(&self, address_to_chunk: usize) -> Result<usize, ConnectionFailure> {
-        fail!(from self, when self.update_connections(),
-            "Unable to send sample since the connections could not be updated.");
+    fn allows_user_time_stamps(&self) -> bool {
+        self.service
+            .state()
+            .static_config
+            .publish_subscribe()
+            .allow_user_time_stamps
+    }
 
-        self.add_to_history(address_to_chunk);
-        Ok(self.deliver_sample(address_to_chunk))
+    fn send_impl(
+        &self,
+        address_to_chunk: usize,
+        keep_time_stamp: bool,
+    ) -> Result<usize, PublisherSendError> {
+        self.send_sample(address_to_chunk, keep_time_stamp)
+            .map(|(number_of_recipients, _)| number_of_recipients)
+    }
+
+    fn send_with_token_impl(
+        &self,
+        address_to_chunk: usize,
+        keep_time_stamp: bool,
+    ) -> Result<SampleToken, PublisherSendError> {
+        self.send_sample(address_to_chunk, keep_time_stamp)
+            .map(|(_, token)| token)
     }
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Default + Debug>
-    DefaultLoan<MessageType> for Publisher<'a, 'config, Service, MessageType>
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Default + Debug + ZeroCopySend,
+        UserHeader: Debug + Default,
+    > DefaultLoan<MessageType, UserHeader>
+    for Publisher<'a, 'config, Service, MessageType, UserHeader>
 {
-    fn loan(&self) -> Result<SampleMut<MessageType>, PublisherLoanError> {
+    fn loan(&self) -> Result<SampleMut<MessageType, UserHeader>, PublisherLoanError> {
         Ok(self.loan_uninit()?.write_payload(MessageType::default()))
     }
 }
@@ -0,0 +1,777 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/Calculator")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     println!("received request: {}", *active_request);
+//!
+//!     // loan some uninitialized memory and send it as response
+//!     let response = active_request.loan_uninit()?;
+//!     let response = response.write_payload(*active_request * 2);
+//!     response.send()?;
+//!
+//!     // send a copy of the value as another response to the same request
+//!     active_request.send_copy(*active_request * 3)?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::alloc::Layout;
+use std::cell::{Cell, UnsafeCell};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::Ordering;
+
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
+use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::shared_memory::SharedMemory;
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::*;
+
+use crate::message::Message;
+use crate::port::details::client_connections::ClientConnections;
+use crate::port::details::data_segment::DataSegment;
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::raw_sample::{RawSample, RawSampleMut};
+use crate::service;
+use crate::service::config_scheme::data_segment_config;
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+use crate::service::naming_scheme::server_data_segment_name;
+use crate::service::port_factory::server::LocalServerConfig;
+use crate::service::stale_resources::add_port_to_dynamic_config;
+use crate::service::static_config::request_response::StaticConfig;
+
+/// Describes the failures when a new [`Server`] is created via the
+/// [`crate::service::port_factory::server::PortFactoryServer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerCreateError {
+    ExceedsMaxSupportedServers,
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ServerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServerCreateError {}
+
+/// Defines the failures that can occur when a request is received with [`Server::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerReceiveError {
+    ExceedsMaxActiveRequests,
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl std::fmt::Display for ServerReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServerReceiveError {}
+
+/// Defines the failures that can occur when a response is loaned with
+/// [`ActiveRequest::loan_uninit()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum ResponseLoanError {
+    ExceedsMaxLoanedResponses,
+    OutOfMemory,
+    InternalFailure,
+}
+
+impl std::fmt::Display for ResponseLoanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ResponseLoanError {}
+
+enum_gen! {
+    /// Defines the failures that can occur when a response is sent with [`ResponseMut::send()`]
+    /// or [`ActiveRequest::send_copy()`].
+    ResponseSendError
+  entry:
+    RequestNoLongerActive,
+    ClientDisconnected,
+    ExceedsMaxResponsesPerRequest,
+    ReceiveBufferFull,
+    ConnectionCorrupted
+  mapping:
+    ResponseLoanError
+}
+
+impl std::fmt::Display for ResponseSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ResponseSendError {}
+
+/// The interface the active requests and responses use to return their memory to the
+/// [`Server`].
+trait ServerMgmt: Debug {
+    fn server_id(&self) -> UniqueServerId;
+    fn max_responses_per_request(&self) -> usize;
+    fn is_connected(&self, channel_id: usize, client_id: UniqueClientId) -> bool;
+    fn release_request(&self, channel_id: usize, offset: PointerOffset);
+    fn loan_response(&self) -> Result<(PointerOffset, usize), ResponseLoanError>;
+    fn return_loaned_response(&self, offset: PointerOffset);
+    fn send_response(
+        &self,
+        channel_id: usize,
+        client_id: UniqueClientId,
+        offset: PointerOffset,
+    ) -> Result<(), ResponseSendError>;
+}
+
+/// The responding endpoint of a request-response communication. It receives the requests of all
+/// connected [`crate::port::client::Client`]s as [`ActiveRequest`]s and sends the responses
+/// with them.
+#[derive(Debug)]
+pub struct Server<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    RequestPayload: Debug,
+    ResponsePayload: Debug,
+> {
+    client_connections: ClientConnections<'config, Service>,
+    client_list_state: UnsafeCell<ContainerState<'a, UniqueClientId>>,
+    data_segment: DataSegment<Service::SharedMemory>,
+    service: &'a Service,
+    loan_counter: Cell<usize>,
+    max_loaned_responses: usize,
+    _dynamic_config_guard: UniqueIndex<'a>,
+    _phantom_request_payload: PhantomData<RequestPayload>,
+    _phantom_response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        RequestPayload: Debug,
+        ResponsePayload: Debug,
+    > Server<'a, 'config, Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        service: &'a Service,
+        static_config: &StaticConfig,
+        config: &LocalServerConfig,
+    ) -> Result<Self, ServerCreateError> {
+        let msg = "Unable to create Server port";
+        let origin = "Server::new()";
+        let port_id = UniqueServerId::new();
+        let client_list = &service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .clients;
+
+        let data_segment = fail!(from origin,
+                when DataSegment::create(
+                    &server_data_segment_name(port_id),
+                    &data_segment_config::<Service>(service.state().global_config),
+                    1,
+                    Layout::new::<Message<ResponseHeader, (), ResponsePayload>>(),
+                    static_config.required_amount_of_responses_per_data_segment(config.max_loaned_responses)),
+                with ServerCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        // !MUST! be the last task otherwise a server is added to the dynamic config without the
+        // creation of all required resources
+        let _dynamic_config_guard = match add_port_to_dynamic_config::<Service, _>(
+            service.state().dynamic_storage.get(),
+            service.state().global_config,
+            || {
+                service
+                    .state()
+                    .dynamic_storage
+                    .get()
+                    .request_response()
+                    .add_server_id(port_id)
+            },
+        ) {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with ServerCreateError::ExceedsMaxSupportedServers,
+                            "{} since it would exceed the maximum supported amount of servers of {}.",
+                            msg, static_config.max_servers);
+            }
+        };
+
+        let new_self = Self {
+            client_connections: ClientConnections::new(
+                client_list.capacity(),
+                port_id,
+                service.state().global_config,
+                static_config,
+            ),
+            client_list_state: UnsafeCell::new(unsafe { client_list.get_state() }),
+            data_segment,
+            service,
+            loan_counter: Cell::new(0),
+            max_loaned_responses: config.max_loaned_responses,
+            _dynamic_config_guard,
+            _phantom_request_payload: PhantomData,
+            _phantom_response_payload: PhantomData,
+        };
+
+        if let Err(e) = new_self.populate_client_channels() {
+            warn!(from new_self, "The new Server port is unable to connect to every Client port, caused by {:?}.", e);
+        }
+
+        Ok(new_self)
+    }
+
+    fn populate_client_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.client_connections.capacity(), None);
+
+        unsafe {
+            (*self.client_list_state.get()).for_each(|index, client_id| {
+                visited_indices[index as usize] = Some(*client_id);
+            })
+        };
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(client_id) => {
+                    if let Err(e) = self.client_connections.create(i, *client_id) {
+                        warn!(from self, "Unable to establish connection to new client {:?}.", client_id);
+                        result = Err(e.into());
+                    }
+                }
+                None => self.client_connections.disconnect(i),
+            }
+        }
+
+        result
+    }
+
+    fn reference_counter(&self, offset: PointerOffset) -> &std::sync::atomic::AtomicU64 {
+        self.data_segment.reference_counter(offset.value())
+    }
+
+    fn release_response(&self, offset: PointerOffset) {
+        if self
+            .reference_counter(offset)
+            .fetch_sub(1, Ordering::Relaxed)
+            == 1
+        {
+            unsafe {
+                fatal_panic!(from self, when self.data_segment.memory.deallocate(offset, self.data_segment.sample_layout),
+                    "Internal logic error. The response should always contain a valid memory chunk from the provided allocator.");
+            };
+        }
+    }
+
+    fn retrieve_returned_responses(&self) {
+        for i in 0..self.client_connections.len() {
+            if let Some(ref connection) = self.client_connections.get(i) {
+                loop {
+                    match connection.response_sender.reclaim() {
+                        Ok(Some(offset)) => self.release_response(offset),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim responses from connection {:?} due to {:?}. This may lead to a situation where no more responses will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`Server`]
+    pub fn id(&self) -> UniqueServerId {
+        self.client_connections.server_id()
+    }
+
+    /// Returns the instance uuid of the service of the [`Server`], see
+    /// [`crate::service::static_config::StaticConfig::instance_uuid()`].
+    pub fn service_instance_uuid(&self) -> &str {
+        self.service.state().static_config.instance_uuid()
+    }
+
+    /// Returns true when at least one [`crate::port::client::Client`] has sent a request
+    /// that was not yet received.
+    pub fn has_requests(&self) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+            "Some requests are not being received since not all connections to clients could be established.");
+
+        for id in 0..self.client_connections.len() {
+            if let Some(ref connection) = self.client_connections.get(id) {
+                if connection.request_receiver.has_data() {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Receives the next request of any connected [`crate::port::client::Client`]. If
+    /// currently no request is available [`None`] is returned. Requests whose
+    /// [`crate::port::client::PendingResponse`] was already dropped are skipped.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<ActiveRequest<'_, RequestPayload, ResponsePayload>>, ServerReceiveError>
+    {
+        let msg = "Unable to receive another request";
+        if let Err(e) = self.update_connections() {
+            fail!(from self, with ServerReceiveError::ConnectionFailure(e),
+                "Some requests are not being received since not all connections to clients could be established.");
+        }
+
+        for id in 0..self.client_connections.len() {
+            let connection = match self.client_connections.get_mut(id) {
+                Some(connection) => connection,
+                None => continue,
+            };
+
+            loop {
+                match connection.request_receiver.receive() {
+                    Ok(None) => break,
+                    Ok(Some(offset)) => {
+                        let data_segment_start = match connection.data_segment() {
+                            Ok(data_segment) => data_segment.allocator_data_start_address(),
+                            Err(e) => {
+                                self.release_request(id, offset);
+                                fail!(from self, with ServerReceiveError::ConnectionFailure(e.into()),
+                                    "{} since the data segment of the client could not be mapped.", msg);
+                            }
+                        };
+
+                        let message = (data_segment_start + offset.value())
+                            as *const Message<RequestHeader, (), RequestPayload>;
+                        let ptr = unsafe { RawSample::new_unchecked(message) };
+
+                        if !ptr.as_header_ref().is_active() {
+                            self.release_request(id, offset);
+                            continue;
+                        }
+
+                        return Ok(Some(ActiveRequest {
+                            server: self,
+                            channel_id: id,
+                            client_id: connection.client_id,
+                            offset,
+                            ptr,
+                            number_of_responses: Cell::new(0),
+                            _phantom_response_payload: PhantomData,
+                        }));
+                    }
+                    Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                        fail!(from self, with ServerReceiveError::ExceedsMaxActiveRequests,
+                            "{} since it would exceed the maximum {} of active requests of a client.",
+                            msg, connection.request_receiver.max_borrowed_samples());
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        RequestPayload: Debug,
+        ResponsePayload: Debug,
+    > UpdateConnections for Server<'a, 'config, Service, RequestPayload, ResponsePayload>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe { (*self.client_list_state.get()).update() } {
+            fail!(from self, when self.populate_client_channels(),
+                "Connections were updated only partially since at least one connection to a client failed.");
+        }
+
+        Ok(())
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        RequestPayload: Debug,
+        ResponsePayload: Debug,
+    > ServerMgmt for Server<'a, 'config, Service, RequestPayload, ResponsePayload>
+{
+    fn server_id(&self) -> UniqueServerId {
+        self.id()
+    }
+
+    fn max_responses_per_request(&self) -> usize {
+        self.service
+            .state()
+            .static_config
+            .request_response()
+            .max_responses_per_request
+    }
+
+    fn is_connected(&self, channel_id: usize, client_id: UniqueClientId) -> bool {
+        match self.client_connections.get(channel_id) {
+            Some(connection) => {
+                connection.client_id == client_id && !connection.is_client_disconnected
+            }
+            None => false,
+        }
+    }
+
+    fn release_request(&self, channel_id: usize, offset: PointerOffset) {
+        match self.client_connections.get(channel_id) {
+            Some(c) => match c.request_receiver.release(offset) {
+                Ok(()) => (),
+                Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                    fatal_panic!(from self, when c.request_receiver.release(offset),
+                        "This should never happen! The clients retrieve channel is full and the request cannot be returned.");
+                }
+            },
+            None => {
+                warn!(from self, "Unable to release request since the connection is broken. The request will be discarded and has to be reclaimed manually by the client.");
+            }
+        }
+    }
+
+    fn loan_response(&self) -> Result<(PointerOffset, usize), ResponseLoanError> {
+        self.retrieve_returned_responses();
+        let msg = "Unable to loan response";
+
+        if self.loan_counter.get() >= self.max_loaned_responses {
+            fail!(from self, with ResponseLoanError::ExceedsMaxLoanedResponses,
+                "{} since already {} responses were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned response to loan another response.",
+                msg, self.loan_counter.get(), self.max_loaned_responses);
+        }
+
+        let chunk = match self
+            .data_segment
+            .memory
+            .allocate(self.data_segment.sample_layout)
+        {
+            Ok(chunk) => chunk,
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with ResponseLoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ResponseLoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        };
+
+        if self
+            .reference_counter(chunk.offset)
+            .fetch_add(1, Ordering::Relaxed)
+            != 0
+        {
+            fatal_panic!(from self,
+                "{} since the allocated response is already in use! This should never happen!", msg);
+        }
+
+        self.loan_counter.set(self.loan_counter.get() + 1);
+        Ok((chunk.offset, chunk.data_ptr as usize))
+    }
+
+    fn return_loaned_response(&self, offset: PointerOffset) {
+        self.loan_counter.set(self.loan_counter.get() - 1);
+        self.release_response(offset);
+    }
+
+    fn send_response(
+        &self,
+        channel_id: usize,
+        client_id: UniqueClientId,
+        offset: PointerOffset,
+    ) -> Result<(), ResponseSendError> {
+        let msg = "Unable to send response";
+        self.retrieve_returned_responses();
+
+        if !self.is_connected(channel_id, client_id) {
+            fail!(from self, with ResponseSendError::ClientDisconnected,
+                "{} since the client {:?} is no longer connected.", msg, client_id);
+        }
+
+        let connection = match self.client_connections.get(channel_id) {
+            Some(connection) => connection,
+            None => {
+                fail!(from self, with ResponseSendError::ClientDisconnected,
+                    "{} since the client {:?} is no longer connected.", msg, client_id);
+            }
+        };
+
+        match connection.response_sender.try_send(offset) {
+            Ok(overflow) => {
+                if let Some(old) = overflow {
+                    self.release_response(old);
+                }
+            }
+            Err(ZeroCopySendError::ReceiveBufferFull) => {
+                fail!(from self, with ResponseSendError::ReceiveBufferFull,
+                    "{} since the buffer of the client {:?} is full.", msg, client_id);
+            }
+            Err(ZeroCopySendError::ClearRetrieveChannelBeforeSend) => {
+                fail!(from self, with ResponseSendError::ConnectionCorrupted,
+                    "{} since the retrieve channel of the client {:?} is full. This can be caused by a corrupted retrieve channel.",
+                    msg, client_id);
+            }
+        }
+
+        // the reference of the loan is handed over to the connection
+        self.loan_counter.set(self.loan_counter.get() - 1);
+        Ok(())
+    }
+}
+
+/// A request that was received with [`Server::receive()`]. It is used to send one or more
+/// responses back to the [`crate::port::client::Client`] that sent it. The memory of the
+/// request is returned to the client when it goes out of scope.
+#[derive(Debug)]
+pub struct ActiveRequest<'server, RequestPayload: Debug, ResponsePayload: Debug> {
+    server: &'server dyn ServerMgmt,
+    channel_id: usize,
+    client_id: UniqueClientId,
+    offset: PointerOffset,
+    ptr: RawSample<RequestHeader, (), RequestPayload>,
+    number_of_responses: Cell<usize>,
+    _phantom_response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<RequestPayload: Debug, ResponsePayload: Debug> Drop
+    for ActiveRequest<'_, RequestPayload, ResponsePayload>
+{
+    fn drop(&mut self) {
+        self.server.release_request(self.channel_id, self.offset);
+    }
+}
+
+impl<RequestPayload: Debug, ResponsePayload: Debug>
+    ActiveRequest<'_, RequestPayload, ResponsePayload>
+{
+    /// Returns a reference to the [`RequestHeader`] of the request
+    pub fn header(&self) -> &RequestHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the payload of the request
+    pub fn payload(&self) -> &RequestPayload {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`crate::port::client::Client`] that sent the
+    /// request
+    pub fn client_id(&self) -> UniqueClientId {
+        self.client_id
+    }
+
+    /// Returns the number of responses that were sent so far for the request
+    pub fn number_of_responses(&self) -> usize {
+        self.number_of_responses.get()
+    }
+
+    /// Returns true as long as the [`crate::port::client::Client`] is connected and still
+    /// waits for responses. When it returns false every further response would be discarded.
+    pub fn is_connected(&self) -> bool {
+        self.header().is_active() && self.server.is_connected(self.channel_id, self.client_id)
+    }
+
+    /// Loans an uninitialized response for the request that can be written and sent with
+    /// [`ResponseMut::send()`].
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<ResponseMut<'_, MaybeUninit<ResponsePayload>>, ResponseLoanError> {
+        let (offset, address) = fail!(from self, when self.server.loan_response(),
+            "Unable to loan a response for the request {}.", self.header().request_id());
+
+        let message = address as *mut Message<ResponseHeader, (), MaybeUninit<ResponsePayload>>;
+        unsafe {
+            core::ptr::addr_of_mut!((*message).header).write(ResponseHeader::new(
+                self.server.server_id(),
+                self.header().request_id(),
+            ))
+        };
+
+        Ok(ResponseMut {
+            server: self.server,
+            request_header: self.header(),
+            channel_id: self.channel_id,
+            client_id: self.client_id,
+            number_of_responses: &self.number_of_responses,
+            offset,
+            ptr: unsafe { RawSampleMut::new_unchecked(message) },
+        })
+    }
+
+    /// Copies the value into a loaned response and sends it to the
+    /// [`crate::port::client::Client`] of the request.
+    pub fn send_copy(&self, value: ResponsePayload) -> Result<(), ResponseSendError> {
+        let response = fail!(from self, when self.loan_uninit(),
+            "Unable to send copy of response since the loan of a response failed.");
+
+        response.write_payload(value).send()
+    }
+}
+
+impl<RequestPayload: Debug, ResponsePayload: Debug> Deref
+    for ActiveRequest<'_, RequestPayload, ResponsePayload>
+{
+    type Target = RequestPayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
+
+/// A response that was loaned with [`ActiveRequest::loan_uninit()`]. Its payload has to be
+/// written before it can be sent with [`ResponseMut::send()`]. When it goes out of scope
+/// without being sent its memory is returned to the [`Server`].
+#[derive(Debug)]
+pub struct ResponseMut<'active, ResponsePayload: Debug> {
+    server: &'active dyn ServerMgmt,
+    request_header: &'active RequestHeader,
+    channel_id: usize,
+    client_id: UniqueClientId,
+    number_of_responses: &'active Cell<usize>,
+    offset: PointerOffset,
+    ptr: RawSampleMut<ResponseHeader, (), ResponsePayload>,
+}
+
+impl<ResponsePayload: Debug> Drop for ResponseMut<'_, ResponsePayload> {
+    fn drop(&mut self) {
+        self.server.return_loaned_response(self.offset);
+    }
+}
+
+impl<ResponsePayload: Debug> ResponseMut<'_, ResponsePayload> {
+    /// Returns a reference to the [`ResponseHeader`] of the response
+    pub fn header(&self) -> &ResponseHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the response
+    pub fn payload_mut(&mut self) -> &mut ResponsePayload {
+        self.ptr.as_data_mut()
+    }
+}
+
+impl<'active, ResponsePayload: Debug> ResponseMut<'active, MaybeUninit<ResponsePayload>> {
+    /// Writes the payload into the response and returns the initialized response.
+    pub fn write_payload(
+        mut self,
+        value: ResponsePayload,
+    ) -> ResponseMut<'active, ResponsePayload> {
+        self.payload_mut().write(value);
+        // SAFETY: the payload was initialized in the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Converts the response into an initialized response.
+    ///
+    /// # Safety
+    ///
+    /// The payload must be initialized with [`ResponseMut::payload_mut()`] beforehand.
+    pub unsafe fn assume_init(self) -> ResponseMut<'active, ResponsePayload> {
+        let response = ResponseMut {
+            server: self.server,
+            request_header: self.request_header,
+            channel_id: self.channel_id,
+            client_id: self.client_id,
+            number_of_responses: self.number_of_responses,
+            offset: self.offset,
+            ptr: RawSampleMut::from_header_and_payload_ptrs(
+                self.ptr.as_headers_mut_ptr(),
+                self.ptr.as_data_mut_ptr() as *mut ResponsePayload,
+            ),
+        };
+        // the ownership of the memory chunk is transferred to the initialized response
+        std::mem::forget(self);
+        response
+    }
+}
+
+impl<ResponsePayload: Debug> ResponseMut<'_, ResponsePayload> {
+    /// Sends the response to the [`crate::port::client::Client`] of the request. Fails when
+    /// the client dropped the corresponding [`crate::port::client::PendingResponse`] or when
+    /// the request already received
+    /// [`crate::service::static_config::request_response::StaticConfig::max_responses_per_request()`]
+    /// responses.
+    pub fn send(self) -> Result<(), ResponseSendError> {
+        let msg = "Unable to send response";
+        if !self.request_header.is_active() {
+            fail!(from self, with ResponseSendError::RequestNoLongerActive,
+                "{} since the client is no longer interested in responses to the request {}.",
+                msg, self.request_header.request_id());
+        }
+
+        let max_responses_per_request = self.server.max_responses_per_request();
+        if self.number_of_responses.get() >= max_responses_per_request {
+            fail!(from self, with ResponseSendError::ExceedsMaxResponsesPerRequest,
+                "{} since the request {} already received the maximum of {} responses.",
+                msg, self.request_header.request_id(), max_responses_per_request);
+        }
+
+        fail!(from self, when self.server.send_response(self.channel_id, self.client_id, self.offset),
+            "{} to the request {}.", msg, self.request_header.request_id());
+
+        self.number_of_responses
+            .set(self.number_of_responses.get() + 1);
+        // the memory chunk is owned by the connection now
+        std::mem::forget(self);
+        Ok(())
+    }
+}
+
+impl<ResponsePayload: Debug> Deref for ResponseMut<'_, ResponsePayload> {
+    type Target = ResponsePayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
+
+impl<ResponsePayload: Debug> DerefMut for ResponseMut<'_, ResponsePayload> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.ptr.as_data_mut()
+    }
+}
@@ -41,20 +41,47 @@
 
 use std::fmt::Debug;
 
+use iceoryx2_bb_elementary::enum_gen;
+
 use crate::sample::Sample;
 
 use crate::port::update_connections::ConnectionFailure;
 
 /// Defines the failure that can occur when receiving data with [`Subscribe::receive()`].
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+#[non_exhaustive]
 pub enum SubscriberReceiveError {
     ExceedsMaxBorrowedSamples,
     ConnectionFailure(ConnectionFailure),
+    CorruptedSample,
+    IncompatibleHeaderVersion,
 }
 
 impl std::fmt::Display for SubscriberReceiveError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+        match self {
+            SubscriberReceiveError::ExceedsMaxBorrowedSamples => std::write!(
+                f,
+                "{}::ExceedsMaxBorrowedSamples: the subscriber already holds the maximum number of samples of a publisher, drop received samples or increase subscriber_max_borrowed_samples",
+                std::stringify!(Self)
+            ),
+            SubscriberReceiveError::ConnectionFailure(e) => std::write!(
+                f,
+                "{}::ConnectionFailure({:?}): no sample was received and a connection to a publisher is broken, see Subscriber::connection_errors()",
+                std::stringify!(Self),
+                e
+            ),
+            SubscriberReceiveError::CorruptedSample => std::write!(
+                f,
+                "{}::CorruptedSample: a received sample describes a payload that exceeds the memory of the publisher or contains an invalid value, like an unknown enum discriminant, the sample was discarded",
+                std::stringify!(Self)
+            ),
+            SubscriberReceiveError::IncompatibleHeaderVersion => std::write!(
+                f,
+                "{}::IncompatibleHeaderVersion: the header of a received sample has another layout version, the publisher uses an incompatible version of iceoryx2, the sample was discarded",
+                std::stringify!(Self)
+            ),
+        }
     }
 }
 
@@ -63,31 +90,175 @@ impl std::error::Error for SubscriberReceiveError {}
 /// Describes the failures when a new [`Subscribe`] is created via the
 /// [`crate::service::port_factory::subscriber::PortFactorySubscriber`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[non_exhaustive]
 pub enum SubscriberCreateError {
+    /// All subscriber slots of the service are occupied. The slot of a dropped subscriber can
+    /// be reused right away, therefore the creation can be retried as soon as another
+    /// subscriber leaves the service.
     ExceedsMaxSupportedSubscribers,
+    UnableToCreateNotificationListener,
+    InvalidPayloadTypeDetails,
+    /// The required minimum buffer size is larger than the buffer of the subscriber, no
+    /// connection could ever be established.
+    RequiredMinBufferSizeExceedsBufferSize,
+    /// An unexpected failure of the underlying resources, a retry is unlikely to succeed.
+    InternalFailure,
 }
 
 impl std::fmt::Display for SubscriberCreateError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+        let hint = match self {
+            SubscriberCreateError::ExceedsMaxSupportedSubscribers => "the service already has the maximum number of subscribers, drop another subscriber or create the service with a larger max_subscribers",
+            SubscriberCreateError::UnableToCreateNotificationListener => "the listener that is woken up by the publishers could not be created, check the permissions of the service directory",
+            SubscriberCreateError::InvalidPayloadTypeDetails => "the payload type details of the service cannot be used for an untyped port",
+            SubscriberCreateError::RequiredMinBufferSizeExceedsBufferSize => "the required min buffer size exceeds the buffer size of the subscriber, lower it or increase the buffer size",
+            SubscriberCreateError::InternalFailure => "an internal failure occurred while the resources of the subscriber were created",
+        };
+        std::write!(f, "{}::{:?}: {}", std::stringify!(Self), self, hint)
     }
 }
 
 impl std::error::Error for SubscriberCreateError {}
 
+enum_gen! {
+    /// Defines the failures that can occur while a [`crate::port::subscriber::Subscriber`]
+    /// waits for a [`crate::sample::Sample`] with
+    /// [`crate::port::subscriber::Subscriber::timed_receive()`] or
+    /// [`crate::port::subscriber::Subscriber::blocking_receive()`].
+    SubscriberWaitError
+  entry:
+    NotificationsNotEnabled,
+    InternalFailure
+  mapping:
+    SubscriberReceiveError to ReceiveError
+}
+
+impl std::fmt::Display for SubscriberWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for SubscriberWaitError {}
+
 pub(crate) mod internal {
     use std::fmt::Debug;
 
+    use iceoryx2_cal::zero_copy_connection::PointerOffset;
+
+    use crate::port::port_identifiers::UniquePublisherId;
+
     pub(crate) trait SubscribeMgmt: Debug {
-        fn release_sample(&self, channel_id: usize, sample: usize);
+        /// Returns the sample to the connection of the publisher with `publisher_id`, which
+        /// is either still connected via `channel_id` or was retired since it was replaced.
+        fn release_sample(
+            &self,
+            channel_id: usize,
+            publisher_id: UniquePublisherId,
+            offset: PointerOffset,
+        );
+        /// Is called when the last clone of a sample is dropped by a thread other than the one
+        /// that received it. The sample is released with the next receive call.
+        fn defer_release_sample(
+            &self,
+            channel_id: usize,
+            publisher_id: UniquePublisherId,
+            offset: PointerOffset,
+        );
+        fn has_single_subscriber(&self) -> bool;
     }
 }
 
 /// The interface of the receiving endpoint of a publish-subscribe communication.
-pub trait Subscribe<MessageType: Debug> {
+pub trait Subscribe<MessageType: Debug + ?Sized, UserHeader: Debug = ()> {
     /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`SubscriberReceiveError`] is returned.
-    fn receive(&self) -> Result<Option<Sample<MessageType>>, SubscriberReceiveError>;
+    /// A [`SubscriberReceiveError::ConnectionFailure`] is only returned when no sample could be
+    /// received from the remaining [`crate::port::publisher::Publisher`]s, the affected
+    /// [`crate::port::publisher::Publisher`]s are available via
+    /// [`crate::port::subscriber::Subscriber::connection_errors()`].
+    ///
+    /// The order in which the [`crate::sample::Sample`]s of multiple
+    /// [`crate::port::publisher::Publisher`]s are received is defined by
+    /// [`crate::service::port_factory::subscriber::ReceiveOrder`].
+    fn receive(&self) -> Result<Option<Sample<MessageType, UserHeader>>, SubscriberReceiveError>;
+
+    /// Receives up to `max` [`crate::sample::Sample`]s from all connected
+    /// [`crate::port::publisher::Publisher`]s in one pass. The connections are updated only once
+    /// and the [`crate::sample::Sample`]s of one [`crate::port::publisher::Publisher`] are
+    /// ordered like they were sent. The [`crate::port::publisher::Publisher`]s are visited in
+    /// the same order as with [`Subscribe::receive()`]. If no sample could be received an empty
+    /// [`Vec`] is returned.
+    ///
+    /// A [`SubscriberReceiveError`] is only returned when not a single
+    /// [`crate::sample::Sample`] was received. When a failure occurs after some samples were
+    /// already received, they are returned and the failure is reported by the next call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let subscriber = service.subscriber().create()?;
+    ///
+    /// for sample in subscriber.receive_batch(16)? {
+    ///     println!("received: {:?}", *sample);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn receive_batch(
+        &self,
+        max: usize,
+    ) -> Result<Vec<Sample<MessageType, UserHeader>>, SubscriberReceiveError>;
+
+    /// Drains the buffers of all connected [`crate::port::publisher::Publisher`]s and returns
+    /// only the newest [`crate::sample::Sample`], all older ones are returned to their
+    /// [`crate::port::publisher::Publisher`]s right away and count as received. Within one
+    /// [`crate::port::publisher::Publisher`] the last sent sample is the newest, across
+    /// multiple [`crate::port::publisher::Publisher`]s the one with the latest
+    /// [`crate::service::header::publish_subscribe::Header::time_stamp()`] wins, independent of
+    /// the [`crate::service::port_factory::subscriber::ReceiveOrder`]. A state like topic with a
+    /// buffer size of 1 and safe overflow delivers the latest value with it.
+    ///
+    /// At most one sample is borrowed while the buffers are drained, therefore it works with a
+    /// subscriber that can borrow only a single sample. The previous newest sample is kept until
+    /// the next one is received, only when the subscriber cannot borrow another sample from the
+    /// same [`crate::port::publisher::Publisher`] it is released first. If the next one is
+    /// rejected the previous newest sample is returned instead of the error. Like [`Subscribe::receive()`] a
+    /// [`SubscriberReceiveError::ConnectionFailure`] is only returned when no sample could be
+    /// received at all.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let subscriber = service.subscriber().create()?;
+    ///
+    /// if let Some(sample) = subscriber.receive_latest()? {
+    ///     println!("latest value: {:?}", *sample);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn receive_latest(
+        &self,
+    ) -> Result<Option<Sample<MessageType, UserHeader>>, SubscriberReceiveError>;
 
     /// Explicitly updates all connections to the [`crate::port::publisher::Publisher`]s. This is
     /// required to be called whenever a new [`crate::port::publisher::Publisher`] connected to
@@ -31,52 +31,295 @@
 //! # }
 //! ```
 
-use std::cell::UnsafeCell;
+use std::cell::{Cell, RefCell, UnsafeCell};
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::atomic::{fence, AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
 use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
-use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_log::{error, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::{ListenerBuilder, ListenerCreateError};
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::{shared_memory::*, zero_copy_connection::*};
 
+use crate::port::event_id::EventId;
 use crate::port::DegrationAction;
-use crate::service::static_config::publish_subscribe::StaticConfig;
+use crate::service::builder::{is_instance_available, open_replacement};
+use crate::service::config_scheme::event_config;
+use crate::service::dynamic_config::publish_subscribe::{
+    DynamicConfig, PublisherDetails, SubscriberCounters, SubscriberStatistics,
+};
+use crate::service::naming_scheme::subscriber_event_concept_name;
+use crate::service::port_factory::subscriber::{LocalSubscriberConfig, ReceiveOrder};
+use crate::service::stale_resources::add_port_to_dynamic_config;
+use crate::service::static_config::publish_subscribe::{StaticConfig, TypeDetails};
+use crate::testing::{abort_port_creation_if_requested, PortCreationStep};
 use crate::{
-    message::Message, raw_sample::RawSample, sample::Sample, service,
+    message::Message,
+    payload_type::PayloadType,
+    raw_sample::RawSample,
+    sample::{Sample, SampleHandle},
+    service,
     service::header::publish_subscribe::Header,
 };
 
+use super::connection_monitor::{
+    announce_connection_change, ConnectionMonitor, ConnectionMonitorCreateError,
+    WaitForConnectionsError,
+};
 use super::details::publisher_connections::{Connection, PublisherConnections};
+use super::details::segment_offset::SegmentOffset;
 use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use super::subscribe::internal::SubscribeMgmt;
-use super::subscribe::{Subscribe, SubscriberCreateError, SubscriberReceiveError};
+use super::subscribe::{
+    Subscribe, SubscriberCreateError, SubscriberReceiveError, SubscriberWaitError,
+};
+use super::tracing_hooks::TracingHooks;
 use super::update_connections::ConnectionFailure;
 use super::DegrationCallback;
 
+type SampleFilterFn<'a, UserHeader> = dyn Fn(&Header, &UserHeader) -> bool + 'a;
+
+/// Decides with the [`Header`] and the user header of a [`Sample`] whether the [`Subscriber`]
+/// receives it. It is set with
+/// [`crate::service::port_factory::subscriber::PortFactorySubscriber::filter()`].
+pub(crate) struct SampleFilter<'a, UserHeader>(Rc<SampleFilterFn<'a, UserHeader>>);
+
+// a derived Clone would require UserHeader: Clone, only the Rc is cloned
+impl<UserHeader> Clone for SampleFilter<'_, UserHeader> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<'a, UserHeader> SampleFilter<'a, UserHeader> {
+    pub(crate) fn new<F: Fn(&Header, &UserHeader) -> bool + 'a>(filter: F) -> Self {
+        Self(Rc::new(filter))
+    }
+
+    fn accepts(&self, header: &Header, user_header: &UserHeader) -> bool {
+        (self.0)(header, user_header)
+    }
+}
+
+impl<UserHeader> Debug for SampleFilter<'_, UserHeader> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SampleFilter")
+    }
+}
+
+/// Reported by [`Subscriber::missed_deadlines()`] when a [`crate::port::publisher::Publisher`]
+/// did not send a [`Sample`] within the deadline of the service, see
+/// [`crate::service::builder::publish_subscribe::Builder::deadline()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeadlineMissed {
+    publisher_id: UniquePublisherId,
+    elapsed: Duration,
+}
+
+impl DeadlineMissed {
+    /// Returns the [`UniquePublisherId`] of the [`crate::port::publisher::Publisher`] that
+    /// missed the deadline.
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_id
+    }
+
+    /// Returns the time that has passed since the last [`Sample`] of the
+    /// [`crate::port::publisher::Publisher`] was sent or, when none was received so far, since
+    /// the connection was established.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+/// The state of the connection from a [`Subscriber`] to a [`crate::port::publisher::Publisher`],
+/// see [`Subscriber::connection_state()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublisherConnectionState {
+    /// The [`crate::port::publisher::Publisher`] is alive and can deliver new [`Sample`]s.
+    Connected,
+    /// The [`crate::port::publisher::Publisher`] is gone. [`Sample`]s it has sent beforehand
+    /// can still be received.
+    Disconnected,
+}
+
+/// The state of the service instance a [`Subscriber`] is attached to, see
+/// [`Subscriber::service_state()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubscriberServiceState {
+    /// The instance is still the one that is registered under the name of the service.
+    Healthy,
+    /// The instance was removed or replaced by a new instance with the same name, no
+    /// [`crate::port::publisher::Publisher`] can connect to the [`Subscriber`] anymore.
+    ServiceGone,
+}
+
+/// A copy of the [`Header`] and the user header of the [`Sample`] that the next
+/// [`Subscribe::receive()`] returns, see [`Subscriber::peek()`].
+#[derive(Debug, Clone, Copy)]
+pub struct HeaderView<UserHeader> {
+    header: Header,
+    user_header: UserHeader,
+}
+
+impl<UserHeader> HeaderView<UserHeader> {
+    /// Returns the [`Header`] of the peeked [`Sample`].
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the user header of the peeked [`Sample`].
+    pub fn user_header(&self) -> &UserHeader {
+        &self.user_header
+    }
+}
+
+/// The outcome of the checks a sample of a publisher has to pass before it is delivered.
+enum Inspection {
+    Deliverable {
+        absolute_address: usize,
+        number_of_elements: usize,
+    },
+    Filtered {
+        absolute_address: usize,
+    },
+}
+
+/// Announces a [`Subscriber`] as waiting for a [`Sample`] as long as it exists so that the
+/// publishers notify it.
+struct WaitingAnnouncement<'a> {
+    dynamic_config: &'a DynamicConfig,
+}
+
+impl<'a> WaitingAnnouncement<'a> {
+    fn new(dynamic_config: &'a DynamicConfig) -> Self {
+        dynamic_config.announce_waiting_subscriber();
+        Self { dynamic_config }
+    }
+}
+
+impl Drop for WaitingAnnouncement<'_> {
+    fn drop(&mut self) {
+        self.dynamic_config.withdraw_waiting_subscriber();
+    }
+}
+
 /// The receiving endpoint of a publish-subscribe communication.
+///
+/// The received [`Sample`]s borrow the [`Subscriber`], it can be dropped only when all of its
+/// samples are dropped. The chunks of leaked samples are returned to the publishers when the
+/// [`Subscriber`] is dropped. Since a leaked sample is a bug of the user, debug builds panic
+/// afterwards with the number of samples that were still outstanding.
 #[derive(Debug)]
-pub struct Subscriber<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> {
-    dynamic_config_guard: Option<UniqueIndex<'a>>,
+pub struct Subscriber<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    MessageType: Debug + ?Sized,
+    UserHeader: Debug = (),
+> {
+    // replaced when the subscriber moves to a new instance of the service, see
+    // reconnect_if_required()
+    dynamic_config_guard: UnsafeCell<Option<UniqueIndex<'a>>>,
     publisher_connections: PublisherConnections<'config, Service>,
-    service: &'a Service,
+    service: Cell<&'a Service>,
     degration_callback: Option<DegrationCallback<'a>>,
+    tracing_hooks: Option<Box<dyn TracingHooks<UserHeader> + 'a>>,
+    // samples whose last clone was dropped by another thread, they are released by the
+    // receiving thread since the connections are not thread-safe
+    deferred_releases: Mutex<Vec<(usize, UniquePublisherId, PointerOffset)>>,
+    has_deferred_releases: AtomicBool,
+    // one handle for every sample the subscriber can borrow, the search for a free handle
+    // starts at the handle after the last acquired one
+    sample_handles: Vec<SampleHandle>,
+    next_sample_handle: Cell<usize>,
+    // the connection of the last peeked sample, the next receive starts with it so that it
+    // returns the peeked sample even when the connections changed in between
+    peeked_channel_id: Cell<Option<usize>>,
+    // the connection the next round robin receive starts with
+    next_channel_id: Cell<usize>,
+    receive_order: ReceiveOrder,
+    // is woken up by the publishers while the subscriber waits for a sample, only available
+    // when the service has notifications enabled
+    listener: Option<<Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener>,
+    filter: Option<SampleFilter<'a, UserHeader>>,
+    // failed connections since the last call of connection_errors(), every failure is
+    // reported only once
+    connection_errors: RefCell<Vec<(UniquePublisherId, ConnectionFailure)>>,
+    // publishers whose failure was reported, the failures of the retried connections are not
+    // reported again until the publisher is connected or gone
+    failed_publishers: RefCell<Vec<UniquePublisherId>>,
 
-    publisher_list_state: UnsafeCell<ContainerState<'a, UniquePublisherId>>,
+    publisher_list_state: UnsafeCell<ContainerState<'a, PublisherDetails>>,
+    // distance from the start of a sample to its payload, it depends on the payload alignment
+    payload_offset: usize,
+    // how many payload elements of the subscriber form one element of the service payload, it
+    // is the size of the payload type for untyped subscribers and 1 otherwise
+    element_size: usize,
+    auto_reconnect: bool,
+    last_service_check: Cell<Instant>,
+    number_of_reconnects: Cell<u64>,
     _phantom_message_type: PhantomData<MessageType>,
+    _phantom_user_header: PhantomData<UserHeader>,
+    // the instance the subscriber moved to, the previous one is released with the next move,
+    // declared last so that it is dropped after the dynamic config guard and the publisher list
+    // state
+    reconnected_service: UnsafeCell<Option<Box<Service>>>,
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
-    Subscriber<'a, 'config, Service, MessageType>
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug,
+    > Subscriber<'a, 'config, Service, MessageType, UserHeader>
 {
+    /// The minimum time between two checks whether the service was replaced by a new instance,
+    /// see
+    /// [`crate::service::port_factory::subscriber::PortFactorySubscriber::auto_reconnect()`].
+    pub const RECONNECT_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
     pub(crate) fn new(
         service: &'a Service,
         static_config: &StaticConfig,
+        config: &LocalSubscriberConfig,
+        filter: Option<SampleFilter<'a, UserHeader>>,
+        element_size: usize,
     ) -> Result<Self, SubscriberCreateError> {
         let msg = "Failed to create Subscriber port";
-        let origin = "Subscriber::new()";
         let port_id = UniqueSubscriberId::new();
+        let origin = format!(
+            "Subscriber::new(service: {}, port id: {})",
+            service.state().static_config.service_name(),
+            port_id
+        );
+
+        let buffer_size = config.effective_buffer_size(static_config);
+        match config.buffer_size {
+            0 => {
+                warn!(from origin,
+                    "Setting the buffer size to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            }
+            n if n != buffer_size => {
+                warn!(from origin,
+                    "The requested buffer size of {} exceeds the maximum supported buffer size of the service. Adjust it to {}.",
+                    n, buffer_size);
+            }
+            _ => (),
+        }
+
+        if config.required_min_buffer_size > buffer_size {
+            fail!(from origin, with SubscriberCreateError::RequiredMinBufferSizeExceedsBufferSize,
+                "{} since the required min buffer size of {} exceeds the buffer size of {}.",
+                msg, config.required_min_buffer_size, buffer_size);
+        }
 
         let publisher_list = &service
             .state()
@@ -85,34 +328,83 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             .publish_subscribe()
             .publishers;
 
+        let listener = match static_config.enable_notifications {
+            false => None,
+            true => {
+                let event_name = subscriber_event_concept_name(&port_id);
+                Some(fail!(from origin,
+                    when <Service::Event as iceoryx2_cal::event::Event<EventId>>::ListenerBuilder::new(&event_name)
+                        .config(&event_config::<Service>(service.state().global_config))
+                        .create(),
+                    map ListenerCreateError::InternalFailure => SubscriberCreateError::InternalFailure,
+                    unmatched SubscriberCreateError::UnableToCreateNotificationListener,
+                    "{} since the underlying event concept \"{}\" could not be created.", msg, event_name))
+            }
+        };
+
+        let number_of_sample_handles =
+            publisher_list.capacity() * static_config.subscriber_max_borrowed_samples;
         let mut new_self = Self {
             publisher_connections: PublisherConnections::new(
                 publisher_list.capacity(),
                 port_id,
+                buffer_size,
+                config.required_min_buffer_size,
                 service.state().global_config,
                 static_config,
             ),
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
-            dynamic_config_guard: None,
-            service,
+            dynamic_config_guard: UnsafeCell::new(None),
+            service: Cell::new(service),
             degration_callback: None,
+            tracing_hooks: None,
+            deferred_releases: Mutex::new(Vec::with_capacity(number_of_sample_handles)),
+            has_deferred_releases: AtomicBool::new(false),
+            sample_handles: (0..number_of_sample_handles)
+                .map(|_| SampleHandle::new())
+                .collect(),
+            next_sample_handle: Cell::new(0),
+            peeked_channel_id: Cell::new(None),
+            next_channel_id: Cell::new(0),
+            receive_order: config.receive_order,
+            listener,
+            filter,
+            connection_errors: RefCell::new(vec![]),
+            failed_publishers: RefCell::new(vec![]),
+            payload_offset: Message::<Header, UserHeader, ()>::payload_offset(
+                static_config.payload_alignment,
+            ),
+            element_size,
+            auto_reconnect: config.auto_reconnect,
+            last_service_check: Cell::new(Instant::now()),
+            number_of_reconnects: Cell::new(0),
             _phantom_message_type: PhantomData,
+            _phantom_user_header: PhantomData,
+            reconnected_service: UnsafeCell::new(None),
         };
 
         if let Err(e) = new_self.populate_publisher_channels() {
             warn!(from new_self, "The new subscriber is unable to connect to every publisher, caused by {:?}.", e);
         }
 
+        abort_port_creation_if_requested(PortCreationStep::ResourcesCreated);
+
         // !MUST! be the last task otherwise a subscriber is added to the dynamic config without
         // the creation of all required channels
-        new_self.dynamic_config_guard = Some(
-            match service
-                .state()
-                .dynamic_storage
-                .get()
-                .publish_subscribe()
-                .add_subscriber_id(port_id)
-            {
+        let details = new_self.publisher_connections.details();
+        *new_self.dynamic_config_guard.get_mut() = Some(
+            match add_port_to_dynamic_config::<Service, _>(
+                service.state().dynamic_storage.get(),
+                service.state().global_config,
+                || {
+                    service
+                        .state()
+                        .dynamic_storage
+                        .get()
+                        .publish_subscribe()
+                        .add_subscriber(details)
+                },
+            ) {
                 Some(unique_index) => unique_index,
                 None => {
                     fail!(from origin, with SubscriberCreateError::ExceedsMaxSupportedSubscribers,
@@ -121,82 +413,1144 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                 }
             },
         );
+        abort_port_creation_if_requested(PortCreationStep::AddedToDynamicConfig);
+
+        announce_connection_change::<Service>(
+            service.state().global_config,
+            service.state().static_config.uuid(),
+        );
 
         Ok(new_self)
     }
 
     fn populate_publisher_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut result = Ok(());
         let mut visited_indices = vec![];
         visited_indices.resize(self.publisher_connections.capacity(), None);
 
         unsafe {
-            (*self.publisher_list_state.get()).for_each(|index, publisher_id| {
-                visited_indices[index as usize] = Some(*publisher_id);
+            (*self.publisher_list_state.get()).for_each(|index, details| {
+                visited_indices[index as usize] = Some(*details);
             })
         };
 
         // update all connections
         for (i, index) in visited_indices.iter().enumerate() {
             match index {
-                Some(publisher_id) => match self.publisher_connections.create(i, *publisher_id) {
-                    Ok(()) => (),
-                    Err(e) => match &self.degration_callback {
-                        None => {
-                            warn!(from self, "Unable to establish connection to new publisher {:?}.", publisher_id)
-                        }
-                        Some(c) => {
-                            match c.call(
-                                self.service.state().static_config.clone(),
-                                *publisher_id,
-                                self.publisher_connections.subscriber_id(),
-                            ) {
-                                DegrationAction::Ignore => (),
-                                DegrationAction::Warn => {
-                                    warn!(from self, "Unable to establish connection to new publisher {:?}.", publisher_id)
-                                }
-                                DegrationAction::Fail => {
-                                    fail!(from self, with e, "Unable to establish connection to new publisher {:?}.", publisher_id);
+                Some(details) => {
+                    let publisher_id = &details.publisher_id;
+                    match self.publisher_connections.create(i, details) {
+                        Ok(()) => self
+                            .failed_publishers
+                            .borrow_mut()
+                            .retain(|id| id != publisher_id),
+                        Err(e) => match &self.degration_callback {
+                            None => {
+                                self.report_connection_error(*publisher_id, e);
+                                warn!(from self, "Unable to establish connection to new publisher {:?}.", publisher_id)
+                            }
+                            Some(c) => {
+                                match c.call(
+                                    self.service().state().static_config.clone(),
+                                    *publisher_id,
+                                    self.publisher_connections.subscriber_id(),
+                                ) {
+                                    DegrationAction::Ignore => (),
+                                    DegrationAction::Warn => {
+                                        self.report_connection_error(*publisher_id, e);
+                                        warn!(from self, "Unable to establish connection to new publisher {:?}.", publisher_id)
+                                    }
+                                    DegrationAction::Fail => {
+                                        // the remaining publishers are still connected
+                                        self.report_connection_error(*publisher_id, e);
+                                        error!(from self, "Unable to establish connection to new publisher {:?}.", publisher_id);
+                                        if result.is_ok() {
+                                            result = Err(e);
+                                        }
+                                    }
                                 }
                             }
+                        },
+                    }
+                }
+                None => self.publisher_connections.disconnect(i),
+            }
+        }
+
+        self.failed_publishers.borrow_mut().retain(|id| {
+            visited_indices
+                .iter()
+                .flatten()
+                .any(|details| details.publisher_id == *id)
+        });
+
+        result
+    }
+
+    fn update_publisher_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe { (*self.publisher_list_state.get()).update() } {
+            fail!(from self, when self.populate_publisher_channels(),
+                "Connections were updated only partially since at least one connection to a publisher failed.");
+        }
+
+        Ok(())
+    }
+
+    /// Moves the subscriber to the instance that replaced its service under the same name when
+    /// auto reconnect is enabled. It replaces the connections, therefore it must never be called
+    /// while a connection is accessed.
+    fn reconnect_if_required(&self) {
+        if !self.auto_reconnect
+            || self.last_service_check.get().elapsed() < Self::RECONNECT_CHECK_INTERVAL
+        {
+            return;
+        }
+        self.last_service_check.set(Instant::now());
+
+        // the connections of the previous instance are replaced, the borrowed samples have to be
+        // returned to them beforehand
+        if self.sample_handles.iter().any(|handle| !handle.is_free()) {
+            return;
+        }
+
+        let service = match open_replacement(self.service()) {
+            Some(service) => Box::new(service),
+            None => return,
+        };
+        // SAFETY: the box is owned by the subscriber and dropped after the guard and the
+        // publisher list state that refer to it
+        let new_service: &'a Service = unsafe { &*(&*service as *const Service) };
+
+        let dynamic_config = new_service.state().dynamic_storage.get();
+        let details = self.publisher_connections.details();
+        let guard = match add_port_to_dynamic_config::<Service, _>(
+            dynamic_config,
+            new_service.state().global_config,
+            || dynamic_config.publish_subscribe().add_subscriber(details),
+        ) {
+            Some(guard) => guard,
+            None => {
+                warn!(from self, "Unable to move to the new instance {} of the service since it would exceed the maximum supported amount of subscribers.",
+                    new_service.state().static_config.instance_uuid());
+                return;
+            }
+        };
+
+        if let Some(ref old_guard) = self.dynamic_config_guard() {
+            self.service()
+                .state()
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .reset_subscriber_counters(old_guard.value());
+        }
+
+        // SAFETY: the subscriber is not Sync and no reference to the guard, the publisher list
+        // state or the previous instance is held while this function runs
+        unsafe {
+            *self.dynamic_config_guard.get() = Some(guard);
+            *self.publisher_list_state.get() =
+                dynamic_config.publish_subscribe().publishers.get_state();
+        }
+        self.service.set(new_service);
+        // SAFETY: nothing refers to the previous instance anymore, the guard and the publisher
+        // list state were replaced and no sample is borrowed
+        drop(unsafe { (*self.reconnected_service.get()).replace(service) });
+
+        self.peeked_channel_id.set(None);
+        self.next_channel_id.set(0);
+        self.number_of_reconnects
+            .set(self.number_of_reconnects.get() + 1);
+
+        // the publishers of the new instance count their sequence numbers from the start, their
+        // connections are new and the loss detection starts with their first sample
+        if let Err(e) = self.populate_publisher_channels() {
+            warn!(from self, "The subscriber moved to the new instance of the service but is unable to connect to every publisher, caused by {:?}.", e);
+        }
+
+        announce_connection_change::<Service>(
+            new_service.state().global_config,
+            new_service.state().static_config.uuid(),
+        );
+    }
+
+    fn receive_sample(
+        &self,
+    ) -> Result<Option<Sample<MessageType, UserHeader>>, SubscriberReceiveError> {
+        // a broken connection does not stop the subscriber from receiving the samples of the
+        // other publishers, it is reported only when none of them has a sample
+        let mut failure = self.update_publisher_connections().err();
+
+        if let Some(sample) = self.receive_next(&mut failure)? {
+            return Ok(Some(sample));
+        }
+
+        match failure {
+            Some(e) => {
+                fail!(from self, with SubscriberReceiveError::ConnectionFailure(e),
+                    "No sample was received and not all connections to publishers could be established.");
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Runs the checks every sample of a publisher has to pass before it is delivered, it
+    /// neither dequeues nor releases the sample.
+    fn inspect_sample(
+        &self,
+        connection: &mut Connection<'config, Service>,
+        relative_addr: PointerOffset,
+    ) -> Result<Inspection, SubscriberReceiveError> {
+        let msg = "Unable to receive another sample";
+        let offset = SegmentOffset::from(relative_addr);
+        let (data_segment_start, data_segment_size) =
+            match connection.data_segment(offset.segment_id()) {
+                Ok(data_segment) => (
+                    data_segment.allocator_data_start_address(),
+                    data_segment.size(),
+                ),
+                Err(e) => {
+                    self.report_connection_error(connection.publisher_id, e.into());
+                    fail!(from self, with SubscriberReceiveError::ConnectionFailure(e.into()),
+                        "{} since the data segment {} of the publisher could not be mapped.",
+                        msg, offset.segment_id());
+                }
+            };
+        let absolute_address = data_segment_start + offset.offset();
+        // the remaining fields of the header are only meaningful when its layout matches
+        let layout_version = unsafe { &*(absolute_address as *const Header) }.layout_version();
+        if layout_version != Header::LAYOUT_VERSION {
+            fail!(from self, with SubscriberReceiveError::IncompatibleHeaderVersion,
+                "{} since the sample of the publisher {:?} has the header layout version {} but version {} is required.",
+                msg, connection.publisher_id, layout_version, Header::LAYOUT_VERSION);
+        }
+
+        // the header contains the actual number of elements of slice payloads, it is written by
+        // another process and must not let the payload exceed the data segment
+        let number_of_elements = match self.number_of_payload_elements(
+            absolute_address,
+            offset.offset(),
+            data_segment_size,
+        ) {
+            Some(number_of_elements) => number_of_elements,
+            None => {
+                fail!(from self, with SubscriberReceiveError::CorruptedSample,
+                    "{} since the sample of the publisher {:?} describes a payload that exceeds its data segment.",
+                    msg, connection.publisher_id);
+            }
+        };
+
+        // the payload was written by another process, a value with an invalid representation,
+        // like an enum with an unknown discriminant, must never be handed out
+        let payload = MessageType::pointer_with_metadata(
+            (absolute_address + self.payload_offset) as *const u8,
+            number_of_elements,
+        );
+        if !unsafe { MessageType::is_valid_representation(payload) } {
+            fail!(from self, with SubscriberReceiveError::CorruptedSample,
+                "{} since the sample of the publisher {:?} contains an invalid payload value.",
+                msg, connection.publisher_id);
+        }
+
+        if let Some(filter) = &self.filter {
+            let message = absolute_address as *const Message<Header, UserHeader, ()>;
+            if !filter.accepts(unsafe { &(*message).header }, unsafe {
+                &(*message).user_header
+            }) {
+                return Ok(Inspection::Filtered { absolute_address });
+            }
+        }
+
+        Ok(Inspection::Deliverable {
+            absolute_address,
+            number_of_elements,
+        })
+    }
+
+    /// Updates the sequence number and deadline tracking of the connection with a sample that
+    /// left its buffer.
+    fn track_sample(connection: &mut Connection<'config, Service>, absolute_address: usize) {
+        let header = unsafe { &*(absolute_address as *const Header) };
+
+        if let Some(last) = connection.last_sequence_number {
+            connection.number_of_lost_samples += header
+                .sequence_number()
+                .wrapping_sub(last)
+                .saturating_sub(1);
+        }
+        connection.last_sequence_number = Some(header.sequence_number());
+
+        // the deadline window restarts with the send time of every sample
+        connection.last_activity =
+            std::cmp::max(connection.last_activity, header.time_stamp().as_duration());
+        connection.is_deadline_miss_reported = false;
+    }
+
+    fn return_sample(
+        &self,
+        connection: &Connection<'config, Service>,
+        relative_addr: PointerOffset,
+        reason: &str,
+    ) {
+        if let Err(e) = connection.receiver.release(relative_addr) {
+            warn!(from self, "Unable to return the {} sample to the publisher ({:?}).", reason, e);
+        }
+        self.notify_waiting_publisher(connection);
+    }
+
+    /// Returns the relative and the absolute address of the next sample of the connection that
+    /// would be delivered without dequeuing it. Samples that are never delivered, since they
+    /// are filtered or invalid, are discarded like [`Subscribe::receive()`] does.
+    fn peek_deliverable_sample(
+        &self,
+        connection: &mut Connection<'config, Service>,
+    ) -> Result<Option<(PointerOffset, usize)>, SubscriberReceiveError> {
+        let msg = "Unable to peek the next sample";
+        loop {
+            let relative_addr = match connection.peek_sample() {
+                Some(relative_addr) => relative_addr,
+                None => return Ok(None),
+            };
+
+            let inspection = self.inspect_sample(connection, relative_addr);
+            if let Ok(Inspection::Deliverable {
+                absolute_address, ..
+            }) = inspection
+            {
+                return Ok(Some((relative_addr, absolute_address)));
+            }
+
+            let discarded_addr = match connection.receive_sample() {
+                Ok(Some(discarded_addr)) => discarded_addr,
+                // an overflowing publisher removed the sample in the meantime
+                Ok(None) => return Ok(None),
+                Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                    fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
+                        "{} since discarding the sample would exceed the maximum {} of borrowed samples.",
+                        msg, connection.receiver.max_borrowed_samples());
+                }
+            };
+
+            // an overflowing publisher replaced the inspected sample in the meantime, the newer
+            // one was dequeued instead and is inspected in the next iteration
+            if discarded_addr != relative_addr {
+                connection.keep_dequeued_sample(discarded_addr);
+                continue;
+            }
+
+            match inspection {
+                Ok(Inspection::Filtered { absolute_address }) => {
+                    Self::track_sample(connection, absolute_address);
+                    if let Some(counters) = self.counters() {
+                        counters.received_samples.fetch_add(1, Ordering::Relaxed);
+                    }
+                    self.return_sample(connection, discarded_addr, "filtered");
+                }
+                Ok(Inspection::Deliverable { .. }) => {
+                    fatal_panic!(from self,
+                        "This should never happen! A deliverable sample was discarded while it was peeked.");
+                }
+                Err(e) => {
+                    self.return_sample(connection, discarded_addr, "rejected");
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Dequeues the next sample of the connection and returns it to the publisher right away
+    /// without borrowing it. Like a filtered sample it counts as received.
+    fn discard_next_sample(
+        &self,
+        connection: &mut Connection<'config, Service>,
+    ) -> Result<(), SubscriberReceiveError> {
+        let relative_addr = match connection.receive_sample() {
+            Ok(Some(relative_addr)) => relative_addr,
+            Ok(None) => return Ok(()),
+            Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
+                    "Unable to discard the outdated sample since it would exceed the maximum {} of borrowed samples.",
+                    connection.receiver.max_borrowed_samples());
+            }
+        };
+
+        match self.inspect_sample(connection, relative_addr) {
+            Ok(Inspection::Deliverable {
+                absolute_address, ..
+            })
+            | Ok(Inspection::Filtered { absolute_address }) => {
+                Self::track_sample(connection, absolute_address);
+                if let Some(counters) = self.counters() {
+                    counters.received_samples.fetch_add(1, Ordering::Relaxed);
+                }
+                self.return_sample(connection, relative_addr, "outdated");
+                Ok(())
+            }
+            Err(e) => {
+                self.return_sample(connection, relative_addr, "rejected");
+                Err(e)
+            }
+        }
+    }
+
+    /// Returns the id of the connection whose next deliverable sample has the oldest time
+    /// stamp.
+    fn oldest_connection(
+        &self,
+        failure: &mut Option<ConnectionFailure>,
+    ) -> Result<Option<usize>, SubscriberReceiveError> {
+        let mut oldest: Option<(usize, Duration)> = None;
+        for id in 0..self.publisher_connections.len() {
+            if let Some(connection) = &mut self.publisher_connections.get_mut(id) {
+                match self.peek_deliverable_sample(connection) {
+                    Ok(Some((_, absolute_address))) => {
+                        let time_stamp = unsafe { &*(absolute_address as *const Header) }
+                            .time_stamp()
+                            .as_duration();
+                        if oldest.map_or(true, |(_, oldest)| time_stamp < oldest) {
+                            oldest = Some((id, time_stamp));
                         }
-                    },
+                    }
+                    Ok(None) => (),
+                    Err(SubscriberReceiveError::ConnectionFailure(e)) => {
+                        failure.get_or_insert(e);
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        Ok(oldest.map(|(id, _)| id))
+    }
+
+    /// Returns the ids of the connections in the order they are visited by the next receive
+    /// or peek, it depends on the [`ReceiveOrder`] of the subscriber.
+    fn connection_order(
+        &self,
+        first: Option<usize>,
+        failure: &mut Option<ConnectionFailure>,
+    ) -> Result<impl Iterator<Item = usize>, SubscriberReceiveError> {
+        let oldest = match self.receive_order {
+            ReceiveOrder::RoundRobin => None,
+            ReceiveOrder::ByTimestamp => self.oldest_connection(failure)?,
+        };
+
+        let number_of_connections = self.publisher_connections.len();
+        let start = self.next_channel_id.get();
+        Ok(first
+            .into_iter()
+            .chain(oldest)
+            .chain(start..number_of_connections)
+            .chain(0..start))
+    }
+
+    fn receive_next(
+        &self,
+        failure: &mut Option<ConnectionFailure>,
+    ) -> Result<Option<Sample<MessageType, UserHeader>>, SubscriberReceiveError> {
+        let peeked_channel_id = self.peeked_channel_id.take();
+        for id in self.connection_order(peeked_channel_id, failure)? {
+            match &mut self.publisher_connections.get_mut(id) {
+                Some(ref mut connection) => match self.receive_from_connection(id, connection) {
+                    Ok(Some(sample)) => {
+                        self.next_channel_id
+                            .set((id + 1) % self.publisher_connections.len());
+                        return Ok(Some(sample));
+                    }
+                    Ok(None) => (),
+                    Err(SubscriberReceiveError::ConnectionFailure(e)) => {
+                        failure.get_or_insert(e);
+                    }
+                    Err(e) => return Err(e),
                 },
-                None => self.publisher_connections.remove(i),
+                None => (),
             }
         }
 
-        Ok(())
+        Ok(None)
     }
 
     fn receive_from_connection<'subscriber>(
         &'subscriber self,
         channel_id: usize,
         connection: &mut Connection<'config, Service>,
-    ) -> Result<Option<Sample<'subscriber, MessageType>>, SubscriberReceiveError> {
+    ) -> Result<Option<Sample<'subscriber, MessageType, UserHeader>>, SubscriberReceiveError> {
         let msg = "Unable to receive another sample";
-        match connection.receiver.receive() {
-            Ok(data) => match data {
-                None => Ok(None),
-                Some(relative_addr) => {
-                    let absolute_address = relative_addr.value()
-                        + connection.data_segment.allocator_data_start_address();
-                    Ok(Some(Sample {
-                        subscriber: self,
-                        channel_id,
-                        ptr: unsafe {
-                            RawSample::new_unchecked(
-                                absolute_address as *mut Message<Header, MessageType>,
-                            )
-                        },
-                    }))
+        // filtered samples are released and skipped until a deliverable one is found, the loop
+        // terminates since the buffer of the connection is bounded
+        let (relative_addr, absolute_address, number_of_elements) = loop {
+            let relative_addr = match connection.receive_sample() {
+                Ok(None) => return Ok(None),
+                Ok(Some(relative_addr)) => relative_addr,
+                Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                    fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
+                        "{} since it would exceed the maximum {} of borrowed samples.",
+                        msg, connection.receiver.max_borrowed_samples());
                 }
-            },
-            Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+            };
+
+            // a publisher that flushes its samples waits until the buffer is drained
+            if !connection.has_samples() {
+                self.notify_waiting_publisher(connection);
+            }
+
+            match self.inspect_sample(connection, relative_addr) {
+                Ok(Inspection::Deliverable {
+                    absolute_address,
+                    number_of_elements,
+                }) => break (relative_addr, absolute_address, number_of_elements),
+                Ok(Inspection::Filtered { absolute_address }) => {
+                    Self::track_sample(connection, absolute_address);
+                    // the sample left the buffer, therefore it counts as received
+                    if let Some(counters) = self.counters() {
+                        counters.received_samples.fetch_add(1, Ordering::Relaxed);
+                    }
+                    self.return_sample(connection, relative_addr, "filtered");
+                }
+                Err(e) => {
+                    // the publisher has to be able to reclaim the sample
+                    self.return_sample(connection, relative_addr, "rejected");
+                    return Err(e);
+                }
+            }
+        };
+        Self::track_sample(connection, absolute_address);
+
+        let payload = MessageType::pointer_with_metadata(
+            (absolute_address + self.payload_offset) as *const u8,
+            number_of_elements,
+        );
+
+        let publisher_id = connection.publisher_id;
+        let handle = match self.acquire_sample_handle(channel_id, publisher_id, relative_addr) {
+            Some(handle) => handle,
+            None => {
+                self.return_sample(connection, relative_addr, "borrowed");
                 fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
-                    "{} since it would exceed the maximum {} of borrowed samples.",
-                    msg, connection.receiver.max_borrowed_samples());
+                    "{} since all {} samples the subscriber can borrow are in use.",
+                    msg, self.sample_handles.len());
+            }
+        };
+
+        connection.borrowed_samples += 1;
+        if let Some(counters) = self.counters() {
+            counters.received_samples.fetch_add(1, Ordering::Relaxed);
+            counters.borrowed_samples.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let sample = Sample {
+            subscriber: self,
+            handle,
+            tracing_hooks: self.tracing_hooks.as_deref(),
+            ptr: unsafe {
+                RawSample::from_header_and_payload_ptrs(
+                    absolute_address as *const Message<Header, UserHeader, ()>,
+                    payload,
+                )
+            },
+        };
+        if let Some(hooks) = &self.tracing_hooks {
+            hooks.on_receive(sample.header(), sample.user_header());
+        }
+
+        Ok(Some(sample))
+    }
+
+    /// Returns the number of elements of the subscriber payload type in the sample at
+    /// `absolute_address` or [`None`] when the sample, which starts `offset` bytes into a data
+    /// segment of `data_segment_size` bytes, does not fit into it.
+    fn number_of_payload_elements(
+        &self,
+        absolute_address: usize,
+        offset: usize,
+        data_segment_size: usize,
+    ) -> Option<usize> {
+        if offset.checked_add(self.payload_offset)? > data_segment_size {
+            return None;
+        }
+
+        let header = unsafe { &*(absolute_address as *const Header) };
+        let number_of_elements = usize::try_from(header.number_of_elements())
+            .ok()?
+            .checked_mul(self.element_size)?;
+        let sample_size = MessageType::payload_size(number_of_elements)?
+            .checked_add(offset + self.payload_offset)?;
+
+        (sample_size <= data_segment_size).then_some(number_of_elements)
+    }
+
+    /// Returns how many [`Sample`]s of the [`crate::port::publisher::Publisher`] with the
+    /// provided [`UniquePublisherId`] were lost, for instance since they were overridden in a
+    /// full buffer of a safely overflowing service. It is derived from the gaps between the
+    /// [`Header::sequence_number()`]s of consecutively received [`Sample`]s, therefore
+    /// samples that were sent before the first one was received are not counted.
+    /// Returns [`None`] when the [`Subscriber`] is not connected to the
+    /// [`crate::port::publisher::Publisher`].
+    pub fn number_of_lost_samples(&self, publisher_id: UniquePublisherId) -> Option<u64> {
+        (0..self.publisher_connections.len()).find_map(|id| {
+            match self.publisher_connections.get(id) {
+                Some(connection) if connection.publisher_id == publisher_id => {
+                    Some(connection.number_of_lost_samples)
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns true when at least one [`Sample`] can be received with [`Subscribe::receive()`]
+    /// without dequeuing it. Like [`Subscribe::receive()`] it updates the connections first so
+    /// that the [`Sample`]s of a newly connected [`crate::port::publisher::Publisher`] are
+    /// considered.
+    pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+            "Unable to check for samples since the connections could not be updated.");
+
+        Ok((0..self.publisher_connections.len()).any(|id| {
+            matches!(self.publisher_connections.get(id), Some(connection) if connection.has_samples())
+        }))
+    }
+
+    /// Receives a [`Sample`] like [`Subscribe::receive()`] but when none is available it sleeps
+    /// until a [`crate::port::publisher::Publisher`] sends one or the timeout has passed.
+    /// Returns [`None`] on timeout and when no [`crate::port::publisher::Publisher`] is
+    /// connected to the service anymore. Requires a service with notifications, see
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notifications()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .enable_notifications(true)
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let subscriber = service.subscriber().create()?;
+    /// let publisher = service.publisher().create()?;
+    /// publisher.send_copy(1234)?;
+    ///
+    /// if let Some(sample) = subscriber.timed_receive(Duration::from_millis(100))? {
+    ///     println!("received: {:?}", *sample);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn timed_receive(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<MessageType, UserHeader>>, SubscriberWaitError> {
+        self.wait_for_sample(Some(Instant::now() + timeout))
+    }
+
+    /// Receives a [`Sample`] like [`Subscribe::receive()`] but when none is available it sleeps
+    /// until a [`crate::port::publisher::Publisher`] sends one. Returns [`None`] when no
+    /// [`crate::port::publisher::Publisher`] is connected to the service anymore, so that the
+    /// caller does not block forever. Requires a service with notifications, see
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notifications()`].
+    pub fn blocking_receive(
+        &self,
+    ) -> Result<Option<Sample<MessageType, UserHeader>>, SubscriberWaitError> {
+        self.wait_for_sample(None)
+    }
+
+    fn wait_for_sample(
+        &self,
+        deadline: Option<Instant>,
+    ) -> Result<Option<Sample<MessageType, UserHeader>>, SubscriberWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let msg = "Unable to wait for a sample";
+
+        let listener = match self.listener {
+            Some(ref listener) => listener,
+            None => {
+                fail!(from self, with SubscriberWaitError::NotificationsNotEnabled,
+                    "{} since the service does not have notifications enabled.", msg);
+            }
+        };
+        loop {
+            self.release_deferred_samples();
+            // the publishers of the current instance have to see the announcement, therefore the
+            // dynamic config is acquired after a possible move to a new instance
+            self.reconnect_if_required();
+            let dynamic_config = self
+                .service()
+                .state()
+                .dynamic_storage
+                .get()
+                .publish_subscribe();
+
+            // the subscriber is announced before it checks for a sample the last time, a
+            // publisher that delivers a sample afterwards sees the announcement and wakes it up
+            let _announcement = WaitingAnnouncement::new(dynamic_config);
+
+            if let Some(sample) = self.receive_sample()? {
+                return Ok(Some(sample));
+            }
+
+            if dynamic_config.number_of_publishers() == 0 {
+                return Ok(None);
+            }
+
+            let wait_result = match deadline {
+                None => listener.blocking_wait(),
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(timeout) if !timeout.is_zero() => listener.timed_wait(timeout),
+                    _ => return Ok(None),
+                },
+            };
+
+            if let Err(e) = wait_result {
+                fail!(from self, with SubscriberWaitError::InternalFailure,
+                    "{} since the underlying listener failed ({:?}).", msg, e);
+            }
+
+            // every publisher notifies on its own, the samples of all of them are received by
+            // the next iteration, spurious wake ups end up there as well
+            while let Ok(Some(_)) = listener.try_wait() {}
+        }
+    }
+
+    /// Receives a [`Sample`] like [`Subscriber::blocking_receive()`] without blocking the
+    /// thread, the task is suspended until a [`crate::port::publisher::Publisher`] sends a
+    /// [`Sample`]. Returns [`None`] when no [`crate::port::publisher::Publisher`] is connected
+    /// to the service anymore. Requires the `async` feature, a running tokio runtime and a
+    /// service with notifications, see
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notifications()`]. On
+    /// unix the file descriptor of the notification listener is registered in the reactor of
+    /// the runtime, on all other platforms the [`Subscriber`] is checked every millisecond.
+    ///
+    /// The future is cancellation safe. A [`Sample`] is only taken from the
+    /// [`crate::port::publisher::Publisher`] when the future completes, when it is dropped while
+    /// it waits, no [`Sample`] is lost.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+    /// # runtime.block_on(async {
+    /// # let service_name = ServiceName::new("My/Funk/AsyncServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .enable_notifications(true)
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let subscriber = service.subscriber().create()?;
+    /// let publisher = service.publisher().create()?;
+    /// publisher.send_copy(1234)?;
+    ///
+    /// if let Some(sample) = subscriber.receive_async().await? {
+    ///     println!("received: {:?}", *sample);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn receive_async(
+        &self,
+    ) -> Result<Option<Sample<MessageType, UserHeader>>, SubscriberWaitError>
+    where
+        <Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener:
+            iceoryx2_bb_posix::file_descriptor::FileDescriptorBased,
+    {
+        use iceoryx2_cal::event::Listener;
+        let msg = "Unable to wait asynchronously for a sample";
+
+        let listener = match self.listener {
+            Some(ref listener) => listener,
+            None => {
+                fail!(from self, with SubscriberWaitError::NotificationsNotEnabled,
+                    "{} since the service does not have notifications enabled.", msg);
+            }
+        };
+        loop {
+            self.release_deferred_samples();
+            self.reconnect_if_required();
+            let dynamic_config = self
+                .service()
+                .state()
+                .dynamic_storage
+                .get()
+                .publish_subscribe();
+
+            // see wait_for_sample(), the announcement is withdrawn when the future is dropped
+            let _announcement = WaitingAnnouncement::new(dynamic_config);
+
+            if let Some(sample) = self.receive_sample()? {
+                return Ok(Some(sample));
+            }
+
+            if dynamic_config.number_of_publishers() == 0 {
+                return Ok(None);
+            }
+
+            if let Err(e) = crate::port::details::readiness::readable(listener).await {
+                fail!(from self, with SubscriberWaitError::InternalFailure,
+                    "{} since the file descriptor of the listener could not be registered in the async runtime ({:?}).", msg, e);
+            }
+
+            // the wake ups only trigger the next receive, they do not carry any samples
+            while let Ok(Some(_)) = listener.try_wait() {}
+        }
+    }
+
+    /// Returns the [`SubscriberStatistics`] of the [`Subscriber`]. The same values are available
+    /// to other processes via
+    /// [`crate::service::dynamic_config::publish_subscribe::DynamicConfig::subscriber_statistics()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let subscriber = service.subscriber().create()?;
+    ///
+    /// // shed load when the subscriber falls behind
+    /// if subscriber.statistics().pending_samples() > 100 {
+    ///     while let Some(_sample) = subscriber.receive()? {}
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn statistics(&self) -> SubscriberStatistics {
+        match self.dynamic_config_guard() {
+            Some(ref guard) => self
+                .service()
+                .state()
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .subscriber_statistics_of(guard.value()),
+            None => SubscriberStatistics::default(),
+        }
+    }
+
+    /// Returns a [`DeadlineMissed`] for every connected [`crate::port::publisher::Publisher`]
+    /// that did not send a [`Sample`] within the deadline of the service. The deadline window
+    /// starts when the [`Subscriber`] connects to the [`crate::port::publisher::Publisher`] and
+    /// restarts with every [`Sample`] received from it, calls of [`Subscribe::receive()`] that
+    /// do not return a [`Sample`] have no effect. A missed deadline is reported only once until
+    /// the next [`Sample`] of that [`crate::port::publisher::Publisher`] is received.
+    ///
+    /// Without a deadline in the static config
+    /// ([`crate::service::static_config::publish_subscribe::StaticConfig::deadline()`]) the
+    /// list is always empty.
+    pub fn missed_deadlines(&self) -> Result<Vec<DeadlineMissed>, ConnectionFailure> {
+        let deadline = match self
+            .service()
+            .state()
+            .static_config
+            .publish_subscribe()
+            .deadline
+        {
+            Some(deadline) => deadline,
+            None => return Ok(vec![]),
+        };
+
+        fail!(from self, when self.update_connections(),
+            "Unable to check for missed deadlines since the connections could not be updated.");
+
+        let clock_type = self
+            .service()
+            .state()
+            .static_config
+            .publish_subscribe()
+            .clock_type;
+        let now = fatal_panic!(from self, when Time::now_with_clock(clock_type.into()),
+            "Unable to check for missed deadlines since the current time could not be acquired.")
+        .as_duration();
+
+        let mut missed_deadlines = vec![];
+        for id in 0..self.publisher_connections.len() {
+            if let Some(connection) = self.publisher_connections.get_mut(id) {
+                if connection.is_publisher_disconnected {
+                    continue;
+                }
+
+                let elapsed = now.saturating_sub(connection.last_activity);
+                if deadline < elapsed && !connection.is_deadline_miss_reported {
+                    connection.is_deadline_miss_reported = true;
+                    missed_deadlines.push(DeadlineMissed {
+                        publisher_id: connection.publisher_id,
+                        elapsed,
+                    });
+                }
             }
         }
+
+        Ok(missed_deadlines)
+    }
+
+    /// Returns the [`PublisherConnectionState`] of the [`crate::port::publisher::Publisher`]
+    /// with the provided [`UniquePublisherId`] or [`None`] when the [`Subscriber`] is not aware
+    /// of it. When a [`crate::port::publisher::Publisher`] goes out of scope it is
+    /// reported as [`PublisherConnectionState::Disconnected`] right away, the [`Sample`]s it
+    /// has sent beforehand remain in the buffer and can still be received. This allows to
+    /// distinguish a quiet [`crate::port::publisher::Publisher`] from one that is gone.
+    ///
+    /// # Notes
+    ///
+    /// A [`crate::port::publisher::Publisher`] of a crashed process stays
+    /// [`PublisherConnectionState::Connected`] until its resources are removed from the
+    /// service, since the [`Subscriber`] cannot observe the death of another process on its
+    /// own.
+    pub fn connection_state(
+        &self,
+        publisher_id: UniquePublisherId,
+    ) -> Result<Option<PublisherConnectionState>, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+            "Unable to acquire the connection state of publisher {:?} since the connections could not be updated.", publisher_id);
+
+        Ok((0..self.publisher_connections.len()).find_map(|id| {
+            match self.publisher_connections.get(id) {
+                Some(connection) if connection.publisher_id == publisher_id => {
+                    match connection.is_publisher_disconnected {
+                        true => Some(PublisherConnectionState::Disconnected),
+                        false => Some(PublisherConnectionState::Connected),
+                    }
+                }
+                _ => None,
+            }
+        }))
+    }
+
+    /// Returns the buffer size of the [`Subscriber`]. It defines how many
+    /// [`crate::sample::Sample`]s of every connected [`crate::port::publisher::Publisher`] can
+    /// be held in the buffer before they are received.
+    pub fn buffer_size(&self) -> usize {
+        self.publisher_connections.buffer_size()
+    }
+
+    /// Returns the smallest buffer size the [`Subscriber`] accepts for the connection to a
+    /// [`crate::port::publisher::Publisher`], see
+    /// [`crate::service::port_factory::subscriber::PortFactorySubscriber::required_min_buffer_size()`].
+    pub fn required_min_buffer_size(&self) -> usize {
+        self.publisher_connections.required_min_buffer_size()
+    }
+
+    /// Returns the buffer size of the connection to the [`crate::port::publisher::Publisher`]
+    /// with the provided id, the smaller of [`Subscriber::buffer_size()`] and the
+    /// [`crate::port::publisher::Publisher::subscriber_max_buffer_size()`]. Returns [`None`]
+    /// when no connection to the publisher is established, for instance since its buffer is
+    /// smaller than [`Subscriber::required_min_buffer_size()`], see
+    /// [`Subscriber::connection_errors()`].
+    pub fn effective_buffer_size(&self, publisher_id: UniquePublisherId) -> Option<usize> {
+        (0..self.publisher_connections.len()).find_map(|id| {
+            match self.publisher_connections.get(id) {
+                Some(connection) if connection.publisher_id == publisher_id => {
+                    Some(connection.receiver.buffer_size())
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns how many [`crate::sample::Sample`]s of a single
+    /// [`crate::port::publisher::Publisher`] can be held at the same time. When the limit is
+    /// reached [`Subscribe::receive()`] fails with
+    /// [`SubscriberReceiveError::ExceedsMaxBorrowedSamples`] until a [`Sample`] of that
+    /// [`crate::port::publisher::Publisher`] is dropped.
+    pub fn max_borrowed_samples(&self) -> usize {
+        self.service()
+            .state()
+            .static_config
+            .publish_subscribe()
+            .subscriber_max_borrowed_samples
+    }
+
+    /// Returns the connection failures that occurred since the last call together with the
+    /// [`UniquePublisherId`] of the affected [`crate::port::publisher::Publisher`] and clears
+    /// them. Every failure is returned only once, the failing connection to a
+    /// [`crate::port::publisher::Publisher`] is retried whenever the connections are updated but
+    /// it is reported again only when it was established in the meantime. The failures occur
+    /// while the connections are updated, for instance in [`Subscribe::receive()`], and do not
+    /// affect the connections to the other [`crate::port::publisher::Publisher`]s. Failures the
+    /// [`DegrationCallback`] decided to ignore with [`DegrationAction::Ignore`] are not reported.
+    pub fn connection_errors(&self) -> Vec<(UniquePublisherId, ConnectionFailure)> {
+        std::mem::take(&mut *self.connection_errors.borrow_mut())
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the [`Subscriber`]
+    pub fn id(&self) -> UniqueSubscriberId {
+        self.publisher_connections.subscriber_id()
+    }
+
+    /// Returns the instance uuid of the service of the [`Subscriber`], see
+    /// [`crate::service::static_config::StaticConfig::instance_uuid()`]. It changes when the
+    /// [`Subscriber`] moves to a new instance of the service, see
+    /// [`crate::service::port_factory::subscriber::PortFactorySubscriber::auto_reconnect()`].
+    /// It is returned by value since the previous instance is released when the [`Subscriber`]
+    /// moves.
+    pub fn service_instance_uuid(&self) -> String {
+        self.service()
+            .state()
+            .static_config
+            .instance_uuid()
+            .to_string()
+    }
+
+    /// Returns [`SubscriberServiceState::ServiceGone`] when the instance of the service the
+    /// [`Subscriber`] is attached to was removed or replaced by a new instance with the same
+    /// name, for instance since the process that created it was restarted. Such a
+    /// [`Subscriber`] does not receive any new [`Sample`]s unless it was created with
+    /// [`crate::service::port_factory::subscriber::PortFactorySubscriber::auto_reconnect()`],
+    /// then it moves to the new instance the next time it updates its connections.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::subscriber::SubscriberServiceState;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let subscriber = service.subscriber().create()?;
+    ///
+    /// if subscriber.service_state() == SubscriberServiceState::ServiceGone {
+    ///     println!("the service was restarted, the subscriber has to be recreated");
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn service_state(&self) -> SubscriberServiceState {
+        match is_instance_available(self.service()) {
+            true => SubscriberServiceState::Healthy,
+            false => SubscriberServiceState::ServiceGone,
+        }
+    }
+
+    /// Returns how often the [`Subscriber`] moved to a new instance of the service, see
+    /// [`crate::service::port_factory::subscriber::PortFactorySubscriber::auto_reconnect()`].
+    /// A changed value signals the transition, the [`crate::port::publisher::Publisher`]s of
+    /// the new instance start their [`Header::sequence_number()`]s from the beginning and
+    /// [`Subscriber::number_of_lost_samples()`] counts only the gaps within the samples of them.
+    pub fn number_of_reconnects(&self) -> u64 {
+        self.number_of_reconnects.get()
+    }
+
+    /// Returns how many [`crate::port::publisher::Publisher`]s, of this and of other
+    /// processes, are attached to the service. It reads the dynamic config of the service
+    /// and does not depend on the connections of the [`Subscriber`], publishers of processes
+    /// that died are not counted. The liveness of the process of every attached
+    /// [`crate::port::publisher::Publisher`] is checked with a system call, a frequent caller
+    /// shall prefer a [`Subscriber::connection_monitor()`] which reports the changes instead.
+    pub fn number_of_connected_publishers(&self) -> usize {
+        self.service()
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .number_of_live_publishers()
+    }
+
+    /// Returns true when at least one [`crate::port::publisher::Publisher`] is attached to
+    /// the service, see [`Subscriber::number_of_connected_publishers()`].
+    pub fn has_publishers(&self) -> bool {
+        self.number_of_connected_publishers() > 0
+    }
+
+    /// Creates a [`ConnectionMonitor`] that reports when the number of
+    /// [`Subscriber::number_of_connected_publishers()`] transitions between zero and non-zero,
+    /// see [`crate::port::connection_monitor`]. It is woken up by the publishers of every
+    /// instance of the service with the same name, therefore it follows the [`Subscriber`]
+    /// when it moves to a new instance.
+    pub fn connection_monitor(
+        &self,
+    ) -> Result<ConnectionMonitor<'_, 'config, Service>, ConnectionMonitorCreateError> {
+        ConnectionMonitor::new(
+            self.service().state().global_config,
+            self.service().state().static_config.uuid(),
+            move || self.number_of_connected_publishers(),
+        )
+    }
+
+    /// Blocks until at least `min` [`crate::port::publisher::Publisher`]s are attached to the
+    /// service, see [`Subscriber::number_of_connected_publishers()`], and returns their number.
+    /// It returns immediately when the condition already holds. The wait is woken up by every
+    /// port that is added to or removed from the service instead of polling the service.
+    ///
+    /// Returns [`WaitForConnectionsError::Timeout`] when the number was not reached within the
+    /// `timeout` and [`WaitForConnectionsError::CounterpartDied`] when the process of an
+    /// attached [`crate::port::publisher::Publisher`] dies while waiting, so that the caller
+    /// does not wait for a counterpart that will never come back. A dead process cannot wake
+    /// the wait up, its death is recognized with the next wake up by another port or by
+    /// [`crate::service::Details::remove_stale_resources()`], at the latest after the `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service = zero_copy::Service::new(&ServiceName::new("My/Funk/ServiceName")?)
+    ///     .publish_subscribe()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let subscriber = service.subscriber().create()?;
+    /// # let _publisher = service.publisher().create()?;
+    ///
+    /// // the startup continues when the data source is attached
+    /// subscriber.wait_for_publishers(1, Duration::from_secs(5))?;
+    /// while let Some(sample) = subscriber.receive()? {
+    ///     println!("received: {:?}", *sample);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_publishers(
+        &self,
+        min: usize,
+        timeout: Duration,
+    ) -> Result<usize, WaitForConnectionsError> {
+        let number_of_connections = self.number_of_connected_publishers();
+        if number_of_connections >= min {
+            return Ok(number_of_connections);
+        }
+
+        let mut monitor = fail!(from self, when self.connection_monitor(),
+            with WaitForConnectionsError::InternalFailure,
+            "Unable to wait for {} publishers since the connection monitor could not be created.", min);
+
+        monitor.wait_for_connections(min, timeout, || {
+            self.service()
+                .state()
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .publisher_system_ids()
+        })
+    }
+
+    /// Returns the [`TypeDetails`] of the payload type of the service. Untyped subscribers can
+    /// use them to interpret the bytes they receive.
+    pub fn payload_type_details(&self) -> &TypeDetails {
+        // the instance of the service can be replaced, the copy of the static config cannot
+        self.publisher_connections
+            .static_config()
+            .payload_type_details()
     }
 
     /// Sets the [`DegrationCallback`] of the [`Subscriber`]. Whenever a connection to a
@@ -218,60 +1572,504 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             None => self.degration_callback = None,
         }
     }
+
+    /// Sets the [`TracingHooks`] of the [`Subscriber`] that are called whenever a
+    /// [`Sample`] is received or its last clone is dropped. [`None`] removes them.
+    pub fn set_tracing_hooks<H: TracingHooks<UserHeader> + 'a>(&mut self, hooks: Option<H>) {
+        self.tracing_hooks =
+            hooks.map(|hooks| Box::new(hooks) as Box<dyn TracingHooks<UserHeader> + 'a>);
+    }
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> Subscribe<MessageType>
-    for Subscriber<'a, 'config, Service, MessageType>
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ?Sized,
+        UserHeader: Debug,
+    > Subscriber<'a, 'config, Service, MessageType, UserHeader>
 {
-    fn receive(&self) -> Result<Option<Sample<MessageType>>, SubscriberReceiveError> {
-        if let Err(e) = self.update_connections() {
-            fail!(from self,
-                with SubscriberReceiveError::ConnectionFailure(e),
-                "Some samples are not being received since not all connections to publishers could be established.");
+    fn report_connection_error(&self, publisher_id: UniquePublisherId, failure: ConnectionFailure) {
+        let mut failed_publishers = self.failed_publishers.borrow_mut();
+        if failed_publishers.contains(&publisher_id) {
+            return;
         }
+        failed_publishers.push(publisher_id);
 
-        for id in 0..self.publisher_connections.len() {
-            match &mut self.publisher_connections.get_mut(id) {
-                Some(ref mut connection) => {
-                    if let Some(sample) = self.receive_from_connection(id, connection)? {
-                        return Ok(Some(sample));
+        self.connection_errors
+            .borrow_mut()
+            .push((publisher_id, failure));
+    }
+
+    fn lock_deferred_releases(
+        &self,
+    ) -> std::sync::MutexGuard<'_, Vec<(usize, UniquePublisherId, PointerOffset)>> {
+        fatal_panic!(from self, when self.deferred_releases.lock(),
+            "Unable to acquire the lock of the deferred sample releases since a thread panicked while holding it.")
+    }
+
+    fn service(&self) -> &'a Service {
+        self.service.get()
+    }
+
+    fn dynamic_config_guard(&self) -> &Option<UniqueIndex<'a>> {
+        // SAFETY: the guard is only replaced by reconnect_if_required() which is never called
+        // while a reference to it exists
+        unsafe { &*self.dynamic_config_guard.get() }
+    }
+
+    fn counters(&self) -> Option<&SubscriberCounters> {
+        self.dynamic_config_guard().as_ref().map(|guard| {
+            self.service()
+                .state()
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .subscriber_counters(guard.value())
+        })
+    }
+
+    fn count_returned_sample(&self) {
+        if let Some(counters) = self.counters() {
+            counters.borrowed_samples.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    fn acquire_sample_handle(
+        &self,
+        channel_id: usize,
+        publisher_id: UniquePublisherId,
+        offset: PointerOffset,
+    ) -> Option<&SampleHandle> {
+        let number_of_handles = self.sample_handles.len();
+        let start = self.next_sample_handle.get();
+        for n in 0..number_of_handles {
+            let index = (start + n) % number_of_handles;
+            let handle = &self.sample_handles[index];
+            if handle.is_free() {
+                // SAFETY: the handle is free and the subscriber is not Sync, only the receiving
+                // thread acquires handles
+                unsafe { handle.acquire(channel_id, publisher_id, offset) };
+                self.next_sample_handle.set((index + 1) % number_of_handles);
+                return Some(handle);
+            }
+        }
+
+        None
+    }
+
+    fn release_deferred_samples(&self) {
+        if !self.has_deferred_releases.swap(false, Ordering::Acquire) {
+            return;
+        }
+
+        // the entries are drained instead of taken so that the preallocated capacity is kept
+        let mut deferred_releases = self.lock_deferred_releases();
+        for (channel_id, publisher_id, offset) in deferred_releases.drain(..) {
+            // counted by the receiving thread since the counters of the subscriber are replaced
+            // when it moves to a new instance of the service
+            self.count_returned_sample();
+            match self
+                .publisher_connections
+                .origin_of_sample(channel_id, publisher_id)
+            {
+                Some(c) => {
+                    if let Err(e) = c.receiver.release(offset) {
+                        warn!(from self, "Unable to release the sample of a deferred release ({:?}). It has to be reclaimed manually by the publisher.", e);
                     }
+                    c.borrowed_samples = c.borrowed_samples.saturating_sub(1);
+                    self.notify_waiting_publisher(c);
+                }
+                None => {
+                    warn!(from self, "Unable to release the sample of a deferred release since the connection is broken. It has to be reclaimed manually by the publisher.");
                 }
-                None => (),
             }
         }
-
-        Ok(None)
+        self.publisher_connections.remove_drained_connections();
     }
 
-    fn update_connections(&self) -> Result<(), ConnectionFailure> {
-        if unsafe { (*self.publisher_list_state.get()).update() } {
-            fail!(from self, when self.populate_publisher_channels(),
-                "Connections were updated only partially since at least one connection to a publisher failed.");
+    /// Wakes up the publisher of the connection when it waits for a free sample or for the
+    /// buffers of its subscribers to be drained.
+    fn notify_waiting_publisher(&self, connection: &Connection<'config, Service>) {
+        use iceoryx2_cal::event::Notifier;
+
+        let notifier = match connection.notifier {
+            Some(ref notifier) => notifier,
+            None => return,
+        };
+
+        // a publisher announces that it waits before it tries to loan a sample the last time,
+        // the release of the sample must be visible before the announcement is read
+        fence(Ordering::SeqCst);
+        if self
+            .service()
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .number_of_waiting_publishers()
+            == 0
+        {
+            return;
         }
 
-        Ok(())
+        if let Err(e) = notifier.notify(EventId::new(0)) {
+            warn!(from self, "Unable to wake up publisher {:?} ({:?}).", connection.publisher_id, e);
+        }
     }
-}
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> SubscribeMgmt
-    for Subscriber<'a, 'config, Service, MessageType>
-{
-    fn release_sample(&self, channel_id: usize, sample: usize) {
-        match self.publisher_connections.get(channel_id) {
+    fn return_sample_to_publisher(
+        &self,
+        channel_id: usize,
+        publisher_id: UniquePublisherId,
+        offset: PointerOffset,
+    ) {
+        self.count_returned_sample();
+        match self
+            .publisher_connections
+            .origin_of_sample(channel_id, publisher_id)
+        {
             Some(c) => {
-                let distance = sample - c.data_segment.allocator_data_start_address();
-                match c.receiver.release(PointerOffset::new(distance)) {
+                match c.receiver.release(offset) {
                     Ok(()) => (),
                     Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
-                        fatal_panic!(from self, when c.receiver.release(PointerOffset::new(distance)),
-                                    "This should never happen! The publishers retrieve channel is full and the sample cannot be returned.");
+                        fatal_panic!(from self, when c.receiver.release(offset),
+                                "This should never happen! The publishers retrieve channel is full and the sample cannot be returned.");
                     }
                 }
+                c.borrowed_samples = c.borrowed_samples.saturating_sub(1);
+                self.notify_waiting_publisher(c);
             }
             None => {
                 warn!(from self, "Unable to release sample since the connection is broken. The sample will be discarded and has to be reclaimed manually by the publisher.");
             }
         }
+        // the retired connection of a replaced publisher is dropped with its last sample
+        self.publisher_connections.remove_drained_connections();
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug + Clone,
+    > Subscriber<'a, 'config, Service, MessageType, UserHeader>
+{
+    /// Returns a copy of the [`Header`] and the user header of the [`Sample`] that the next
+    /// [`Subscribe::receive()`] returns without dequeuing or borrowing it, [`None`] when no
+    /// [`Sample`] is available. The [`crate::port::publisher::Publisher`]s are visited in the
+    /// same order as with [`Subscribe::receive()`].
+    ///
+    /// [`Sample`]s that [`Subscribe::receive()`] would discard, since they are rejected by the
+    /// filter of the [`Subscriber`] or are invalid, are discarded by [`Subscriber::peek()`] as
+    /// well and the same error is returned. When the service has safe overflow, a
+    /// [`crate::port::publisher::Publisher`] that overflows the buffer replaces the peeked
+    /// [`Sample`] with a newer one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// let subscriber = service.subscriber().create()?;
+    ///
+    /// if let Some(view) = subscriber.peek()? {
+    ///     println!("next sample is from {:?}", view.header().publisher_id());
+    ///     let sample = subscriber.receive()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn peek(&self) -> Result<Option<HeaderView<UserHeader>>, SubscriberReceiveError> {
+        self.release_deferred_samples();
+        self.peeked_channel_id.set(None);
+
+        let mut failure = self.update_connections().err();
+
+        for id in self.connection_order(None, &mut failure)? {
+            if let Some(ref mut connection) = &mut self.publisher_connections.get_mut(id) {
+                match self.peek_connection(connection) {
+                    Ok(Some(view)) => {
+                        self.peeked_channel_id.set(Some(id));
+                        return Ok(Some(view));
+                    }
+                    Ok(None) => (),
+                    Err(SubscriberReceiveError::ConnectionFailure(e)) => {
+                        failure.get_or_insert(e);
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        match failure {
+            Some(e) => {
+                fail!(from self, with SubscriberReceiveError::ConnectionFailure(e),
+                    "No sample was peeked and not all connections to publishers could be established.");
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn peek_connection(
+        &self,
+        connection: &mut Connection<'config, Service>,
+    ) -> Result<Option<HeaderView<UserHeader>>, SubscriberReceiveError> {
+        loop {
+            let (relative_addr, absolute_address) =
+                match self.peek_deliverable_sample(connection)? {
+                    Some(sample) => sample,
+                    None => return Ok(None),
+                };
+
+            let message = absolute_address as *const Message<Header, UserHeader, ()>;
+            let view = HeaderView {
+                header: unsafe { (*message).header },
+                user_header: unsafe { (*message).user_header.clone() },
+            };
+
+            // the copy is consistent only when the publisher did not replace the sample while
+            // it was taken
+            if connection.peek_sample() == Some(relative_addr) {
+                return Ok(Some(view));
+            }
+        }
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ?Sized,
+        UserHeader: Debug,
+    > Drop for Subscriber<'a, 'config, Service, MessageType, UserHeader>
+{
+    fn drop(&mut self) {
+        self.release_deferred_samples();
+
+        // the samples borrow the subscriber, the handles that are still in use belong to samples
+        // that were leaked and can no longer be accessed, their chunks are returned before the
+        // publishers remove the connections
+        let mut leaked_samples = 0;
+        for handle in &self.sample_handles {
+            // SAFETY: the subscriber is exclusively borrowed, no sample refers to the handle
+            if let Some((channel_id, publisher_id, offset)) = unsafe { handle.reclaim() } {
+                leaked_samples += 1;
+                self.return_sample_to_publisher(channel_id, publisher_id, offset);
+            }
+        }
+        if leaked_samples != 0 {
+            warn!(from self, "{} sample(s) were leaked and never dropped, their chunks are returned to the publishers since the subscriber is dropped.", leaked_samples);
+        }
+
+        if let Some(ref guard) = self.dynamic_config_guard() {
+            self.service()
+                .state()
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .reset_subscriber_counters(guard.value());
+        }
+
+        // the connection monitors count the subscribers of the dynamic config, the subscriber
+        // must be removed from it before they are woken up
+        self.dynamic_config_guard.get_mut().take();
+        announce_connection_change::<Service>(
+            self.service().state().global_config,
+            self.service().state().static_config.uuid(),
+        );
+
+        #[cfg(debug_assertions)]
+        if leaked_samples != 0 && !std::thread::panicking() {
+            fatal_panic!(from self,
+                "The subscriber is dropped while {} sample(s) are still outstanding, they were leaked and never dropped.",
+                leaked_samples);
+        }
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug,
+    > Subscribe<MessageType, UserHeader>
+    for Subscriber<'a, 'config, Service, MessageType, UserHeader>
+{
+    fn receive(&self) -> Result<Option<Sample<MessageType, UserHeader>>, SubscriberReceiveError> {
+        self.release_deferred_samples();
+        self.reconnect_if_required();
+        self.receive_sample()
+    }
+
+    fn receive_batch(
+        &self,
+        max: usize,
+    ) -> Result<Vec<Sample<MessageType, UserHeader>>, SubscriberReceiveError> {
+        self.release_deferred_samples();
+
+        let mut failure = self.update_connections().err();
+
+        let mut samples = vec![];
+        while samples.len() < max {
+            match self.receive_next(&mut failure) {
+                Ok(Some(sample)) => samples.push(sample),
+                Ok(None) => break,
+                // the already received samples are not dropped, the failure occurs again on
+                // the next call
+                Err(e) if samples.is_empty() => return Err(e),
+                Err(_) => return Ok(samples),
+            }
+        }
+
+        match failure {
+            Some(e) if samples.is_empty() => {
+                fail!(from self, with SubscriberReceiveError::ConnectionFailure(e),
+                    "No sample was received and not all connections to publishers could be established.");
+            }
+            _ => Ok(samples),
+        }
+    }
+
+    fn receive_latest(
+        &self,
+    ) -> Result<Option<Sample<MessageType, UserHeader>>, SubscriberReceiveError> {
+        self.release_deferred_samples();
+        self.peeked_channel_id.set(None);
+
+        let mut failure = self.update_connections().err();
+
+        let mut latest: Option<(usize, Sample<MessageType, UserHeader>)> = None;
+        'connections: for id in 0..self.publisher_connections.len() {
+            loop {
+                // the connection is borrowed only for a single step, the previous sample is
+                // dropped outside of it since its release accesses the connection again
+                let next = match &mut self.publisher_connections.get_mut(id) {
+                    None => break,
+                    Some(connection) => {
+                        let absolute_address = match self.peek_deliverable_sample(connection) {
+                            Ok(Some((_, absolute_address))) => absolute_address,
+                            Ok(None) => break,
+                            Err(SubscriberReceiveError::ConnectionFailure(e)) => {
+                                failure.get_or_insert(e);
+                                break;
+                            }
+                            Err(e) if latest.is_none() => return Err(e),
+                            Err(_) => break 'connections,
+                        };
+
+                        let time_stamp = unsafe { &*(absolute_address as *const Header) }
+                            .time_stamp()
+                            .as_duration();
+                        let (is_newer, is_same_connection) = match &latest {
+                            Some((latest_id, sample)) => (
+                                *latest_id == id
+                                    || sample.header().time_stamp().as_duration() <= time_stamp,
+                                *latest_id == id,
+                            ),
+                            None => (true, false),
+                        };
+
+                        if !is_newer {
+                            match self.discard_next_sample(connection) {
+                                Err(SubscriberReceiveError::ExceedsMaxBorrowedSamples) => {
+                                    break 'connections
+                                }
+                                // rejected samples are removed from the buffer as well
+                                Ok(()) | Err(_) => continue,
+                            }
+                        }
+
+                        // a subscriber that cannot borrow another sample from this publisher
+                        // releases the previous one first, otherwise it is kept until the next
+                        // one is received
+                        if is_same_connection
+                            && connection.borrowed_samples
+                                >= connection.receiver.max_borrowed_samples()
+                        {
+                            None
+                        } else {
+                            match self.receive_from_connection(id, connection) {
+                                Ok(Some(sample)) => Some(sample),
+                                Ok(None) => break,
+                                Err(SubscriberReceiveError::ConnectionFailure(e)) => {
+                                    failure.get_or_insert(e);
+                                    break;
+                                }
+                                Err(e) if latest.is_none() => return Err(e),
+                                Err(_) => break 'connections,
+                            }
+                        }
+                    }
+                };
+
+                match next {
+                    Some(sample) => latest = Some((id, sample)),
+                    None => latest = None,
+                }
+            }
+        }
+
+        match (latest, failure) {
+            (Some((_, sample)), _) => Ok(Some(sample)),
+            (None, Some(e)) => {
+                fail!(from self, with SubscriberReceiveError::ConnectionFailure(e),
+                    "No sample was received and not all connections to publishers could be established.");
+            }
+            (None, None) => Ok(None),
+        }
+    }
+
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.reconnect_if_required();
+        self.update_publisher_connections()
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug,
+    > SubscribeMgmt for Subscriber<'a, 'config, Service, MessageType, UserHeader>
+{
+    fn release_sample(
+        &self,
+        channel_id: usize,
+        publisher_id: UniquePublisherId,
+        offset: PointerOffset,
+    ) {
+        self.return_sample_to_publisher(channel_id, publisher_id, offset)
+    }
+
+    fn defer_release_sample(
+        &self,
+        channel_id: usize,
+        publisher_id: UniquePublisherId,
+        offset: PointerOffset,
+    ) {
+        self.lock_deferred_releases()
+            .push((channel_id, publisher_id, offset));
+        self.has_deferred_releases.store(true, Ordering::Release);
+    }
+
+    fn has_single_subscriber(&self) -> bool {
+        self.service()
+            .state()
+            .static_config
+            .publish_subscribe()
+            .enable_single_subscriber
     }
 }
@@ -0,0 +1,542 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//!
+//! let publisher = service.publisher().create()?.into_sync();
+//!
+//! std::thread::scope(|s| {
+//!     for n in 0..4 {
+//!         let publisher = &publisher;
+//!         s.spawn(move || {
+//!             let sample = publisher.loan_uninit().unwrap();
+//!             sample.write_payload(n).send().unwrap();
+//!         });
+//!     }
+//! });
+//!
+//! // a sample can be loaned in one thread and sent in another one
+//! let sample = publisher.loan()?;
+//! std::thread::scope(|s| {
+//!     s.spawn(move || sample.send().unwrap());
+//! });
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See also, [`crate::port::publisher::Publisher`]
+
+use std::fmt::Debug;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use super::connection_monitor::WaitForConnectionsError;
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use super::publish::internal::{LoanRelease, PublishMgmt};
+use super::publish::{
+    PublisherLoanError, PublisherSendError, PublisherWaitError, SampleToken, SendCopy,
+    SubscriberConnectionError,
+};
+use crate::payload_mut::{internal::PayloadMgmt, PayloadMut, UninitPayloadMut};
+use crate::payload_type::PayloadType;
+use crate::port::publisher::{wait_for_subscribers, ConnectionDiff, Publisher};
+use crate::port::tracing_hooks::TracingHooks;
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::port::DegrationAction;
+use crate::sample_mut::{SampleMut, SampleMutSetTimeStampError};
+use crate::service;
+use crate::service::header::publish_subscribe::Header;
+use crate::service::static_config::StaticConfig;
+use crate::zero_copy_send::ZeroCopySend;
+
+/// Thread-safe sending endpoint of a publish-subscriber based communication. It is created
+/// with [`Publisher::into_sync()`] and guards the loan and delivery bookkeeping of the
+/// underlying [`Publisher`] with a lock. In contrast to the [`SampleMut`] of a [`Publisher`]
+/// the loaned [`SyncSampleMut`] implements [`Send`].
+///
+/// # Notes
+///
+/// Every loan, release and send operation acquires the lock, therefore the [`SyncPublisher`]
+/// should only be used when samples have to cross thread boundaries.
+#[derive(Debug)]
+pub struct SyncPublisher<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    MessageType: Debug + ?Sized,
+    UserHeader: Debug = (),
+> {
+    publisher: Mutex<Publisher<'a, 'config, Service, MessageType, UserHeader>>,
+}
+
+// SAFETY: the state of the underlying publisher is only accessed while holding the lock. The
+// degration callback is removed by Publisher::into_sync() and can only be replaced by a Send +
+// Sync callback, see SyncPublisher::set_degration_callback(), the tracing hooks are Send + Sync.
+// The service is only read, its static config is immutable and its dynamic config resides in
+// shared memory that is designed for concurrent access.
+unsafe impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ?Sized,
+        UserHeader: Debug,
+    > Send for SyncPublisher<'a, 'config, Service, MessageType, UserHeader>
+{
+}
+
+// SAFETY: the state of the underlying publisher is only accessed while holding the lock. The
+// degration callback is removed by Publisher::into_sync() and can only be replaced by a Send +
+// Sync callback, see SyncPublisher::set_degration_callback(), the tracing hooks are Send + Sync.
+// The service is only read, its static config is immutable and its dynamic config resides in
+// shared memory that is designed for concurrent access.
+unsafe impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ?Sized,
+        UserHeader: Debug,
+    > Sync for SyncPublisher<'a, 'config, Service, MessageType, UserHeader>
+{
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug + Default,
+    > SyncPublisher<'a, 'config, Service, MessageType, UserHeader>
+{
+    pub(crate) fn new(publisher: Publisher<'a, 'config, Service, MessageType, UserHeader>) -> Self {
+        Self {
+            publisher: Mutex::new(publisher),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Publisher<'a, 'config, Service, MessageType, UserHeader>> {
+        fatal_panic!(from self, when self.publisher.lock(),
+            "Unable to acquire the publisher lock since a thread panicked while holding it.")
+    }
+
+    /// Returns the [`UniquePublisherId`] of the underlying [`Publisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.lock().id()
+    }
+
+    /// Returns how many [`crate::port::subscriber::Subscriber`]s are attached to the service.
+    /// See [`Publisher::number_of_connected_subscribers()`] for more details.
+    pub fn number_of_connected_subscribers(&self) -> usize {
+        self.lock().number_of_connected_subscribers()
+    }
+
+    /// Returns true when at least one [`crate::port::subscriber::Subscriber`] is attached to
+    /// the service. See [`Publisher::has_subscribers()`] for more details.
+    pub fn has_subscribers(&self) -> bool {
+        self.lock().has_subscribers()
+    }
+
+    /// Blocks until at least `min` [`crate::port::subscriber::Subscriber`]s are attached to the
+    /// service and returns their number. In contrast to the other blocking calls the lock is
+    /// not held while waiting since only the service is read. See
+    /// [`Publisher::wait_for_subscribers()`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service = zero_copy::Service::new(&ServiceName::new("My/Funk/ServiceName")?)
+    ///     .publish_subscribe()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let publisher = service.publisher().create()?.into_sync();
+    /// # let _subscriber = service.subscriber().create()?;
+    ///
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| {
+    ///         publisher.wait_for_subscribers(1, Duration::from_secs(5)).unwrap();
+    ///         publisher.send_copy(1234).unwrap();
+    ///     });
+    /// });
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_subscribers(
+        &self,
+        min: usize,
+        timeout: Duration,
+    ) -> Result<usize, WaitForConnectionsError> {
+        let service = self.lock().service();
+        wait_for_subscribers(service, min, timeout)
+    }
+
+    /// Explicitly updates all connections of the underlying [`Publisher`]. See
+    /// [`Publisher::update_connections()`] for more details.
+    pub fn update_connections(&self) -> Result<ConnectionDiff, SubscriberConnectionError> {
+        self.lock().update_connections()
+    }
+
+    /// Sets the [`DegrationCallback`](crate::port::DegrationCallback) of the underlying
+    /// [`Publisher`], see [`Publisher::set_degration_callback()`]. In contrast to the
+    /// [`Publisher`] the callback must be [`Send`] and [`Sync`] since it is called by whatever
+    /// thread holds the lock.
+    pub fn set_degration_callback<
+        F: Fn(StaticConfig, UniquePublisherId, UniqueSubscriberId) -> DegrationAction
+            + Send
+            + Sync
+            + 'a,
+    >(
+        &self,
+        callback: Option<F>,
+    ) {
+        self.lock().set_degration_callback(callback)
+    }
+
+    /// Sets the [`TracingHooks`] of the underlying [`Publisher`], see
+    /// [`Publisher::set_tracing_hooks()`]. They are called by whatever thread loans or sends a
+    /// sample, therefore they must be [`Send`] and [`Sync`].
+    pub fn set_tracing_hooks<H: TracingHooks<UserHeader> + 'a>(&self, hooks: Option<H>) {
+        self.lock().set_tracing_hooks(hooks)
+    }
+
+    /// Returns the sequence number up to which all sent samples were consumed. See
+    /// [`Publisher::consumed_up_to()`] for more details.
+    pub fn consumed_up_to(&self) -> Option<u64> {
+        self.lock().consumed_up_to()
+    }
+
+    /// Blocks until the sample of the [`SampleToken`] was consumed or the timeout has passed.
+    /// The lock is held while waiting, other threads that use the [`SyncPublisher`] block
+    /// until it returns. See [`Publisher::wait_consumed()`] for more details.
+    pub fn wait_consumed(
+        &self,
+        token: SampleToken,
+        timeout: Duration,
+    ) -> Result<bool, PublisherWaitError> {
+        self.lock().wait_consumed(token, timeout)
+    }
+
+    /// Returns the underlying [`Publisher`]. All [`SyncSampleMut`]s must be sent or dropped
+    /// beforehand since they borrow the [`SyncPublisher`].
+    pub fn into_inner(self) -> Publisher<'a, 'config, Service, MessageType, UserHeader> {
+        fatal_panic!(from "SyncPublisher::into_inner()", when self.publisher.into_inner(),
+            "Unable to acquire the publisher since a thread panicked while holding the lock.")
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ZeroCopySend,
+        UserHeader: Debug + Default,
+    > SyncPublisher<'a, 'config, Service, MessageType, UserHeader>
+{
+    /// Loans/allocates a [`SyncSampleMut`] from the underlying data segment of the
+    /// [`Publisher`]. The user has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<SyncSampleMut<MaybeUninit<MessageType>, UserHeader>, PublisherLoanError> {
+        Ok(SyncSampleMut {
+            sample: self.lock().loan_uninit_for(self)?,
+        })
+    }
+
+    /// Copies the input `value` into a [`SyncSampleMut`] and delivers it.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data, otherwise a [`PublisherSendError`] describing the failure.
+    pub fn send_copy(&self, value: MessageType) -> Result<usize, PublisherSendError> {
+        self.lock().send_copy(value)
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ZeroCopySend + Default,
+        UserHeader: Debug + Default,
+    > SyncPublisher<'a, 'config, Service, MessageType, UserHeader>
+{
+    /// Loans/allocates a [`SyncSampleMut`] from the underlying data segment of the
+    /// [`Publisher`] and initializes it with the default value.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    pub fn loan(&self) -> Result<SyncSampleMut<MessageType, UserHeader>, PublisherLoanError> {
+        Ok(self.loan_uninit()?.write_payload(MessageType::default()))
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: Debug + ZeroCopySend,
+        UserHeader: Debug + Default,
+    > SyncPublisher<'a, 'config, Service, [MessageType], UserHeader>
+{
+    /// Loans/allocates a [`SyncSampleMut`] with a slice of `number_of_elements` uninitialized
+    /// elements. See [`Publisher::loan_slice_uninit()`] for more details.
+    pub fn loan_slice_uninit(
+        &self,
+        number_of_elements: usize,
+    ) -> Result<SyncSampleMut<[MaybeUninit<MessageType>], UserHeader>, PublisherLoanError> {
+        Ok(SyncSampleMut {
+            sample: self
+                .lock()
+                .loan_slice_uninit_for(self, number_of_elements)?,
+        })
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug + Default,
+    > UpdateConnections for SyncPublisher<'a, 'config, Service, MessageType, UserHeader>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        SyncPublisher::update_connections(self)
+            .map(|_| ())
+            .map_err(|e| e.failure())
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug + Default,
+    > PublishMgmt for SyncPublisher<'a, 'config, Service, MessageType, UserHeader>
+{
+    fn return_loaned_sample(&self, distance_to_chunk: PointerOffset, release: LoanRelease) {
+        self.lock().return_loaned_sample(distance_to_chunk, release)
+    }
+
+    fn allows_user_time_stamps(&self) -> bool {
+        self.lock().allows_user_time_stamps()
+    }
+
+    fn send_impl(
+        &self,
+        address_to_chunk: usize,
+        keep_time_stamp: bool,
+    ) -> Result<usize, PublisherSendError> {
+        self.lock().send_impl(address_to_chunk, keep_time_stamp)
+    }
+
+    fn send_with_token_impl(
+        &self,
+        address_to_chunk: usize,
+        keep_time_stamp: bool,
+    ) -> Result<SampleToken, PublisherSendError> {
+        self.lock()
+            .send_with_token_impl(address_to_chunk, keep_time_stamp)
+    }
+}
+
+/// Acquired by a [`SyncPublisher`] via [`SyncPublisher::loan()`],
+/// [`SyncPublisher::loan_uninit()`] or [`SyncPublisher::loan_slice_uninit()`]. It provides the
+/// same functionality as the [`SampleMut`] but it implements [`Send`] and can therefore be
+/// initialized, sent or dropped in any thread.
+#[derive(Debug)]
+pub struct SyncSampleMut<'publisher, M: Debug + ?Sized, UserHeader: Debug = ()> {
+    sample: SampleMut<'publisher, M, UserHeader>,
+}
+
+// SAFETY: the sample refers to a SyncPublisher which serializes the release and send operations
+// and the payload memory is exclusively owned by the sample until it is sent
+unsafe impl<M: Debug + ?Sized + Send, UserHeader: Debug + Send> Send
+    for SyncSampleMut<'_, M, UserHeader>
+{
+}
+
+impl<'publisher, M: Debug + ?Sized, UserHeader: Debug> SyncSampleMut<'publisher, M, UserHeader> {
+    /// Returns a reference to the [`Header`] of the sample, see [`SampleMut::header()`].
+    pub fn header(&self) -> &Header {
+        self.sample.header()
+    }
+
+    /// Returns a reference to the user header of the sample.
+    pub fn user_header(&self) -> &UserHeader {
+        self.sample.user_header()
+    }
+
+    /// Returns a mutable reference to the user header of the sample.
+    pub fn user_header_mut(&mut self) -> &mut UserHeader {
+        self.sample.user_header_mut()
+    }
+
+    /// Sets the time stamp of the sample, see [`SampleMut::set_time_stamp()`].
+    pub fn set_time_stamp(&mut self, value: Time) -> Result<(), SampleMutSetTimeStampError> {
+        self.sample.set_time_stamp(value)
+    }
+
+    /// Discards the sample without sending it, see [`SampleMut::cancel()`].
+    pub fn cancel(self) {
+        self.sample.cancel()
+    }
+
+    /// Sends the sample and returns its [`SampleToken`], see [`SampleMut::send_with_token()`].
+    pub fn send_with_token(self) -> Result<SampleToken, PublisherSendError> {
+        self.sample.send_with_token()
+    }
+}
+
+impl<M: Debug + ZeroCopySend + ?Sized, UserHeader: Debug> Deref
+    for SyncSampleMut<'_, M, UserHeader>
+{
+    type Target = M;
+    fn deref(&self) -> &Self::Target {
+        &self.sample
+    }
+}
+
+impl<M: Debug + ZeroCopySend + ?Sized, UserHeader: Debug> DerefMut
+    for SyncSampleMut<'_, M, UserHeader>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.sample
+    }
+}
+
+impl<'publisher, M: Debug + ?Sized, UserHeader: Debug> PayloadMgmt
+    for SyncSampleMut<'publisher, M, UserHeader>
+{
+    fn offset_to_chunk(&self) -> PointerOffset {
+        self.sample.offset_to_chunk()
+    }
+}
+
+impl<'publisher, M: Debug + ?Sized, UserHeader: Debug> PayloadMut<M>
+    for SyncSampleMut<'publisher, M, UserHeader>
+{
+    fn header(&self) -> &Header {
+        SyncSampleMut::header(self)
+    }
+
+    fn payload(&self) -> &M {
+        self.sample.payload()
+    }
+
+    fn payload_mut(&mut self) -> &mut M {
+        self.sample.payload_mut()
+    }
+
+    fn send(self) -> Result<usize, PublisherSendError> {
+        self.sample.send()
+    }
+}
+
+impl<'publisher, MessageType: Debug, UserHeader: Debug> UninitPayloadMut<MessageType>
+    for SyncSampleMut<'publisher, MaybeUninit<MessageType>, UserHeader>
+{
+    type InitializedSample = SyncSampleMut<'publisher, MessageType, UserHeader>;
+
+    fn write_payload(self, value: MessageType) -> Self::InitializedSample {
+        SyncSampleMut {
+            sample: self.sample.write_payload(value),
+        }
+    }
+
+    unsafe fn write_from_fn<F: FnOnce(&mut MaybeUninit<MessageType>)>(
+        self,
+        initializer: F,
+    ) -> Self::InitializedSample {
+        SyncSampleMut {
+            sample: self.sample.write_from_fn(initializer),
+        }
+    }
+
+    unsafe fn assume_init(self) -> Self::InitializedSample {
+        SyncSampleMut {
+            sample: self.sample.assume_init(),
+        }
+    }
+}
+
+impl<'publisher, MessageType: Debug, UserHeader: Debug>
+    SyncSampleMut<'publisher, [MaybeUninit<MessageType>], UserHeader>
+{
+    /// Copies the elements of `value` into the payload and labels the sample as initialized.
+    /// See [`SampleMut::write_from_slice()`] for more details.
+    pub fn write_from_slice(
+        self,
+        value: &[MessageType],
+    ) -> SyncSampleMut<'publisher, [MessageType], UserHeader>
+    where
+        MessageType: Copy,
+    {
+        SyncSampleMut {
+            sample: self.sample.write_from_slice(value),
+        }
+    }
+
+    /// Moves the elements of `iter` into the payload and labels the sample as initialized.
+    /// See [`SampleMut::write_from_iter()`] for more details.
+    pub fn write_from_iter<I: ExactSizeIterator<Item = MessageType>>(
+        self,
+        iter: I,
+    ) -> Result<SyncSampleMut<'publisher, [MessageType], UserHeader>, Self> {
+        match self.sample.write_from_iter(iter) {
+            Ok(sample) => Ok(SyncSampleMut { sample }),
+            Err(sample) => Err(SyncSampleMut { sample }),
+        }
+    }
+
+    /// Initializes every element of the payload with the value `initializer` returns for its
+    /// index. See [`SampleMut::write_from_fn()`] for more details.
+    pub fn write_from_fn<F: FnMut(usize) -> MessageType>(
+        self,
+        initializer: F,
+    ) -> SyncSampleMut<'publisher, [MessageType], UserHeader> {
+        SyncSampleMut {
+            sample: self.sample.write_from_fn(initializer),
+        }
+    }
+
+    /// Labels all elements of the sample as initialized.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that every element of the slice really is initialized. See
+    /// [`SampleMut::assume_init()`] for more details.
+    pub unsafe fn assume_init(self) -> SyncSampleMut<'publisher, [MessageType], UserHeader> {
+        SyncSampleMut {
+            sample: self.sample.assume_init(),
+        }
+    }
+}
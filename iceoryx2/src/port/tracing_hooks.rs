@@ -0,0 +1,97 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::port::tracing_hooks::TracingHooks;
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::header::publish_subscribe::Header;
+//!
+//! #[derive(Debug, Default, ZeroCopySend)]
+//! #[repr(C)]
+//! struct TraceHeader {
+//!     trace_id: u64,
+//! }
+//!
+//! struct TraceIdPropagation;
+//!
+//! impl TracingHooks<TraceHeader> for TraceIdPropagation {
+//!     fn on_loan(&self, header: &Header, user_header: &mut TraceHeader) {
+//!         user_header.trace_id = header.publisher_id().value() as u64;
+//!     }
+//!
+//!     fn on_receive(&self, header: &Header, user_header: &TraceHeader) {
+//!         println!("received sample {} of trace {}", header.sequence_number(), user_header.trace_id);
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/TracedServiceName")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .user_header::<TraceHeader>()
+//!     .open_or_create::<u64>()?;
+//!
+//! let mut publisher = service.publisher().create()?;
+//! publisher.set_tracing_hooks(Some(TraceIdPropagation));
+//! let mut subscriber = service.subscriber().create()?;
+//! subscriber.set_tracing_hooks(Some(TraceIdPropagation));
+//!
+//! publisher.loan_uninit()?.write_payload(1234).send()?;
+//! let sample = subscriber.receive()?.unwrap();
+//! assert_eq!(sample.user_header().trace_id, publisher.id().value() as u64);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use crate::service::header::publish_subscribe::Header;
+
+/// Callbacks a [`crate::port::publisher::Publisher`] or a
+/// [`crate::port::subscriber::Subscriber`] calls at the stations of the lifetime of a sample,
+/// for instance to open and close spans of a tracing framework. They are registered per port
+/// with [`crate::port::publisher::Publisher::set_tracing_hooks()`] and
+/// [`crate::port::subscriber::Subscriber::set_tracing_hooks()`], a port without hooks pays a
+/// single branch per station.
+///
+/// The hooks are called in the hot path and shall return quickly, they must not loan, send or
+/// receive samples of the port they are registered at. All hooks do nothing by default.
+pub trait TracingHooks<UserHeader>: Send + Sync {
+    /// Called by the [`crate::port::publisher::Publisher`] when a sample was loaned, the user
+    /// header is already default initialized and can be modified, for instance to attach the
+    /// id of the current trace. The sequence number and the time stamp of the [`Header`] are
+    /// not yet assigned. When the hook panics, the loan is cancelled and the memory is returned
+    /// to the publisher.
+    fn on_loan(&self, _header: &Header, _user_header: &mut UserHeader) {}
+
+    /// Called by the [`crate::port::publisher::Publisher`] when a sample is sent, before it is
+    /// delivered to the subscribers. The [`Header`] contains the sequence number and the time
+    /// stamp of the sample. Loaned samples that are dropped or cancelled are not sent.
+    fn on_send(&self, _header: &Header, _user_header: &UserHeader) {}
+
+    /// Called by the [`crate::port::subscriber::Subscriber`] when a sample was received, before
+    /// it is handed to the user.
+    fn on_receive(&self, _header: &Header, _user_header: &UserHeader) {}
+
+    /// Called when the last clone of a received [`crate::sample::Sample`] is dropped, by the
+    /// thread that drops it, before the sample is returned to the
+    /// [`crate::port::publisher::Publisher`].
+    fn on_release(&self, _header: &Header, _user_header: &UserHeader) {}
+}
+
+impl<UserHeader> Debug for dyn TracingHooks<UserHeader> + '_ {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TracingHooks")
+    }
+}
@@ -12,10 +12,31 @@
 
 pub use crate::iox2::Iox2;
 pub use crate::iox2::Iox2Event;
+pub use crate::log::{set_log_level, set_logger, LogLevel};
+pub use crate::node::{node_name::NodeName, NodeBuilder};
 pub use crate::payload::Payload;
 pub use crate::payload_mut::{PayloadMut, UninitPayloadMut};
 pub use crate::port::{
-    event_id::EventId, listen::Listen, notify::Notify, publish::DefaultLoan, publish::Publish,
-    publish::SendCopy, publish::UninitLoan, subscribe::Subscribe,
+    event_id::EventId, event_id::EventIdEnum, listen::Listen, notify::Notify, publish::DefaultLoan,
+    publish::Publish, publish::PublisherCreateError, publish::PublisherLoanError,
+    publish::PublisherSendError, publish::SendCopy, publish::UninitLoan, subscribe::Subscribe,
+    subscribe::SubscriberCreateError, subscribe::SubscriberReceiveError,
 };
-pub use crate::service::{process_local, service_name::ServiceName, zero_copy, Details, Service};
+pub use crate::service::builder::{
+    event::{EventCreateError, EventOpenError, EventOpenOrCreateError},
+    publish_subscribe::{
+        PublishSubscribeCreateError, PublishSubscribeOpenError, PublishSubscribeOpenOrCreateError,
+    },
+    request_response::{
+        RequestResponseCreateError, RequestResponseOpenError, RequestResponseOpenOrCreateError,
+    },
+};
+pub use crate::service::static_config::ServicePersistence;
+pub use crate::service::{
+    process_local, service_name::ServiceName, zero_copy, Details, Service, ServiceRemoveError,
+};
+pub use crate::tagged_payload::TaggedPayload;
+pub use crate::zero_copy_send::ZeroCopySend;
+pub use iceoryx2_bb_container::{byte_string::FixedSizeByteString, vec::FixedSizeVec};
+pub use iceoryx2_bb_elementary::alignment::Alignment;
+pub use iceoryx2_bb_elementary::callback_progression::CallbackProgression;
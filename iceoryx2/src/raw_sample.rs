@@ -14,192 +14,253 @@ use crate::message::Message;
 
 use core::fmt;
 
-/// A `*const Message<Header, Data>` non-zero sample pointer to the message.
-#[repr(transparent)]
-pub(crate) struct RawSample<Header, Data> {
-    message: *const Message<Header, Data>,
+/// A non-zero sample pointer to a message. The headers are stored at the start of the message
+/// and are followed by the payload, which can be stored with an alignment that is larger than
+/// the alignment of `Data`, see
+/// [`crate::service::builder::publish_subscribe::Builder::payload_alignment()`]. Therefore the
+/// payload is addressed with its own pointer instead of the `data` field of the
+/// `#[repr(C)]` [`Message`].
+#[repr(C)]
+pub struct RawSample<Header, UserHeader, Data: ?Sized> {
+    headers: *const Message<Header, UserHeader, ()>,
+    data: *const Data,
 }
 
-impl<Header, Data> RawSample<Header, Data> {
-    /// Creates a new `RawSample`.
+impl<Header, UserHeader, Data: ?Sized> RawSample<Header, UserHeader, Data> {
+    /// Creates a new `RawSample` from a message whose payload is stored at its natural offset.
     ///
     /// # Safety
     ///
-    /// `message` must be non-null.
+    /// `message` must be non-null and point to a valid message.
     #[inline]
-    pub(crate) unsafe fn new_unchecked(message: *const Message<Header, Data>) -> Self {
+    pub unsafe fn new_unchecked(message: *const Message<Header, UserHeader, Data>) -> Self {
         debug_assert!(
             !message.is_null(),
             "RawSample::new_unchecked requires that the message pointer is non-null"
         );
-        Self { message }
+        Self {
+            headers: message as *const Message<Header, UserHeader, ()>,
+            data: core::ptr::addr_of!((*message).data),
+        }
     }
 
-    /// Creates a new `RawSample`.
-    #[allow(dead_code)]
+    /// Creates a new `RawSample` from the headers and the payload of a message.
+    ///
+    /// # Safety
+    ///
+    ///  * `headers` and `data` must be non-null and point into the same message
+    ///  * `headers` must be aligned to `Header` and point to an initialized `Header` that is
+    ///    followed by an initialized `UserHeader`
+    ///  * `data` must be aligned to `Data` and point to an initialized `Data`, for a slice its
+    ///    metadata must not let the payload exceed the memory of the message
+    ///  * the memory must stay mapped and must not be modified while the `RawSample` or a
+    ///    reference acquired from it is used
     #[inline]
-    pub(crate) fn new(message: *const Message<Header, Data>) -> Option<Self> {
-        if !message.is_null() {
-            // SAFETY: `message` pointer is checked to be non-null
-            Some(unsafe { Self::new_unchecked(message) })
-        } else {
-            None
-        }
+    pub unsafe fn from_header_and_payload_ptrs(
+        headers: *const Message<Header, UserHeader, ()>,
+        data: *const Data,
+    ) -> Self {
+        debug_assert!(
+            !headers.is_null() && !(data as *const u8).is_null(),
+            "RawSample::from_header_and_payload_ptrs requires that the pointers are non-null"
+        );
+        Self { headers, data }
     }
 
-    /// Acquires the underlying message as `*const` pointer.
+    /// Acquires the headers of the underlying message as `*const` pointer.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_ptr(self) -> *const Message<Header, Data> {
-        self.message
+    pub fn as_headers_ptr(self) -> *const Message<Header, UserHeader, ()> {
+        self.headers
     }
 
-    /// Acquires the underlying message as reference.
+    /// Acquires the payload of the underlying message as `*const` pointer.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_ref(&self) -> &Message<Header, Data> {
-        // SAFETY: `self.as_ptr()` returns a non-null ptr and `Data` is either the actual message type or wrapped by a `MaybeUninit` which makes a reference to `Message::data` safe
-        unsafe { &(*self.as_ptr()) }
+    pub fn as_data_ptr(self) -> *const Data {
+        self.data
     }
 
     /// Acquires the underlying header as reference.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_header_ref(&self) -> &Header {
-        &self.as_ref().header
+    pub fn as_header_ref(&self) -> &Header {
+        // SAFETY: `self.headers` is non-null and points to the headers of a valid message
+        unsafe { &(*self.headers).header }
+    }
+
+    /// Acquires the underlying user header as reference.
+    #[must_use]
+    #[inline(always)]
+    pub fn as_user_header_ref(&self) -> &UserHeader {
+        // SAFETY: `self.headers` is non-null and points to the headers of a valid message
+        unsafe { &(*self.headers).user_header }
     }
 
     /// Acquires the underlying data as reference.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_data_ref(&self) -> &Data {
-        &self.as_ref().data
+    pub fn as_data_ref(&self) -> &Data {
+        // SAFETY: `self.data` is non-null and `Data` is either the actual message type or wrapped by a `MaybeUninit` which makes a reference to it safe
+        unsafe { &*self.data }
     }
 }
 
-impl<Header, Data> Clone for RawSample<Header, Data> {
+impl<Header, UserHeader, Data: ?Sized> Clone for RawSample<Header, UserHeader, Data> {
     #[inline(always)]
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<Header, Data> Copy for RawSample<Header, Data> {}
+impl<Header, UserHeader, Data: ?Sized> Copy for RawSample<Header, UserHeader, Data> {}
 
-impl<Header: fmt::Debug, Data: fmt::Debug> fmt::Debug for RawSample<Header, Data> {
+impl<Header: fmt::Debug, UserHeader: fmt::Debug, Data: fmt::Debug + ?Sized> fmt::Debug
+    for RawSample<Header, UserHeader, Data>
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Pointer::fmt(&self.message, f)
+        fmt::Pointer::fmt(&self.headers, f)
     }
 }
 
-impl<Header, Data> fmt::Pointer for RawSample<Header, Data> {
+impl<Header, UserHeader, Data: ?Sized> fmt::Pointer for RawSample<Header, UserHeader, Data> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Pointer::fmt(&self.message, f)
+        fmt::Pointer::fmt(&self.headers, f)
     }
 }
 
-/// A `*mut Message<Header, Data>` non-zero sample pointer to the message.
-#[repr(transparent)]
-pub(crate) struct RawSampleMut<Header, Data> {
-    message: *mut Message<Header, Data>,
+/// The mutable counterpart of [`RawSample`].
+#[repr(C)]
+pub struct RawSampleMut<Header, UserHeader, Data: ?Sized> {
+    headers: *mut Message<Header, UserHeader, ()>,
+    data: *mut Data,
 }
 
-impl<Header, Data> RawSampleMut<Header, Data> {
-    /// Creates a new `RawSampleMut`.
+impl<Header, UserHeader, Data: ?Sized> RawSampleMut<Header, UserHeader, Data> {
+    /// Creates a new `RawSampleMut` from a message whose payload is stored at its natural
+    /// offset.
     ///
     /// # Safety
     ///
-    /// `message` must be non-null.
+    /// `message` must be non-null and point to a valid message.
     #[inline]
-    pub(crate) unsafe fn new_unchecked(message: *mut Message<Header, Data>) -> Self {
+    pub unsafe fn new_unchecked(message: *mut Message<Header, UserHeader, Data>) -> Self {
         debug_assert!(
             !message.is_null(),
             "RawSampleMut::new_unchecked requires that the message pointer is non-null"
         );
-        Self { message }
+        Self {
+            headers: message as *mut Message<Header, UserHeader, ()>,
+            data: core::ptr::addr_of_mut!((*message).data),
+        }
     }
 
-    /// Creates a new `RawSampleMut`.
-    #[allow(dead_code)]
+    /// Creates a new `RawSampleMut` from the headers and the payload of a message.
+    ///
+    /// # Safety
+    ///
+    ///  * `headers` and `data` must be non-null and point into the same message
+    ///  * `headers` must be aligned to `Header` and point to an initialized `Header` that is
+    ///    followed by an initialized `UserHeader`
+    ///  * `data` must be aligned to `Data` and point to a `Data` that is either initialized
+    ///    or wrapped by a `MaybeUninit`, for a slice its metadata must not let the payload
+    ///    exceed the memory of the message
+    ///  * the memory must stay mapped and must be accessed exclusively via the
+    ///    `RawSampleMut` while it or a reference acquired from it is used
     #[inline]
-    pub(crate) fn new(message: *mut Message<Header, Data>) -> Option<Self> {
-        if !message.is_null() {
-            // SAFETY: `message` pointer is checked to be non-null
-            Some(unsafe { Self::new_unchecked(message) })
-        } else {
-            None
-        }
+    pub unsafe fn from_header_and_payload_ptrs(
+        headers: *mut Message<Header, UserHeader, ()>,
+        data: *mut Data,
+    ) -> Self {
+        debug_assert!(
+            !headers.is_null() && !(data as *const u8).is_null(),
+            "RawSampleMut::from_header_and_payload_ptrs requires that the pointers are non-null"
+        );
+        Self { headers, data }
     }
 
-    /// Acquires the underlying message as `*const` pointer.
+    /// Acquires the headers of the underlying message as `*mut` pointer.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_ptr(self) -> *const Message<Header, Data> {
-        self.message
+    pub fn as_headers_mut_ptr(self) -> *mut Message<Header, UserHeader, ()> {
+        self.headers
     }
 
-    /// Acquires the underlying message as `*mut` pointer.
+    /// Acquires the payload of the underlying message as `*mut` pointer.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_mut_ptr(self) -> *mut Message<Header, Data> {
-        self.message
+    pub fn as_data_mut_ptr(self) -> *mut Data {
+        self.data
     }
 
-    /// Acquires the underlying message as reference.
+    /// Acquires the underlying header as reference.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_ref(&self) -> &Message<Header, Data> {
-        // SAFETY: `self.as_ptr()` returns a non-null ptr and `Data` is either the actual message type or wrapped by a `MaybeUninit` which makes a reference to `Message::data` safe
-        unsafe { &(*self.as_ptr()) }
+    pub fn as_header_ref(&self) -> &Header {
+        // SAFETY: `self.headers` is non-null and points to the headers of a valid message
+        unsafe { &(*self.headers).header }
     }
 
-    /// Acquires the underlying message as mut reference.
+    /// Acquires the underlying user header as reference.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_mut(&mut self) -> &mut Message<Header, Data> {
-        // SAFETY: `self.as_ptr()` returns a non-null ptr and `Data` is either the actual message type or wrapped by a `MaybeUninit` which makes a reference to `Message::data` safe
-        unsafe { &mut (*self.as_mut_ptr()) }
+    pub fn as_user_header_ref(&self) -> &UserHeader {
+        // SAFETY: `self.headers` is non-null and points to the headers of a valid message
+        unsafe { &(*self.headers).user_header }
     }
 
-    /// Acquires the underlying header as reference.
+    /// Acquires the underlying data as reference.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_header_ref(&self) -> &Header {
-        &self.as_ref().header
+    pub fn as_data_ref(&self) -> &Data {
+        // SAFETY: `self.data` is non-null and `Data` is either the actual message type or wrapped by a `MaybeUninit` which makes a reference to it safe
+        unsafe { &*self.data }
     }
 
-    /// Acquires the underlying data as reference.
+    /// Acquires the underlying header as mut reference.
+    #[must_use]
+    #[inline(always)]
+    pub fn as_header_mut(&mut self) -> &mut Header {
+        // SAFETY: `self.headers` is non-null and points to the headers of a valid message
+        unsafe { &mut (*self.headers).header }
+    }
+
+    /// Acquires the underlying user header as mut reference.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_data_ref(&self) -> &Data {
-        &self.as_ref().data
+    pub fn as_user_header_mut(&mut self) -> &mut UserHeader {
+        // SAFETY: `self.headers` is non-null and points to the headers of a valid message
+        unsafe { &mut (*self.headers).user_header }
     }
 
     /// Acquires the underlying data as mut reference.
     #[must_use]
     #[inline(always)]
-    pub(crate) fn as_data_mut(&mut self) -> &mut Data {
-        &mut self.as_mut().data
+    pub fn as_data_mut(&mut self) -> &mut Data {
+        // SAFETY: `self.data` is non-null and `Data` is either the actual message type or wrapped by a `MaybeUninit` which makes a reference to it safe
+        unsafe { &mut *self.data }
     }
 }
 
-impl<Header, Data> Clone for RawSampleMut<Header, Data> {
+impl<Header, UserHeader, Data: ?Sized> Clone for RawSampleMut<Header, UserHeader, Data> {
     #[inline(always)]
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<Header, Data> Copy for RawSampleMut<Header, Data> {}
+impl<Header, UserHeader, Data: ?Sized> Copy for RawSampleMut<Header, UserHeader, Data> {}
 
-impl<Header: fmt::Debug, Data: fmt::Debug> fmt::Debug for RawSampleMut<Header, Data> {
+impl<Header: fmt::Debug, UserHeader: fmt::Debug, Data: fmt::Debug + ?Sized> fmt::Debug
+    for RawSampleMut<Header, UserHeader, Data>
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Pointer::fmt(&self.as_ptr(), f)
+        fmt::Pointer::fmt(&self.headers, f)
     }
 }
 
-impl<Header, Data> fmt::Pointer for RawSampleMut<Header, Data> {
+impl<Header, UserHeader, Data: ?Sized> fmt::Pointer for RawSampleMut<Header, UserHeader, Data> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Pointer::fmt(&self.as_ptr(), f)
+        fmt::Pointer::fmt(&self.headers, f)
     }
 }
@@ -33,42 +33,323 @@
 //!
 //! See also [`crate::sample::Sample`].
 
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::ThreadId;
 use std::{fmt::Debug, ops::Deref};
 
+use iceoryx2_cal::zero_copy_connection::PointerOffset;
+
+use crate::port::port_identifiers::UniquePublisherId;
 use crate::port::subscribe::internal::SubscribeMgmt;
+use crate::port::tracing_hooks::TracingHooks;
 use crate::service::header::publish_subscribe::Header;
-use crate::{payload::Payload, raw_sample::RawSample};
+use crate::{message::Message, payload::Payload, raw_sample::RawSample};
+
+#[derive(Debug, Clone, Copy)]
+struct SampleLocation {
+    channel_id: usize,
+    // identifies the connection of the sample when its publisher was replaced in the meantime
+    publisher_id: UniquePublisherId,
+    offset: PointerOffset,
+    receiving_thread: ThreadId,
+}
+
+/// Counts the clones of a [`Sample`] so that the memory chunk can be returned to the
+/// subscriber when the last clone goes out of scope. The subscriber owns one handle for every
+/// sample it can borrow and reuses them, therefore receiving a sample does not allocate.
+#[derive(Debug)]
+pub(crate) struct SampleHandle {
+    references: AtomicUsize,
+    location: UnsafeCell<SampleLocation>,
+}
+
+// SAFETY: the location is written only by the receiving thread while no sample refers to the
+// handle and is read-only as long as a sample refers to it
+unsafe impl Sync for SampleHandle {}
+
+impl SampleHandle {
+    pub(crate) fn new() -> Self {
+        Self {
+            references: AtomicUsize::new(0),
+            location: UnsafeCell::new(SampleLocation {
+                channel_id: 0,
+                publisher_id: UniquePublisherId::from(0),
+                offset: PointerOffset::new(0),
+                receiving_thread: std::thread::current().id(),
+            }),
+        }
+    }
+
+    pub(crate) fn is_free(&self) -> bool {
+        self.references.load(Ordering::Acquire) == 0
+    }
+
+    /// # Safety
+    ///
+    ///  * the handle must be free, see [`SampleHandle::is_free()`]
+    ///  * must be called only by the thread that receives the sample
+    pub(crate) unsafe fn acquire(
+        &self,
+        channel_id: usize,
+        publisher_id: UniquePublisherId,
+        offset: PointerOffset,
+    ) {
+        *self.location.get() = SampleLocation {
+            channel_id,
+            publisher_id,
+            offset,
+            receiving_thread: std::thread::current().id(),
+        };
+        self.references.store(1, Ordering::Relaxed);
+    }
+
+    /// Returns the location of the sample the handle currently refers to.
+    ///
+    /// # Safety
+    ///
+    ///  * the handle must be acquired
+    pub(crate) unsafe fn location(&self) -> (usize, UniquePublisherId, PointerOffset) {
+        let location = *self.location.get();
+        (location.channel_id, location.publisher_id, location.offset)
+    }
+
+    /// Frees a handle that is still in use and returns the channel id, the publisher id and the
+    /// offset of its sample, [`None`] when the handle is free.
+    ///
+    /// # Safety
+    ///
+    ///  * no [`Sample`] refers to the handle anymore, for instance since it was leaked with
+    ///    [`core::mem::forget()`]
+    pub(crate) unsafe fn reclaim(&self) -> Option<(usize, UniquePublisherId, PointerOffset)> {
+        if self.references.swap(0, Ordering::AcqRel) == 0 {
+            return None;
+        }
+
+        let location = *self.location.get();
+        Some((location.channel_id, location.publisher_id, location.offset))
+    }
+}
 
 /// It stores the payload and is acquired by the [`crate::port::subscriber::Subscriber`] whenever
 /// it receives new data from a [`crate::port::publisher::Publisher`] via
 /// [`crate::port::subscribe::Subscribe::receive()`].
+///
+/// A [`Sample`] can be cloned to hand out read-only views of the same payload, for instance to
+/// several threads, without copying it. The memory chunk is returned to the
+/// [`crate::port::publisher::Publisher`] when the last clone is dropped. All clones count as a
+/// single borrowed sample regarding
+/// [`crate::service::static_config::publish_subscribe::StaticConfig::subscriber_max_borrowed_samples()`].
+/// When the last clone is dropped by another thread than the one that received the [`Sample`],
+/// the chunk is returned with the next [`crate::port::subscribe::Subscribe::receive()`] call.
+///
+/// A [`Sample`] borrows the [`crate::port::subscriber::Subscriber`] it was received from,
+/// therefore the subscriber cannot be dropped before all of its samples are dropped. When a
+/// [`Sample`] is leaked, for instance with [`core::mem::forget()`], its chunk is returned when
+/// the subscriber is dropped and debug builds panic with the number of leaked samples.
 #[derive(Debug)]
-pub struct Sample<'subscriber, MessageType: Debug> {
+pub struct Sample<'subscriber, MessageType: Debug + ?Sized, UserHeader: Debug = ()> {
     pub(crate) subscriber: &'subscriber dyn SubscribeMgmt,
-    pub(crate) ptr: RawSample<Header, MessageType>,
-    pub(crate) channel_id: usize,
+    pub(crate) handle: &'subscriber SampleHandle,
+    pub(crate) tracing_hooks: Option<&'subscriber dyn TracingHooks<UserHeader>>,
+    pub(crate) ptr: RawSample<Header, UserHeader, MessageType>,
 }
 
-impl<MessageType: Debug> Deref for Sample<'_, MessageType> {
-    type Target = MessageType;
-    fn deref(&self) -> &Self::Target {
-        self.ptr.as_data_ref()
+// SAFETY: other threads access the subscriber only via Sample::drop() which defers the
+// release to the receiving thread, the payload is accessed read-only while clones exist
+unsafe impl<MessageType: Debug + ?Sized + Sync, UserHeader: Debug + Sync> Send
+    for Sample<'_, MessageType, UserHeader>
+{
+}
+
+unsafe impl<MessageType: Debug + ?Sized + Sync, UserHeader: Debug + Sync> Sync
+    for Sample<'_, MessageType, UserHeader>
+{
+}
+
+impl<MessageType: Debug + ?Sized, UserHeader: Debug> Clone for Sample<'_, MessageType, UserHeader> {
+    fn clone(&self) -> Self {
+        self.handle.references.fetch_add(1, Ordering::Relaxed);
+        Self {
+            subscriber: self.subscriber,
+            handle: self.handle,
+            tracing_hooks: self.tracing_hooks,
+            ptr: self.ptr,
+        }
     }
 }
 
-impl<MessageType: Debug> Drop for Sample<'_, MessageType> {
+impl<MessageType: Debug + ?Sized, UserHeader: Debug> Drop for Sample<'_, MessageType, UserHeader> {
     fn drop(&mut self) {
-        self.subscriber
-            .release_sample(self.channel_id, self.ptr.as_ptr() as usize);
+        // the subscriber reuses the handle as soon as the last reference is returned, therefore
+        // the location is read beforehand
+        let location = unsafe { *self.handle.location.get() };
+        if self.handle.references.fetch_sub(1, Ordering::AcqRel) != 1 {
+            return;
+        }
+
+        if let Some(hooks) = self.tracing_hooks {
+            hooks.on_release(self.ptr.as_header_ref(), self.ptr.as_user_header_ref());
+        }
+
+        // the subscriber is borrowed by the sample and cannot be moved, only the receiving thread
+        // has access to the connections
+        if std::thread::current().id() == location.receiving_thread {
+            self.subscriber.release_sample(
+                location.channel_id,
+                location.publisher_id,
+                location.offset,
+            );
+        } else {
+            self.subscriber.defer_release_sample(
+                location.channel_id,
+                location.publisher_id,
+                location.offset,
+            );
+        }
+    }
+}
+
+impl<MessageType: Debug + ?Sized, UserHeader: Debug> Sample<'_, MessageType, UserHeader> {
+    /// Returns a reference to the [`Header`] of the sample. It is an inherent method so that
+    /// it is not shadowed by a method of the payload the sample dereferences to.
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`crate::port::publisher::Publisher`] that sent
+    /// the sample. It can be compared with [`crate::port::publisher::Publisher::id()`].
+    pub fn origin(&self) -> UniquePublisherId {
+        self.ptr.as_header_ref().publisher_id()
+    }
+
+    /// Returns a reference to the user header of the sample.
+    pub fn user_header(&self) -> &UserHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a mutable reference to the user header of the sample when the service was
+    /// created with
+    /// [`enable_single_subscriber(true)`](crate::service::builder::publish_subscribe::Builder::enable_single_subscriber())
+    /// and the [`Sample`] was not cloned, otherwise [`None`]. The same restrictions as for
+    /// [`Sample::payload_mut()`] apply.
+    pub fn user_header_mut(&mut self) -> Option<&mut UserHeader> {
+        if !self.is_exclusively_owned() {
+            return None;
+        }
+
+        // SAFETY: see payload_mut()
+        Some(unsafe {
+            &mut (*(self.ptr.as_headers_ptr() as *mut Message<Header, UserHeader, ()>)).user_header
+        })
+    }
+
+    /// Returns a mutable reference to the payload of the sample when the service was created
+    /// with
+    /// [`enable_single_subscriber(true)`](crate::service::builder::publish_subscribe::Builder::enable_single_subscriber())
+    /// and the [`Sample`] was not cloned, otherwise [`None`].
+    ///
+    /// # Notes
+    ///
+    /// A [`crate::port::publisher::Publisher`] delivers the same memory chunk to every connected
+    /// [`crate::port::subscriber::Subscriber`] and may deliver it again from its history.
+    /// Writing into the payload would therefore alter the data that other subscribers read at
+    /// the same time which is a data race and undefined behavior. Only when the service
+    /// guarantees a single subscriber without history the sample is owned exclusively by the
+    /// receiver and can be modified safely.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/SingleSubscriber")?;
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .enable_single_subscriber(true)
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let publisher = service.publisher().create()?;
+    /// let subscriber = service.subscriber().create()?;
+    ///
+    /// publisher.send_copy(1234)?;
+    ///
+    /// while let Some(mut sample) = subscriber.receive()? {
+    ///     if let Some(payload) = sample.payload_mut() {
+    ///         *payload += 1;
+    ///     }
+    ///     println!("received: {:?}", *sample);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn payload_mut(&mut self) -> Option<&mut MessageType> {
+        if !self.is_exclusively_owned() {
+            return None;
+        }
+
+        // SAFETY: the service guarantees that the sample is delivered to exactly one subscriber,
+        // the publisher does not access the payload after it was sent and no clone exists
+        Some(unsafe { &mut *(self.ptr.as_data_ptr() as *mut MessageType) })
+    }
+
+    /// Returns the payload as bytes, exactly as they were written by the
+    /// [`crate::port::publisher::Publisher`]. It allows to forward a [`Sample`], for instance in
+    /// a gateway, without knowing its payload type. The bytes contain the padding of the payload
+    /// type as well.
+    ///
+    /// The padding bytes have unspecified values, they can contain data of samples that were
+    /// previously sent with the same chunk. They are never uninitialized memory since the
+    /// payload resides in the shared memory of the publisher which is zeroed when it is
+    /// created. When the payloads are forwarded to another trust domain, the publisher shall
+    /// zero the loaned memory before it writes the payload.
+    pub fn payload_as_bytes(&self) -> &[u8] {
+        let payload = self.ptr.as_data_ref();
+        // SAFETY: the payload was validated on receive to lie within the data segment of the
+        // publisher and stays read-only as long as the sample exists
+        unsafe {
+            core::slice::from_raw_parts(
+                (payload as *const MessageType).cast::<u8>(),
+                core::mem::size_of_val(payload),
+            )
+        }
+    }
+
+    fn is_exclusively_owned(&mut self) -> bool {
+        self.handle.references.load(Ordering::Acquire) == 1
+            && self.subscriber.has_single_subscriber()
+    }
+}
+
+impl<MessageType: Debug, UserHeader: Debug> Sample<'_, [MessageType], UserHeader> {
+    /// Returns the number of elements the [`crate::port::publisher::Publisher`] has sent, see
+    /// [`Header::number_of_elements()`].
+    pub fn len(&self) -> usize {
+        self.ptr.as_data_ref().len()
+    }
+
+    /// Returns true when the [`crate::port::publisher::Publisher`] has sent an empty slice.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 }
 
-impl<'subscriber, MessageType: Debug> Payload<MessageType> for Sample<'subscriber, MessageType> {
+impl<MessageType: Debug + ?Sized, UserHeader: Debug> Deref for Sample<'_, MessageType, UserHeader> {
+    type Target = MessageType;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
+
+impl<'subscriber, MessageType: Debug + ?Sized, UserHeader: Debug> Payload<MessageType>
+    for Sample<'subscriber, MessageType, UserHeader>
+{
     fn payload(&self) -> &MessageType {
         self.ptr.as_data_ref()
     }
 
     fn header(&self) -> &Header {
-        self.ptr.as_header_ref()
+        Sample::header(self)
     }
 }
@@ -23,11 +23,14 @@
 //! #
 //! # let publisher = service.publisher().create()?;
 //!
-//! let sample = publisher.loan_uninit()?;
-//! let sample = sample.write_payload(1234);
+//! let mut sample = publisher.loan()?;
 //!
-//! println!("timestamp: {:?}, publisher port id: {:?}",
-//!     sample.header().time_stamp(), sample.header().publisher_id());
+//! // the sample dereferences to the payload
+//! *sample = 1234;
+//! *sample += 1;
+//!
+//! println!("payload: {}, timestamp: {:?}, publisher port id: {:?}",
+//!     *sample, sample.header().time_stamp(), sample.header().publisher_id());
 //! sample.send()?;
 //!
 //! # Ok(())
@@ -38,43 +41,91 @@
 
 use crate::{
     payload_mut::{internal::PayloadMgmt, PayloadMut, UninitPayloadMut},
-    port::{publish::internal::PublishMgmt, update_connections::ConnectionFailure},
+    port::publish::{
+        internal::{LoanRelease, PublishMgmt},
+        PublisherSendError, SampleToken,
+    },
     raw_sample::RawSampleMut,
     service::header::publish_subscribe::Header,
+    zero_copy_send::ZeroCopySend,
 };
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_cal::shared_memory::*;
-use std::{fmt::Debug, mem::MaybeUninit};
+use std::{
+    fmt::Debug,
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut},
+};
 
 /// Acquired by a [`crate::port::publisher::Publisher`] via
 /// [`crate::port::publish::DefaultLoan::loan()`] or
 /// [`crate::port::publish::UninitLoan::loan_uninit()`]. It stores the payload that will be sent
 /// to all connected [`crate::port::subscriber::Subscriber`]s. If the [`SampleMut`] is not sent
-/// it will release the loaned memory when going out of scope.
+/// it will release the loaned memory when going out of scope. Samples that shall not be sent
+/// should be discarded explicitly with [`SampleMut::cancel()`], dropping them is reported as a
+/// warning in debug builds.
 ///
 /// # Notes
 ///
 /// Does not implement [`Send`] since it releases unsent samples in the [`crate::port::publisher::Publisher`] and the
-/// [`crate::port::publisher::Publisher`] is not thread-safe!
+/// [`crate::port::publisher::Publisher`] is not thread-safe! Use
+/// [`crate::port::publisher::Publisher::into_sync()`] when samples have to be handed over to other threads.
 ///
 /// The generic parameter `M` is either a `MessageType` or a [`core::mem::MaybeUninit<MessageType>`], depending
-/// which API is used to obtain the sample.
+/// which API is used to obtain the sample. Samples loaned with
+/// [`crate::port::publisher::Publisher::loan_slice_uninit()`] contain a slice of them.
+/// An initialized sample dereferences to its payload, an uninitialized one does not so that
+/// the uninitialized memory cannot be read by accident.
 #[derive(Debug)]
-pub struct SampleMut<'publisher, M: Debug> {
+pub struct SampleMut<'publisher, M: Debug + ?Sized, UserHeader: Debug = ()> {
     pub(crate) publisher: &'publisher dyn PublishMgmt,
-    ptr: RawSampleMut<Header, M>,
+    ptr: RawSampleMut<Header, UserHeader, M>,
     offset_to_chunk: PointerOffset,
+    has_user_time_stamp: bool,
+    release: LoanRelease,
+}
+
+/// Failures that can occur when the time stamp of a [`SampleMut`] is set with
+/// [`SampleMut::set_time_stamp()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+#[non_exhaustive]
+pub enum SampleMutSetTimeStampError {
+    /// The service was not created with
+    /// [`crate::service::builder::publish_subscribe::Builder::allow_user_time_stamps()`].
+    UserTimeStampsNotAllowed,
+    /// The [`Time`] was not acquired with the
+    /// [`crate::service::header::publish_subscribe::ClockType`] of the service.
+    IncompatibleClockType,
 }
 
-impl<M: Debug> Drop for SampleMut<'_, M> {
+impl std::fmt::Display for SampleMutSetTimeStampError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let hint = match self {
+            SampleMutSetTimeStampError::UserTimeStampsNotAllowed => {
+                "the service does not allow user defined time stamps"
+            }
+            SampleMutSetTimeStampError::IncompatibleClockType => {
+                "the time stamp was acquired with another clock than the one of the service"
+            }
+        };
+        std::write!(f, "{}::{:?}: {}", std::stringify!(Self), self, hint)
+    }
+}
+
+impl std::error::Error for SampleMutSetTimeStampError {}
+
+impl<M: Debug + ?Sized, UserHeader: Debug> Drop for SampleMut<'_, M, UserHeader> {
     fn drop(&mut self) {
-        self.publisher.return_loaned_sample(self.offset_to_chunk);
+        self.publisher
+            .return_loaned_sample(self.offset_to_chunk, self.release);
     }
 }
 
-impl<'publisher, MessageType: Debug> SampleMut<'publisher, MaybeUninit<MessageType>> {
+impl<'publisher, M: Debug + ?Sized, UserHeader: Debug> SampleMut<'publisher, M, UserHeader> {
     pub(crate) fn new(
         publisher: &'publisher dyn PublishMgmt,
-        ptr: RawSampleMut<Header, MaybeUninit<MessageType>>,
+        ptr: RawSampleMut<Header, UserHeader, M>,
         offset_to_chunk: PointerOffset,
     ) -> Self {
         // SAFETY: the transmute is not nice but safe since MaybeUninit is #[repr(transparent)} to the inner type
@@ -84,40 +135,506 @@ impl<'publisher, MessageType: Debug> SampleMut<'publisher, MaybeUninit<MessageTy
             publisher,
             ptr,
             offset_to_chunk,
+            has_user_time_stamp: false,
+            release: LoanRelease::Dropped,
+        }
+    }
+
+    /// Discards the sample without sending it and returns the loaned memory to the
+    /// [`crate::port::publisher::Publisher`]. The sample is counted in
+    /// [`crate::service::dynamic_config::publish_subscribe::PublisherStatistics::cancelled_samples()`]
+    /// like a sample that is dropped, but it is not reported as a possible bug.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let sample = publisher.loan_uninit()?.write_payload(1234);
+    ///
+    /// // the message is outdated, it shall not be sent
+    /// sample.cancel();
+    ///
+    /// assert_eq!(publisher.statistics().cancelled_samples(), 1);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel(mut self) {
+        self.release = LoanRelease::Cancelled;
+    }
+
+    /// Sends the sample like [`PayloadMut::send()`] and returns the [`SampleToken`] of it
+    /// instead of the number of [`crate::port::subscriber::Subscriber`]s that received it. The
+    /// token is used to wait with [`crate::port::publisher::Publisher::wait_consumed()`] until
+    /// the sample was consumed when the [`crate::port::publisher::Publisher`] was created with
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::enable_consumption_tracking()`].
+    /// A sample that failed with [`PublisherSendError::PartialDelivery`] is tracked as well,
+    /// its progress is reported by [`crate::port::publisher::Publisher::consumed_up_to()`].
+    pub fn send_with_token(mut self) -> Result<SampleToken, PublisherSendError> {
+        self.release = LoanRelease::Sent;
+        self.publisher
+            .send_with_token_impl(self.offset_to_chunk.value(), self.has_user_time_stamp)
+    }
+
+    /// Returns a reference to the user header of the sample. It is initialized with its
+    /// [`Default`] value when the sample is loaned.
+    pub fn user_header(&self) -> &UserHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a mutable reference to the user header of the sample.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// #[derive(Debug, Default, ZeroCopySend)]
+    /// #[repr(C)]
+    /// struct Priority(u8);
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/PrioritizedService")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .user_header::<Priority>()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// sample.user_header_mut().0 = 7;
+    /// *sample.payload_mut() = 1234;
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn user_header_mut(&mut self) -> &mut UserHeader {
+        self.ptr.as_user_header_mut()
+    }
+
+    /// Sets the time stamp of the [`Header`] that the subscribers receive. Otherwise it is
+    /// assigned when the sample is sent. The sequence number is not affected and is still
+    /// assigned by the [`crate::port::publisher::Publisher`]. It requires a service that was
+    /// created with
+    /// [`crate::service::builder::publish_subscribe::Builder::allow_user_time_stamps()`] and a
+    /// [`Time`] of the [`crate::service::header::publish_subscribe::ClockType`] of the
+    /// service.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_bb_posix::clock::TimeBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ReplayedService")?;
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .allow_user_time_stamps(true)
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let publisher = service.publisher().create()?;
+    ///
+    /// // the time stamp of a recorded sample
+    /// let recorded_time_stamp = TimeBuilder::new()
+    ///     .clock_type(service.static_config().clock_type().into())
+    ///     .seconds(1700000000)
+    ///     .create();
+    ///
+    /// let mut sample = publisher.loan_uninit()?.write_payload(1234);
+    /// sample.set_time_stamp(recorded_time_stamp)?;
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_time_stamp(&mut self, value: Time) -> Result<(), SampleMutSetTimeStampError> {
+        let msg = "Unable to set the time stamp of the sample";
+        if !self.publisher.allows_user_time_stamps() {
+            fail!(from self, with SampleMutSetTimeStampError::UserTimeStampsNotAllowed,
+                "{} since the service does not allow user defined time stamps.", msg);
+        }
+
+        let clock_type = self.ptr.as_header_ref().clock_type();
+        if value.clock_type() != clock_type.into() {
+            fail!(from self, with SampleMutSetTimeStampError::IncompatibleClockType,
+                "{} {:?} since the service uses the clock {:?}.", msg, value, clock_type);
         }
+
+        self.ptr.as_header_mut().set_time_stamp(value);
+        self.has_user_time_stamp = true;
+        Ok(())
+    }
+
+    /// Returns a reference to the [`Header`] of the sample. It is an inherent method so that
+    /// it is not shadowed by a method of the payload the sample dereferences to.
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    pub(crate) fn header_mut(&mut self) -> &mut Header {
+        self.ptr.as_header_mut()
     }
 }
 
-impl<'publisher, MessageType: Debug> PayloadMgmt for SampleMut<'publisher, MessageType> {
+// `MaybeUninit` does not implement `ZeroCopySend`, uninitialized samples do not dereference
+impl<M: Debug + ZeroCopySend + ?Sized, UserHeader: Debug> Deref for SampleMut<'_, M, UserHeader> {
+    type Target = M;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
+
+impl<M: Debug + ZeroCopySend + ?Sized, UserHeader: Debug> DerefMut
+    for SampleMut<'_, M, UserHeader>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.ptr.as_data_mut()
+    }
+}
+
+impl<'publisher, MessageType: Debug, UserHeader: Debug>
+    SampleMut<'publisher, MessageType, UserHeader>
+{
+    /// Turns the initialized sample back into an uninitialized one without releasing the loaned
+    /// memory chunk, so that it can be reused for another message instead of dropping and
+    /// loaning a new sample. The payload stays at the same address and the reused sample does
+    /// not count again against the
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::max_loaned_samples()`]
+    /// of the [`crate::port::publisher::Publisher`] since still only one chunk is loaned. The
+    /// user header is kept.
+    ///
+    /// The destructor of the previous payload is not called. The time stamp and the sequence
+    /// number of the [`Header`] are assigned when the sample is sent, therefore a reused
+    /// sample does not carry the values of the time when it was loaned. A time stamp that was
+    /// set with [`SampleMut::set_time_stamp()`] is discarded as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let sample = publisher.loan_uninit()?.write_payload(1234);
+    ///
+    /// // the message shall not be sent, reuse the chunk for the next one
+    /// let sample = sample.forget_payload();
+    /// let sample = sample.write_payload(5678);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn forget_payload(mut self) -> SampleMut<'publisher, MaybeUninit<MessageType>, UserHeader> {
+        self.has_user_time_stamp = false;
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<'publisher, MessageType: Debug + ?Sized, UserHeader: Debug> PayloadMgmt
+    for SampleMut<'publisher, MessageType, UserHeader>
+{
     fn offset_to_chunk(&self) -> PointerOffset {
         self.offset_to_chunk
     }
 }
 
-impl<'publisher, MessageType: Debug> UninitPayloadMut<MessageType>
-    for SampleMut<'publisher, MaybeUninit<MessageType>>
+impl<'publisher, MessageType: Debug, UserHeader: Debug> UninitPayloadMut<MessageType>
+    for SampleMut<'publisher, MaybeUninit<MessageType>, UserHeader>
 {
-    type InitializedSample = SampleMut<'publisher, MessageType>;
+    type InitializedSample = SampleMut<'publisher, MessageType, UserHeader>;
 
-    fn write_payload(mut self, value: MessageType) -> SampleMut<'publisher, MessageType> {
+    fn write_payload(
+        mut self,
+        value: MessageType,
+    ) -> SampleMut<'publisher, MessageType, UserHeader> {
         self.payload_mut().write(value);
         // SAFETY: this is safe since the payload was initialized on the line above
         unsafe { self.assume_init() }
     }
 
-    unsafe fn assume_init(self) -> SampleMut<'publisher, MessageType> {
+    unsafe fn write_from_fn<F: FnOnce(&mut MaybeUninit<MessageType>)>(
+        mut self,
+        initializer: F,
+    ) -> SampleMut<'publisher, MessageType, UserHeader> {
+        // when the initializer panics, self is dropped during unwinding and the chunk is returned
+        initializer(self.payload_mut());
+        self.assume_init()
+    }
+
+    unsafe fn assume_init(self) -> SampleMut<'publisher, MessageType, UserHeader> {
         // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
         std::mem::transmute(self)
     }
 }
 
+/// Tracks how many elements of an uninitialized slice were written so that they are dropped when
+/// the initialization is aborted, either by an error or by a panic during unwinding.
+struct InitGuard<'a, T> {
+    slice: &'a mut [MaybeUninit<T>],
+    initialized: usize,
+}
+
+impl<'a, T> InitGuard<'a, T> {
+    fn new(slice: &'a mut [MaybeUninit<T>]) -> Self {
+        Self {
+            slice,
+            initialized: 0,
+        }
+    }
+
+    fn push(&mut self, value: T) {
+        self.slice[self.initialized].write(value);
+        self.initialized += 1;
+    }
+
+    /// Hands the ownership of the written elements over to the caller.
+    fn release(self) {
+        core::mem::forget(self)
+    }
+}
+
+impl<T> Drop for InitGuard<'_, T> {
+    fn drop(&mut self) {
+        for element in &mut self.slice[..self.initialized] {
+            // SAFETY: the first `initialized` elements were written with push()
+            unsafe { element.assume_init_drop() };
+        }
+    }
+}
+
+impl<'publisher, MessageType: Debug, UserHeader: Debug>
+    SampleMut<'publisher, [MaybeUninit<MessageType>], UserHeader>
+{
+    /// Copies the elements of `value` into the payload and labels the sample as initialized.
+    /// The length of `value` must be equal to the number of elements that were requested with
+    /// [`crate::port::publisher::Publisher::loan_slice_uninit()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ByteSlice").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .max_slice_len(16)
+    /// #     .open_or_create::<[u8]>()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let sample = publisher.loan_slice_uninit(4)?;
+    /// let sample = sample.write_from_slice(&[1, 2, 3, 4]);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_slice(
+        mut self,
+        value: &[MessageType],
+    ) -> SampleMut<'publisher, [MessageType], UserHeader>
+    where
+        MessageType: Copy,
+    {
+        if self.payload().len() != value.len() {
+            fatal_panic!(from self,
+                "Unable to write the slice since it contains {} elements but the sample was loaned with {} elements.",
+                value.len(), self.payload().len());
+        }
+
+        // SAFETY: the length was verified above and MaybeUninit<T> has the same layout as T
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                value.as_ptr(),
+                self.payload_mut().as_mut_ptr() as *mut MessageType,
+                value.len(),
+            );
+            self.assume_init()
+        }
+    }
+
+    /// Moves the elements of `iter` into the payload and labels the sample as initialized. When
+    /// the iterator does not provide exactly the number of elements that were requested with
+    /// [`crate::port::publisher::Publisher::loan_slice_uninit()`], the elements written so far
+    /// are dropped and the uninitialized sample is returned as error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/IterSlice").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .max_slice_len(16)
+    /// #     .open_or_create::<[u64]>()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let sample = publisher.loan_slice_uninit(4)?;
+    /// let sample = match sample.write_from_iter((0..4u32).map(|n| (n * n) as u64)) {
+    ///     Ok(sample) => sample,
+    ///     Err(_) => panic!("the iterator provides exactly 4 elements"),
+    /// };
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_iter<I: ExactSizeIterator<Item = MessageType>>(
+        mut self,
+        mut iter: I,
+    ) -> Result<SampleMut<'publisher, [MessageType], UserHeader>, Self> {
+        if iter.len() != self.payload().len() {
+            return Err(self);
+        }
+
+        let mut guard = InitGuard::new(self.payload_mut());
+        while guard.initialized < guard.slice.len() {
+            match iter.next() {
+                Some(value) => guard.push(value),
+                // the iterator reported a wrong length, the guard drops the written elements
+                None => {
+                    drop(guard);
+                    return Err(self);
+                }
+            }
+        }
+
+        if iter.next().is_some() {
+            drop(guard);
+            return Err(self);
+        }
+
+        guard.release();
+        // SAFETY: every element was written by the guard
+        Ok(unsafe { self.assume_init() })
+    }
+
+    /// Initializes every element of the payload with the value `initializer` returns for its
+    /// index and labels the sample as initialized. When `initializer` panics, the elements that
+    /// were already written are dropped and the loaned memory is released.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/IndexSlice").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .max_slice_len(16)
+    /// #     .open_or_create::<[u64]>()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let sample = publisher.loan_slice_uninit(8)?;
+    /// let sample = sample.write_from_fn(|idx| idx as u64 * 2);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_fn<F: FnMut(usize) -> MessageType>(
+        mut self,
+        mut initializer: F,
+    ) -> SampleMut<'publisher, [MessageType], UserHeader> {
+        let mut guard = InitGuard::new(self.payload_mut());
+        while guard.initialized < guard.slice.len() {
+            let value = initializer(guard.initialized);
+            guard.push(value);
+        }
+
+        guard.release();
+        // SAFETY: every element was written by the guard
+        unsafe { self.assume_init() }
+    }
+
+    /// Labels all elements of the sample as initialized.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that every element of the slice really is initialized. Calling
+    /// this when the content is not fully initialized causes immediate undefined behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/NumberSlice").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .max_slice_len(16)
+    /// #     .open_or_create::<[u64]>()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let mut sample = publisher.loan_slice_uninit(8)?;
+    /// for (n, element) in sample.payload_mut().iter_mut().enumerate() {
+    ///     element.write(n as u64);
+    /// }
+    /// let sample = unsafe { sample.assume_init() };
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn assume_init(self) -> SampleMut<'publisher, [MessageType], UserHeader> {
+        // the transmute cannot be used since the size of the fat pointers depends on the generic
+        // type, the memory is handed over to the initialized sample without calling drop
+        let this = ManuallyDrop::new(self);
+        SampleMut {
+            publisher: this.publisher,
+            ptr: RawSampleMut::from_header_and_payload_ptrs(
+                this.ptr.as_headers_mut_ptr(),
+                this.ptr.as_data_mut_ptr() as *mut [MessageType],
+            ),
+            offset_to_chunk: this.offset_to_chunk,
+            has_user_time_stamp: this.has_user_time_stamp,
+            release: this.release,
+        }
+    }
+}
+
 impl<
         'publisher,
-        M: Debug, // `M` is either a `MessageType` or a `MaybeUninit<MessageType>`
-    > PayloadMut<M> for SampleMut<'publisher, M>
+        M: Debug + ?Sized, // `M` is either a `MessageType`, a `MaybeUninit<MessageType>` or a slice of them
+        UserHeader: Debug,
+    > PayloadMut<M> for SampleMut<'publisher, M, UserHeader>
 {
     fn header(&self) -> &Header {
-        self.ptr.as_header_ref()
+        SampleMut::header(self)
     }
 
     fn payload(&self) -> &M {
@@ -128,7 +645,9 @@ impl<
         self.ptr.as_data_mut()
     }
 
-    fn send(self) -> Result<usize, ConnectionFailure> {
-        self.publisher.send_impl(self.offset_to_chunk.value())
+    fn send(mut self) -> Result<usize, PublisherSendError> {
+        self.release = LoanRelease::Sent;
+        self.publisher
+            .send_impl(self.offset_to_chunk.value(), self.has_user_time_stamp)
     }
 }
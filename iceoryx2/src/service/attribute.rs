@@ -0,0 +1,273 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::attribute::{AttributeSpecifier, AttributeVerifier};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//!
+//! let service_creator = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe::<u64>()
+//!     .attributes(
+//!         &AttributeSpecifier::new()
+//!             .define("schema_version", "3")?
+//!             .define("owner", "vision_team")?,
+//!     )
+//!     .create()?;
+//!
+//! let service_opener = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe::<u64>()
+//!     .required_attributes(
+//!         &AttributeVerifier::new()
+//!             .require("schema_version", "3")
+//!             .require_key("owner"),
+//!     )
+//!     .open()?;
+//!
+//! for attribute in service_opener.attributes().iter() {
+//!     println!("{} = {}", attribute.key(), attribute.value());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// The maximum length in bytes of the key of an [`Attribute`].
+pub const MAX_ATTRIBUTE_KEY_LENGTH: usize = 64;
+
+/// The maximum length in bytes of the value of an [`Attribute`].
+pub const MAX_ATTRIBUTE_VALUE_LENGTH: usize = 256;
+
+/// Failures that can occur when an [`Attribute`] is defined with
+/// [`AttributeSpecifier::define()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum AttributeDefinitionError {
+    KeyIsEmpty,
+    KeyExceedsMaxLength,
+    ValueExceedsMaxLength,
+}
+
+impl std::fmt::Display for AttributeDefinitionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for AttributeDefinitionError {}
+
+/// A key-value pair that is attached to a [`crate::service::Service`] when it is created.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Attribute {
+    key: String,
+    value: String,
+}
+
+impl Attribute {
+    /// Returns the key of the [`Attribute`]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the value of the [`Attribute`]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+/// All [`Attribute`]s of a [`crate::service::Service`]. A key can have multiple values, every
+/// value is stored as separate [`Attribute`] in the order of its definition.
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct AttributeSet(Vec<Attribute>);
+
+impl AttributeSet {
+    /// Returns the number of [`Attribute`]s
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true when the [`AttributeSet`] contains no [`Attribute`]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over all [`Attribute`]s
+    pub fn iter(&self) -> impl Iterator<Item = &Attribute> {
+        self.0.iter()
+    }
+
+    /// Returns all values of the provided key. When the key is not defined the list is empty.
+    pub fn get_key_values(&self, key: &str) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+            .collect()
+    }
+
+    /// Returns true when the key has at least one value
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.iter().any(|attribute| attribute.key == key)
+    }
+
+    /// Returns true when the key has the provided value
+    pub fn contains(&self, key: &str, value: &str) -> bool {
+        self.0
+            .iter()
+            .any(|attribute| attribute.key == key && attribute.value == value)
+    }
+
+    fn push(&mut self, key: &str, value: &str) {
+        if !self.contains(key, value) {
+            self.0.push(Attribute {
+                key: key.to_string(),
+                value: value.to_string(),
+            });
+        }
+    }
+}
+
+/// Defines the [`Attribute`]s of a [`crate::service::Service`] that is created, for instance with
+/// [`crate::service::builder::publish_subscribe::Builder::attributes()`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AttributeSpecifier(AttributeSet);
+
+impl AttributeSpecifier {
+    /// Creates a new [`AttributeSpecifier`] without any [`Attribute`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a value to the key. Calling it multiple times with the same key defines multiple
+    /// values for it. Keys and values that exceed [`MAX_ATTRIBUTE_KEY_LENGTH`] or
+    /// [`MAX_ATTRIBUTE_VALUE_LENGTH`] are rejected.
+    pub fn define(mut self, key: &str, value: &str) -> Result<Self, AttributeDefinitionError> {
+        if key.is_empty() {
+            return Err(AttributeDefinitionError::KeyIsEmpty);
+        }
+
+        if key.len() > MAX_ATTRIBUTE_KEY_LENGTH {
+            return Err(AttributeDefinitionError::KeyExceedsMaxLength);
+        }
+
+        if value.len() > MAX_ATTRIBUTE_VALUE_LENGTH {
+            return Err(AttributeDefinitionError::ValueExceedsMaxLength);
+        }
+
+        self.0.push(key, value);
+        Ok(self)
+    }
+
+    /// Returns the defined [`Attribute`]s
+    pub fn attributes(&self) -> &AttributeSet {
+        &self.0
+    }
+}
+
+/// A requirement of an [`AttributeVerifier`]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum AttributeRequirement {
+    /// The key must have at least one value
+    Key(String),
+    /// The key must have the value, other values of the key are allowed
+    KeyValue(String, String),
+}
+
+impl AttributeRequirement {
+    fn is_satisfied_by(&self, attributes: &AttributeSet) -> bool {
+        match self {
+            AttributeRequirement::Key(key) => attributes.contains_key(key),
+            AttributeRequirement::KeyValue(key, value) => attributes.contains(key, value),
+        }
+    }
+}
+
+impl std::fmt::Display for AttributeRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttributeRequirement::Key(key) => std::write!(f, "{}", key),
+            AttributeRequirement::KeyValue(key, value) => std::write!(f, "{}={}", key, value),
+        }
+    }
+}
+
+/// Defines the [`Attribute`]s that an existing [`crate::service::Service`] must have so that it
+/// can be opened, for instance with
+/// [`crate::service::builder::publish_subscribe::Builder::required_attributes()`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AttributeVerifier {
+    requirements: Vec<AttributeRequirement>,
+}
+
+impl AttributeVerifier {
+    /// Creates a new [`AttributeVerifier`] without any requirement
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires that the key has the provided value
+    pub fn require(mut self, key: &str, value: &str) -> Self {
+        self.requirements.push(AttributeRequirement::KeyValue(
+            key.to_string(),
+            value.to_string(),
+        ));
+        self
+    }
+
+    /// Requires that the key has at least one value
+    pub fn require_key(mut self, key: &str) -> Self {
+        self.requirements
+            .push(AttributeRequirement::Key(key.to_string()));
+        self
+    }
+
+    /// Returns all requirements
+    pub fn requirements(&self) -> &[AttributeRequirement] {
+        &self.requirements
+    }
+
+    /// Verifies the requirements against the provided [`AttributeSet`]. On failure it returns
+    /// all requirements that are not satisfied.
+    pub fn verify_requirements(
+        &self,
+        attributes: &AttributeSet,
+    ) -> Result<(), Vec<AttributeRequirement>> {
+        let unsatisfied: Vec<AttributeRequirement> = self
+            .requirements
+            .iter()
+            .filter(|requirement| !requirement.is_satisfied_by(attributes))
+            .cloned()
+            .collect();
+
+        match unsatisfied.is_empty() {
+            true => Ok(()),
+            false => Err(unsatisfied),
+        }
+    }
+
+    // a service that does not exist is created with the required key-value pairs when it is
+    // opened or created with the verifier
+    pub(crate) fn to_specifier(&self) -> AttributeSpecifier {
+        let mut attributes = AttributeSet::default();
+        for requirement in &self.requirements {
+            if let AttributeRequirement::KeyValue(key, value) = requirement {
+                attributes.push(key, value);
+            }
+        }
+        AttributeSpecifier(attributes)
+    }
+}
@@ -14,18 +14,37 @@
 //!
 //! See [`crate::service`]
 //!
+use std::alloc::Layout;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::service::attribute::{AttributeRequirement, AttributeSpecifier, AttributeVerifier};
+use crate::service::dynamic_config::event::MAX_PAYLOAD_SIZE;
 use crate::service::messaging_pattern::MessagingPattern;
 use crate::service::port_factory::event;
+use crate::service::static_config::publish_subscribe::TypeDetails;
+use crate::service::static_config::ServicePersistence;
 use crate::service::*;
 use crate::service::{self, dynamic_config::event::DynamicConfigSettings};
-use iceoryx2_bb_elementary::enum_gen;
+use crate::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_cal::static_storage::StaticStorageCreateError;
+
+use super::{CreationWaitFailure, ServiceState};
+
+/// The maximum size in bytes of the payload that can be attached to an event, see
+/// [`Builder::event_payload()`].
+pub const MAX_EVENT_PAYLOAD_SIZE: usize = MAX_PAYLOAD_SIZE;
 
-use super::ServiceState;
+/// The largest value [`Builder::event_id_max_value()`] can be set to. Every
+/// [`crate::port::listener::Listener`] keeps one bit per [`crate::port::event_id::EventId`],
+/// larger values would require an unreasonable amount of memory.
+pub const MAX_EVENT_ID_MAX_VALUE: u64 = (1 << 24) - 1;
 
 /// Failures that can occur when an existing [`MessagingPattern::Event`] [`Service`] shall be opened.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum EventOpenError {
     DoesNotExist,
     PermissionDenied,
@@ -35,12 +54,37 @@ pub enum EventOpenError {
     HangsInCreation,
     DoesNotSupportRequestedAmountOfNotifiers,
     DoesNotSupportRequestedAmountOfListeners,
+    DoesNotSupportRequestedMaxEventId,
+    IncompatibleEventPayloadType,
+    /// The service does not satisfy the listed requirements of the
+    /// [`AttributeVerifier`].
+    IncompatibleAttributes(Vec<AttributeRequirement>),
     UnableToOpenDynamicServiceInformation,
 }
 
 impl std::fmt::Display for EventOpenError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+        let hint = match self {
+            EventOpenError::DoesNotExist => "no service with this name exists, create it first or use open_or_create()",
+            EventOpenError::PermissionDenied => "the process lacks the permissions to access the service",
+            EventOpenError::EventInCorruptedState => "the service resources are corrupted, remove the stale resources of the service",
+            EventOpenError::IncompatibleMessagingPattern => "the service exists with another messaging pattern",
+            EventOpenError::InternalFailure => "an internal failure occurred, check the log output for details",
+            EventOpenError::HangsInCreation => "another process did not finish the creation of the service in time, it may have crashed during creation",
+            EventOpenError::DoesNotSupportRequestedAmountOfNotifiers => "the service supports fewer notifiers than requested",
+            EventOpenError::DoesNotSupportRequestedAmountOfListeners => "the service supports fewer listeners than requested",
+            EventOpenError::DoesNotSupportRequestedMaxEventId => "the service supports a smaller max event id than requested",
+            EventOpenError::IncompatibleEventPayloadType => "the service exists with a different event payload type, use the type it was created with",
+            EventOpenError::IncompatibleAttributes(unsatisfied) => {
+                std::write!(f, "{}::IncompatibleAttributes: the service does not have the required attributes", std::stringify!(Self))?;
+                for (n, requirement) in unsatisfied.iter().enumerate() {
+                    std::write!(f, "{} {}", if n == 0 { ":" } else { "," }, requirement)?;
+                }
+                return Ok(());
+            }
+            EventOpenError::UnableToOpenDynamicServiceInformation => "the dynamic service information could not be opened, the service may be in the process of being removed",
+        };
+        std::write!(f, "{}::{:?}: {}", std::stringify!(Self), self, hint)
     }
 }
 
@@ -48,6 +92,7 @@ impl std::error::Error for EventOpenError {}
 
 /// Failures that can occur when a new [`MessagingPattern::Event`] [`Service`] shall be created.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum EventCreateError {
     Corrupted,
     InternalFailure,
@@ -55,23 +100,47 @@ pub enum EventCreateError {
     AlreadyExists,
     PermissionDenied,
     UnableToCreateStaticServiceInformation,
+    EventPayloadExceedsMaxSize,
+    EventIdMaxValueExceedsLimit,
 }
 
 impl std::fmt::Display for EventCreateError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+        let hint = match self {
+            EventCreateError::Corrupted => "leftovers of an old instance of the service exist, remove the stale resources of the service",
+            EventCreateError::InternalFailure => "an internal failure occurred, check the log output for details",
+            EventCreateError::IsBeingCreatedByAnotherInstance => "another process creates the service right now, open it once the creation is finished",
+            EventCreateError::AlreadyExists => "a service with this name already exists, open it or use open_or_create()",
+            EventCreateError::PermissionDenied => "the process lacks the permissions to create the service resources",
+            EventCreateError::UnableToCreateStaticServiceInformation => "the static service information could not be written, check the permissions of the service directory",
+            EventCreateError::EventPayloadExceedsMaxSize => "the event payload is larger than MAX_EVENT_PAYLOAD_SIZE bytes or the event id max value is too large to store a payload for every event id",
+            EventCreateError::EventIdMaxValueExceedsLimit => "the event id max value is larger than MAX_EVENT_ID_MAX_VALUE",
+        };
+        std::write!(f, "{}::{:?}: {}", std::stringify!(Self), self, hint)
     }
 }
 
 impl std::error::Error for EventCreateError {}
 
-enum_gen! {
-    /// Failures that can occur when a [`MessagingPattern::Event`] [`Service`] shall be opened or
-    /// created.
-    EventOpenOrCreateError
-  mapping:
-    EventOpenError,
-    EventCreateError
+/// Failures that can occur when a [`MessagingPattern::Event`] [`Service`] shall be opened or
+/// created.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventOpenOrCreateError {
+    EventOpenError(EventOpenError),
+    EventCreateError(EventCreateError),
+}
+
+impl From<EventOpenError> for EventOpenOrCreateError {
+    fn from(value: EventOpenError) -> Self {
+        EventOpenOrCreateError::EventOpenError(value)
+    }
+}
+
+impl From<EventCreateError> for EventOpenOrCreateError {
+    fn from(value: EventCreateError) -> Self {
+        EventOpenOrCreateError::EventCreateError(value)
+    }
 }
 
 impl std::fmt::Display for EventOpenOrCreateError {
@@ -88,10 +157,12 @@ impl std::error::Error for EventOpenOrCreateError {}
 ///
 /// See [`crate::service`]
 #[derive(Debug)]
-pub struct Builder<'config, ServiceType: service::Details<'config>> {
+pub struct Builder<'config, ServiceType: service::Details<'config>, EventPayload: Debug = ()> {
     base: builder::BuilderWithServiceType<'config, ServiceType>,
     verify_max_notifiers: bool,
     verify_max_listeners: bool,
+    verify_event_id_max_value: bool,
+    _phantom_event_payload: PhantomData<EventPayload>,
 }
 
 impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceType> {
@@ -100,6 +171,8 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
             base,
             verify_max_notifiers: false,
             verify_max_listeners: false,
+            verify_event_id_max_value: false,
+            _phantom_event_payload: PhantomData,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::Event(
@@ -108,6 +181,58 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
 
         new_self
     }
+}
+
+impl<'config, ServiceType: service::Details<'config>, EventPayload: Debug>
+    Builder<'config, ServiceType, EventPayload>
+{
+    /// Defines the payload that a [`crate::port::notifier::Notifier`] can attach to a
+    /// notification with [`crate::port::notifier::Notifier::notify_with_payload()`]. The payload
+    /// must not be larger than [`MAX_EVENT_PAYLOAD_SIZE`] bytes, otherwise the creation fails
+    /// with [`EventCreateError::EventPayloadExceedsMaxSize`]. An existing [`Service`] can only be
+    /// opened with the event payload type it was created with.
+    ///
+    /// The service stores one payload per [`crate::port::event_id::EventId`], the required
+    /// memory grows with the
+    /// [`crate::service::static_config::event::StaticConfig::event_id_max_value()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// #[derive(Debug, Clone, Copy, ZeroCopySend)]
+    /// #[repr(C)]
+    /// struct ErrorReport {
+    ///     error_code: u64,
+    ///     counter: u64,
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let event_name = ServiceName::new("My/Funk/ErrorEvent")?;
+    /// let event = zero_copy::Service::new(&event_name)
+    ///     .event()
+    ///     .event_id_max_value(16)
+    ///     .event_payload::<ErrorReport>()
+    ///     .open_or_create()?;
+    ///
+    /// let notifier = event.notifier().create()?;
+    /// notifier.notify_with_payload(EventId::new(3), ErrorReport { error_code: 42, counter: 1 })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn event_payload<P: ZeroCopySend + Debug + Copy>(self) -> Builder<'config, ServiceType, P> {
+        let mut new_self = Builder {
+            base: self.base,
+            verify_max_notifiers: self.verify_max_notifiers,
+            verify_max_listeners: self.verify_max_listeners,
+            verify_event_id_max_value: self.verify_event_id_max_value,
+            _phantom_event_payload: PhantomData,
+        };
+
+        new_self.config_details().payload_type_details = TypeDetails::new::<P>(Layout::new::<P>());
+        new_self
+    }
 
     fn config_details(&mut self) -> &mut static_config::event::StaticConfig {
         match self.base.service_config.messaging_pattern {
@@ -118,6 +243,15 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         }
     }
 
+    /// If the [`Service`] is created it defines whether it is removed when its last instance
+    /// is dropped or whether it persists until it is removed explicitly with
+    /// [`crate::service::Details::remove()`]. If an existing [`Service`] is opened the setting
+    /// is ignored, the service keeps the persistence it was created with.
+    pub fn persistence(mut self, value: ServicePersistence) -> Self {
+        self.base.service_config.persistence = value;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::port::notifier::Notifier`] shall
     /// be supported at most. If an existing [`Service`] is opened it defines how many
     /// [`crate::port::notifier::Notifier`] must be at least supported.
@@ -136,35 +270,95 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         self
     }
 
+    /// If the [`Service`] is created it defines the largest value an
+    /// [`crate::port::event_id::EventId`] can have. If an existing [`Service`] is opened it
+    /// defines the largest [`crate::port::event_id::EventId`] value that must be at least
+    /// supported. The creation fails with [`EventCreateError::EventIdMaxValueExceedsLimit`]
+    /// when the value is larger than [`MAX_EVENT_ID_MAX_VALUE`].
+    pub fn event_id_max_value(mut self, value: u64) -> Self {
+        self.config_details().event_id_max_value = value;
+        self.verify_event_id_max_value = true;
+        self
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(
         self,
-    ) -> Result<event::PortFactory<'config, ServiceType>, EventOpenOrCreateError> {
-        let msg = "Unable to open or create event service";
+    ) -> Result<event::PortFactory<'config, ServiceType, EventPayload>, EventOpenOrCreateError>
+    {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
 
-        match self.base.is_service_available() {
-            Ok(Some(_)) => Ok(self.open()?),
-            Ok(None) => Ok(self.create()?),
-            Err(ServiceState::IsBeingCreatedByAnotherInstance) => Ok(self.open()?),
-            Err(ServiceState::Corrupted) => {
-                fail!(from self, with EventOpenOrCreateError::EventOpenError(EventOpenError::EventInCorruptedState),
-                    "{} since the event is in a corrupted state.", msg);
-            }
-            Err(ServiceState::IncompatibleMessagingPattern) => {
-                fail!(from self, with EventOpenOrCreateError::EventOpenError(EventOpenError::IncompatibleMessagingPattern),
-                    "{} since the services messaging pattern does not match.", msg);
-            }
-            Err(ServiceState::PermissionDenied) => {
-                fail!(from self, with EventOpenOrCreateError::EventOpenError(EventOpenError::PermissionDenied),
-                    "{} due to insufficient permissions.", msg);
+    /// If the [`Service`] exists, it will be opened when it satisfies the requirements of the
+    /// [`AttributeVerifier`]. Otherwise a new [`Service`] will be created, its attributes are
+    /// the required key-value pairs of the [`AttributeVerifier`].
+    pub fn open_or_create_with_attributes(
+        self,
+        attributes: &AttributeVerifier,
+    ) -> Result<event::PortFactory<'config, ServiceType, EventPayload>, EventOpenOrCreateError>
+    {
+        let msg = format!(
+            "Unable to open or create event service \"{}\"",
+            self.base.service_config.service_name()
+        );
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with EventOpenOrCreateError::EventOpenError(EventOpenError::InternalFailure),
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.base.is_service_available() {
+                Ok(Some(_)) => return Ok(self.open_with_attributes(attributes)?),
+                Ok(None) => return Ok(self.create_with_attributes(&attributes.to_specifier())?),
+                Err(ServiceState::IsBeingCreatedByAnotherInstance) => {
+                    // the service is opened as soon as the creation is finished, when the creator
+                    // crashed the service is created again
+                    match self.base.wait_for_creation(&mut adaptive_wait) {
+                        Ok(()) => (),
+                        Err(CreationWaitFailure::HangsInCreation) => {
+                            fail!(from self, with EventOpenOrCreateError::EventOpenError(EventOpenError::HangsInCreation),
+                                "{} since the service hangs while being created.", msg);
+                        }
+                        Err(CreationWaitFailure::InternalFailure) => {
+                            fail!(from self, with EventOpenOrCreateError::EventOpenError(EventOpenError::InternalFailure),
+                                "{} since the wait for the creation of the service failed.", msg);
+                        }
+                    }
+                }
+                Err(ServiceState::Corrupted) => {
+                    fail!(from self, with EventOpenOrCreateError::EventOpenError(EventOpenError::EventInCorruptedState),
+                        "{} since the event is in a corrupted state.", msg);
+                }
+                Err(ServiceState::IncompatibleMessagingPattern) => {
+                    fail!(from self, with EventOpenOrCreateError::EventOpenError(EventOpenError::IncompatibleMessagingPattern),
+                        "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceState::PermissionDenied) => {
+                    fail!(from self, with EventOpenOrCreateError::EventOpenError(EventOpenError::PermissionDenied),
+                        "{} due to insufficient permissions.", msg);
+                }
             }
         }
     }
 
     /// Opens an existing [`Service`].
-    pub fn open(mut self) -> Result<event::PortFactory<'config, ServiceType>, EventOpenError> {
-        let msg = "Unable to open event service";
+    pub fn open(
+        self,
+    ) -> Result<event::PortFactory<'config, ServiceType, EventPayload>, EventOpenError> {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] when its attributes satisfy all requirements of the
+    /// [`AttributeVerifier`], otherwise it fails with [`EventOpenError::IncompatibleAttributes`].
+    pub fn open_with_attributes(
+        mut self,
+        attributes: &AttributeVerifier,
+    ) -> Result<event::PortFactory<'config, ServiceType, EventPayload>, EventOpenError> {
+        let msg = format!(
+            "Unable to open event service \"{}\"",
+            self.base.service_config.service_name()
+        );
 
         let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
                                         with EventOpenError::InternalFailure,
@@ -177,14 +371,44 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                         "{} since the event does not exist.", msg);
                 }
                 Ok(Some((static_config, static_storage))) => {
+                    if let Err(unsatisfied) =
+                        attributes.verify_requirements(static_config.attributes())
+                    {
+                        let requirements = unsatisfied
+                            .iter()
+                            .map(|r| r.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        fail!(from self, with EventOpenError::IncompatibleAttributes(unsatisfied),
+                            "{} since the event does not satisfy the attribute requirements [{}].",
+                            msg, requirements);
+                    }
+
+                    let existing_attributes = static_config.attributes().clone();
+                    let existing_persistence = static_config.persistence();
+                    let existing_instance_uuid = static_config.instance_uuid().to_string();
                     let static_config = self.verify_service_properties(&static_config)?;
 
-                    let dynamic_config = fail!(from self, when self.base.open_dynamic_config_storage(),
-                            with EventOpenError::UnableToOpenDynamicServiceInformation,
-                            "{} since the dynamic service informations could not be opened.", msg);
+                    let (dynamic_config, owner_index) = match self
+                        .base
+                        .open_dynamic_config_storage()
+                    {
+                        Ok(v) => v,
+                        Err(e) if e.is_service_removed() => {
+                            fail!(from self, with EventOpenError::DoesNotExist,
+                                "{} since the service was removed while it was opened.", msg);
+                        }
+                        Err(e) => {
+                            fail!(from self, with EventOpenError::UnableToOpenDynamicServiceInformation,
+                                "{} since the dynamic service information could not be opened ({:?}).", msg, e);
+                        }
+                    };
 
                     self.base.service_config.messaging_pattern =
                         MessagingPattern::Event(static_config);
+                    self.base.service_config.attributes = existing_attributes;
+                    self.base.service_config.persistence = existing_persistence;
+                    self.base.service_config.instance_uuid = existing_instance_uuid;
 
                     return Ok(event::PortFactory::new(ServiceType::from_state(
                         service::ServiceState::new(
@@ -192,18 +416,23 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                             self.base.global_config,
                             dynamic_config,
                             static_storage,
+                            owner_index,
+                            self.base.node_id,
+                            service::discovery::announce_change::<ServiceType>,
                         ),
                     )));
                 }
                 Err(ServiceState::IsBeingCreatedByAnotherInstance) => {
-                    let timeout = fail!(from self, when adaptive_wait.wait(),
-                                        with EventOpenError::InternalFailure,
-                                        "{} since the adaptive wait failed.", msg);
-
-                    if timeout > self.base.global_config.global.service.creation_timeout {
-                        fail!(from self, with EventOpenError::HangsInCreation,
-                            "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded. Waited for {:?} but the state did not change.",
-                            msg, self.base.global_config.global.service.creation_timeout, timeout);
+                    match self.base.wait_for_creation(&mut adaptive_wait) {
+                        Ok(()) => (),
+                        Err(CreationWaitFailure::HangsInCreation) => {
+                            fail!(from self, with EventOpenError::HangsInCreation,
+                                "{} since the service hangs while being created.", msg);
+                        }
+                        Err(CreationWaitFailure::InternalFailure) => {
+                            fail!(from self, with EventOpenError::InternalFailure,
+                                "{} since the wait for the creation of the service failed.", msg);
+                        }
                     }
                 }
                 Err(ServiceState::PermissionDenied) => {
@@ -223,21 +452,68 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
     }
 
     /// Creates a new [`Service`].
-    pub fn create(mut self) -> Result<event::PortFactory<'config, ServiceType>, EventCreateError> {
+    pub fn create(
+        self,
+    ) -> Result<event::PortFactory<'config, ServiceType, EventPayload>, EventCreateError> {
+        self.create_with_attributes(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`] with the attributes of the [`AttributeSpecifier`].
+    pub fn create_with_attributes(
+        mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<event::PortFactory<'config, ServiceType, EventPayload>, EventCreateError> {
         self.adjust_properties_to_meaningful_values();
+        self.base.service_config.attributes = attributes.attributes().clone();
 
-        let msg = "Unable to create event service";
+        let msg = format!(
+            "Unable to create event service \"{}\"",
+            self.base.service_config.service_name()
+        );
 
         match self.base.is_service_available() {
             Ok(None) => {
+                let event_config = self.base.service_config.event();
+                let payload_size = event_config.payload_type_details.size;
+                let number_of_payload_slots = match event_config.has_event_payload() {
+                    false => Some(0),
+                    true => event_config
+                        .event_id_max_value
+                        .checked_add(1)
+                        .and_then(|v| usize::try_from(v).ok()),
+                };
+
+                if event_config.event_id_max_value > MAX_EVENT_ID_MAX_VALUE {
+                    fail!(from self, with EventCreateError::EventIdMaxValueExceedsLimit,
+                        "{} since the event id max value of {} exceeds the supported maximum of {}.",
+                        msg, event_config.event_id_max_value, MAX_EVENT_ID_MAX_VALUE);
+                }
+
+                if payload_size > MAX_EVENT_PAYLOAD_SIZE {
+                    fail!(from self, with EventCreateError::EventPayloadExceedsMaxSize,
+                        "{} since the event payload has a size of {} bytes but at most {} bytes are supported.",
+                        msg, payload_size, MAX_EVENT_PAYLOAD_SIZE);
+                }
+
+                let number_of_payload_slots = match number_of_payload_slots {
+                    Some(v) => v,
+                    None => {
+                        fail!(from self, with EventCreateError::EventPayloadExceedsMaxSize,
+                            "{} since a payload slot for every event id up to {} cannot be provided.",
+                            msg, event_config.event_id_max_value);
+                    }
+                };
+
                 let static_config = fail!(from self, when self.base.create_static_config_storage(),
-                    with EventCreateError::UnableToCreateStaticServiceInformation,
+                    map StaticStorageCreateError::AlreadyExists => EventCreateError::AlreadyExists,
+                    unmatched EventCreateError::UnableToCreateStaticServiceInformation,
                     "{} since the static service information could not be created.", msg);
                 let event_config = self.base.service_config.event();
 
                 let dynamic_config_setting = DynamicConfigSettings {
                     number_of_listeners: event_config.max_listeners,
                     number_of_notifiers: event_config.max_notifiers,
+                    number_of_payload_slots,
                 };
 
                 let dynamic_config = self.base.create_dynamic_config_storage(
@@ -246,7 +522,7 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                     ),
                     dynamic_config::event::DynamicConfig::memory_size(&dynamic_config_setting),
                 );
-                let dynamic_config = fail!(from self, when dynamic_config,
+                let (dynamic_config, owner_index) = fail!(from self, when dynamic_config,
                     with EventCreateError::InternalFailure,
                     "{} since the dynamic service segment could not be created.", msg);
 
@@ -259,14 +535,19 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                             with EventCreateError::Corrupted,
                             "{} since the configuration could not be written to the static storage.", msg);
 
-                return Ok(event::PortFactory::new(ServiceType::from_state(
+                service::discovery::announce_change::<ServiceType>(self.base.global_config);
+
+                Ok(event::PortFactory::new(ServiceType::from_state(
                     service::ServiceState::new(
                         self.base.service_config.clone(),
                         self.base.global_config,
                         dynamic_config,
                         unlocked_static_details,
+                        owner_index,
+                        self.base.node_id,
+                        service::discovery::announce_change::<ServiceType>,
                     ),
-                )));
+                )))
             }
             Ok(Some(_)) | Err(ServiceState::IncompatibleMessagingPattern) => {
                 fail!(from self, with EventCreateError::AlreadyExists,
@@ -306,7 +587,10 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         &self,
         existing_settings: &static_config::StaticConfig,
     ) -> Result<static_config::event::StaticConfig, EventOpenError> {
-        let msg = "Unable to open event";
+        let msg = format!(
+            "Unable to open event \"{}\"",
+            self.base.service_config.service_name()
+        );
 
         let required_settings = self.base.service_config.event();
         let existing_settings = match &existing_settings.messaging_pattern {
@@ -333,6 +617,20 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                 msg, existing_settings.max_notifiers, existing_settings.max_listeners);
         }
 
-        Ok(*existing_settings)
+        if self.verify_event_id_max_value
+            && existing_settings.event_id_max_value < required_settings.event_id_max_value
+        {
+            fail!(from self, with EventOpenError::DoesNotSupportRequestedMaxEventId,
+                "{} since the event supports only event ids up to {} but a support of event ids up to {} was requested.",
+                msg, existing_settings.event_id_max_value, required_settings.event_id_max_value);
+        }
+
+        if existing_settings.payload_type_details != required_settings.payload_type_details {
+            fail!(from self, with EventOpenError::IncompatibleEventPayloadType,
+                "{} since the event has the payload type {:?} but the payload type {:?} was requested.",
+                msg, existing_settings.payload_type_details, required_settings.payload_type_details);
+        }
+
+        Ok(existing_settings.clone())
     }
 }
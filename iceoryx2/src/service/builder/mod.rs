@@ -20,14 +20,23 @@ pub mod event;
 /// Builder for [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 pub mod publish_subscribe;
 
+/// Builder for [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+pub mod request_response;
+
 use crate::config;
+use crate::node::NodeId;
 use crate::service;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
+use crate::testing::{abort_service_creation_if_requested, ServiceCreationStep};
+use crate::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWait;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::dynamic_storage::DynamicStorageOpenError;
@@ -37,6 +46,7 @@ use iceoryx2_cal::named_concept::NamedConceptDoesExistError;
 use iceoryx2_cal::named_concept::NamedConceptMgmt;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::*;
+use std::fmt::Debug;
 use std::marker::PhantomData;
 
 use super::config_scheme::dynamic_config_storage_config;
@@ -44,6 +54,7 @@ use super::config_scheme::static_config_storage_config;
 use super::naming_scheme::dynamic_config_storage_name;
 use super::naming_scheme::static_config_storage_name;
 use super::service_name::ServiceName;
+use super::stale_resources::remove_abandoned_creation;
 use super::Service;
 
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -54,11 +65,19 @@ enum ServiceState {
     Corrupted,
 }
 
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum CreationWaitFailure {
+    HangsInCreation,
+    InternalFailure,
+}
+
 enum_gen! {
 #[doc(hidden)]
     OpenDynamicStorageFailure
   entry:
-    IsMarkedForDestruction
+    IsMarkedForDestruction,
+    ExceedsMaxNumberOfOwners,
+    InternalError
   mapping:
     DynamicStorageOpenError
 }
@@ -71,6 +90,20 @@ impl std::fmt::Display for OpenDynamicStorageFailure {
 
 impl std::error::Error for OpenDynamicStorageFailure {}
 
+impl OpenDynamicStorageFailure {
+    /// Returns true when the service was removed while it was opened, the opener shall treat
+    /// it as if it never existed.
+    pub(crate) fn is_service_removed(&self) -> bool {
+        matches!(
+            self,
+            OpenDynamicStorageFailure::IsMarkedForDestruction
+                | OpenDynamicStorageFailure::DynamicStorageOpenError(
+                    DynamicStorageOpenError::DoesNotExist
+                )
+        )
+    }
+}
+
 enum_gen! {
 #[doc(hidden)]
     ReadStaticStorageFailure
@@ -95,6 +128,7 @@ impl std::error::Error for ReadStaticStorageFailure {}
 #[derive(Debug)]
 pub struct Builder<S: Service> {
     name: ServiceName,
+    node_id: Option<NodeId>,
     _phantom_s: PhantomData<S>,
 }
 
@@ -102,30 +136,40 @@ impl<S: Service> Builder<S> {
     pub(crate) fn new(name: &ServiceName) -> Self {
         Self {
             name: *name,
+            node_id: None,
             _phantom_s: PhantomData,
         }
     }
 
+    /// The service is created or opened by the [`crate::node::Node`] with the given id
+    pub(crate) fn node_id(mut self, value: NodeId) -> Self {
+        self.node_id = Some(value);
+        self
+    }
+
     /// Create a new builder to create a
-    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`].
-    pub fn publish_subscribe<'config>(
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`]
+    /// with the payload type `Payload`. When it is omitted, it is inferred from the terminal
+    /// call of the [`publish_subscribe::Builder`].
+    pub fn publish_subscribe<'config, Payload: Debug + ?Sized>(
         self,
-    ) -> publish_subscribe::Builder<'config, S::Type<'config>> {
+    ) -> publish_subscribe::Builder<'config, S::Type<'config>, Payload> {
         self.publish_subscribe_with_custom_config(config::Config::get_global_config())
     }
 
     /// Create a new builder to create a
     /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`].
     /// with a custom [`config::Config`]
-    pub fn publish_subscribe_with_custom_config(
+    pub fn publish_subscribe_with_custom_config<Payload: Debug + ?Sized>(
         self,
         config: &config::Config,
-    ) -> publish_subscribe::Builder<'_, S::Type<'_>> {
+    ) -> publish_subscribe::Builder<'_, S::Type<'_>, Payload> {
         BuilderWithServiceType::new(
             StaticConfig::new_publish_subscribe::<
                 <<S as service::Service>::Type<'_> as service::Details<'_>>::ServiceNameHasher,
             >(&self.name, config),
             config,
+            self.node_id,
         )
         .publish_subscribe()
     }
@@ -148,9 +192,44 @@ impl<S: Service> Builder<S> {
                 <<S as service::Service>::Type<'_> as service::Details<'_>>::ServiceNameHasher,
             >(&self.name, config),
             config,
+            self.node_id,
         )
         .event()
     }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`]
+    /// whose [`crate::port::client::Client`]s send a `RequestPayload` and whose
+    /// [`crate::port::server::Server`]s answer with `ResponsePayload`s.
+    pub fn request_response<
+        'config,
+        RequestPayload: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend,
+    >(
+        self,
+    ) -> request_response::Builder<'config, S::Type<'config>, RequestPayload, ResponsePayload> {
+        self.request_response_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
+    /// with a custom [`config::Config`]
+    pub fn request_response_with_custom_config<
+        RequestPayload: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend,
+    >(
+        self,
+        config: &config::Config,
+    ) -> request_response::Builder<'_, S::Type<'_>, RequestPayload, ResponsePayload> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_request_response::<
+                <<S as service::Service>::Type<'_> as service::Details<'_>>::ServiceNameHasher,
+            >(&self.name, config),
+            config,
+            self.node_id,
+        )
+        .request_response()
+    }
 }
 
 #[doc(hidden)]
@@ -158,21 +237,29 @@ impl<S: Service> Builder<S> {
 pub struct BuilderWithServiceType<'config, ServiceType: service::Details<'config>> {
     service_config: StaticConfig,
     global_config: &'config config::Config,
+    node_id: Option<NodeId>,
     _phantom_data: PhantomData<ServiceType>,
     _phantom_lifetime_b: PhantomData<&'config ()>,
 }
 
 impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'config, ServiceType> {
-    fn new(service_config: StaticConfig, global_config: &'config config::Config) -> Self {
+    fn new(
+        service_config: StaticConfig,
+        global_config: &'config config::Config,
+        node_id: Option<NodeId>,
+    ) -> Self {
         Self {
             service_config,
             global_config,
+            node_id,
             _phantom_data: PhantomData,
             _phantom_lifetime_b: PhantomData,
         }
     }
 
-    fn publish_subscribe(self) -> publish_subscribe::Builder<'config, ServiceType> {
+    fn publish_subscribe<Payload: Debug + ?Sized>(
+        self,
+    ) -> publish_subscribe::Builder<'config, ServiceType, Payload> {
         publish_subscribe::Builder::new(self)
     }
 
@@ -180,6 +267,15 @@ impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'co
         event::Builder::new(self)
     }
 
+    fn request_response<
+        RequestPayload: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend,
+    >(
+        self,
+    ) -> request_response::Builder<'config, ServiceType, RequestPayload, ResponsePayload> {
+        request_response::Builder::new(self)
+    }
+
     fn is_service_available(
         &self,
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceState> {
@@ -206,6 +302,14 @@ impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'co
                                         .config(&static_storage_config)
                                         .open() { v }
                 else {
+                    // the service was removed after its existence was checked
+                    if let Ok(false) = <ServiceType::StaticStorage as NamedConceptMgmt>::does_exist_cfg(
+                        &file_name_uuid,
+                        &static_storage_config,
+                    ) {
+                        return Ok(None);
+                    }
+
                     fail!(from self, with ServiceState::PermissionDenied,
                             "{} since it is not possible to open the services underlying static details. Is the service accessible?", msg);
                 };
@@ -249,31 +353,51 @@ impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'co
         &self,
         messaging_pattern: super::dynamic_config::MessagingPattern,
         additional_size: usize,
-    ) -> Result<ServiceType::DynamicStorage, DynamicStorageCreateError> {
+    ) -> Result<(ServiceType::DynamicStorage, u32), DynamicStorageCreateError> {
+        let msg = "Failed to create dynamic storage for service";
+        let max_owners = self.global_config.global.service.max_owners;
+        let owner_id = fail!(from self, when UniqueSystemId::new(),
+                with DynamicStorageCreateError::InternalError,
+                "{} since the unique id of the service owner could not be generated.", msg);
+
+        // the creator is registered as owner before the storage is marked as initialized, a
+        // process that dies in between leaves an uninitialized storage behind
+        let mut owner_index = None;
         match <<ServiceType::DynamicStorage as DynamicStorage<
             DynamicConfig,
         >>::Builder as NamedConceptBuilder<
             ServiceType::DynamicStorage,
         >>::new(&dynamic_config_storage_name(&self.service_config))
             .config(&dynamic_config_storage_config::<ServiceType>(self.global_config))
-            .supplementary_size(additional_size)
+            .supplementary_size(additional_size + DynamicConfig::memory_size(max_owners))
             .has_ownership(false)
-            .create_and_initialize(DynamicConfig::new_uninit(messaging_pattern),
+            .create_and_initialize(DynamicConfig::new_uninit(messaging_pattern, max_owners, self.service_config.persistence()),
                 |config, allocator| {
                     unsafe { config.init(allocator) };
-                    true
+                    owner_index = config.register_owner(owner_id);
+                    abort_service_creation_if_requested(ServiceCreationStep::DynamicConfigInitialization);
+                    owner_index.is_some()
                 }
                 ) {
-                Ok(dynamic_storage) => Ok(dynamic_storage),
+                Ok(dynamic_storage) => match owner_index {
+                    Some(owner_index) => {
+                        abort_service_creation_if_requested(ServiceCreationStep::DynamicConfigCreated);
+                        Ok((dynamic_storage, owner_index))
+                    }
+                    None => {
+                        fail!(from self, with DynamicStorageCreateError::InitializationFailed,
+                            "{} since the creator could not be registered as owner.", msg);
+                    }
+                },
                 Err(e) => {
-                    fail!(from self, with e, "Failed to create dynamic storage for service.");
+                    fail!(from self, with e, "{}.", msg);
                 }
             }
     }
 
     fn open_dynamic_config_storage(
         &self,
-    ) -> Result<ServiceType::DynamicStorage, OpenDynamicStorageFailure> {
+    ) -> Result<(ServiceType::DynamicStorage, u32), OpenDynamicStorageFailure> {
         let msg = "Failed to open dynamic service information";
         let storage = fail!(from self, when
             <<ServiceType::DynamicStorage as DynamicStorage<
@@ -286,11 +410,26 @@ impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'co
                 .open(),
             "{} since the dynamic storage could not be opened.", msg);
 
-        fail!(from self, when storage.get().increment_reference_counter(),
-                with OpenDynamicStorageFailure::IsMarkedForDestruction,
+        let owner_id = fail!(from self, when UniqueSystemId::new(),
+                with OpenDynamicStorageFailure::InternalError,
+                "{} since the unique id of the service owner could not be generated.", msg);
+
+        let owner_index = match storage.get().register_owner(owner_id) {
+            Some(index) => index,
+            None => {
+                fail!(from self, with OpenDynamicStorageFailure::ExceedsMaxNumberOfOwners,
+                    "{} since it would exceed the maximum number of {} service owners.",
+                    msg, self.global_config.global.service.max_owners);
+            }
+        };
+
+        if storage.get().increment_reference_counter().is_err() {
+            storage.get().deregister_owner(owner_index);
+            fail!(from self, with OpenDynamicStorageFailure::IsMarkedForDestruction,
                 "{} since the dynamic storage is marked for destruction.", msg);
+        }
 
-        Ok(storage)
+        Ok((storage, owner_index))
     }
 
     fn create_static_config_storage(
@@ -299,8 +438,7 @@ impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'co
         <<ServiceType as service::Details<'config>>::StaticStorage as StaticStorage>::Locked,
         StaticStorageCreateError,
     > {
-        Ok(
-            fail!(from self, when <<ServiceType::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+        let storage = fail!(from self, when <<ServiceType::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
                         <ServiceType as service::Details>::StaticStorage,
                     >>::new(&static_config_storage_name(self.service_config.uuid()))
                     .config(&static_config_storage_config::<ServiceType>(
@@ -308,7 +446,100 @@ impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'co
                     ))
                     .has_ownership(false)
                     .create_locked(),
-                    "Failed to create static service information since the underlying static storage could not be created."),
-        )
+                    "Failed to create static service information since the underlying static storage could not be created.");
+
+        abort_service_creation_if_requested(ServiceCreationStep::StaticConfigCreated);
+        Ok(storage)
+    }
+
+    /// Waits for another process to finish the creation of the service. The static details are
+    /// written last, when they are not finalized within the
+    /// [`config::Service::creation_timeout`] the creator crashed during the creation. Its
+    /// remainders are removed so that the next lookup does not find the service and it can
+    /// be created again.
+    fn wait_for_creation(
+        &self,
+        adaptive_wait: &mut AdaptiveWait,
+    ) -> Result<(), CreationWaitFailure> {
+        let msg = "Unable to wait for the creation of the service";
+        let timeout = fail!(from self, when adaptive_wait.wait(),
+                            with CreationWaitFailure::InternalFailure,
+                            "{} since the adaptive wait failed.", msg);
+
+        let creation_timeout = self.global_config.global.service.creation_timeout;
+        if timeout <= creation_timeout {
+            return Ok(());
+        }
+
+        let uuid = fatal_panic!(from self,
+                        when FileName::new(self.service_config.uuid().as_bytes()),
+                        "This should never happen! The uuid should be always a valid file name.");
+        if remove_abandoned_creation::<ServiceType>(&uuid, self.global_config) {
+            warn!(from self,
+                "The creation of the service was not finalized within {:?}, the remainders of the crashed creator were removed.",
+                creation_timeout);
+            return Ok(());
+        }
+
+        fail!(from self, with CreationWaitFailure::HangsInCreation,
+            "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded. Waited for {:?} but the state did not change.",
+            msg, creation_timeout, timeout);
+    }
+}
+
+/// Returns true when the provided service is still the instance that is registered under its
+/// name. It is false when the service was removed or replaced by a new instance with the same
+/// name, for instance since the process that created it was restarted.
+pub(crate) fn is_instance_available<'config, ServiceType: service::Details<'config>>(
+    service: &ServiceType,
+) -> bool {
+    let state = service.state();
+    let builder = BuilderWithServiceType::<ServiceType>::new(
+        state.static_config.clone(),
+        state.global_config,
+        state.node_id,
+    );
+
+    match builder.is_service_available() {
+        Ok(Some((static_config, _))) => {
+            static_config.instance_uuid() == state.static_config.instance_uuid()
+        }
+        Ok(None) => false,
+        Err(ServiceState::IsBeingCreatedByAnotherInstance) => false,
+        // an instance is only gone when it is proven, an inaccessible service could still be
+        // the same instance
+        Err(_) => true,
     }
 }
+
+/// Opens the instance that replaced the provided service under the same name. Returns [`None`]
+/// when there is no new instance or when its messaging pattern differs, since the ports of the
+/// previous instance cannot be moved to it then.
+pub(crate) fn open_replacement<'config, ServiceType: service::Details<'config>>(
+    service: &ServiceType,
+) -> Option<ServiceType> {
+    let state = service.state();
+    let builder = BuilderWithServiceType::<ServiceType>::new(
+        state.static_config.clone(),
+        state.global_config,
+        state.node_id,
+    );
+
+    let (static_config, static_storage) = builder.is_service_available().ok()??;
+    if static_config.instance_uuid() == state.static_config.instance_uuid()
+        || static_config.messaging_pattern != state.static_config.messaging_pattern
+    {
+        return None;
+    }
+
+    let (dynamic_storage, owner_index) = builder.open_dynamic_config_storage().ok()?;
+    Some(ServiceType::from_state(service::ServiceState::new(
+        static_config,
+        state.global_config,
+        dynamic_storage,
+        static_storage,
+        owner_index,
+        state.node_id,
+        service::discovery::announce_change::<ServiceType>,
+    )))
+}
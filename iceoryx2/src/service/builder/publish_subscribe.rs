@@ -14,32 +14,54 @@
 //!
 //! See [`crate::service`]
 //!
+use crate::payload_type::PayloadType;
 use crate::service;
+use crate::service::attribute::{AttributeRequirement, AttributeSpecifier, AttributeVerifier};
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
+use crate::service::header::publish_subscribe::ClockType;
 use crate::service::messaging_pattern::MessagingPattern;
 use crate::service::port_factory::publish_subscribe;
+use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::static_config::publish_subscribe::{
+    IncompatibleSetting, Requirements, TypeDetails,
+};
+use crate::service::static_config::ServicePersistence;
 use crate::service::*;
-use iceoryx2_bb_elementary::enum_gen;
+use crate::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_elementary::alignment::Alignment;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_cal::serialize::Serialize;
-use iceoryx2_cal::static_storage::StaticStorageLocked;
+use iceoryx2_cal::static_storage::{StaticStorageCreateError, StaticStorageLocked};
+use std::alloc::Layout;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::time::Duration;
 
-use super::ServiceState;
+use super::{CreationWaitFailure, ServiceState};
 
 /// Errors that can occur when an existing [`MessagingPattern::PublishSubscribe`] [`Service`] shall be opened.
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+#[non_exhaustive]
 pub enum PublishSubscribeOpenError {
     DoesNotExist,
     InternalFailure,
-    IncompatibleTypes,
+    /// The payload or the user header type of the service differs from the requested one, the
+    /// payload type is verified first.
+    IncompatibleTypes {
+        /// The [`TypeDetails`] the service was created with.
+        expected: Box<TypeDetails>,
+        /// The requested [`TypeDetails`].
+        actual: Box<TypeDetails>,
+    },
     IncompatibleMessagingPattern,
-    DoesNotSupportRequestedMinBufferSize,
-    DoesNotSupportRequestedMinHistorySize,
-    DoesNotSupportRequestedMinSubscriberBorrowedSamples,
-    DoesNotSupportRequestedAmountOfPublishers,
-    DoesNotSupportRequestedAmountOfSubscribers,
-    IncompatibleOverflowBehavior,
+    /// The settings of the service do not satisfy the requested ones, every
+    /// [`IncompatibleSetting`] names a setting together with its existing and requested value,
+    /// see [`static_config::publish_subscribe::StaticConfig::compatibility_diff()`].
+    IncompatibleSettings(Vec<IncompatibleSetting>),
+    /// The service does not satisfy the listed requirements of the
+    /// [`AttributeVerifier`].
+    IncompatibleAttributes(Vec<AttributeRequirement>),
     Inaccessible,
     PermissionDenied,
     ServiceInCorruptedState,
@@ -49,7 +71,34 @@ pub enum PublishSubscribeOpenError {
 
 impl std::fmt::Display for PublishSubscribeOpenError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+        let hint = match self {
+            PublishSubscribeOpenError::DoesNotExist => "no service with this name exists, create it first or use open_or_create()",
+            PublishSubscribeOpenError::InternalFailure => "an internal failure occurred, check the log output for details",
+            PublishSubscribeOpenError::IncompatibleTypes { expected, actual } => {
+                return std::write!(f, "{}::IncompatibleTypes: the service exists with the type {} but the type {} was requested, use the types it was created with", std::stringify!(Self), expected, actual);
+            }
+            PublishSubscribeOpenError::IncompatibleMessagingPattern => "the service exists with another messaging pattern",
+            PublishSubscribeOpenError::IncompatibleSettings(settings) => {
+                std::write!(f, "{}::IncompatibleSettings: the service does not satisfy the requested settings", std::stringify!(Self))?;
+                for (n, setting) in settings.iter().enumerate() {
+                    std::write!(f, "{} {}", if n == 0 { ":" } else { "," }, setting)?;
+                }
+                return Ok(());
+            }
+            PublishSubscribeOpenError::IncompatibleAttributes(unsatisfied) => {
+                std::write!(f, "{}::IncompatibleAttributes: the service does not have the required attributes", std::stringify!(Self))?;
+                for (n, requirement) in unsatisfied.iter().enumerate() {
+                    std::write!(f, "{} {}", if n == 0 { ":" } else { "," }, requirement)?;
+                }
+                return Ok(());
+            }
+            PublishSubscribeOpenError::Inaccessible => "the static service information could not be read, check the permissions of the service directory",
+            PublishSubscribeOpenError::PermissionDenied => "the process lacks the permissions to access the service",
+            PublishSubscribeOpenError::ServiceInCorruptedState => "the service resources are corrupted, remove the stale resources of the service",
+            PublishSubscribeOpenError::HangsInCreation => "another process did not finish the creation of the service in time, it may have crashed during creation",
+            PublishSubscribeOpenError::UnableToOpenDynamicServiceInformation => "the dynamic service information could not be opened, the service may be in the process of being removed",
+        };
+        std::write!(f, "{}::{:?}: {}", std::stringify!(Self), self, hint)
     }
 }
 
@@ -57,6 +106,7 @@ impl std::error::Error for PublishSubscribeOpenError {}
 
 /// Errors that can occur when a new [`MessagingPattern::PublishSubscribe`] [`Service`] shall be created.
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[non_exhaustive]
 pub enum PublishSubscribeCreateError {
     Corrupted,
     SubscriberBufferMustBeLargerThanHistorySize,
@@ -65,29 +115,55 @@ pub enum PublishSubscribeCreateError {
     InternalFailure,
     IsBeingCreatedByAnotherInstance,
     UnableToCreateStaticServiceInformation,
+    InvalidPayloadAlignment,
 }
 
 impl std::fmt::Display for PublishSubscribeCreateError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+        let hint = match self {
+            PublishSubscribeCreateError::Corrupted => "leftovers of an old instance of the service exist, remove the stale resources of the service",
+            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize => "the subscriber buffer cannot hold the history, increase subscriber_max_buffer_size or decrease history_size",
+            PublishSubscribeCreateError::AlreadyExists => "a service with this name already exists, open it or use open_or_create()",
+            PublishSubscribeCreateError::PermissionDenied => "the process lacks the permissions to create the service resources",
+            PublishSubscribeCreateError::InternalFailure => "an internal failure occurred, check the log output for details",
+            PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance => "another process creates the service right now, open it once the creation is finished",
+            PublishSubscribeCreateError::UnableToCreateStaticServiceInformation => "the static service information could not be written, check the permissions of the service directory",
+            PublishSubscribeCreateError::InvalidPayloadAlignment => "the requested payload alignment is smaller than the alignment of the payload type",
+        };
+        std::write!(f, "{}::{:?}: {}", std::stringify!(Self), self, hint)
     }
 }
 
 impl std::error::Error for PublishSubscribeCreateError {}
 
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
 enum ServiceAvailabilityState {
     ServiceState(ServiceState),
-    IncompatibleTypes,
+    IncompatibleTypes {
+        expected: Box<TypeDetails>,
+        actual: Box<TypeDetails>,
+    },
 }
 
-enum_gen! {
-    /// Errors that can occur when a [`MessagingPattern::PublishSubscribe`] [`Service`] shall be
-    /// created or opened.
-    PublishSubscribeOpenOrCreateError
-  mapping:
-    PublishSubscribeOpenError,
-    PublishSubscribeCreateError
+/// Errors that can occur when a [`MessagingPattern::PublishSubscribe`] [`Service`] shall be
+/// created or opened.
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum PublishSubscribeOpenOrCreateError {
+    PublishSubscribeOpenError(PublishSubscribeOpenError),
+    PublishSubscribeCreateError(PublishSubscribeCreateError),
+}
+
+impl From<PublishSubscribeOpenError> for PublishSubscribeOpenOrCreateError {
+    fn from(value: PublishSubscribeOpenError) -> Self {
+        PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(value)
+    }
+}
+
+impl From<PublishSubscribeCreateError> for PublishSubscribeOpenOrCreateError {
+    fn from(value: PublishSubscribeCreateError) -> Self {
+        PublishSubscribeOpenOrCreateError::PublishSubscribeCreateError(value)
+    }
 }
 
 impl std::fmt::Display for PublishSubscribeOpenOrCreateError {
@@ -98,13 +174,142 @@ impl std::fmt::Display for PublishSubscribeOpenOrCreateError {
 
 impl std::error::Error for PublishSubscribeOpenOrCreateError {}
 
+/// The state of a [`Builder`]. It tracks whether settings were applied that are only used when
+/// the [`Service`] is created or only when it is opened, and restricts the terminal calls to the
+/// ones that respect all of them.
+///
+/// * [`Unrestricted`] - only settings that are applied on creation and verified on opening were
+///   used, like [`Builder::max_publishers()`]. All terminal calls are available.
+/// * [`CreateOnly`] - a create-only setting was used, like [`Builder::attributes()`]. Only
+///   [`Builder::create()`] and [`Builder::open_or_create()`] remain.
+/// * [`OpenOnly`] - an open-only requirement was used, like [`Builder::required_attributes()`].
+///   Only [`Builder::open()`] and [`Builder::open_or_create()`] remain.
+/// * [`OpenOrCreateOnly`] - both were used, only [`Builder::open_or_create()`] remains. The
+///   create-only settings are applied only when the [`Service`] is actually created.
+///
+/// The quantities of the service, like [`Builder::max_publishers()`] or
+/// [`Builder::history_size()`], keep the state unchanged. They are not create-only, when an
+/// existing [`Service`] is opened they define the minimum it has to support and opening a
+/// service that supports less fails, for instance with
+/// [`PublishSubscribeOpenError::IncompatibleSettings`].
+pub trait BuilderState: Debug + internal::Sealed {
+    /// The state after a create-only setting was used.
+    type WithCreateSettings: BuilderState;
+    /// The state after an open-only requirement was used.
+    type WithOpenRequirements: BuilderState;
+}
+
+/// A [`BuilderState`] that allows [`Builder::open()`].
+pub trait SupportsOpen: BuilderState {}
+
+/// A [`BuilderState`] that allows [`Builder::create()`].
+pub trait SupportsCreate: BuilderState {}
+
+mod internal {
+    pub trait Sealed {}
+}
+
+/// [`BuilderState`] of a [`Builder`] without create-only settings and open-only requirements.
+#[derive(Debug)]
+pub struct Unrestricted;
+
+/// [`BuilderState`] of a [`Builder`] with create-only settings.
+#[derive(Debug)]
+pub struct CreateOnly;
+
+/// [`BuilderState`] of a [`Builder`] with open-only requirements.
+#[derive(Debug)]
+pub struct OpenOnly;
+
+/// [`BuilderState`] of a [`Builder`] with create-only settings and open-only requirements.
+#[derive(Debug)]
+pub struct OpenOrCreateOnly;
+
+impl internal::Sealed for Unrestricted {}
+impl internal::Sealed for CreateOnly {}
+impl internal::Sealed for OpenOnly {}
+impl internal::Sealed for OpenOrCreateOnly {}
+
+impl BuilderState for Unrestricted {
+    type WithCreateSettings = CreateOnly;
+    type WithOpenRequirements = OpenOnly;
+}
+
+impl BuilderState for CreateOnly {
+    type WithCreateSettings = CreateOnly;
+    type WithOpenRequirements = OpenOrCreateOnly;
+}
+
+impl BuilderState for OpenOnly {
+    type WithCreateSettings = OpenOrCreateOnly;
+    type WithOpenRequirements = OpenOnly;
+}
+
+impl BuilderState for OpenOrCreateOnly {
+    type WithCreateSettings = OpenOrCreateOnly;
+    type WithOpenRequirements = OpenOrCreateOnly;
+}
+
+impl SupportsOpen for Unrestricted {}
+impl SupportsOpen for OpenOnly {}
+impl SupportsCreate for Unrestricted {}
+impl SupportsCreate for CreateOnly {}
+
+/// Relates the payload type of a [`Builder`] to the payload type of its terminal call. Every
+/// type is only related to itself, therefore the payload type can be provided either to
+/// [`crate::service::Builder::publish_subscribe()`] or to the terminal call, like
+/// [`Builder::create()`], and is inferred for the other one.
+#[doc(hidden)]
+pub trait TypeIdentity {
+    type Type: ?Sized;
+}
+
+impl<T: ?Sized> TypeIdentity for T {
+    type Type = T;
+}
+
 /// Builder to create new [`MessagingPattern::PublishSubscribe`] based [`Service`]s
 ///
+/// The `Payload` type is defined with [`crate::service::Builder::publish_subscribe()`], for
+/// instance `publish_subscribe::<u64>()`. When it is omitted, it is inferred from the terminal
+/// call, like `create::<u64>()`. Settings that are only used when the [`Service`] is created or
+/// only when it is opened change the [`BuilderState`] so that a terminal call that would ignore
+/// them does not compile.
+///
 /// # Example
 ///
-/// See [`crate::service`]
+/// ```
+/// use iceoryx2::prelude::*;
+/// use iceoryx2::service::attribute::{AttributeSpecifier, AttributeVerifier};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let service_name = ServiceName::new("My/Funk/TypeStateService")?;
+/// let service = zero_copy::Service::new(&service_name)
+///     .publish_subscribe::<u64>()
+///     .max_publishers(2)
+///     // applied only when the service is created
+///     .attributes(&AttributeSpecifier::new().define("owner", "vision_team")?)
+///     // verified only when the service is opened
+///     .required_attributes(&AttributeVerifier::new().require_key("owner"))
+///     .open_or_create()?;
+///
+/// let opened = zero_copy::Service::new(&service_name)
+///     .publish_subscribe::<u64>()
+///     .required_attributes(&AttributeVerifier::new().require("owner", "vision_team"))
+///     .open()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See also [`crate::service`]
 #[derive(Debug)]
-pub struct Builder<'config, ServiceType: service::Details<'config>> {
+pub struct Builder<
+    'config,
+    ServiceType: service::Details<'config>,
+    Payload: Debug + ?Sized,
+    UserHeader: Debug = (),
+    State = Unrestricted,
+> {
     base: builder::BuilderWithServiceType<'config, ServiceType>,
     verify_number_of_subscribers: bool,
     verify_number_of_publishers: bool,
@@ -112,9 +317,28 @@ pub struct Builder<'config, ServiceType: service::Details<'config>> {
     verify_subscriber_max_borrowed_samples: bool,
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
+    verify_enable_single_subscriber: bool,
+    verify_enable_notifications: bool,
+    verify_enable_deterministic_memory: bool,
+    verify_max_slice_len: bool,
+    verify_payload_alignment: bool,
+    verify_clock_type: bool,
+    verify_unable_to_deliver_strategy: bool,
+    verify_allow_user_time_stamps: bool,
+    verify_deadline: bool,
+    verify_type_names: bool,
+    verify_payload_type: bool,
+    custom_payload_type_details: Option<TypeDetails>,
+    attribute_specifier: Option<AttributeSpecifier>,
+    attribute_verifier: AttributeVerifier,
+    _phantom_payload: PhantomData<Payload>,
+    _phantom_user_header: PhantomData<UserHeader>,
+    _phantom_state: PhantomData<State>,
 }
 
-impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceType> {
+impl<'config, ServiceType: service::Details<'config>, Payload: Debug + ?Sized>
+    Builder<'config, ServiceType, Payload>
+{
     pub(crate) fn new(base: builder::BuilderWithServiceType<'config, ServiceType>) -> Self {
         let mut new_self = Self {
             base,
@@ -124,6 +348,23 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
             verify_publisher_history_size: false,
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
+            verify_enable_single_subscriber: false,
+            verify_enable_notifications: false,
+            verify_enable_deterministic_memory: false,
+            verify_max_slice_len: false,
+            verify_payload_alignment: false,
+            verify_clock_type: false,
+            verify_unable_to_deliver_strategy: false,
+            verify_allow_user_time_stamps: false,
+            verify_deadline: false,
+            verify_type_names: true,
+            verify_payload_type: true,
+            custom_payload_type_details: None,
+            attribute_specifier: None,
+            attribute_verifier: AttributeVerifier::new(),
+            _phantom_payload: PhantomData,
+            _phantom_user_header: PhantomData,
+            _phantom_state: PhantomData,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::PublishSubscribe(
@@ -132,7 +373,16 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
 
         new_self
     }
+}
 
+impl<
+        'config,
+        ServiceType: service::Details<'config>,
+        Payload: Debug + ?Sized,
+        UserHeader: Debug + Default,
+        State: BuilderState,
+    > Builder<'config, ServiceType, Payload, UserHeader, State>
+{
     fn config_details_mut(&mut self) -> &mut static_config::publish_subscribe::StaticConfig {
         match self.base.service_config.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref mut v) => v,
@@ -151,17 +401,193 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         }
     }
 
+    /// Defines the user header that is stored in front of the payload of every
+    /// [`crate::sample::Sample`]. It can be used to attach metadata, like a trace id, to samples
+    /// of different payload types. When the [`Service`] is opened the user header type must be
+    /// equal to the user header type of the existing [`Service`]. Without a user header the type
+    /// `()` is used which does not occupy any memory. Like the payload the user header is shared
+    /// between processes and must implement [`ZeroCopySend`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// #[derive(Debug, Default, ZeroCopySend)]
+    /// #[repr(C)]
+    /// struct TraceHeader {
+    ///     trace_id: u64,
+    ///     priority: u8,
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/TracedService")?;
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .user_header::<TraceHeader>()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let publisher = service.publisher().create()?;
+    ///
+    /// let mut sample = publisher.loan_uninit()?;
+    /// sample.user_header_mut().trace_id = 42;
+    /// let sample = sample.write_payload(1234);
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn user_header<H: Debug + Default + ZeroCopySend>(
+        self,
+    ) -> Builder<'config, ServiceType, Payload, H, State> {
+        let mut new_self = self.transition::<H, State>();
+        new_self.config_details_mut().user_header_type_details =
+            TypeDetails::new::<H>(Layout::new::<H>());
+        new_self
+    }
+
+    fn transition<H: Debug + Default, NewState: BuilderState>(
+        self,
+    ) -> Builder<'config, ServiceType, Payload, H, NewState> {
+        Builder {
+            base: self.base,
+            verify_number_of_subscribers: self.verify_number_of_subscribers,
+            verify_number_of_publishers: self.verify_number_of_publishers,
+            verify_subscriber_max_buffer_size: self.verify_subscriber_max_buffer_size,
+            verify_subscriber_max_borrowed_samples: self.verify_subscriber_max_borrowed_samples,
+            verify_publisher_history_size: self.verify_publisher_history_size,
+            verify_enable_safe_overflow: self.verify_enable_safe_overflow,
+            verify_enable_single_subscriber: self.verify_enable_single_subscriber,
+            verify_enable_notifications: self.verify_enable_notifications,
+            verify_enable_deterministic_memory: self.verify_enable_deterministic_memory,
+            verify_max_slice_len: self.verify_max_slice_len,
+            verify_payload_alignment: self.verify_payload_alignment,
+            verify_clock_type: self.verify_clock_type,
+            verify_unable_to_deliver_strategy: self.verify_unable_to_deliver_strategy,
+            verify_allow_user_time_stamps: self.verify_allow_user_time_stamps,
+            verify_deadline: self.verify_deadline,
+            verify_type_names: self.verify_type_names,
+            verify_payload_type: self.verify_payload_type,
+            custom_payload_type_details: self.custom_payload_type_details,
+            attribute_specifier: self.attribute_specifier,
+            attribute_verifier: self.attribute_verifier,
+            _phantom_payload: PhantomData,
+            _phantom_user_header: PhantomData,
+            _phantom_state: PhantomData,
+        }
+    }
+
     /// If the [`Service`] is created, defines the overflow behavior of the service. If an existing
     /// [`Service`] is opened it requires the service to have the defined overflow behavior.
+    ///
+    /// With safe overflow the [`crate::port::publisher::Publisher`] never blocks and replaces the
+    /// oldest [`crate::sample::Sample`] in the buffer of a [`crate::port::subscriber::Subscriber`]
+    /// that is full. Without it, the
+    /// [`crate::service::port_factory::publisher::UnableToDeliverStrategy`] of the
+    /// [`crate::port::publisher::Publisher`] decides if it blocks until there is space again or
+    /// if it discards the new [`crate::sample::Sample`] for this
+    /// [`crate::port::subscriber::Subscriber`]. The latter is reported with a lower number of
+    /// recipients returned by [`crate::payload_mut::PayloadMut::send()`].
     pub fn enable_safe_overflow(mut self, value: bool) -> Self {
         self.config_details_mut().enable_safe_overflow = value;
         self.verify_enable_safe_overflow = true;
         self
     }
 
+    /// If the [`Service`] is created, it defines if the ports of the service acquire all of
+    /// their memory when they are created. It is intended for deployments that must not
+    /// allocate after the initialization. If an existing [`Service`] is opened it requires the
+    /// service to have the same setting.
+    ///
+    /// A [`crate::port::publisher::Publisher`] of the service reserves its data segment on
+    /// creation and its creation fails unless it uses
+    /// [`crate::service::port_factory::publisher::AllocationStrategy::Static`]. The data segment
+    /// is never extended, a loan that does not fit into it fails. A
+    /// [`crate::port::subscriber::Subscriber`] preallocates the bookkeeping of every
+    /// [`crate::sample::Sample`] it can borrow. Loaning, sending and receiving samples does not
+    /// allocate heap memory. The memory that is reserved is reported by [`crate::port::publisher::Publisher::memory_budget()`] and
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::memory_footprint()`].
+    ///
+    /// Connections are established when ports are created or when
+    /// [`crate::port::update_connections::UpdateConnections::update_connections()`] is called,
+    /// therefore all ports should be created during the initialization.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/DeterministicMemory")?;
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .enable_deterministic_memory(true)
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let publisher = service.publisher().create()?;
+    /// println!("reserved {} bytes for the samples", publisher.memory_budget());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn enable_deterministic_memory(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_deterministic_memory = value;
+        self.verify_enable_deterministic_memory = true;
+        self
+    }
+
+    /// If the [`Service`] is created, it defines if the service guarantees that every
+    /// [`crate::sample::Sample`] is consumed by exactly one [`crate::port::subscriber::Subscriber`].
+    /// The maximum number of subscribers is restricted to 1 and the history is deactivated so
+    /// that the [`crate::sample::Sample`] can be modified with
+    /// [`crate::sample::Sample::payload_mut()`]. If an existing [`Service`] is opened it requires
+    /// the service to have the same setting.
+    pub fn enable_single_subscriber(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_single_subscriber = value;
+        self.verify_enable_single_subscriber = true;
+        self
+    }
+
+    /// If the [`Service`] is created, it defines if the [`crate::port::publisher::Publisher`]s
+    /// notify [`crate::port::subscriber::Subscriber`]s that wait for a new
+    /// [`crate::sample::Sample`] with [`crate::port::subscriber::Subscriber::timed_receive()`]
+    /// or [`crate::port::subscriber::Subscriber::blocking_receive()`]. A publisher pays the cost
+    /// of a notification only while at least one subscriber is waiting. If an existing
+    /// [`Service`] is opened it requires the service to have the same setting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/NotifyingService")?;
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .enable_notifications(true)
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let subscriber = service.subscriber().create()?;
+    ///
+    /// // sleeps until a sample arrives or the timeout has passed
+    /// if let Some(sample) = subscriber.timed_receive(Duration::from_millis(10))? {
+    ///     println!("received: {:?}", *sample);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn enable_notifications(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_notifications = value;
+        self.verify_enable_notifications = true;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
     /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel. If an existing
     /// [`Service`] is opened it defines the minimum required.
+    /// A received [`crate::sample::Sample`] counts only once, no matter how many clones of it
+    /// exist.
     pub fn subscriber_max_borrowed_samples(mut self, value: usize) -> Self {
         self.config_details_mut().subscriber_max_borrowed_samples = std::cmp::max(value, 1);
         self.verify_subscriber_max_borrowed_samples = true;
@@ -186,9 +612,50 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         self
     }
 
+    /// If the [`Service`] is created it defines whether it is removed when its last instance
+    /// is dropped or whether it persists until it is removed explicitly with
+    /// [`crate::service::Details::remove()`]. It is a create-only setting, an existing
+    /// [`Service`] keeps the persistence it was created with, therefore [`Builder::open()`] is
+    /// no longer available.
+    pub fn persistence(
+        mut self,
+        value: ServicePersistence,
+    ) -> Builder<'config, ServiceType, Payload, UserHeader, State::WithCreateSettings> {
+        self.base.service_config.persistence = value;
+        self.transition()
+    }
+
+    /// Defines the attributes of the [`Service`] when it is created. It is a create-only
+    /// setting, therefore [`Builder::open()`] is no longer available. When the [`Service`] is
+    /// created with [`Builder::open_or_create()`] without this setting, the attributes are the
+    /// required key-value pairs of [`Builder::required_attributes()`].
+    pub fn attributes(
+        mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Builder<'config, ServiceType, Payload, UserHeader, State::WithCreateSettings> {
+        self.attribute_specifier = Some(attributes.clone());
+        self.transition()
+    }
+
+    /// Defines the attributes an existing [`Service`] must have so that it can be opened,
+    /// otherwise the opening fails with [`PublishSubscribeOpenError::IncompatibleAttributes`].
+    /// It is an open-only requirement, therefore [`Builder::create()`] is no longer available.
+    pub fn required_attributes(
+        mut self,
+        attributes: &AttributeVerifier,
+    ) -> Builder<'config, ServiceType, Payload, UserHeader, State::WithOpenRequirements> {
+        self.attribute_verifier = attributes.clone();
+        self.transition()
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::port::subscriber::Subscriber`] shall
     /// be supported at most. If an existing [`Service`] is opened it defines how many
     /// [`crate::port::subscriber::Subscriber`] must be at least supported.
+    ///
+    /// When the limit is reached, the creation of another
+    /// [`crate::port::subscriber::Subscriber`] fails with
+    /// [`crate::port::subscribe::SubscriberCreateError::ExceedsMaxSupportedSubscribers`] until
+    /// one of the existing ones is dropped.
     pub fn max_subscribers(mut self, value: usize) -> Self {
         self.config_details_mut().max_subscribers = value;
         self.verify_number_of_subscribers = true;
@@ -198,22 +665,250 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
     /// If the [`Service`] is created it defines how many [`crate::port::publisher::Publisher`] shall
     /// be supported at most. If an existing [`Service`] is opened it defines how many
     /// [`crate::port::publisher::Publisher`] must be at least supported.
+    ///
+    /// The limit is enforced across all processes, therefore `max_publishers(1)` guarantees
+    /// that at most one [`crate::port::publisher::Publisher`] exists at any time. Further
+    /// creation attempts fail with
+    /// [`crate::port::publish::PublisherCreateError::ExceedsMaxSupportedPublishers`].
     pub fn max_publishers(mut self, value: usize) -> Self {
         self.config_details_mut().max_publishers = value;
         self.verify_number_of_publishers = true;
         self
     }
 
+    /// If the [`Service`] is created it defines how many elements a slice payload, like `[u8]`,
+    /// can contain at most. If an existing [`Service`] is opened it defines the minimum required.
+    /// It has no effect on services with a sized payload type.
+    pub fn max_slice_len(mut self, value: usize) -> Self {
+        self.config_details_mut().max_slice_len = value;
+        self.verify_max_slice_len = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the alignment of the payload in every
+    /// [`crate::sample::Sample`], e.g. to place it on a cache line or to satisfy the requirements
+    /// of SIMD instructions. It must be at least the alignment of the payload type. If an
+    /// existing [`Service`] is opened it defines the minimum required alignment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .payload_alignment(Alignment::new(64).unwrap())
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let publisher = service.publisher().create()?;
+    /// let sample = publisher.loan_uninit()?;
+    /// assert!(sample.payload() as *const _ as usize % 64 == 0);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn payload_alignment(mut self, value: Alignment) -> Self {
+        self.config_details_mut().payload_alignment = value.value();
+        self.verify_payload_alignment = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the [`ClockType`] of the time stamps in the
+    /// [`crate::service::header::publish_subscribe::Header`] of every
+    /// [`crate::sample::Sample`]. If an existing [`Service`] is opened it requires the service
+    /// to use the same clock.
+    pub fn clock_type(mut self, value: ClockType) -> Self {
+        self.config_details_mut().clock_type = value;
+        self.verify_clock_type = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the [`UnableToDeliverStrategy`] of the service.
+    /// It is the default of every [`crate::port::publisher::Publisher`] and the strongest
+    /// strategy one can override it with, see
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::unable_to_deliver_strategy()`].
+    /// If an existing [`Service`] is opened it requires the service to use the same strategy.
+    pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
+        self.config_details_mut().unable_to_deliver_strategy = value;
+        self.verify_unable_to_deliver_strategy = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines if the time stamp of a
+    /// [`crate::sample_mut::SampleMut`] can be set by the user with
+    /// [`crate::sample_mut::SampleMut::set_time_stamp()`] instead of being assigned when the
+    /// sample is sent. Tools that replay recorded samples use it to keep the original time
+    /// stamps. The sequence number is always assigned by the
+    /// [`crate::port::publisher::Publisher`]. If an existing [`Service`] is opened it requires
+    /// the service to have the same setting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_bb_posix::clock::Time;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ReplayedService")?;
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .allow_user_time_stamps(true)
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let publisher = service.publisher().create()?;
+    /// let clock_type = service.static_config().clock_type().into();
+    /// let recorded_time_stamp = Time::now_with_clock(clock_type).unwrap();
+    ///
+    /// let mut sample = publisher.loan_uninit()?.write_payload(1234);
+    /// sample.set_time_stamp(recorded_time_stamp)?;
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn allow_user_time_stamps(mut self, value: bool) -> Self {
+        self.config_details_mut().allow_user_time_stamps = value;
+        self.verify_allow_user_time_stamps = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the deadline contract of the service: every
+    /// [`crate::port::publisher::Publisher`] promises to send a [`crate::sample::Sample`] at
+    /// least once within `value`. Violations are reported by
+    /// [`crate::port::subscriber::Subscriber::missed_deadlines()`]. If an existing [`Service`]
+    /// is opened it requires a deadline that is at most `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ControlLoop")?;
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .deadline(Duration::from_millis(100))
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// // a publisher can verify that it is able to fulfill the contract
+    /// println!("deadline: {:?}", service.static_config().deadline());
+    ///
+    /// let subscriber = service.subscriber().create()?;
+    /// for missed in subscriber.missed_deadlines()? {
+    ///     println!("publisher {:?} is silent since {:?}", missed.publisher_id(), missed.elapsed());
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deadline(mut self, value: Duration) -> Self {
+        self.config_details_mut().deadline = Some(value);
+        self.verify_deadline = true;
+        self
+    }
+
+    /// Overrides the [`TypeDetails`] of the payload type that are stored in and verified
+    /// against the static config of the [`Service`], for instance to describe a type that is
+    /// defined in another language with [`TypeDetails::from_parts()`]. When the details contain
+    /// a layout hash, existing services are opened when the layout hashes match.
+    ///
+    /// # Safety
+    ///
+    ///  * The size and the alignment of the details must be equal to the ones of the payload
+    ///    type that is used to create or open the [`Service`].
+    ///  * The layout hash must only be equal to the layout hash of another type when both
+    ///    types have exactly the same memory layout.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::static_config::publish_subscribe::TypeDetails;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ForeignTypeService")?;
+    /// let type_details = TypeDetails::from_parts("ForeignU64", 8, 8, Some(0x6c62272e07bb0142));
+    ///
+    /// let service = unsafe {
+    ///     zero_copy::Service::new(&service_name)
+    ///         .publish_subscribe()
+    ///         .payload_type_details(type_details)
+    ///         .open_or_create::<u64>()?
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn payload_type_details(mut self, value: TypeDetails) -> Self {
+        self.custom_payload_type_details = Some(value);
+        self
+    }
+
+    fn set_payload_type<MessageType: PayloadType + ?Sized>(&mut self) {
+        self.config_details_mut().payload_type_details = match &self.custom_payload_type_details {
+            Some(details) => details.clone(),
+            None => TypeDetails::with_layout_hash::<MessageType>(MessageType::payload_layout(1)),
+        };
+    }
+
+    fn is_type_compatible(&self, existing: &TypeDetails, required: &TypeDetails) -> bool {
+        if !existing.has_same_layout(required) {
+            return false;
+        }
+
+        if !self.verify_type_names {
+            return true;
+        }
+
+        match (existing.layout_hash, required.layout_hash) {
+            (Some(existing_hash), Some(required_hash)) => existing_hash == required_hash,
+            (None, None) => existing.type_name == required.type_name,
+            _ => {
+                warn!(from self,
+                    "The layout of the type {} cannot be compared with the type {} since only one of them provides a layout hash. Only their size and alignment are verified.",
+                    existing, required);
+                true
+            }
+        }
+    }
+
     fn is_service_available(
         &mut self,
         error_msg: &str,
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
         match self.base.is_service_available() {
             Ok(Some((config, storage))) => {
-                if config.publish_subscribe().type_name != self.config_details().type_name {
-                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
-                        "{} since the service offers the type \"{}\" but the requested type is \"{}\".",
-                        error_msg, &config.publish_subscribe().type_name , self.config_details().type_name);
+                let existing_settings = config.publish_subscribe();
+                let required_settings = self.config_details();
+
+                if self.verify_payload_type
+                    && !self.is_type_compatible(
+                        &existing_settings.payload_type_details,
+                        &required_settings.payload_type_details,
+                    )
+                {
+                    warn!(from self,
+                        "{} since the service offers the payload type {} but the requested payload type is {}.",
+                        error_msg, existing_settings.payload_type_details, required_settings.payload_type_details);
+                    return Err(ServiceAvailabilityState::IncompatibleTypes {
+                        expected: Box::new(existing_settings.payload_type_details.clone()),
+                        actual: Box::new(required_settings.payload_type_details.clone()),
+                    });
+                }
+
+                if !self.is_type_compatible(
+                    &existing_settings.user_header_type_details,
+                    &required_settings.user_header_type_details,
+                ) {
+                    warn!(from self,
+                        "{} since the service offers the user header type {} but the requested user header type is {}.",
+                        error_msg, existing_settings.user_header_type_details, required_settings.user_header_type_details);
+                    return Err(ServiceAvailabilityState::IncompatibleTypes {
+                        expected: Box::new(existing_settings.user_header_type_details.clone()),
+                        actual: Box::new(required_settings.user_header_type_details.clone()),
+                    });
                 }
 
                 Ok(Some((config, storage)))
@@ -224,72 +919,207 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
     }
 
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
-    /// created.
-    pub fn open_or_create<MessageType: Debug>(
+    /// created. It is available in every [`BuilderState`], the create-only settings, like
+    /// [`Builder::attributes()`], are applied only when the [`Service`] is actually created and
+    /// the open-only requirements, like [`Builder::required_attributes()`], are verified only
+    /// when it is opened.
+    pub fn open_or_create<MessageType>(
+        self,
+    ) -> Result<
+        publish_subscribe::PortFactory<'config, ServiceType, MessageType, UserHeader>,
+        PublishSubscribeOpenOrCreateError,
+    >
+    where
+        Payload: TypeIdentity<Type = MessageType>,
+        MessageType: PayloadType + ?Sized + TypeIdentity<Type = Payload>,
+    {
+        self.open_or_create_impl::<MessageType>()
+    }
+
+    /// If the [`Service`] exists, it will be opened when it satisfies the requirements of the
+    /// [`AttributeVerifier`]. Otherwise a new [`Service`] will be created, its attributes are
+    /// the required key-value pairs of the [`AttributeVerifier`].
+    #[deprecated(
+        since = "0.3.0",
+        note = "use required_attributes() followed by open_or_create() instead"
+    )]
+    pub fn open_or_create_with_attributes<MessageType>(
+        mut self,
+        attributes: &AttributeVerifier,
+    ) -> Result<
+        publish_subscribe::PortFactory<'config, ServiceType, MessageType, UserHeader>,
+        PublishSubscribeOpenOrCreateError,
+    >
+    where
+        Payload: TypeIdentity<Type = MessageType>,
+        MessageType: PayloadType + ?Sized + TypeIdentity<Type = Payload>,
+    {
+        self.attribute_verifier = attributes.clone();
+        self.open_or_create_impl::<MessageType>()
+    }
+
+    fn open_or_create_impl<MessageType: PayloadType + ?Sized>(
         mut self,
     ) -> Result<
-        publish_subscribe::PortFactory<'config, ServiceType, MessageType>,
+        publish_subscribe::PortFactory<'config, ServiceType, MessageType, UserHeader>,
         PublishSubscribeOpenOrCreateError,
     > {
-        let msg = "Unable to open or create publish subscribe service";
-        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
+        let msg = format!(
+            "Unable to open or create publish subscribe service \"{}\"",
+            self.base.service_config.service_name()
+        );
+        self.set_payload_type::<MessageType>();
 
-        match self.is_service_available(msg) {
-            Ok(Some(_)) => Ok(self.open::<MessageType>()?),
-            Ok(None) => Ok(self.create::<MessageType>()?),
-            Err(ServiceAvailabilityState::ServiceState(
-                ServiceState::IsBeingCreatedByAnotherInstance,
-            )) => Ok(self.open::<MessageType>()?),
-            Err(ServiceAvailabilityState::IncompatibleTypes) => {
-                fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::IncompatibleTypes),
-                    "{} since the service is not type compatible.", msg);
-            }
-            Err(ServiceAvailabilityState::ServiceState(
-                ServiceState::IncompatibleMessagingPattern,
-            )) => {
-                fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::IncompatibleMessagingPattern),
-                    "{} since the services messaging pattern does not match.", msg);
-            }
-            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
-                fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::ServiceInCorruptedState),
-                    "{} since the service is in a corrupted state.", msg);
-            }
-            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
-                fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::PermissionDenied),
-                    "{} due to insufficient permissions to access the service.", msg);
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::InternalFailure),
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.is_service_available(&msg) {
+                Ok(Some(_)) => return Ok(self.open_impl::<MessageType>()?),
+                Ok(None) => return Ok(self.create_impl::<MessageType>()?),
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IsBeingCreatedByAnotherInstance,
+                )) => {
+                    // the service is opened as soon as the creation is finished, when the creator
+                    // crashed the service is created again
+                    match self.base.wait_for_creation(&mut adaptive_wait) {
+                        Ok(()) => (),
+                        Err(CreationWaitFailure::HangsInCreation) => {
+                            fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::HangsInCreation),
+                                "{} since the service hangs while being created.", msg);
+                        }
+                        Err(CreationWaitFailure::InternalFailure) => {
+                            fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::InternalFailure),
+                                "{} since the wait for the creation of the service failed.", msg);
+                        }
+                    }
+                }
+                Err(ServiceAvailabilityState::IncompatibleTypes { expected, actual }) => {
+                    fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::IncompatibleTypes { expected, actual }),
+                        "{} since the service is not type compatible.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IncompatibleMessagingPattern,
+                )) => {
+                    fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::IncompatibleMessagingPattern),
+                        "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                    fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::ServiceInCorruptedState),
+                        "{} since the service is in a corrupted state.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                    fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::PermissionDenied),
+                        "{} due to insufficient permissions to access the service.", msg);
+                }
             }
         }
     }
 
-    /// Opens an existing [`Service`].
-    pub fn open<MessageType: Debug>(
+    /// Opens an existing [`Service`]. It is not available when a create-only setting, like
+    /// [`Builder::attributes()`], was used.
+    pub fn open<MessageType>(
+        self,
+    ) -> Result<
+        publish_subscribe::PortFactory<'config, ServiceType, MessageType, UserHeader>,
+        PublishSubscribeOpenError,
+    >
+    where
+        State: SupportsOpen,
+        Payload: TypeIdentity<Type = MessageType>,
+        MessageType: PayloadType + ?Sized + TypeIdentity<Type = Payload>,
+    {
+        self.open_impl::<MessageType>()
+    }
+
+    /// Opens an existing [`Service`] when its attributes satisfy all requirements of the
+    /// [`AttributeVerifier`], otherwise it fails with
+    /// [`PublishSubscribeOpenError::IncompatibleAttributes`].
+    #[deprecated(
+        since = "0.3.0",
+        note = "use required_attributes() followed by open() instead"
+    )]
+    pub fn open_with_attributes<MessageType>(
         mut self,
+        attributes: &AttributeVerifier,
     ) -> Result<
-        publish_subscribe::PortFactory<'config, ServiceType, MessageType>,
+        publish_subscribe::PortFactory<'config, ServiceType, MessageType, UserHeader>,
+        PublishSubscribeOpenError,
+    >
+    where
+        State: SupportsOpen,
+        Payload: TypeIdentity<Type = MessageType>,
+        MessageType: PayloadType + ?Sized + TypeIdentity<Type = Payload>,
+    {
+        self.attribute_verifier = attributes.clone();
+        self.open_impl::<MessageType>()
+    }
+
+    fn open_impl<MessageType: PayloadType + ?Sized>(
+        mut self,
+    ) -> Result<
+        publish_subscribe::PortFactory<'config, ServiceType, MessageType, UserHeader>,
         PublishSubscribeOpenError,
     > {
-        let msg = "Unable to open publish subscribe service";
-        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
+        let msg = format!(
+            "Unable to open publish subscribe service \"{}\"",
+            self.base.service_config.service_name()
+        );
+        self.set_payload_type::<MessageType>();
 
         let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
                                         with PublishSubscribeOpenError::InternalFailure,
                                         "{} since the adaptive wait could not be created.", msg);
 
         loop {
-            match self.is_service_available(msg) {
+            match self.is_service_available(&msg) {
                 Ok(None) => {
                     fail!(from self, with PublishSubscribeOpenError::DoesNotExist,
                         "{} since the service does not exist.", msg);
                 }
                 Ok(Some((static_config, static_storage))) => {
+                    if let Err(unsatisfied) = self
+                        .attribute_verifier
+                        .verify_requirements(static_config.attributes())
+                    {
+                        let requirements = unsatisfied
+                            .iter()
+                            .map(|r| r.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        fail!(from self, with PublishSubscribeOpenError::IncompatibleAttributes(unsatisfied),
+                            "{} since the service does not satisfy the attribute requirements [{}].",
+                            msg, requirements);
+                    }
+
+                    let existing_attributes = static_config.attributes().clone();
+                    let existing_persistence = static_config.persistence();
+                    let existing_instance_uuid = static_config.instance_uuid().to_string();
                     let static_config = self.verify_service_properties(&static_config)?;
 
-                    let dynamic_config = fail!(from self, when self.base.open_dynamic_config_storage(),
-                            with PublishSubscribeOpenError::UnableToOpenDynamicServiceInformation,
-                            "{} since the dynamic service information could not be opened.", msg);
+                    let (dynamic_config, owner_index) = match self
+                        .base
+                        .open_dynamic_config_storage()
+                    {
+                        Ok(v) => v,
+                        Err(e) if e.is_service_removed() => {
+                            fail!(from self, with PublishSubscribeOpenError::DoesNotExist,
+                                "{} since the service was removed while it was opened.", msg);
+                        }
+                        Err(e) => {
+                            fail!(from self, with PublishSubscribeOpenError::UnableToOpenDynamicServiceInformation,
+                                "{} since the dynamic service information could not be opened ({:?}).", msg, e);
+                        }
+                    };
 
                     self.base.service_config.messaging_pattern =
                         MessagingPattern::PublishSubscribe(static_config.clone());
+                    self.base.service_config.attributes = existing_attributes;
+                    self.base.service_config.persistence = existing_persistence;
+                    // the generated uuid of the builder identifies no instance, the ports must
+                    // agree on the one of the opened service
+                    self.base.service_config.instance_uuid = existing_instance_uuid;
 
                     return Ok(publish_subscribe::PortFactory::new(
                         ServiceType::from_state(service::ServiceState::new(
@@ -297,24 +1127,27 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                             self.base.global_config,
                             dynamic_config,
                             static_storage,
+                            owner_index,
+                            self.base.node_id,
+                            service::discovery::announce_change::<ServiceType>,
                         )),
                     ));
                 }
                 Err(ServiceAvailabilityState::ServiceState(
                     ServiceState::IsBeingCreatedByAnotherInstance,
-                )) => {
-                    let timeout = fail!(from self, when adaptive_wait.wait(),
-                                        with PublishSubscribeOpenError::InternalFailure,
-                                        "{} since the adaptive wait failed.", msg);
-
-                    if timeout > self.base.global_config.global.service.creation_timeout {
+                )) => match self.base.wait_for_creation(&mut adaptive_wait) {
+                    Ok(()) => (),
+                    Err(CreationWaitFailure::HangsInCreation) => {
                         fail!(from self, with PublishSubscribeOpenError::HangsInCreation,
-                            "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded. Waited for {:?} but the state did not change.",
-                            msg, self.base.global_config.global.service.creation_timeout, timeout);
+                                "{} since the service hangs while being created.", msg);
                     }
-                }
-                Err(ServiceAvailabilityState::IncompatibleTypes) => {
-                    fail!(from self, with PublishSubscribeOpenError::IncompatibleTypes,
+                    Err(CreationWaitFailure::InternalFailure) => {
+                        fail!(from self, with PublishSubscribeOpenError::InternalFailure,
+                                "{} since the wait for the creation of the service failed.", msg);
+                    }
+                },
+                Err(ServiceAvailabilityState::IncompatibleTypes { expected, actual }) => {
+                    fail!(from self, with PublishSubscribeOpenError::IncompatibleTypes { expected, actual },
                     "{} since the service is not type compatible.", msg);
                 }
                 Err(ServiceAvailabilityState::ServiceState(
@@ -335,17 +1168,168 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         }
     }
 
-    /// Creates a new [`Service`].
-    pub fn create<MessageType: Debug>(
+    /// Opens an existing [`Service`] without comparing the type names of the payload and the
+    /// user header, only their size and alignment must match. It is intended for participants
+    /// whose type is defined in another language and therefore has a different type name.
+    ///
+    /// # Safety
+    ///
+    ///  * The types must have the same memory representation as the types of the service,
+    ///    matching size and alignment is not sufficient to guarantee that.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .create::<u64>()?;
+    ///
+    /// #[repr(C)]
+    /// #[derive(Debug, ZeroCopySend)]
+    /// struct ForeignU64 {
+    ///     value: u64,
+    /// }
+    ///
+    /// let foreign_service = unsafe {
+    ///     zero_copy::Service::new(&service_name)
+    ///         .publish_subscribe()
+    ///         .open_with_type_details_unchecked::<ForeignU64>()?
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn open_with_type_details_unchecked<MessageType>(
         mut self,
     ) -> Result<
-        publish_subscribe::PortFactory<'config, ServiceType, MessageType>,
+        publish_subscribe::PortFactory<'config, ServiceType, MessageType, UserHeader>,
+        PublishSubscribeOpenError,
+    >
+    where
+        State: SupportsOpen,
+        Payload: TypeIdentity<Type = MessageType>,
+        MessageType: PayloadType + ?Sized + TypeIdentity<Type = Payload>,
+    {
+        self.verify_type_names = false;
+        self.open_impl::<MessageType>()
+    }
+
+    /// Opens an existing [`Service`] without knowing its payload type, for instance to bridge
+    /// it to another transport. The payload is accessed as bytes by the ports that are created
+    /// with [`crate::service::port_factory::publisher::PortFactoryPublisher::create_untyped()`]
+    /// and [`crate::service::port_factory::subscriber::PortFactorySubscriber::create_untyped()`],
+    /// they verify the size and alignment of the payload type in the static config. The type
+    /// itself is described by
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::payload_type_details()`].
+    /// The user header is still verified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let _typed_service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .open_untyped()?;
+    ///
+    /// println!("payload type: {}", service.static_config().type_name());
+    /// let subscriber = service.subscriber().create_untyped()?;
+    /// while let Some(sample) = subscriber.receive()? {
+    ///     println!("received bytes: {:?}", sample.payload());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn open_untyped(
+        mut self,
+    ) -> Result<
+        publish_subscribe::PortFactory<'config, ServiceType, [u8], UserHeader>,
+        PublishSubscribeOpenError,
+    >
+    where
+        State: SupportsOpen,
+        Payload: TypeIdentity<Type = [u8]>,
+        [u8]: TypeIdentity<Type = Payload>,
+    {
+        self.verify_payload_type = false;
+        self.open_impl::<[u8]>()
+    }
+
+    /// Creates a new [`Service`]. It is not available when an open-only requirement, like
+    /// [`Builder::required_attributes()`], was used.
+    pub fn create<MessageType>(
+        self,
+    ) -> Result<
+        publish_subscribe::PortFactory<'config, ServiceType, MessageType, UserHeader>,
+        PublishSubscribeCreateError,
+    >
+    where
+        State: SupportsCreate,
+        Payload: TypeIdentity<Type = MessageType>,
+        MessageType: PayloadType + ?Sized + TypeIdentity<Type = Payload>,
+    {
+        self.create_impl::<MessageType>()
+    }
+
+    /// Creates a new [`Service`] with the attributes of the [`AttributeSpecifier`].
+    #[deprecated(
+        since = "0.3.0",
+        note = "use attributes() followed by create() instead"
+    )]
+    pub fn create_with_attributes<MessageType>(
+        mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<
+        publish_subscribe::PortFactory<'config, ServiceType, MessageType, UserHeader>,
+        PublishSubscribeCreateError,
+    >
+    where
+        State: SupportsCreate,
+        Payload: TypeIdentity<Type = MessageType>,
+        MessageType: PayloadType + ?Sized + TypeIdentity<Type = Payload>,
+    {
+        self.attribute_specifier = Some(attributes.clone());
+        self.create_impl::<MessageType>()
+    }
+
+    fn create_impl<MessageType: PayloadType + ?Sized>(
+        mut self,
+    ) -> Result<
+        publish_subscribe::PortFactory<'config, ServiceType, MessageType, UserHeader>,
         PublishSubscribeCreateError,
     > {
         self.adjust_properties_to_meaningful_values();
+        // without explicitly defined attributes, a service that is opened or created is created
+        // with the required key-value pairs
+        let attributes = match self.attribute_specifier.take() {
+            Some(attributes) => attributes,
+            None => self.attribute_verifier.to_specifier(),
+        };
+        self.base.service_config.attributes = attributes.attributes().clone();
+
+        let msg = format!(
+            "Unable to create publish subscribe service \"{}\"",
+            self.base.service_config.service_name()
+        );
+        self.set_payload_type::<MessageType>();
 
-        let msg = "Unable to create publish subscribe service";
-        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
+        let type_alignment = self.config_details().payload_type_details.alignment;
+        if self.verify_payload_alignment && self.config_details().payload_alignment < type_alignment
+        {
+            fail!(from self, with PublishSubscribeCreateError::InvalidPayloadAlignment,
+                "{} since the requested payload alignment of {} is smaller than the alignment {} of the payload type.",
+                msg, self.config_details().payload_alignment, type_alignment);
+        }
+        self.config_details_mut().payload_alignment =
+            self.config_details().payload_alignment.max(type_alignment);
 
         if !self.config_details().enable_safe_overflow
             && (self.config_details().subscriber_max_buffer_size
@@ -355,11 +1339,12 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                 "{} since the history size is greater than the subscriber buffer size. The subscriber buffer size must be always greater or equal to the history size in the non-overflowing setup.", msg);
         }
 
-        match self.is_service_available(msg) {
+        match self.is_service_available(&msg) {
             Ok(None) => {
                 // create static config
                 let static_config = fail!(from self, when self.base.create_static_config_storage(),
-                    with PublishSubscribeCreateError::UnableToCreateStaticServiceInformation,
+                    map StaticStorageCreateError::AlreadyExists => PublishSubscribeCreateError::AlreadyExists,
+                    unmatched PublishSubscribeCreateError::UnableToCreateStaticServiceInformation,
                     "{} since the static service information could not be created.", msg);
 
                 let pubsub_config = self.base.service_config.publish_subscribe();
@@ -380,7 +1365,7 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                         &dynamic_config_setting,
                     ),
                 );
-                let dynamic_config = fail!(from self, when dynamic_config,
+                let (dynamic_config, owner_index) = fail!(from self, when dynamic_config,
                     with PublishSubscribeCreateError::InternalFailure,
                     "{} since the dynamic service segment could not be created.", msg);
 
@@ -393,17 +1378,22 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                             with PublishSubscribeCreateError::Corrupted,
                             "{} since the configuration could not be written to the static storage.", msg);
 
-                return Ok(publish_subscribe::PortFactory::new(
+                service::discovery::announce_change::<ServiceType>(self.base.global_config);
+
+                Ok(publish_subscribe::PortFactory::new(
                     ServiceType::from_state(service::ServiceState::new(
                         self.base.service_config.clone(),
                         self.base.global_config,
                         dynamic_config,
                         unlocked_static_details,
+                        owner_index,
+                        self.base.node_id,
+                        service::discovery::announce_change::<ServiceType>,
                     )),
-                ));
+                ))
             }
             Ok(Some(_))
-            | Err(ServiceAvailabilityState::IncompatibleTypes)
+            | Err(ServiceAvailabilityState::IncompatibleTypes { .. })
             | Err(ServiceAvailabilityState::ServiceState(
                 ServiceState::IncompatibleMessagingPattern,
             )) => {
@@ -454,15 +1444,95 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                 "Setting the maximum amount of publishers to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_publishers = 1;
         }
+
+        if settings.enable_single_subscriber && settings.max_subscribers > 1 {
+            warn!(from origin,
+                "A single subscriber service supports only one subscriber. Adjust the maximum amount of subscribers from {} to 1.",
+                settings.max_subscribers);
+            settings.max_subscribers = 1;
+        }
+
+        if settings.enable_single_subscriber && settings.history_size != 0 {
+            warn!(from origin,
+                "A single subscriber service does not support a history since a modified sample could be delivered again. Adjust the history size from {} to 0.",
+                settings.history_size);
+            settings.history_size = 0;
+        }
+
+        if settings.max_slice_len == 0 {
+            warn!(from origin,
+                "Setting the maximum slice length to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_slice_len = 1;
+        }
+    }
+
+    fn requirements(&self) -> Requirements {
+        let required_settings = self.base.service_config.publish_subscribe();
+        Requirements {
+            max_publishers: self
+                .verify_number_of_publishers
+                .then_some(required_settings.max_publishers),
+            max_subscribers: self
+                .verify_number_of_subscribers
+                .then_some(required_settings.max_subscribers),
+            subscriber_max_buffer_size: self
+                .verify_subscriber_max_buffer_size
+                .then_some(required_settings.subscriber_max_buffer_size),
+            history_size: self
+                .verify_publisher_history_size
+                .then_some(required_settings.history_size),
+            subscriber_max_borrowed_samples: self
+                .verify_subscriber_max_borrowed_samples
+                .then_some(required_settings.subscriber_max_borrowed_samples),
+            enable_single_subscriber: self
+                .verify_enable_single_subscriber
+                .then_some(required_settings.enable_single_subscriber),
+            enable_notifications: self
+                .verify_enable_notifications
+                .then_some(required_settings.enable_notifications),
+            max_slice_len: self
+                .verify_max_slice_len
+                .then_some(required_settings.max_slice_len),
+            payload_alignment: self
+                .verify_payload_alignment
+                .then_some(required_settings.payload_alignment),
+            clock_type: self
+                .verify_clock_type
+                .then_some(required_settings.clock_type),
+            unable_to_deliver_strategy: self
+                .verify_unable_to_deliver_strategy
+                .then_some(required_settings.unable_to_deliver_strategy),
+            allow_user_time_stamps: self
+                .verify_allow_user_time_stamps
+                .then_some(required_settings.allow_user_time_stamps),
+            // a deadline is verified only when it was set
+            deadline: if self.verify_deadline {
+                required_settings.deadline
+            } else {
+                None
+            },
+            enable_safe_overflow: self
+                .verify_enable_safe_overflow
+                .then_some(required_settings.enable_safe_overflow),
+            enable_deterministic_memory: self
+                .verify_enable_deterministic_memory
+                .then_some(required_settings.enable_deterministic_memory),
+            // the types are verified before the settings, honoring the disabled type name
+            // verification, see `is_type_compatible()`
+            payload_type_details: None,
+            user_header_type_details: None,
+        }
     }
 
     fn verify_service_properties(
         &self,
         existing_settings: &static_config::StaticConfig,
     ) -> Result<static_config::publish_subscribe::StaticConfig, PublishSubscribeOpenError> {
-        let msg = "Unable to open publish subscribe service";
+        let msg = format!(
+            "Unable to open publish subscribe service \"{}\"",
+            self.base.service_config.service_name()
+        );
 
-        let required_settings = self.base.service_config.publish_subscribe();
         let existing_settings = match &existing_settings.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v,
             p => {
@@ -471,54 +1541,15 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
             }
         };
 
-        if self.verify_number_of_publishers
-            && existing_settings.max_publishers < required_settings.max_publishers
-        {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers,
-                                "{} since the service supports only {} publishers but a support of {} publishers was requested.",
-                                msg, existing_settings.max_publishers, required_settings.max_publishers);
-        }
-
-        if self.verify_number_of_subscribers
-            && existing_settings.max_subscribers < required_settings.max_subscribers
-        {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers,
-                                "{} since the service supports only {} subscribers but a support of {} subscribers was requested.",
-                                msg, existing_settings.max_subscribers, required_settings.max_subscribers);
-        }
-
-        if self.verify_subscriber_max_buffer_size
-            && existing_settings.subscriber_max_buffer_size
-                < required_settings.subscriber_max_buffer_size
-        {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize,
-                                "{} since the service supports only a subscriber buffer size of {} but a buffer size of {} was requested.",
-                                msg, existing_settings.subscriber_max_buffer_size, required_settings.subscriber_max_buffer_size);
-        }
-
-        if self.verify_publisher_history_size
-            && existing_settings.history_size < required_settings.history_size
-        {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize,
-                                "{} since the service supports only a history size of {} but a history size of {} was requested.",
-                                msg, existing_settings.history_size, required_settings.history_size);
-        }
-
-        if self.verify_subscriber_max_borrowed_samples
-            && existing_settings.subscriber_max_borrowed_samples
-                < required_settings.subscriber_max_borrowed_samples
-        {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples,
-                                "{} since the service supports only {} borrowed subscriber samples but a {} borrowed subscriber samples were requested.",
-                                msg, existing_settings.subscriber_max_borrowed_samples, required_settings.subscriber_max_borrowed_samples);
-        }
-
-        if self.verify_enable_safe_overflow
-            && existing_settings.enable_safe_overflow != required_settings.enable_safe_overflow
-        {
-            fail!(from self, with PublishSubscribeOpenError::IncompatibleOverflowBehavior,
-                                "{} since the service has an incompatible safe overflow behavior.",
-                                msg);
+        let incompatible_settings = existing_settings.compatibility_diff(&self.requirements());
+        if !incompatible_settings.is_empty() {
+            let diff = incompatible_settings
+                .iter()
+                .map(|setting| setting.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleSettings(incompatible_settings),
+                "{} since the service has incompatible settings: {}.", msg, diff);
         }
 
         Ok(existing_settings.clone())
@@ -0,0 +1,659 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! See [`crate::service`]
+//!
+use crate::service;
+use crate::service::attribute::{AttributeSpecifier, AttributeVerifier};
+use crate::service::dynamic_config::request_response::DynamicConfigSettings;
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::port_factory::request_response;
+use crate::service::static_config::publish_subscribe::TypeDetails;
+use crate::service::static_config::ServicePersistence;
+use crate::service::*;
+use crate::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::static_storage::{StaticStorageCreateError, StaticStorageLocked};
+use std::alloc::Layout;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use super::{CreationWaitFailure, ServiceState};
+
+/// Errors that can occur when an existing [`MessagingPattern::RequestResponse`] [`Service`] shall
+/// be opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum RequestResponseOpenError {
+    DoesNotExist,
+    InternalFailure,
+    IncompatibleTypes,
+    IncompatibleMessagingPattern,
+    IncompatibleAttributes,
+    DoesNotSupportRequestedAmountOfClients,
+    DoesNotSupportRequestedAmountOfServers,
+    DoesNotSupportRequestedAmountOfActiveRequests,
+    DoesNotSupportRequestedAmountOfResponsesPerRequest,
+    PermissionDenied,
+    ServiceInCorruptedState,
+    HangsInCreation,
+    UnableToOpenDynamicServiceInformation,
+}
+
+impl std::fmt::Display for RequestResponseOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let hint = match self {
+            RequestResponseOpenError::DoesNotExist => "no service with this name exists, create it first or use open_or_create()",
+            RequestResponseOpenError::InternalFailure => "an internal failure occurred, check the log output for details",
+            RequestResponseOpenError::IncompatibleTypes => "the service exists with different payload types, use the types it was created with",
+            RequestResponseOpenError::IncompatibleMessagingPattern => "the service exists with another messaging pattern",
+            RequestResponseOpenError::IncompatibleAttributes => "the service does not have the required attributes",
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients => "the service supports fewer clients than requested",
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers => "the service supports fewer servers than requested",
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequests => "the service supports fewer active requests per client than requested",
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfResponsesPerRequest => "the service supports fewer responses per request than requested",
+            RequestResponseOpenError::PermissionDenied => "the process lacks the permissions to access the service",
+            RequestResponseOpenError::ServiceInCorruptedState => "the service resources are corrupted, remove the stale resources of the service",
+            RequestResponseOpenError::HangsInCreation => "another process did not finish the creation of the service in time, it may have crashed during creation",
+            RequestResponseOpenError::UnableToOpenDynamicServiceInformation => "the dynamic service information could not be opened, the service may be in the process of being removed",
+        };
+        std::write!(f, "{}::{:?}: {}", std::stringify!(Self), self, hint)
+    }
+}
+
+impl std::error::Error for RequestResponseOpenError {}
+
+/// Errors that can occur when a new [`MessagingPattern::RequestResponse`] [`Service`] shall be
+/// created.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum RequestResponseCreateError {
+    Corrupted,
+    AlreadyExists,
+    PermissionDenied,
+    InternalFailure,
+    IsBeingCreatedByAnotherInstance,
+    UnableToCreateStaticServiceInformation,
+}
+
+impl std::fmt::Display for RequestResponseCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let hint = match self {
+            RequestResponseCreateError::Corrupted => "leftovers of an old instance of the service exist, remove the stale resources of the service",
+            RequestResponseCreateError::AlreadyExists => "a service with this name already exists, open it or use open_or_create()",
+            RequestResponseCreateError::PermissionDenied => "the process lacks the permissions to create the service resources",
+            RequestResponseCreateError::InternalFailure => "an internal failure occurred, check the log output for details",
+            RequestResponseCreateError::IsBeingCreatedByAnotherInstance => "another process creates the service right now, open it once the creation is finished",
+            RequestResponseCreateError::UnableToCreateStaticServiceInformation => "the static service information could not be written, check the permissions of the service directory",
+        };
+        std::write!(f, "{}::{:?}: {}", std::stringify!(Self), self, hint)
+    }
+}
+
+impl std::error::Error for RequestResponseCreateError {}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum ServiceAvailabilityState {
+    ServiceState(ServiceState),
+    IncompatibleTypes,
+}
+
+enum_gen! {
+    /// Errors that can occur when a [`MessagingPattern::RequestResponse`] [`Service`] shall be
+    /// created or opened.
+    #[non_exhaustive]
+    RequestResponseOpenOrCreateError
+  mapping:
+    RequestResponseOpenError,
+    RequestResponseCreateError
+}
+
+impl std::fmt::Display for RequestResponseOpenOrCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestResponseOpenOrCreateError {}
+
+/// Builder to create new [`MessagingPattern::RequestResponse`] based [`Service`]s. The
+/// `RequestPayload` is sent by the [`crate::port::client::Client`]s and every
+/// [`crate::port::server::Server`] answers it with one or more `ResponsePayload`s. Both
+/// payloads are shared between processes and must implement [`ZeroCopySend`].
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Builder<
+    'config,
+    ServiceType: service::Details<'config>,
+    RequestPayload: Debug + ZeroCopySend,
+    ResponsePayload: Debug + ZeroCopySend,
+> {
+    base: builder::BuilderWithServiceType<'config, ServiceType>,
+    verify_number_of_clients: bool,
+    verify_number_of_servers: bool,
+    verify_max_active_requests: bool,
+    verify_max_responses_per_request: bool,
+    _phantom_request_payload: PhantomData<RequestPayload>,
+    _phantom_response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<
+        'config,
+        ServiceType: service::Details<'config>,
+        RequestPayload: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend,
+    > Builder<'config, ServiceType, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(base: builder::BuilderWithServiceType<'config, ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            verify_number_of_clients: false,
+            verify_number_of_servers: false,
+            verify_max_active_requests: false,
+            verify_max_responses_per_request: false,
+            _phantom_request_payload: PhantomData,
+            _phantom_response_payload: PhantomData,
+        };
+
+        let mut static_config =
+            static_config::request_response::StaticConfig::new(new_self.base.global_config);
+        static_config.request_type_details =
+            TypeDetails::new::<RequestPayload>(Layout::new::<RequestPayload>());
+        static_config.response_type_details =
+            TypeDetails::new::<ResponsePayload>(Layout::new::<ResponsePayload>());
+        new_self.base.service_config.messaging_pattern =
+            MessagingPattern::RequestResponse(static_config);
+
+        new_self
+    }
+
+    fn config_details_mut(&mut self) -> &mut static_config::request_response::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::RequestResponse(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
+            }
+        }
+    }
+
+    fn config_details(&self) -> &static_config::request_response::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
+            }
+        }
+    }
+
+    /// If the [`Service`] is created it defines whether it is removed when its last instance
+    /// is dropped or whether it persists until it is removed explicitly with
+    /// [`crate::service::Details::remove()`]. If an existing [`Service`] is opened the setting
+    /// is ignored, the service keeps the persistence it was created with.
+    pub fn persistence(mut self, value: ServicePersistence) -> Self {
+        self.base.service_config.persistence = value;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::client::Client`]s shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::client::Client`]s must be at least supported.
+    pub fn max_clients(mut self, value: usize) -> Self {
+        self.config_details_mut().max_clients = value;
+        self.verify_number_of_clients = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::server::Server`]s shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::server::Server`]s must be at least supported.
+    pub fn max_servers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_servers = value;
+        self.verify_number_of_servers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many requests a
+    /// [`crate::port::client::Client`] can have in flight at the same time, loaned requests and
+    /// [`crate::port::client::PendingResponse`]s are both counted. If an existing [`Service`] is
+    /// opened it defines how many active requests must be at least supported.
+    pub fn max_active_requests(mut self, value: usize) -> Self {
+        self.config_details_mut().max_active_requests = value;
+        self.verify_max_active_requests = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many responses a
+    /// [`crate::port::server::Server`] can send for a single request. If an existing
+    /// [`Service`] is opened it defines how many responses per request must be at least
+    /// supported.
+    pub fn max_responses_per_request(mut self, value: usize) -> Self {
+        self.config_details_mut().max_responses_per_request = value;
+        self.verify_max_responses_per_request = true;
+        self
+    }
+
+    fn is_service_available(
+        &mut self,
+        error_msg: &str,
+    ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
+        match self.base.is_service_available() {
+            Ok(Some((config, storage))) => {
+                let existing_settings = config.request_response();
+                let required_settings = self.config_details();
+
+                if existing_settings.request_type_details != required_settings.request_type_details
+                {
+                    warn!(from self,
+                        "{} since the service offers the request type {} but the requested request type is {}.",
+                        error_msg, existing_settings.request_type_details, required_settings.request_type_details);
+                    return Err(ServiceAvailabilityState::IncompatibleTypes);
+                }
+
+                if existing_settings.response_type_details
+                    != required_settings.response_type_details
+                {
+                    warn!(from self,
+                        "{} since the service offers the response type {} but the requested response type is {}.",
+                        error_msg, existing_settings.response_type_details, required_settings.response_type_details);
+                    return Err(ServiceAvailabilityState::IncompatibleTypes);
+                }
+
+                Ok(Some((config, storage)))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => Err(ServiceAvailabilityState::ServiceState(e)),
+        }
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<
+        request_response::PortFactory<'config, ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened when it satisfies the requirements of the
+    /// [`AttributeVerifier`]. Otherwise a new [`Service`] will be created, its attributes are
+    /// the required key-value pairs of the [`AttributeVerifier`].
+    pub fn open_or_create_with_attributes(
+        mut self,
+        attributes: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<'config, ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseOpenOrCreateError,
+    > {
+        let msg = format!(
+            "Unable to open or create request response service \"{}\"",
+            self.base.service_config.service_name()
+        );
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::InternalFailure),
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.is_service_available(&msg) {
+                Ok(Some(_)) => return Ok(self.open_with_attributes(attributes)?),
+                Ok(None) => return Ok(self.create_with_attributes(&attributes.to_specifier())?),
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IsBeingCreatedByAnotherInstance,
+                )) => {
+                    // the service is opened as soon as the creation is finished, when the creator
+                    // crashed the service is created again
+                    match self.base.wait_for_creation(&mut adaptive_wait) {
+                        Ok(()) => (),
+                        Err(CreationWaitFailure::HangsInCreation) => {
+                            fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::HangsInCreation),
+                                "{} since the service hangs while being created.", msg);
+                        }
+                        Err(CreationWaitFailure::InternalFailure) => {
+                            fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::InternalFailure),
+                                "{} since the wait for the creation of the service failed.", msg);
+                        }
+                    }
+                }
+                Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                    fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::IncompatibleTypes),
+                        "{} since the service is not type compatible.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IncompatibleMessagingPattern,
+                )) => {
+                    fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::IncompatibleMessagingPattern),
+                        "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                    fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::ServiceInCorruptedState),
+                        "{} since the service is in a corrupted state.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                    fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::PermissionDenied),
+                        "{} due to insufficient permissions to access the service.", msg);
+                }
+            }
+        }
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        self,
+    ) -> Result<
+        request_response::PortFactory<'config, ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseOpenError,
+    > {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] when its attributes satisfy all requirements of the
+    /// [`AttributeVerifier`], otherwise it fails with
+    /// [`RequestResponseOpenError::IncompatibleAttributes`].
+    pub fn open_with_attributes(
+        mut self,
+        attributes: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<'config, ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseOpenError,
+    > {
+        let msg = format!(
+            "Unable to open request response service \"{}\"",
+            self.base.service_config.service_name()
+        );
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with RequestResponseOpenError::InternalFailure,
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.is_service_available(&msg) {
+                Ok(None) => {
+                    fail!(from self, with RequestResponseOpenError::DoesNotExist,
+                        "{} since the service does not exist.", msg);
+                }
+                Ok(Some((static_config, static_storage))) => {
+                    if let Err(unsatisfied) =
+                        attributes.verify_requirements(static_config.attributes())
+                    {
+                        fail!(from self, with RequestResponseOpenError::IncompatibleAttributes,
+                            "{} since the service does not satisfy the attribute requirements [{}].",
+                            msg, unsatisfied.iter().map(|r| r.to_string()).collect::<Vec<_>>().join(", "));
+                    }
+
+                    let existing_attributes = static_config.attributes().clone();
+                    let existing_persistence = static_config.persistence();
+                    let existing_instance_uuid = static_config.instance_uuid().to_string();
+                    let static_config = self.verify_service_properties(&static_config)?;
+
+                    let (dynamic_config, owner_index) = match self
+                        .base
+                        .open_dynamic_config_storage()
+                    {
+                        Ok(v) => v,
+                        Err(e) if e.is_service_removed() => {
+                            fail!(from self, with RequestResponseOpenError::DoesNotExist,
+                                "{} since the service was removed while it was opened.", msg);
+                        }
+                        Err(e) => {
+                            fail!(from self, with RequestResponseOpenError::UnableToOpenDynamicServiceInformation,
+                                "{} since the dynamic service information could not be opened ({:?}).", msg, e);
+                        }
+                    };
+
+                    self.base.service_config.messaging_pattern =
+                        MessagingPattern::RequestResponse(static_config);
+                    self.base.service_config.attributes = existing_attributes;
+                    self.base.service_config.persistence = existing_persistence;
+                    self.base.service_config.instance_uuid = existing_instance_uuid;
+
+                    return Ok(request_response::PortFactory::new(ServiceType::from_state(
+                        service::ServiceState::new(
+                            self.base.service_config,
+                            self.base.global_config,
+                            dynamic_config,
+                            static_storage,
+                            owner_index,
+                            self.base.node_id,
+                            service::discovery::announce_change::<ServiceType>,
+                        ),
+                    )));
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IsBeingCreatedByAnotherInstance,
+                )) => match self.base.wait_for_creation(&mut adaptive_wait) {
+                    Ok(()) => (),
+                    Err(CreationWaitFailure::HangsInCreation) => {
+                        fail!(from self, with RequestResponseOpenError::HangsInCreation,
+                                "{} since the service hangs while being created.", msg);
+                    }
+                    Err(CreationWaitFailure::InternalFailure) => {
+                        fail!(from self, with RequestResponseOpenError::InternalFailure,
+                                "{} since the wait for the creation of the service failed.", msg);
+                    }
+                },
+                Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                    fail!(from self, with RequestResponseOpenError::IncompatibleTypes,
+                    "{} since the service is not type compatible.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IncompatibleMessagingPattern,
+                )) => {
+                    fail!(from self, with RequestResponseOpenError::IncompatibleMessagingPattern,
+                    "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                    fail!(from self, with RequestResponseOpenError::ServiceInCorruptedState,
+                    "{} since the service is in a corrupted state.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                    fail!(from self, with RequestResponseOpenError::PermissionDenied,
+                    "{} due to insufficient permissions to access the service.", msg);
+                }
+            }
+        }
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        self,
+    ) -> Result<
+        request_response::PortFactory<'config, ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseCreateError,
+    > {
+        self.create_with_attributes(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`] with the attributes of the [`AttributeSpecifier`].
+    pub fn create_with_attributes(
+        mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<
+        request_response::PortFactory<'config, ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseCreateError,
+    > {
+        self.adjust_properties_to_meaningful_values();
+        self.base.service_config.attributes = attributes.attributes().clone();
+
+        let msg = format!(
+            "Unable to create request response service \"{}\"",
+            self.base.service_config.service_name()
+        );
+
+        match self.is_service_available(&msg) {
+            Ok(None) => {
+                let static_config = fail!(from self, when self.base.create_static_config_storage(),
+                    map StaticStorageCreateError::AlreadyExists => RequestResponseCreateError::AlreadyExists,
+                    unmatched RequestResponseCreateError::UnableToCreateStaticServiceInformation,
+                    "{} since the static service information could not be created.", msg);
+
+                let request_response_config = self.base.service_config.request_response();
+
+                let dynamic_config_setting = DynamicConfigSettings {
+                    number_of_clients: request_response_config.max_clients,
+                    number_of_servers: request_response_config.max_servers,
+                };
+
+                let dynamic_config = self.base.create_dynamic_config_storage(
+                    dynamic_config::MessagingPattern::RequestResponse(
+                        dynamic_config::request_response::DynamicConfig::new(
+                            &dynamic_config_setting,
+                        ),
+                    ),
+                    dynamic_config::request_response::DynamicConfig::memory_size(
+                        &dynamic_config_setting,
+                    ),
+                );
+                let (dynamic_config, owner_index) = fail!(from self, when dynamic_config,
+                    with RequestResponseCreateError::InternalFailure,
+                    "{} since the dynamic service segment could not be created.", msg);
+
+                let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                            with RequestResponseCreateError::Corrupted,
+                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with RequestResponseCreateError::Corrupted,
+                            "{} since the configuration could not be written to the static storage.", msg);
+
+                service::discovery::announce_change::<ServiceType>(self.base.global_config);
+
+                Ok(request_response::PortFactory::new(ServiceType::from_state(
+                    service::ServiceState::new(
+                        self.base.service_config.clone(),
+                        self.base.global_config,
+                        dynamic_config,
+                        unlocked_static_details,
+                        owner_index,
+                        self.base.node_id,
+                        service::discovery::announce_change::<ServiceType>,
+                    ),
+                )))
+            }
+            Ok(Some(_))
+            | Err(ServiceAvailabilityState::IncompatibleTypes)
+            | Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with RequestResponseCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with RequestResponseCreateError::PermissionDenied,
+                    "{} due to possible insufficient permissions to access the underlying service details.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with RequestResponseCreateError::Corrupted,
+                    "{} since a service in a corrupted state already exists. A cleanup of the service constructs may help.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => {
+                fail!(from self, with RequestResponseCreateError::IsBeingCreatedByAnotherInstance,
+                    "{} since the service is being created by another instance.", msg);
+            }
+        }
+    }
+
+    fn adjust_properties_to_meaningful_values(&mut self) {
+        let origin = format!("{:?}", self);
+        let settings = self.base.service_config.request_response_mut();
+
+        if settings.max_clients == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of clients to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_clients = 1;
+        }
+
+        if settings.max_servers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of servers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_servers = 1;
+        }
+
+        if settings.max_active_requests == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of active requests to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_active_requests = 1;
+        }
+
+        if settings.max_responses_per_request == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of responses per request to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_responses_per_request = 1;
+        }
+    }
+
+    fn verify_service_properties(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+    ) -> Result<static_config::request_response::StaticConfig, RequestResponseOpenError> {
+        let msg = format!(
+            "Unable to open request response service \"{}\"",
+            self.base.service_config.service_name()
+        );
+
+        let required_settings = self.config_details();
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            p => {
+                fail!(from self, with RequestResponseOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::RequestResponse is required.", msg, p);
+            }
+        };
+
+        if self.verify_number_of_clients
+            && existing_settings.max_clients < required_settings.max_clients
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients,
+                "{} since the service supports only {} clients but a support of {} clients was requested.",
+                msg, existing_settings.max_clients, required_settings.max_clients);
+        }
+
+        if self.verify_number_of_servers
+            && existing_settings.max_servers < required_settings.max_servers
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers,
+                "{} since the service supports only {} servers but a support of {} servers was requested.",
+                msg, existing_settings.max_servers, required_settings.max_servers);
+        }
+
+        if self.verify_max_active_requests
+            && existing_settings.max_active_requests < required_settings.max_active_requests
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequests,
+                "{} since the service supports only {} active requests per client but a support of {} active requests was requested.",
+                msg, existing_settings.max_active_requests, required_settings.max_active_requests);
+        }
+
+        if self.verify_max_responses_per_request
+            && existing_settings.max_responses_per_request
+                < required_settings.max_responses_per_request
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfResponsesPerRequest,
+                "{} since the service supports only {} responses per request but a support of {} responses was requested.",
+                msg, existing_settings.max_responses_per_request, required_settings.max_responses_per_request);
+        }
+
+        Ok(existing_settings.clone())
+    }
+}
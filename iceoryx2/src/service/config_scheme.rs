@@ -16,6 +16,7 @@ use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_cal::named_concept::{NamedConceptConfiguration, NamedConceptMgmt};
+use iceoryx2_cal::zero_copy_connection::process_local;
 
 fn generate_default_config<T: NamedConceptConfiguration>(
     origin: &str,
@@ -56,29 +57,58 @@ pub(crate) fn dynamic_config_storage_config<'config, Service: crate::service::De
     )
 }
 
+fn root_path_with_directory(origin: &str, msg: &str, root_path: Path, directory: &str) -> Path {
+    let mut path_hint = root_path;
+    let directory_entry: FixedSizeByteString<{ FileName::max_len() }> = fatal_panic!(from origin,
+            when FixedSizeByteString::from_bytes(directory.as_bytes()),
+            "{} since the directory entry \"{}\" is invalid.",
+            msg, directory);
+
+    fatal_panic!(from origin, when path_hint.add_path_entry(&directory_entry),
+            "{} since the combination of root directory and directory entry result in an invalid directory \"{}{}\".",
+            msg, path_hint, directory_entry);
+
+    path_hint
+}
+
 pub(crate) fn static_config_storage_config<'config, Service: crate::service::Details<'config>>(
     global_config: &config::Config,
 ) -> <Service::StaticStorage as NamedConceptMgmt>::Configuration {
     let origin = "static_config_storage_config";
-    let msg = "Unable to generate static config storage directory";
-    let mut path_hint = global_config.global.root_path();
-    let service_directory: FixedSizeByteString<{ FileName::max_len() }> = fatal_panic!(from origin,
-            when FixedSizeByteString::from_bytes(global_config.global.service.directory.as_bytes()),
-            "{} since the directory entry \"{}\" is invalid.",
-            msg, global_config.global.service.directory);
-
-    fatal_panic!(from origin, when path_hint.add_path_entry(&service_directory),
-            "{} since the combination of root directory and service directory entry result in an invalid directory \"{}{}\".",
-            msg, path_hint, service_directory);
+    let path_hint = root_path_with_directory(
+        origin,
+        "Unable to generate static config storage directory",
+        global_config.global.root_path(),
+        &global_config.global.service.directory,
+    );
 
     generate_default_config::<<Service::StaticStorage as NamedConceptMgmt>::Configuration>(
-        "static_config_storage_config",
+        origin,
         &global_config.global.prefix,
         &global_config.global.service.static_config_storage_suffix,
         &path_hint,
     )
 }
 
+pub(crate) fn node_details_config<'config, Service: crate::service::Details<'config>>(
+    global_config: &config::Config,
+) -> <Service::StaticStorage as NamedConceptMgmt>::Configuration {
+    let origin = "node_details_config";
+    let path_hint = root_path_with_directory(
+        origin,
+        "Unable to generate node details directory",
+        global_config.global.root_path(),
+        &global_config.global.node.directory,
+    );
+
+    generate_default_config::<<Service::StaticStorage as NamedConceptMgmt>::Configuration>(
+        origin,
+        &global_config.global.prefix,
+        &global_config.global.node.static_config_suffix,
+        &path_hint,
+    )
+}
+
 pub(crate) fn connection_config<'config, Service: crate::service::Details<'config>>(
     global_config: &config::Config,
 ) -> <Service::Connection as NamedConceptMgmt>::Configuration {
@@ -90,6 +120,54 @@ pub(crate) fn connection_config<'config, Service: crate::service::Details<'confi
     )
 }
 
+pub(crate) fn intra_process_connection_config(
+    global_config: &config::Config,
+) -> <process_local::Connection as NamedConceptMgmt>::Configuration {
+    generate_default_config::<<process_local::Connection as NamedConceptMgmt>::Configuration>(
+        "intra_process_connection_config",
+        &global_config.global.prefix,
+        &global_config.global.service.connection_suffix,
+        &global_config.global.root_path(),
+    )
+}
+
+pub(crate) fn event_config<'config, Service: crate::service::Details<'config>>(
+    global_config: &config::Config,
+) -> <Service::Event as NamedConceptMgmt>::Configuration {
+    generate_default_config::<<Service::Event as NamedConceptMgmt>::Configuration>(
+        "event_config",
+        &global_config.global.prefix,
+        &global_config.global.service.event_connection_suffix,
+        // socket based events require an existing directory, the root path may not exist yet
+        &iceoryx2_bb_posix::config::temp_directory(),
+    )
+}
+
+pub(crate) fn discovery_event_config<'config, Service: crate::service::Details<'config>>(
+    global_config: &config::Config,
+) -> <Service::Event as NamedConceptMgmt>::Configuration {
+    generate_default_config::<<Service::Event as NamedConceptMgmt>::Configuration>(
+        "discovery_event_config",
+        &global_config.global.prefix,
+        &global_config.global.service.discovery_event_suffix,
+        &iceoryx2_bb_posix::config::temp_directory(),
+    )
+}
+
+pub(crate) fn connection_monitor_event_config<
+    'config,
+    Service: crate::service::Details<'config>,
+>(
+    global_config: &config::Config,
+) -> <Service::Event as NamedConceptMgmt>::Configuration {
+    generate_default_config::<<Service::Event as NamedConceptMgmt>::Configuration>(
+        "connection_monitor_event_config",
+        &global_config.global.prefix,
+        &global_config.global.service.connection_monitor_event_suffix,
+        &iceoryx2_bb_posix::config::temp_directory(),
+    )
+}
+
 pub(crate) fn data_segment_config<'config, Service: crate::service::Details<'config>>(
     global_config: &config::Config,
 ) -> <Service::SharedMemory as NamedConceptMgmt>::Configuration {
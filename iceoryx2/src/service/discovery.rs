@@ -0,0 +1,317 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::discovery::{ServiceDiscovery, ServiceEvent};
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut discovery = ServiceDiscovery::<zero_copy::Service>::new()?;
+//!
+//! // the services that existed before the discovery was created
+//! for service in discovery.services() {
+//!     println!("existing service: {}", service.service_name());
+//! }
+//!
+//! for event in discovery.timed_wait_for_change(Duration::from_millis(100))? {
+//!     match event {
+//!         ServiceEvent::Created(service) => println!("created: {}", service.service_name()),
+//!         ServiceEvent::Removed(service) => println!("removed: {}", service.service_name()),
+//!     }
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::{Duration, Instant};
+
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, trace};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::event::{Event, Listener, ListenerBuilder, Notifier, NotifierBuilder};
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptMgmt};
+
+use crate::config;
+use crate::port::event_id::EventId;
+use crate::service::config_scheme::discovery_event_config;
+use crate::service::naming_scheme::discovery_event_concept_name;
+use crate::service::{Details, ServiceDetails, ServiceListError};
+
+enum_gen! {
+    /// Defines the failures that can occur when a [`ServiceDiscovery`] is created.
+    ServiceDiscoveryCreateError
+  entry:
+    UnableToCreateNotificationListener,
+    UnableToAcquireSystemId
+  mapping:
+    ServiceListError to ListError
+}
+
+impl std::fmt::Display for ServiceDiscoveryCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServiceDiscoveryCreateError {}
+
+enum_gen! {
+    /// Defines the failures that can occur while a [`ServiceDiscovery`] waits for a change with
+    /// [`ServiceDiscovery::try_wait_for_change()`],
+    /// [`ServiceDiscovery::timed_wait_for_change()`] or
+    /// [`ServiceDiscovery::blocking_wait_for_change()`].
+    ServiceDiscoveryWaitError
+  entry:
+    InternalFailure
+  mapping:
+    ServiceListError to ListError
+}
+
+impl std::fmt::Display for ServiceDiscoveryWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServiceDiscoveryWaitError {}
+
+/// A change of the services in the system that was detected by a [`ServiceDiscovery`]. The
+/// [`ServiceDetails`] contain the static details of the service so that one can decide
+/// whether to open it without another lookup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServiceEvent {
+    /// The service was created.
+    Created(ServiceDetails),
+    /// The service was removed, it contains the details of the service when it was seen the
+    /// last time.
+    Removed(ServiceDetails),
+}
+
+impl ServiceEvent {
+    /// Returns the [`ServiceDetails`] of the service that was created or removed.
+    pub fn details(&self) -> &ServiceDetails {
+        match self {
+            ServiceEvent::Created(details) => details,
+            ServiceEvent::Removed(details) => details,
+        }
+    }
+}
+
+/// Watches the services that are created under a [`config::Config`] and reports when they
+/// are created or removed. It owns an event concept that is notified by every process that
+/// creates or removes a service, therefore waiting for a change does not poll
+/// [`Details::list_details()`].
+///
+/// The services that existed already when the [`ServiceDiscovery`] was created are available
+/// via [`ServiceDiscovery::services()`], the changes afterwards are reported as
+/// [`ServiceEvent`]s. Nothing is missed in between since the event concept is created before
+/// the services are listed the first time.
+#[derive(Debug)]
+pub struct ServiceDiscovery<'config, Service: Details<'config>> {
+    listener: <Service::Event as Event<EventId>>::Listener,
+    services: Vec<ServiceDetails>,
+    config: &'config config::Config,
+}
+
+impl<'config, Service: Details<'config>> ServiceDiscovery<'config, Service> {
+    /// Creates a new [`ServiceDiscovery`] for the services created with the global config.
+    pub fn new() -> Result<Self, ServiceDiscoveryCreateError> {
+        Self::with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Creates a new [`ServiceDiscovery`] for the services created under a given
+    /// [`config::Config`].
+    pub fn with_custom_config(
+        config: &'config config::Config,
+    ) -> Result<Self, ServiceDiscoveryCreateError> {
+        let msg = "Unable to create the service discovery";
+        let origin = "ServiceDiscovery::with_custom_config()";
+
+        let id = fail!(from origin, when UniqueSystemId::new(),
+            with ServiceDiscoveryCreateError::UnableToAcquireSystemId,
+            "{} since no unique system id could be acquired.", msg);
+
+        let event_name = discovery_event_concept_name(id);
+        let listener = fail!(from origin,
+            when <Service::Event as Event<EventId>>::ListenerBuilder::new(&event_name)
+                .config(&discovery_event_config::<Service>(config))
+                .create(),
+            with ServiceDiscoveryCreateError::UnableToCreateNotificationListener,
+            "{} since the underlying event concept \"{}\" could not be created.", msg, event_name);
+
+        // the listener exists before the services are listed, every change afterwards is
+        // announced to it
+        let services = fail!(from origin, when Service::list_details_with_custom_config(config),
+            "{} since the existing services could not be listed.", msg);
+
+        Ok(Self {
+            listener,
+            services,
+            config,
+        })
+    }
+
+    /// Returns the [`ServiceDetails`] of all services that exist according to the last
+    /// detected change. Directly after the creation it is the snapshot of all services that
+    /// existed already.
+    pub fn services(&self) -> &[ServiceDetails] {
+        &self.services
+    }
+
+    /// Returns the changes since the last call without waiting. When nothing changed the
+    /// returned list is empty.
+    pub fn try_wait_for_change(&mut self) -> Result<Vec<ServiceEvent>, ServiceDiscoveryWaitError> {
+        while let Ok(Some(_)) = self.listener.try_wait() {}
+        self.update()
+    }
+
+    /// Waits until a service is created or removed or the timeout has passed and returns the
+    /// changes. On timeout the returned list is empty.
+    pub fn timed_wait_for_change(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<Vec<ServiceEvent>, ServiceDiscoveryWaitError> {
+        self.wait_for_change(Some(Instant::now() + timeout))
+    }
+
+    /// Waits until a service is created or removed and returns the changes.
+    pub fn blocking_wait_for_change(
+        &mut self,
+    ) -> Result<Vec<ServiceEvent>, ServiceDiscoveryWaitError> {
+        self.wait_for_change(None)
+    }
+
+    fn wait_for_change(
+        &mut self,
+        deadline: Option<Instant>,
+    ) -> Result<Vec<ServiceEvent>, ServiceDiscoveryWaitError> {
+        let msg = "Unable to wait for a change of the services";
+
+        loop {
+            let changes = self.try_wait_for_change()?;
+            if !changes.is_empty() {
+                return Ok(changes);
+            }
+
+            let wait_result = match deadline {
+                None => self.listener.blocking_wait(),
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(timeout) if !timeout.is_zero() => self.listener.timed_wait(timeout),
+                    _ => return Ok(changes),
+                },
+            };
+
+            if let Err(e) = wait_result {
+                fail!(from self, with ServiceDiscoveryWaitError::InternalFailure,
+                    "{} since the underlying listener failed ({:?}).", msg, e);
+            }
+        }
+    }
+
+    fn update(&mut self) -> Result<Vec<ServiceEvent>, ServiceDiscoveryWaitError> {
+        let services = fail!(from self, when Service::list_details_with_custom_config(self.config),
+            "Unable to update the services since they could not be listed.");
+
+        // a service that is removed and created again under the same name is a different
+        // instance, the instance uuid tells them apart
+        let is_same = |lhs: &ServiceDetails, rhs: &ServiceDetails| {
+            lhs.uuid() == rhs.uuid() && lhs.instance_uuid() == rhs.instance_uuid()
+        };
+
+        let mut changes = vec![];
+        for service in &self.services {
+            if !services.iter().any(|s| is_same(s, service)) {
+                changes.push(ServiceEvent::Removed(service.clone()));
+            }
+        }
+        for service in &services {
+            if !self.services.iter().any(|s| is_same(s, service)) {
+                changes.push(ServiceEvent::Created(service.clone()));
+            }
+        }
+
+        self.services = services;
+        Ok(changes)
+    }
+}
+
+/// Wakes up every [`ServiceDiscovery`] of the given [`config::Config`]. It is called whenever
+/// a service is created or removed.
+pub(crate) fn announce_change<'config, Service: Details<'config>>(config: &config::Config) {
+    let origin = "announce_change()";
+    let event_config = discovery_event_config::<Service>(config);
+
+    let discoveries = match <Service::Event as NamedConceptMgmt>::list_cfg(&event_config) {
+        Ok(discoveries) => discoveries,
+        Err(e) => {
+            trace!(from origin, "Unable to list the service discoveries ({:?}).", e);
+            return;
+        }
+    };
+
+    for discovery in discoveries {
+        // a discovery that is removed in the meantime does not have to be informed
+        match <Service::Event as Event<EventId>>::NotifierBuilder::new(&discovery)
+            .config(&event_config)
+            .open()
+        {
+            Ok(notifier) => {
+                if let Err(e) = notifier.notify(EventId::new(0)) {
+                    trace!(from origin, "Unable to wake up the service discovery \"{}\" ({:?}).", discovery, e);
+                }
+            }
+            Err(e) => {
+                trace!(from origin, "Unable to open the service discovery \"{}\" ({:?}).", discovery, e);
+            }
+        }
+    }
+}
+
+/// Announces the removal of a service to every [`ServiceDiscovery`] when it goes out of scope.
+/// It is owned by the [`crate::service::ServiceState`] and declared after its storages so that
+/// the service is already removed when the discoveries are woken up.
+#[derive(Debug)]
+pub(crate) struct RemovalAnnouncement<'config> {
+    global_config: &'config config::Config,
+    announce: fn(&config::Config),
+    is_armed: bool,
+}
+
+impl<'config> RemovalAnnouncement<'config> {
+    pub(crate) fn new(
+        global_config: &'config config::Config,
+        announce: fn(&config::Config),
+    ) -> Self {
+        Self {
+            global_config,
+            announce,
+            is_armed: false,
+        }
+    }
+
+    /// The removal is announced only when the last owner removes the service.
+    pub(crate) fn arm(&mut self) {
+        self.is_armed = true;
+    }
+}
+
+impl Drop for RemovalAnnouncement<'_> {
+    fn drop(&mut self) {
+        if self.is_armed {
+            (self.announce)(self.global_config);
+        }
+    }
+}
@@ -26,6 +26,8 @@
 //! # Ok(())
 //! # }
 //! ```
+use std::sync::atomic::{fence, AtomicBool, AtomicU64, Ordering};
+
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::UniqueIndex};
 use iceoryx2_bb_log::fatal_panic;
@@ -33,10 +35,108 @@ use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
 
 use crate::port::port_identifiers::{UniqueListenerId, UniqueNotifierId};
 
+use super::Table;
+
+/// The maximum size of the payload of an event in bytes.
+pub(crate) const MAX_PAYLOAD_SIZE: usize = 64;
+const PAYLOAD_WORDS: usize = MAX_PAYLOAD_SIZE / std::mem::size_of::<u64>();
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DynamicConfigSettings {
     pub number_of_listeners: usize,
     pub number_of_notifiers: usize,
+    pub number_of_payload_slots: usize,
+}
+
+/// Stores the payload that was attached to the last notification of an event id. It is a
+/// sequence lock, the sequence is odd while a notifier writes the payload. Notifiers of the
+/// same event id are serialized and listeners retry until they have read a payload that was
+/// not modified concurrently. The payload is stored in atomic words, a listener that reads
+/// while a notifier writes discards the torn copy.
+#[derive(Debug, Default)]
+pub(crate) struct PayloadSlot {
+    sequence: AtomicU64,
+    has_payload: AtomicBool,
+    data: [AtomicU64; PAYLOAD_WORDS],
+}
+
+impl PayloadSlot {
+    /// Stores the payload or removes it when `payload` is [`None`]. The payload must not
+    /// exceed [`MAX_PAYLOAD_SIZE`] bytes.
+    pub(crate) fn store(&self, payload: Option<&[u8]>) {
+        let mut sequence = self.sequence.load(Ordering::Relaxed);
+        loop {
+            if sequence % 2 == 1 {
+                std::hint::spin_loop();
+                sequence = self.sequence.load(Ordering::Relaxed);
+                continue;
+            }
+
+            match self.sequence.compare_exchange_weak(
+                sequence,
+                sequence + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(v) => sequence = v,
+            }
+        }
+        fence(Ordering::Release);
+
+        let mut words = [0u64; PAYLOAD_WORDS];
+        if let Some(payload) = payload {
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    payload.as_ptr(),
+                    words.as_mut_ptr() as *mut u8,
+                    payload.len(),
+                )
+            };
+        }
+
+        for (word, value) in self.data.iter().zip(words) {
+            word.store(value, Ordering::Relaxed);
+        }
+        self.has_payload.store(payload.is_some(), Ordering::Relaxed);
+
+        self.sequence.store(sequence + 2, Ordering::Release);
+    }
+
+    /// Copies the payload into `payload` and returns true. When no payload is stored it returns
+    /// false and `payload` is not modified.
+    pub(crate) fn load(&self, payload: &mut [u8]) -> bool {
+        let mut words = [0u64; PAYLOAD_WORDS];
+        loop {
+            let sequence = self.sequence.load(Ordering::Acquire);
+            if sequence % 2 == 1 {
+                std::hint::spin_loop();
+                continue;
+            }
+
+            for (value, word) in words.iter_mut().zip(self.data.iter()) {
+                *value = word.load(Ordering::Relaxed);
+            }
+            let has_payload = self.has_payload.load(Ordering::Relaxed);
+
+            fence(Ordering::Acquire);
+            if self.sequence.load(Ordering::Relaxed) != sequence {
+                continue;
+            }
+
+            if has_payload {
+                unsafe {
+                    std::ptr::copy_nonoverlapping(
+                        words.as_ptr() as *const u8,
+                        payload.as_mut_ptr(),
+                        payload.len(),
+                    )
+                };
+            }
+
+            return has_payload;
+        }
+    }
 }
 
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
@@ -45,6 +145,8 @@ pub(crate) struct DynamicConfigSettings {
 pub struct DynamicConfig {
     pub(crate) listeners: Container<UniqueListenerId>,
     pub(crate) notifiers: Container<UniqueNotifierId>,
+    // one slot per event id, the table is empty when the service has no event payload
+    payload_slots: Table<PayloadSlot>,
 }
 
 impl DynamicConfig {
@@ -52,6 +154,7 @@ impl DynamicConfig {
         Self {
             listeners: unsafe { Container::new_uninit(config.number_of_listeners) },
             notifiers: unsafe { Container::new_uninit(config.number_of_notifiers) },
+            payload_slots: unsafe { Table::new_uninit(config.number_of_payload_slots) },
         }
     }
 
@@ -62,11 +165,15 @@ impl DynamicConfig {
         fatal_panic!(from "event::DynamicConfig::init",
             when self.notifiers.init(allocator),
             "This should never happen! Unable to initialize notifier port id container.");
+        fatal_panic!(from "event::DynamicConfig::init",
+            when self.payload_slots.init(allocator),
+            "This should never happen! Unable to initialize the event payload slots.");
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<UniqueListenerId>::memory_size(config.number_of_listeners)
             + Container::<UniqueNotifierId>::memory_size(config.number_of_notifiers)
+            + Table::<PayloadSlot>::memory_size(config.number_of_payload_slots)
     }
 
     /// Returns the how many [`crate::port::listener::Listener`] ports are currently connected.
@@ -83,6 +190,10 @@ impl DynamicConfig {
         unsafe { self.listeners.add(id) }
     }
 
+    pub(crate) fn payload_slot(&self, event_id: u64) -> &PayloadSlot {
+        self.payload_slots.get(event_id as usize)
+    }
+
     pub(crate) fn add_notifier_id(&self, id: UniqueNotifierId) -> Option<UniqueIndex> {
         unsafe { self.notifiers.add(id) }
     }
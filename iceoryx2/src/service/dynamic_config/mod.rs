@@ -20,26 +20,104 @@ pub mod event;
 /// based service.
 pub mod publish_subscribe;
 
+/// The dynamic service configuration of a
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based service.
+pub mod request_response;
+
 use std::{
+    alloc::Layout,
     fmt::Display,
-    sync::atomic::{AtomicU64, Ordering},
+    sync::atomic::{AtomicI64, AtomicU64, Ordering},
 };
 
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_lock_free::mpmc::container::Container;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::process::{Process, ProcessId};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+
+use crate::service::static_config::ServicePersistence;
+
+/// Stores a fixed number of default initialized entries in the dynamic config, e.g. one entry
+/// per port slot where a port uses the entry with the index of its slot in the corresponding
+/// [`Container`].
+#[derive(Debug)]
+pub(crate) struct Table<T> {
+    data_ptr: RelocatablePointer<T>,
+    capacity: usize,
+}
+
+impl<T: Default> Table<T> {
+    pub(crate) unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            data_ptr: RelocatablePointer::new_uninit(),
+            capacity,
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) -> Result<(), AllocationError> {
+        // the allocator rejects empty allocations, an empty table is never accessed
+        if self.capacity == 0 {
+            return Ok(());
+        }
+
+        let memory = allocator.allocate(Layout::from_size_align_unchecked(
+            std::mem::size_of::<T>() * self.capacity,
+            std::mem::align_of::<T>(),
+        ))?;
+        self.data_ptr.init(memory);
+
+        let data = self.data_ptr.as_ptr() as *mut T;
+        for i in 0..self.capacity {
+            data.add(i).write(T::default());
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn memory_size(capacity: usize) -> usize {
+        std::mem::size_of::<T>() * capacity + std::mem::align_of::<T>() - 1
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &T {
+        if index >= self.capacity {
+            fatal_panic!(from "Table::get()",
+                "This should never happen! The index {} exceeds the capacity {}.", index, self.capacity);
+        }
+
+        unsafe { &*self.data_ptr.as_ptr().add(index) }
+    }
+}
 
 const MARKED_FOR_DESTRUCTION: u64 = u64::MAX - 1;
+const NO_CLEANER: i64 = 0;
 
+/// The result of [`DynamicConfig::decrement_reference_counter()`], the service must be removed
+/// only on [`DecrementReferenceCounterResult::NoMoreOwners`]. A persistent service has owners
+/// until it is removed explicitly.
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub(crate) enum DecrementReferenceCounterResult {
     HasOwners,
     NoMoreOwners,
 }
 
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub(crate) enum MarkForDestructionResult {
+    Marked,
+    InUse,
+    AlreadyMarked,
+}
+
 #[derive(Debug)]
 pub(crate) enum MessagingPattern {
     PublishSubscribe(publish_subscribe::DynamicConfig),
     Event(event::DynamicConfig),
+    RequestResponse(request_response::DynamicConfig),
 }
 
 #[doc(hidden)]
@@ -47,6 +125,9 @@ pub(crate) enum MessagingPattern {
 pub struct DynamicConfig {
     messaging_pattern: MessagingPattern,
     reference_counter: AtomicU64,
+    owners: Container<UniqueSystemId>,
+    cleaner: AtomicI64,
+    is_persistent: bool,
 }
 
 impl Display for DynamicConfig {
@@ -60,20 +141,84 @@ impl Display for DynamicConfig {
 }
 
 impl DynamicConfig {
-    pub(crate) fn new_uninit(messaging_pattern: MessagingPattern) -> Self {
+    pub(crate) fn new_uninit(
+        messaging_pattern: MessagingPattern,
+        max_owners: usize,
+        persistence: ServicePersistence,
+    ) -> Self {
         Self {
             messaging_pattern,
             reference_counter: AtomicU64::new(1),
+            owners: unsafe { Container::new_uninit(max_owners) },
+            cleaner: AtomicI64::new(NO_CLEANER),
+            is_persistent: persistence == ServicePersistence::Persistent,
         }
     }
 
     pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from self, when self.owners.init(allocator),
+            "This should never happen! Unable to initialize the service owner container.");
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v.init(allocator),
             MessagingPattern::Event(ref v) => v.init(allocator),
+            MessagingPattern::RequestResponse(ref v) => v.init(allocator),
         }
     }
 
+    pub(crate) fn memory_size(max_owners: usize) -> usize {
+        Container::<UniqueSystemId>::memory_size(max_owners)
+    }
+
+    /// Registers the owner of a service instance so that it can be removed when its process
+    /// dies. Returns the index of the entry or [`None`] when the maximum number of owners is
+    /// exceeded.
+    pub(crate) fn register_owner(&self, owner_id: UniqueSystemId) -> Option<u32> {
+        unsafe { self.owners.add(owner_id) }.map(|index| {
+            let value = index.value();
+            // the entry is removed explicitly with deregister_owner, either by the service
+            // instance itself or by the cleanup of a dead process
+            std::mem::forget(index);
+            value
+        })
+    }
+
+    pub(crate) fn deregister_owner(&self, index: u32) {
+        unsafe { self.owners.remove_raw_index(index) };
+    }
+
+    pub(crate) fn list_owners<F: FnMut(u32, &UniqueSystemId)>(&self, callback: F) {
+        unsafe { self.owners.get_state() }.for_each(callback)
+    }
+
+    /// Acquires the exclusive right to remove the stale resources of the service. When another
+    /// process is cleaning up it returns false, unless that process is dead.
+    pub(crate) fn acquire_cleaner_role(&self) -> bool {
+        let own_pid = i64::from(Process::from_self().id().value());
+        let mut current_cleaner = self.cleaner.load(Ordering::Relaxed);
+
+        loop {
+            if current_cleaner != NO_CLEANER
+                && Process::from_pid(ProcessId::new(current_cleaner as _)).is_alive()
+            {
+                return false;
+            }
+
+            match self.cleaner.compare_exchange(
+                current_cleaner,
+                own_pid,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return true,
+                Err(v) => current_cleaner = v,
+            }
+        }
+    }
+
+    pub(crate) fn release_cleaner_role(&self) {
+        self.cleaner.store(NO_CLEANER, Ordering::Relaxed);
+    }
+
     pub(crate) fn increment_reference_counter(&self) -> Result<(), ()> {
         let mut current_value = self.reference_counter.load(Ordering::Relaxed);
         loop {
@@ -101,10 +246,15 @@ impl DynamicConfig {
         let mut current_value = self.reference_counter.load(Ordering::Relaxed);
 
         loop {
+            // the service was removed with force while it was still in use
+            if current_value == MARKED_FOR_DESTRUCTION {
+                return DecrementReferenceCounterResult::HasOwners;
+            }
+
             result = DecrementReferenceCounterResult::HasOwners;
             match self.reference_counter.compare_exchange(
                 current_value,
-                if current_value == 1 {
+                if current_value == 1 && !self.is_persistent {
                     result = DecrementReferenceCounterResult::NoMoreOwners;
                     MARKED_FOR_DESTRUCTION
                 } else {
@@ -121,6 +271,31 @@ impl DynamicConfig {
         result
     }
 
+    /// Marks the service for destruction when no instance of it exists anymore so that it
+    /// cannot be opened while it is removed.
+    pub(crate) fn try_mark_for_destruction(&self) -> MarkForDestructionResult {
+        match self.reference_counter.compare_exchange(
+            0,
+            MARKED_FOR_DESTRUCTION,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => MarkForDestructionResult::Marked,
+            Err(MARKED_FOR_DESTRUCTION) => MarkForDestructionResult::AlreadyMarked,
+            Err(_) => MarkForDestructionResult::InUse,
+        }
+    }
+
+    /// Marks the service for destruction even when it is still in use.
+    pub(crate) fn force_mark_for_destruction(&self) {
+        self.reference_counter
+            .store(MARKED_FOR_DESTRUCTION, Ordering::Relaxed);
+    }
+
+    pub(crate) fn messaging_pattern(&self) -> &MessagingPattern {
+        &self.messaging_pattern
+    }
+
     pub(crate) fn publish_subscribe(&self) -> &publish_subscribe::DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v,
@@ -138,4 +313,13 @@ impl DynamicConfig {
             }
         }
     }
+
+    pub(crate) fn request_response(&self) -> &request_response::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access request_response::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
 }
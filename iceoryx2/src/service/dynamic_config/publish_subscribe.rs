@@ -23,15 +23,28 @@
 //!
 //! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
 //! println!("number of active subscribers:     {:?}", pubsub.dynamic_config().number_of_subscribers());
+//!
+//! for (publisher_id, statistics) in pubsub.dynamic_config().publisher_statistics() {
+//!     println!("publisher {:?}: {:?}", publisher_id, statistics);
+//! }
 //! # Ok(())
 //! # }
 //! ```
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+use iceoryx2_bb_elementary::callback_progression::CallbackProgression;
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::UniqueIndex};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::clock::{Time, TimeBuilder};
+use iceoryx2_bb_posix::process::{Process, ProcessId};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 
 use crate::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::service::port_factory::publisher::MAX_NUMBER_OF_BUCKETS;
+
+use super::Table;
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DynamicConfigSettings {
@@ -39,12 +52,327 @@ pub(crate) struct DynamicConfigSettings {
     pub number_of_publishers: usize,
 }
 
+/// Describes a connected [`crate::port::subscriber::Subscriber`] so that a
+/// [`crate::port::publisher::Publisher`] can establish a connection that fits its requirements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SubscriberDetails {
+    pub(crate) subscriber_id: UniqueSubscriberId,
+    pub(crate) buffer_size: usize,
+    // the connection is refused when the publisher cannot provide this buffer size
+    pub(crate) required_min_buffer_size: usize,
+}
+
+/// Describes a connected [`crate::port::publisher::Publisher`] so that a
+/// [`crate::port::subscriber::Subscriber`] can establish a connection with the buffer size
+/// that the publisher agrees on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PublisherDetails {
+    pub(crate) publisher_id: UniquePublisherId,
+    // the largest subscriber buffer the publisher serves
+    pub(crate) max_buffer_size: usize,
+}
+
+impl SubscriberDetails {
+    /// Returns the buffer size of the connection between the subscriber and the publisher, the
+    /// smaller of both requests, or [`None`] when it is below the
+    /// [`SubscriberDetails::required_min_buffer_size`] and the connection must be refused.
+    pub(crate) fn negotiate_buffer_size(&self, publisher: &PublisherDetails) -> Option<usize> {
+        let buffer_size = self.buffer_size.min(publisher.max_buffer_size);
+        match buffer_size < self.required_min_buffer_size {
+            true => None,
+            false => Some(buffer_size),
+        }
+    }
+}
+
+/// The id of a port that is attached to the service, see [`PortDetails::id()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PortId {
+    /// The id of a [`crate::port::publisher::Publisher`]
+    Publisher(UniquePublisherId),
+    /// The id of a [`crate::port::subscriber::Subscriber`]
+    Subscriber(UniqueSubscriberId),
+}
+
+/// Describes a port that was attached to the service when [`DynamicConfig::list_ports()`]
+/// was called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortDetails {
+    id: PortId,
+    buffer_size: Option<usize>,
+    pid: ProcessId,
+    is_stale: bool,
+}
+
+impl PortDetails {
+    fn new(id: PortId, buffer_size: Option<usize>, pid: ProcessId) -> Self {
+        Self {
+            id,
+            buffer_size,
+            pid,
+            is_stale: !Process::from_pid(pid).is_alive(),
+        }
+    }
+
+    /// Returns the [`PortId`] of the port.
+    pub fn id(&self) -> PortId {
+        self.id
+    }
+
+    /// Returns true if the port is a [`crate::port::publisher::Publisher`].
+    pub fn is_publisher(&self) -> bool {
+        matches!(self.id, PortId::Publisher(_))
+    }
+
+    /// Returns true if the port is a [`crate::port::subscriber::Subscriber`].
+    pub fn is_subscriber(&self) -> bool {
+        matches!(self.id, PortId::Subscriber(_))
+    }
+
+    /// Returns the buffer size of a [`crate::port::subscriber::Subscriber`]. Publishers do not
+    /// have a port specific buffer and return [`None`].
+    pub fn buffer_size(&self) -> Option<usize> {
+        self.buffer_size
+    }
+
+    /// Returns the [`ProcessId`] of the process that created the port.
+    pub fn pid(&self) -> ProcessId {
+        self.pid
+    }
+
+    /// Returns true when the process that created the port is no longer alive. The entry was
+    /// left behind and will be removed by the stale resource cleanup.
+    pub fn is_stale(&self) -> bool {
+        self.is_stale
+    }
+}
+
+/// Runtime statistics of a [`crate::port::publisher::Publisher`], acquired with
+/// [`crate::port::publisher::Publisher::statistics()`] or [`DynamicConfig::publisher_statistics()`].
+/// The counters are updated without synchronization, under concurrency they are approximate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PublisherStatistics {
+    sent_samples: u64,
+    cancelled_samples: u64,
+    loaned_samples: usize,
+    active_connections: usize,
+    buckets: [BucketStatistics; MAX_NUMBER_OF_BUCKETS],
+    number_of_buckets: usize,
+}
+
+impl PublisherStatistics {
+    /// Returns how many samples the [`crate::port::publisher::Publisher`] has sent so far.
+    pub fn sent_samples(&self) -> u64 {
+        self.sent_samples
+    }
+
+    /// Returns how many loaned samples were discarded without being sent, either explicitly
+    /// with [`crate::sample_mut::SampleMut::cancel()`] or by dropping them.
+    pub fn cancelled_samples(&self) -> u64 {
+        self.cancelled_samples
+    }
+
+    /// Returns how many samples are currently loaned and not yet sent or dropped.
+    pub fn loaned_samples(&self) -> usize {
+        self.loaned_samples
+    }
+
+    /// Returns to how many [`crate::port::subscriber::Subscriber`]s the
+    /// [`crate::port::publisher::Publisher`] is connected.
+    pub fn active_connections(&self) -> usize {
+        self.active_connections
+    }
+
+    /// Returns the occupancy of the buckets of the
+    /// [`crate::service::port_factory::publisher::BucketConfig`] of the
+    /// [`crate::port::publisher::Publisher`], ordered by their payload size. It is empty when
+    /// the publisher uses a single data segment.
+    pub fn buckets(&self) -> &[BucketStatistics] {
+        &self.buckets[..self.number_of_buckets]
+    }
+}
+
+/// The occupancy of a bucket of a [`crate::port::publisher::Publisher`], see
+/// [`PublisherStatistics::buckets()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BucketStatistics {
+    payload_size: usize,
+    chunk_size: usize,
+    number_of_samples: usize,
+    used_samples: usize,
+}
+
+impl BucketStatistics {
+    /// Returns the maximum payload size in bytes of the samples of the bucket.
+    pub fn payload_size(&self) -> usize {
+        self.payload_size
+    }
+
+    /// Returns the size in bytes a sample occupies in the data segment of the bucket,
+    /// including the headers.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Returns how many samples the bucket provides.
+    pub fn number_of_samples(&self) -> usize {
+        self.number_of_samples
+    }
+
+    /// Returns how many samples of the bucket are currently loaned, sent or held by the
+    /// history and the [`crate::port::subscriber::Subscriber`]s.
+    pub fn used_samples(&self) -> usize {
+        self.used_samples
+    }
+}
+
+/// Runtime statistics of a [`crate::port::subscriber::Subscriber`], acquired with
+/// [`crate::port::subscriber::Subscriber::statistics()`] or
+/// [`DynamicConfig::subscriber_statistics()`]. The counters are updated without
+/// synchronization, under concurrency they are approximate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SubscriberStatistics {
+    received_samples: u64,
+    pending_samples: usize,
+    borrowed_samples: usize,
+}
+
+impl SubscriberStatistics {
+    /// Returns how many samples the [`crate::port::subscriber::Subscriber`] has received so far.
+    pub fn received_samples(&self) -> u64 {
+        self.received_samples
+    }
+
+    /// Returns how many samples were delivered to the [`crate::port::subscriber::Subscriber`]
+    /// and wait in its buffers to be received.
+    pub fn pending_samples(&self) -> usize {
+        self.pending_samples
+    }
+
+    /// Returns how many received samples are currently held by the user. A sample that is
+    /// dropped by another thread than the receiving one is counted as returned with the next
+    /// receive of the [`crate::port::subscriber::Subscriber`].
+    pub fn borrowed_samples(&self) -> usize {
+        self.borrowed_samples
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct PublisherCounters {
+    pub(crate) sent_samples: AtomicU64,
+    pub(crate) cancelled_samples: AtomicU64,
+    pub(crate) loaned_samples: AtomicUsize,
+    pub(crate) active_connections: AtomicUsize,
+    pub(crate) buckets: [BucketCounters; MAX_NUMBER_OF_BUCKETS],
+    pub(crate) number_of_buckets: AtomicUsize,
+}
+
+impl PublisherCounters {
+    fn reset(&self) {
+        self.sent_samples.store(0, Ordering::Relaxed);
+        self.cancelled_samples.store(0, Ordering::Relaxed);
+        self.loaned_samples.store(0, Ordering::Relaxed);
+        self.active_connections.store(0, Ordering::Relaxed);
+        self.number_of_buckets.store(0, Ordering::Relaxed);
+        for bucket in &self.buckets {
+            bucket.reset();
+        }
+    }
+
+    fn statistics(&self) -> PublisherStatistics {
+        let mut buckets = [BucketStatistics::default(); MAX_NUMBER_OF_BUCKETS];
+        for (statistics, bucket) in buckets.iter_mut().zip(self.buckets.iter()) {
+            *statistics = bucket.statistics();
+        }
+
+        PublisherStatistics {
+            sent_samples: self.sent_samples.load(Ordering::Relaxed),
+            cancelled_samples: self.cancelled_samples.load(Ordering::Relaxed),
+            loaned_samples: self.loaned_samples.load(Ordering::Relaxed),
+            active_connections: self.active_connections.load(Ordering::Relaxed),
+            buckets,
+            number_of_buckets: self
+                .number_of_buckets
+                .load(Ordering::Relaxed)
+                .min(MAX_NUMBER_OF_BUCKETS),
+        }
+    }
+}
+
+// the size of a bucket is written once when the publisher is created, only the number of used
+// samples changes afterwards
+#[derive(Debug, Default)]
+pub(crate) struct BucketCounters {
+    pub(crate) payload_size: AtomicUsize,
+    pub(crate) chunk_size: AtomicUsize,
+    pub(crate) number_of_samples: AtomicUsize,
+    pub(crate) used_samples: AtomicUsize,
+}
+
+impl BucketCounters {
+    fn reset(&self) {
+        self.payload_size.store(0, Ordering::Relaxed);
+        self.chunk_size.store(0, Ordering::Relaxed);
+        self.number_of_samples.store(0, Ordering::Relaxed);
+        self.used_samples.store(0, Ordering::Relaxed);
+    }
+
+    fn statistics(&self) -> BucketStatistics {
+        BucketStatistics {
+            payload_size: self.payload_size.load(Ordering::Relaxed),
+            chunk_size: self.chunk_size.load(Ordering::Relaxed),
+            number_of_samples: self.number_of_samples.load(Ordering::Relaxed),
+            used_samples: self.used_samples.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct SubscriberCounters {
+    // increased by the publishers, a sample that is overridden in the subscriber buffer by a
+    // newer one is not counted
+    pub(crate) delivered_samples: AtomicU64,
+    pub(crate) received_samples: AtomicU64,
+    pub(crate) borrowed_samples: AtomicUsize,
+}
+
+impl SubscriberCounters {
+    fn reset(&self) {
+        self.delivered_samples.store(0, Ordering::Relaxed);
+        self.received_samples.store(0, Ordering::Relaxed);
+        self.borrowed_samples.store(0, Ordering::Relaxed);
+    }
+
+    fn statistics(&self) -> SubscriberStatistics {
+        let received_samples = self.received_samples.load(Ordering::Relaxed);
+        SubscriberStatistics {
+            received_samples,
+            pending_samples: self
+                .delivered_samples
+                .load(Ordering::Relaxed)
+                .saturating_sub(received_samples) as usize,
+            borrowed_samples: self.borrowed_samples.load(Ordering::Relaxed),
+        }
+    }
+}
+
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
 /// based service. Contains dynamic parameters like the connected endpoints etc..
 #[derive(Debug)]
 pub struct DynamicConfig {
-    pub(crate) subscribers: Container<UniqueSubscriberId>,
-    pub(crate) publishers: Container<UniquePublisherId>,
+    pub(crate) subscribers: Container<SubscriberDetails>,
+    pub(crate) publishers: Container<PublisherDetails>,
+    // subscribers that sleep until a publisher notifies them about a new sample
+    waiting_subscribers: AtomicUsize,
+    // publishers that sleep until a subscriber releases a sample
+    waiting_publishers: AtomicUsize,
+    // the latest time stamp in nanoseconds that a publisher assigned to a sample
+    latest_time_stamp: AtomicU64,
+    publisher_counters: Table<PublisherCounters>,
+    subscriber_counters: Table<SubscriberCounters>,
 }
 
 impl DynamicConfig {
@@ -52,6 +380,11 @@ impl DynamicConfig {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            waiting_subscribers: AtomicUsize::new(0),
+            waiting_publishers: AtomicUsize::new(0),
+            latest_time_stamp: AtomicU64::new(0),
+            publisher_counters: unsafe { Table::new_uninit(config.number_of_publishers) },
+            subscriber_counters: unsafe { Table::new_uninit(config.number_of_subscribers) },
         }
     }
 
@@ -62,11 +395,19 @@ impl DynamicConfig {
         fatal_panic!(from "publish_subscribe::DynamicConfig::init",
             when self.publishers.init(allocator),
             "This should never happen! Unable to initialize publisher port id container.");
+        fatal_panic!(from "publish_subscribe::DynamicConfig::init",
+            when self.publisher_counters.init(allocator),
+            "This should never happen! Unable to initialize the publisher statistics.");
+        fatal_panic!(from "publish_subscribe::DynamicConfig::init",
+            when self.subscriber_counters.init(allocator),
+            "This should never happen! Unable to initialize the subscriber statistics.");
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
-        Container::<UniqueSubscriberId>::memory_size(config.number_of_subscribers)
-            + Container::<UniquePublisherId>::memory_size(config.number_of_publishers)
+        Container::<SubscriberDetails>::memory_size(config.number_of_subscribers)
+            + Container::<PublisherDetails>::memory_size(config.number_of_publishers)
+            + Table::<SubscriberCounters>::memory_size(config.number_of_subscribers)
+            + Table::<PublisherCounters>::memory_size(config.number_of_publishers)
     }
 
     /// Returns how many [`crate::port::publisher::Publisher`] ports are currently connected.
@@ -79,11 +420,184 @@ impl DynamicConfig {
         self.subscribers.len()
     }
 
-    pub(crate) fn add_subscriber_id(&self, id: UniqueSubscriberId) -> Option<UniqueIndex> {
-        unsafe { self.subscribers.add(id) }
+    /// Returns how many [`crate::port::publisher::Publisher`] ports of live processes are
+    /// connected. In contrast to [`DynamicConfig::number_of_publishers()`] the entries of dead
+    /// processes that were not yet removed by the stale resource cleanup are not counted. It
+    /// costs one system call per connected port.
+    pub fn number_of_live_publishers(&self) -> usize {
+        let mut number_of_publishers = 0;
+        unsafe { self.publishers.get_state() }.for_each(|_, details| {
+            if Process::from_pid(details.publisher_id.0.pid()).is_alive() {
+                number_of_publishers += 1;
+            }
+        });
+        number_of_publishers
+    }
+
+    /// Returns how many [`crate::port::subscriber::Subscriber`] ports of live processes are
+    /// connected. In contrast to [`DynamicConfig::number_of_subscribers()`] the entries of dead
+    /// processes that were not yet removed by the stale resource cleanup are not counted. It
+    /// costs one system call per connected port.
+    pub fn number_of_live_subscribers(&self) -> usize {
+        let mut number_of_subscribers = 0;
+        unsafe { self.subscribers.get_state() }.for_each(|_, details| {
+            if Process::from_pid(details.subscriber_id.0.pid()).is_alive() {
+                number_of_subscribers += 1;
+            }
+        });
+        number_of_subscribers
+    }
+
+    pub(crate) fn publisher_system_ids(&self) -> Vec<UniqueSystemId> {
+        let mut ids = vec![];
+        unsafe { self.publishers.get_state() }
+            .for_each(|_, details| ids.push(details.publisher_id.0));
+        ids
+    }
+
+    pub(crate) fn subscriber_system_ids(&self) -> Vec<UniqueSystemId> {
+        let mut ids = vec![];
+        unsafe { self.subscribers.get_state() }
+            .for_each(|_, details| ids.push(details.subscriber_id.0));
+        ids
+    }
+
+    /// Returns the [`PublisherStatistics`] of every currently connected
+    /// [`crate::port::publisher::Publisher`].
+    pub fn publisher_statistics(&self) -> Vec<(UniquePublisherId, PublisherStatistics)> {
+        let mut statistics = vec![];
+        unsafe { self.publishers.get_state() }.for_each(|index, details| {
+            statistics.push((
+                details.publisher_id,
+                self.publisher_counters.get(index as usize).statistics(),
+            ))
+        });
+        statistics
+    }
+
+    /// Returns the [`SubscriberStatistics`] of every currently connected
+    /// [`crate::port::subscriber::Subscriber`].
+    pub fn subscriber_statistics(&self) -> Vec<(UniqueSubscriberId, SubscriberStatistics)> {
+        let mut statistics = vec![];
+        unsafe { self.subscribers.get_state() }.for_each(|index, details| {
+            statistics.push((
+                details.subscriber_id,
+                self.subscriber_counters.get(index as usize).statistics(),
+            ))
+        });
+        statistics
+    }
+
+    /// Calls the callback for every port that is attached to the service until it returns
+    /// [`CallbackProgression::Stop`]. The ports are acquired from a snapshot that is taken when
+    /// the call starts, ports that are created or removed concurrently may or may not be part of
+    /// it.
+    pub fn list_ports<F: FnMut(&PortDetails) -> CallbackProgression>(&self, mut callback: F) {
+        let mut ports = vec![];
+        unsafe { self.publishers.get_state() }.for_each(|_, details| {
+            ports.push(PortDetails::new(
+                PortId::Publisher(details.publisher_id),
+                None,
+                details.publisher_id.0.pid(),
+            ))
+        });
+        unsafe { self.subscribers.get_state() }.for_each(|_, details| {
+            ports.push(PortDetails::new(
+                PortId::Subscriber(details.subscriber_id),
+                Some(details.buffer_size),
+                details.subscriber_id.0.pid(),
+            ))
+        });
+
+        for port in &ports {
+            if callback(port) == CallbackProgression::Stop {
+                break;
+            }
+        }
+    }
+
+    pub(crate) fn publisher_counters(&self, index: u32) -> &PublisherCounters {
+        self.publisher_counters.get(index as usize)
+    }
+
+    pub(crate) fn subscriber_counters(&self, index: u32) -> &SubscriberCounters {
+        self.subscriber_counters.get(index as usize)
+    }
+
+    pub(crate) fn publisher_statistics_of(&self, index: u32) -> PublisherStatistics {
+        self.publisher_counters.get(index as usize).statistics()
+    }
+
+    pub(crate) fn subscriber_statistics_of(&self, index: u32) -> SubscriberStatistics {
+        self.subscriber_counters.get(index as usize).statistics()
+    }
+
+    /// The counters of a slot are reset before the slot is released so that the next port in
+    /// it starts from zero.
+    pub(crate) fn reset_publisher_counters(&self, index: u32) {
+        self.publisher_counters.get(index as usize).reset()
+    }
+
+    pub(crate) fn reset_subscriber_counters(&self, index: u32) {
+        self.subscriber_counters.get(index as usize).reset()
+    }
+
+    pub(crate) fn number_of_waiting_subscribers(&self) -> usize {
+        self.waiting_subscribers.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn announce_waiting_subscriber(&self) {
+        self.waiting_subscribers.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub(crate) fn withdraw_waiting_subscriber(&self) {
+        self.waiting_subscribers.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    pub(crate) fn number_of_waiting_publishers(&self) -> usize {
+        self.waiting_publishers.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn announce_waiting_publisher(&self) {
+        self.waiting_publishers.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub(crate) fn withdraw_waiting_publisher(&self) {
+        self.waiting_publishers.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// Returns a time stamp that is later than every time stamp that was assigned to a sample
+    /// of the service before, `now` when the clock has advanced since. The samples of
+    /// different publishers are therefore ordered even when the resolution of the clock is too
+    /// coarse to distinguish them.
+    pub(crate) fn next_time_stamp(&self, now: Time) -> Time {
+        let now_in_ns = now.as_duration().as_nanos() as u64;
+        let mut latest = self.latest_time_stamp.load(Ordering::Relaxed);
+        loop {
+            let next = now_in_ns.max(latest.saturating_add(1));
+            match self.latest_time_stamp.compare_exchange_weak(
+                latest,
+                next,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    return TimeBuilder::new()
+                        .clock_type(now.clock_type())
+                        .seconds(next / 1_000_000_000)
+                        .nanoseconds((next % 1_000_000_000) as u32)
+                        .create()
+                }
+                Err(value) => latest = value,
+            }
+        }
+    }
+
+    pub(crate) fn add_subscriber(&self, details: SubscriberDetails) -> Option<UniqueIndex> {
+        unsafe { self.subscribers.add(details) }
     }
 
-    pub(crate) fn add_publisher_id(&self, id: UniquePublisherId) -> Option<UniqueIndex> {
-        unsafe { self.publishers.add(id) }
+    pub(crate) fn add_publisher(&self, details: PublisherDetails) -> Option<UniqueIndex> {
+        unsafe { self.publishers.add(details) }
     }
 }
@@ -31,47 +31,262 @@
 //! # }
 //! ```
 use iceoryx2_bb_posix::clock::{Time, TimeBuilder};
+use serde::{de::Visitor, Deserialize, Serialize};
 
+use crate::node::NodeId;
 use crate::port::port_identifiers::UniquePublisherId;
 
-#[derive(Debug)]
+/// Defines the clock that is used for the [`Header::time_stamp()`] of the samples of a
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// service. It is part of the static config of the service so that all
+/// [`crate::port::publisher::Publisher`]s and [`crate::port::subscriber::Subscriber`]s use the
+/// same clock.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Hash)]
+#[repr(u32)]
+pub enum ClockType {
+    /// The current system time. It can jump when the system time is adjusted.
+    #[default]
+    Realtime = 0,
+    /// A steady clock that is not affected by adjustments of the system time. Use it when the
+    /// time stamps are used to measure latencies.
+    Monotonic = 1,
+}
+
+impl From<ClockType> for iceoryx2_bb_posix::clock::ClockType {
+    fn from(value: ClockType) -> Self {
+        match value {
+            ClockType::Realtime => iceoryx2_bb_posix::clock::ClockType::Realtime,
+            ClockType::Monotonic => iceoryx2_bb_posix::clock::ClockType::Monotonic,
+        }
+    }
+}
+
+impl ClockType {
+    pub(crate) fn now(&self) -> Time {
+        Time::now_with_clock((*self).into()).unwrap()
+    }
+}
+
+impl Serialize for ClockType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            ClockType::Realtime => serializer.serialize_str("realtime"),
+            ClockType::Monotonic => serializer.serialize_str("monotonic"),
+        }
+    }
+}
+
+struct ClockTypeVisitor;
+
+impl<'de> Visitor<'de> for ClockTypeVisitor {
+    type Value = ClockType;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a string containing either 'realtime' or 'monotonic'")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "realtime" => Ok(ClockType::Realtime),
+            "monotonic" => Ok(ClockType::Monotonic),
+            v => Err(E::custom(format!(
+                "Invalid ClockType provided: \"{:?}\".",
+                v
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ClockType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ClockTypeVisitor)
+    }
+}
+
+// the padding is explicit since `u64` is only 4 byte aligned on some 32-bit targets, the
+// layout would differ otherwise
+#[derive(Debug, Clone, Copy)]
 #[repr(C)]
 struct TimeStamp {
     seconds: u64,
     nanoseconds: u32,
+    _padding: u32,
+}
+
+impl TimeStamp {
+    fn new(value: Time) -> Self {
+        Self {
+            seconds: value.seconds(),
+            nanoseconds: value.nanoseconds(),
+            _padding: 0,
+        }
+    }
 }
 
 /// Message header used by
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
-#[derive(Debug)]
+///
+/// The header is part of every sample and is read from C and C++ as well, its layout is
+/// therefore fixed and contains neither an `Option` nor an enum without a defined
+/// representation. The fields are only accessible with the accessors so that the layout can
+/// evolve, every change of the layout increments [`Header::LAYOUT_VERSION`] which is stored in
+/// the first byte.
+///
+/// | offset | size | field                                              |
+/// |--------|------|----------------------------------------------------|
+/// | 0      | 4    | layout version, `u8`, and 3 bytes padding          |
+/// | 4      | 4    | [`ClockType`], `u32`                               |
+/// | 8      | 8    | number of elements, `u64`                          |
+/// | 16     | 16   | publisher id, `u128`                               |
+/// | 32     | 16   | node id, `u128`, 0 when there is no node           |
+/// | 48     | 8    | time stamp seconds, `u64`                          |
+/// | 56     | 8    | time stamp nanoseconds, `u32`, and 4 bytes padding |
+/// | 64     | 8    | sequence number, `u64`                             |
+/// | 72     | 8    | payload size in bytes, `u64`                       |
+///
+/// The size is 80 bytes on every target, independent of the pointer width.
+#[derive(Debug, Clone, Copy)]
 #[repr(C)]
+#[non_exhaustive]
 pub struct Header {
+    layout_version: u8,
+    _padding: [u8; 3],
+    clock_type: ClockType,
+    number_of_elements: u64,
     publisher_port_id: UniquePublisherId,
+    node_id: u128,
     time_stamp: TimeStamp,
+    sequence_number: u64,
+    payload_size: u64,
 }
 
+const _: () = assert!(core::mem::size_of::<TimeStamp>() == 16);
+const _: () = assert!(core::mem::size_of::<Header>() == 80);
+
 impl Header {
-    pub(crate) fn new(publisher_port_id: UniquePublisherId) -> Self {
-        let now = Time::now().unwrap();
+    /// The version of the memory layout of the [`Header`]. A
+    /// [`crate::port::subscriber::Subscriber`] rejects samples with another version with
+    /// [`crate::port::subscribe::SubscriberReceiveError::IncompatibleHeaderVersion`].
+    pub const LAYOUT_VERSION: u8 = 1;
+
+    pub(crate) fn new(
+        publisher_port_id: UniquePublisherId,
+        node_id: Option<NodeId>,
+        number_of_elements: usize,
+        payload_size: usize,
+        clock_type: ClockType,
+    ) -> Self {
+        let now = clock_type.now();
         Self {
+            layout_version: Self::LAYOUT_VERSION,
+            _padding: [0; 3],
+            clock_type,
+            number_of_elements: number_of_elements as u64,
             publisher_port_id,
-            time_stamp: TimeStamp {
-                seconds: now.seconds(),
-                nanoseconds: now.nanoseconds(),
-            },
+            node_id: node_id.map_or(0, |id| id.value()),
+            time_stamp: TimeStamp::new(now),
+            sequence_number: 0,
+            payload_size: payload_size as u64,
+        }
+    }
+
+    pub(crate) fn set_layout_version(&mut self, value: u8) {
+        self.layout_version = value;
+    }
+
+    pub(crate) fn set_number_of_elements(&mut self, value: u64) {
+        self.number_of_elements = value;
+    }
+
+    pub(crate) fn set_sequence_number(&mut self, value: u64) {
+        self.sequence_number = value;
+    }
+
+    pub(crate) fn update_time_stamp(&mut self) {
+        self.time_stamp = TimeStamp::new(self.clock_type.now());
+    }
+
+    pub(crate) fn set_time_stamp(&mut self, value: Time) {
+        self.time_stamp = TimeStamp::new(value);
+    }
+
+    /// Returns the bytes of the [`Header`] in the layout that is described above, for instance
+    /// to store them next to the payload in a recording. It contains no uninitialized bytes
+    /// since the padding is explicit.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: the header is `repr(C)` with explicit padding, every byte is initialized
+        unsafe {
+            core::slice::from_raw_parts(
+                (self as *const Self).cast::<u8>(),
+                core::mem::size_of::<Self>(),
+            )
         }
     }
 
+    /// Returns the version of the memory layout of the [`Header`], see
+    /// [`Header::LAYOUT_VERSION`].
+    pub fn layout_version(&self) -> u8 {
+        self.layout_version
+    }
+
     /// Returns the [`UniquePublisherId`] of the source [`crate::port::publisher::Publisher`].
     pub fn publisher_id(&self) -> UniquePublisherId {
         self.publisher_port_id
     }
 
-    /// Returns the [`Time`] when the [`crate::sample::Sample`] was delivered.
+    /// Returns the [`NodeId`] of the [`crate::node::Node`] whose service created the source
+    /// [`crate::port::publisher::Publisher`] or [`None`] when the service was not created via
+    /// a [`crate::node::Node`]. The process of the publisher is part of the
+    /// [`Header::publisher_id()`].
+    pub fn node_id(&self) -> Option<NodeId> {
+        (self.node_id != 0).then_some(NodeId(self.node_id))
+    }
+
+    /// Returns the [`Time`] when the [`crate::sample::Sample`] was sent. As long as a
+    /// [`crate::sample_mut::SampleMut`] is not sent it is the time when it was loaned. If the
+    /// service allows user time stamps it can also be the time that was set with
+    /// [`crate::sample_mut::SampleMut::set_time_stamp()`]. The time stamps that are assigned on
+    /// send are strictly increasing across all publishers of the service, when two samples
+    /// are sent within the resolution of the clock the later one is one nanosecond ahead.
     pub fn time_stamp(&self) -> Time {
         TimeBuilder::new()
+            .clock_type(self.clock_type.into())
             .nanoseconds(self.time_stamp.nanoseconds)
             .seconds(self.time_stamp.seconds)
             .create()
     }
+
+    /// Returns the [`ClockType`] of the [`Header::time_stamp()`]. It is defined by the service.
+    pub fn clock_type(&self) -> ClockType {
+        self.clock_type
+    }
+
+    /// Returns the number of elements of the payload. For slice payloads it is the actual length
+    /// of the slice, otherwise it is always 1.
+    pub fn number_of_elements(&self) -> u64 {
+        self.number_of_elements
+    }
+
+    /// Returns the size of the payload in bytes, for slice payloads it is the number of
+    /// elements multiplied with the size of one element.
+    pub fn payload_size_bytes(&self) -> u64 {
+        self.payload_size
+    }
+
+    /// Returns the sequence number of the [`crate::sample::Sample`]. Every
+    /// [`crate::port::publisher::Publisher`] numbers its sent samples consecutively, starting
+    /// with 0. Loaned samples that are dropped without being sent do not consume a sequence
+    /// number, therefore a gap between two received samples means that samples were lost.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
 }
@@ -0,0 +1,108 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/Calculator")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server().create()?;
+//!
+//! while let Some(request) = server.receive()? {
+//!     println!("request header: {:?}", request.header());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+
+/// Request header used by
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+#[derive(Debug)]
+#[repr(C)]
+pub struct RequestHeader {
+    client_port_id: UniqueClientId,
+    request_id: u64,
+    // cleared by the client when it is no longer interested in the responses, the request
+    // stays in the data segment of the client as long as a server holds it
+    is_active: AtomicBool,
+}
+
+impl RequestHeader {
+    pub(crate) fn new(client_port_id: UniqueClientId, request_id: u64) -> Self {
+        Self {
+            client_port_id,
+            request_id,
+            is_active: AtomicBool::new(true),
+        }
+    }
+
+    pub(crate) fn deactivate(&self) {
+        self.is_active.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`crate::port::client::Client`] that sent the
+    /// request.
+    pub fn client_id(&self) -> UniqueClientId {
+        self.client_port_id
+    }
+
+    /// Returns the id of the request. It is unique for all requests of the
+    /// [`crate::port::client::Client`] and is used to correlate the responses with the request.
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+
+    /// Returns true as long as the [`crate::port::client::Client`] waits for responses, see
+    /// [`crate::port::client::PendingResponse`].
+    pub fn is_active(&self) -> bool {
+        self.is_active.load(Ordering::Relaxed)
+    }
+}
+
+/// Response header used by
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+#[derive(Debug)]
+#[repr(C)]
+pub struct ResponseHeader {
+    server_port_id: UniqueServerId,
+    request_id: u64,
+}
+
+impl ResponseHeader {
+    pub(crate) fn new(server_port_id: UniqueServerId, request_id: u64) -> Self {
+        Self {
+            server_port_id,
+            request_id,
+        }
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`crate::port::server::Server`] that sent the
+    /// response.
+    pub fn server_id(&self) -> UniqueServerId {
+        self.server_port_id
+    }
+
+    /// Returns the id of the request this is a response to, see
+    /// [`RequestHeader::request_id()`].
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+}
@@ -31,10 +31,19 @@
 //! [`Listener`](crate::port::listener::Listener)s.
 //!
 //! **Note:** This does **not** send or receive POSIX signals nor is it based on them.
+//!
+//! ### Request-Response
+//!
+//! `n` [`Client`](crate::port::client::Client)s send requests to `m`
+//! [`Server`](crate::port::server::Server)s and every server can stream multiple responses back
+//! to the client that sent the request. The client receives the responses of a request via the
+//! [`PendingResponse`](crate::port::client::PendingResponse) that was returned when the request
+//! was sent.
 use std::fmt::Display;
 
 use crate::service::static_config::event;
 use crate::service::static_config::publish_subscribe;
+use crate::service::static_config::request_response;
 use serde::{Deserialize, Serialize};
 
 /// Contains the static config of the corresponding messaging pattern.
@@ -44,6 +53,7 @@ use serde::{Deserialize, Serialize};
 pub enum MessagingPattern {
     PublishSubscribe(publish_subscribe::StaticConfig),
     Event(event::StaticConfig),
+    RequestResponse(request_response::StaticConfig),
 }
 
 impl Display for MessagingPattern {
@@ -51,6 +61,7 @@ impl Display for MessagingPattern {
         match self {
             MessagingPattern::Event(_) => write!(f, "Event"),
             MessagingPattern::PublishSubscribe(_) => write!(f, "PublishSubscribe"),
+            MessagingPattern::RequestResponse(_) => write!(f, "RequestResponse"),
         }
     }
 }
@@ -60,6 +71,7 @@ impl From<MessagingPattern> for u32 {
         match value {
             MessagingPattern::Event(_) => 0,
             MessagingPattern::PublishSubscribe(_) => 1,
+            MessagingPattern::RequestResponse(_) => 2,
         }
     }
 }
@@ -73,6 +85,9 @@ impl MessagingPattern {
             MessagingPattern::Event(_) => {
                 matches!(rhs, MessagingPattern::Event(_))
             }
+            MessagingPattern::RequestResponse(_) => {
+                matches!(rhs, MessagingPattern::RequestResponse(_))
+            }
         }
     }
 
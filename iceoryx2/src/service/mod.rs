@@ -58,6 +58,29 @@
 //! # }
 //! ```
 //!
+//! ## Request-Response
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/Calculator")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     // define the messaging pattern with the request and the response type
+//!     .request_response::<u64, u64>()
+//!     // various QoS
+//!     .max_clients(4)
+//!     .max_servers(1)
+//!     .max_active_requests(2)
+//!     .max_responses_per_request(3)
+//!     // if the service already exists, open it, otherwise create it
+//!     .open_or_create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Publish-Subscribe With Custom Configuration
 //!
 //! ```
@@ -100,9 +123,16 @@
 //! # }
 //! ```
 
+/// Key-value pairs that are attached to a [`Service`] when it is created and can be required when
+/// it is opened
+pub mod attribute;
+
 /// The builder to create or open [`Service`]s
 pub mod builder;
 
+/// Watches the [`Service`]s in the system and reports when they are created or removed
+pub mod discovery;
+
 /// The dynamic configuration of a [`Service`]
 pub mod dynamic_config;
 
@@ -121,6 +151,9 @@ pub mod port_factory;
 /// Represents the name of a [`Service`]
 pub mod service_name;
 
+/// Represents a pattern that matches a hierarchy of [`service_name::ServiceName`]s
+pub mod service_name_pattern;
+
 /// Represents the static configuration of a [`Service`]. These are the settings that never change
 /// during the runtime of a service, like:
 ///
@@ -137,16 +170,24 @@ pub mod zero_copy;
 
 pub(crate) mod config_scheme;
 pub(crate) mod naming_scheme;
+pub(crate) mod stale_resources;
 
 use std::fmt::Debug;
 
 use crate::config;
+use crate::node::NodeId;
 use crate::port::event_id::EventId;
+use crate::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::service::discovery::RemovalAnnouncement;
+use crate::service::dynamic_config::publish_subscribe::{
+    PublisherStatistics, SubscriberStatistics,
+};
 use crate::service::dynamic_config::DynamicConfig;
+use crate::service::messaging_pattern::MessagingPattern;
 use crate::service::static_config::*;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::{fail, trace, warn};
-use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
 use iceoryx2_cal::event::Event;
 use iceoryx2_cal::hash::Hash;
 use iceoryx2_cal::named_concept::NamedConceptListError;
@@ -179,7 +220,7 @@ impl std::error::Error for ServiceDoesExistError {}
 
 /// Failure that can be reported by [`Details::list()`] or
 /// [`Details::list_with_custom_config()`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ServiceListError {
     InsufficientPermissions,
     InternalError,
@@ -193,6 +234,120 @@ impl std::fmt::Display for ServiceListError {
 
 impl std::error::Error for ServiceListError {}
 
+/// Failure that can be reported by [`Details::remove_stale_resources()`] or
+/// [`Details::remove_stale_resources_with_custom_config()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceRemoveStaleResourcesError {
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for ServiceRemoveStaleResourcesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServiceRemoveStaleResourcesError {}
+
+/// Failure that can be reported by [`Details::remove()`], [`Details::force_remove()`] or their
+/// variants with a custom [`config::Config`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceRemoveError {
+    DoesNotExist,
+    InUse,
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for ServiceRemoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServiceRemoveError {}
+
+/// The number of currently connected ports of an existing [`Service`], depending on its
+/// [`MessagingPattern`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DynamicDetails {
+    PublishSubscribe {
+        number_of_publishers: usize,
+        number_of_subscribers: usize,
+    },
+    Event {
+        number_of_notifiers: usize,
+        number_of_listeners: usize,
+    },
+    RequestResponse {
+        number_of_clients: usize,
+        number_of_servers: usize,
+    },
+}
+
+/// Describes an existing [`Service`]. Acquired with [`Details::list_details()`] or
+/// [`Details::list_details_with_custom_config()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceDetails {
+    static_details: StaticConfig,
+    dynamic_details: Option<DynamicDetails>,
+    publisher_statistics: Vec<(UniquePublisherId, PublisherStatistics)>,
+    subscriber_statistics: Vec<(UniqueSubscriberId, SubscriberStatistics)>,
+}
+
+impl ServiceDetails {
+    /// Returns the [`ServiceName`] of the [`Service`]
+    pub fn service_name(&self) -> &ServiceName {
+        self.static_details.service_name()
+    }
+
+    /// Returns the uuid of the [`Service`]
+    pub fn uuid(&self) -> &str {
+        self.static_details.uuid()
+    }
+
+    /// Returns the uuid of the concrete instance of the [`Service`], see
+    /// [`StaticConfig::instance_uuid()`].
+    pub fn instance_uuid(&self) -> &str {
+        self.static_details.instance_uuid()
+    }
+
+    /// Returns the [`MessagingPattern`] of the [`Service`] which contains the type details and
+    /// the configured limits.
+    pub fn messaging_pattern(&self) -> &MessagingPattern {
+        self.static_details.messaging_pattern()
+    }
+
+    /// Returns the [`StaticConfig`] of the [`Service`]
+    pub fn static_details(&self) -> &StaticConfig {
+        &self.static_details
+    }
+
+    /// Returns the number of connected ports. If the dynamic service information could not be
+    /// acquired, for instance since the [`Service`] is currently created or removed, it returns
+    /// [`None`].
+    pub fn dynamic_details(&self) -> Option<DynamicDetails> {
+        self.dynamic_details
+    }
+
+    /// Returns the [`PublisherStatistics`] of every connected
+    /// [`crate::port::publisher::Publisher`]. It is empty for services of other messaging
+    /// patterns or when the dynamic service information could not be acquired.
+    pub fn publisher_statistics(&self) -> &[(UniquePublisherId, PublisherStatistics)] {
+        &self.publisher_statistics
+    }
+
+    /// Returns the [`SubscriberStatistics`] of every connected
+    /// [`crate::port::subscriber::Subscriber`]. It is empty for services of other messaging
+    /// patterns or when the dynamic service information could not be acquired.
+    pub fn subscriber_statistics(&self) -> &[(UniqueSubscriberId, SubscriberStatistics)] {
+        &self.subscriber_statistics
+    }
+}
+
 /// Represents the [`Service`]s state.
 #[derive(Debug)]
 pub struct ServiceState<'config, Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>> {
@@ -200,6 +355,10 @@ pub struct ServiceState<'config, Static: StaticStorage, Dynamic: DynamicStorage<
     pub(crate) global_config: &'config config::Config,
     pub(crate) dynamic_storage: Dynamic,
     pub(crate) static_storage: Static,
+    pub(crate) node_id: Option<NodeId>,
+    owner_index: u32,
+    // declared after the storages, the removal is announced after they are removed
+    removal_announcement: RemovalAnnouncement<'config>,
 }
 
 impl<'config, Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>>
@@ -210,12 +369,18 @@ impl<'config, Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>>
         global_config: &'config config::Config,
         dynamic_storage: Dynamic,
         static_storage: Static,
+        owner_index: u32,
+        node_id: Option<NodeId>,
+        announce_change: fn(&config::Config),
     ) -> Self {
         let new_self = Self {
             static_config,
             global_config,
             dynamic_storage,
             static_storage,
+            node_id,
+            owner_index,
+            removal_announcement: RemovalAnnouncement::new(global_config, announce_change),
         };
         trace!(from new_self, "open service");
         new_self
@@ -226,6 +391,9 @@ impl<'config, Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>> Dro
     for ServiceState<'config, Static, Dynamic>
 {
     fn drop(&mut self) {
+        self.dynamic_storage
+            .get()
+            .deregister_owner(self.owner_index);
         match self.dynamic_storage.get().decrement_reference_counter() {
             DecrementReferenceCounterResult::HasOwners => {
                 trace!(from self, "close service");
@@ -233,6 +401,7 @@ impl<'config, Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>> Dro
             DecrementReferenceCounterResult::NoMoreOwners => {
                 self.static_storage.acquire_ownership();
                 self.dynamic_storage.acquire_ownership();
+                self.removal_announcement.arm();
                 trace!(from self, "close and remove service");
             }
         }
@@ -400,6 +569,108 @@ pub trait Details<'config>: Debug + Sized {
         Self::list_with_custom_config(config::Config::get_global_config())
     }
 
+    /// Returns the [`ServiceDetails`] of all created services in the system. Services whose
+    /// static information is corrupted are skipped and reported with a warning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// for service in zero_copy::Service::list_details()? {
+    ///     println!("{} {}: {:?}", service.uuid(), service.service_name(),
+    ///         service.dynamic_details());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_details() -> Result<Vec<ServiceDetails>, ServiceListError> {
+        Self::list_details_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Returns the [`ServiceDetails`] of all services created under a given [`config::Config`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut custom_config = Config::default();
+    /// let services = zero_copy::Service::list_details_with_custom_config(&custom_config)?;
+    ///
+    /// for service in services {
+    ///     println!("\n{:#?}", &service);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_details_with_custom_config(
+        config: &'config config::Config,
+    ) -> Result<Vec<ServiceDetails>, ServiceListError> {
+        let origin = "Service::list_details_with_custom_config()";
+        let services = fail!(from origin, when Self::list_with_custom_config(config),
+                "Unable to list the details of all services since the services could not be listed.");
+
+        let dynamic_storage_config = config_scheme::dynamic_config_storage_config::<Self>(config);
+        let mut details = vec![];
+        for static_details in services {
+            let mut publisher_statistics = vec![];
+            let mut subscriber_statistics = vec![];
+            let dynamic_details = match <<Self::DynamicStorage as DynamicStorage<
+                DynamicConfig,
+            >>::Builder as NamedConceptBuilder<Self::DynamicStorage>>::new(
+                &naming_scheme::dynamic_config_storage_name(&static_details),
+            )
+            .config(&dynamic_storage_config)
+            .has_ownership(false)
+            .try_open()
+            {
+                Ok(storage) => match static_details.messaging_pattern() {
+                    MessagingPattern::PublishSubscribe(_) => {
+                        let dynamic_config = storage.get().publish_subscribe();
+                        publisher_statistics = dynamic_config.publisher_statistics();
+                        subscriber_statistics = dynamic_config.subscriber_statistics();
+                        Some(DynamicDetails::PublishSubscribe {
+                            number_of_publishers: dynamic_config.number_of_publishers(),
+                            number_of_subscribers: dynamic_config.number_of_subscribers(),
+                        })
+                    }
+                    MessagingPattern::Event(_) => {
+                        let dynamic_config = storage.get().event();
+                        Some(DynamicDetails::Event {
+                            number_of_notifiers: dynamic_config.number_of_notifiers(),
+                            number_of_listeners: dynamic_config.number_of_listeners(),
+                        })
+                    }
+                    MessagingPattern::RequestResponse(_) => {
+                        let dynamic_config = storage.get().request_response();
+                        Some(DynamicDetails::RequestResponse {
+                            number_of_clients: dynamic_config.number_of_clients(),
+                            number_of_servers: dynamic_config.number_of_servers(),
+                        })
+                    }
+                },
+                Err(e) => {
+                    warn!(from origin, "Unable to acquire the dynamic service information of the service \"{}\" ({:?}).",
+                        static_details.service_name(), e);
+                    None
+                }
+            };
+
+            details.push(ServiceDetails {
+                static_details,
+                dynamic_details,
+                publisher_statistics,
+                subscriber_statistics,
+            });
+        }
+
+        Ok(details)
+    }
+
     /// Returns a list of all services created under a given [`config::Config`].
     ///
     /// # Example
@@ -478,4 +749,193 @@ pub trait Details<'config>: Debug + Sized {
 
         Ok(service_vec)
     }
+
+    /// Returns a list of all created services in the system whose
+    /// [`service_name::ServiceName`] matches the
+    /// [`ServiceNamePattern`](service_name_pattern::ServiceNamePattern).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::service_name_pattern::ServiceNamePattern;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = ServiceNamePattern::new("vehicle/sensors/**")?;
+    ///
+    /// for service in zero_copy::Service::list_matching(&pattern)? {
+    ///     println!("{}", service.service_name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_matching(
+        pattern: &service_name_pattern::ServiceNamePattern,
+    ) -> Result<Vec<StaticConfig>, ServiceListError> {
+        Self::list_matching_with_custom_config(config::Config::get_global_config(), pattern)
+    }
+
+    /// Returns a list of all services created under a given [`config::Config`] whose
+    /// [`service_name::ServiceName`] matches the
+    /// [`ServiceNamePattern`](service_name_pattern::ServiceNamePattern).
+    fn list_matching_with_custom_config(
+        config: &'config config::Config,
+        pattern: &service_name_pattern::ServiceNamePattern,
+    ) -> Result<Vec<StaticConfig>, ServiceListError> {
+        let services = fail!(from "Service::list_matching_with_custom_config()",
+                when Self::list_with_custom_config(config),
+                "Unable to list the services matching \"{}\" since the services could not be listed.", pattern);
+
+        Ok(services
+            .into_iter()
+            .filter(|service| pattern.matches(service.service_name()))
+            .collect())
+    }
+    /// Removes the resources that were left behind by processes that died without closing their
+    /// services, like the ports and data segments of a crashed process. When all owners of a
+    /// service are dead or the creation of a service was never finished, the whole service is
+    /// removed so that it can be created again. Persistent services are kept, they must be
+    /// removed with [`Details::remove()`]. Resources of live processes are never touched.
+    /// Returns the number of removed services.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let number_of_removed_services = zero_copy::Service::remove_stale_resources()?;
+    /// println!("removed {} stale services", number_of_removed_services);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn remove_stale_resources() -> Result<usize, ServiceRemoveStaleResourcesError> {
+        Self::remove_stale_resources_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Removes the stale resources of all services created under a given [`config::Config`].
+    /// See [`Details::remove_stale_resources()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut custom_config = Config::default();
+    /// let number_of_removed_services =
+    ///     zero_copy::Service::remove_stale_resources_with_custom_config(&custom_config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn remove_stale_resources_with_custom_config(
+        config: &'config config::Config,
+    ) -> Result<usize, ServiceRemoveStaleResourcesError> {
+        stale_resources::remove_stale_resources::<Self>(config)
+    }
+
+    /// Removes every service with the given name, regardless of its
+    /// [`ServicePersistence`](crate::service::static_config::ServicePersistence). It fails with
+    /// [`ServiceRemoveError::InUse`] when a process has the service still opened, ports keep
+    /// the service opened as well. A process that opens the service concurrently either opens
+    /// it before it is removed or fails because it does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/PersistentService")?;
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .persistence(ServicePersistence::Persistent)
+    ///     .create::<u64>()?;
+    /// drop(service);
+    ///
+    /// // the service is still available for processes that open it later
+    /// zero_copy::Service::remove(&service_name)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn remove(service_name: &ServiceName) -> Result<(), ServiceRemoveError> {
+        Self::remove_with_custom_config(service_name, config::Config::get_global_config())
+    }
+
+    /// Removes every service with the given name that was created under a given
+    /// [`config::Config`]. See [`Details::remove()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/PersistentService")?;
+    /// let mut custom_config = Config::default();
+    /// match zero_copy::Service::remove_with_custom_config(&service_name, &custom_config) {
+    ///     Ok(()) | Err(ServiceRemoveError::DoesNotExist) => (),
+    ///     Err(e) => println!("unable to remove the service: {}", e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn remove_with_custom_config(
+        service_name: &ServiceName,
+        config: &'config config::Config,
+    ) -> Result<(), ServiceRemoveError> {
+        stale_resources::remove_service::<Self>(service_name, config, false)
+    }
+
+    /// Removes every service with the given name together with the resources of the ports of
+    /// dead processes, even when processes have the service still opened. It is intended to
+    /// recover from crashes, the processes that still use the service continue to work with
+    /// the removed service but new processes cannot connect to it anymore. The ports of live
+    /// processes release their resources when they are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/CrashedService")?;
+    /// match zero_copy::Service::force_remove(&service_name) {
+    ///     Ok(()) | Err(ServiceRemoveError::DoesNotExist) => (),
+    ///     Err(e) => println!("unable to remove the service: {}", e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn force_remove(service_name: &ServiceName) -> Result<(), ServiceRemoveError> {
+        Self::force_remove_with_custom_config(service_name, config::Config::get_global_config())
+    }
+
+    /// Removes every service with the given name that was created under a given
+    /// [`config::Config`] with force. See [`Details::force_remove()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/CrashedService")?;
+    /// let mut custom_config = Config::default();
+    /// match zero_copy::Service::force_remove_with_custom_config(&service_name, &custom_config) {
+    ///     Ok(()) | Err(ServiceRemoveError::DoesNotExist) => (),
+    ///     Err(e) => println!("unable to remove the service: {}", e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn force_remove_with_custom_config(
+        service_name: &ServiceName,
+        config: &'config config::Config,
+    ) -> Result<(), ServiceRemoveError> {
+        stale_resources::remove_service::<Self>(service_name, config, true)
+    }
 }
@@ -10,20 +10,75 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::port::port_identifiers::{UniqueListenerId, UniquePublisherId, UniqueSubscriberId};
+use crate::node::NodeId;
+use crate::port::port_identifiers::{
+    UniqueClientId, UniqueListenerId, UniquePublisherId, UniqueServerId, UniqueSubscriberId,
+};
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
 
 use super::static_config::StaticConfig;
 
+// The names are part of paths whose length is limited by the platform, for instance unix
+// datagram sockets are limited to 108 characters and windows paths to 255 characters. The id
+// is therefore written as fixed width hexadecimal number, it contains the process id already.
+fn id_as_hex(id: UniqueSystemId) -> String {
+    format!("{:032x}", id.value())
+}
+
+fn event_concept_name_of(port_id: UniqueSystemId) -> FileName {
+    let msg = "The system does not support the required file name length for the event concept name of a port.";
+    fatal_panic!(from "event_concept_name()",
+        when FileName::new(id_as_hex(port_id).as_bytes()), "{}", msg)
+}
+
 pub(crate) fn event_concept_name(listener_id: &UniqueListenerId) -> FileName {
-    let msg = "The system does not support the required file name length for the listeners event concept name.";
-    let origin = "event_concept_name()";
-    let mut file = fatal_panic!(from origin, when FileName::new(listener_id.0.pid().to_string().as_bytes()), "{}", msg);
-    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
-    fatal_panic!(from origin, when file.push_bytes(listener_id.0.value().to_string().as_bytes()), "{}", msg);
-    file
+    event_concept_name_of(listener_id.0)
+}
+
+// subscribers of a service with notifications are woken up by the publishers via their own
+// event concept
+pub(crate) fn subscriber_event_concept_name(subscriber_id: &UniqueSubscriberId) -> FileName {
+    event_concept_name_of(subscriber_id.0)
+}
+
+// publishers waiting for a free sample are woken up by the subscribers that release samples
+pub(crate) fn publisher_event_concept_name(publisher_id: &UniquePublisherId) -> FileName {
+    event_concept_name_of(publisher_id.0)
+}
+
+// every service discovery is woken up via its own event concept when a service is created or
+// removed
+pub(crate) fn discovery_event_concept_name(discovery_id: UniqueSystemId) -> FileName {
+    event_concept_name_of(discovery_id)
+}
+
+// every connection monitor is woken up via its own event concept when a port of its service is
+// created or removed, the name starts with the service uuid so that the monitors of a service
+// can be found
+pub(crate) fn connection_monitor_event_concept_name(
+    service_uuid: &str,
+    monitor_id: UniqueSystemId,
+) -> FileName {
+    let msg = "The system does not support the required file name length for the event concept name of a connection monitor.";
+    fatal_panic!(from "connection_monitor_event_concept_name()",
+        when FileName::new(format!("{}_{}", service_uuid, id_as_hex(monitor_id)).as_bytes()), "{}", msg)
+}
+
+// the id of a connection monitor contains the process id of its creator, it is required to
+// remove the event concepts of monitors whose process died
+pub(crate) fn connection_monitor_id(event_concept_name: &FileName) -> Option<UniqueSystemId> {
+    let name = core::str::from_utf8(event_concept_name.as_bytes()).ok()?;
+    let (_, id) = name.rsplit_once('_')?;
+    u128::from_str_radix(id, 16).ok().map(UniqueSystemId::from)
+}
+
+pub(crate) fn node_details_storage_name(node_id: &NodeId) -> FileName {
+    let msg = "The system does not support the required file name length for the node details.";
+    fatal_panic!(from "node_details_storage_name()",
+        when FileName::new(node_id.value().to_string().as_bytes()), "{}", msg)
 }
 
 pub(crate) fn dynamic_config_storage_name(static_config: &StaticConfig) -> FileName {
@@ -34,23 +89,55 @@ pub(crate) fn static_config_storage_name(uuid: &str) -> FileName {
     FileName::new(uuid.as_bytes()).unwrap()
 }
 
+fn connection_name_of(sender_id: UniqueSystemId, receiver_id: UniqueSystemId) -> FileName {
+    let mut file = FileName::new(id_as_hex(sender_id).as_bytes()).unwrap();
+    file.push(b'_').unwrap();
+    file.push_bytes(id_as_hex(receiver_id).as_bytes()).unwrap();
+    file
+}
+
 pub(crate) fn connection_name(
     publisher_id: UniquePublisherId,
     subscriber_id: UniqueSubscriberId,
 ) -> FileName {
-    let mut file = FileName::new(publisher_id.0.value().to_string().as_bytes()).unwrap();
-    file.push(b'_').unwrap();
-    file.push_bytes(subscriber_id.0.value().to_string().as_bytes())
-        .unwrap();
-    file
+    connection_name_of(publisher_id.0, subscriber_id.0)
+}
+
+pub(crate) fn request_connection_name(
+    client_id: UniqueClientId,
+    server_id: UniqueServerId,
+) -> FileName {
+    connection_name_of(client_id.0, server_id.0)
 }
 
-pub(crate) fn data_segment_name(publisher_id: UniquePublisherId) -> FileName {
-    let msg = "The system does not support the required file name length for the publishers data segment.";
+pub(crate) fn response_connection_name(
+    server_id: UniqueServerId,
+    client_id: UniqueClientId,
+) -> FileName {
+    connection_name_of(server_id.0, client_id.0)
+}
+
+fn data_segment_name_of(port_id: UniqueSystemId, segment_id: usize) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the data segment of a port.";
     let origin = "data_segment_name()";
 
-    let mut file = fatal_panic!(from origin, when FileName::new(publisher_id.0.pid().to_string().as_bytes()), "{}", msg);
+    let mut file =
+        fatal_panic!(from origin, when FileName::new(id_as_hex(port_id).as_bytes()), "{}", msg);
     fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
-    fatal_panic!(from origin, when file.push_bytes(publisher_id.0.value().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(segment_id.to_string().as_bytes()), "{}", msg);
     file
 }
+
+pub(crate) fn data_segment_name(publisher_id: UniquePublisherId, segment_id: usize) -> FileName {
+    data_segment_name_of(publisher_id.0, segment_id)
+}
+
+// clients and servers own a single data segment for their requests and responses
+pub(crate) fn client_data_segment_name(client_id: UniqueClientId) -> FileName {
+    data_segment_name_of(client_id.0, 0)
+}
+
+pub(crate) fn server_data_segment_name(server_id: UniqueServerId) -> FileName {
+    data_segment_name_of(server_id.0, 0)
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/Calculator")?;
+//! let rpc = zero_copy::Service::new(&service_name)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = rpc.client().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use crate::port::client::{Client, ClientCreateError};
+use crate::service;
+
+use super::request_response::PortFactory;
+
+/// Factory to create a new [`Client`] port/endpoint for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based communication.
+#[derive(Debug)]
+pub struct PortFactoryClient<
+    'factory,
+    'config,
+    Service: service::Details<'config>,
+    RequestPayload: Debug,
+    ResponsePayload: Debug,
+> {
+    pub(crate) factory: &'factory PortFactory<'config, Service, RequestPayload, ResponsePayload>,
+}
+
+impl<
+        'factory,
+        'config,
+        Service: service::Details<'config>,
+        RequestPayload: Debug,
+        ResponsePayload: Debug,
+    > PortFactoryClient<'factory, 'config, Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        factory: &'factory PortFactory<'config, Service, RequestPayload, ResponsePayload>,
+    ) -> Self {
+        Self { factory }
+    }
+
+    /// Creates a new [`Client`] or returns a [`ClientCreateError`] on failure.
+    pub fn create(
+        &self,
+    ) -> Result<
+        Client<'factory, 'config, Service, RequestPayload, ResponsePayload>,
+        ClientCreateError,
+    > {
+        Ok(
+            fail!(from self, when Client::new(&self.factory.service, self.factory.service.state().static_config.request_response()),
+                "Failed to create new Client port."),
+        )
+    }
+}
@@ -35,8 +35,10 @@
 //! ```
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
+use crate::service::attribute::AttributeSet;
 use crate::service::{self, static_config};
 use crate::service::{dynamic_config, ServiceName};
+use std::fmt::Debug;
 use std::marker::PhantomData;
 
 use super::listener::PortFactoryListener;
@@ -47,18 +49,28 @@ use super::notifier::PortFactoryNotifier;
 /// acquire dynamic and static service informations and create [`crate::port::notifier::Notifier`]
 /// or [`crate::port::listener::Listener`] ports.
 #[derive(Debug)]
-pub struct PortFactory<'config, Service: service::Details<'config>> {
+pub struct PortFactory<'config, Service: service::Details<'config>, EventPayload: Debug = ()> {
     pub(crate) service: Service,
+    _phantom_event_payload: PhantomData<EventPayload>,
     _phantom_lifetime_b: PhantomData<&'config ()>,
 }
 
-unsafe impl<'config, Service: service::Details<'config>> Send for PortFactory<'config, Service> {}
-unsafe impl<'config, Service: service::Details<'config>> Sync for PortFactory<'config, Service> {}
+unsafe impl<'config, Service: service::Details<'config>, EventPayload: Debug> Send
+    for PortFactory<'config, Service, EventPayload>
+{
+}
+unsafe impl<'config, Service: service::Details<'config>, EventPayload: Debug> Sync
+    for PortFactory<'config, Service, EventPayload>
+{
+}
 
-impl<'config, Service: service::Details<'config>> PortFactory<'config, Service> {
+impl<'config, Service: service::Details<'config>, EventPayload: Debug>
+    PortFactory<'config, Service, EventPayload>
+{
     pub(crate) fn new(service: Service) -> Self {
         Self {
             service,
+            _phantom_event_payload: PhantomData,
             _phantom_lifetime_b: PhantomData,
         }
     }
@@ -73,6 +85,17 @@ impl<'config, Service: service::Details<'config>> PortFactory<'config, Service>
         self.service.state().static_config.uuid()
     }
 
+    /// Returns the uuid of the concrete instance of the [`crate::service::Service`], it changes
+    /// when the service is removed and created again.
+    pub fn instance_uuid(&self) -> &str {
+        self.service.state().static_config.instance_uuid()
+    }
+
+    /// Returns the [`AttributeSet`] the [`crate::service::Service`] was created with
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
     /// Returns the [`static_config::event::StaticConfig`] of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     pub fn static_config(&self) -> &static_config::event::StaticConfig {
@@ -102,7 +125,7 @@ impl<'config, Service: service::Details<'config>> PortFactory<'config, Service>
     /// # Ok(())
     /// # }
     /// ```
-    pub fn notifier<'a>(&'a self) -> PortFactoryNotifier<'a, 'config, Service> {
+    pub fn notifier<'a>(&'a self) -> PortFactoryNotifier<'a, 'config, Service, EventPayload> {
         PortFactoryNotifier::new(self)
     }
 
@@ -123,7 +146,7 @@ impl<'config, Service: service::Details<'config>> PortFactory<'config, Service>
     /// # Ok(())
     /// # }
     /// ```
-    pub fn listener<'a>(&'a self) -> PortFactoryListener<'a, 'config, Service> {
+    pub fn listener<'a>(&'a self) -> PortFactoryListener<'a, 'config, Service, EventPayload> {
         PortFactoryListener { factory: self }
     }
 }
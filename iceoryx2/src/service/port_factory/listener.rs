@@ -38,15 +38,22 @@ use super::event::PortFactory;
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) based
 /// communication.
 #[derive(Debug)]
-pub struct PortFactoryListener<'factory, 'config, Service: service::Details<'config>> {
-    pub(crate) factory: &'factory PortFactory<'config, Service>,
+pub struct PortFactoryListener<
+    'factory,
+    'config,
+    Service: service::Details<'config>,
+    EventPayload: Debug = (),
+> {
+    pub(crate) factory: &'factory PortFactory<'config, Service, EventPayload>,
 }
 
-impl<'factory, 'config, Service: service::Details<'config>>
-    PortFactoryListener<'factory, 'config, Service>
+impl<'factory, 'config, Service: service::Details<'config>, EventPayload: Debug>
+    PortFactoryListener<'factory, 'config, Service, EventPayload>
 {
     /// Creates the [`Listener`] port or returns a [`ListenerCreateError`] on failure.
-    pub fn create(&self) -> Result<Listener<'factory, 'config, Service>, ListenerCreateError> {
+    pub fn create(
+        &self,
+    ) -> Result<Listener<'factory, 'config, Service, EventPayload>, ListenerCreateError> {
         Ok(fail!(from self, when Listener::new(&self.factory.service),
                     "Failed to create new Listener port."))
     }
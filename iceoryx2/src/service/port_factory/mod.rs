@@ -10,6 +10,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// Factory to create a [`crate::port::client::Client`]
+pub mod client;
+
 /// Factory to create the endpoints of
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) based
 /// communication and to acquire static and dynamic service information
@@ -29,5 +32,13 @@ pub mod publish_subscribe;
 /// Factory to create a [`crate::port::publisher::Publisher`]
 pub mod publisher;
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) based
+/// communication and to acquire static and dynamic service information
+pub mod request_response;
+
+/// Factory to create a [`crate::port::server::Server`]
+pub mod server;
+
 /// Factory to create a [`crate::port::subscriber::Subscriber`]
 pub mod subscriber;
@@ -41,15 +41,20 @@ use super::event::PortFactory;
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) based
 /// communication.
 #[derive(Debug)]
-pub struct PortFactoryNotifier<'factory, 'config, Service: service::Details<'config>> {
-    pub(crate) factory: &'factory PortFactory<'config, Service>,
+pub struct PortFactoryNotifier<
+    'factory,
+    'config,
+    Service: service::Details<'config>,
+    EventPayload: Debug = (),
+> {
+    pub(crate) factory: &'factory PortFactory<'config, Service, EventPayload>,
     default_event_id: EventId,
 }
 
-impl<'factory, 'config, Service: service::Details<'config>>
-    PortFactoryNotifier<'factory, 'config, Service>
+impl<'factory, 'config, Service: service::Details<'config>, EventPayload: Debug>
+    PortFactoryNotifier<'factory, 'config, Service, EventPayload>
 {
-    pub(crate) fn new(factory: &'factory PortFactory<'config, Service>) -> Self {
+    pub(crate) fn new(factory: &'factory PortFactory<'config, Service, EventPayload>) -> Self {
         Self {
             factory,
             default_event_id: EventId::default(),
@@ -64,7 +69,9 @@ impl<'factory, 'config, Service: service::Details<'config>>
     }
 
     /// Creates a new [`Notifier`] port or returns a [`NotifierCreateError`] on failure.
-    pub fn create(&self) -> Result<Notifier<'factory, 'config, Service>, NotifierCreateError> {
+    pub fn create(
+        &self,
+    ) -> Result<Notifier<'factory, 'config, Service, EventPayload>, NotifierCreateError> {
         Ok(
             fail!(from self, when Notifier::new(&self.factory.service, self.default_event_id),
                     "Failed to create new Notifier port."),
@@ -42,8 +42,12 @@
 
 use std::{fmt::Debug, marker::PhantomData};
 
+use iceoryx2_bb_elementary::callback_progression::CallbackProgression;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
+use crate::payload_type::PayloadType;
+use crate::service::attribute::AttributeSet;
+use crate::service::dynamic_config::publish_subscribe::{DynamicConfigSettings, PortDetails};
 use crate::service::service_name::ServiceName;
 use crate::service::{self, dynamic_config, static_config};
 
@@ -55,28 +59,47 @@ use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 /// [`crate::port::publisher::Publisher`]
 /// or [`crate::port::subscriber::Subscriber`] ports.
 #[derive(Debug)]
-pub struct PortFactory<'config, Service: service::Details<'config>, MessageType: Debug> {
+pub struct PortFactory<
+    'config,
+    Service: service::Details<'config>,
+    MessageType: Debug + ?Sized,
+    UserHeader: Debug = (),
+> {
     pub(crate) service: Service,
     _phantom_message_type: PhantomData<MessageType>,
+    _phantom_user_header: PhantomData<UserHeader>,
     _phantom_lifetime_b: PhantomData<&'config ()>,
 }
 
-unsafe impl<'config, Service: service::Details<'config>, MessageType: Debug> Send
-    for PortFactory<'config, Service, MessageType>
+unsafe impl<
+        'config,
+        Service: service::Details<'config>,
+        MessageType: Debug + ?Sized,
+        UserHeader: Debug,
+    > Send for PortFactory<'config, Service, MessageType, UserHeader>
 {
 }
-unsafe impl<'config, Service: service::Details<'config>, MessageType: Debug> Sync
-    for PortFactory<'config, Service, MessageType>
+unsafe impl<
+        'config,
+        Service: service::Details<'config>,
+        MessageType: Debug + ?Sized,
+        UserHeader: Debug,
+    > Sync for PortFactory<'config, Service, MessageType, UserHeader>
 {
 }
 
-impl<'config, Service: service::Details<'config>, MessageType: Debug>
-    PortFactory<'config, Service, MessageType>
+impl<
+        'config,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug + Default,
+    > PortFactory<'config, Service, MessageType, UserHeader>
 {
     pub(crate) fn new(service: Service) -> Self {
         Self {
             service,
             _phantom_message_type: PhantomData,
+            _phantom_user_header: PhantomData,
             _phantom_lifetime_b: PhantomData,
         }
     }
@@ -91,8 +114,22 @@ impl<'config, Service: service::Details<'config>, MessageType: Debug>
         self.service.state().static_config.uuid()
     }
 
-    /// Returns the [`static_config::event::StaticConfig`] of the [`crate::service::Service`].
-    /// Contains all settings that never change during the lifetime of the service.
+    /// Returns the uuid of the concrete instance of the [`crate::service::Service`], it changes
+    /// when the service is removed and created again.
+    pub fn instance_uuid(&self) -> &str {
+        self.service.state().static_config.instance_uuid()
+    }
+
+    /// Returns the [`AttributeSet`] the [`crate::service::Service`] was created with
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`static_config::publish_subscribe::StaticConfig`] of the
+    /// [`crate::service::Service`]. Contains all settings that never change during the lifetime
+    /// of the service, the attributes are returned by [`PortFactory::attributes()`]. It can be
+    /// compared with the requirements of other participants with
+    /// [`static_config::publish_subscribe::StaticConfig::compatibility_diff()`].
     pub fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
         self.service.state().static_config.publish_subscribe()
     }
@@ -107,6 +144,70 @@ impl<'config, Service: service::Details<'config>, MessageType: Debug>
             .publish_subscribe()
     }
 
+    /// Returns the number of bytes of shared memory that the dynamic service information
+    /// occupies. The data segments of the [`crate::port::publisher::Publisher`]s are not
+    /// included, they are reported by
+    /// [`Publisher::memory_budget()`](crate::port::publisher::Publisher::memory_budget()).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let pubsub = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// println!("the service occupies {} bytes", pubsub.memory_footprint());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn memory_footprint(&self) -> usize {
+        let state = self.service.state();
+        let static_config = self.static_config();
+        let settings = DynamicConfigSettings {
+            number_of_publishers: static_config.max_publishers,
+            number_of_subscribers: static_config.max_subscribers,
+        };
+
+        std::mem::size_of::<dynamic_config::DynamicConfig>()
+            + dynamic_config::DynamicConfig::memory_size(
+                state.global_config.global.service.max_owners,
+            )
+            + dynamic_config::publish_subscribe::DynamicConfig::memory_size(&settings)
+    }
+
+    /// Visits every [`crate::port::publisher::Publisher`] and
+    /// [`crate::port::subscriber::Subscriber`] that is currently attached to the service, see
+    /// [`dynamic_config::publish_subscribe::DynamicConfig::list_ports()`] for the consistency
+    /// guarantees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let pubsub = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// pubsub.dynamic_info(|port| {
+    ///     println!("{:?} of process {:?}, stale: {}", port.id(), port.pid(), port.is_stale());
+    ///     CallbackProgression::Continue
+    /// });
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dynamic_info<F: FnMut(&PortDetails) -> CallbackProgression>(&self, callback: F) {
+        self.dynamic_config().list_ports(callback)
+    }
+
     /// Returns a [`PortFactorySubscriber`] to create a new
     /// [`crate::port::subscriber::Subscriber`] port.
     ///
@@ -126,8 +227,10 @@ impl<'config, Service: service::Details<'config>, MessageType: Debug>
     /// # Ok(())
     /// # }
     /// ```
-    pub fn subscriber<'a>(&'a self) -> PortFactorySubscriber<'a, 'config, Service, MessageType> {
-        PortFactorySubscriber { factory: self }
+    pub fn subscriber<'a>(
+        &'a self,
+    ) -> PortFactorySubscriber<'a, 'config, Service, MessageType, UserHeader> {
+        PortFactorySubscriber::new(self)
     }
 
     /// Returns a [`PortFactoryPublisher`] to create a new
@@ -153,7 +256,9 @@ impl<'config, Service: service::Details<'config>, MessageType: Debug>
     /// # Ok(())
     /// # }
     /// ```
-    pub fn publisher<'a>(&'a self) -> PortFactoryPublisher<'a, 'config, Service, MessageType> {
+    pub fn publisher<'a>(
+        &'a self,
+    ) -> PortFactoryPublisher<'a, 'config, Service, MessageType, UserHeader> {
         PortFactoryPublisher::new(self)
     }
 }
@@ -33,16 +33,23 @@
 use std::fmt::Debug;
 
 use iceoryx2_bb_log::fail;
+use iceoryx2_cal::shared_memory::HugePages;
 use serde::{de::Visitor, Deserialize, Serialize};
 
 use super::publish_subscribe::PortFactory;
-use crate::{port::publish::PublisherCreateError, port::publisher::Publisher, service};
+use crate::{
+    payload_type::PayloadType, port::details::data_segment::MemoryOptions,
+    port::publish::PublisherCreateError, port::publisher::Publisher, service,
+    service::static_config::publish_subscribe::StaticConfig,
+};
 
 /// Defines the strategy the [`Publisher`] shall pursue in
 /// [`crate::payload_mut::PayloadMut::send()`] or
 /// [`crate::port::publish::SendCopy::send_copy()`] when the buffer of a
 /// [`crate::port::subscriber::Subscriber`] is full and the service does not overflow.
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+/// [`UnableToDeliverStrategy::DiscardSample`] is the weaker guarantee, a [`Publisher`] can
+/// always discard in a service that blocks but not the other way around.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
 pub enum UnableToDeliverStrategy {
     /// Blocks until the [`crate::port::subscriber::Subscriber`] has consumed the
     /// [`crate::sample::Sample`] from the buffer and there is space again
@@ -56,7 +63,10 @@ impl Serialize for UnableToDeliverStrategy {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&std::format!("{:?}", self))
+        serializer.serialize_str(match self {
+            UnableToDeliverStrategy::Block => "block",
+            UnableToDeliverStrategy::DiscardSample => "discard_sample",
+        })
     }
 }
 
@@ -93,30 +103,324 @@ impl<'de> Deserialize<'de> for UnableToDeliverStrategy {
     }
 }
 
+impl UnableToDeliverStrategy {
+    /// Returns true if a [`Publisher`] with this strategy can be part of a service that declared
+    /// the `service_strategy`.
+    pub(crate) fn is_compatible_with(&self, service_strategy: UnableToDeliverStrategy) -> bool {
+        *self == service_strategy || *self == UnableToDeliverStrategy::DiscardSample
+    }
+}
+
+/// Defines how the [`Publisher`] acquires additional memory when a loan does not fit into its
+/// data segment, either since all samples are in use or since a slice with more elements than
+/// the samples can hold was requested in
+/// [`Publisher::loan_slice_uninit()`](crate::port::publisher::Publisher::loan_slice_uninit()).
+/// The additional memory is a new data segment, the previous one stays mapped until all of its
+/// samples are released.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum AllocationStrategy {
+    /// The data segment is never extended, the loan fails instead.
+    #[default]
+    Static,
+    /// The new data segment rounds the number of elements of its samples up to the next power
+    /// of two and doubles the number of samples when all samples are in use. Repeated loans of
+    /// growing slices require only a few new data segments.
+    PowerOfTwo,
+    /// The samples of the new data segment hold exactly the requested number of elements and
+    /// the number of samples stays the same.
+    BestFit,
+}
+
+/// The maximum number of buckets of a [`BucketConfig`].
+pub const MAX_NUMBER_OF_BUCKETS: usize = 8;
+
+/// A size class of a [`BucketConfig`], a pool of samples whose payload can hold up to
+/// [`Bucket::payload_size()`] bytes.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct Bucket {
+    payload_size: usize,
+    number_of_samples: usize,
+}
+
+impl Bucket {
+    /// Returns the maximum payload size in bytes of the samples of the bucket.
+    pub fn payload_size(&self) -> usize {
+        self.payload_size
+    }
+
+    /// Returns how many samples the bucket provides.
+    pub fn number_of_samples(&self) -> usize {
+        self.number_of_samples
+    }
+}
+
+/// Splits the memory of the [`Publisher`] into pools of different size classes, see
+/// [`PortFactoryPublisher::allocator()`]. Every bucket is a data segment of its own whose
+/// samples all have the same size, a loan is served by the smallest bucket that fits the
+/// payload. A publisher that sends mostly small samples and occasionally a large one does not
+/// have to reserve every sample with the maximum slice length.
+///
+/// The pools do not share one data segment. The segment id of a sample identifies its bucket,
+/// therefore a [`crate::port::subscriber::Subscriber`] resolves the samples of all buckets
+/// like the samples of a publisher with multiple data segments, at the cost of one shared
+/// memory segment per bucket.
+///
+/// The buckets are ordered by their payload size. A configuration with no or more than
+/// [`MAX_NUMBER_OF_BUCKETS`] buckets, with an empty bucket or with two buckets of the same
+/// payload size is rejected by [`PortFactoryPublisher::create()`] with
+/// [`PublisherCreateError::InvalidBucketConfig`]. So is a bucket that does not provide more
+/// samples than the history size of the service, the history keeps its samples until newer
+/// ones replace them and could occupy the whole bucket.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+/// use iceoryx2::service::port_factory::publisher::BucketConfig;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let service_name = ServiceName::new("My/Funk/BucketService")?;
+/// let pubsub = zero_copy::Service::new(&service_name)
+///     .publish_subscribe()
+///     .max_slice_len(1 << 20)
+///     .open_or_create::<[u8]>()?;
+///
+/// // 1024 samples with up to 64 bytes, 256 with up to 4 KiB and 8 with up to 1 MiB
+/// let publisher = pubsub
+///     .publisher()
+///     .allocator(BucketConfig::new(&[(64, 1024), (4096, 256), (1 << 20, 8)]))
+///     .create()?;
+///
+/// let sample = publisher.loan_slice_uninit(100)?;
+/// assert_eq!(publisher.statistics().buckets()[1].used_samples(), 1);
+/// # drop(sample);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct BucketConfig {
+    buckets: [Bucket; MAX_NUMBER_OF_BUCKETS],
+    // the number of requested buckets, it exceeds the capacity when too many were provided
+    number_of_buckets: usize,
+}
+
+impl BucketConfig {
+    /// Creates a new [`BucketConfig`] from a list of `(payload size in bytes, number of
+    /// samples)` pairs.
+    pub fn new(buckets: &[(usize, usize)]) -> Self {
+        let mut new_self = Self {
+            buckets: [Bucket::default(); MAX_NUMBER_OF_BUCKETS],
+            number_of_buckets: buckets.len(),
+        };
+
+        for (bucket, (payload_size, number_of_samples)) in
+            new_self.buckets.iter_mut().zip(buckets.iter())
+        {
+            *bucket = Bucket {
+                payload_size: *payload_size,
+                number_of_samples: *number_of_samples,
+            };
+        }
+
+        let len = new_self.number_of_buckets.min(MAX_NUMBER_OF_BUCKETS);
+        new_self.buckets[..len].sort_unstable_by_key(|bucket| bucket.payload_size);
+        new_self
+    }
+
+    /// Returns the buckets ordered by their payload size.
+    pub fn buckets(&self) -> &[Bucket] {
+        &self.buckets[..self.number_of_buckets.min(MAX_NUMBER_OF_BUCKETS)]
+    }
+
+    pub(crate) fn is_valid(&self) -> bool {
+        let buckets = self.buckets();
+        (1..=MAX_NUMBER_OF_BUCKETS).contains(&self.number_of_buckets)
+            && buckets
+                .iter()
+                .all(|bucket| bucket.payload_size != 0 && bucket.number_of_samples != 0)
+            && buckets
+                .windows(2)
+                .all(|pair| pair[0].payload_size != pair[1].payload_size)
+    }
+}
+
+/// Defines if the [`Publisher`] wipes the payload of its samples so that a new loan never
+/// exposes data of a previous user of the chunk, for instance when the publisher hands out
+/// samples of different trust levels. Only the payload, with the actual size of a slice, is
+/// zeroed, the headers are rewritten with every loan anyway. Wiping costs a write of the whole
+/// payload per sample, therefore it is disabled by default.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum MemoryHygiene {
+    /// The payload of a loaned sample contains whatever the previous user of the chunk wrote.
+    #[default]
+    None,
+    /// The payload is zeroed when the sample is returned to the [`Publisher`], after the
+    /// [`Publisher`] and all [`crate::port::subscriber::Subscriber`]s released it. Stale data
+    /// does not remain in the shared memory while the chunk is unused.
+    ZeroOnRelease,
+    /// The payload is zeroed right before the sample is loaned, the loan takes longer but
+    /// returning a sample stays cheap.
+    ZeroOnAcquire,
+}
+
+impl Serialize for MemoryHygiene {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            MemoryHygiene::None => "none",
+            MemoryHygiene::ZeroOnRelease => "zero_on_release",
+            MemoryHygiene::ZeroOnAcquire => "zero_on_acquire",
+        })
+    }
+}
+
+struct MemoryHygieneVisitor;
+
+impl<'de> Visitor<'de> for MemoryHygieneVisitor {
+    type Value = MemoryHygiene;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter
+            .write_str("a string containing either 'none', 'zero_on_release' or 'zero_on_acquire'")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "none" => Ok(MemoryHygiene::None),
+            "zero_on_release" => Ok(MemoryHygiene::ZeroOnRelease),
+            "zero_on_acquire" => Ok(MemoryHygiene::ZeroOnAcquire),
+            v => Err(E::custom(format!(
+                "Invalid MemoryHygiene provided: \"{:?}\".",
+                v
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MemoryHygiene {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(MemoryHygieneVisitor)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct LocalPublisherConfig {
     pub(crate) max_loaned_samples: usize,
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
+    pub(crate) update_connections_on_send: bool,
+    pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) memory_hygiene: MemoryHygiene,
+    pub(crate) subscriber_max_buffer_size: usize,
+    pub(crate) bucket_config: Option<BucketConfig>,
+    pub(crate) enable_consumption_tracking: bool,
+    pub(crate) lock_memory: bool,
+    pub(crate) huge_page_threshold: Option<usize>,
+    pub(crate) strict_huge_pages: bool,
+    pub(crate) prefault: bool,
+}
+
+impl LocalPublisherConfig {
+    /// Returns the largest subscriber buffer the [`Publisher`] serves, 0 is adjusted to 1 and
+    /// values above the maximum of the service are clamped.
+    pub(crate) fn effective_subscriber_max_buffer_size(
+        &self,
+        static_config: &StaticConfig,
+    ) -> usize {
+        self.subscriber_max_buffer_size
+            .clamp(1, static_config.subscriber_max_buffer_size.max(1))
+    }
+
+    /// Returns the [`MemoryOptions`] of a data segment with a size of `segment_size` bytes.
+    pub(crate) fn memory_options(&self, segment_size: usize) -> MemoryOptions {
+        let huge_pages = match self.huge_page_threshold {
+            Some(threshold) if segment_size >= threshold => match self.strict_huge_pages {
+                true => HugePages::Required,
+                false => HugePages::IfAvailable,
+            },
+            _ => HugePages::Disabled,
+        };
+
+        MemoryOptions {
+            lock_memory: self.lock_memory,
+            huge_pages,
+            prefault: self.prefault,
+        }
+    }
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
-/// communication.
+/// communication. It can be cloned and stored, every call of [`PortFactoryPublisher::create()`]
+/// creates a new [`Publisher`] with the same settings.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let service_name = ServiceName::new("My/Funk/ReusedPublisherFactory")?;
+/// let pubsub = zero_copy::Service::new(&service_name)
+///     .publish_subscribe()
+///     .max_publishers(2)
+///     .open_or_create::<u64>()?;
+///
+/// let factory = pubsub.publisher().max_loaned_samples(4);
+/// println!("max loaned samples: {}", factory.effective_max_loaned_samples());
+///
+/// let publisher_a = factory.create()?;
+/// let publisher_b = factory.create()?;
+///
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug)]
 pub struct PortFactoryPublisher<
     'factory,
     'config,
     Service: service::Details<'config>,
-    MessageType: Debug,
+    MessageType: Debug + ?Sized,
+    UserHeader: Debug = (),
 > {
     config: LocalPublisherConfig,
-    pub(crate) factory: &'factory PortFactory<'config, Service, MessageType>,
+    pub(crate) factory: &'factory PortFactory<'config, Service, MessageType, UserHeader>,
 }
 
-impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
-    PortFactoryPublisher<'factory, 'config, Service, MessageType>
+impl<
+        'factory,
+        'config,
+        Service: service::Details<'config>,
+        MessageType: Debug + ?Sized,
+        UserHeader: Debug,
+    > Clone for PortFactoryPublisher<'factory, 'config, Service, MessageType, UserHeader>
 {
-    pub(crate) fn new(factory: &'factory PortFactory<'config, Service, MessageType>) -> Self {
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config,
+            factory: self.factory,
+        }
+    }
+}
+
+impl<
+        'factory,
+        'config,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug + Default,
+    > PortFactoryPublisher<'factory, 'config, Service, MessageType, UserHeader>
+{
+    pub(crate) fn new(
+        factory: &'factory PortFactory<'config, Service, MessageType, UserHeader>,
+    ) -> Self {
         Self {
             config: LocalPublisherConfig {
                 max_loaned_samples: factory
@@ -127,12 +431,32 @@ impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
                     .publish_subscribe
                     .publisher_max_loaned_samples,
                 unable_to_deliver_strategy: factory
+                    .service
+                    .state()
+                    .static_config
+                    .publish_subscribe()
+                    .unable_to_deliver_strategy,
+                update_connections_on_send: true,
+                allocation_strategy: AllocationStrategy::default(),
+                memory_hygiene: factory
                     .service
                     .state()
                     .global_config
                     .defaults
                     .publish_subscribe
-                    .unable_to_deliver_strategy,
+                    .publisher_memory_hygiene,
+                subscriber_max_buffer_size: factory
+                    .service
+                    .state()
+                    .static_config
+                    .publish_subscribe()
+                    .subscriber_max_buffer_size,
+                bucket_config: None,
+                enable_consumption_tracking: false,
+                lock_memory: false,
+                huge_page_threshold: None,
+                strict_huge_pages: false,
+                prefault: false,
             },
             factory,
         }
@@ -140,25 +464,309 @@ impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
 
     /// Defines how many [`crate::sample_mut::SampleMut`] the [`Publisher`] can loan with
     /// [`crate::port::publish::DefaultLoan::loan()`] or
-    /// [`crate::port::publish::UninitLoan::loan_uninit()`] in parallel.
+    /// [`crate::port::publish::UninitLoan::loan_uninit()`] in parallel. The data segment of the
+    /// [`Publisher`] is sized accordingly. Exceeding the limit causes
+    /// [`crate::port::publish::PublisherLoanError::ExceedsMaxLoanedSamples`], a loaned sample
+    /// frees its slot as soon as it is sent or dropped.
     pub fn max_loaned_samples(mut self, value: usize) -> Self {
         self.config.max_loaned_samples = value;
         self
     }
 
-    /// Sets the [`UnableToDeliverStrategy`].
+    /// Overrides the [`UnableToDeliverStrategy`] of the service for this [`Publisher`]. The
+    /// strategy must be equal to or weaker than the one of the service, see
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::unable_to_deliver_strategy()`],
+    /// otherwise [`PortFactoryPublisher::create()`] fails with
+    /// [`PublisherCreateError::IncompatibleUnableToDeliverStrategy`].
     pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
         self.config.unable_to_deliver_strategy = value;
         self
     }
 
+    /// Defines if the [`Publisher`] updates its connections implicitly whenever a sample is
+    /// sent, this is the default. When it is disabled the latency of a send does not depend on
+    /// joining or leaving [`crate::port::subscriber::Subscriber`]s but the connections must be
+    /// updated explicitly with [`Publisher::update_connections()`].
+    pub fn update_connections_on_send(mut self, value: bool) -> Self {
+        self.config.update_connections_on_send = value;
+        self
+    }
+
+    /// Sets the [`AllocationStrategy`] that is pursued when a loan does not fit into the data
+    /// segment of the [`Publisher`]. With any strategy other than [`AllocationStrategy::Static`]
+    /// the maximum slice length of the service defines only the initial size of the samples.
+    pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
+        self.config.allocation_strategy = value;
+        self
+    }
+
+    /// Sets the [`MemoryHygiene`] of the [`Publisher`], it overrides
+    /// [`crate::config::PublishSubscribe::publisher_memory_hygiene`] of the config.
+    pub fn memory_hygiene(mut self, value: MemoryHygiene) -> Self {
+        self.config.memory_hygiene = value;
+        self
+    }
+
+    /// Replaces the single data segment of the [`Publisher`] with the pools of the
+    /// [`BucketConfig`], a loan is served by the smallest bucket whose payload size fits.
+    /// A loan whose payload is larger than every bucket fails with
+    /// [`crate::port::publish::PublisherLoanError::ExceedsMaxLoanSize`], an exhausted bucket
+    /// with [`crate::port::publish::PublisherLoanError::BucketExhausted`] even when a larger
+    /// bucket has free samples. The buckets cannot grow, the [`Publisher`] must use
+    /// [`AllocationStrategy::Static`] otherwise [`PortFactoryPublisher::create()`] fails with
+    /// [`PublisherCreateError::IncompatibleAllocationStrategy`]. Slices are still limited by
+    /// the maximum slice length of the service. The occupancy of the buckets is part of
+    /// [`Publisher::statistics()`].
+    pub fn allocator(mut self, value: BucketConfig) -> Self {
+        self.config.bucket_config = Some(value);
+        self
+    }
+
+    /// Enables the tracking of the samples the [`Publisher`] sent until they are consumed, a
+    /// sample is consumed when every [`crate::port::subscriber::Subscriber`] that received it
+    /// has released it and it left the history of the [`Publisher`]. The progress is reported
+    /// by [`Publisher::consumed_up_to()`] and [`Publisher::wait_consumed()`] waits for the
+    /// [`crate::port::publish::SampleToken`] of a sample that was sent with
+    /// [`crate::sample_mut::SampleMut::send_with_token()`]. It is disabled by default since it
+    /// adds bookkeeping to every send and every release of a sample.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/TrackedPublisherService")?;
+    /// let pubsub = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .enable_notifications(true)
+    ///     .history_size(0)
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let publisher = pubsub.publisher().enable_consumption_tracking(true).create()?;
+    /// let subscriber = pubsub.subscriber().create()?;
+    ///
+    /// let token = publisher.loan_uninit()?.write_payload(1234).send_with_token()?;
+    /// drop(subscriber.receive()?);
+    ///
+    /// // the external resource that belongs to the sample can be recycled
+    /// assert!(publisher.wait_consumed(token, Duration::from_millis(100))?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn enable_consumption_tracking(mut self, value: bool) -> Self {
+        self.config.enable_consumption_tracking = value;
+        self
+    }
+
+    /// Locks the data segments of the [`Publisher`] and its connections to the
+    /// [`crate::port::subscriber::Subscriber`]s into RAM so that they are never swapped out. The
+    /// memory a process can lock is limited by `RLIMIT_MEMLOCK`, when the data segment exceeds
+    /// it [`PortFactoryPublisher::create()`] fails with
+    /// [`PublisherCreateError::UnableToLockMemory`]. A connection that cannot be locked is
+    /// reported like any other connection failure, see [`Publisher::update_connections()`].
+    pub fn lock_memory(mut self, value: bool) -> Self {
+        self.config.lock_memory = value;
+        self
+    }
+
+    /// Backs every data segment of the [`Publisher`] that is at least `value` bytes large with
+    /// huge pages, which reduces the TLB misses when large samples are accessed. On Linux it
+    /// requires transparent huge pages for shared memory and a data segment that is at least one
+    /// huge page large, the segment is faulted in on creation to verify that it is backed by huge
+    /// pages. When huge pages are unavailable a warning is emitted and the default page size is
+    /// used, unless [`PortFactoryPublisher::strict_huge_pages()`] is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/HugePublisherService")?;
+    /// let pubsub = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .open_or_create::<[u8; 1024 * 1024]>()?;
+    ///
+    /// let publisher = pubsub
+    ///     .publisher()
+    ///     .huge_page_threshold(2 * 1024 * 1024)
+    ///     .prefault(true)
+    ///     .create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn huge_page_threshold(mut self, value: usize) -> Self {
+        self.config.huge_page_threshold = Some(value);
+        self
+    }
+
+    /// When it is set [`PortFactoryPublisher::create()`] fails with
+    /// [`PublisherCreateError::HugePagesUnavailable`] instead of falling back to the default page
+    /// size when a data segment above the [`PortFactoryPublisher::huge_page_threshold()`] cannot
+    /// be backed by huge pages.
+    pub fn strict_huge_pages(mut self, value: bool) -> Self {
+        self.config.strict_huge_pages = value;
+        self
+    }
+
+    /// Touches every page of the data segments when the [`Publisher`] is created so that the
+    /// first loans do not cause page faults.
+    pub fn prefault(mut self, value: bool) -> Self {
+        self.config.prefault = value;
+        self
+    }
+
+    /// Limits the buffer of the connections to the [`crate::port::subscriber::Subscriber`]s, for
+    /// instance when the [`Publisher`] is started with a config that reserves fewer samples
+    /// than the service was created with. By default it is the
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::subscriber_max_buffer_size()`]
+    /// of the service, larger values are clamped to it and 0 is adjusted to 1.
+    ///
+    /// Every connection uses the smaller buffer size of both ports, it can be acquired with
+    /// [`Publisher::effective_buffer_size()`] and
+    /// [`crate::port::subscriber::Subscriber::effective_buffer_size()`]. A
+    /// [`crate::port::subscriber::Subscriber`] that requires a larger buffer with
+    /// [`crate::service::port_factory::subscriber::PortFactorySubscriber::required_min_buffer_size()`]
+    /// refuses the connection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ShallowPublisherService")?;
+    /// let pubsub = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .subscriber_max_buffer_size(16)
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let publisher = pubsub.publisher().subscriber_max_buffer_size(4).create()?;
+    /// let subscriber = pubsub.subscriber().buffer_size(8).create()?;
+    ///
+    /// assert_eq!(subscriber.effective_buffer_size(publisher.id()), Some(4));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscriber_max_buffer_size(mut self, value: usize) -> Self {
+        self.config.subscriber_max_buffer_size = value;
+        self
+    }
+
+    /// Returns the largest buffer of a [`crate::port::subscriber::Subscriber`] connection the
+    /// [`Publisher`] will serve. It is the value of
+    /// [`PortFactoryPublisher::subscriber_max_buffer_size()`] adjusted to the limits of the
+    /// service.
+    pub fn effective_subscriber_max_buffer_size(&self) -> usize {
+        self.config.effective_subscriber_max_buffer_size(
+            self.factory
+                .service
+                .state()
+                .static_config
+                .publish_subscribe(),
+        )
+    }
+
+    /// Returns how many [`crate::sample_mut::SampleMut`] the [`Publisher`] will be able to
+    /// loan in parallel, see [`PortFactoryPublisher::max_loaned_samples()`].
+    pub fn effective_max_loaned_samples(&self) -> usize {
+        self.config.max_loaned_samples
+    }
+
+    /// Returns the [`UnableToDeliverStrategy`] the [`Publisher`] will be created with, it is
+    /// the one of the service unless it was overridden with
+    /// [`PortFactoryPublisher::unable_to_deliver_strategy()`].
+    pub fn effective_unable_to_deliver_strategy(&self) -> UnableToDeliverStrategy {
+        self.config.unable_to_deliver_strategy
+    }
+
+    /// Returns true when the [`Publisher`] will update its connections whenever a sample is
+    /// sent, see [`PortFactoryPublisher::update_connections_on_send()`].
+    pub fn effective_update_connections_on_send(&self) -> bool {
+        self.config.update_connections_on_send
+    }
+
+    /// Returns the [`AllocationStrategy`] the [`Publisher`] will be created with, see
+    /// [`PortFactoryPublisher::allocation_strategy()`].
+    pub fn effective_allocation_strategy(&self) -> AllocationStrategy {
+        self.config.allocation_strategy
+    }
+
+    /// Returns the [`MemoryHygiene`] the [`Publisher`] will be created with, see
+    /// [`PortFactoryPublisher::memory_hygiene()`].
+    pub fn effective_memory_hygiene(&self) -> MemoryHygiene {
+        self.config.memory_hygiene
+    }
+
+    /// Returns true when the [`Publisher`] will be created with consumption tracking, see
+    /// [`PortFactoryPublisher::enable_consumption_tracking()`].
+    pub fn effective_consumption_tracking(&self) -> bool {
+        self.config.enable_consumption_tracking
+    }
+
+    /// Returns true when the memory of the [`Publisher`] will be locked, see
+    /// [`PortFactoryPublisher::lock_memory()`].
+    pub fn effective_lock_memory(&self) -> bool {
+        self.config.lock_memory
+    }
+
+    /// Returns the size from which on a data segment is backed by huge pages or [`None`] when
+    /// huge pages are not used, see [`PortFactoryPublisher::huge_page_threshold()`].
+    pub fn effective_huge_page_threshold(&self) -> Option<usize> {
+        self.config.huge_page_threshold
+    }
+
+    /// Returns true when the data segments will be prefaulted, see
+    /// [`PortFactoryPublisher::prefault()`].
+    pub fn effective_prefault(&self) -> bool {
+        self.config.prefault
+    }
+
+    /// Returns the [`BucketConfig`] the [`Publisher`] will be created with or [`None`] when it
+    /// uses a single data segment, see [`PortFactoryPublisher::allocator()`].
+    pub fn effective_allocator(&self) -> Option<BucketConfig> {
+        self.config.bucket_config
+    }
+
     /// Creates a new [`Publisher`] or returns a [`PublisherCreateError`] on failure.
     pub fn create(
-        self,
-    ) -> Result<Publisher<'factory, 'config, Service, MessageType>, PublisherCreateError> {
+        &self,
+    ) -> Result<Publisher<'factory, 'config, Service, MessageType, UserHeader>, PublisherCreateError>
+    {
         Ok(
-            fail!(from self, when Publisher::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe(), &self.config),
+            fail!(from self, when Publisher::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe(), &self.config, 1),
                 "Failed to create new Publisher port."),
         )
     }
+
+    /// Creates a new [`Publisher`] that sends the payload as bytes without knowing its type,
+    /// see [`crate::service::builder::publish_subscribe::Builder::open_untyped()`] and
+    /// [`Publisher::loan_untyped()`]. Fails with
+    /// [`PublisherCreateError::InvalidPayloadTypeDetails`] when the size and alignment of the
+    /// payload type are not valid.
+    pub fn create_untyped(
+        &self,
+    ) -> Result<Publisher<'factory, 'config, Service, [u8], UserHeader>, PublisherCreateError> {
+        let static_config = self
+            .factory
+            .service
+            .state()
+            .static_config
+            .publish_subscribe();
+        let element_size = match static_config.untyped_element_size() {
+            Some(element_size) => element_size,
+            None => {
+                fail!(from self, with PublisherCreateError::InvalidPayloadTypeDetails,
+                    "Failed to create new untyped Publisher port since the service has the invalid payload type {}.",
+                    static_config.payload_type_details());
+            }
+        };
+
+        Ok(
+            fail!(from self, when Publisher::new(&self.factory.service, static_config, &self.config, element_size),
+                "Failed to create new untyped Publisher port."),
+        )
+    }
 }
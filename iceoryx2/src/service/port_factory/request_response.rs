@@ -0,0 +1,187 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/Calculator")?;
+//! let rpc = zero_copy::Service::new(&service_name)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! println!("name:                             {:?}", rpc.name());
+//! println!("uuid:                             {:?}", rpc.uuid());
+//! println!("request type:                     {:?}", rpc.static_config().request_type_details());
+//! println!("response type:                    {:?}", rpc.static_config().response_type_details());
+//! println!("max clients:                      {:?}", rpc.static_config().max_supported_clients());
+//! println!("max servers:                      {:?}", rpc.static_config().max_supported_servers());
+//! println!("number of active clients:         {:?}", rpc.dynamic_config().number_of_clients());
+//! println!("number of active servers:         {:?}", rpc.dynamic_config().number_of_servers());
+//!
+//! let client = rpc.client().create()?;
+//! let server = rpc.server().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::attribute::AttributeSet;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config};
+
+use super::{client::PortFactoryClient, server::PortFactoryServer};
+
+/// The factory for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::client::Client`]
+/// or [`crate::port::server::Server`] ports.
+#[derive(Debug)]
+pub struct PortFactory<
+    'config,
+    Service: service::Details<'config>,
+    RequestPayload: Debug,
+    ResponsePayload: Debug,
+> {
+    pub(crate) service: Service,
+    _phantom_request_payload: PhantomData<RequestPayload>,
+    _phantom_response_payload: PhantomData<ResponsePayload>,
+    _phantom_lifetime_b: PhantomData<&'config ()>,
+}
+
+unsafe impl<
+        'config,
+        Service: service::Details<'config>,
+        RequestPayload: Debug,
+        ResponsePayload: Debug,
+    > Send for PortFactory<'config, Service, RequestPayload, ResponsePayload>
+{
+}
+unsafe impl<
+        'config,
+        Service: service::Details<'config>,
+        RequestPayload: Debug,
+        ResponsePayload: Debug,
+    > Sync for PortFactory<'config, Service, RequestPayload, ResponsePayload>
+{
+}
+
+impl<
+        'config,
+        Service: service::Details<'config>,
+        RequestPayload: Debug,
+        ResponsePayload: Debug,
+    > PortFactory<'config, Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _phantom_request_payload: PhantomData,
+            _phantom_response_payload: PhantomData,
+            _phantom_lifetime_b: PhantomData,
+        }
+    }
+
+    /// Returns the [`ServiceName`] of the service
+    pub fn name(&self) -> &ServiceName {
+        self.service.state().static_config.service_name()
+    }
+
+    /// Returns the uuid of the [`crate::service::Service`]
+    pub fn uuid(&self) -> &str {
+        self.service.state().static_config.uuid()
+    }
+
+    /// Returns the uuid of the concrete instance of the [`crate::service::Service`], it changes
+    /// when the service is removed and created again.
+    pub fn instance_uuid(&self) -> &str {
+        self.service.state().static_config.instance_uuid()
+    }
+
+    /// Returns the [`AttributeSet`] the [`crate::service::Service`] was created with
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`static_config::request_response::StaticConfig`] of the
+    /// [`crate::service::Service`]. Contains all settings that never change during the lifetime
+    /// of the service.
+    pub fn static_config(&self) -> &static_config::request_response::StaticConfig {
+        self.service.state().static_config.request_response()
+    }
+
+    /// Returns the [`dynamic_config::request_response::DynamicConfig`] of the
+    /// [`crate::service::Service`]. Contains all dynamic settings, like the current participants
+    /// etc..
+    pub fn dynamic_config(&self) -> &dynamic_config::request_response::DynamicConfig {
+        self.service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+    }
+
+    /// Returns a [`PortFactoryClient`] to create a new [`crate::port::client::Client`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/Calculator")?;
+    /// let rpc = zero_copy::Service::new(&service_name)
+    ///     .request_response::<u64, u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let client = rpc.client().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn client<'a>(
+        &'a self,
+    ) -> PortFactoryClient<'a, 'config, Service, RequestPayload, ResponsePayload> {
+        PortFactoryClient::new(self)
+    }
+
+    /// Returns a [`PortFactoryServer`] to create a new [`crate::port::server::Server`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/Calculator")?;
+    /// let rpc = zero_copy::Service::new(&service_name)
+    ///     .request_response::<u64, u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let server = rpc.server().max_loaned_responses(4).create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn server<'a>(
+        &'a self,
+    ) -> PortFactoryServer<'a, 'config, Service, RequestPayload, ResponsePayload> {
+        PortFactoryServer::new(self)
+    }
+}
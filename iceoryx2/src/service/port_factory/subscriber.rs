@@ -33,33 +33,377 @@ use std::fmt::Debug;
 
 use iceoryx2_bb_log::fail;
 
-use crate::{port::subscribe::SubscriberCreateError, port::subscriber::Subscriber, service};
+use crate::{
+    payload_type::PayloadType,
+    port::subscribe::SubscriberCreateError,
+    port::subscriber::{SampleFilter, Subscriber},
+    service,
+    service::header::publish_subscribe::Header,
+    service::static_config::publish_subscribe::StaticConfig,
+};
 
 use super::publish_subscribe::PortFactory;
 
+/// Defines in which order [`crate::port::subscribe::Subscribe::receive()`] returns the
+/// [`crate::sample::Sample`]s of multiple [`crate::port::publisher::Publisher`]s, see
+/// [`PortFactorySubscriber::receive_order()`].
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveOrder {
+    /// Every receive call starts with the [`crate::port::publisher::Publisher`] after the one
+    /// of the last received [`crate::sample::Sample`], a publisher that sends at a high rate
+    /// cannot starve the others.
+    #[default]
+    RoundRobin,
+    /// Returns the oldest available [`crate::sample::Sample`] of all
+    /// [`crate::port::publisher::Publisher`]s based on the time stamp of its [`Header`]. Every
+    /// receive call inspects the next [`crate::sample::Sample`] of every connection.
+    ByTimestamp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LocalSubscriberConfig {
+    pub(crate) buffer_size: usize,
+    pub(crate) required_min_buffer_size: usize,
+    pub(crate) receive_order: ReceiveOrder,
+    pub(crate) auto_reconnect: bool,
+}
+
+impl LocalSubscriberConfig {
+    /// Returns the buffer size the [`Subscriber`] is created with, 0 is adjusted to 1 and values
+    /// above the maximum of the service are clamped.
+    pub(crate) fn effective_buffer_size(&self, static_config: &StaticConfig) -> usize {
+        self.buffer_size
+            .clamp(1, static_config.subscriber_max_buffer_size.max(1))
+    }
+}
+
 /// Factory to create a new [`Subscriber`] port/endpoint for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
-/// communication.
+/// communication. It can be cloned and stored, every call of [`PortFactorySubscriber::create()`]
+/// creates a new [`Subscriber`] with the same settings and its own buffer.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let service_name = ServiceName::new("My/Funk/ReusedFactoryService")?;
+/// let pubsub = zero_copy::Service::new(&service_name)
+///     .publish_subscribe()
+///     .open_or_create::<u64>()?;
+///
+/// let factory = pubsub.subscriber().buffer_size(2);
+/// println!("buffer size: {}", factory.effective_buffer_size());
+///
+/// let subscriber_a = factory.create()?;
+/// let subscriber_b = factory.clone().create()?;
+///
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug)]
 pub struct PortFactorySubscriber<
     'factory,
     'config,
     Service: service::Details<'config>,
-    MessageType: Debug,
+    MessageType: Debug + ?Sized,
+    UserHeader: Debug = (),
 > {
-    pub(crate) factory: &'factory PortFactory<'config, Service, MessageType>,
+    config: LocalSubscriberConfig,
+    filter: Option<SampleFilter<'factory, UserHeader>>,
+    pub(crate) factory: &'factory PortFactory<'config, Service, MessageType, UserHeader>,
+}
+
+impl<
+        'factory,
+        'config,
+        Service: service::Details<'config>,
+        MessageType: Debug + ?Sized,
+        UserHeader: Debug,
+    > Clone for PortFactorySubscriber<'factory, 'config, Service, MessageType, UserHeader>
+{
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config,
+            filter: self.filter.clone(),
+            factory: self.factory,
+        }
+    }
 }
 
-impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
-    PortFactorySubscriber<'factory, 'config, Service, MessageType>
+impl<
+        'factory,
+        'config,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        UserHeader: Debug,
+    > PortFactorySubscriber<'factory, 'config, Service, MessageType, UserHeader>
 {
+    pub(crate) fn new(
+        factory: &'factory PortFactory<'config, Service, MessageType, UserHeader>,
+    ) -> Self {
+        Self {
+            config: LocalSubscriberConfig {
+                buffer_size: factory
+                    .service
+                    .state()
+                    .static_config
+                    .publish_subscribe()
+                    .subscriber_max_buffer_size,
+                required_min_buffer_size: 1,
+                receive_order: ReceiveOrder::default(),
+                auto_reconnect: false,
+            },
+            filter: None,
+            factory,
+        }
+    }
+
+    /// Defines how many [`crate::sample::Sample`]s of every
+    /// [`crate::port::publisher::Publisher`] the [`Subscriber`] can hold in its buffer. By
+    /// default it is the
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::subscriber_max_buffer_size()`]
+    /// of the service. Larger values are clamped to this maximum and 0 is adjusted to 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/DeepBufferService")?;
+    /// let pubsub = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .subscriber_max_buffer_size(16)
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// // only interested in the latest sample
+    /// let control_loop = pubsub.subscriber().buffer_size(1).create()?;
+    /// // uses the maximum buffer size of the service
+    /// let logger = pubsub.subscriber().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn buffer_size(mut self, value: usize) -> Self {
+        self.config.buffer_size = value;
+        self
+    }
+
+    /// Returns the buffer size the [`Subscriber`] will be created with. It is the value of
+    /// [`PortFactorySubscriber::buffer_size()`] adjusted to the limits of the service.
+    pub fn effective_buffer_size(&self) -> usize {
+        self.config.effective_buffer_size(
+            self.factory
+                .service
+                .state()
+                .static_config
+                .publish_subscribe(),
+        )
+    }
+
+    /// Defines the smallest buffer size the [`Subscriber`] accepts for the connection to a
+    /// [`crate::port::publisher::Publisher`]. Every connection uses the smaller buffer size of
+    /// both ports, a [`crate::port::publisher::Publisher`] can limit it with
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::subscriber_max_buffer_size()`].
+    /// By default it is 1, every connection is accepted.
+    ///
+    /// A connection with a smaller buffer is refused by both ports. The [`Subscriber`] reports
+    /// it with [`Subscriber::connection_errors()`], the
+    /// [`crate::port::publisher::Publisher`] as
+    /// [`crate::port::publish::DeliveryFailure`] of every sent sample, both with
+    /// [`iceoryx2_cal::zero_copy_connection::ZeroCopyCreationError::IncompatibleBufferSize`].
+    /// When the value exceeds the [`PortFactorySubscriber::effective_buffer_size()`]
+    /// [`PortFactorySubscriber::create()`] fails with
+    /// [`SubscriberCreateError::RequiredMinBufferSizeExceedsBufferSize`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/RequiredBufferService")?;
+    /// let pubsub = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .subscriber_max_buffer_size(16)
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let publisher = pubsub.publisher().subscriber_max_buffer_size(2).create()?;
+    /// let subscriber = pubsub
+    ///     .subscriber()
+    ///     .buffer_size(8)
+    ///     .required_min_buffer_size(4)
+    ///     .create()?;
+    ///
+    /// // the publisher provides only 2 slots, the connection is refused
+    /// assert_eq!(subscriber.effective_buffer_size(publisher.id()), None);
+    /// for (publisher_id, failure) in subscriber.connection_errors() {
+    ///     println!("refused connection to {:?}: {}", publisher_id, failure);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn required_min_buffer_size(mut self, value: usize) -> Self {
+        self.config.required_min_buffer_size = value;
+        self
+    }
+
+    /// Returns the smallest buffer size the [`Subscriber`] will accept for a connection, see
+    /// [`PortFactorySubscriber::required_min_buffer_size()`].
+    pub fn effective_required_min_buffer_size(&self) -> usize {
+        self.config.required_min_buffer_size
+    }
+
+    /// Defines in which order the [`Subscriber`] receives the [`crate::sample::Sample`]s of
+    /// multiple [`crate::port::publisher::Publisher`]s. By default it is
+    /// [`ReceiveOrder::RoundRobin`]. The order of the [`crate::sample::Sample`]s of one
+    /// [`crate::port::publisher::Publisher`] is always preserved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::port_factory::subscriber::ReceiveOrder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/MergedStreamService")?;
+    /// let pubsub = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let subscriber = pubsub
+    ///     .subscriber()
+    ///     .receive_order(ReceiveOrder::ByTimestamp)
+    ///     .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive_order(mut self, value: ReceiveOrder) -> Self {
+        self.config.receive_order = value;
+        self
+    }
+
+    /// Returns the [`ReceiveOrder`] the [`Subscriber`] will be created with.
+    pub fn effective_receive_order(&self) -> ReceiveOrder {
+        self.config.receive_order
+    }
+
+    /// Defines whether the [`Subscriber`] moves to a new instance of the service when the
+    /// service is removed and created again under the same name, for instance since the
+    /// process of the [`crate::port::publisher::Publisher`] was restarted. By default it is
+    /// disabled and the [`Subscriber`] stays with the removed instance, see
+    /// [`Subscriber::service_state()`].
+    ///
+    /// The new instance must have the same static config as the previous one. The
+    /// [`Subscriber`] keeps its [`crate::port::port_identifiers::UniqueSubscriberId`] and looks
+    /// for a new instance at most every [`Subscriber::RECONNECT_CHECK_INTERVAL`] whenever it
+    /// updates its connections, see [`Subscriber::number_of_reconnects()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/RestartingService")?;
+    /// let pubsub = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let subscriber = pubsub.subscriber().auto_reconnect(true).create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn auto_reconnect(mut self, value: bool) -> Self {
+        self.config.auto_reconnect = value;
+        self
+    }
+
+    /// Defines a filter that decides with the [`Header`] and the user header of every
+    /// [`crate::sample::Sample`] whether the [`Subscriber`] receives it. Rejected samples are
+    /// skipped by [`crate::port::subscribe::Subscribe::receive()`] and released right away, so
+    /// that they neither occupy the buffer nor count against the borrowed samples. The filter
+    /// has no access to the payload.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// #[derive(Debug, Default, ZeroCopySend)]
+    /// #[repr(C)]
+    /// struct Shard(u8);
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ShardedService")?;
+    /// let pubsub = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .user_header::<Shard>()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let subscriber = pubsub
+    ///     .subscriber()
+    ///     .filter(|_header, shard| shard.0 == 3)
+    ///     .create()?;
+    ///
+    /// while let Some(sample) = subscriber.receive()? {
+    ///     println!("received from shard 3: {:?}", *sample);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn filter<F: Fn(&Header, &UserHeader) -> bool + 'factory>(mut self, filter: F) -> Self {
+        self.filter = Some(SampleFilter::new(filter));
+        self
+    }
+
     /// Creates a new [`Subscriber`] or returns a [`SubscriberCreateError`] on failure.
     pub fn create(
         &self,
-    ) -> Result<Subscriber<'factory, 'config, Service, MessageType>, SubscriberCreateError> {
+    ) -> Result<
+        Subscriber<'factory, 'config, Service, MessageType, UserHeader>,
+        SubscriberCreateError,
+    > {
         Ok(
-            fail!(from self, when Subscriber::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe()),
+            fail!(from self, when Subscriber::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe(), &self.config, self.filter.clone(), 1),
                 "Failed to create new Subscriber port."),
         )
     }
+
+    /// Creates a new [`Subscriber`] that receives the payload as bytes without knowing its
+    /// type, see
+    /// [`crate::service::builder::publish_subscribe::Builder::open_untyped()`]. Every
+    /// [`crate::sample::Sample`] contains a multiple of
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::type_size()`] bytes.
+    /// Fails with [`SubscriberCreateError::InvalidPayloadTypeDetails`] when the size and
+    /// alignment of the payload type are not valid.
+    pub fn create_untyped(
+        &self,
+    ) -> Result<Subscriber<'factory, 'config, Service, [u8], UserHeader>, SubscriberCreateError>
+    {
+        let static_config = self
+            .factory
+            .service
+            .state()
+            .static_config
+            .publish_subscribe();
+        let element_size = match static_config.untyped_element_size() {
+            Some(element_size) => element_size,
+            None => {
+                fail!(from self, with SubscriberCreateError::InvalidPayloadTypeDetails,
+                    "Failed to create new untyped Subscriber port since the service has the invalid payload type {}.",
+                    static_config.payload_type_details());
+            }
+        };
+
+        Ok(
+            fail!(from self, when Subscriber::new(&self.factory.service, static_config, &self.config, self.filter.clone(), element_size),
+                "Failed to create new untyped Subscriber port."),
+        )
+    }
 }
@@ -0,0 +1,171 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::service_name_pattern::ServiceNamePattern;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let pattern = ServiceNamePattern::new("vehicle/sensors/*")?;
+//!
+//! assert!(pattern.matches(&ServiceName::new("vehicle/sensors/lidar")?));
+//! assert!(!pattern.matches(&ServiceName::new("vehicle/sensors/lidar/front")?));
+//!
+//! for service in zero_copy::Service::list_matching(&pattern)? {
+//!     println!("{}", service.service_name());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+//! # Matching Semantics
+//!
+//! A [`ServiceNamePattern`] and a [`ServiceName`] are split at every `/` into segments, see
+//! [`ServiceName::components()`], and are compared segment by segment. A pattern never matches
+//! a part of a segment, `vehicle/sens` does not match `vehicle/sensors`.
+//!
+//!  * `*` matches exactly one arbitrary segment
+//!  * `**` matches zero or more arbitrary segments
+//!  * every other segment matches only an identical segment
+//!
+//! A wildcard must occupy the whole segment, `lid*` or `***` are rejected with
+//! [`ServiceNamePatternError::InvalidWildcard`]. Empty segments, caused by a leading, trailing
+//! or double `/`, are ordinary segments and must be present in the name as well.
+//!
+//! | pattern              | matches                                       | does not match       |
+//! |----------------------|-----------------------------------------------|----------------------|
+//! | `vehicle/sensors/*`  | `vehicle/sensors/lidar`                       | `vehicle/sensors`    |
+//! | `vehicle/**`         | `vehicle`, `vehicle/sensors/lidar/front`      | `vehicles/sensors`   |
+//! | `**/front`           | `front`, `vehicle/sensors/lidar/front`        | `vehicle/front/left` |
+//! | `*/sensors/**`       | `vehicle/sensors`, `vehicle/sensors/lidar`    | `sensors/lidar`      |
+
+use iceoryx2_bb_container::byte_string::FixedSizeByteString;
+
+use crate::service::service_name::ServiceName;
+
+const SEGMENT_SEPARATOR: char = '/';
+const SINGLE_SEGMENT_WILDCARD: &str = "*";
+const MULTI_SEGMENT_WILDCARD: &str = "**";
+
+/// Failures that can occur when a [`ServiceNamePattern`] is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ServiceNamePatternError {
+    IsEmpty,
+    ExceedsMaximumLength,
+    InvalidCharacter,
+    InvalidWildcard,
+}
+
+impl std::fmt::Display for ServiceNamePatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServiceNamePatternError {}
+
+/// A pattern that matches the [`ServiceName`]s of a whole hierarchy of services, see the
+/// [module documentation](self) for the matching semantics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ServiceNamePattern {
+    value: FixedSizeByteString<{ ServiceName::max_len() }>,
+}
+
+impl ServiceNamePattern {
+    /// Creates a new [`ServiceNamePattern`]. Besides the rules of a [`ServiceName`] every
+    /// segment that contains a `*` must be either `*` or `**`.
+    pub fn new(pattern: &str) -> Result<Self, ServiceNamePatternError> {
+        if pattern.is_empty() {
+            return Err(ServiceNamePatternError::IsEmpty);
+        }
+
+        if pattern.len() > ServiceName::max_len() {
+            return Err(ServiceNamePatternError::ExceedsMaximumLength);
+        }
+
+        if pattern.bytes().any(|c| matches!(c, 0x00..=0x1f | 0x7f)) {
+            return Err(ServiceNamePatternError::InvalidCharacter);
+        }
+
+        if pattern.split(SEGMENT_SEPARATOR).any(|segment| {
+            segment.contains('*')
+                && segment != SINGLE_SEGMENT_WILDCARD
+                && segment != MULTI_SEGMENT_WILDCARD
+        }) {
+            return Err(ServiceNamePatternError::InvalidWildcard);
+        }
+
+        Ok(Self {
+            value: FixedSizeByteString::from_bytes_const(pattern.as_bytes()),
+        })
+    }
+
+    /// Returns a str reference to the [`ServiceNamePattern`]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: `ServiceNamePattern` was created from a `&str` and therefore this conversion
+        // is safe
+        unsafe { std::str::from_utf8_unchecked(self.value.as_bytes()) }
+    }
+
+    /// Returns true when the [`ServiceName`] matches the pattern segment by segment.
+    pub fn matches(&self, name: &ServiceName) -> bool {
+        let pattern: Vec<&str> = self.as_str().split(SEGMENT_SEPARATOR).collect();
+        let name: Vec<&str> = name.components().collect();
+
+        let mut p = 0;
+        let mut n = 0;
+        // the position of the last `**` in the pattern and the first name segment it has not
+        // consumed yet, a mismatch afterwards lets the `**` consume one more segment
+        let mut backtrack: Option<(usize, usize)> = None;
+
+        while n < name.len() {
+            match pattern.get(p) {
+                Some(&MULTI_SEGMENT_WILDCARD) => {
+                    backtrack = Some((p, n));
+                    p += 1;
+                }
+                Some(&segment) if segment == SINGLE_SEGMENT_WILDCARD || segment == name[n] => {
+                    p += 1;
+                    n += 1;
+                }
+                _ => match backtrack {
+                    Some((wildcard, consumed)) => {
+                        p = wildcard + 1;
+                        n = consumed + 1;
+                        backtrack = Some((wildcard, n));
+                    }
+                    None => return false,
+                },
+            }
+        }
+
+        pattern[p..]
+            .iter()
+            .all(|segment| *segment == MULTI_SEGMENT_WILDCARD)
+    }
+}
+
+impl TryFrom<&str> for ServiceNamePattern {
+    type Error = ServiceNamePatternError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl std::fmt::Display for ServiceNamePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}", self.value)
+    }
+}
@@ -0,0 +1,605 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Removes the resources of [`Service`](crate::service::Service)s that were left behind by
+//! crashed processes and removes services explicitly.
+//!
+//! Every service instance registers its owner, a [`UniqueSystemId`] that contains the process
+//! id, in the [`DynamicConfig`] and the port ids contain the process id of their creator as
+//! well. A resource is considered stale when the process that created it is no longer alive.
+//! A reused process id can only cause a stale resource to survive a cleanup, it never causes the
+//! removal of a resource of a live process.
+
+use crate::config;
+use crate::port::connection_monitor::announce_connection_change;
+use crate::port::details::segment_offset::MAX_NUMBER_OF_SEGMENTS;
+use crate::service::config_scheme::{
+    connection_config, connection_monitor_event_config, data_segment_config,
+    dynamic_config_storage_config, event_config, static_config_storage_config,
+};
+use crate::service::discovery::announce_change;
+use crate::service::dynamic_config::{
+    event, publish_subscribe, request_response, DecrementReferenceCounterResult, DynamicConfig,
+    MarkForDestructionResult, MessagingPattern,
+};
+use crate::service::naming_scheme::{
+    client_data_segment_name, connection_monitor_id, connection_name, data_segment_name,
+    event_concept_name, publisher_event_concept_name, request_connection_name,
+    response_connection_name, server_data_segment_name, subscriber_event_concept_name,
+};
+use crate::service::service_name::ServiceName;
+use crate::service::{
+    Details, ServiceListError, ServiceRemoveError, ServiceRemoveStaleResourcesError,
+};
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::{fail, fatal_panic, trace, warn};
+use iceoryx2_bb_posix::clock::nanosleep;
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::dynamic_storage::{
+    DynamicStorage, DynamicStorageBuilder, DynamicStorageOpenError,
+};
+use iceoryx2_cal::named_concept::{
+    NamedConceptBuilder, NamedConceptDoesExistError, NamedConceptListError, NamedConceptMgmt,
+};
+
+fn is_alive(id: &UniqueSystemId) -> bool {
+    Process::from_pid(id.pid()).is_alive()
+}
+
+fn is_dead(id: &UniqueSystemId) -> bool {
+    !is_alive(id)
+}
+
+fn try_open_dynamic_storage<'config, Service: Details<'config>>(
+    uuid: &FileName,
+    config: &config::Config,
+) -> Result<Service::DynamicStorage, DynamicStorageOpenError> {
+    <<Service::DynamicStorage as DynamicStorage<DynamicConfig>>::Builder as NamedConceptBuilder<
+        Service::DynamicStorage,
+    >>::new(uuid)
+    .config(&dynamic_config_storage_config::<Service>(config))
+    .has_ownership(false)
+    .try_open()
+}
+
+fn remove_resource<T: NamedConceptMgmt>(name: &FileName, cfg: &T::Configuration, origin: &str) {
+    if let Err(e) = unsafe { T::remove_cfg(name, cfg) } {
+        warn!(from origin, "Unable to remove the resource \"{}\" ({:?}).", name, e);
+    }
+}
+
+fn remove_publish_subscribe_ports<'config, Service: Details<'config>>(
+    dynamic_config: &publish_subscribe::DynamicConfig,
+    config: &config::Config,
+    is_removable: fn(&UniqueSystemId) -> bool,
+) -> bool {
+    let origin = "remove_publish_subscribe_ports()";
+    let connection_cfg = connection_config::<Service>(config);
+    let event_cfg = event_config::<Service>(config);
+
+    let mut publishers = vec![];
+    unsafe { dynamic_config.publishers.get_state() }
+        .for_each(|index, details| publishers.push((index, details.publisher_id)));
+    let mut subscribers = vec![];
+    unsafe { dynamic_config.subscribers.get_state() }
+        .for_each(|index, details| subscribers.push((index, details.subscriber_id)));
+
+    for (index, publisher_id) in publishers.iter().filter(|(_, id)| is_removable(&id.0)) {
+        for (_, subscriber_id) in &subscribers {
+            remove_resource::<Service::Connection>(
+                &connection_name(*publisher_id, *subscriber_id),
+                &connection_cfg,
+                origin,
+            );
+        }
+        for segment_id in 0..MAX_NUMBER_OF_SEGMENTS {
+            remove_resource::<Service::SharedMemory>(
+                &data_segment_name(*publisher_id, segment_id),
+                &data_segment_config::<Service>(config),
+                origin,
+            );
+        }
+        remove_resource::<Service::Event>(
+            &publisher_event_concept_name(publisher_id),
+            &event_cfg,
+            origin,
+        );
+        dynamic_config.reset_publisher_counters(*index);
+        unsafe { dynamic_config.publishers.remove_raw_index(*index) };
+        trace!(from origin, "removed publisher {:?}", publisher_id);
+    }
+
+    for (index, subscriber_id) in subscribers.iter().filter(|(_, id)| is_removable(&id.0)) {
+        for (_, publisher_id) in &publishers {
+            remove_resource::<Service::Connection>(
+                &connection_name(*publisher_id, *subscriber_id),
+                &connection_cfg,
+                origin,
+            );
+        }
+        // only exists when the service has notifications enabled, removing a non-existing
+        // event concept is not an error
+        remove_resource::<Service::Event>(
+            &subscriber_event_concept_name(subscriber_id),
+            &event_cfg,
+            origin,
+        );
+        dynamic_config.reset_subscriber_counters(*index);
+        unsafe { dynamic_config.subscribers.remove_raw_index(*index) };
+        trace!(from origin, "removed subscriber {:?}", subscriber_id);
+    }
+
+    publishers.iter().any(|(_, id)| is_removable(&id.0))
+        || subscribers.iter().any(|(_, id)| is_removable(&id.0))
+}
+
+fn remove_event_ports<'config, Service: Details<'config>>(
+    dynamic_config: &event::DynamicConfig,
+    config: &config::Config,
+    is_removable: fn(&UniqueSystemId) -> bool,
+) {
+    let origin = "remove_event_ports()";
+    let event_cfg = event_config::<Service>(config);
+
+    let mut listeners = vec![];
+    unsafe { dynamic_config.listeners.get_state() }
+        .for_each(|index, id| listeners.push((index, *id)));
+    for (index, listener_id) in listeners.iter().filter(|(_, id)| is_removable(&id.0)) {
+        remove_resource::<Service::Event>(&event_concept_name(listener_id), &event_cfg, origin);
+        unsafe { dynamic_config.listeners.remove_raw_index(*index) };
+        trace!(from origin, "removed listener {:?}", listener_id);
+    }
+
+    let mut notifiers = vec![];
+    unsafe { dynamic_config.notifiers.get_state() }
+        .for_each(|index, id| notifiers.push((index, *id)));
+    for (index, notifier_id) in notifiers.iter().filter(|(_, id)| is_removable(&id.0)) {
+        unsafe { dynamic_config.notifiers.remove_raw_index(*index) };
+        trace!(from origin, "removed notifier {:?}", notifier_id);
+    }
+}
+
+fn remove_request_response_ports<'config, Service: Details<'config>>(
+    dynamic_config: &request_response::DynamicConfig,
+    config: &config::Config,
+    is_removable: fn(&UniqueSystemId) -> bool,
+) {
+    let origin = "remove_request_response_ports()";
+    let connection_cfg = connection_config::<Service>(config);
+    let data_segment_cfg = data_segment_config::<Service>(config);
+
+    let mut clients = vec![];
+    unsafe { dynamic_config.clients.get_state() }.for_each(|index, id| clients.push((index, *id)));
+    let mut servers = vec![];
+    unsafe { dynamic_config.servers.get_state() }.for_each(|index, id| servers.push((index, *id)));
+
+    for (index, client_id) in clients.iter().filter(|(_, id)| is_removable(&id.0)) {
+        for (_, server_id) in &servers {
+            remove_resource::<Service::Connection>(
+                &request_connection_name(*client_id, *server_id),
+                &connection_cfg,
+                origin,
+            );
+            remove_resource::<Service::Connection>(
+                &response_connection_name(*server_id, *client_id),
+                &connection_cfg,
+                origin,
+            );
+        }
+        remove_resource::<Service::SharedMemory>(
+            &client_data_segment_name(*client_id),
+            &data_segment_cfg,
+            origin,
+        );
+        unsafe { dynamic_config.clients.remove_raw_index(*index) };
+        trace!(from origin, "removed client {:?}", client_id);
+    }
+
+    for (index, server_id) in servers.iter().filter(|(_, id)| is_removable(&id.0)) {
+        for (_, client_id) in &clients {
+            remove_resource::<Service::Connection>(
+                &request_connection_name(*client_id, *server_id),
+                &connection_cfg,
+                origin,
+            );
+            remove_resource::<Service::Connection>(
+                &response_connection_name(*server_id, *client_id),
+                &connection_cfg,
+                origin,
+            );
+        }
+        remove_resource::<Service::SharedMemory>(
+            &server_data_segment_name(*server_id),
+            &data_segment_cfg,
+            origin,
+        );
+        unsafe { dynamic_config.servers.remove_raw_index(*index) };
+        trace!(from origin, "removed server {:?}", server_id);
+    }
+}
+
+/// Returns true when a publisher or subscriber was removed, the connection monitors of the
+/// service have to be informed about it.
+fn remove_ports<'config, Service: Details<'config>>(
+    dynamic_config: &DynamicConfig,
+    config: &config::Config,
+    is_removable: fn(&UniqueSystemId) -> bool,
+) -> bool {
+    match dynamic_config.messaging_pattern() {
+        MessagingPattern::PublishSubscribe(ref v) => {
+            remove_publish_subscribe_ports::<Service>(v, config, is_removable)
+        }
+        MessagingPattern::Event(ref v) => {
+            remove_event_ports::<Service>(v, config, is_removable);
+            false
+        }
+        MessagingPattern::RequestResponse(ref v) => {
+            remove_request_response_ports::<Service>(v, config, is_removable);
+            false
+        }
+    }
+}
+
+/// Removes the event concepts of the connection monitors of dead processes. A monitor removes
+/// its event concept when it is dropped, only the ones of crashed processes are left behind.
+fn remove_dead_connection_monitors<'config, Service: Details<'config>>(config: &config::Config) {
+    let origin = "remove_dead_connection_monitors()";
+    let event_cfg = connection_monitor_event_config::<Service>(config);
+
+    let monitors = match <Service::Event as NamedConceptMgmt>::list_cfg(&event_cfg) {
+        Ok(monitors) => monitors,
+        Err(e) => {
+            warn!(from origin, "Unable to list the connection monitors ({:?}).", e);
+            return;
+        }
+    };
+
+    for monitor in monitors {
+        if connection_monitor_id(&monitor).is_some_and(|id| is_dead(&id)) {
+            remove_resource::<Service::Event>(&monitor, &event_cfg, origin);
+            trace!(from origin, "removed connection monitor {}", monitor);
+        }
+    }
+}
+
+/// Removes the ports and owners of dead processes. Returns true when no owner is left and the
+/// service was removed.
+fn remove_dead_owners<'config, Service: Details<'config>>(
+    uuid: &FileName,
+    mut storage: Service::DynamicStorage,
+    config: &config::Config,
+) -> bool {
+    let origin = "remove_dead_owners()";
+    let dynamic_config = storage.get();
+
+    if !dynamic_config.acquire_cleaner_role() {
+        trace!(from origin, "skip service {} since it is cleaned up by another process", uuid);
+        return false;
+    }
+
+    let has_removed_connections = remove_ports::<Service>(dynamic_config, config, is_dead);
+
+    let mut dead_owners = vec![];
+    dynamic_config.list_owners(|index, id| {
+        if !is_alive(id) {
+            dead_owners.push(index)
+        }
+    });
+
+    let mut has_owners = true;
+    for index in dead_owners {
+        dynamic_config.deregister_owner(index);
+        if dynamic_config.decrement_reference_counter()
+            == DecrementReferenceCounterResult::NoMoreOwners
+        {
+            has_owners = false;
+        }
+    }
+    dynamic_config.release_cleaner_role();
+
+    if has_owners {
+        // a port that waits for its counterparts recognizes the dead ones without polling
+        if has_removed_connections {
+            if let Ok(uuid) = core::str::from_utf8(uuid.as_bytes()) {
+                announce_connection_change::<Service>(config, uuid);
+            }
+        }
+        return false;
+    }
+
+    storage.acquire_ownership();
+    remove_resource::<Service::StaticStorage>(
+        uuid,
+        &static_config_storage_config::<Service>(config),
+        origin,
+    );
+    trace!(from origin, "removed service {} since all of its owners are dead", uuid);
+    true
+}
+
+/// A service whose dynamic storage is not initialized or whose static details exist without a
+/// dynamic storage is either in the middle of a creation or removal or its process died while
+/// doing so. Returns true when the service was still in this state after the creation timeout
+/// and was removed.
+fn remove_unfinished_service<'config, Service: Details<'config>>(
+    uuid: &FileName,
+    config: &config::Config,
+) -> bool {
+    let origin = "remove_unfinished_service()";
+    let static_storage_config = static_config_storage_config::<Service>(config);
+
+    match try_open_dynamic_storage::<Service>(uuid, config) {
+        Err(DynamicStorageOpenError::InitializationNotYetFinalized) => {
+            remove_resource::<Service::DynamicStorage>(
+                uuid,
+                &dynamic_config_storage_config::<Service>(config),
+                origin,
+            );
+        }
+        Err(DynamicStorageOpenError::DoesNotExist) => {
+            // a static storage that is being set up belongs to a service that is currently
+            // created
+            match <Service::StaticStorage as NamedConceptMgmt>::does_exist_cfg(
+                uuid,
+                &static_storage_config,
+            ) {
+                Ok(true) => (),
+                Ok(false) | Err(NamedConceptDoesExistError::UnderlyingResourcesBeingSetUp) => {
+                    return false
+                }
+                Err(e) => {
+                    warn!(from origin, "Unable to check the static details of the service {} ({:?}).", uuid, e);
+                    return false;
+                }
+            }
+        }
+        _ => return false,
+    }
+
+    remove_resource::<Service::StaticStorage>(uuid, &static_storage_config, origin);
+    trace!(from origin, "removed service {} which was not created or removed completely", uuid);
+    true
+}
+
+/// Removes a service whose static details are still locked after the creation timeout, its
+/// creator died before the creation was finalized. Returns true when the service is not in
+/// creation anymore, either since it was removed or since the creation was finished in the
+/// meantime.
+pub(crate) fn remove_abandoned_creation<'config, Service: Details<'config>>(
+    uuid: &FileName,
+    config: &config::Config,
+) -> bool {
+    let origin = "remove_abandoned_creation()";
+    let static_storage_config = static_config_storage_config::<Service>(config);
+
+    match <Service::StaticStorage as NamedConceptMgmt>::does_exist_cfg(uuid, &static_storage_config)
+    {
+        Err(NamedConceptDoesExistError::UnderlyingResourcesBeingSetUp) => (),
+        Ok(_) => return true,
+        Err(e) => {
+            warn!(from origin, "Unable to check the static details of the service {} ({:?}).", uuid, e);
+            return false;
+        }
+    }
+
+    match try_open_dynamic_storage::<Service>(uuid, config) {
+        // the creator died after the dynamic storage was initialized, a live creator is still
+        // registered as owner
+        Ok(storage) => remove_dead_owners::<Service>(uuid, storage, config),
+        Err(DynamicStorageOpenError::InitializationNotYetFinalized)
+        | Err(DynamicStorageOpenError::DoesNotExist) => {
+            remove_resource::<Service::DynamicStorage>(
+                uuid,
+                &dynamic_config_storage_config::<Service>(config),
+                origin,
+            );
+            remove_resource::<Service::StaticStorage>(uuid, &static_storage_config, origin);
+            trace!(from origin, "removed service {} whose creation was abandoned", uuid);
+            true
+        }
+        Err(e) => {
+            warn!(from origin, "Unable to open the dynamic storage of the service {} ({:?}).", uuid, e);
+            false
+        }
+    }
+}
+
+/// Removes the ports of dead processes from the dynamic config of a service so that their
+/// slots can be reused. Returns false when another process is cleaning up the service right
+/// now.
+pub(crate) fn remove_dead_ports<'config, Service: Details<'config>>(
+    dynamic_config: &DynamicConfig,
+    config: &config::Config,
+) -> bool {
+    if !dynamic_config.acquire_cleaner_role() {
+        return false;
+    }
+
+    // the port that is added afterwards announces the change of the connections
+    remove_ports::<Service>(dynamic_config, config, is_dead);
+    dynamic_config.release_cleaner_role();
+    true
+}
+
+/// Adds a port to the dynamic config of a service with `add_port`. When all slots are occupied
+/// the ports of dead processes are removed and it is tried once more, so that a process that
+/// crashed while or after creating a port does not block its slot.
+pub(crate) fn add_port_to_dynamic_config<'config, Service: Details<'config>, T>(
+    dynamic_config: &DynamicConfig,
+    config: &config::Config,
+    mut add_port: impl FnMut() -> Option<T>,
+) -> Option<T> {
+    if let Some(v) = add_port() {
+        return Some(v);
+    }
+
+    if !remove_dead_ports::<Service>(dynamic_config, config) {
+        return None;
+    }
+
+    add_port()
+}
+
+pub(crate) fn remove_stale_resources<'config, Service: Details<'config>>(
+    config: &config::Config,
+) -> Result<usize, ServiceRemoveStaleResourcesError> {
+    let msg = "Unable to remove stale resources";
+    let origin = "Service::remove_stale_resources()";
+
+    let mut services = fail!(from origin,
+            when <Service::StaticStorage as NamedConceptMgmt>::list_cfg(&static_config_storage_config::<Service>(config)),
+            map NamedConceptListError::InsufficientPermissions => ServiceRemoveStaleResourcesError::InsufficientPermissions,
+            unmatched ServiceRemoveStaleResourcesError::InternalError,
+            "{} since the static details of all services could not be listed.", msg);
+
+    // a service whose creator died before the static details were written is only
+    // discoverable by its dynamic storage
+    let dynamic_storages = fail!(from origin,
+            when <Service::DynamicStorage as NamedConceptMgmt>::list_cfg(&dynamic_config_storage_config::<Service>(config)),
+            map NamedConceptListError::InsufficientPermissions => ServiceRemoveStaleResourcesError::InsufficientPermissions,
+            unmatched ServiceRemoveStaleResourcesError::InternalError,
+            "{} since the dynamic storages of all services could not be listed.", msg);
+    for uuid in dynamic_storages {
+        if !services.contains(&uuid) {
+            services.push(uuid);
+        }
+    }
+
+    let mut number_of_removed_services = 0;
+    let mut unfinished_services = vec![];
+    for uuid in services {
+        match try_open_dynamic_storage::<Service>(&uuid, config) {
+            Ok(storage) => {
+                if remove_dead_owners::<Service>(&uuid, storage, config) {
+                    number_of_removed_services += 1;
+                }
+            }
+            Err(DynamicStorageOpenError::InitializationNotYetFinalized)
+            | Err(DynamicStorageOpenError::DoesNotExist) => unfinished_services.push(uuid),
+            Err(e) => {
+                warn!(from origin, "Unable to open the dynamic storage of the service {} ({:?}), skipping it.", uuid, e);
+            }
+        }
+    }
+
+    remove_dead_connection_monitors::<Service>(config);
+
+    if unfinished_services.is_empty() {
+        if number_of_removed_services > 0 {
+            announce_change::<Service>(config);
+        }
+        return Ok(number_of_removed_services);
+    }
+
+    // a live process finishes the creation or removal of a service within the creation timeout
+    fail!(from origin, when nanosleep(config.global.service.creation_timeout),
+            with ServiceRemoveStaleResourcesError::InternalError,
+            "{} since the wait for unfinished services failed.", msg);
+
+    for uuid in unfinished_services {
+        if remove_unfinished_service::<Service>(&uuid, config) {
+            number_of_removed_services += 1;
+        }
+    }
+
+    if number_of_removed_services > 0 {
+        announce_change::<Service>(config);
+    }
+
+    Ok(number_of_removed_services)
+}
+
+/// Removes all services with the given name. Without `force` a service is only removed when no
+/// process has it opened, otherwise the resources of the ports of dead processes are removed as
+/// well. The ports of live processes keep their slots and resources until they are dropped,
+/// removing them underneath would let the ports release them a second time.
+/// The dynamic storage is marked for destruction and removed before the static details, a
+/// process that opens the service concurrently either succeeds before the removal started or
+/// fails with `DoesNotExist`.
+pub(crate) fn remove_service<'config, Service: Details<'config>>(
+    service_name: &ServiceName,
+    config: &'config config::Config,
+    force: bool,
+) -> Result<(), ServiceRemoveError> {
+    let msg = "Unable to remove the service";
+    let origin = "Service::remove()";
+
+    let services = fail!(from origin, when Service::list_with_custom_config(config),
+            map ServiceListError::InsufficientPermissions => ServiceRemoveError::InsufficientPermissions,
+            unmatched ServiceRemoveError::InternalError,
+            "{} \"{}\" since the existing services could not be listed.", msg, service_name);
+
+    let mut number_of_removed_services = 0;
+    for static_config in services.iter().filter(|s| s.service_name() == service_name) {
+        let uuid = fatal_panic!(from origin,
+                when FileName::new(static_config.uuid().as_bytes()),
+                "This should never happen! The uuid should be always a valid file name.");
+
+        match try_open_dynamic_storage::<Service>(&uuid, config) {
+            Ok(mut storage) => {
+                let dynamic_config = storage.get();
+                if force {
+                    dynamic_config.force_mark_for_destruction();
+                    remove_ports::<Service>(dynamic_config, config, is_dead);
+                } else {
+                    match dynamic_config.try_mark_for_destruction() {
+                        MarkForDestructionResult::Marked => (),
+                        // removed concurrently by its last owner or another process
+                        MarkForDestructionResult::AlreadyMarked => continue,
+                        MarkForDestructionResult::InUse => {
+                            fail!(from origin, with ServiceRemoveError::InUse,
+                                "{} \"{}\" since it is still opened by at least one process.", msg, service_name);
+                        }
+                    }
+                }
+
+                storage.acquire_ownership();
+                drop(storage);
+            }
+            Err(DynamicStorageOpenError::DoesNotExist) if !force => continue,
+            Err(DynamicStorageOpenError::InitializationNotYetFinalized) if !force => {
+                fail!(from origin, with ServiceRemoveError::InUse,
+                    "{} \"{}\" since it is currently created.", msg, service_name);
+            }
+            Err(DynamicStorageOpenError::DoesNotExist)
+            | Err(DynamicStorageOpenError::InitializationNotYetFinalized) => {
+                remove_resource::<Service::DynamicStorage>(
+                    &uuid,
+                    &dynamic_config_storage_config::<Service>(config),
+                    origin,
+                );
+            }
+            Err(e) => {
+                fail!(from origin, with ServiceRemoveError::InternalError,
+                    "{} \"{}\" since its dynamic storage could not be opened ({:?}).", msg, service_name, e);
+            }
+        }
+
+        remove_resource::<Service::StaticStorage>(
+            &uuid,
+            &static_config_storage_config::<Service>(config),
+            origin,
+        );
+        trace!(from origin, "removed service {}", uuid);
+        number_of_removed_services += 1;
+    }
+
+    if number_of_removed_services == 0 {
+        fail!(from origin, with ServiceRemoveError::DoesNotExist,
+            "{} \"{}\" since it does not exist.", msg, service_name);
+    }
+
+    announce_change::<Service>(config);
+    Ok(())
+}
@@ -23,20 +23,28 @@
 //!
 //! println!("max listeners:                {:?}", event.static_config().max_supported_listeners());
 //! println!("max notifiers:                {:?}", event.static_config().max_supported_notifiers());
+//! println!("event id max value:           {:?}", event.static_config().event_id_max_value());
 //!
 //! # Ok(())
 //! # }
 //! ```
+use std::alloc::Layout;
+
 use crate::config;
 use serde::{Deserialize, Serialize};
 
+use super::publish_subscribe::TypeDetails;
+
 /// The static configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
 /// based service. Contains all parameters that do not change during the lifetime of a
 /// [`crate::service::Service`].
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct StaticConfig {
     pub(crate) max_notifiers: usize,
     pub(crate) max_listeners: usize,
+    pub(crate) event_id_max_value: u64,
+    // serialized as table, therefore it must be the last entry
+    pub(crate) payload_type_details: TypeDetails,
 }
 
 impl StaticConfig {
@@ -44,6 +52,8 @@ impl StaticConfig {
         Self {
             max_notifiers: config.defaults.event.max_notifiers,
             max_listeners: config.defaults.event.max_listeners,
+            event_id_max_value: config.defaults.event.event_id_max_value,
+            payload_type_details: TypeDetails::new::<()>(Layout::new::<()>()),
         }
     }
 
@@ -56,4 +66,21 @@ impl StaticConfig {
     pub fn max_supported_listeners(&self) -> usize {
         self.max_listeners
     }
+
+    /// Returns the largest value an [`crate::port::event_id::EventId`] can have. Every
+    /// [`crate::port::listener::Listener`] tracks the received event ids in a bitset of this size.
+    pub fn event_id_max_value(&self) -> u64 {
+        self.event_id_max_value
+    }
+
+    /// Returns the [`TypeDetails`] of the payload that can be attached to a notification, see
+    /// [`crate::service::builder::event::Builder::event_payload()`]. Services without an event
+    /// payload have the payload type `()`.
+    pub fn payload_type_details(&self) -> &TypeDetails {
+        &self.payload_type_details
+    }
+
+    pub(crate) fn has_event_payload(&self) -> bool {
+        self.payload_type_details.size != 0
+    }
 }
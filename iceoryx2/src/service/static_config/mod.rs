@@ -20,8 +20,15 @@ pub mod event;
 /// based service.
 pub mod publish_subscribe;
 
+/// The static service configuration of a
+/// [`MessagingPattern::RequestResponse`]
+/// based service.
+pub mod request_response;
+
+use crate::service::attribute::AttributeSet;
 use crate::service::messaging_pattern::MessagingPattern;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::hash::Hash;
 use serde::{Deserialize, Serialize};
 
@@ -29,11 +36,28 @@ use crate::config;
 
 use super::service_name::ServiceName;
 
+/// Defines what happens with a [`crate::service::Service`] when the last process that opened
+/// it closes it.
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum ServicePersistence {
+    /// The service is removed as soon as the last instance of the service, and therefore also
+    /// its last port, is dropped.
+    #[default]
+    RemoveWhenLastPortDrops,
+    /// The service stays available for processes that open it later, until it is removed
+    /// explicitly with [`crate::service::Details::remove()`].
+    Persistent,
+}
+
 /// Defines a common set of static service configuration details every service shares.
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct StaticConfig {
     uuid: String,
+    pub(crate) instance_uuid: String,
     service_name: ServiceName,
+    pub(crate) persistence: ServicePersistence,
+    // must precede the messaging pattern since the attributes are serialized as array of tables
+    pub(crate) attributes: AttributeSet,
     pub(crate) messaging_pattern: MessagingPattern,
 }
 
@@ -47,6 +71,13 @@ fn create_uuid<Hasher: Hash>(
     Hasher::new(pattern_and_service.as_bytes())
 }
 
+fn create_instance_uuid() -> String {
+    let id = fatal_panic!(from "StaticConfig::create_instance_uuid()",
+        when UniqueSystemId::new(),
+        "Unable to create the instance uuid of the service.");
+    format!("{:032x}", id.value())
+}
+
 impl StaticConfig {
     pub(crate) fn new_event<Hasher: Hash>(
         service_name: &ServiceName,
@@ -55,7 +86,10 @@ impl StaticConfig {
         let messaging_pattern = MessagingPattern::Event(event::StaticConfig::new(config));
         Self {
             uuid: create_uuid::<Hasher>(service_name, &messaging_pattern).as_hex_string(),
+            instance_uuid: create_instance_uuid(),
             service_name: *service_name,
+            persistence: ServicePersistence::default(),
+            attributes: AttributeSet::default(),
             messaging_pattern,
         }
     }
@@ -68,7 +102,26 @@ impl StaticConfig {
             MessagingPattern::PublishSubscribe(publish_subscribe::StaticConfig::new(config));
         Self {
             uuid: create_uuid::<Hasher>(service_name, &messaging_pattern).as_hex_string(),
+            instance_uuid: create_instance_uuid(),
+            service_name: *service_name,
+            persistence: ServicePersistence::default(),
+            attributes: AttributeSet::default(),
+            messaging_pattern,
+        }
+    }
+
+    pub(crate) fn new_request_response<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        let messaging_pattern =
+            MessagingPattern::RequestResponse(request_response::StaticConfig::new(config));
+        Self {
+            uuid: create_uuid::<Hasher>(service_name, &messaging_pattern).as_hex_string(),
+            instance_uuid: create_instance_uuid(),
             service_name: *service_name,
+            persistence: ServicePersistence::default(),
+            attributes: AttributeSet::default(),
             messaging_pattern,
         }
     }
@@ -78,11 +131,29 @@ impl StaticConfig {
         &self.uuid
     }
 
+    /// Returns the uuid of the concrete instance of the [`crate::service::Service`]. In contrast
+    /// to [`StaticConfig::uuid()`], which is derived from the name and the messaging pattern,
+    /// it is generated when the service is created and differs whenever a service with the
+    /// same name is removed and created again.
+    pub fn instance_uuid(&self) -> &str {
+        &self.instance_uuid
+    }
+
     /// Returns the [`ServiceName`] of the [`crate::service::Service`]
     pub fn service_name(&self) -> &ServiceName {
         &self.service_name
     }
 
+    /// Returns the [`ServicePersistence`] the [`crate::service::Service`] was created with
+    pub fn persistence(&self) -> ServicePersistence {
+        self.persistence
+    }
+
+    /// Returns the [`AttributeSet`] the [`crate::service::Service`] was created with
+    pub fn attributes(&self) -> &AttributeSet {
+        &self.attributes
+    }
+
     /// Returns the [`MessagingPattern`] of the [`crate::service::Service`]
     pub fn messaging_pattern(&self) -> &MessagingPattern {
         &self.messaging_pattern
@@ -130,4 +201,23 @@ impl StaticConfig {
             }
         }
     }
+
+    pub(crate) fn request_response(&self) -> &request_response::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access request_response::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn request_response_mut(&mut self) -> &mut request_response::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access request_response::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
 }
@@ -22,19 +22,370 @@
 //!     .open_or_create::<u64>()?;
 //!
 //! println!("type name:                        {:?}", pubsub.static_config().type_name());
+//! println!("user header type name:            {:?}", pubsub.static_config().user_header_type_name());
+//! println!("type size:                        {:?}", pubsub.static_config().type_size());
+//! println!("type alignment:                   {:?}", pubsub.static_config().type_alignment());
+//! println!("pointer width:                    {:?}", pubsub.static_config().payload_type_details().pointer_width());
+//! println!("payload type details:             {}", pubsub.static_config().payload_type_details());
+//! println!("user header type details:         {}", pubsub.static_config().user_header_type_details());
 //! println!("max publishers:                   {:?}", pubsub.static_config().max_supported_publishers());
 //! println!("max subscribers:                  {:?}", pubsub.static_config().max_supported_subscribers());
 //! println!("subscriber buffer size:           {:?}", pubsub.static_config().subscriber_max_buffer_size());
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("single subscriber:                {:?}", pubsub.static_config().has_single_subscriber());
+//! println!("notifications:                    {:?}", pubsub.static_config().has_notifications());
+//! println!("max slice length:                 {:?}", pubsub.static_config().max_slice_len());
+//! println!("payload alignment:                {:?}", pubsub.static_config().payload_alignment());
+//! println!("clock type:                       {:?}", pubsub.static_config().clock_type());
+//! println!("unable to deliver strategy:       {:?}", pubsub.static_config().unable_to_deliver_strategy());
+//! println!("deadline:                         {:?}", pubsub.static_config().deadline());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use std::{alloc::Layout, fmt::Debug, fmt::Display, time::Duration};
+
 use crate::config;
-use serde::{Deserialize, Serialize};
+use crate::service::header::publish_subscribe::{ClockType, Header};
+use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::testing::pointer_width;
+use crate::zero_copy_send::ZeroCopySend;
+use serde::{de::Unexpected, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Describes a type that is transmitted by a
+/// [`crate::service::messaging_pattern::MessagingPattern::PublishSubscribe`] or
+/// [`crate::service::messaging_pattern::MessagingPattern::Event`] based
+/// [`crate::service::Service`]. It is stored when the service is created and every participant
+/// that opens the service must provide a matching type.
+///
+/// The shared control structures of a service, like the connection queues, the dynamic config
+/// and the [`iceoryx2_cal::shm_allocator::PointerOffset`]s, consist of `usize` values. A
+/// process with another pointer width would interpret them differently, therefore the
+/// [`TypeDetails::pointer_width()`] is part of the type and a process with another pointer
+/// width cannot open the service.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TypeDetails {
+    pub(crate) type_name: String,
+    pub(crate) size: usize,
+    pub(crate) alignment: usize,
+    // services of versions without a pointer width are incompatible
+    #[serde(default)]
+    pub(crate) pointer_width: u32,
+    // services of versions without a layout hash are compared by size and alignment only
+    #[serde(
+        default,
+        serialize_with = "serialize_layout_hash",
+        deserialize_with = "deserialize_layout_hash"
+    )]
+    pub(crate) layout_hash: Option<u64>,
+}
+
+// TOML integers are signed 64-bit values and cannot represent every hash, therefore the layout
+// hash is stored as a hex string.
+fn serialize_layout_hash<S: Serializer>(
+    value: &Option<u64>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(hash) => serializer.serialize_some(&format!("{:#018x}", hash)),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_layout_hash<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<u64>, D::Error> {
+    match Option::<String>::deserialize(deserializer)? {
+        Some(value) => value
+            .strip_prefix("0x")
+            .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+            .map(Some)
+            .ok_or_else(|| {
+                serde::de::Error::invalid_value(
+                    Unexpected::Str(&value),
+                    &"a hex encoded 64-bit layout hash",
+                )
+            }),
+        None => Ok(None),
+    }
+}
+
+impl Display for TypeDetails {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "\"{}\" {{ size: {}, alignment: {}, pointer width: {}, layout hash: ",
+            self.type_name, self.size, self.alignment, self.pointer_width
+        )?;
+        match self.layout_hash {
+            Some(hash) => write!(f, "{:#018x} }}", hash),
+            None => write!(f, "none }}"),
+        }
+    }
+}
+
+impl TypeDetails {
+    pub(crate) fn new<T: ?Sized>(layout: Layout) -> Self {
+        Self {
+            type_name: std::any::type_name::<T>().to_string(),
+            size: layout.size(),
+            alignment: layout.align(),
+            pointer_width: pointer_width(),
+            layout_hash: None,
+        }
+    }
+
+    pub(crate) fn with_layout_hash<T: ?Sized + ZeroCopySend>(layout: Layout) -> Self {
+        Self {
+            layout_hash: T::LAYOUT_HASH,
+            ..Self::new::<T>(layout)
+        }
+    }
+
+    /// Creates [`TypeDetails`] from their parts, for instance for a payload type that is
+    /// defined in another language, see
+    /// [`crate::service::builder::publish_subscribe::Builder::payload_type_details()`]. The
+    /// pointer width is the one of the current process. Without a `layout_hash` an opened
+    /// service is verified by the size and the alignment of the type only.
+    pub fn from_parts(
+        type_name: &str,
+        size: usize,
+        alignment: usize,
+        layout_hash: Option<u64>,
+    ) -> Self {
+        Self {
+            type_name: type_name.to_string(),
+            size,
+            alignment,
+            pointer_width: pointer_width(),
+            layout_hash,
+        }
+    }
+
+    /// Returns the name of the type as provided by [`std::any::type_name()`].
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns the size of the type in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the alignment of the type.
+    pub fn alignment(&self) -> usize {
+        self.alignment
+    }
+
+    /// Returns the pointer width in bits of the process that created the service.
+    pub fn pointer_width(&self) -> u32 {
+        self.pointer_width
+    }
+
+    /// Returns the structural fingerprint of the type, see
+    /// [`crate::zero_copy_send::ZeroCopySend::LAYOUT_HASH`]. Services are opened when the
+    /// fingerprints match, independent of the type name, so that the same type can be defined
+    /// in different crates. It is [`None`] for types whose layout is unknown, like the user
+    /// header, and for services of older versions.
+    pub fn layout_hash(&self) -> Option<u64> {
+        self.layout_hash
+    }
+
+    pub(crate) fn has_same_layout(&self, other: &TypeDetails) -> bool {
+        self.size == other.size
+            && self.alignment == other.alignment
+            && self.pointer_width == other.pointer_width
+    }
+
+    // types with a layout hash are compared by it, types without one by their name, when only
+    // one of them has a layout hash the layout is all that can be compared
+    pub(crate) fn is_compatible_with(&self, other: &TypeDetails) -> bool {
+        if !self.has_same_layout(other) {
+            return false;
+        }
+
+        match (self.layout_hash, other.layout_hash) {
+            (Some(hash), Some(other_hash)) => hash == other_hash,
+            (None, None) => self.type_name == other.type_name,
+            _ => true,
+        }
+    }
+}
+
+/// A setting of an existing [`crate::service::Service`] that does not satisfy the
+/// [`Requirements`] of a participant that opens it, see [`StaticConfig::compatibility_diff()`].
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct IncompatibleSetting {
+    /// The name of the setting, it is the name of the method of
+    /// [`crate::service::builder::publish_subscribe::Builder`] that requires it.
+    pub name: &'static str,
+    /// The value of the setting the [`crate::service::Service`] was created with.
+    pub existing: String,
+    /// The value of the setting that was requested.
+    pub requested: String,
+}
+
+impl IncompatibleSetting {
+    fn new<T: Debug>(name: &'static str, existing: T, requested: T) -> Self {
+        Self {
+            name,
+            existing: format!("{:?}", existing),
+            requested: format!("{:?}", requested),
+        }
+    }
+}
+
+impl Display for IncompatibleSetting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (existing: {}, requested: {})",
+            self.name, self.existing, self.requested
+        )
+    }
+}
+
+/// The settings a participant requires from an existing
+/// [`crate::service::messaging_pattern::MessagingPattern::PublishSubscribe`] based
+/// [`crate::service::Service`], only the settings that are set are verified. The limits are
+/// satisfied when the service supports at least the requested value, all other settings must
+/// be equal. The [`crate::service::builder::publish_subscribe::Builder`] verifies the settings
+/// that were set on it when it opens a service, the [`Requirements`] allow tools to check an
+/// existing [`StaticConfig`] before they open it, see [`StaticConfig::compatibility_diff()`].
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq)]
+pub struct Requirements {
+    pub(crate) max_publishers: Option<usize>,
+    pub(crate) max_subscribers: Option<usize>,
+    pub(crate) subscriber_max_buffer_size: Option<usize>,
+    pub(crate) history_size: Option<usize>,
+    pub(crate) subscriber_max_borrowed_samples: Option<usize>,
+    pub(crate) enable_single_subscriber: Option<bool>,
+    pub(crate) enable_notifications: Option<bool>,
+    pub(crate) max_slice_len: Option<usize>,
+    pub(crate) payload_alignment: Option<usize>,
+    pub(crate) clock_type: Option<ClockType>,
+    pub(crate) unable_to_deliver_strategy: Option<UnableToDeliverStrategy>,
+    pub(crate) allow_user_time_stamps: Option<bool>,
+    pub(crate) deadline: Option<Duration>,
+    pub(crate) enable_safe_overflow: Option<bool>,
+    pub(crate) enable_deterministic_memory: Option<bool>,
+    pub(crate) payload_type_details: Option<TypeDetails>,
+    pub(crate) user_header_type_details: Option<TypeDetails>,
+}
+
+impl Requirements {
+    /// Creates [`Requirements`] that do not require any setting.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires a service that supports at least `value` publishers.
+    pub fn max_publishers(mut self, value: usize) -> Self {
+        self.max_publishers = Some(value);
+        self
+    }
+
+    /// Requires a service that supports at least `value` subscribers.
+    pub fn max_subscribers(mut self, value: usize) -> Self {
+        self.max_subscribers = Some(value);
+        self
+    }
+
+    /// Requires a service that supports a subscriber buffer size of at least `value`.
+    pub fn subscriber_max_buffer_size(mut self, value: usize) -> Self {
+        self.subscriber_max_buffer_size = Some(value);
+        self
+    }
+
+    /// Requires a service that supports a history size of at least `value`.
+    pub fn history_size(mut self, value: usize) -> Self {
+        self.history_size = Some(value);
+        self
+    }
+
+    /// Requires a service that allows a subscriber to borrow at least `value` samples.
+    pub fn subscriber_max_borrowed_samples(mut self, value: usize) -> Self {
+        self.subscriber_max_borrowed_samples = Some(value);
+        self
+    }
+
+    /// Requires a service with the given single subscriber setting.
+    pub fn enable_single_subscriber(mut self, value: bool) -> Self {
+        self.enable_single_subscriber = Some(value);
+        self
+    }
+
+    /// Requires a service with the given notification setting.
+    pub fn enable_notifications(mut self, value: bool) -> Self {
+        self.enable_notifications = Some(value);
+        self
+    }
+
+    /// Requires a service that supports slices with at least `value` elements.
+    pub fn max_slice_len(mut self, value: usize) -> Self {
+        self.max_slice_len = Some(value);
+        self
+    }
+
+    /// Requires a service that aligns the payload to at least `value` bytes.
+    pub fn payload_alignment(mut self, value: usize) -> Self {
+        self.payload_alignment = Some(value);
+        self
+    }
+
+    /// Requires a service that uses the given [`ClockType`].
+    pub fn clock_type(mut self, value: ClockType) -> Self {
+        self.clock_type = Some(value);
+        self
+    }
+
+    /// Requires a service with the given [`UnableToDeliverStrategy`].
+    pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
+        self.unable_to_deliver_strategy = Some(value);
+        self
+    }
+
+    /// Requires a service with the given setting for user defined time stamps.
+    pub fn allow_user_time_stamps(mut self, value: bool) -> Self {
+        self.allow_user_time_stamps = Some(value);
+        self
+    }
+
+    /// Requires a service with a deadline of at most `value`.
+    pub fn deadline(mut self, value: Duration) -> Self {
+        self.deadline = Some(value);
+        self
+    }
+
+    /// Requires a service with the given safe overflow setting.
+    pub fn enable_safe_overflow(mut self, value: bool) -> Self {
+        self.enable_safe_overflow = Some(value);
+        self
+    }
+
+    /// Requires a service with the given deterministic memory setting.
+    pub fn enable_deterministic_memory(mut self, value: bool) -> Self {
+        self.enable_deterministic_memory = Some(value);
+        self
+    }
+
+    /// Requires a service whose payload type is compatible with `value`. Types with a
+    /// [`TypeDetails::layout_hash()`] are compared by it, types without one by their name.
+    pub fn payload_type_details(mut self, value: TypeDetails) -> Self {
+        self.payload_type_details = Some(value);
+        self
+    }
+
+    /// Requires a service whose user header type is compatible with `value`, see
+    /// [`Requirements::payload_type_details()`].
+    pub fn user_header_type_details(mut self, value: TypeDetails) -> Self {
+        self.user_header_type_details = Some(value);
+        self
+    }
+}
 
 /// The static configuration of an
 /// [`crate::service::messaging_pattern::MessagingPattern::PublishSubscribe`]
@@ -48,7 +399,23 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
-    pub(crate) type_name: String,
+    pub(crate) enable_single_subscriber: bool,
+    pub(crate) enable_notifications: bool,
+    pub(crate) max_slice_len: usize,
+    pub(crate) payload_alignment: usize,
+    pub(crate) clock_type: ClockType,
+    pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
+    pub(crate) allow_user_time_stamps: bool,
+    pub(crate) enable_intra_process_fast_path: bool,
+    // services of versions without a header layout version are incompatible
+    #[serde(default)]
+    pub(crate) header_layout_version: u8,
+    #[serde(default)]
+    pub(crate) enable_deterministic_memory: bool,
+    // the following entries are serialized as tables, therefore they must be the last entries
+    pub(crate) payload_type_details: TypeDetails,
+    pub(crate) user_header_type_details: TypeDetails,
+    pub(crate) deadline: Option<Duration>,
 }
 
 impl StaticConfig {
@@ -66,7 +433,31 @@ impl StaticConfig {
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
-            type_name: String::new(),
+            enable_single_subscriber: false,
+            enable_notifications: false,
+            max_slice_len: 1,
+            payload_alignment: 1,
+            clock_type: config.defaults.publish_subscribe.clock_type,
+            unable_to_deliver_strategy: config
+                .defaults
+                .publish_subscribe
+                .unable_to_deliver_strategy,
+            allow_user_time_stamps: false,
+            enable_intra_process_fast_path: config
+                .defaults
+                .publish_subscribe
+                .enable_intra_process_fast_path,
+            header_layout_version: Header::LAYOUT_VERSION,
+            enable_deterministic_memory: false,
+            payload_type_details: TypeDetails {
+                type_name: String::new(),
+                size: 0,
+                alignment: 1,
+                pointer_width: pointer_width(),
+                layout_hash: None,
+            },
+            user_header_type_details: TypeDetails::new::<()>(Layout::new::<()>()),
+            deadline: None,
         }
     }
 
@@ -104,8 +495,306 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns true if the [`crate::service::Service`] guarantees that there is at most one
+    /// [`crate::port::subscriber::Subscriber`] that consumes a [`crate::sample::Sample`].
+    /// Only then [`crate::sample::Sample::payload_mut()`] provides mutable access to the
+    /// received payload.
+    pub fn has_single_subscriber(&self) -> bool {
+        self.enable_single_subscriber
+    }
+
+    /// Returns true if the [`crate::port::publisher::Publisher`]s wake up
+    /// [`crate::port::subscriber::Subscriber`]s that wait in
+    /// [`crate::port::subscriber::Subscriber::timed_receive()`] or
+    /// [`crate::port::subscriber::Subscriber::blocking_receive()`] for a new
+    /// [`crate::sample::Sample`].
+    pub fn has_notifications(&self) -> bool {
+        self.enable_notifications
+    }
+
+    /// Returns the maximum number of elements a slice payload can contain. For services with a
+    /// sized payload type it is always 1.
+    pub fn max_slice_len(&self) -> usize {
+        self.max_slice_len
+    }
+
+    /// Returns the alignment of the payload in every [`crate::sample::Sample`]. It is at least
+    /// the [`StaticConfig::type_alignment()`] and can be increased with
+    /// [`crate::service::builder::publish_subscribe::Builder::payload_alignment()`].
+    pub fn payload_alignment(&self) -> usize {
+        self.payload_alignment
+    }
+
+    /// Returns the [`ClockType`] that is used for the time stamps of the
+    /// [`crate::service::header::publish_subscribe::Header`].
+    pub fn clock_type(&self) -> ClockType {
+        self.clock_type
+    }
+
+    /// Returns the [`Header::LAYOUT_VERSION`] of the iceoryx2 version that created the
+    /// [`crate::service::Service`].
+    pub fn header_layout_version(&self) -> u8 {
+        self.header_layout_version
+    }
+
+    /// Returns the [`UnableToDeliverStrategy`] of the [`crate::service::Service`]. It is the
+    /// strongest strategy a [`crate::port::publisher::Publisher`] of the service can pursue.
+    pub fn unable_to_deliver_strategy(&self) -> UnableToDeliverStrategy {
+        self.unable_to_deliver_strategy
+    }
+
+    /// Returns true if the time stamp of a [`crate::sample_mut::SampleMut`] can be set with
+    /// [`crate::sample_mut::SampleMut::set_time_stamp()`], otherwise it is always the time when
+    /// the sample was sent.
+    pub fn allows_user_time_stamps(&self) -> bool {
+        self.allow_user_time_stamps
+    }
+
+    /// Returns true if [`crate::port::subscriber::Subscriber`]s receive the samples of
+    /// [`crate::port::publisher::Publisher`]s of the same process via process local queues,
+    /// see [`crate::config::PublishSubscribe::enable_intra_process_fast_path`].
+    pub fn has_intra_process_fast_path(&self) -> bool {
+        self.enable_intra_process_fast_path
+    }
+
+    /// Returns true if the ports of the [`crate::service::Service`] acquire all of their memory
+    /// when they are created, see
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_deterministic_memory()`].
+    pub fn has_deterministic_memory(&self) -> bool {
+        self.enable_deterministic_memory
+    }
+
     /// Returns the type name of the [`crate::service::Service`].
     pub fn type_name(&self) -> &str {
-        &self.type_name
+        &self.payload_type_details.type_name
+    }
+
+    /// Returns the size of the payload type in bytes. For slice payloads it is the size of a
+    /// single element.
+    pub fn type_size(&self) -> usize {
+        self.payload_type_details.size
+    }
+
+    /// Returns the alignment of the payload type. For slice payloads it is the alignment of a
+    /// single element.
+    pub fn type_alignment(&self) -> usize {
+        self.payload_type_details.alignment
+    }
+
+    /// Returns the type name of the user header that precedes the payload of every
+    /// [`crate::sample::Sample`]. Without a custom user header it is `()`.
+    pub fn user_header_type_name(&self) -> &str {
+        &self.user_header_type_details.type_name
+    }
+
+    /// Returns the [`TypeDetails`] of the payload. For slice payloads they describe a single
+    /// element.
+    pub fn payload_type_details(&self) -> &TypeDetails {
+        &self.payload_type_details
+    }
+
+    /// Returns the size of one payload element in bytes for ports that access the payload as
+    /// untyped bytes. Returns [`None`] when the size and alignment of the payload type do not
+    /// describe a valid memory layout.
+    pub(crate) fn untyped_element_size(&self) -> Option<usize> {
+        let details = &self.payload_type_details;
+        let is_valid = details.size != 0
+            && details.alignment.is_power_of_two()
+            && details.size % details.alignment == 0
+            && details.alignment <= self.payload_alignment;
+
+        is_valid.then_some(details.size)
+    }
+
+    /// Returns the [`TypeDetails`] of the user header.
+    pub fn user_header_type_details(&self) -> &TypeDetails {
+        &self.user_header_type_details
+    }
+
+    /// Returns the deadline contract of the [`crate::service::Service`]. When it is set, every
+    /// [`crate::port::publisher::Publisher`] promises to send a [`crate::sample::Sample`] at
+    /// least once within the deadline, otherwise it is [`None`].
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// Returns every setting that does not satisfy the [`Requirements`], in the order in which
+    /// the [`crate::service::builder::publish_subscribe::Builder`] verifies them. The list is
+    /// empty when the service can be opened with the [`Requirements`]. The layout version of
+    /// the [`Header`] is verified independent of the [`Requirements`], the payload and user
+    /// header types only when they are part of the [`Requirements`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::static_config::publish_subscribe::Requirements;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let pubsub = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .history_size(2)
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let requirements = Requirements::new().history_size(4);
+    /// for setting in pubsub.static_config().compatibility_diff(&requirements) {
+    ///     println!("{} is {} but {} is required", setting.name, setting.existing, setting.requested);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compatibility_diff(&self, requested: &Requirements) -> Vec<IncompatibleSetting> {
+        fn at_least(
+            diff: &mut Vec<IncompatibleSetting>,
+            name: &'static str,
+            existing: usize,
+            requested: Option<usize>,
+        ) {
+            if let Some(requested) = requested {
+                if existing < requested {
+                    diff.push(IncompatibleSetting::new(name, existing, requested));
+                }
+            }
+        }
+
+        fn equal<T: Debug + PartialEq>(
+            diff: &mut Vec<IncompatibleSetting>,
+            name: &'static str,
+            existing: T,
+            requested: Option<T>,
+        ) {
+            if let Some(requested) = requested {
+                if existing != requested {
+                    diff.push(IncompatibleSetting::new(name, existing, requested));
+                }
+            }
+        }
+
+        let mut diff = vec![];
+        equal(
+            &mut diff,
+            "header_layout_version",
+            self.header_layout_version,
+            Some(Header::LAYOUT_VERSION),
+        );
+        at_least(
+            &mut diff,
+            "max_publishers",
+            self.max_publishers,
+            requested.max_publishers,
+        );
+        at_least(
+            &mut diff,
+            "max_subscribers",
+            self.max_subscribers,
+            requested.max_subscribers,
+        );
+        at_least(
+            &mut diff,
+            "subscriber_max_buffer_size",
+            self.subscriber_max_buffer_size,
+            requested.subscriber_max_buffer_size,
+        );
+        at_least(
+            &mut diff,
+            "history_size",
+            self.history_size,
+            requested.history_size,
+        );
+        at_least(
+            &mut diff,
+            "subscriber_max_borrowed_samples",
+            self.subscriber_max_borrowed_samples,
+            requested.subscriber_max_borrowed_samples,
+        );
+        equal(
+            &mut diff,
+            "enable_single_subscriber",
+            self.enable_single_subscriber,
+            requested.enable_single_subscriber,
+        );
+        equal(
+            &mut diff,
+            "enable_notifications",
+            self.enable_notifications,
+            requested.enable_notifications,
+        );
+        at_least(
+            &mut diff,
+            "max_slice_len",
+            self.max_slice_len,
+            requested.max_slice_len,
+        );
+        at_least(
+            &mut diff,
+            "payload_alignment",
+            self.payload_alignment,
+            requested.payload_alignment,
+        );
+        equal(
+            &mut diff,
+            "clock_type",
+            self.clock_type,
+            requested.clock_type,
+        );
+        equal(
+            &mut diff,
+            "unable_to_deliver_strategy",
+            self.unable_to_deliver_strategy,
+            requested.unable_to_deliver_strategy,
+        );
+        equal(
+            &mut diff,
+            "allow_user_time_stamps",
+            self.allow_user_time_stamps,
+            requested.allow_user_time_stamps,
+        );
+        if let Some(deadline) = requested.deadline {
+            match self.deadline {
+                Some(existing) if existing <= deadline => (),
+                existing => diff.push(IncompatibleSetting::new(
+                    "deadline",
+                    existing,
+                    Some(deadline),
+                )),
+            }
+        }
+        equal(
+            &mut diff,
+            "enable_safe_overflow",
+            self.enable_safe_overflow,
+            requested.enable_safe_overflow,
+        );
+        equal(
+            &mut diff,
+            "enable_deterministic_memory",
+            self.enable_deterministic_memory,
+            requested.enable_deterministic_memory,
+        );
+        for (name, existing, requested) in [
+            (
+                "payload_type_details",
+                &self.payload_type_details,
+                &requested.payload_type_details,
+            ),
+            (
+                "user_header_type_details",
+                &self.user_header_type_details,
+                &requested.user_header_type_details,
+            ),
+        ] {
+            if let Some(requested) = requested {
+                if !existing.is_compatible_with(requested) {
+                    diff.push(IncompatibleSetting {
+                        name,
+                        existing: existing.to_string(),
+                        requested: requested.to_string(),
+                    });
+                }
+            }
+        }
+
+        diff
     }
 }
@@ -0,0 +1,123 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/Calculator")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! println!("request type details:             {}", service.static_config().request_type_details());
+//! println!("response type details:            {}", service.static_config().response_type_details());
+//! println!("max clients:                      {:?}", service.static_config().max_supported_clients());
+//! println!("max servers:                      {:?}", service.static_config().max_supported_servers());
+//! println!("max active requests:              {:?}", service.static_config().max_active_requests());
+//! println!("max responses per request:        {:?}", service.static_config().max_responses_per_request());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::alloc::Layout;
+
+use crate::config;
+use crate::service::static_config::publish_subscribe::TypeDetails;
+use serde::{Deserialize, Serialize};
+
+/// The static configuration of a
+/// [`crate::service::messaging_pattern::MessagingPattern::RequestResponse`]
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`crate::service::Service`].
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_clients: usize,
+    pub(crate) max_servers: usize,
+    pub(crate) max_active_requests: usize,
+    pub(crate) max_responses_per_request: usize,
+    // the following entries are serialized as tables, therefore they must be the last entries
+    pub(crate) request_type_details: TypeDetails,
+    pub(crate) response_type_details: TypeDetails,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_clients: config.defaults.request_response.max_clients,
+            max_servers: config.defaults.request_response.max_servers,
+            max_active_requests: config.defaults.request_response.max_active_requests,
+            max_responses_per_request: config.defaults.request_response.max_responses_per_request,
+            request_type_details: TypeDetails::new::<()>(Layout::new::<()>()),
+            response_type_details: TypeDetails::new::<()>(Layout::new::<()>()),
+        }
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::client::Client`] ports
+    pub fn max_supported_clients(&self) -> usize {
+        self.max_clients
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::server::Server`] ports
+    pub fn max_supported_servers(&self) -> usize {
+        self.max_servers
+    }
+
+    /// Returns how many requests a [`crate::port::client::Client`] can loan or wait for in
+    /// parallel. Every [`crate::port::client::PendingResponse`] counts as one active request
+    /// until it goes out of scope.
+    pub fn max_active_requests(&self) -> usize {
+        self.max_active_requests
+    }
+
+    /// Returns how many responses a [`crate::port::server::Server`] can send for a single
+    /// request.
+    pub fn max_responses_per_request(&self) -> usize {
+        self.max_responses_per_request
+    }
+
+    /// Returns the [`TypeDetails`] of the request payload.
+    pub fn request_type_details(&self) -> &TypeDetails {
+        &self.request_type_details
+    }
+
+    /// Returns the [`TypeDetails`] of the response payload.
+    pub fn response_type_details(&self) -> &TypeDetails {
+        &self.response_type_details
+    }
+
+    /// The number of responses a [`crate::port::client::Client`] can hold in the buffer of a
+    /// single [`crate::port::server::Server`] connection, it covers all responses of all active
+    /// requests.
+    pub(crate) fn client_response_buffer_size(&self) -> usize {
+        self.max_active_requests * self.max_responses_per_request
+    }
+
+    /// A request stays in the data segment of the [`crate::port::client::Client`] while it is
+    /// loaned or pending and while a [`crate::port::server::Server`] holds it in its buffer or
+    /// as [`crate::port::server::ActiveRequest`], even after the client lost interest.
+    pub(crate) fn required_amount_of_requests_per_data_segment(&self) -> usize {
+        self.max_servers * 2 * self.max_active_requests + self.max_active_requests + 1
+    }
+
+    /// A response stays in the data segment of the [`crate::port::server::Server`] while it is
+    /// loaned and while a [`crate::port::client::Client`] holds it in its buffer or borrows it.
+    pub(crate) fn required_amount_of_responses_per_data_segment(
+        &self,
+        server_max_loaned_responses: usize,
+    ) -> usize {
+        self.max_clients * 2 * self.client_response_buffer_size() + server_max_loaned_responses + 1
+    }
+}
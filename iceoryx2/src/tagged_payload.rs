@@ -0,0 +1,103 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Message types that are sent rarely do not need a service of their own, they can share one
+//! service whose payload is a tagged union, an enum with a primitive representation like
+//! `#[repr(C, u32)]` whose variants contain the message types. The
+//! [`crate::port::publisher::Publisher`] sends a variant with
+//! [`crate::port::publisher::Publisher::send_variant()`], the
+//! [`crate::port::subscriber::Subscriber`] matches on the received payload.
+//!
+//! The discriminant of a received value was written by another process, therefore it is
+//! validated, together with the fields of the variant, before the sample is handed out. A
+//! sample with an unknown discriminant is discarded with
+//! [`crate::port::subscribe::SubscriberReceiveError::CorruptedSample`].
+//!
+//! # Memory
+//!
+//! Every sample has the size of the largest variant plus the discriminant and its padding,
+//! even when a small variant is sent. When the variants differ a lot in size, a separate
+//! service for the large ones uses less memory. The sample is still sent without a copy, a
+//! service with a few rarely sent variants is usually cheaper than one service per variant
+//! since every service acquires its own resources for the ports.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! #[derive(Debug, Clone, Copy, ZeroCopySend)]
+//! #[repr(C)]
+//! struct Start {
+//!     speed: u32,
+//! }
+//!
+//! #[derive(Debug, Clone, Copy, ZeroCopySend)]
+//! #[repr(C)]
+//! struct Shutdown {
+//!     delay_in_ms: u64,
+//! }
+//!
+//! #[derive(Debug, Clone, Copy, ZeroCopySend, TaggedPayload)]
+//! #[repr(C, u32)]
+//! enum ControlMessage {
+//!     Start(Start),
+//!     Shutdown(Shutdown),
+//!     Reset,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/Control")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe::<ControlMessage>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher().create()?;
+//! let subscriber = service.subscriber().create()?;
+//!
+//! publisher.send_variant(Start { speed: 12 })?;
+//! publisher.send_variant(ControlMessage::Reset)?;
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     match *sample {
+//!         ControlMessage::Start(start) => println!("start with {}", start.speed),
+//!         ControlMessage::Shutdown(shutdown) => println!("shutdown in {} ms", shutdown.delay_in_ms),
+//!         ControlMessage::Reset => println!("reset"),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+pub use iceoryx2_derive::TaggedPayload;
+
+use crate::payload_type::PayloadType;
+
+/// A tagged union that is used as payload, an enum whose discriminant has a primitive
+/// representation like `#[repr(C, u32)]`. It should be derived with
+/// [`TaggedPayload`](derive@TaggedPayload), which implements `From` for the type of every
+/// variant with exactly one unnamed field so that it can be sent with
+/// [`crate::port::publisher::Publisher::send_variant()`].
+///
+/// # Safety
+///
+///  * the type must be an enum with a primitive representation of the type
+///    [`TaggedPayload::Discriminant`]
+pub unsafe trait TaggedPayload: PayloadType + Sized {
+    /// The primitive representation of the discriminant.
+    type Discriminant: Debug + Copy + Eq;
+
+    /// Returns the discriminant of the variant.
+    fn discriminant(&self) -> Self::Discriminant;
+}
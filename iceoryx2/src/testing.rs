@@ -0,0 +1,211 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::testing::generate_isolated_config;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = generate_isolated_config();
+//!
+//! // the service is not visible to anyone that uses another prefix
+//! let service = zero_copy::Service::new(&ServiceName::new("My/Funk/ServiceName")?)
+//!     .publish_subscribe_with_custom_config(&config)
+//!     .create::<u64>()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "testing")]
+use std::sync::atomic::{AtomicU32, AtomicU8, Ordering};
+
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+
+use crate::config::Config;
+use crate::payload_mut::internal::PayloadMgmt;
+use crate::port::publish::internal::LoanRelease;
+use crate::sample::Sample;
+use crate::sample_mut::SampleMut;
+
+/// Returns a copy of the global [`Config`] with a unique [`crate::config::Global::prefix`].
+/// All services and nodes that are created with it are isolated from every other config, so
+/// tests that run in parallel cannot interfere with each other.
+pub fn generate_isolated_config() -> Config {
+    let id = fatal_panic!(from "generate_isolated_config()", when UniqueSystemId::new(),
+                "Unable to generate a unique prefix for an isolated config.");
+
+    // the prefix is part of every resource name, the names of some resources, like the unix
+    // datagram sockets of the connection monitors, are limited to 108 characters, therefore
+    // only 48 bits of the hashed id are used
+    let mut hasher = DefaultHasher::new();
+    id.value().hash(&mut hasher);
+
+    let mut config = Config::get_global_config().clone();
+    config.global.prefix = format!("iox2_{:012x}_", hasher.finish() & 0xffff_ffff_ffff);
+    config
+}
+
+/// Overrides the [`crate::service::header::publish_subscribe::Header::layout_version()`] of a
+/// [`SampleMut`] that was not yet sent to emulate a publisher that was built with another
+/// version of iceoryx2.
+pub fn set_header_layout_version<M: Debug + ?Sized, UserHeader: Debug>(
+    sample: &mut SampleMut<'_, M, UserHeader>,
+    version: u8,
+) {
+    sample.header_mut().set_layout_version(version);
+}
+
+/// Overrides the [`crate::service::header::publish_subscribe::Header::number_of_elements()`]
+/// of a [`SampleMut`] that was not yet sent to emulate a publisher that describes a payload
+/// which does not fit into its data segment.
+pub fn set_header_number_of_elements<M: Debug + ?Sized, UserHeader: Debug>(
+    sample: &mut SampleMut<'_, M, UserHeader>,
+    number_of_elements: u64,
+) {
+    sample
+        .header_mut()
+        .set_number_of_elements(number_of_elements);
+}
+
+/// Returns the chunk of a [`SampleMut`] to its [`crate::port::publisher::Publisher`] while the
+/// sample is still alive to emulate a chunk that is returned twice. In debug builds the next
+/// send or release of the sample panics.
+///
+/// # Safety
+///
+///  * the sample must neither be sent nor dropped afterwards in release builds, the chunk
+///    would be returned to the allocator a second time
+pub unsafe fn return_loaned_sample<M: Debug + ?Sized, UserHeader: Debug>(
+    sample: &SampleMut<'_, M, UserHeader>,
+) {
+    sample
+        .publisher
+        .return_loaned_sample(sample.offset_to_chunk(), LoanRelease::Cancelled);
+}
+
+/// Returns a received [`Sample`] to its [`crate::port::publisher::Publisher`] while the sample
+/// is still alive to emulate a subscriber that releases a sample twice. In debug builds the
+/// publisher panics when it retrieves the sample the second time.
+///
+/// # Safety
+///
+///  * the sample must not be dropped afterwards in release builds, the chunk would be returned
+///    to the allocator a second time
+pub unsafe fn return_received_sample<M: Debug + ?Sized, UserHeader: Debug>(
+    sample: &Sample<'_, M, UserHeader>,
+) {
+    let (channel_id, publisher_id, offset) = sample.handle.location();
+    sample
+        .subscriber
+        .release_sample(channel_id, publisher_id, offset);
+}
+
+/// The intermediate steps of the creation of a [`crate::service::Service`] at which
+/// [`abort_service_creation_at()`] terminates the process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ServiceCreationStep {
+    /// The static details are created but still locked.
+    StaticConfigCreated = 1,
+    /// The dynamic config is created but not yet initialized.
+    DynamicConfigInitialization = 2,
+    /// The dynamic config is initialized but the static details are still locked.
+    DynamicConfigCreated = 3,
+}
+
+#[cfg(feature = "testing")]
+static ABORT_SERVICE_CREATION_AT: AtomicU8 = AtomicU8::new(0);
+
+/// Terminates the process without any cleanup when the next service creation reaches the
+/// given [`ServiceCreationStep`] to emulate a process that crashes while creating a service.
+/// Requires the feature `testing`.
+#[cfg(feature = "testing")]
+pub fn abort_service_creation_at(step: ServiceCreationStep) {
+    ABORT_SERVICE_CREATION_AT.store(step as u8, Ordering::Relaxed);
+}
+
+#[cfg(feature = "testing")]
+pub(crate) fn abort_service_creation_if_requested(step: ServiceCreationStep) {
+    if ABORT_SERVICE_CREATION_AT.load(Ordering::Relaxed) == step as u8 {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(not(feature = "testing"))]
+pub(crate) fn abort_service_creation_if_requested(_step: ServiceCreationStep) {}
+
+/// The intermediate steps of the creation of a [`crate::port::publisher::Publisher`] or a
+/// [`crate::port::subscriber::Subscriber`] at which [`abort_port_creation_at()`] terminates
+/// the process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PortCreationStep {
+    /// The resources of the port, like the data segments and the listener, are created but
+    /// the port is not yet added to the dynamic config of the service.
+    ResourcesCreated = 1,
+    /// The port is added to the dynamic config of the service but its creation is not yet
+    /// completed.
+    AddedToDynamicConfig = 2,
+}
+
+#[cfg(feature = "testing")]
+static ABORT_PORT_CREATION_AT: AtomicU8 = AtomicU8::new(0);
+
+/// Terminates the process without any cleanup when the next port creation reaches the given
+/// [`PortCreationStep`] to emulate a process that crashes while creating a port.
+/// Requires the feature `testing`.
+#[cfg(feature = "testing")]
+pub fn abort_port_creation_at(step: PortCreationStep) {
+    ABORT_PORT_CREATION_AT.store(step as u8, Ordering::Relaxed);
+}
+
+#[cfg(feature = "testing")]
+pub(crate) fn abort_port_creation_if_requested(step: PortCreationStep) {
+    if ABORT_PORT_CREATION_AT.load(Ordering::Relaxed) == step as u8 {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(not(feature = "testing"))]
+pub(crate) fn abort_port_creation_if_requested(_step: PortCreationStep) {}
+
+#[cfg(feature = "testing")]
+static EMULATED_POINTER_WIDTH: AtomicU32 = AtomicU32::new(0);
+
+/// Records the given pointer width in bits in the
+/// [`crate::service::static_config::publish_subscribe::TypeDetails`] of every service that is
+/// created or opened afterwards to emulate a process that was compiled for another target.
+/// A value of 0 restores the actual pointer width. Requires the feature `testing`.
+#[cfg(feature = "testing")]
+pub fn emulate_pointer_width(bits: u32) {
+    EMULATED_POINTER_WIDTH.store(bits, Ordering::Relaxed);
+}
+
+#[cfg(feature = "testing")]
+pub(crate) fn pointer_width() -> u32 {
+    match EMULATED_POINTER_WIDTH.load(Ordering::Relaxed) {
+        0 => usize::BITS,
+        bits => bits,
+    }
+}
+
+#[cfg(not(feature = "testing"))]
+pub(crate) fn pointer_width() -> u32 {
+    usize::BITS
+}
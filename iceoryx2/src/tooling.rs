@@ -0,0 +1,530 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Records the [`Sample`]s of a service into a byte stream, like a file, and replays them
+//! later with the recorded time stamps and the recorded time between two samples. The payload
+//! is recorded as bytes, therefore the [`Recorder`] works with an untyped
+//! [`crate::port::subscriber::Subscriber`], see
+//! [`crate::service::builder::publish_subscribe::Builder::open_untyped()`], and the
+//! [`Replayer`] publishes with an untyped [`Publisher`]. The time stamps can only be replayed
+//! into a service that allows user time stamps, see
+//! [`crate::service::builder::publish_subscribe::Builder::allow_user_time_stamps()`].
+//!
+//! Every recorded [`Sample`] is stored as a [`RecordHeader`] that is followed by the payload
+//! bytes. All values are little endian.
+//!
+//! | size | field                                                      |
+//! |------|------------------------------------------------------------|
+//! | 4    | [`RECORD_MAGIC`]                                           |
+//! | 1    | [`RECORD_FORMAT_VERSION`]                                  |
+//! | 4    | length `n` of the service uuid, `u32`                      |
+//! | n    | service uuid, UTF-8                                        |
+//! | 4    | length `m` of the payload type name, `u32`                 |
+//! | m    | payload type name, UTF-8                                   |
+//! | 8    | payload type size, `u64`                                   |
+//! | 8    | payload type alignment, `u64`                              |
+//! | 4    | pointer width, `u32`                                       |
+//! | 4    | [`ClockType`] of the time stamp, `u32`                     |
+//! | 8    | time stamp seconds, `u64`                                  |
+//! | 4    | time stamp nanoseconds, `u32`                              |
+//! | 8    | length of the payload in bytes, `u64`                      |
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::tooling::{Recorder, Replayer};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/RecordedService")?;
+//! # let replay_service_name = ServiceName::new("My/Funk/ReplayTarget")?;
+//! # let _typed_service = zero_copy::Service::new(&service_name)
+//! #     .publish_subscribe()
+//! #     .open_or_create::<u64>()?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_untyped()?;
+//! let subscriber = service.subscriber().create_untyped()?;
+//!
+//! let mut recording = vec![];
+//! let mut recorder = Recorder::new(&mut recording, &service);
+//! while let Some(sample) = subscriber.receive()? {
+//!     recorder.record(&sample)?;
+//! }
+//!
+//! let replay_service = zero_copy::Service::new(&replay_service_name)
+//!     .publish_subscribe()
+//!     .allow_user_time_stamps(true)
+//!     .open_or_create::<u64>()?;
+//! let publisher = replay_service.publisher().create_untyped()?;
+//!
+//! let mut replayer = Replayer::new(recording.as_slice());
+//! while let Some(chunk) = replayer.next_chunk()? {
+//!     replayer.publish(&publisher, &chunk)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::io::{Read, Write};
+use std::time::{Duration, Instant};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::clock::{Time, TimeBuilder};
+
+use crate::payload_mut::PayloadMut;
+use crate::payload_type::PayloadType;
+use crate::port::publish::{PublisherLoanError, PublisherSendError};
+use crate::port::publisher::Publisher;
+use crate::sample::Sample;
+use crate::sample_mut::SampleMutSetTimeStampError;
+use crate::service;
+use crate::service::header::publish_subscribe::ClockType;
+use crate::service::port_factory::publish_subscribe::PortFactory;
+use crate::service::static_config::publish_subscribe::TypeDetails;
+
+/// The first bytes of every [`RecordHeader`].
+pub const RECORD_MAGIC: [u8; 4] = *b"IOXR";
+
+/// The version of the binary framing, it is incremented with every change of the layout of
+/// the [`RecordHeader`].
+pub const RECORD_FORMAT_VERSION: u8 = 1;
+
+// guards against allocating huge strings when a corrupted record is read
+const MAX_STRING_LENGTH: usize = 4096;
+
+/// Defines the failures that can occur when a [`Sample`] is recorded with
+/// [`Recorder::record()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecorderError {
+    /// The record could not be written into the underlying writer.
+    UnableToWrite,
+}
+
+impl std::fmt::Display for RecorderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RecorderError {}
+
+/// Defines the failures that can occur when a recording is read with
+/// [`Replayer::next_chunk()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReplayerError {
+    /// The underlying reader failed.
+    UnableToRead,
+    /// The recording ends in the middle of a record or contains a record that is no
+    /// [`RecordHeader`].
+    CorruptedRecord,
+    /// The record was written with another [`RECORD_FORMAT_VERSION`].
+    UnsupportedFormatVersion,
+}
+
+impl std::fmt::Display for ReplayerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ReplayerError {}
+
+/// Defines the failures that can occur when a [`RecordedChunk`] is published with
+/// [`Replayer::publish()`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ReplayerPublishError {
+    IncompatibleType,
+    UnableToLoan(PublisherLoanError),
+    UnableToSetTimeStamp(SampleMutSetTimeStampError),
+    UnableToSend(PublisherSendError),
+}
+
+impl From<PublisherLoanError> for ReplayerPublishError {
+    fn from(value: PublisherLoanError) -> Self {
+        ReplayerPublishError::UnableToLoan(value)
+    }
+}
+
+impl From<SampleMutSetTimeStampError> for ReplayerPublishError {
+    fn from(value: SampleMutSetTimeStampError) -> Self {
+        ReplayerPublishError::UnableToSetTimeStamp(value)
+    }
+}
+
+impl From<PublisherSendError> for ReplayerPublishError {
+    fn from(value: PublisherSendError) -> Self {
+        ReplayerPublishError::UnableToSend(value)
+    }
+}
+
+impl std::fmt::Display for ReplayerPublishError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ReplayerPublishError {}
+
+/// Describes a recorded [`Sample`], it precedes the payload bytes in the recording. The
+/// binary layout is described in the [module documentation](crate::tooling).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordHeader {
+    service_uuid: String,
+    type_details: TypeDetails,
+    clock_type: ClockType,
+    time_stamp: Time,
+    len: usize,
+}
+
+impl RecordHeader {
+    /// Returns the uuid of the service the [`Sample`] was received from.
+    pub fn service_uuid(&self) -> &str {
+        &self.service_uuid
+    }
+
+    /// Returns the [`TypeDetails`] of the payload type of the service.
+    pub fn type_details(&self) -> &TypeDetails {
+        &self.type_details
+    }
+
+    /// Returns the [`ClockType`] of [`RecordHeader::time_stamp()`].
+    pub fn clock_type(&self) -> ClockType {
+        self.clock_type
+    }
+
+    /// Returns the time stamp of the [`crate::service::header::publish_subscribe::Header`] of
+    /// the recorded [`Sample`].
+    pub fn time_stamp(&self) -> Time {
+        self.time_stamp
+    }
+
+    /// Returns the length of the payload in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true when the payload is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Writes the [`RecordHeader`] in its binary layout into the writer.
+    pub fn write<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&RECORD_MAGIC)?;
+        writer.write_all(&[RECORD_FORMAT_VERSION])?;
+        write_str(writer, &self.service_uuid)?;
+        write_str(writer, &self.type_details.type_name)?;
+        writer.write_all(&(self.type_details.size as u64).to_le_bytes())?;
+        writer.write_all(&(self.type_details.alignment as u64).to_le_bytes())?;
+        writer.write_all(&self.type_details.pointer_width.to_le_bytes())?;
+        writer.write_all(&(self.clock_type as u32).to_le_bytes())?;
+        writer.write_all(&self.time_stamp.seconds().to_le_bytes())?;
+        writer.write_all(&self.time_stamp.nanoseconds().to_le_bytes())?;
+        writer.write_all(&(self.len as u64).to_le_bytes())
+    }
+
+    /// Reads the next [`RecordHeader`] from the reader. Returns [`None`] when the reader
+    /// ends before the record starts.
+    pub fn read<R: Read>(reader: &mut R) -> Result<Option<Self>, ReplayerError> {
+        let origin = "RecordHeader::read()";
+        let msg = "Unable to read the record header";
+
+        let mut magic = [0u8; 4];
+        match read_until_end(reader, &mut magic) {
+            Ok(0) => return Ok(None),
+            Ok(n) if n == magic.len() => (),
+            Ok(_) => {
+                fail!(from origin, with ReplayerError::CorruptedRecord,
+                    "{} since the recording ends in the middle of it.", msg);
+            }
+            Err(e) => {
+                fail!(from origin, with ReplayerError::UnableToRead,
+                    "{} since the reader failed ({:?}).", msg, e);
+            }
+        }
+
+        if magic != RECORD_MAGIC {
+            fail!(from origin, with ReplayerError::CorruptedRecord,
+                "{} since the record starts with {:?} instead of {:?}.", msg, magic, RECORD_MAGIC);
+        }
+
+        let version = read_array::<1, _>(reader)?[0];
+        if version != RECORD_FORMAT_VERSION {
+            fail!(from origin, with ReplayerError::UnsupportedFormatVersion,
+                "{} since it was written with the format version {} but only the version {} is supported.",
+                msg, version, RECORD_FORMAT_VERSION);
+        }
+
+        let service_uuid = read_str(reader)?;
+        let type_name = read_str(reader)?;
+        let size = u64::from_le_bytes(read_array(reader)?) as usize;
+        let alignment = u64::from_le_bytes(read_array(reader)?) as usize;
+        let pointer_width = u32::from_le_bytes(read_array(reader)?);
+        let clock_type = match u32::from_le_bytes(read_array(reader)?) {
+            v if v == ClockType::Realtime as u32 => ClockType::Realtime,
+            v if v == ClockType::Monotonic as u32 => ClockType::Monotonic,
+            v => {
+                fail!(from origin, with ReplayerError::CorruptedRecord,
+                    "{} since it contains the unknown clock type {}.", msg, v);
+            }
+        };
+        let seconds = u64::from_le_bytes(read_array(reader)?);
+        let nanoseconds = u32::from_le_bytes(read_array(reader)?);
+        let len = u64::from_le_bytes(read_array(reader)?) as usize;
+
+        Ok(Some(Self {
+            service_uuid,
+            type_details: TypeDetails {
+                type_name,
+                size,
+                alignment,
+                pointer_width,
+                layout_hash: None,
+            },
+            clock_type,
+            time_stamp: TimeBuilder::new()
+                .clock_type(clock_type.into())
+                .seconds(seconds)
+                .nanoseconds(nanoseconds)
+                .create(),
+            len,
+        }))
+    }
+}
+
+/// A recorded [`Sample`] that was read with [`Replayer::next_chunk()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedChunk {
+    header: RecordHeader,
+    payload: Vec<u8>,
+}
+
+impl RecordedChunk {
+    /// Returns the [`RecordHeader`] of the [`RecordedChunk`].
+    pub fn header(&self) -> &RecordHeader {
+        &self.header
+    }
+
+    /// Returns the payload bytes, exactly as they were received.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+}
+
+/// Writes received [`Sample`]s of a service with their [`RecordHeader`] into a writer, like a
+/// [`std::fs::File`].
+#[derive(Debug)]
+pub struct Recorder<W: Write> {
+    writer: W,
+    service_uuid: String,
+    type_details: TypeDetails,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Creates a new [`Recorder`] for the [`Sample`]s of the service of the given
+    /// [`PortFactory`].
+    pub fn new<'config, Service: service::Details<'config>, Payload, UserHeader>(
+        writer: W,
+        service: &PortFactory<'config, Service, Payload, UserHeader>,
+    ) -> Self
+    where
+        Payload: PayloadType + ?Sized,
+        UserHeader: Debug + Default,
+    {
+        Self {
+            writer,
+            service_uuid: service.uuid().to_string(),
+            type_details: service.static_config().payload_type_details().clone(),
+        }
+    }
+
+    /// Records the payload bytes and the time stamp of the [`Sample`]. The user header is not
+    /// recorded.
+    pub fn record<MessageType: Debug + ?Sized, UserHeader: Debug>(
+        &mut self,
+        sample: &Sample<'_, MessageType, UserHeader>,
+    ) -> Result<(), RecorderError> {
+        let payload = sample.payload_as_bytes();
+        let header = RecordHeader {
+            service_uuid: self.service_uuid.clone(),
+            type_details: self.type_details.clone(),
+            clock_type: sample.header().clock_type(),
+            time_stamp: sample.header().time_stamp(),
+            len: payload.len(),
+        };
+
+        let result = header
+            .write(&mut self.writer)
+            .and_then(|_| self.writer.write_all(payload));
+        fail!(from "Recorder::record()", when result,
+            with RecorderError::UnableToWrite,
+            "Unable to record the sample since the writer failed.");
+
+        Ok(())
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> Result<(), RecorderError> {
+        fail!(from "Recorder::flush()", when self.writer.flush(),
+            with RecorderError::UnableToWrite,
+            "Unable to flush the recording since the writer failed.");
+        Ok(())
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads a recording that was written by a [`Recorder`] and publishes the
+/// [`RecordedChunk`]s with the recorded time between them.
+#[derive(Debug)]
+pub struct Replayer<R: Read> {
+    reader: R,
+    reference: Option<(Instant, Duration)>,
+}
+
+impl<R: Read> Replayer<R> {
+    /// Creates a new [`Replayer`] that reads the recording from the reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            reference: None,
+        }
+    }
+
+    /// Reads the next [`RecordedChunk`] without waiting. Returns [`None`] at the end of the
+    /// recording.
+    pub fn next_chunk(&mut self) -> Result<Option<RecordedChunk>, ReplayerError> {
+        let header = match RecordHeader::read(&mut self.reader)? {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+
+        let mut payload = Vec::new();
+        let result = (&mut self.reader)
+            .take(header.len as u64)
+            .read_to_end(&mut payload);
+        fail!(from "Replayer::next_chunk()", when result,
+            with ReplayerError::UnableToRead,
+            "Unable to read the payload of the record since the reader failed.");
+
+        if payload.len() != header.len {
+            fail!(from "Replayer::next_chunk()", with ReplayerError::CorruptedRecord,
+                "Unable to read the payload of the record since the recording ends after {} of {} bytes.",
+                payload.len(), header.len);
+        }
+
+        Ok(Some(RecordedChunk { header, payload }))
+    }
+
+    /// Publishes the [`RecordedChunk`] with its recorded time stamp. The first published
+    /// [`RecordedChunk`] is sent immediately, every following one is sent when the time that
+    /// passed since then matches the recorded time between their time stamps. The payload
+    /// type of the service must have the same layout as the recorded one, the type name is
+    /// not verified.
+    pub fn publish<'config, Service: service::Details<'config>, UserHeader: Debug + Default>(
+        &mut self,
+        publisher: &Publisher<'_, 'config, Service, [u8], UserHeader>,
+        chunk: &RecordedChunk,
+    ) -> Result<usize, ReplayerPublishError> {
+        let msg = "Unable to publish the recorded chunk";
+        if !chunk
+            .header
+            .type_details
+            .has_same_layout(publisher.payload_type_details())
+        {
+            fail!(from "Replayer::publish()", with ReplayerPublishError::IncompatibleType,
+                "{} since it contains the type {} but the service has the type {}.",
+                msg, chunk.header.type_details, publisher.payload_type_details());
+        }
+
+        let recorded_time = chunk.header.time_stamp.as_duration();
+        match self.reference {
+            None => self.reference = Some((Instant::now(), recorded_time)),
+            Some((start, first_recorded_time)) => {
+                let due = start + recorded_time.saturating_sub(first_recorded_time);
+                if let Some(remaining) = due.checked_duration_since(Instant::now()) {
+                    std::thread::sleep(remaining);
+                }
+            }
+        }
+
+        let sample = fail!(from "Replayer::publish()", when publisher.loan_slice_uninit(chunk.payload.len()),
+            "{} since the payload could not be loaned.", msg);
+        let mut sample = sample.write_from_slice(&chunk.payload);
+        fail!(from "Replayer::publish()", when sample.set_time_stamp(chunk.header.time_stamp),
+            "{} since the recorded time stamp could not be set.", msg);
+
+        Ok(fail!(from "Replayer::publish()", when sample.send(),
+            "{} since the sample could not be sent.", msg))
+    }
+}
+
+fn write_str<W: Write>(writer: &mut W, value: &str) -> std::io::Result<()> {
+    writer.write_all(&(value.len() as u32).to_le_bytes())?;
+    writer.write_all(value.as_bytes())
+}
+
+fn read_str<R: Read>(reader: &mut R) -> Result<String, ReplayerError> {
+    let len = u32::from_le_bytes(read_array(reader)?) as usize;
+    if len > MAX_STRING_LENGTH {
+        fail!(from "RecordHeader::read()", with ReplayerError::CorruptedRecord,
+            "Unable to read the record header since it contains a string with {} bytes.", len);
+    }
+
+    let mut value = vec![0u8; len];
+    read_exact(reader, &mut value)?;
+    Ok(
+        fail!(from "RecordHeader::read()", when String::from_utf8(value),
+        with ReplayerError::CorruptedRecord,
+        "Unable to read the record header since it contains a string that is not valid UTF-8."),
+    )
+}
+
+fn read_array<const N: usize, R: Read>(reader: &mut R) -> Result<[u8; N], ReplayerError> {
+    let mut value = [0u8; N];
+    read_exact(reader, &mut value)?;
+    Ok(value)
+}
+
+fn read_exact<R: Read>(reader: &mut R, buffer: &mut [u8]) -> Result<(), ReplayerError> {
+    let origin = "RecordHeader::read()";
+    match read_until_end(reader, buffer) {
+        Ok(n) if n == buffer.len() => Ok(()),
+        Ok(_) => {
+            fail!(from origin, with ReplayerError::CorruptedRecord,
+                "Unable to read the record header since the recording ends in the middle of it.");
+        }
+        Err(e) => {
+            fail!(from origin, with ReplayerError::UnableToRead,
+                "Unable to read the record header since the reader failed ({:?}).", e);
+        }
+    }
+}
+
+// fills the buffer until the reader ends and returns the number of bytes that were read
+fn read_until_end<R: Read>(reader: &mut R, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut position = 0;
+    while position < buffer.len() {
+        match reader.read(&mut buffer[position..]) {
+            Ok(0) => break,
+            Ok(n) => position += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => (),
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(position)
+}
@@ -0,0 +1,425 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::waitset::WaitSetBuilder;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let event = zero_copy::Service::new(&ServiceName::new("MyEventName")?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let mut listener = event.listener().create()?;
+//! let notifier = event.notifier().create()?;
+//!
+//! let waitset = WaitSetBuilder::new().create()?;
+//! let listener_guard = waitset.attach(&listener)?;
+//! let tick_guard = waitset.attach_interval(Duration::from_millis(100))?;
+//!
+//! notifier.notify()?;
+//!
+//! waitset.wait_and_process(|attachment_id| {
+//!     if attachment_id == listener_guard.id() {
+//!         println!("the listener received an event");
+//!     } else if attachment_id == tick_guard.id() {
+//!         println!("tick");
+//!     }
+//! })?;
+//!
+//! // detaches the listener from the waitset
+//! drop(listener_guard);
+//! for event_id in listener.try_wait()? {
+//!     println!("event was triggered with id: {:?}", event_id);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See also [`crate::waitset::WaitSet`]
+
+use std::cell::{Cell, RefCell};
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptor;
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_cal::reactor::{
+    posix_select, Reactor, ReactorAttachError, ReactorBuilder, ReactorWaitError,
+};
+
+type ReactorType = posix_select::Reactor;
+
+/// Failures that can occur when a [`WaitSet`] is created with [`WaitSetBuilder::create()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetCreateError {
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitSetCreateError {}
+
+/// Failures that can occur when an attachment is added to the [`WaitSet`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetAttachmentError {
+    InsufficientCapacity,
+    InvalidInterval,
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetAttachmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitSetAttachmentError {}
+
+/// Failures that can occur while the [`WaitSet`] waits for activity on its attachments.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetRunError {
+    InsufficientPermissions,
+    Interrupt,
+    NoAttachments,
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetRunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitSetRunError {}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+enum AttachmentIdType {
+    FileDescriptor(i32),
+    Interval(u64),
+}
+
+/// Identifies an attachment of the [`WaitSet`]. It is provided to the callback of
+/// [`WaitSet::wait_and_process()`] and can be compared with [`WaitSetGuard::id()`] to find out
+/// which attachment had activity.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub struct AttachmentId(AttachmentIdType);
+
+#[derive(Debug)]
+struct Interval {
+    id: u64,
+    period: Duration,
+    next_activation: Instant,
+}
+
+/// Represents an attachment of a [`WaitSet`]. When it goes out of scope the attachment is
+/// removed from the [`WaitSet`].
+#[derive(Debug)]
+pub struct WaitSetGuard<'waitset> {
+    waitset: &'waitset WaitSet,
+    id: AttachmentId,
+    // the reactor guard refers to the file descriptor and must be dropped first, therefore it
+    // must be declared before the file descriptor
+    _reactor_guard: Option<<ReactorType as Reactor>::Guard<'waitset, 'static>>,
+    _file_descriptor: Option<Box<FileDescriptor>>,
+}
+
+impl Drop for WaitSetGuard<'_> {
+    fn drop(&mut self) {
+        self.waitset.detach(self.id);
+    }
+}
+
+impl WaitSetGuard<'_> {
+    /// Returns the [`AttachmentId`] of the attachment.
+    pub fn id(&self) -> AttachmentId {
+        self.id
+    }
+}
+
+/// Creates a new [`WaitSet`].
+#[derive(Debug, Default)]
+pub struct WaitSetBuilder {}
+
+impl WaitSetBuilder {
+    /// Creates a new [`WaitSetBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the [`WaitSet`].
+    pub fn create(self) -> Result<WaitSet, WaitSetCreateError> {
+        let reactor = fail!(from self, when <ReactorType as Reactor>::Builder::new().create(),
+                with WaitSetCreateError::InternalError,
+                "Unable to create WaitSet since the underlying reactor could not be created.");
+
+        Ok(WaitSet {
+            reactor,
+            attachments: RefCell::new(vec![]),
+            intervals: RefCell::new(vec![]),
+            next_interval_id: Cell::new(0),
+        })
+    }
+}
+
+/// Waits on multiple attachments at once in a single blocking call. Attachments are
+/// [`crate::port::listener::Listener`]s whose underlying event concept is file descriptor based,
+/// like the ones of [`crate::service::zero_copy::Service`], external constructs that implement
+/// [`SynchronousMultiplexing`] and interval timers.
+///
+/// Since the [`crate::port::subscriber::Subscriber`] provides no file descriptor, a
+/// subscriber is usually paired with an event service whose
+/// [`crate::port::notifier::Notifier`] is triggered after a sample was sent.
+///
+/// # Notes
+///
+/// Every attachment is represented by a [`WaitSetGuard`] which borrows the [`WaitSet`].
+/// Dropping the guard detaches it.
+/// The [`WaitSet`] does not implement [`Sync`], therefore attachments can only be added or
+/// removed while no other thread waits on it.
+#[derive(Debug)]
+pub struct WaitSet {
+    reactor: ReactorType,
+    attachments: RefCell<Vec<AttachmentId>>,
+    intervals: RefCell<Vec<Interval>>,
+    next_interval_id: Cell<u64>,
+}
+
+impl WaitSet {
+    /// Returns the maximum number of file descriptor based attachments.
+    pub fn capacity(&self) -> usize {
+        ReactorType::capacity()
+    }
+
+    /// Returns the number of attachments, intervals included.
+    pub fn len(&self) -> usize {
+        self.attachments.borrow().len()
+    }
+
+    /// Returns true when nothing is attached to the [`WaitSet`], otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.attachments.borrow().is_empty()
+    }
+
+    /// Attaches a file descriptor based construct like a [`crate::port::listener::Listener`]
+    /// to the [`WaitSet`]. The attachment has activity whenever the underlying file
+    /// descriptor becomes readable.
+    ///
+    /// The [`WaitSet`] waits on a duplicate of the file descriptor, therefore the attachment
+    /// is not borrowed and a [`crate::port::listener::Listener`] can still be used mutably
+    /// to collect the events after a wakeup.
+    pub fn attach<T: SynchronousMultiplexing + Debug>(
+        &self,
+        attachment: &T,
+    ) -> Result<WaitSetGuard<'_>, WaitSetAttachmentError> {
+        let msg = "Unable to attach";
+        let file_descriptor = Box::new(attachment.file_descriptor().clone());
+        let id = AttachmentId(AttachmentIdType::FileDescriptor(unsafe {
+            file_descriptor.native_handle()
+        }));
+
+        // SAFETY: the file descriptor is owned by the guard and lives on the heap, the reactor
+        //         guard is dropped before the file descriptor
+        let file_descriptor_ref: &'static FileDescriptor =
+            unsafe { &*(file_descriptor.as_ref() as *const FileDescriptor) };
+
+        let reactor_guard = match self.reactor.attach(file_descriptor_ref) {
+            Ok(guard) => guard,
+            Err(ReactorAttachError::CapacityExceeded) => {
+                fail!(from self, with WaitSetAttachmentError::InsufficientCapacity,
+                    "{} {:?} since it would exceed the capacity of {} attachments.",
+                    msg, attachment, self.capacity());
+            }
+            Err(e) => {
+                fail!(from self, with WaitSetAttachmentError::InternalError,
+                    "{} {:?} since the underlying reactor failed ({:?}).", msg, attachment, e);
+            }
+        };
+
+        self.attachments.borrow_mut().push(id);
+
+        Ok(WaitSetGuard {
+            waitset: self,
+            id,
+            _reactor_guard: Some(reactor_guard),
+            _file_descriptor: Some(file_descriptor),
+        })
+    }
+
+    /// Attaches an interval timer to the [`WaitSet`]. The attachment has activity every time the
+    /// `interval` has passed. The first activation happens one `interval` after this call.
+    pub fn attach_interval(
+        &self,
+        interval: Duration,
+    ) -> Result<WaitSetGuard<'_>, WaitSetAttachmentError> {
+        if interval.is_zero() {
+            fail!(from self, with WaitSetAttachmentError::InvalidInterval,
+                "Unable to attach interval since an interval of zero would always be active.");
+        }
+
+        let interval_id = self.next_interval_id.get();
+        self.next_interval_id.set(interval_id + 1);
+
+        let id = AttachmentId(AttachmentIdType::Interval(interval_id));
+        self.intervals.borrow_mut().push(Interval {
+            id: interval_id,
+            period: interval,
+            next_activation: Instant::now() + interval,
+        });
+        self.attachments.borrow_mut().push(id);
+
+        Ok(WaitSetGuard {
+            waitset: self,
+            id,
+            _reactor_guard: None,
+            _file_descriptor: None,
+        })
+    }
+
+    /// Checks all attachments for activity without blocking and calls `fn_call` with the
+    /// [`AttachmentId`] of every attachment that had activity.
+    pub fn try_wait_and_process<F: FnMut(AttachmentId)>(
+        &self,
+        fn_call: F,
+    ) -> Result<(), WaitSetRunError> {
+        self.wait_impl(Some(Instant::now()), fn_call)
+    }
+
+    /// Blocks until either an attachment had activity or the `timeout` has passed. The
+    /// `fn_call` is called with the [`AttachmentId`] of every attachment that had activity.
+    pub fn timed_wait_and_process<F: FnMut(AttachmentId)>(
+        &self,
+        timeout: Duration,
+        fn_call: F,
+    ) -> Result<(), WaitSetRunError> {
+        self.wait_impl(Some(Instant::now() + timeout), fn_call)
+    }
+
+    /// Blocks until at least one attachment had activity and calls `fn_call` with the
+    /// [`AttachmentId`] of every attachment that had activity. Spurious wakeups are handled
+    /// internally and never invoke `fn_call`.
+    pub fn wait_and_process<F: FnMut(AttachmentId)>(
+        &self,
+        fn_call: F,
+    ) -> Result<(), WaitSetRunError> {
+        if self.is_empty() {
+            fail!(from self, with WaitSetRunError::NoAttachments,
+                "Unable to wait on WaitSet since it has no attachments and would block forever.");
+        }
+
+        self.wait_impl(None, fn_call)
+    }
+
+    fn detach(&self, id: AttachmentId) {
+        self.attachments.borrow_mut().retain(|v| *v != id);
+
+        if let AttachmentIdType::Interval(interval_id) = id.0 {
+            self.intervals.borrow_mut().retain(|v| v.id != interval_id);
+        }
+    }
+
+    fn next_interval_activation(&self) -> Option<Instant> {
+        self.intervals
+            .borrow()
+            .iter()
+            .map(|v| v.next_activation)
+            .min()
+    }
+
+    fn collect_due_intervals(&self, triggered: &mut Vec<AttachmentId>) {
+        let now = Instant::now();
+        for interval in self.intervals.borrow_mut().iter_mut() {
+            if interval.next_activation <= now {
+                triggered.push(AttachmentId(AttachmentIdType::Interval(interval.id)));
+
+                // missed activations are merged into a single one
+                while interval.next_activation <= now {
+                    interval.next_activation += interval.period;
+                }
+            }
+        }
+    }
+
+    fn wait_impl<F: FnMut(AttachmentId)>(
+        &self,
+        deadline: Option<Instant>,
+        mut fn_call: F,
+    ) -> Result<(), WaitSetRunError> {
+        let msg = "Unable to wait on WaitSet";
+        let mut triggered = vec![];
+
+        loop {
+            let wakeup = match (deadline, self.next_interval_activation()) {
+                (Some(d), Some(i)) => Some(std::cmp::min(d, i)),
+                (d, i) => d.or(i),
+            };
+
+            let push_fd = |fd: &FileDescriptor| {
+                triggered.push(AttachmentId(AttachmentIdType::FileDescriptor(unsafe {
+                    fd.native_handle()
+                })))
+            };
+
+            let result = match wakeup {
+                Some(wakeup) => self
+                    .reactor
+                    .timed_wait(push_fd, wakeup.saturating_duration_since(Instant::now())),
+                None => self.reactor.blocking_wait(push_fd),
+            };
+
+            match result {
+                Ok(()) => (),
+                Err(ReactorWaitError::Interrupt) => {
+                    fail!(from self, with WaitSetRunError::Interrupt,
+                        "{} since an interrupt signal was received.", msg);
+                }
+                Err(ReactorWaitError::InsufficientPermissions) => {
+                    fail!(from self, with WaitSetRunError::InsufficientPermissions,
+                        "{} due to insufficient permissions.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with WaitSetRunError::InternalError,
+                        "{} since the underlying reactor failed ({:?}).", msg, e);
+                }
+            }
+
+            self.collect_due_intervals(&mut triggered);
+
+            let deadline_reached = match deadline {
+                Some(d) => d <= Instant::now(),
+                None => false,
+            };
+
+            if !triggered.is_empty() || deadline_reached {
+                break;
+            }
+        }
+
+        for id in triggered {
+            // the callback may have removed an attachment that had activity in the same round
+            if self.attachments.borrow().contains(&id) {
+                fn_call(id);
+            }
+        }
+
+        Ok(())
+    }
+}
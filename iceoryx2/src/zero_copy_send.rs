@@ -0,0 +1,54 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Payloads are shared between processes and must not refer to memory outside of the payload
+//! itself, a `String` or a `Vec` would hand over pointers into the heap of the sending process.
+//! Only types that implement [`ZeroCopySend`] can be used as payload of a
+//! [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+//! based service.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! #[derive(Debug, ZeroCopySend)]
+//! #[repr(u8)]
+//! enum Gear {
+//!     Park,
+//!     Drive { speed: u32 },
+//! }
+//!
+//! #[derive(Debug, ZeroCopySend)]
+//! #[repr(C)]
+//! struct TransmissionData {
+//!     x: i32,
+//!     y: i32,
+//!     funky: f64,
+//!     gear: Gear,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_or_create::<TransmissionData>()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The trait and its derive are defined in the `no_std` capable `iceoryx2_core` crate so that
+//! payload definitions can be shared with targets without `std`.
+
+// the derive macro shares the name with the trait and is re-exported with it, the derived
+// implementations refer to the layout hash functions via this module
+pub use iceoryx2_core::zero_copy_send::{layout_hash, ZeroCopySend};
@@ -0,0 +1,123 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::advanced::{PointerOffset, SegmentOffset};
+use iceoryx2_bb_testing::assert_that;
+
+#[test]
+fn segment_offset_survives_the_conversion_into_a_pointer_offset() {
+    let sut = SegmentOffset::new(3, 0x1234);
+    let converted = SegmentOffset::from(PointerOffset::from(sut));
+
+    assert_that!(converted, eq sut);
+    assert_that!(converted.segment_id(), eq 3);
+    assert_that!(converted.offset(), eq 0x1234);
+    assert_that!(converted.address_in(0x1000), eq 0x2234);
+}
+
+#[generic_tests::define]
+mod advanced {
+    use iceoryx2::advanced::{self, DataSegmentView, Header, RawSample, SegmentOffset};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::{Details, Service};
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[derive(Debug, Clone, Copy, PartialEq, ZeroCopySend)]
+    #[repr(C)]
+    struct Snapshot {
+        value: u64,
+        other: u32,
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "advanced_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn mapped_data_segment_contains_the_loaned_sample<Sut: Service + Details<'static>>() {
+        const PAYLOAD_ALIGNMENT: usize = 64;
+        let config = generate_isolated_config();
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .payload_alignment(Alignment::new(PAYLOAD_ALIGNMENT).unwrap())
+            .create::<Snapshot>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+
+        let sut = DataSegmentView::<Sut>::open(&config, &publisher.data_segment_name()).unwrap();
+        let payload_offset = advanced::payload_offset::<()>(PAYLOAD_ALIGNMENT);
+        assert_that!(payload_offset % PAYLOAD_ALIGNMENT, eq 0);
+
+        for n in 0..4 {
+            let payload = Snapshot {
+                value: n * 1234,
+                other: n as u32,
+            };
+            let sample = publisher.loan_uninit().unwrap().write_payload(payload);
+            let offset = SegmentOffset::from(advanced::offset_of(&sample));
+            assert_that!(offset.segment_id(), eq publisher.segment_id());
+
+            let address = sut.address_of(offset).unwrap();
+            let snapshot = unsafe {
+                RawSample::<Header, (), Snapshot>::from_header_and_payload_ptrs(
+                    address as *const _,
+                    (address + payload_offset) as *const Snapshot,
+                )
+            };
+            assert_that!(*snapshot.as_data_ref(), eq payload);
+            assert_that!(snapshot.as_header_ref().publisher_id(), eq publisher.id());
+
+            assert_that!(sample.send(), eq Ok(1));
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq payload);
+        }
+    }
+
+    #[test]
+    fn mapped_data_segment_rejects_offsets_outside_of_it<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+
+        let sut = DataSegmentView::<Sut>::open(&config, &publisher.data_segment_name()).unwrap();
+        let size = sut.allocator_data_size();
+        let header_size = core::mem::size_of::<Header>();
+        assert_that!(sut.address_of(SegmentOffset::new(0, size)), is_none);
+        assert_that!(
+            sut.address_of(SegmentOffset::new(0, size - header_size + 1)),
+            is_none
+        );
+        assert_that!(
+            sut.address_of(SegmentOffset::new(0, size - header_size)),
+            eq Some(sut.allocator_data_start_address() + size - header_size)
+        );
+        assert_that!(
+            sut.address_of(SegmentOffset::new(0, 0)),
+            eq Some(sut.allocator_data_start_address())
+        );
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
@@ -0,0 +1,176 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "async")]
+
+use std::time::Duration;
+
+use iceoryx2::port::subscribe::SubscriberWaitError;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_testing::assert_that;
+
+const TIMEOUT: Duration = Duration::from_millis(25);
+
+// the services are opened in spawned threads that cannot borrow an isolated config, therefore
+// the tests use the global config, the unique service names keep them apart
+fn generate_name() -> ServiceName {
+    ServiceName::new(&format!(
+        "async_tests_{}",
+        UniqueSystemId::new().unwrap().value()
+    ))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn listener_wait_async_returns_pending_events_immediately() {
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .event()
+        .create()
+        .unwrap();
+
+    let mut listener = sut.listener().create().unwrap();
+    let notifier = sut.notifier().create().unwrap();
+
+    assert_that!(notifier.notify_with_custom_event_id(EventId::new(3)), is_ok);
+    assert_that!(notifier.notify_with_custom_event_id(EventId::new(5)), is_ok);
+
+    let events = listener.wait_async().await.unwrap().to_vec();
+    assert_that!(events, len 2);
+    assert_that!(events, contains EventId::new(3));
+    assert_that!(events, contains EventId::new(5));
+}
+
+#[tokio::test]
+async fn listener_wait_async_wakes_up_when_notified() {
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .event()
+        .create()
+        .unwrap();
+
+    let mut listener = sut.listener().create().unwrap();
+
+    let notifier_thread = std::thread::spawn(move || {
+        let service = zero_copy::Service::new(&service_name)
+            .event()
+            .open()
+            .unwrap();
+        let notifier = service.notifier().create().unwrap();
+        std::thread::sleep(TIMEOUT);
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(9)), is_ok);
+    });
+
+    let events = listener.wait_async().await.unwrap().to_vec();
+    assert_that!(events, eq vec![EventId::new(9)]);
+    notifier_thread.join().unwrap();
+}
+
+#[tokio::test]
+async fn dropped_listener_wait_async_future_does_not_lose_events() {
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .event()
+        .create()
+        .unwrap();
+
+    let mut listener = sut.listener().create().unwrap();
+    let notifier = sut.notifier().create().unwrap();
+
+    let result = tokio::time::timeout(TIMEOUT, listener.wait_async()).await;
+    assert_that!(result, is_err);
+
+    assert_that!(notifier.notify_with_custom_event_id(EventId::new(4)), is_ok);
+    assert_that!(listener.try_wait().unwrap(), eq & [EventId::new(4)]);
+}
+
+#[tokio::test]
+async fn subscriber_receive_async_fails_without_notifications() {
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .publish_subscribe()
+        .create::<u64>()
+        .unwrap();
+
+    let subscriber = sut.subscriber().create().unwrap();
+
+    assert_that!(subscriber.receive_async().await.err(), eq Some(SubscriberWaitError::NotificationsNotEnabled));
+}
+
+#[tokio::test]
+async fn subscriber_receive_async_wakes_up_when_a_sample_is_sent() {
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .publish_subscribe()
+        .enable_notifications(true)
+        .create::<u64>()
+        .unwrap();
+    let subscriber = sut.subscriber().create().unwrap();
+
+    let (publisher_created_tx, publisher_created_rx) = std::sync::mpsc::channel();
+    let (sample_received_tx, sample_received_rx) = std::sync::mpsc::channel::<()>();
+    let publisher_thread = std::thread::spawn(move || {
+        let service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+        publisher_created_tx.send(()).unwrap();
+
+        std::thread::sleep(TIMEOUT);
+        assert_that!(publisher.send_copy(42), eq Ok(1));
+
+        // keep the publisher alive until the sample was received
+        sample_received_rx.recv().unwrap();
+    });
+
+    publisher_created_rx.recv().unwrap();
+    let sample = subscriber.receive_async().await.unwrap();
+    assert_that!(*sample.unwrap(), eq 42);
+    sample_received_tx.send(()).unwrap();
+    publisher_thread.join().unwrap();
+}
+
+#[tokio::test]
+async fn subscriber_receive_async_returns_none_without_publishers() {
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .publish_subscribe()
+        .enable_notifications(true)
+        .create::<u64>()
+        .unwrap();
+
+    let subscriber = sut.subscriber().create().unwrap();
+
+    assert_that!(subscriber.receive_async().await.unwrap(), is_none);
+}
+
+#[tokio::test]
+async fn dropped_subscriber_receive_async_future_does_not_lose_samples() {
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .publish_subscribe()
+        .enable_notifications(true)
+        .create::<u64>()
+        .unwrap();
+
+    let subscriber = sut.subscriber().create().unwrap();
+    let publisher = sut.publisher().create().unwrap();
+
+    let result = tokio::time::timeout(TIMEOUT, subscriber.receive_async()).await;
+    assert_that!(result, is_err);
+
+    assert_that!(publisher.send_copy(1234), eq Ok(1));
+    let sample = subscriber.receive().unwrap();
+    assert_that!(*sample.unwrap(), eq 1234);
+}
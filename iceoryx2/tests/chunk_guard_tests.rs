@@ -0,0 +1,212 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// the chunks are only guarded in debug builds
+#[cfg(debug_assertions)]
+#[generic_tests::define]
+mod chunk_guard {
+    use std::mem::MaybeUninit;
+
+    use iceoryx2::payload_mut::{PayloadMut, UninitPayloadMut};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::header::publish_subscribe::Header;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::{
+        generate_isolated_config, return_loaned_sample, return_received_sample,
+    };
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const POISON_PATTERN: u8 = 0xDE;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "chunk_guard_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn released_sample_is_poisoned<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+
+        let sample = publisher.loan_uninit().unwrap();
+        let mut sample = sample.write_payload(0x0123_4567_89AB_CDEF);
+        let payload = sample.payload_mut() as *mut u64 as *const u8;
+        sample.cancel();
+
+        // the data segment is still mapped by the publisher
+        let payload = unsafe { std::slice::from_raw_parts(payload, core::mem::size_of::<u64>()) };
+        assert_that!(payload.iter().all(|b| *b == POISON_PATTERN), eq true);
+    }
+
+    #[test]
+    fn sample_that_is_received_by_a_subscriber_is_poisoned_after_its_last_release<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .history_size(0)
+            .create::<u64>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_copy(0x0123_4567_89AB_CDEF), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        let payload = &*sample as *const u64 as *const u8;
+        assert_that!(*sample, eq 0x0123_4567_89AB_CDEF);
+        drop(sample);
+
+        // the returned chunk is released with the next loan
+        let _sample = publisher.loan_uninit().unwrap();
+        let payload = unsafe { std::slice::from_raw_parts(payload, core::mem::size_of::<u64>()) };
+        assert_that!(payload.iter().all(|b| *b == POISON_PATTERN), eq true);
+    }
+
+    #[test]
+    #[should_panic(expected = "is returned twice")]
+    fn sample_that_is_returned_twice_panics<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+
+        let sample = publisher.loan().unwrap();
+        unsafe { return_loaned_sample(&sample) };
+        drop(sample);
+    }
+
+    #[test]
+    #[should_panic(expected = "is sent although it is not loaned")]
+    fn sample_that_is_sent_after_it_was_returned_panics<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+        let _subscriber = service.subscriber().create().unwrap();
+
+        let sample = publisher.loan().unwrap();
+        unsafe { return_loaned_sample(&sample) };
+        let _ = sample.send();
+    }
+
+    #[test]
+    #[should_panic(expected = "the payload was overrun")]
+    fn slice_payload_overrun_is_detected_on_release<Sut: Service>() {
+        let config = generate_isolated_config();
+        const MAX_SLICE_LEN: usize = 8;
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(MAX_SLICE_LEN)
+            .create::<[u64]>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+
+        let mut sample = publisher.loan_slice_uninit(2).unwrap();
+        // the chunk is large enough for MAX_SLICE_LEN elements, the write stays inside of it
+        unsafe {
+            sample
+                .payload_mut()
+                .as_mut_ptr()
+                .add(2)
+                .write(MaybeUninit::new(1234));
+        }
+        sample.cancel();
+    }
+
+    #[test]
+    #[should_panic(expected = "the memory in front of the sample was overwritten")]
+    fn corrupted_memory_in_front_of_the_sample_is_detected_on_release<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+
+        let sample = publisher.loan_uninit().unwrap();
+        // the canary is placed directly in front of the header of the sample
+        unsafe {
+            (sample.header() as *const Header as *mut u8)
+                .sub(1)
+                .write(0);
+        }
+        sample.cancel();
+    }
+
+    #[test]
+    #[should_panic(expected = "is released more often than it was referenced")]
+    fn received_sample_that_is_returned_twice_panics<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .history_size(0)
+            .create::<u64>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        assert_that!(publisher.send_copy(5678), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        unsafe { return_received_sample(&sample) };
+        // the connection accepts the second release since another sample is borrowed
+        let other_sample = subscriber.receive().unwrap().unwrap();
+        drop(sample);
+        // the connection considers the other sample as returned already, it must not be
+        // returned again while the test unwinds
+        std::mem::forget(other_sample);
+        std::mem::forget(subscriber);
+
+        // the publisher retrieves the returned samples with the next loan
+        let _ = publisher.loan_uninit();
+    }
+
+    #[test]
+    fn slice_payload_that_stays_within_its_length_is_released<Sut: Service>() {
+        let config = generate_isolated_config();
+        const MAX_SLICE_LEN: usize = 8;
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(MAX_SLICE_LEN)
+            .create::<[u64]>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+
+        for number_of_elements in 1..=MAX_SLICE_LEN {
+            let payload: Vec<u64> = (0..number_of_elements as u64).collect();
+            let sample = publisher.loan_slice_uninit(number_of_elements).unwrap();
+            assert_that!(sample.write_from_slice(&payload).send(), eq Ok(1));
+            let received = subscriber.receive().unwrap().unwrap();
+            assert_that!(received.payload(), eq payload.as_slice());
+        }
+
+        assert_that!(publisher.statistics().loaned_samples(), eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
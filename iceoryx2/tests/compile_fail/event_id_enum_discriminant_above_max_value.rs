@@ -0,0 +1,10 @@
+use iceoryx2::prelude::*;
+
+#[derive(Debug, EventIdEnum)]
+#[event_id(max_value = 16)]
+enum CameraEvent {
+    FrameReady = 1,
+    CameraFailure = 17,
+}
+
+fn main() {}
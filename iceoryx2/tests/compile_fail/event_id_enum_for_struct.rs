@@ -0,0 +1,8 @@
+use iceoryx2::prelude::*;
+
+#[derive(Debug, EventIdEnum)]
+struct CameraEvent {
+    id: u64,
+}
+
+fn main() {}
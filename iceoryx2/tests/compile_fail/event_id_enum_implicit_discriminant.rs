@@ -0,0 +1,9 @@
+use iceoryx2::prelude::*;
+
+#[derive(Debug, EventIdEnum)]
+enum CameraEvent {
+    FrameReady = 1,
+    CameraFailure,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use iceoryx2::prelude::*;
+
+#[derive(Debug, EventIdEnum)]
+#[repr(i8)]
+enum CameraEvent {
+    FrameReady = 1,
+    CameraFailure = -1,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use iceoryx2::prelude::*;
+
+#[derive(Debug, EventIdEnum)]
+#[repr(u8)]
+enum CameraEvent {
+    FrameReady(u32) = 1,
+    CameraFailure = 2,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Every file in tests/compile_fail must be rejected with the error message in the .stderr file
+// next to it. After an intended change of a message the files are regenerated with
+// TRYBUILD=overwrite.
+#[test]
+fn event_id_enum_derive_rejects_invalid_enums() {
+    let sut = trybuild::TestCases::new();
+    sut.compile_fail("tests/compile_fail/event_id_enum_*.rs");
+}
@@ -0,0 +1,95 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::config::{Config, ConfigCreationError};
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_testing::assert_that;
+
+fn fixture(name: &str) -> FilePath {
+    let path = format!("{}/tests/config/{}", env!("CARGO_MANIFEST_DIR"), name);
+    FilePath::new(path.as_bytes()).unwrap()
+}
+
+#[test]
+fn config_file_overrides_only_the_defined_entries() {
+    let sut = Config::from_file(&fixture("custom_buffer_size.toml")).unwrap();
+    let default_config = Config::default();
+
+    assert_that!(sut.global.prefix, eq "config_tests_");
+    assert_that!(sut.defaults.publish_subscribe.subscriber_max_buffer_size, eq 17);
+    assert_that!(
+        sut.defaults.publish_subscribe.max_publishers,
+        eq default_config.defaults.publish_subscribe.max_publishers
+    );
+    assert_that!(sut.global.service.directory, eq default_config.global.service.directory);
+    assert_that!(sut.global.root_path(), eq default_config.global.root_path());
+}
+
+#[test]
+fn config_file_with_invalid_entry_type_fails() {
+    let sut = Config::from_file(&fixture("invalid_type.toml"));
+
+    assert_that!(sut.err().unwrap(), eq ConfigCreationError::UnableToDeserializeContents);
+}
+
+#[test]
+fn config_file_with_zero_buffer_size_fails() {
+    let sut = Config::from_file(&fixture("zero_buffer_size.toml"));
+
+    assert_that!(sut.err().unwrap(), eq ConfigCreationError::UnableToDeserializeContents);
+}
+
+#[test]
+fn default_config_file_matches_default_config() {
+    let config_file = format!("{}/../config/iceoryx2.toml", env!("CARGO_MANIFEST_DIR"));
+    let sut = Config::from_file(&FilePath::new(config_file.as_bytes()).unwrap()).unwrap();
+    let default_config = Config::default();
+
+    assert_that!(
+        sut.defaults.publish_subscribe.subscriber_max_buffer_size,
+        eq default_config.defaults.publish_subscribe.subscriber_max_buffer_size
+    );
+    assert_that!(sut.global.prefix, eq default_config.global.prefix);
+}
+
+#[generic_tests::define]
+mod config_file {
+    use super::fixture;
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn service_uses_buffer_size_from_config_file<Sut: Service>() {
+        let config = Config::from_file(&fixture("custom_buffer_size.toml")).unwrap();
+        let service_name = ServiceName::new(&format!(
+            "config_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 17);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
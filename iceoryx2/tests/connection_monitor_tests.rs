@@ -0,0 +1,246 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod connection_monitor {
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::port::connection_monitor::{ConnectionTransition, WaitForConnectionsError};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "connection_monitor_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn publisher_counts_the_subscribers_of_the_service<Sut: Service>() {
+        let config = generate_isolated_config();
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.number_of_connected_subscribers(), eq 0);
+        assert_that!(publisher.has_subscribers(), eq false);
+
+        let subscriber_1 = sut.subscriber().create().unwrap();
+        let subscriber_2 = sut.subscriber().create().unwrap();
+        assert_that!(publisher.number_of_connected_subscribers(), eq 2);
+        assert_that!(publisher.has_subscribers(), eq true);
+
+        drop(subscriber_1);
+        assert_that!(publisher.number_of_connected_subscribers(), eq 1);
+        drop(subscriber_2);
+        assert_that!(publisher.has_subscribers(), eq false);
+
+        let publisher = publisher.into_sync();
+        let _subscriber = sut.subscriber().create().unwrap();
+        assert_that!(publisher.number_of_connected_subscribers(), eq 1);
+        assert_that!(publisher.has_subscribers(), eq true);
+    }
+
+    #[test]
+    fn subscriber_counts_the_publishers_of_the_service<Sut: Service>() {
+        let config = generate_isolated_config();
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .max_publishers(2)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(subscriber.number_of_connected_publishers(), eq 0);
+        assert_that!(subscriber.has_publishers(), eq false);
+
+        let publisher_1 = sut.publisher().create().unwrap();
+        let publisher_2 = sut.publisher().create().unwrap();
+        assert_that!(subscriber.number_of_connected_publishers(), eq 2);
+        assert_that!(subscriber.has_publishers(), eq true);
+
+        drop(publisher_1);
+        drop(publisher_2);
+        assert_that!(subscriber.number_of_connected_publishers(), eq 0);
+        assert_that!(subscriber.has_publishers(), eq false);
+    }
+
+    #[test]
+    fn publisher_monitor_reports_transitions_between_zero_and_non_zero<Sut: Service>() {
+        let config = generate_isolated_config();
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let mut monitor = publisher.connection_monitor().unwrap();
+        assert_that!(monitor.is_connected(), eq false);
+        assert_that!(monitor.try_wait(), eq Ok(None));
+
+        let subscriber_1 = sut.subscriber().create().unwrap();
+        assert_that!(monitor.timed_wait(TIMEOUT), eq Ok(Some(ConnectionTransition::Connected)));
+        assert_that!(monitor.is_connected(), eq true);
+
+        // only the transitions between zero and non-zero are reported
+        let subscriber_2 = sut.subscriber().create().unwrap();
+        assert_that!(monitor.timed_wait(TIMEOUT), eq Ok(None));
+        drop(subscriber_1);
+        assert_that!(monitor.timed_wait(TIMEOUT), eq Ok(None));
+
+        drop(subscriber_2);
+        assert_that!(monitor.blocking_wait(), eq Ok(ConnectionTransition::Disconnected));
+        assert_that!(monitor.is_connected(), eq false);
+    }
+
+    #[test]
+    fn subscriber_monitor_reports_transitions_between_zero_and_non_zero<Sut: Service>() {
+        let config = generate_isolated_config();
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+        let mut monitor = subscriber.connection_monitor().unwrap();
+        assert_that!(monitor.is_connected(), eq true);
+
+        drop(publisher);
+        assert_that!(monitor.timed_wait(TIMEOUT), eq Ok(Some(ConnectionTransition::Disconnected)));
+
+        let _publisher = sut.publisher().create().unwrap();
+        assert_that!(monitor.try_wait(), eq Ok(Some(ConnectionTransition::Connected)));
+    }
+
+    #[test]
+    fn monitor_is_not_woken_up_by_ports_of_other_services<Sut: Service>() {
+        let config = generate_isolated_config();
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let other_sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let mut monitor = publisher.connection_monitor().unwrap();
+
+        let _subscriber = other_sut.subscriber().create().unwrap();
+        assert_that!(monitor.timed_wait(TIMEOUT), eq Ok(None));
+        assert_that!(publisher.has_subscribers(), eq false);
+    }
+
+    #[test]
+    fn wait_for_counterparts_returns_immediately_when_they_are_connected<Sut: Service>() {
+        let config = generate_isolated_config();
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber_1 = sut.subscriber().create().unwrap();
+        let _subscriber_2 = sut.subscriber().create().unwrap();
+
+        let start = Instant::now();
+        assert_that!(publisher.wait_for_subscribers(2, Duration::from_secs(10)), eq Ok(2));
+        assert_that!(publisher.wait_for_subscribers(0, Duration::ZERO), eq Ok(2));
+        assert_that!(subscriber_1.wait_for_publishers(1, Duration::from_secs(10)), eq Ok(1));
+        assert_that!(start.elapsed(), lt Duration::from_secs(10));
+    }
+
+    #[test]
+    fn wait_for_counterparts_times_out_when_they_do_not_connect<Sut: Service>() {
+        let config = generate_isolated_config();
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let start = Instant::now();
+        assert_that!(
+            publisher.wait_for_subscribers(2, TIMEOUT),
+            eq Err(WaitForConnectionsError::Timeout)
+        );
+        assert_that!(start.elapsed(), ge TIMEOUT);
+
+        drop(publisher);
+        assert_that!(
+            subscriber.wait_for_publishers(1, TIMEOUT),
+            eq Err(WaitForConnectionsError::Timeout)
+        );
+    }
+
+    #[test]
+    fn wait_for_counterparts_is_woken_up_when_they_connect<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_publishers(2)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let sut = Sut::new(&service_name)
+                    .publish_subscribe_with_custom_config(&config)
+                    .open::<u64>()
+                    .unwrap();
+                std::thread::sleep(TIMEOUT);
+                let _subscriber = sut.subscriber().create().unwrap();
+                let _publisher = sut.publisher().create().unwrap();
+                std::thread::sleep(TIMEOUT * 4);
+            });
+
+            assert_that!(publisher.wait_for_subscribers(2, Duration::from_secs(10)), eq Ok(2));
+            assert_that!(subscriber.wait_for_publishers(2, Duration::from_secs(10)), eq Ok(2));
+        });
+    }
+
+    #[test]
+    fn sync_publisher_does_not_block_other_threads_while_waiting_for_subscribers<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap().into_sync();
+
+        std::thread::scope(|s| {
+            let waiter = s.spawn(|| publisher.wait_for_subscribers(1, Duration::from_secs(10)));
+
+            std::thread::sleep(TIMEOUT);
+            assert_that!(publisher.send_copy(1234), eq Ok(0));
+            let _subscriber = sut.subscriber().create().unwrap();
+
+            assert_that!(waiter.join().unwrap(), eq Ok(1));
+        });
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
@@ -0,0 +1,197 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+// the tests run in parallel, only the allocations of the thread that is currently counting
+// are recorded
+thread_local! {
+    static IS_COUNTING: Cell<bool> = const { Cell::new(false) };
+    static NUMBER_OF_ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if IS_COUNTING.with(|v| v.get()) {
+            NUMBER_OF_ALLOCATIONS.with(|v| v.set(v.get() + 1));
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if IS_COUNTING.with(|v| v.get()) {
+            NUMBER_OF_ALLOCATIONS.with(|v| v.set(v.get() + 1));
+        }
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count_allocations<F: FnOnce()>(f: F) -> usize {
+    NUMBER_OF_ALLOCATIONS.with(|v| v.set(0));
+    IS_COUNTING.with(|v| v.set(true));
+    f();
+    IS_COUNTING.with(|v| v.set(false));
+    NUMBER_OF_ALLOCATIONS.with(|v| v.get())
+}
+
+#[generic_tests::define]
+mod deterministic_memory {
+    use iceoryx2::payload_mut::UninitPayloadMut;
+    use iceoryx2::port::publish::PublisherCreateError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::publisher::AllocationStrategy;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    use super::count_allocations;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "deterministic_memory_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn loan_send_and_receive_do_not_allocate<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .enable_deterministic_memory(true)
+            .subscriber_max_borrowed_samples(2)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+        publisher.update_connections().unwrap();
+
+        // the first round trip establishes the connections
+        publisher.send_copy(0).unwrap();
+        drop(subscriber.receive().unwrap());
+
+        for n in 0..16 {
+            let number_of_allocations = count_allocations(|| {
+                let sample = publisher.loan_uninit().unwrap().write_payload(n);
+                sample.send().unwrap();
+
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(*sample, eq n);
+                let copy = sample.clone();
+                drop(sample);
+                drop(copy);
+            });
+
+            assert_that!(number_of_allocations, eq 0);
+        }
+    }
+
+    #[test]
+    fn publisher_with_extending_allocation_strategy_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .enable_deterministic_memory(true)
+            .create::<[u64]>()
+            .unwrap();
+
+        let publisher = sut
+            .publisher()
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create();
+        assert_that!(publisher.err(), eq Some(PublisherCreateError::IncompatibleAllocationStrategy));
+
+        let publisher = sut
+            .publisher()
+            .allocation_strategy(AllocationStrategy::Static)
+            .create();
+        assert_that!(publisher, is_ok);
+    }
+
+    #[test]
+    fn open_with_different_deterministic_memory_setting_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .enable_deterministic_memory(true)
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .enable_deterministic_memory(false)
+            .open::<u64>();
+        let Some(PublishSubscribeOpenError::IncompatibleSettings(settings)) = sut2.err() else {
+            panic!("opening the service must fail with incompatible settings");
+        };
+        assert_that!(settings, len 1);
+        assert_that!(settings[0].name, eq "enable_deterministic_memory");
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .enable_deterministic_memory(true)
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().has_deterministic_memory(), eq true);
+    }
+
+    #[test]
+    fn memory_budget_and_footprint_are_reported<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .enable_deterministic_memory(true)
+            .create::<[u8; 1024]>()
+            .unwrap();
+
+        let publisher = sut.publisher().max_loaned_samples(4).create().unwrap();
+        let budget = publisher.memory_budget();
+        assert_that!(budget, ge 4 * 1024);
+
+        publisher
+            .loan_uninit()
+            .unwrap()
+            .write_payload([0; 1024])
+            .send()
+            .unwrap();
+        assert_that!(publisher.memory_budget(), eq budget);
+
+        assert_that!(sut.memory_footprint(), gt 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
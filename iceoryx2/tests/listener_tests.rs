@@ -0,0 +1,113 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::time::Duration;
+
+use iceoryx2::prelude::*;
+use iceoryx2::testing::generate_isolated_config;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::{FileDescriptorSet, FileEvent};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_testing::assert_that;
+
+fn generate_name() -> ServiceName {
+    ServiceName::new(&format!(
+        "listener_tests_{}",
+        UniqueSystemId::new().unwrap().value()
+    ))
+    .unwrap()
+}
+
+fn number_of_readable_fds(fd_set: &FileDescriptorSet) -> usize {
+    let mut number_of_readable_fds = 0;
+    fd_set
+        .timed_wait(Duration::ZERO, FileEvent::Read, |_| {
+            number_of_readable_fds += 1
+        })
+        .unwrap();
+    number_of_readable_fds
+}
+
+#[test]
+fn file_descriptor_is_readable_while_notifications_are_pending() {
+    let config = generate_isolated_config();
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .event_with_custom_config(&config)
+        .create()
+        .unwrap();
+
+    let mut listener = sut.listener().create().unwrap();
+    let notifier = sut.notifier().create().unwrap();
+
+    let fd_set = FileDescriptorSet::new();
+    {
+        let _guard = fd_set.add(&listener).unwrap();
+        assert_that!(number_of_readable_fds(&fd_set), eq 0);
+
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(1)), is_ok);
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(2)), is_ok);
+        assert_that!(number_of_readable_fds(&fd_set), eq 1);
+        // level triggered, stays readable until the notifications are consumed
+        assert_that!(number_of_readable_fds(&fd_set), eq 1);
+    }
+
+    // the guard borrows the listener, it is attached again after the notifications are consumed
+    assert_that!(listener.try_wait().unwrap(), len 2);
+
+    let _guard = fd_set.add(&listener).unwrap();
+    assert_that!(number_of_readable_fds(&fd_set), eq 0);
+
+    assert_that!(notifier.notify_with_custom_event_id(EventId::new(3)), is_ok);
+    assert_that!(number_of_readable_fds(&fd_set), eq 1);
+}
+
+#[cfg(unix)]
+#[test]
+fn raw_file_descriptor_is_the_file_descriptor_of_the_listener() {
+    use std::os::unix::io::AsRawFd;
+
+    let config = generate_isolated_config();
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .event_with_custom_config(&config)
+        .create()
+        .unwrap();
+
+    let listener = sut.listener().create().unwrap();
+
+    assert_that!(listener.as_raw_fd(), eq unsafe { listener.file_descriptor().native_handle() });
+}
+
+#[test]
+fn file_descriptor_is_closed_when_the_listener_is_dropped() {
+    let config = generate_isolated_config();
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .event_with_custom_config(&config)
+        .create()
+        .unwrap();
+
+    let listener = sut.listener().create().unwrap();
+    let native_handle = unsafe { listener.file_descriptor().native_handle() };
+    // the acquired file descriptor is forgotten so that it does not close the one of the listener
+    assert_that!(
+        FileDescriptor::new(native_handle).map(core::mem::forget),
+        is_some
+    );
+
+    drop(listener);
+    assert_that!(
+        FileDescriptor::new(native_handle).map(core::mem::forget),
+        is_none
+    );
+}
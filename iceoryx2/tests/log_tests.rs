@@ -0,0 +1,78 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod log {
+    use iceoryx2::log::buffer::{Entry, Logger};
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    // the logger can be set only once per process, every test installs it before it logs
+    // anything and only inspects the messages of its own service
+    static LOGGER: Logger = Logger::new();
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "log_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn entries_of(service_name: &ServiceName) -> Vec<Entry> {
+        LOGGER
+            .content()
+            .into_iter()
+            .filter(|e| e.message.contains(service_name.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn open_non_existing_service_logs_at_debug<Sut: Service>() {
+        set_logger(&LOGGER);
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenError::DoesNotExist));
+
+        let entries = entries_of(&service_name);
+        assert_that!(entries, is_not_empty);
+        for entry in entries {
+            assert_that!(entry.log_level, eq LogLevel::Debug);
+        }
+    }
+
+    #[test]
+    fn open_service_with_incompatible_type_logs_at_warn<Sut: Service>() {
+        set_logger(&LOGGER);
+        let service_name = generate_name();
+
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let sut = Sut::new(&service_name).publish_subscribe().open::<u32>();
+        assert_that!(
+            matches!(sut.err(), Some(PublishSubscribeOpenError::IncompatibleTypes { .. })), eq true
+        );
+
+        let entries = entries_of(&service_name);
+        assert_that!(entries.iter().any(|e| e.log_level >= LogLevel::Warn), eq true);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
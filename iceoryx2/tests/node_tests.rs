@@ -0,0 +1,433 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod node {
+    use core::time::Duration;
+    use iceoryx2::node::liveness::{
+        NodeLiveness, NodeLivenessMonitorBuilder, HEARTBEAT_SERVICE_NAME,
+    };
+    use iceoryx2::node::{Node, NodeCreationFailure, NodeState};
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const HEARTBEAT_PERIOD: Duration = Duration::from_millis(10);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "node_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn node_without_name_has_empty_name<Sut: Service>() {
+        let sut = NodeBuilder::new().create::<Sut>().unwrap();
+
+        assert_that!(sut.name().as_str(), eq "");
+    }
+
+    #[test]
+    fn node_with_name_can_be_created<Sut: Service>() {
+        let node_name = NodeName::new("sensor_fusion").unwrap();
+        let sut = NodeBuilder::new().name(&node_name).create::<Sut>().unwrap();
+
+        assert_that!(*sut.name(), eq node_name);
+    }
+
+    #[test]
+    fn nodes_have_unique_ids<Sut: Service>() {
+        let sut_1 = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut_2 = NodeBuilder::new().create::<Sut>().unwrap();
+
+        assert_that!(sut_1.id(), ne sut_2.id());
+    }
+
+    #[test]
+    fn created_nodes_are_listed_until_they_are_dropped<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node_name = NodeName::new("listed_node").unwrap();
+
+        let sut_1 = NodeBuilder::new()
+            .name(&node_name)
+            .config(&config)
+            .create::<Sut>()
+            .unwrap();
+        let sut_2 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let nodes = Node::<Sut>::list_with_custom_config(&config).unwrap();
+        assert_that!(nodes, len 2);
+        for node in &nodes {
+            assert_that!(*node, eq NodeState::Alive(*node.details()));
+        }
+
+        let details = nodes
+            .iter()
+            .find(|node| node.details().id() == sut_1.id())
+            .unwrap()
+            .details();
+        assert_that!(*details.name(), eq node_name);
+
+        let sut_2_id = sut_2.id();
+        drop(sut_2);
+
+        let nodes = Node::<Sut>::list_with_custom_config(&config).unwrap();
+        assert_that!(nodes, len 1);
+        assert_that!(nodes[0].details().id(), eq sut_1.id());
+        assert_that!(nodes[0].details().id(), ne sut_2_id);
+
+        drop(sut_1);
+        assert_that!(
+            Node::<Sut>::list_with_custom_config(&config).unwrap(),
+            is_empty
+        );
+    }
+
+    #[test]
+    fn cleanup_does_not_remove_alive_nodes<Sut: Service>() {
+        let config = generate_isolated_config();
+        let _sut = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        assert_that!(Node::<Sut>::cleanup_dead_nodes_with_custom_config(&config), eq Ok(0));
+        assert_that!(Node::<Sut>::list_with_custom_config(&config).unwrap(), len 1);
+    }
+
+    #[test]
+    fn services_of_node_use_the_node_config<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _service = sut
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let service = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(service, is_err);
+
+        let service = sut
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .open::<u64>();
+        assert_that!(service, is_ok);
+    }
+
+    #[test]
+    fn node_without_heartbeat_is_unmonitored<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let monitor = NodeLivenessMonitorBuilder::new()
+            .config(&config)
+            .create::<Sut>()
+            .unwrap();
+
+        assert_that!(monitor.nodes(), len 1);
+        assert_that!(monitor.liveness(node.id()), eq Some(NodeLiveness::Unmonitored));
+    }
+
+    #[test]
+    fn node_with_zero_heartbeat_period_cannot_be_created<Sut: Service>() {
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .heartbeat(Duration::ZERO)
+            .create::<Sut>();
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::HeartbeatPeriodIsZero));
+
+        assert_that!(Node::<Sut>::list_with_custom_config(&config).unwrap(), len 0);
+    }
+
+    #[test]
+    fn node_with_heartbeat_is_alive<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .heartbeat(HEARTBEAT_PERIOD)
+            .create::<Sut>()
+            .unwrap();
+
+        let monitor = NodeLivenessMonitorBuilder::new()
+            .config(&config)
+            .check_interval(HEARTBEAT_PERIOD)
+            .create::<Sut>()
+            .unwrap();
+
+        std::thread::sleep(HEARTBEAT_PERIOD * 10);
+
+        let nodes = monitor.nodes();
+        assert_that!(nodes, len 1);
+        assert_that!(nodes[0].0.id(), eq node.id());
+        assert_that!(nodes[0].0.heartbeat_period(), eq Some(HEARTBEAT_PERIOD));
+        assert_that!(nodes[0].1, eq NodeLiveness::Alive);
+    }
+
+    #[test]
+    fn heartbeat_is_stopped_when_node_is_dropped<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .heartbeat(HEARTBEAT_PERIOD)
+            .create::<Sut>()
+            .unwrap();
+
+        assert_that!(<Sut::Type<'_>>::does_exist_with_custom_config(&HEARTBEAT_SERVICE_NAME, &config), eq Ok(true));
+
+        let details = Node::<Sut>::list_with_custom_config(&config).unwrap();
+        assert_that!(details[0].details().heartbeat_period(), eq Some(HEARTBEAT_PERIOD));
+
+        drop(node);
+
+        // the heartbeat service is removed together with the last publisher
+        assert_that!(<Sut::Type<'_>>::does_exist_with_custom_config(&HEARTBEAT_SERVICE_NAME, &config), eq Ok(false));
+        assert_that!(
+            Node::<Sut>::list_with_custom_config(&config).unwrap(),
+            is_empty
+        );
+    }
+
+    #[test]
+    fn dropped_node_is_no_longer_monitored<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .heartbeat(HEARTBEAT_PERIOD)
+            .create::<Sut>()
+            .unwrap();
+        let node_id = node.id();
+
+        let monitor = NodeLivenessMonitorBuilder::new()
+            .config(&config)
+            .check_interval(HEARTBEAT_PERIOD)
+            .create::<Sut>()
+            .unwrap();
+        assert_that!(monitor.liveness(node_id), eq Some(NodeLiveness::Alive));
+
+        drop(node);
+        std::thread::sleep(HEARTBEAT_PERIOD * 10);
+
+        assert_that!(monitor.liveness(node_id), eq None);
+        assert_that!(monitor.nodes(), is_empty);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
+
+// The child process is spawned by re-executing this test binary with a single ignored test
+// selected, it terminates without dropping its node.
+mod node_cross_process {
+    use std::process::Command;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::node::{Node, NodeState};
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const PREFIX_VARIABLE: &str = "IOX2_CROSS_PROCESS_NODE_PREFIX";
+
+    fn config_with_prefix(prefix: &str) -> Config {
+        let mut config = Config::default();
+        config.global.prefix = prefix.to_string();
+        config
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_creates_node_and_terminates() {
+        let prefix = match std::env::var(PREFIX_VARIABLE) {
+            Ok(prefix) => prefix,
+            Err(_) => return,
+        };
+
+        let node = NodeBuilder::new()
+            .name(&NodeName::new("dead_node").unwrap())
+            .config(&config_with_prefix(&prefix))
+            .create::<zero_copy::Service>()
+            .unwrap();
+
+        std::mem::forget(node);
+        std::process::exit(0);
+    }
+
+    #[test]
+    fn node_of_terminated_process_is_dead_and_can_be_cleaned_up() {
+        let prefix = format!("node_tests_{}_", UniqueSystemId::new().unwrap().value());
+        let config = config_with_prefix(&prefix);
+
+        let status = Command::new(std::env::current_exe().unwrap())
+            .args([
+                "node_cross_process::child_creates_node_and_terminates",
+                "--exact",
+                "--ignored",
+                "--nocapture",
+            ])
+            .env(PREFIX_VARIABLE, &prefix)
+            .status()
+            .unwrap();
+        assert_that!(status.success(), eq true);
+
+        let alive_node = NodeBuilder::new()
+            .config(&config)
+            .create::<zero_copy::Service>()
+            .unwrap();
+
+        let nodes = Node::<zero_copy::Service>::list_with_custom_config(&config).unwrap();
+        assert_that!(nodes, len 2);
+        let dead_node = nodes
+            .iter()
+            .find(|node| node.details().id() != alive_node.id())
+            .unwrap();
+        assert_that!(*dead_node, eq NodeState::Dead(*dead_node.details()));
+        assert_that!(dead_node.details().name().as_str(), eq "dead_node");
+
+        assert_that!(Node::<zero_copy::Service>::cleanup_dead_nodes_with_custom_config(&config), eq Ok(1));
+
+        let nodes = Node::<zero_copy::Service>::list_with_custom_config(&config).unwrap();
+        assert_that!(nodes, len 1);
+        assert_that!(nodes[0], eq NodeState::Alive(*nodes[0].details()));
+        assert_that!(nodes[0].details().id(), eq alive_node.id());
+    }
+}
+
+// the heartbeat of the child is suspended with SIGSTOP which is not available on windows
+#[cfg(not(target_os = "windows"))]
+mod node_heartbeat_cross_process {
+    use core::time::Duration;
+    use std::process::Command;
+    use std::sync::{Arc, Mutex};
+    use std::time::Instant;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::node::liveness::{NodeLiveness, NodeLivenessMonitor, NodeLivenessMonitorBuilder};
+    use iceoryx2::node::{Node, NodeDetails};
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::process::{Process, ProcessId};
+    use iceoryx2_bb_posix::signal::Signal;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const PREFIX_VARIABLE: &str = "IOX2_CROSS_PROCESS_NODE_HEARTBEAT_PREFIX";
+
+    fn config_with_prefix(prefix: &str) -> Config {
+        let mut config = Config::default();
+        config.global.prefix = prefix.to_string();
+        config
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_publishes_heartbeat_until_it_is_killed() {
+        let prefix = match std::env::var(PREFIX_VARIABLE) {
+            Ok(prefix) => prefix,
+            Err(_) => return,
+        };
+
+        let _node = NodeBuilder::new()
+            .name(&NodeName::new("monitored_node").unwrap())
+            .config(&config_with_prefix(&prefix))
+            .heartbeat(HEARTBEAT_PERIOD)
+            .create::<zero_copy::Service>()
+            .unwrap();
+
+        loop {
+            std::thread::sleep(HEARTBEAT_PERIOD);
+        }
+    }
+
+    const HEARTBEAT_PERIOD: Duration = Duration::from_millis(10);
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn wait_for_node_in_state<F: Fn(&NodeLiveness) -> bool>(
+        monitor: &NodeLivenessMonitor<zero_copy::Service>,
+        predicate: F,
+    ) -> Option<(NodeDetails, NodeLiveness)> {
+        let start = Instant::now();
+        while start.elapsed() < TIMEOUT {
+            if let Some(node) = monitor.nodes().into_iter().find(|(_, l)| predicate(l)) {
+                return Some(node);
+            }
+            std::thread::sleep(HEARTBEAT_PERIOD);
+        }
+        None
+    }
+
+    #[test]
+    fn node_of_stopped_process_misses_its_heartbeat_and_is_dead() {
+        let prefix = format!("node_tests_{}_", UniqueSystemId::new().unwrap().value());
+        let config = config_with_prefix(&prefix);
+
+        let transitions = Arc::new(Mutex::new(vec![]));
+        let recorded_transitions = transitions.clone();
+        let monitor = NodeLivenessMonitorBuilder::new()
+            .config(&config)
+            .check_interval(HEARTBEAT_PERIOD)
+            .missed_heartbeats_until_dead(3)
+            .on_transition(move |details, previous, current| {
+                recorded_transitions
+                    .lock()
+                    .unwrap()
+                    .push((details, previous, current));
+            })
+            .create::<zero_copy::Service>()
+            .unwrap();
+
+        let mut child = Command::new(std::env::current_exe().unwrap())
+            .args([
+                "node_heartbeat_cross_process::child_publishes_heartbeat_until_it_is_killed",
+                "--exact",
+                "--ignored",
+                "--nocapture",
+            ])
+            .env(PREFIX_VARIABLE, &prefix)
+            .spawn()
+            .unwrap();
+
+        let alive_node = wait_for_node_in_state(&monitor, |l| *l == NodeLiveness::Alive);
+        assert_that!(alive_node, is_some);
+        let (details, _) = alive_node.unwrap();
+        assert_that!(details.name().as_str(), eq "monitored_node");
+        assert_that!(details.heartbeat_period(), eq Some(HEARTBEAT_PERIOD));
+
+        // the process still exists but does not publish its heartbeat anymore
+        Process::from_pid(ProcessId::new(child.id() as _))
+            .send_signal(Signal::StopExecution)
+            .unwrap();
+
+        let dead_node =
+            wait_for_node_in_state(&monitor, |l| matches!(l, NodeLiveness::Dead { .. }));
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        assert_that!(dead_node, is_some);
+        let (dead_details, liveness) = dead_node.unwrap();
+        assert_that!(dead_details.id(), eq details.id());
+        assert_that!(liveness, ne NodeLiveness::Dead { last_seen: None });
+
+        let transitions = transitions.lock().unwrap().clone();
+        assert_that!(transitions.iter().any(|(d, previous, current)| d.id() == details.id()
+            && *previous == NodeLiveness::Alive
+            && *current == liveness), eq true);
+
+        drop(monitor);
+        assert_that!(Node::<zero_copy::Service>::cleanup_dead_nodes_with_custom_config(&config), eq Ok(1));
+    }
+}
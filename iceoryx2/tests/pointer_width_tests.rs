@@ -0,0 +1,99 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use iceoryx2::service::header::publish_subscribe::Header;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+// the header and the port ids are shared with every process of a service and have a fixed layout
+const _: () = assert!(core::mem::size_of::<Header>() == 80);
+const _: () = assert!(core::mem::size_of::<UniquePublisherId>() == 16);
+const _: () = assert!(core::mem::size_of::<UniqueSubscriberId>() == 16);
+// the chunk offsets have the size of a pointer, the pointer width is therefore part of the
+// type details
+const _: () = assert!(core::mem::size_of::<PointerOffset>() == core::mem::size_of::<usize>());
+
+#[test]
+fn header_layout_does_not_depend_on_the_pointer_width() {
+    assert_that!(core::mem::size_of::<Header>(), eq 80);
+    assert_that!(core::mem::align_of::<Header>() <= 16, eq true);
+}
+
+#[generic_tests::define]
+mod pointer_width {
+    use std::sync::Mutex;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::{emulate_pointer_width, generate_isolated_config};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    // the emulated pointer width is global, the tests must not run concurrently
+    static EMULATION_LOCK: Mutex<()> = Mutex::new(());
+
+    const OTHER_POINTER_WIDTH: u32 = if usize::BITS == 64 { 32 } else { 64 };
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "pointer_width_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn type_details_contain_the_pointer_width<Sut: Service>() {
+        let _lock = EMULATION_LOCK.lock().unwrap();
+        let config = generate_isolated_config();
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.static_config().payload_type_details().pointer_width(), eq usize::BITS);
+        assert_that!(sut.static_config().user_header_type_details().pointer_width(), eq usize::BITS);
+    }
+
+    #[test]
+    fn service_of_another_pointer_width_cannot_be_opened<Sut: Service>() {
+        let _lock = EMULATION_LOCK.lock().unwrap();
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+
+        emulate_pointer_width(OTHER_POINTER_WIDTH);
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>();
+        emulate_pointer_width(0);
+        let sut = sut.unwrap();
+        assert_that!(sut.static_config().payload_type_details().pointer_width(), eq OTHER_POINTER_WIDTH);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .open::<u64>();
+        let Some(PublishSubscribeOpenError::IncompatibleTypes { expected, actual }) = sut2.err()
+        else {
+            panic!("opening the service must fail with incompatible types");
+        };
+        assert_that!(expected.pointer_width(), eq OTHER_POINTER_WIDTH);
+        assert_that!(actual.pointer_width(), ne OTHER_POINTER_WIDTH);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
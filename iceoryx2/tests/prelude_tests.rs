@@ -0,0 +1,105 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Apart from the test utilities only the prelude is imported, the tests fail to compile when it
+// lacks a type that is required by the examples in the documentation.
+use iceoryx2::prelude::*;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_testing::assert_that;
+
+fn generate_name() -> ServiceName {
+    ServiceName::new(&format!(
+        "prelude_tests_{}",
+        UniqueSystemId::new().unwrap().value()
+    ))
+    .unwrap()
+}
+
+#[test]
+fn prelude_contains_everything_required_to_loan_and_send_a_sample(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = zero_copy::Service::new(&generate_name())
+        .publish_subscribe()
+        .open_or_create::<u64>()?;
+    let publisher = service.publisher().create()?;
+    let subscriber = service.subscriber().create()?;
+
+    let sample = publisher.loan_uninit()?;
+    let sample = sample.write_payload(1234);
+    let loaned_at = sample.header().time_stamp().as_duration();
+    assert_that!(sample.header().publisher_id(), eq publisher.id());
+    sample.send()?;
+
+    let sample = subscriber.receive()?.unwrap();
+    assert_that!(*sample, eq 1234);
+    assert_that!(sample.header().publisher_id(), eq publisher.id());
+    // the time stamp is assigned when the sample is sent
+    assert_that!(sample.header().time_stamp().as_duration(), ge loaned_at);
+    assert_that!(sample.header().sequence_number(), eq 0);
+    assert_that!(sample.header().number_of_elements(), eq 1);
+    Ok(())
+}
+
+#[test]
+fn prelude_contains_the_port_and_service_errors() {
+    let service_name = generate_name();
+    let service = zero_copy::Service::new(&service_name)
+        .publish_subscribe()
+        .subscriber_max_borrowed_samples(1)
+        .create::<u64>()
+        .unwrap();
+
+    let create_error: PublishSubscribeCreateError = zero_copy::Service::new(&service_name)
+        .publish_subscribe()
+        .create::<u64>()
+        .err()
+        .unwrap();
+    assert_that!(create_error, eq PublishSubscribeCreateError::AlreadyExists);
+    assert_that!(create_error.to_string().contains("open_or_create()"), eq true);
+
+    let open_error: PublishSubscribeOpenError = zero_copy::Service::new(&service_name)
+        .publish_subscribe()
+        .open::<u32>()
+        .err()
+        .unwrap();
+    assert_that!(
+        matches!(open_error, PublishSubscribeOpenError::IncompatibleTypes { .. }), eq true
+    );
+    assert_that!(open_error.to_string().contains(std::any::type_name::<u32>()), eq true);
+
+    let publisher = service.publisher().max_loaned_samples(1).create().unwrap();
+    let subscriber = service.subscriber().create().unwrap();
+    let sample = publisher.loan_uninit().unwrap();
+
+    // the errors are non exhaustive, new failures can be added without breaking this match
+    match publisher.loan_uninit().err().unwrap() {
+        PublisherLoanError::ExceedsMaxLoanedSamples => (),
+        e => panic!("unexpected failure {}", e),
+    }
+
+    let send_result: Result<usize, PublisherSendError> = publisher.send_copy(1);
+    assert_that!(send_result, is_err);
+
+    drop(sample);
+    publisher.send_copy(1).unwrap();
+    publisher.send_copy(2).unwrap();
+    let _received = subscriber.receive().unwrap().unwrap();
+    let receive_error: SubscriberReceiveError = subscriber.receive().err().unwrap();
+    assert_that!(receive_error, eq SubscriberReceiveError::ExceedsMaxBorrowedSamples);
+    // the display implementations print the enum as "Self"
+    assert_that!(
+        receive_error
+            .to_string()
+            .contains("::ExceedsMaxBorrowedSamples"),
+        eq true
+    );
+}
@@ -12,21 +12,74 @@
 
 #[generic_tests::define]
 mod publisher {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
     use std::time::{Duration, Instant};
 
-    use iceoryx2::payload_mut::UninitPayloadMut;
-    use iceoryx2::port::publish::PublisherLoanError;
+    use iceoryx2::advanced::{self, SegmentOffset};
+    use iceoryx2::config::Config;
+    use iceoryx2::payload_mut::{PayloadMut, UninitPayloadMut};
+    use iceoryx2::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+    use iceoryx2::port::publish::{
+        ConnectionOperation, DeliveryFailureReason, PublisherCreateError, PublisherLoanError,
+        PublisherSendError,
+    };
+    use iceoryx2::port::tracing_hooks::TracingHooks;
+    use iceoryx2::port::update_connections::ConnectionFailure;
+    use iceoryx2::port::DegrationAction;
     use iceoryx2::prelude::*;
-    use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
-    use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2::service::header::publish_subscribe::Header;
+    use iceoryx2::service::port_factory::publisher::{
+        AllocationStrategy, BucketConfig, MemoryHygiene, UnableToDeliverStrategy,
+        MAX_NUMBER_OF_BUCKETS,
+    };
+    use iceoryx2::service::static_config::publish_subscribe::StaticConfig;
+    use iceoryx2::service::{service_name::ServiceName, Details, Service};
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
     use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
+    use iceoryx2_bb_posix::clock::Time;
+    #[cfg(target_os = "linux")]
+    use iceoryx2_bb_posix::system_configuration::ProcessResourceLimit;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::named_concept::{
+        NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+    };
+    use iceoryx2_cal::zero_copy_connection::{
+        ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
+    };
 
     type TestResult<T> = core::result::Result<T, Box<dyn std::error::Error>>;
 
     const TIMEOUT: Duration = Duration::from_millis(25);
 
+    // the locked memory is accounted per process, the tests must not run concurrently
+    #[cfg(target_os = "linux")]
+    static LOCKED_MEMORY_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[cfg(target_os = "linux")]
+    fn locked_memory_of_process_in_kb() -> u64 {
+        std::fs::read_to_string("/proc/self/status")
+            .unwrap()
+            .lines()
+            .find_map(|line| line.strip_prefix("VmLck:"))
+            .map(|value| value.trim().trim_end_matches("kB").trim().parse().unwrap())
+            .unwrap()
+    }
+
+    #[cfg(target_os = "linux")]
+    fn has_capability_to_lock_memory() -> bool {
+        const CAP_IPC_LOCK: u64 = 14;
+        std::fs::read_to_string("/proc/self/status")
+            .unwrap()
+            .lines()
+            .find_map(|line| line.strip_prefix("CapEff:"))
+            .map(|value| u64::from_str_radix(value.trim(), 16).unwrap() & (1 << CAP_IPC_LOCK) != 0)
+            .unwrap()
+    }
+
     fn generate_name() -> TestResult<ServiceName> {
         Ok(ServiceName::new(&format!(
             "service_tests_{}",
@@ -34,11 +87,65 @@ mod publisher {
         ))?)
     }
 
+    type Connection<'config, Sut> =
+        <<Sut as Service>::Type<'config> as Details<'config>>::Connection;
+
+    // the foreign sender occupies the connection in shared memory, the publisher must not bypass
+    // it via the channel of the intra process fast path
+    fn config_without_intra_process_fast_path() -> Config {
+        let mut config = generate_isolated_config();
+        config
+            .defaults
+            .publish_subscribe
+            .enable_intra_process_fast_path = false;
+        config
+    }
+
+    // the ids are written as fixed width hexadecimal numbers, like in the naming scheme of the
+    // service
+    fn connection_name_of(
+        publisher_id: &UniquePublisherId,
+        subscriber_id: &UniqueSubscriberId,
+    ) -> FileName {
+        FileName::new(
+            format!(
+                "{:032x}_{:032x}",
+                publisher_id.value(),
+                subscriber_id.value()
+            )
+            .as_bytes(),
+        )
+        .unwrap()
+    }
+
+    // occupies the sending side of a connection so that the publisher is unable to establish it
+    fn create_foreign_sender<'config, Sut: Service>(
+        config: &'config Config,
+        connection_name: &FileName,
+        static_config: &StaticConfig,
+        buffer_size: usize,
+    ) -> <Connection<'config, Sut> as ZeroCopyConnection>::Sender {
+        let connection_config =
+            <Connection<'config, Sut> as NamedConceptMgmt>::Configuration::default()
+                .prefix(FileName::new(config.global.prefix.as_bytes()).unwrap())
+                .suffix(FileName::new(config.global.service.connection_suffix.as_bytes()).unwrap())
+                .path_hint(config.global.root_path());
+
+        <Connection<'config, Sut> as ZeroCopyConnection>::Builder::new(connection_name)
+            .config(&connection_config)
+            .buffer_size(buffer_size)
+            .receiver_max_borrowed_samples(static_config.subscriber_max_borrowed_samples())
+            .enable_safe_overflow(static_config.has_safe_overflow())
+            .create_sender()
+            .unwrap()
+    }
+
     #[test]
     fn publisher_loan_and_send_sample_works<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
         let service_name = generate_name()?;
         let service = Sut::new(&service_name)
-            .publish_subscribe()
+            .publish_subscribe_with_custom_config(&config)
             .create::<u64>()?;
 
         let sut = service.publisher().max_loaned_samples(2).create()?;
@@ -52,9 +159,10 @@ mod publisher {
 
     #[test]
     fn publisher_loan_unit_and_send_sample_works<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
         let service_name = generate_name()?;
         let service = Sut::new(&service_name)
-            .publish_subscribe()
+            .publish_subscribe_with_custom_config(&config)
             .create::<u64>()?;
 
         let sut = service.publisher().max_loaned_samples(2).create()?;
@@ -68,9 +176,10 @@ mod publisher {
 
     #[test]
     fn publisher_can_borrow_multiple_sample_at_once<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
         let service_name = generate_name()?;
         let service = Sut::new(&service_name)
-            .publish_subscribe()
+            .publish_subscribe_with_custom_config(&config)
             .create::<u64>()?;
 
         let sut = service.publisher().max_loaned_samples(4).create()?;
@@ -98,9 +207,10 @@ mod publisher {
 
     #[test]
     fn publisher_max_loaned_samples_works<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
         let service_name = generate_name()?;
         let service = Sut::new(&service_name)
-            .publish_subscribe()
+            .publish_subscribe_with_custom_config(&config)
             .create::<u64>()?;
 
         let sut = service.publisher().max_loaned_samples(2).create()?;
@@ -110,16 +220,17 @@ mod publisher {
 
         let sample3 = sut.loan_uninit();
         assert_that!(sample3, is_err);
-        assert_that!(sample3.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedChunks);
+        assert_that!(sample3.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
 
         Ok(())
     }
 
     #[test]
     fn publisher_sending_sample_reduces_loan_counter<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
         let service_name = generate_name()?;
         let service = Sut::new(&service_name)
-            .publish_subscribe()
+            .publish_subscribe_with_custom_config(&config)
             .create::<u64>()?;
 
         let sut = service.publisher().max_loaned_samples(2).create()?;
@@ -132,16 +243,17 @@ mod publisher {
         let _sample3 = sut.loan_uninit();
         let sample4 = sut.loan_uninit();
         assert_that!(sample4, is_err);
-        assert_that!(sample4.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedChunks);
+        assert_that!(sample4.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
 
         Ok(())
     }
 
     #[test]
     fn publisher_dropping_sample_reduces_loan_counter<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
         let service_name = generate_name()?;
         let service = Sut::new(&service_name)
-            .publish_subscribe()
+            .publish_subscribe_with_custom_config(&config)
             .create::<u64>()?;
 
         let sut = service.publisher().max_loaned_samples(2).create()?;
@@ -154,7 +266,469 @@ mod publisher {
         let _sample3 = sut.loan_uninit();
         let sample4 = sut.loan_uninit();
         assert_that!(sample4, is_err);
-        assert_that!(sample4.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedChunks);
+        assert_that!(sample4.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_can_loan_again_after_dropping_sample_when_max_loaned_samples_is_reached<
+        Sut: Service,
+    >() -> TestResult<()> {
+        let config = generate_isolated_config();
+        const MAX_LOANED_SAMPLES: usize = 5;
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let sut = service
+            .publisher()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .create()?;
+
+        let mut samples = vec![];
+        for _ in 0..MAX_LOANED_SAMPLES {
+            samples.push(sut.loan()?);
+        }
+
+        let sample = sut.loan();
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+        let sample = sut.loan_uninit();
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+        drop(samples.pop());
+
+        assert_that!(sut.loan_uninit(), is_ok);
+        assert_that!(sut.loan(), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_copy_without_subscribers_delivers_to_nobody<Sut: Service>() -> TestResult<()>
+    {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let sut = service.publisher().create()?;
+
+        let result = sut.send_copy(1234);
+        assert_that!(result, is_ok);
+        assert_that!(result.unwrap(), eq 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_copy_returns_number_of_receiving_subscribers<Sut: Service>() -> TestResult<()>
+    {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let sut = service.publisher().create()?;
+        let _subscriber1 = service.subscriber().create()?;
+        let _subscriber2 = service.subscriber().create()?;
+
+        let result = sut.send_copy(1234);
+        assert_that!(result, is_ok);
+        assert_that!(result.unwrap(), eq 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_copy_is_not_counted_as_cancelled_sample<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let sut = service.publisher().create()?;
+        let subscriber = service.subscriber().create()?;
+
+        assert_that!(sut.send_copy(1234), eq Ok(1));
+        assert_that!(sut.send_copy(5678), eq Ok(1));
+        assert_that!(sut.statistics().cancelled_samples(), eq 0);
+        assert_that!(sut.statistics().sent_samples(), eq 2);
+        assert_that!(sut.statistics().loaned_samples(), eq 0);
+
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1234);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 5678);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_copy_fails_when_max_loaned_samples_is_reached<Sut: Service>() -> TestResult<()>
+    {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let sut = service.publisher().max_loaned_samples(1).create()?;
+
+        let sample = sut.loan_uninit()?;
+
+        let result = sut.send_copy(1234);
+        assert_that!(result, is_err);
+        assert_that!(
+            result.err().unwrap(), eq
+            PublisherSendError::LoanError(PublisherLoanError::ExceedsMaxLoanedSamples)
+        );
+
+        drop(sample);
+        assert_that!(sut.send_copy(1234), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_write_from_fn_initializes_large_payload_in_place<Sut: Service>() -> TestResult<()>
+    {
+        let config = generate_isolated_config();
+        const PAYLOAD_SIZE: usize = 4 * 1024 * 1024;
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_subscribers(1)
+            .history_size(0)
+            .subscriber_max_buffer_size(1)
+            .subscriber_max_borrowed_samples(1)
+            .create::<[u8; PAYLOAD_SIZE]>()?;
+
+        let sut = service.publisher().max_loaned_samples(1).create()?;
+        let subscriber = service.subscriber().create()?;
+
+        let sample = unsafe {
+            sut.loan_uninit()?.write_from_fn(|payload| {
+                payload
+                    .as_mut_ptr()
+                    .cast::<u8>()
+                    .write_bytes(0xab, PAYLOAD_SIZE)
+            })
+        };
+        assert_that!(sample.send(), is_ok);
+
+        let r = subscriber.receive()?;
+        assert_that!(r, is_some);
+        assert_that!(r.unwrap().iter().all(|v| *v == 0xab), eq true);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_zeroed_sets_payload_to_zero_and_keeps_header<Sut: Service>() -> TestResult<()>
+    {
+        let config = generate_isolated_config();
+        const PAYLOAD_SIZE: usize = 1024 * 1024;
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_subscribers(1)
+            .history_size(0)
+            .subscriber_max_buffer_size(1)
+            .subscriber_max_borrowed_samples(1)
+            .create::<[u8; PAYLOAD_SIZE]>()?;
+
+        let sut = service.publisher().max_loaned_samples(1).create()?;
+        let subscriber = service.subscriber().create()?;
+
+        // pollute the chunk so that a reused chunk would reveal a missing zeroing
+        let sample = unsafe {
+            sut.loan_uninit()?.write_from_fn(|payload| {
+                payload
+                    .as_mut_ptr()
+                    .cast::<u8>()
+                    .write_bytes(0xff, PAYLOAD_SIZE)
+            })
+        };
+        drop(sample);
+
+        // the time stamp of the header is acquired with the clock of the service
+        let clock_type = service.static_config().clock_type().into();
+        let before = Time::now_with_clock(clock_type).unwrap().as_duration();
+        let sample = unsafe { sut.loan_zeroed()? };
+        let after = Time::now_with_clock(clock_type).unwrap().as_duration();
+
+        let time_stamp = sample.header().time_stamp().as_duration();
+        assert_that!(time_stamp, ge before);
+        assert_that!(time_stamp, le after);
+        assert_that!(sample.header().number_of_elements(), eq 1);
+        assert_that!(sample.payload().iter().all(|v| *v == 0), eq true);
+        assert_that!(sample.send(), is_ok);
+
+        let r = subscriber.receive()?;
+        assert_that!(r, is_some);
+        assert_that!(r.unwrap().iter().all(|v| *v == 0), eq true);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_write_from_fn_returns_sample_when_initializer_panics<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let sut = service.publisher().max_loaned_samples(1).create()?;
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let sample = sut.loan_uninit().unwrap();
+            let _sample = unsafe { sample.write_from_fn(|_| panic!("initializer failed")) };
+        }));
+        assert_that!(result, is_err);
+
+        let sample = sut.loan_uninit();
+        assert_that!(sample, is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_does_not_deliver_partially_written_sample_when_initializer_panics<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .history_size(0)
+            .create::<[u64; 4]>()?;
+
+        let sut = service.publisher().max_loaned_samples(1).create()?;
+        let subscriber = service.subscriber().create()?;
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let sample = sut.loan_uninit().unwrap();
+            let sample = unsafe {
+                sample.write_from_fn(|payload| {
+                    payload.as_mut_ptr().cast::<u64>().write(1234);
+                    panic!("initializer failed after the first element");
+                })
+            };
+            sample.send().unwrap();
+        }));
+        assert_that!(result, is_err);
+        assert_that!(subscriber.receive()?, is_none);
+        assert_that!(sut.statistics().cancelled_samples(), eq 1);
+
+        assert_that!(sut.send_copy([1, 2, 3, 4]), eq Ok(1));
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample, eq [1, 2, 3, 4]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_reclaims_the_loan_when_a_tracing_hook_panics<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
+        struct PanickingHooks {
+            is_panicking: Arc<AtomicBool>,
+        }
+
+        impl TracingHooks<u64> for PanickingHooks {
+            fn on_loan(&self, _header: &Header, user_header: &mut u64) {
+                *user_header = 1234;
+                if self.is_panicking.load(Ordering::Relaxed) {
+                    panic!("tracing hook failed");
+                }
+            }
+        }
+
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_subscribers(1)
+            .history_size(0)
+            .subscriber_max_buffer_size(1)
+            .subscriber_max_borrowed_samples(1)
+            .user_header::<u64>()
+            .create::<u64>()?;
+
+        let mut sut = service.publisher().max_loaned_samples(1).create()?;
+        let is_panicking = Arc::new(AtomicBool::new(true));
+        sut.set_tracing_hooks(Some(PanickingHooks {
+            is_panicking: is_panicking.clone(),
+        }));
+        let subscriber = service.subscriber().create()?;
+
+        // more aborted loans than the data segment has samples
+        for _ in 0..8 {
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                sut.loan_uninit().unwrap().write_payload(1).send().unwrap();
+            }));
+            assert_that!(result, is_err);
+        }
+        assert_that!(subscriber.receive()?, is_none);
+        assert_that!(sut.statistics().cancelled_samples(), eq 8);
+
+        is_panicking.store(false, Ordering::Relaxed);
+        assert_that!(sut.loan_uninit()?.write_payload(2).send(), eq Ok(1));
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample, eq 2);
+        assert_that!(*sample.user_header(), eq 1234);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_discard_sample_reports_full_subscriber_buffer<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(false)
+            .create::<u64>()?;
+
+        let sut = service
+            .publisher()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()?;
+        let subscriber = service.subscriber().create()?;
+
+        for n in 0..BUFFER_SIZE as u64 {
+            let result = sut.send_copy(n);
+            assert_that!(result, is_ok);
+            assert_that!(result.unwrap(), eq 1);
+        }
+
+        // the buffer is full, the sample is not delivered
+        let result = sut.send_copy(1234);
+        assert_that!(result, is_ok);
+        assert_that!(result.unwrap(), eq 0);
+
+        for n in 0..BUFFER_SIZE as u64 {
+            let sample = subscriber.receive()?;
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq n);
+        }
+
+        let sample = subscriber.receive()?;
+        assert_that!(sample, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sync_publisher_loan_drop_and_send_from_multiple_threads_does_not_leak<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        const NUMBER_OF_THREADS: usize = 4;
+        const ITERATIONS: usize = 1000;
+        const MAX_LOAN: usize = 8;
+
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let sut = service
+            .publisher()
+            .max_loaned_samples(MAX_LOAN)
+            .create()?
+            .into_sync();
+
+        std::thread::scope(|s| {
+            for t in 0..NUMBER_OF_THREADS {
+                let sut = &sut;
+                s.spawn(move || {
+                    for n in 0..ITERATIONS {
+                        let sample = sut.loan_uninit().unwrap();
+                        match n % 3 {
+                            0 => drop(sample),
+                            1 => assert_that!(sample.write_payload(t as u64).send(), is_ok),
+                            _ => assert_that!(sut.send_copy(n as u64), is_ok),
+                        }
+                    }
+                });
+            }
+        });
+
+        let mut samples = vec![];
+        for _ in 0..MAX_LOAN {
+            samples.push(sut.loan_uninit()?);
+        }
+
+        let sample = sut.loan_uninit();
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sync_publisher_sample_can_be_sent_from_another_thread<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let sut = service
+            .publisher()
+            .max_loaned_samples(1)
+            .create()?
+            .into_sync();
+        let subscriber = service.subscriber().create()?;
+
+        let sample = sut.loan_uninit()?;
+        std::thread::scope(|s| {
+            s.spawn(move || {
+                let sample = sample.write_payload(7319);
+                assert_that!(sample.send(), is_ok);
+            });
+        });
+
+        let sample = subscriber.receive()?;
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 7319);
+
+        // the chunk was returned, otherwise the loan would exceed the max loaned samples
+        assert_that!(sut.loan_uninit(), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sync_publisher_waits_until_the_sample_was_consumed<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .enable_notifications(true)
+            .history_size(0)
+            .create::<u64>()?;
+
+        let sut = service
+            .publisher()
+            .enable_consumption_tracking(true)
+            .create()?
+            .into_sync();
+        let subscriber = service.subscriber().create()?;
+
+        let token = sut.loan_uninit()?.write_payload(8912).send_with_token()?;
+        let sample = subscriber.receive()?;
+        assert_that!(sample, is_some);
+        assert_that!(sut.wait_consumed(token, Duration::from_millis(1)), eq Ok(false));
+
+        drop(sample);
+        assert_that!(sut.wait_consumed(token, Duration::from_secs(10)), eq Ok(true));
+        assert_that!(sut.consumed_up_to(), eq Some(token.sequence_number()));
 
         Ok(())
     }
@@ -163,9 +737,10 @@ mod publisher {
     #[ignore]
     #[test]
     fn publisher_block_when_unable_to_deliver_blocks<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
         let service_name = generate_name()?;
         let service = Sut::new(&service_name)
-            .publish_subscribe()
+            .publish_subscribe_with_custom_config(&config)
             .subscriber_max_buffer_size(1)
             .enable_safe_overflow(false)
             .create::<u64>()?;
@@ -181,7 +756,7 @@ mod publisher {
         std::thread::scope(|s| {
             s.spawn(|| {
                 let service = Sut::new(&service_name)
-                    .publish_subscribe()
+                    .publish_subscribe_with_custom_config(&config)
                     .subscriber_max_buffer_size(1)
                     .open::<u64>()
                     .unwrap();
@@ -207,6 +782,767 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_with_safe_overflow_delivers_newest_samples_to_slow_subscriber<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .create::<u64>()?;
+
+        let sut = service.publisher().create()?;
+        let subscriber = service.subscriber().create()?;
+
+        for n in 0..5 {
+            assert_that!(sut.send_copy(n), is_ok);
+        }
+
+        for n in 3..5 {
+            let sample = subscriber.receive()?;
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq n);
+        }
+
+        let sample = subscriber.receive()?;
+        assert_that!(sample, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_delivers_to_remaining_subscribers_when_a_connection_is_broken<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = config_without_intra_process_fast_path();
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let sut = service.publisher().create()?;
+        let broken_subscriber = service.subscriber().create()?;
+        let subscriber = service.subscriber().create()?;
+
+        let connection_name = connection_name_of(&sut.id(), &broken_subscriber.id());
+        let foreign_sender = create_foreign_sender::<Sut>(
+            &config,
+            &connection_name,
+            service.static_config(),
+            broken_subscriber.buffer_size(),
+        );
+
+        let failures = match sut.send_copy(1234) {
+            Err(PublisherSendError::PartialDelivery {
+                number_of_recipients,
+                failures,
+            }) => {
+                assert_that!(number_of_recipients, eq 1);
+                failures
+            }
+            result => panic!("expected a partial delivery but got {:?}", result),
+        };
+        assert_that!(failures, len 1);
+        assert_that!(failures[0].subscriber_id(), eq broken_subscriber.id());
+        assert_that!(failures[0].operation(), eq ConnectionOperation::CreateChannel);
+        assert_that!(failures[0].reason(), eq DeliveryFailureReason::UnableToEstablishConnection(
+            ZeroCopyCreationError::AnotherInstanceIsAlreadyConnected
+        ));
+        let message = failures[0].to_string();
+        assert_that!(message.contains(&broken_subscriber.id().to_string()), eq true);
+
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1234);
+        assert_that!(broken_subscriber.receive()?, is_none);
+
+        // the publisher retries to establish the broken connection and delivers its history
+        drop(foreign_sender);
+        assert_that!(sut.send_copy(5678), eq Ok(2));
+
+        assert_that!(*subscriber.receive()?.unwrap(), eq 5678);
+        assert_that!(*broken_subscriber.receive()?.unwrap(), eq 1234);
+        assert_that!(*broken_subscriber.receive()?.unwrap(), eq 5678);
+
+        Ok(())
+    }
+
+    #[test]
+    fn connection_error_describes_the_affected_subscriber<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = config_without_intra_process_fast_path();
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let mut sut = service.publisher().create()?;
+        sut.set_degration_callback(Some(|_, _, _| DegrationAction::Fail));
+        let broken_subscriber = service.subscriber().create()?;
+
+        let connection_name = connection_name_of(&sut.id(), &broken_subscriber.id());
+        let _foreign_sender = create_foreign_sender::<Sut>(
+            &config,
+            &connection_name,
+            service.static_config(),
+            broken_subscriber.buffer_size(),
+        );
+
+        let error = match sut.send_copy(1234) {
+            Err(PublisherSendError::ConnectionError(error)) => error,
+            result => panic!("expected a connection error but got {:?}", result),
+        };
+        assert_that!(error.subscriber_id(), eq broken_subscriber.id());
+        assert_that!(*error.service_name(), eq service_name);
+        assert_that!(error.operation(), eq ConnectionOperation::CreateChannel);
+        assert_that!(error.failure(), eq ConnectionFailure::FailedToEstablishConnection(
+            ZeroCopyCreationError::AnotherInstanceIsAlreadyConnected
+        ));
+
+        let message = PublisherSendError::ConnectionError(error.clone()).to_string();
+        assert_that!(message.contains(&broken_subscriber.id().to_string()), eq true);
+        assert_that!(message.contains(service_name.as_str()), eq true);
+        assert_that!(message.lines().count(), eq 1);
+
+        let source = std::error::Error::source(&error).unwrap();
+        assert_that!(source.to_string(), eq ZeroCopyCreationError::AnotherInstanceIsAlreadyConnected.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn sync_publisher_calls_only_the_thread_safe_degration_callback<Sut: Service>() -> TestResult<()>
+    {
+        let service_name = generate_name()?;
+        let config = config_without_intra_process_fast_path();
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let calls_of_publisher_callback = Arc::new(AtomicUsize::new(0));
+        let calls_of_sync_publisher_callback = Arc::new(AtomicUsize::new(0));
+
+        let mut sut = service.publisher().create()?;
+        let calls = calls_of_publisher_callback.clone();
+        sut.set_degration_callback(Some(move |_, _, _| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            DegrationAction::Ignore
+        }));
+        // the callback of the publisher is not required to be thread-safe and is removed
+        let sut = sut.into_sync();
+
+        let broken_subscriber = service.subscriber().create()?;
+        let connection_name = connection_name_of(&sut.id(), &broken_subscriber.id());
+        let _foreign_sender = create_foreign_sender::<Sut>(
+            &config,
+            &connection_name,
+            service.static_config(),
+            broken_subscriber.buffer_size(),
+        );
+
+        let _ = sut.update_connections();
+        assert_that!(calls_of_publisher_callback.load(Ordering::Relaxed), eq 0);
+
+        let calls = calls_of_sync_publisher_callback.clone();
+        sut.set_degration_callback(Some(move |_, _, _| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            DegrationAction::Ignore
+        }));
+
+        // the broken connection is retried with every update
+        let _ = sut.update_connections();
+        assert_that!(calls_of_publisher_callback.load(Ordering::Relaxed), eq 0);
+        assert_that!(calls_of_sync_publisher_callback.load(Ordering::Relaxed), eq 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_update_connections_reports_established_and_removed_connections<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let sut = service.publisher().create()?;
+        assert_that!(sut.update_connections()?.is_empty(), eq true);
+
+        let subscriber = service.subscriber().create()?;
+        let subscriber_id = subscriber.id();
+
+        let diff = sut.update_connections()?;
+        assert_that!(diff.established_connections(), eq[subscriber_id]);
+        assert_that!(diff.removed_connections(), is_empty);
+        assert_that!(sut.update_connections()?.is_empty(), eq true);
+
+        drop(subscriber);
+
+        let diff = sut.update_connections()?;
+        assert_that!(diff.established_connections(), is_empty);
+        assert_that!(diff.removed_connections(), eq[subscriber_id]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_without_implicit_connection_updates_delivers_only_after_explicit_update<
+        Sut: Service,
+    >() -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .history_size(0)
+            .create::<u64>()?;
+
+        let sut = service
+            .publisher()
+            .update_connections_on_send(false)
+            .create()?;
+        let subscriber = service.subscriber().create()?;
+
+        assert_that!(sut.send_copy(1234), eq Ok(0));
+        assert_that!(subscriber.receive()?, is_none);
+
+        let diff = sut.update_connections()?;
+        assert_that!(diff.established_connections(), eq[subscriber.id()]);
+
+        assert_that!(sut.send_copy(5678), eq Ok(1));
+        assert_that!(*subscriber.receive()?.unwrap(), eq 5678);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_power_of_two_strategy_grows_through_multiple_data_segments<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        const MAX_SLICE_LEN: usize = 64;
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(1)
+            .create::<[u64]>()?;
+
+        let sut = service
+            .publisher()
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?;
+        let subscriber = service.subscriber().create()?;
+
+        // samples of the first data segment stay valid while the publisher grows
+        let loaned_sample = sut.loan_slice_uninit(1)?;
+        let sample = sut.loan_slice_uninit(1)?;
+        assert_that!(sample.write_from_slice(&[1]).send(), eq Ok(1));
+        let received_sample = subscriber.receive()?.unwrap();
+
+        for number_of_elements in 2..=MAX_SLICE_LEN {
+            let payload: Vec<u64> = (0..number_of_elements as u64).collect();
+            let sample = sut.loan_slice_uninit(number_of_elements)?;
+            assert_that!(sample.write_from_slice(&payload).send(), eq Ok(1));
+
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(sample.payload(), eq payload.as_slice());
+        }
+
+        assert_that!(*received_sample, eq[1u64]);
+        drop(received_sample);
+
+        assert_that!(loaned_sample.write_from_slice(&[1234]).send(), eq Ok(1));
+        assert_that!(*subscriber.receive()?.unwrap(), eq[1234u64]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_best_fit_strategy_loans_slices_larger_than_max_slice_len<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(2)
+            .create::<[u64]>()?;
+
+        let sut = service
+            .publisher()
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()?;
+        let subscriber = service.subscriber().create()?;
+
+        for number_of_elements in [2, 5, 3, 11] {
+            let payload = vec![number_of_elements as u64; number_of_elements];
+            let sample = sut.loan_slice_uninit(number_of_elements)?;
+            assert_that!(sample.write_from_slice(&payload).send(), eq Ok(1));
+
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(sample.header().number_of_elements(), eq number_of_elements as u64);
+            assert_that!(sample.payload(), eq payload.as_slice());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_bucket_config_loans_from_the_smallest_fitting_bucket<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(4096)
+            .history_size(0)
+            .create::<[u8]>()?;
+
+        let sut = service
+            .publisher()
+            .max_loaned_samples(4)
+            .allocator(BucketConfig::new(&[(512, 2), (64, 4), (4096, 1)]))
+            .create()?;
+        let subscriber = service.subscriber().create()?;
+
+        let buckets = sut.statistics().buckets().to_vec();
+        assert_that!(buckets, len 3);
+        for (bucket, (payload_size, number_of_samples)) in
+            buckets.iter().zip([(64, 4), (512, 2), (4096, 1)])
+        {
+            assert_that!(bucket.payload_size(), eq payload_size);
+            assert_that!(bucket.number_of_samples(), eq number_of_samples);
+            assert_that!(bucket.chunk_size(), ge payload_size);
+            assert_that!(bucket.used_samples(), eq 0);
+        }
+
+        for (number_of_elements, bucket) in [(10, 0), (64, 0), (65, 1), (4096, 2)] {
+            let sample = sut.loan_slice_uninit(number_of_elements)?;
+            let offset = SegmentOffset::from(advanced::offset_of(&sample));
+            assert_that!(offset.segment_id(), eq bucket);
+            assert_that!(sut.statistics().buckets()[bucket].used_samples(), eq 1);
+
+            let payload = vec![number_of_elements as u8; number_of_elements];
+            assert_that!(sample.write_from_slice(&payload).send(), eq Ok(1));
+            let received_sample = subscriber.receive()?.unwrap();
+            assert_that!(received_sample.payload(), eq payload.as_slice());
+            drop(received_sample);
+
+            // the sample is returned to its bucket with the next loan
+            let sample = sut.loan_slice_uninit(number_of_elements)?;
+            assert_that!(sut.statistics().buckets()[bucket].used_samples(), eq 1);
+            drop(sample);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_bucket_config_reports_the_exhausted_bucket<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(1024)
+            .history_size(0)
+            .create::<[u8]>()?;
+
+        let sut = service
+            .publisher()
+            .max_loaned_samples(4)
+            .allocator(BucketConfig::new(&[(16, 1), (256, 1)]))
+            .create()?;
+
+        let small_sample = sut.loan_slice_uninit(8)?;
+        assert_that!(
+            sut.loan_slice_uninit(8).err(),
+            eq Some(PublisherLoanError::BucketExhausted { bucket_size: 16 })
+        );
+
+        // the larger bucket is no fallback for the exhausted one but serves its own size
+        let large_sample = sut.loan_slice_uninit(100)?;
+        assert_that!(
+            sut.loan_slice_uninit(100).err(),
+            eq Some(PublisherLoanError::BucketExhausted { bucket_size: 256 })
+        );
+        assert_that!(
+            sut.loan_slice_uninit(257).err(),
+            eq Some(PublisherLoanError::ExceedsMaxLoanSize)
+        );
+
+        drop(small_sample);
+        assert_that!(sut.loan_slice_uninit(16), is_ok);
+
+        let statistics = sut.statistics();
+        assert_that!(statistics.buckets()[0].used_samples(), eq 0);
+        assert_that!(statistics.buckets()[1].used_samples(), eq 1);
+        drop(large_sample);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_bucket_config_reuses_the_samples_that_leave_the_history<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        const HISTORY_SIZE: usize = 2;
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(1024)
+            .history_size(HISTORY_SIZE)
+            .create::<[u8]>()?;
+
+        let sut = service
+            .publisher()
+            .max_loaned_samples(1)
+            .allocator(BucketConfig::new(&[(16, HISTORY_SIZE + 1), (256, 4)]))
+            .create()?;
+
+        // a sample that is replaced in the history returns to its bucket
+        for i in 0..4 * HISTORY_SIZE {
+            let sample = sut.loan_slice_uninit(8)?;
+            assert_that!(sample.write_from_slice(&[i as u8; 8]).send(), eq Ok(0));
+            assert_that!(sut.statistics().buckets()[0].used_samples(), le HISTORY_SIZE + 1);
+        }
+
+        let subscriber = service.subscriber().create()?;
+        assert_that!(sut.update_connections(), is_ok);
+        for i in 4 * HISTORY_SIZE - HISTORY_SIZE..4 * HISTORY_SIZE {
+            let payload = [i as u8; 8];
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(sample.payload(), eq payload.as_slice());
+        }
+
+        let sut = service
+            .publisher()
+            .allocator(BucketConfig::new(&[(16, HISTORY_SIZE), (256, 4)]))
+            .create();
+        assert_that!(sut.err(), eq Some(PublisherCreateError::InvalidBucketConfig));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_bucket_config_reserves_the_memory_of_all_buckets<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(1 << 16)
+            .create::<[u8]>()?;
+
+        let sut = service
+            .publisher()
+            .allocator(BucketConfig::new(&[(64, 32), (4096, 8), (1 << 16, 2)]))
+            .create()?;
+
+        let payload_offset =
+            advanced::payload_offset::<()>(service.static_config().payload_alignment());
+        let mut lower_bound = 0;
+        let mut upper_bound = 0;
+        for bucket in sut.statistics().buckets() {
+            assert_that!(bucket.chunk_size(), ge payload_offset + bucket.payload_size());
+            lower_bound += bucket.chunk_size() * bucket.number_of_samples();
+            // every segment is padded by less than one chunk to align its first sample
+            upper_bound += bucket.chunk_size() * (bucket.number_of_samples() + 1);
+        }
+
+        assert_that!(sut.memory_budget(), ge lower_bound);
+        assert_that!(sut.memory_budget(), lt upper_bound);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_invalid_bucket_config_cannot_be_created<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(1024)
+            .create::<[u64]>()?;
+
+        let too_many_buckets: Vec<(usize, usize)> = (1..=MAX_NUMBER_OF_BUCKETS + 1)
+            .map(|n| (n * 8, 1))
+            .collect();
+        for bucket_config in [
+            BucketConfig::new(&[]),
+            BucketConfig::new(&[(64, 1), (64, 2)]),
+            BucketConfig::new(&[(64, 0)]),
+            BucketConfig::new(&[(0, 1)]),
+            // a bucket smaller than a single u64
+            BucketConfig::new(&[(4, 1), (64, 1)]),
+            BucketConfig::new(&too_many_buckets),
+        ] {
+            let sut = service.publisher().allocator(bucket_config).create();
+            assert_that!(sut.err(), eq Some(PublisherCreateError::InvalidBucketConfig));
+        }
+
+        let sut = service
+            .publisher()
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .allocator(BucketConfig::new(&[(64, 1)]))
+            .create();
+        assert_that!(
+            sut.err(),
+            eq Some(PublisherCreateError::IncompatibleAllocationStrategy)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_zero_on_acquire_loans_zeroed_payloads<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
+        const MAX_SLICE_LEN: usize = 8;
+        const MAX_LOANED_SAMPLES: usize = 2;
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(MAX_SLICE_LEN)
+            .create::<[u64]>()?;
+
+        let sut = service
+            .publisher()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .memory_hygiene(MemoryHygiene::ZeroOnAcquire)
+            .create()?;
+        assert_that!(service.publisher().effective_memory_hygiene(), eq MemoryHygiene::None);
+
+        for number_of_elements in [MAX_SLICE_LEN, 3, 1] {
+            let samples = (0..MAX_LOANED_SAMPLES)
+                .map(|_| {
+                    sut.loan_slice_uninit(MAX_SLICE_LEN)
+                        .map(|sample| sample.write_from_fn(|_| u64::MAX))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            drop(samples);
+
+            let mut sample = sut.loan_slice_uninit(number_of_elements)?;
+            let payload = unsafe {
+                std::slice::from_raw_parts(
+                    sample.payload_mut().as_ptr() as *const u64,
+                    number_of_elements,
+                )
+            };
+            assert_that!(payload.iter().all(|v| *v == 0), eq true);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_zero_on_release_zeroes_the_payload_after_the_last_release<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        const NUMBER_OF_ELEMENTS: usize = 3;
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(8)
+            .history_size(0)
+            .create::<[u64]>()?;
+
+        let sut = service
+            .publisher()
+            .memory_hygiene(MemoryHygiene::ZeroOnRelease)
+            .max_loaned_samples(1)
+            .create()?;
+        let subscriber = service.subscriber().create()?;
+
+        // the data segment stays mapped by the publisher, the released payload can be inspected
+        let mut sample = sut.loan_slice_uninit(NUMBER_OF_ELEMENTS)?;
+        let payload = sample.payload_mut().as_ptr() as *const u64;
+        let sample = sample.write_from_fn(|n| n as u64 + 1);
+        drop(sample);
+        let payload = unsafe { std::slice::from_raw_parts(payload, NUMBER_OF_ELEMENTS) };
+        assert_that!(payload.iter().all(|v| *v == 0), eq true);
+
+        let sample = sut.loan_slice_uninit(NUMBER_OF_ELEMENTS)?;
+        assert_that!(sample.write_from_fn(|n| n as u64 + 1).send(), eq Ok(1));
+        let received_sample = subscriber.receive()?.unwrap();
+        assert_that!(received_sample.payload(), eq [1u64, 2, 3].as_slice());
+        let payload = unsafe {
+            std::slice::from_raw_parts(received_sample.payload().as_ptr(), NUMBER_OF_ELEMENTS)
+        };
+
+        // the returned sample is released with the next loan, it fails since another sample is
+        // loaned already so that the released chunk is not handed out again
+        let _sample = sut.loan_slice_uninit(1)?;
+        drop(received_sample);
+        assert_that!(sut.loan_slice_uninit(1).err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+        assert_that!(payload.iter().all(|v| *v == 0), eq true);
+
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn publisher_with_locked_memory_increases_the_locked_memory_of_the_process<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        let _guard = LOCKED_MEMORY_LOCK.lock().unwrap();
+        let service_name = generate_name()?;
+        // a small data segment that fits into the default memory lock limit
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_publishers(1)
+            .max_subscribers(1)
+            .history_size(0)
+            .subscriber_max_buffer_size(1)
+            .create::<u64>()?;
+
+        let locked_memory_before = locked_memory_of_process_in_kb();
+        let publisher = service
+            .publisher()
+            .max_loaned_samples(1)
+            .lock_memory(true)
+            .create()?;
+        assert_that!(locked_memory_of_process_in_kb(), gt locked_memory_before);
+
+        publisher.send_copy(1234)?;
+
+        drop(publisher);
+        assert_that!(locked_memory_of_process_in_kb(), eq locked_memory_before);
+
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn publisher_with_locked_memory_fails_when_the_memory_lock_limit_is_exceeded<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        let _guard = LOCKED_MEMORY_LOCK.lock().unwrap();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let limit = ProcessResourceLimit::MaxLockedMemory;
+        let soft_limit = limit.soft_limit();
+        limit.set_soft_limit(0);
+        let sut = service.publisher().lock_memory(true).create();
+        limit.set_soft_limit(soft_limit);
+
+        // the memory lock limit does not apply to processes with the CAP_IPC_LOCK capability
+        if has_capability_to_lock_memory() {
+            assert_that!(sut, is_ok);
+        } else {
+            assert_that!(sut.err(), eq Some(PublisherCreateError::UnableToLockMemory));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_huge_pages_and_prefault_delivers_samples<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let sut = service.publisher().huge_page_threshold(0).prefault(true);
+        assert_that!(sut.effective_huge_page_threshold(), eq Some(0));
+        assert_that!(sut.effective_prefault(), eq true);
+        assert_that!(sut.effective_lock_memory(), eq false);
+
+        // falls back to the default page size when huge pages are unavailable
+        let publisher = sut.create()?;
+        let subscriber = service.subscriber().create()?;
+
+        publisher.send_copy(1234)?;
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1234);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_strict_huge_pages_fails_when_the_segment_is_not_backed_by_huge_pages<
+        Sut: Service,
+    >() -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        // the data segment is smaller than a huge page and cannot be backed by one
+        let sut = service
+            .publisher()
+            .huge_page_threshold(0)
+            .strict_huge_pages(true)
+            .create();
+        assert_that!(sut.err(), eq Some(PublisherCreateError::HugePagesUnavailable));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_strict_huge_pages_ignores_segments_below_the_threshold<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let publisher = service
+            .publisher()
+            .huge_page_threshold(usize::MAX)
+            .strict_huge_pages(true)
+            .create()?;
+        let subscriber = service.subscriber().create()?;
+
+        publisher.send_copy(1234)?;
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1234);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publishers_of_the_same_process_have_different_ids<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_publishers(2)
+            .create::<u64>()?;
+
+        let sut_1 = service.publisher().create()?;
+        let sut_2 = service.publisher().create()?;
+
+        assert_that!(sut_1.id(), ne sut_2.id());
+        assert_that!(sut_1.id().to_string(), ne sut_2.id().to_string());
+        assert_that!((sut_1.id().value() >> 96) as u32, eq std::process::id());
+
+        Ok(())
+    }
+
+    #[test]
+    fn port_ids_can_be_restored_from_their_serialized_form<Sut: Service>() -> TestResult<()> {
+        let config = generate_isolated_config();
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()?;
+
+        let publisher = service.publisher().create()?;
+        let subscriber = service.subscriber().create()?;
+
+        let publisher_id = publisher.id();
+        assert_that!(UniquePublisherId::from(u128::from(publisher_id)), eq publisher_id);
+        assert_that!(UniquePublisherId::from_bytes(publisher_id.to_bytes()), eq publisher_id);
+        assert_that!(publisher_id.to_string(), eq format!("{:032x}", publisher_id.value()));
+
+        let subscriber_id = subscriber.id();
+        assert_that!(UniqueSubscriberId::from(subscriber_id.value()), eq subscriber_id);
+        assert_that!(UniqueSubscriberId::from_bytes(subscriber_id.to_bytes()), eq subscriber_id);
+
+        Ok(())
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
@@ -0,0 +1,153 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "serde")]
+
+use iceoryx2::config::Config;
+use iceoryx2::port::event_id::EventId;
+use iceoryx2::port::port_identifiers::*;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_testing::assert_that;
+
+fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(value: &T) -> T {
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+#[test]
+fn config_round_trip_preserves_all_entries() {
+    let mut sut = Config::default();
+    sut.global.prefix = "serde_tests_".to_string();
+    sut.defaults.publish_subscribe.subscriber_max_buffer_size = 17;
+    sut.defaults.event.event_id_max_value = 123;
+
+    let json = serde_json::to_value(&sut).unwrap();
+    let restored: Config = serde_json::from_value(json.clone()).unwrap();
+
+    assert_that!(restored.global.prefix, eq "serde_tests_");
+    assert_that!(restored.defaults.publish_subscribe.subscriber_max_buffer_size, eq 17);
+    assert_that!(restored.defaults.event.event_id_max_value, eq 123);
+    assert_that!(serde_json::to_value(&restored).unwrap(), eq json);
+}
+
+#[test]
+fn config_with_zero_limit_is_rejected() {
+    let mut json = serde_json::to_value(Config::default()).unwrap();
+    json["defaults"]["request_response"]["max_active_requests"] = serde_json::json!(0);
+
+    let sut = serde_json::from_value::<Config>(json);
+    assert_that!(sut, is_err);
+    assert_that!(
+        sut.err().unwrap().to_string().contains("a value greater than zero"),
+        eq true
+    );
+}
+
+#[test]
+fn service_name_and_event_id_round_trip() {
+    let service_name = ServiceName::new("Some/Service/Name").unwrap();
+    assert_that!(serde_json::to_string(&service_name).unwrap(), eq "\"Some/Service/Name\"");
+    assert_that!(round_trip(&service_name), eq service_name);
+
+    let event_id = EventId::new(8129);
+    assert_that!(round_trip(&event_id), eq event_id);
+}
+
+#[test]
+fn port_ids_are_serialized_as_hexadecimal_strings() {
+    let publisher_id = UniquePublisherId::new();
+    assert_that!(
+        serde_json::to_string(&publisher_id).unwrap(),
+        eq format!("\"{}\"", publisher_id)
+    );
+
+    assert_that!(round_trip(&publisher_id), eq publisher_id);
+    let subscriber_id = UniqueSubscriberId::new();
+    assert_that!(round_trip(&subscriber_id), eq subscriber_id);
+    let notifier_id = UniqueNotifierId::new();
+    assert_that!(round_trip(&notifier_id), eq notifier_id);
+    let listener_id = UniqueListenerId::new();
+    assert_that!(round_trip(&listener_id), eq listener_id);
+    let client_id = UniqueClientId::new();
+    assert_that!(round_trip(&client_id), eq client_id);
+    let server_id = UniqueServerId::new();
+    assert_that!(round_trip(&server_id), eq server_id);
+
+    assert_that!(
+        serde_json::from_str::<UniquePublisherId>("\"1234\""),
+        is_err
+    );
+    assert_that!(
+        serde_json::from_str::<UniquePublisherId>("\"not an id\""),
+        is_err
+    );
+}
+
+#[generic_tests::define]
+mod service_details {
+    use super::round_trip;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Details;
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn service_details_round_trip<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let service_name = ServiceName::new(&format!(
+            "serde_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let _subscriber = sut.subscriber().create().unwrap();
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+
+        let details = Sut::list_details_with_custom_config(&config).unwrap();
+        assert_that!(details, len 1);
+        assert_that!(details[0].publisher_statistics(), len 1);
+
+        let restored = round_trip(&details[0]);
+        assert_that!(restored, eq details[0]);
+        assert_that!(
+            round_trip(details[0].static_details()),
+            eq details[0].static_details().clone()
+        );
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
+
+#[test]
+fn fixed_size_containers_round_trip() {
+    let vec = FixedSizeVec::<u32, 4>::try_from([1, 2, 3].as_slice()).unwrap();
+    assert_that!(serde_json::to_string(&vec).unwrap(), eq "[1,2,3]");
+    assert_that!(round_trip(&vec), eq vec);
+
+    let string = FixedSizeByteString::<16>::try_from("hello").unwrap();
+    assert_that!(serde_json::to_string(&string).unwrap(), eq "\"hello\"");
+    assert_that!(round_trip(&string), eq string);
+}
+
+#[test]
+fn fixed_size_containers_exceeding_the_capacity_fail_to_deserialize() {
+    assert_that!(serde_json::from_str::<FixedSizeVec<u32, 2>>("[1,2,3]"), is_err);
+    assert_that!(serde_json::from_str::<FixedSizeByteString<4>>("\"hello\""), is_err);
+}
@@ -0,0 +1,236 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_attribute {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::attribute::{AttributeRequirement, AttributeSpecifier, AttributeVerifier};
+    use iceoryx2::service::builder::event::EventOpenError;
+    use iceoryx2::service::builder::publish_subscribe::{
+        PublishSubscribeOpenError, PublishSubscribeOpenOrCreateError,
+    };
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_attribute_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn deployment_attributes() -> AttributeSpecifier {
+        AttributeSpecifier::new()
+            .define("schema_version", "3")
+            .unwrap()
+            .define("owner", "vision_team")
+            .unwrap()
+            .define("owner", "fusion_team")
+            .unwrap()
+    }
+
+    #[test]
+    fn attributes_of_created_service_are_available_when_it_is_opened<Sut: Service>() {
+        let service_name = generate_name();
+        let sut_create = Sut::new(&service_name)
+            .publish_subscribe::<u64>()
+            .attributes(&deployment_attributes())
+            .create()
+            .unwrap();
+        let specifier = deployment_attributes();
+        assert_that!(sut_create.attributes(), eq specifier.attributes());
+
+        let sut_open = Sut::new(&service_name)
+            .publish_subscribe::<u64>()
+            .required_attributes(
+                &AttributeVerifier::new()
+                    .require("schema_version", "3")
+                    .require("owner", "fusion_team")
+                    .require_key("owner"),
+            )
+            .open()
+            .unwrap();
+
+        let attributes = sut_open.attributes();
+        assert_that!(attributes.len(), eq 3);
+        assert_that!(attributes.get_key_values("schema_version"), eq vec!["3"]);
+        assert_that!(attributes.get_key_values("owner"), eq vec!["vision_team", "fusion_team"]);
+        assert_that!(attributes.get_key_values("does_not_exist"), is_empty);
+
+        let sut_open_without_requirements = Sut::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+        assert_that!(sut_open_without_requirements.attributes(), eq attributes);
+    }
+
+    #[test]
+    fn open_fails_when_attribute_requirements_are_not_satisfied<Sut: Service>() {
+        let service_name = generate_name();
+        let sut_create = Sut::new(&service_name)
+            .publish_subscribe::<u64>()
+            .attributes(&deployment_attributes())
+            .create()
+            .unwrap();
+
+        let verifier = AttributeVerifier::new()
+            .require("schema_version", "3")
+            .require("schema_version", "4")
+            .require_key("deployment");
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe::<u64>()
+            .required_attributes(&verifier)
+            .open();
+        let unsatisfied = vec![
+            AttributeRequirement::KeyValue("schema_version".to_string(), "4".to_string()),
+            AttributeRequirement::Key("deployment".to_string()),
+        ];
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleAttributes(unsatisfied.clone()));
+
+        assert_that!(verifier.verify_requirements(sut_create.attributes()).err(), eq Some(unsatisfied));
+    }
+
+    #[test]
+    fn event_service_supports_attributes<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .event()
+            .create_with_attributes(&deployment_attributes())
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .open_with_attributes(&AttributeVerifier::new().require("owner", "vision_team"))
+            .unwrap();
+        assert_that!(sut.attributes().get_key_values("schema_version"), eq vec!["3"]);
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .open_with_attributes(&AttributeVerifier::new().require("owner", "nobody"));
+        assert_that!(sut.err().unwrap(), eq EventOpenError::IncompatibleAttributes(vec![
+            AttributeRequirement::KeyValue("owner".to_string(), "nobody".to_string())
+        ]));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn open_or_create_with_attributes_creates_service_with_required_attributes<Sut: Service>() {
+        let service_name = generate_name();
+        let verifier = AttributeVerifier::new().require("schema_version", "3");
+
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_or_create_with_attributes::<u64>(&verifier)
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_or_create_with_attributes::<u64>(&verifier)
+            .unwrap();
+        assert_that!(sut.attributes().get_key_values("schema_version"), eq vec!["3"]);
+    }
+
+    #[test]
+    fn open_or_create_applies_attributes_only_when_it_creates_the_service<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut_create = Sut::new(&service_name)
+            .publish_subscribe::<u64>()
+            .attributes(&deployment_attributes())
+            .required_attributes(&AttributeVerifier::new().require_key("owner"))
+            .open_or_create()
+            .unwrap();
+        let specifier = deployment_attributes();
+        assert_that!(sut_create.attributes(), eq specifier.attributes());
+
+        let sut_open = Sut::new(&service_name)
+            .publish_subscribe::<u64>()
+            .attributes(&AttributeSpecifier::new().define("owner", "nobody").unwrap())
+            .required_attributes(&AttributeVerifier::new().require("owner", "vision_team"))
+            .open_or_create()
+            .unwrap();
+        assert_that!(sut_open.attributes(), eq specifier.attributes());
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe::<u64>()
+            .attributes(&deployment_attributes())
+            .required_attributes(&AttributeVerifier::new().require("owner", "nobody"))
+            .open_or_create();
+        let unsatisfied = vec![AttributeRequirement::KeyValue(
+            "owner".to_string(),
+            "nobody".to_string(),
+        )];
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(
+            PublishSubscribeOpenError::IncompatibleAttributes(unsatisfied)));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_publish_subscribe_attribute_calls_still_work<Sut: Service>() {
+        let service_name = generate_name();
+
+        let _sut_create = Sut::new(&service_name)
+            .publish_subscribe()
+            .create_with_attributes::<u64>(&deployment_attributes())
+            .unwrap();
+
+        let sut_open = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_with_attributes::<u64>(&AttributeVerifier::new().require("owner", "vision_team"))
+            .unwrap();
+        let specifier = deployment_attributes();
+        assert_that!(sut_open.attributes(), eq specifier.attributes());
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_with_attributes::<u64>(&AttributeVerifier::new().require("owner", "nobody"));
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleAttributes(vec![
+            AttributeRequirement::KeyValue("owner".to_string(), "nobody".to_string())
+        ]));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
+
+mod attribute_definition {
+    use iceoryx2::service::attribute::{
+        AttributeDefinitionError, AttributeSpecifier, MAX_ATTRIBUTE_KEY_LENGTH,
+        MAX_ATTRIBUTE_VALUE_LENGTH,
+    };
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn attribute_definition_with_exceeding_length_fails() {
+        let max_key = "k".repeat(MAX_ATTRIBUTE_KEY_LENGTH);
+        let max_value = "v".repeat(MAX_ATTRIBUTE_VALUE_LENGTH);
+
+        assert_that!(AttributeSpecifier::new().define(&max_key, &max_value), is_ok);
+        assert_that!(
+            AttributeSpecifier::new().define(&(max_key.clone() + "k"), "value").err().unwrap(),
+            eq AttributeDefinitionError::KeyExceedsMaxLength
+        );
+        assert_that!(
+            AttributeSpecifier::new().define("key", &(max_value + "v")).err().unwrap(),
+            eq AttributeDefinitionError::ValueExceedsMaxLength
+        );
+        assert_that!(
+            AttributeSpecifier::new().define("", "value").err().unwrap(),
+            eq AttributeDefinitionError::KeyIsEmpty
+        );
+    }
+}
@@ -0,0 +1,303 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// The child process is spawned by re-executing this test binary with a single ignored test
+// selected, it terminates at the requested step of the service or port creation.
+mod service_creation_fault_injection {
+    use std::process::Command;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Details;
+    use iceoryx2::testing::{
+        abort_port_creation_at, abort_service_creation_at, PortCreationStep, ServiceCreationStep,
+    };
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const PREFIX_VARIABLE: &str = "IOX2_CROSS_PROCESS_CREATION_FAULT_PREFIX";
+    const STEP_VARIABLE: &str = "IOX2_CROSS_PROCESS_CREATION_FAULT_STEP";
+    const PORT_VARIABLE: &str = "IOX2_CROSS_PROCESS_CREATION_FAULT_PORT";
+    const SERVICE_NAME: &str = "service_creation_fault_injection_tests";
+
+    fn config_with_prefix(prefix: &str) -> Config {
+        let mut config = Config::default();
+        config.global.prefix = prefix.to_string();
+        config
+    }
+
+    fn generate_prefix() -> String {
+        format!(
+            "creation_fault_tests_{}_",
+            UniqueSystemId::new().unwrap().value()
+        )
+    }
+
+    // The terminated child stays registered in the dynamic config, the last port of the parent
+    // therefore cannot remove the service and it has to be cleaned up as stale resource. The data
+    // segment of a publisher that was aborted before it was registered is not referenced by the
+    // service and remains.
+    fn remove_stale_resources(config: &Config) {
+        assert_that!(zero_copy::Service::remove_stale_resources_with_custom_config(config), eq Ok(1));
+    }
+
+    fn step_from_str(value: &str) -> ServiceCreationStep {
+        match value {
+            "StaticConfigCreated" => ServiceCreationStep::StaticConfigCreated,
+            "DynamicConfigInitialization" => ServiceCreationStep::DynamicConfigInitialization,
+            "DynamicConfigCreated" => ServiceCreationStep::DynamicConfigCreated,
+            _ => panic!("unknown service creation step {}", value),
+        }
+    }
+
+    fn port_step_from_str(value: &str) -> PortCreationStep {
+        match value {
+            "ResourcesCreated" => PortCreationStep::ResourcesCreated,
+            "AddedToDynamicConfig" => PortCreationStep::AddedToDynamicConfig,
+            _ => panic!("unknown port creation step {}", value),
+        }
+    }
+
+    fn run_child(test: &str, prefix: &str, variables: &[(&str, String)]) -> Option<i32> {
+        let mut command = Command::new(std::env::current_exe().unwrap());
+        command
+            .args([
+                &format!("service_creation_fault_injection::{}", test),
+                "--exact",
+                "--ignored",
+                "--nocapture",
+            ])
+            .env(PREFIX_VARIABLE, prefix);
+        for (name, value) in variables {
+            command.env(name, value);
+        }
+
+        command.status().unwrap().code()
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_aborts_service_creation() {
+        let (prefix, step) = match (std::env::var(PREFIX_VARIABLE), std::env::var(STEP_VARIABLE)) {
+            (Ok(prefix), Ok(step)) => (prefix, step),
+            _ => return,
+        };
+
+        abort_service_creation_at(step_from_str(&step));
+        let _ = zero_copy::Service::new(&ServiceName::new(SERVICE_NAME).unwrap())
+            .publish_subscribe_with_custom_config(&config_with_prefix(&prefix))
+            .create::<u64>();
+
+        // the creation has to terminate the process
+        std::process::exit(0);
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_aborts_port_creation() {
+        let (prefix, step, port) = match (
+            std::env::var(PREFIX_VARIABLE),
+            std::env::var(STEP_VARIABLE),
+            std::env::var(PORT_VARIABLE),
+        ) {
+            (Ok(prefix), Ok(step), Ok(port)) => (prefix, step, port),
+            _ => return,
+        };
+        let config = config_with_prefix(&prefix);
+
+        let sut = zero_copy::Service::new(&ServiceName::new(SERVICE_NAME).unwrap())
+            .publish_subscribe_with_custom_config(&config)
+            .open::<u64>()
+            .unwrap();
+
+        abort_port_creation_at(port_step_from_str(&step));
+        match port.as_str() {
+            "Publisher" => {
+                let _ = sut.publisher().create();
+            }
+            "Subscriber" => {
+                let _ = sut.subscriber().create();
+            }
+            _ => panic!("unknown port {}", port),
+        }
+
+        // the creation has to terminate the process
+        std::process::exit(0);
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_creates_publisher_and_terminates() {
+        let prefix = match std::env::var(PREFIX_VARIABLE) {
+            Ok(prefix) => prefix,
+            Err(_) => return,
+        };
+        let config = config_with_prefix(&prefix);
+
+        let sut = zero_copy::Service::new(&ServiceName::new(SERVICE_NAME).unwrap())
+            .publish_subscribe_with_custom_config(&config)
+            .open::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        // terminates without removing the publisher from the service
+        std::mem::forget(publisher);
+        std::mem::forget(sut);
+        std::process::exit(0);
+    }
+
+    fn open_or_create_succeeds_after_creation_was_aborted_at(step: ServiceCreationStep) {
+        let prefix = generate_prefix();
+        let config = config_with_prefix(&prefix);
+        let service_name = ServiceName::new(SERVICE_NAME).unwrap();
+
+        let variables = [(STEP_VARIABLE, format!("{:?}", step))];
+        assert_that!(run_child("child_aborts_service_creation", &prefix, &variables), eq Some(1));
+
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .open_or_create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+    }
+
+    #[test]
+    fn open_or_create_succeeds_after_creation_was_aborted_after_static_config_creation() {
+        open_or_create_succeeds_after_creation_was_aborted_at(
+            ServiceCreationStep::StaticConfigCreated,
+        );
+    }
+
+    #[test]
+    fn open_or_create_succeeds_after_creation_was_aborted_during_dynamic_config_initialization() {
+        open_or_create_succeeds_after_creation_was_aborted_at(
+            ServiceCreationStep::DynamicConfigInitialization,
+        );
+    }
+
+    #[test]
+    fn open_or_create_succeeds_after_creation_was_aborted_after_dynamic_config_creation() {
+        open_or_create_succeeds_after_creation_was_aborted_at(
+            ServiceCreationStep::DynamicConfigCreated,
+        );
+    }
+
+    #[test]
+    fn open_fails_with_does_not_exist_after_creation_was_aborted() {
+        let prefix = generate_prefix();
+        let config = config_with_prefix(&prefix);
+        let service_name = ServiceName::new(SERVICE_NAME).unwrap();
+
+        let variables = [(
+            STEP_VARIABLE,
+            format!("{:?}", ServiceCreationStep::DynamicConfigCreated),
+        )];
+        assert_that!(run_child("child_aborts_service_creation", &prefix, &variables), eq Some(1));
+
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .open::<u64>();
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenError::DoesNotExist));
+
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn publisher_slot_of_terminated_process_is_reused() {
+        let prefix = generate_prefix();
+        let config = config_with_prefix(&prefix);
+        let service_name = ServiceName::new(SERVICE_NAME).unwrap();
+
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_publishers(1)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(run_child("child_creates_publisher_and_terminates", &prefix, &[]), eq Some(0));
+
+        let publisher = sut.publisher().create();
+        assert_that!(publisher, is_ok);
+
+        drop(publisher);
+        drop(sut);
+        remove_stale_resources(&config);
+    }
+
+    fn port_slot_is_reused_after_creation_was_aborted_at(port: &str, step: PortCreationStep) {
+        let prefix = generate_prefix();
+        let config = config_with_prefix(&prefix);
+        let service_name = ServiceName::new(SERVICE_NAME).unwrap();
+
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .max_publishers(1)
+            .max_subscribers(1)
+            .create::<u64>()
+            .unwrap();
+
+        let variables = [
+            (STEP_VARIABLE, format!("{:?}", step)),
+            (PORT_VARIABLE, port.to_string()),
+        ];
+        assert_that!(run_child("child_aborts_port_creation", &prefix, &variables), eq Some(1));
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+
+        drop(publisher);
+        drop(subscriber);
+        drop(sut);
+        remove_stale_resources(&config);
+    }
+
+    #[test]
+    fn publisher_can_be_created_after_creation_was_aborted_after_resource_creation() {
+        port_slot_is_reused_after_creation_was_aborted_at(
+            "Publisher",
+            PortCreationStep::ResourcesCreated,
+        );
+    }
+
+    #[test]
+    fn publisher_slot_is_reused_after_creation_was_aborted_after_registration() {
+        port_slot_is_reused_after_creation_was_aborted_at(
+            "Publisher",
+            PortCreationStep::AddedToDynamicConfig,
+        );
+    }
+
+    #[test]
+    fn subscriber_can_be_created_after_creation_was_aborted_after_resource_creation() {
+        port_slot_is_reused_after_creation_was_aborted_at(
+            "Subscriber",
+            PortCreationStep::ResourcesCreated,
+        );
+    }
+
+    #[test]
+    fn subscriber_slot_is_reused_after_creation_was_aborted_after_registration() {
+        port_slot_is_reused_after_creation_was_aborted_at(
+            "Subscriber",
+            PortCreationStep::AddedToDynamicConfig,
+        );
+    }
+}
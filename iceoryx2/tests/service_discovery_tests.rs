@@ -0,0 +1,140 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_discovery {
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::discovery::{ServiceDiscovery, ServiceEvent};
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_discovery_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn existing_services_are_part_of_the_initial_snapshot<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+
+        let _service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+
+        let mut sut = ServiceDiscovery::<Sut::Type<'_>>::with_custom_config(&config).unwrap();
+
+        assert_that!(sut.services(), len 1);
+        assert_that!(sut.services()[0].service_name(), eq & service_name);
+        assert_that!(sut.try_wait_for_change().unwrap(), len 0);
+    }
+
+    #[test]
+    fn created_service_is_reported<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+
+        let mut sut = ServiceDiscovery::<Sut::Type<'_>>::with_custom_config(&config).unwrap();
+        assert_that!(sut.services(), len 0);
+
+        let service = Sut::new(&service_name)
+            .event_with_custom_config(&config)
+            .create()
+            .unwrap();
+
+        let events = sut.timed_wait_for_change(TIMEOUT).unwrap();
+        assert_that!(events, len 1);
+        assert_that!(events[0], eq ServiceEvent::Created(sut.services()[0].clone()));
+        assert_that!(events[0].details().service_name(), eq & service_name);
+        assert_that!(events[0].details().uuid(), eq service.uuid());
+    }
+
+    #[test]
+    fn removed_service_is_reported<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+
+        let mut sut = ServiceDiscovery::<Sut::Type<'_>>::with_custom_config(&config).unwrap();
+        let details = sut.services()[0].clone();
+
+        drop(service);
+
+        let events = sut.timed_wait_for_change(TIMEOUT).unwrap();
+        assert_that!(events, len 1);
+        assert_that!(events[0], eq ServiceEvent::Removed(details));
+        assert_that!(sut.services(), len 0);
+    }
+
+    #[test]
+    fn recreated_service_is_reported_as_removed_and_created<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+
+        let mut sut = ServiceDiscovery::<Sut::Type<'_>>::with_custom_config(&config).unwrap();
+
+        drop(service);
+        let _service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+
+        let mut events = vec![];
+        while events.len() < 2 {
+            let changes = sut.timed_wait_for_change(TIMEOUT).unwrap();
+            assert_that!(changes, is_not_empty);
+            events.extend(changes);
+        }
+
+        assert_that!(events, len 2);
+        assert_that!(matches!(events[0], ServiceEvent::Removed(_)), eq true);
+        assert_that!(matches!(events[1], ServiceEvent::Created(_)), eq true);
+        assert_that!(events[0].details().instance_uuid(), ne events[1].details().instance_uuid());
+    }
+
+    #[test]
+    fn timed_wait_without_change_returns_no_events<Sut: Service>() {
+        let config = generate_isolated_config();
+
+        let mut sut = ServiceDiscovery::<Sut::Type<'_>>::with_custom_config(&config).unwrap();
+
+        let events = sut
+            .timed_wait_for_change(Duration::from_millis(10))
+            .unwrap();
+        assert_that!(events, len 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
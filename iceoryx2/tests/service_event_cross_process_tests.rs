@@ -0,0 +1,130 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// The child processes are spawned by re-executing this test binary with a single ignored test
+// selected, the service name is handed over via an environment variable.
+mod service_event_cross_process {
+    use std::process::{Child, Command};
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const SERVICE_NAME_VARIABLE: &str = "IOX2_CROSS_PROCESS_EVENT_SERVICE_NAME";
+    const TIMEOUT: Duration = Duration::from_secs(10);
+    const EVENT_ID: EventId = EventId::new(29);
+    const ANOTHER_EVENT_ID: EventId = EventId::new(1021);
+    const NUMBER_OF_REPETITIONS: usize = 5;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn spawn_child(test_name: &str, service_name: &ServiceName) -> Child {
+        Command::new(std::env::current_exe().unwrap())
+            .args([
+                &format!("service_event_cross_process::{}", test_name),
+                "--exact",
+                "--ignored",
+                "--nocapture",
+            ])
+            .env(SERVICE_NAME_VARIABLE, service_name.as_str())
+            .spawn()
+            .unwrap()
+    }
+
+    fn service_name_of_parent() -> Option<ServiceName> {
+        std::env::var(SERVICE_NAME_VARIABLE)
+            .ok()
+            .map(|name| ServiceName::new(&name).unwrap())
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_notifies_once() {
+        let service_name = match service_name_of_parent() {
+            Some(name) => name,
+            None => return,
+        };
+
+        let sut = zero_copy::Service::new(&service_name)
+            .event()
+            .open()
+            .unwrap();
+        let notifier = sut.notifier().default_event_id(EVENT_ID).create().unwrap();
+
+        assert_that!(notifier.notify(), eq Ok(1));
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_notifies_repeatedly() {
+        let service_name = match service_name_of_parent() {
+            Some(name) => name,
+            None => return,
+        };
+
+        let sut = zero_copy::Service::new(&service_name)
+            .event()
+            .open()
+            .unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        for _ in 0..NUMBER_OF_REPETITIONS {
+            assert_that!(notifier.notify_with_custom_event_id(EVENT_ID), eq Ok(1));
+            assert_that!(notifier.notify_with_custom_event_id(ANOTHER_EVENT_ID), eq Ok(1));
+        }
+    }
+
+    #[test]
+    fn listener_is_woken_up_by_notifier_of_child_process() {
+        let service_name = generate_name();
+        let sut = zero_copy::Service::new(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let mut listener = sut.listener().create().unwrap();
+
+        let mut child = spawn_child("child_notifies_once", &service_name);
+
+        let received_events = listener.timed_wait(TIMEOUT).unwrap().to_vec();
+        assert_that!(child.wait().unwrap().success(), eq true);
+
+        assert_that!(received_events, len 1);
+        assert_that!(received_events[0], eq EVENT_ID);
+    }
+
+    #[test]
+    fn repeated_notifications_of_child_process_are_coalesced() {
+        let service_name = generate_name();
+        let sut = zero_copy::Service::new(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let mut listener = sut.listener().create().unwrap();
+
+        let mut child = spawn_child("child_notifies_repeatedly", &service_name);
+        assert_that!(child.wait().unwrap().success(), eq true);
+
+        let received_events = listener.timed_wait(TIMEOUT).unwrap().to_vec();
+        assert_that!(received_events, len 2);
+        assert_that!(received_events, contains EVENT_ID);
+        assert_that!(received_events, contains ANOTHER_EVENT_ID);
+
+        assert_that!(listener.try_wait().unwrap(), is_empty);
+    }
+}
@@ -13,11 +13,39 @@
 #[generic_tests::define]
 mod service_event {
     use iceoryx2::config::Config;
+    use iceoryx2::port::event_id::EventIdConversionError;
+    use iceoryx2::port::notify::NotifierNotifyError;
     use iceoryx2::prelude::*;
-    use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
+    use iceoryx2::service::builder::event::{
+        EventCreateError, EventOpenError, MAX_EVENT_ID_MAX_VALUE, MAX_EVENT_PAYLOAD_SIZE,
+    };
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
 
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, EventIdEnum)]
+    #[event_id(max_value = 31)]
+    enum CameraEvent {
+        FrameReady = 0,
+        CameraFailure = 7,
+        Shutdown = 31,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+    #[repr(C)]
+    struct Payload {
+        value: u64,
+        inverted_value: u64,
+    }
+
+    impl Payload {
+        fn new(value: u64) -> Self {
+            Self {
+                value,
+                inverted_value: !value,
+            }
+        }
+    }
+
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
             "service_tests_{}",
@@ -115,6 +143,33 @@ mod service_event {
         assert_that!(sut2, is_ok);
     }
 
+    #[test]
+    fn open_fails_when_service_does_not_support_requested_event_id_max_value<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(128)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(129)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            EventOpenError::DoesNotSupportRequestedMaxEventId
+        );
+
+        let sut2 = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(64)
+            .open();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().event_id_max_value(), eq 128);
+    }
+
     #[test]
     fn open_uses_predefined_settings_when_nothing_is_specified<Sut: Service>() {
         let service_name = generate_name();
@@ -293,6 +348,107 @@ mod service_event {
         }
     }
 
+    #[test]
+    fn notification_with_event_id_above_max_value_fails<Sut: Service>() {
+        const EVENT_ID_MAX_VALUE: u64 = 31;
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(EVENT_ID_MAX_VALUE)
+            .create()
+            .unwrap();
+
+        let mut listener = sut.listener().create().unwrap();
+        let notifier = sut
+            .notifier()
+            .default_event_id(EventId::new(EVENT_ID_MAX_VALUE + 1))
+            .create()
+            .unwrap();
+
+        let result = notifier.notify();
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq NotifierNotifyError::EventIdOutOfBounds);
+
+        assert_that!(
+            notifier.notify_with_custom_event_id(EventId::new(EVENT_ID_MAX_VALUE)),
+            is_ok
+        );
+
+        let received_events = listener.try_wait().unwrap();
+        assert_that!(received_events, len 1);
+        assert_that!(received_events[0], eq EventId::new(EVENT_ID_MAX_VALUE));
+    }
+
+    #[test]
+    fn typed_event_ids_round_trip<Sut: Service>() {
+        let service_name = generate_name();
+        let events = [
+            CameraEvent::FrameReady,
+            CameraEvent::CameraFailure,
+            CameraEvent::Shutdown,
+        ];
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(31)
+            .create()
+            .unwrap();
+
+        let mut listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        for event in events {
+            assert_that!(notifier.notify_with_custom_event_id(event.into()), is_ok);
+
+            let received_events = listener.try_wait().unwrap();
+            assert_that!(received_events, len 1);
+            assert_that!(CameraEvent::try_from(received_events[0]), eq Ok(event));
+        }
+
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(5)), is_ok);
+        let received_events = listener.try_wait().unwrap();
+        assert_that!(received_events, len 1);
+        assert_that!(
+            CameraEvent::try_from(received_events[0]),
+            eq Err(EventIdConversionError::UnknownEventId)
+        );
+    }
+
+    #[test]
+    fn multiple_notifications_with_same_event_id_are_coalesced<Sut: Service>() {
+        const NUMBER_OF_REPETITIONS: usize = 8;
+        const NUMBER_OF_ITERATIONS: usize = 16;
+        let service_name = generate_name();
+        let event_ids = [
+            EventId::new(0),
+            EventId::new(63),
+            EventId::new(64),
+            EventId::new(870),
+        ];
+
+        let sut = Sut::new(&service_name).event().create().unwrap();
+
+        let mut listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        for _ in 0..NUMBER_OF_ITERATIONS {
+            for _ in 0..NUMBER_OF_REPETITIONS {
+                for event_id in &event_ids {
+                    assert_that!(notifier.notify_with_custom_event_id(*event_id), is_ok);
+                }
+            }
+
+            let received_events = listener.try_wait().unwrap().to_vec();
+            assert_that!(received_events, len event_ids.len());
+            for event_id in &event_ids {
+                assert_that!(received_events, contains * event_id);
+            }
+
+            assert_that!(listener.try_wait().unwrap(), is_empty);
+        }
+    }
+
     #[test]
     fn number_of_notifiers_works<Sut: Service>() {
         let service_name = generate_name();
@@ -365,6 +521,241 @@ mod service_event {
         }
     }
 
+    #[test]
+    fn notification_with_payload_delivers_payload<Sut: Service>() {
+        let service_name = generate_name();
+        let event_ids = [EventId::new(0), EventId::new(7), EventId::new(64)];
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(64)
+            .event_payload::<Payload>()
+            .create()
+            .unwrap();
+
+        let mut listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        for event_id in &event_ids {
+            let payload = Payload::new(event_id.as_u64() * 3);
+            assert_that!(notifier.notify_with_payload(*event_id, payload), eq Ok(1));
+        }
+
+        let received_events = listener.try_wait_with_payload().unwrap().to_vec();
+        assert_that!(received_events, len event_ids.len());
+        for event_id in &event_ids {
+            assert_that!(
+                received_events,
+                contains(*event_id, Some(Payload::new(event_id.as_u64() * 3)))
+            );
+        }
+
+        assert_that!(listener.try_wait_with_payload().unwrap(), is_empty);
+    }
+
+    #[test]
+    fn notification_without_payload_delivers_none<Sut: Service>() {
+        let service_name = generate_name();
+        let event_id = EventId::new(5);
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(16)
+            .event_payload::<Payload>()
+            .create()
+            .unwrap();
+
+        let mut listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        assert_that!(notifier.notify_with_custom_event_id(event_id), is_ok);
+        assert_that!(
+            listener.try_wait_with_payload().unwrap(),
+            eq & [(event_id, None)]
+        );
+
+        // a notification without payload removes the payload of the previous one
+        assert_that!(
+            notifier.notify_with_payload(event_id, Payload::new(9)),
+            is_ok
+        );
+        assert_that!(notifier.notify_with_custom_event_id(event_id), is_ok);
+        assert_that!(
+            listener.try_wait_with_payload().unwrap(),
+            eq & [(event_id, None)]
+        );
+    }
+
+    #[test]
+    fn notification_with_payload_and_event_id_above_max_value_fails<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(16)
+            .event_payload::<Payload>()
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier().create().unwrap();
+
+        let result = notifier.notify_with_payload(EventId::new(17), Payload::new(1));
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq NotifierNotifyError::EventIdOutOfBounds);
+    }
+
+    #[test]
+    fn coalesced_notifications_with_payload_deliver_the_last_payload<Sut: Service>() {
+        const NUMBER_OF_REPETITIONS: u64 = 8;
+        let service_name = generate_name();
+        let event_id = EventId::new(12);
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(16)
+            .event_payload::<Payload>()
+            .create()
+            .unwrap();
+
+        let mut listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        for i in 0..NUMBER_OF_REPETITIONS {
+            assert_that!(
+                notifier.notify_with_payload(event_id, Payload::new(i)),
+                is_ok
+            );
+        }
+
+        assert_that!(
+            listener.try_wait_with_payload().unwrap(),
+            eq & [(event_id, Some(Payload::new(NUMBER_OF_REPETITIONS - 1)))]
+        );
+    }
+
+    #[test]
+    fn concurrent_notifications_with_payload_deliver_consistent_payloads<Sut: Service>() {
+        const NUMBER_OF_NOTIFIER_THREADS: u64 = 4;
+        const NUMBER_OF_NOTIFICATIONS: u64 = 1000;
+        let service_name = generate_name();
+        let event_id = EventId::new(3);
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .max_notifiers(NUMBER_OF_NOTIFIER_THREADS as usize)
+            .event_id_max_value(4)
+            .event_payload::<Payload>()
+            .create()
+            .unwrap();
+
+        let mut listener = sut.listener().create().unwrap();
+
+        std::thread::scope(|s| {
+            let mut threads = vec![];
+            for t in 0..NUMBER_OF_NOTIFIER_THREADS {
+                let sut = &sut;
+                threads.push(s.spawn(move || {
+                    let notifier = sut.notifier().create().unwrap();
+                    for i in 0..NUMBER_OF_NOTIFICATIONS {
+                        let payload = Payload::new(t * NUMBER_OF_NOTIFICATIONS + i);
+                        assert_that!(notifier.notify_with_payload(event_id, payload), is_ok);
+                    }
+                }));
+            }
+
+            let mut last_values = vec![];
+            while threads.iter().any(|t| !t.is_finished()) {
+                for (id, payload) in listener.try_wait_with_payload().unwrap() {
+                    assert_that!(*id, eq event_id);
+                    let payload = payload.unwrap();
+                    assert_that!(payload.inverted_value, eq !payload.value);
+                    last_values.push(payload.value);
+                }
+            }
+
+            for (id, payload) in listener.try_wait_with_payload().unwrap() {
+                assert_that!(*id, eq event_id);
+                let payload = payload.unwrap();
+                assert_that!(payload.inverted_value, eq !payload.value);
+                last_values.push(payload.value);
+            }
+
+            // the latest payload is the last one that one of the notifiers has written
+            let last_value = *last_values.last().unwrap();
+            assert_that!(last_value % NUMBER_OF_NOTIFICATIONS, eq NUMBER_OF_NOTIFICATIONS - 1);
+        });
+    }
+
+    #[test]
+    fn open_fails_when_event_payload_type_differs<Sut: Service>() {
+        let service_name = generate_name();
+
+        let _sut = Sut::new(&service_name)
+            .event()
+            .event_payload::<Payload>()
+            .create()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .event()
+            .event_payload::<u64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq EventOpenError::IncompatibleEventPayloadType);
+
+        let sut2 = Sut::new(&service_name).event().open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq EventOpenError::IncompatibleEventPayloadType);
+
+        let sut2 = Sut::new(&service_name)
+            .event()
+            .event_payload::<Payload>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn create_fails_when_event_payload_exceeds_max_size<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .event_payload::<[u8; MAX_EVENT_PAYLOAD_SIZE + 1]>()
+            .create();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq EventCreateError::EventPayloadExceedsMaxSize);
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(8)
+            .event_payload::<[u8; MAX_EVENT_PAYLOAD_SIZE]>()
+            .create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn create_fails_when_event_id_max_value_exceeds_limit<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(u64::MAX)
+            .create();
+        assert_that!(sut.err(), eq Some(EventCreateError::EventIdMaxValueExceedsLimit));
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(MAX_EVENT_ID_MAX_VALUE + 1)
+            .create();
+        assert_that!(sut.err(), eq Some(EventCreateError::EventIdMaxValueExceedsLimit));
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .event_id_max_value(MAX_EVENT_ID_MAX_VALUE)
+            .create();
+        assert_that!(sut, is_ok);
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
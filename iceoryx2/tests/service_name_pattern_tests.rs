@@ -0,0 +1,219 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2::service::service_name_pattern::{ServiceNamePattern, ServiceNamePatternError};
+use iceoryx2_bb_testing::assert_that;
+
+fn matches(pattern: &str, name: &str) -> bool {
+    ServiceNamePattern::new(pattern)
+        .unwrap()
+        .matches(&ServiceName::new(name).unwrap())
+}
+
+// straightforward recursive implementation of the documented semantics
+fn reference_matches(pattern: &[&str], name: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((&"**", rest)) => (0..=name.len()).any(|n| reference_matches(rest, &name[n..])),
+        Some((segment, rest)) => match name.split_first() {
+            None => false,
+            Some((name_segment, name_rest)) => {
+                (*segment == "*" || segment == name_segment) && reference_matches(rest, name_rest)
+            }
+        },
+    }
+}
+
+// xorshift, the tests shall be reproducible without an additional dependency
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self, max: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % max as u64) as usize
+    }
+}
+
+#[test]
+fn service_name_components_are_separated_by_slashes() {
+    let sut = ServiceName::new("vehicle/sensors/lidar/front").unwrap();
+    let components: Vec<&str> = sut.components().collect();
+    assert_that!(components, eq vec!["vehicle", "sensors", "lidar", "front"]);
+
+    let sut = ServiceName::new("/vehicle//lidar/").unwrap();
+    let components: Vec<&str> = sut.components().collect();
+    assert_that!(components, eq vec!["", "vehicle", "", "lidar", ""]);
+
+    let sut = ServiceName::new("vehicle").unwrap();
+    assert_that!(sut.components().count(), eq 1);
+}
+
+#[test]
+fn service_name_pattern_with_invalid_content_fails() {
+    assert_that!(ServiceNamePattern::new("").err(), eq Some(ServiceNamePatternError::IsEmpty));
+    assert_that!(
+        ServiceNamePattern::new(&"x".repeat(ServiceName::max_len() + 1)).err(),
+        eq Some(ServiceNamePatternError::ExceedsMaximumLength)
+    );
+    assert_that!(
+        ServiceNamePattern::new("vehicle/\n/*").err(),
+        eq Some(ServiceNamePatternError::InvalidCharacter)
+    );
+
+    for pattern in [
+        "vehicle/lid*",
+        "*vehicle",
+        "vehicle/***",
+        "vehicle/*/a*b/**",
+    ] {
+        assert_that!(
+            ServiceNamePattern::new(pattern).err(),
+            eq Some(ServiceNamePatternError::InvalidWildcard)
+        );
+    }
+}
+
+#[test]
+fn service_name_pattern_can_be_created_with_try_from() {
+    let sut = ServiceNamePattern::try_from("vehicle/**").unwrap();
+    assert_that!(sut.as_str(), eq "vehicle/**");
+    assert_that!(sut.to_string(), eq "vehicle/**");
+}
+
+#[test]
+fn service_name_pattern_without_wildcards_matches_only_identical_names() {
+    assert_that!(matches("vehicle/sensors", "vehicle/sensors"), eq true);
+    assert_that!(matches("vehicle/sensors", "vehicle/sensors/lidar"), eq false);
+    assert_that!(matches("vehicle/sensors", "vehicle"), eq false);
+    // segments are never matched partially
+    assert_that!(matches("vehicle/sens", "vehicle/sensors"), eq false);
+    assert_that!(matches("vehicle", "vehicles"), eq false);
+}
+
+#[test]
+fn service_name_pattern_single_wildcard_matches_exactly_one_segment() {
+    assert_that!(matches("vehicle/sensors/*", "vehicle/sensors/lidar"), eq true);
+    assert_that!(matches("vehicle/sensors/*", "vehicle/sensors"), eq false);
+    assert_that!(matches("vehicle/sensors/*", "vehicle/sensors/lidar/front"), eq false);
+    assert_that!(matches("*/sensors/*", "vehicle/sensors/lidar"), eq true);
+    assert_that!(matches("*", "vehicle"), eq true);
+    assert_that!(matches("*", "vehicle/sensors"), eq false);
+}
+
+#[test]
+fn service_name_pattern_multi_wildcard_matches_zero_or_more_segments() {
+    assert_that!(matches("vehicle/**", "vehicle"), eq true);
+    assert_that!(matches("vehicle/**", "vehicle/sensors/lidar/front"), eq true);
+    assert_that!(matches("vehicle/**", "vehicles/sensors"), eq false);
+    assert_that!(matches("**/front", "front"), eq true);
+    assert_that!(matches("**/front", "vehicle/sensors/lidar/front"), eq true);
+    assert_that!(matches("**/front", "vehicle/front/left"), eq false);
+    assert_that!(matches("vehicle/**/front", "vehicle/sensors/front/lidar/front"), eq true);
+    assert_that!(matches("vehicle/**/front", "vehicle/sensors/front/lidar"), eq false);
+    assert_that!(matches("**", "vehicle/sensors/lidar"), eq true);
+}
+
+#[test]
+fn service_name_pattern_treats_empty_segments_as_ordinary_segments() {
+    assert_that!(matches("/vehicle/*", "/vehicle/sensors"), eq true);
+    assert_that!(matches("vehicle/*", "/vehicle/sensors"), eq false);
+    assert_that!(matches("vehicle/*", "vehicle/"), eq true);
+    assert_that!(matches("*/sensors", "/sensors"), eq true);
+}
+
+#[test]
+fn service_name_pattern_matches_like_the_reference_implementation() {
+    const NUMBER_OF_ITERATIONS: usize = 20000;
+    const PATTERN_SEGMENTS: [&str; 6] = ["a", "b", "", "*", "**", "ab"];
+    const NAME_SEGMENTS: [&str; 4] = ["a", "b", "", "ab"];
+
+    let mut random = Random(0x2545_f491_4f6c_dd1d);
+    for _ in 0..NUMBER_OF_ITERATIONS {
+        let pattern: Vec<&str> = (0..=random.next(6))
+            .map(|_| PATTERN_SEGMENTS[random.next(PATTERN_SEGMENTS.len())])
+            .collect();
+        let name: Vec<&str> = (0..=random.next(6))
+            .map(|_| NAME_SEGMENTS[random.next(NAME_SEGMENTS.len())])
+            .collect();
+
+        let pattern_str = pattern.join("/");
+        let name_str = name.join("/");
+        // a single empty segment is an empty name which is not allowed
+        if pattern_str.is_empty() || name_str.is_empty() {
+            continue;
+        }
+
+        assert_that!(
+            matches(&pattern_str, &name_str),
+            eq reference_matches(&pattern, &name)
+        );
+    }
+}
+
+#[generic_tests::define]
+mod list_matching {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::service_name_pattern::ServiceNamePattern;
+    use iceoryx2::service::{Details, Service};
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn list_matching_returns_only_services_with_matching_names<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+
+        let mut services = vec![];
+        for name in [
+            "vehicle/sensors/lidar",
+            "vehicle/sensors/lidar/front",
+            "vehicle/sensors/radar",
+            "vehicle/actuators/brake",
+            "vehicle/sensors",
+        ] {
+            services.push(
+                Sut::new(&ServiceName::new(name).unwrap())
+                    .publish_subscribe_with_custom_config(&config)
+                    .create::<u64>()
+                    .unwrap(),
+            );
+        }
+
+        let pattern = ServiceNamePattern::new("vehicle/sensors/*").unwrap();
+        let mut names: Vec<String> =
+            <Sut::Type<'_>>::list_matching_with_custom_config(&config, &pattern)
+                .unwrap()
+                .iter()
+                .map(|s| s.service_name().to_string())
+                .collect();
+        names.sort();
+        assert_that!(names, eq vec!["vehicle/sensors/lidar", "vehicle/sensors/radar"]);
+
+        let pattern = ServiceNamePattern::new("vehicle/**").unwrap();
+        let services =
+            <Sut::Type<'_>>::list_matching_with_custom_config(&config, &pattern).unwrap();
+        assert_that!(services, len 5);
+
+        let pattern = ServiceNamePattern::new("airplane/**").unwrap();
+        let services =
+            <Sut::Type<'_>>::list_matching_with_custom_config(&config, &pattern).unwrap();
+        assert_that!(services, len 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
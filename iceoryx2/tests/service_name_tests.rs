@@ -0,0 +1,76 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::service::service_name::{ServiceName, ServiceNameError};
+use iceoryx2_bb_testing::assert_that;
+
+static STATIC_SERVICE_NAME: ServiceName = ServiceName::from_static_str("My/Static/ServiceName");
+
+#[test]
+fn service_name_with_max_len_can_be_created() {
+    let name = "x".repeat(ServiceName::max_len());
+    let sut = ServiceName::new(&name);
+    assert_that!(sut, is_ok);
+
+    let sut = sut.unwrap();
+    assert_that!(sut.as_str(), eq name);
+}
+
+#[test]
+fn service_name_exceeding_max_len_fails() {
+    let name = "x".repeat(ServiceName::max_len() + 1);
+    let sut = ServiceName::new(&name);
+
+    assert_that!(sut.err().unwrap(), eq ServiceNameError::ExceedsMaximumLength);
+}
+
+#[test]
+fn empty_service_name_fails() {
+    assert_that!(ServiceName::new("").err().unwrap(), eq ServiceNameError::IsEmpty);
+}
+
+#[test]
+fn service_name_with_control_characters_fails() {
+    for name in [
+        "My/Funk\0Name",
+        "My/Funk\nName",
+        "\tMyFunkName",
+        "MyFunkName\x7f",
+    ] {
+        assert_that!(ServiceName::new(name).err().unwrap(), eq ServiceNameError::InvalidCharacter);
+    }
+}
+
+#[test]
+fn service_name_can_be_created_with_try_from() {
+    let sut = ServiceName::try_from("My/Funk/ServiceName").unwrap();
+    assert_that!(sut, eq ServiceName::new("My/Funk/ServiceName").unwrap());
+
+    let sut: Result<ServiceName, _> = "My/Funk\nServiceName".try_into();
+    assert_that!(sut.err().unwrap(), eq ServiceNameError::InvalidCharacter);
+}
+
+#[test]
+fn service_name_can_be_created_in_const_context() {
+    assert_that!(STATIC_SERVICE_NAME.as_str(), eq "My/Static/ServiceName");
+    assert_that!(STATIC_SERVICE_NAME.to_string(), eq "My/Static/ServiceName");
+    assert_that!(
+        STATIC_SERVICE_NAME,
+        eq ServiceName::new("My/Static/ServiceName").unwrap()
+    );
+}
+
+#[test]
+#[should_panic]
+fn service_name_from_static_str_with_invalid_character_panics() {
+    ServiceName::from_static_str("My/Funk\0ServiceName");
+}
@@ -0,0 +1,463 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// The child processes are spawned by re-executing this test binary with a single ignored test
+// selected, the service name is handed over via an environment variable. Only the
+// `zero_copy::Service` can be tested here since the `process_local::Service` cannot be shared
+// between processes.
+mod service_publish_subscribe_cross_process {
+    use std::process::{Child, Command};
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::port::connection_monitor::{ConnectionTransition, WaitForConnectionsError};
+    use iceoryx2::port::subscriber::Subscriber;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Details;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const SERVICE_NAME_VARIABLE: &str = "IOX2_CROSS_PROCESS_PUBLISH_SUBSCRIBE_SERVICE_NAME";
+    const TIMEOUT: Duration = Duration::from_secs(10);
+    const NUMBER_OF_SAMPLES: u64 = 4;
+
+    #[derive(Debug, PartialEq, ZeroCopySend)]
+    #[repr(C)]
+    struct ContainerPayload {
+        name: FixedSizeByteString<64>,
+        values: FixedSizeVec<u32, 8>,
+    }
+
+    fn container_payload() -> ContainerPayload {
+        ContainerPayload {
+            name: FixedSizeByteString::try_from("sent by another process").unwrap(),
+            values: FixedSizeVec::try_from([2, 3, 5, 7, 11].as_slice()).unwrap(),
+        }
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_publish_subscribe_cross_process_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn spawn_child(test_name: &str, service_name: &ServiceName) -> Child {
+        Command::new(std::env::current_exe().unwrap())
+            .args([
+                &format!("service_publish_subscribe_cross_process::{}", test_name),
+                "--exact",
+                "--ignored",
+                "--nocapture",
+            ])
+            .env(SERVICE_NAME_VARIABLE, service_name.as_str())
+            .spawn()
+            .unwrap()
+    }
+
+    fn service_name_of_parent() -> Option<ServiceName> {
+        std::env::var(SERVICE_NAME_VARIABLE)
+            .ok()
+            .map(|name| ServiceName::new(&name).unwrap())
+    }
+
+    // a killed child cannot remove its part of the service, without the removal the service
+    // would outlive the test
+    fn remove_service_of_killed_child(service_name: &ServiceName) {
+        assert_that!(zero_copy::Service::force_remove(service_name), is_ok);
+    }
+
+    fn wait_until<F: FnMut() -> bool>(mut condition: F) {
+        let start = Instant::now();
+        while !condition() {
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_publishes_samples() {
+        let service_name = match service_name_of_parent() {
+            Some(name) => name,
+            None => return,
+        };
+
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i * 3), eq Ok(1));
+        }
+
+        // the samples live in the data segment of the publisher, it has to stay until the
+        // subscriber has consumed them
+        wait_until(|| sut.dynamic_config().number_of_subscribers() == 0);
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_receives_samples() {
+        let service_name = match service_name_of_parent() {
+            Some(name) => name,
+            None => return,
+        };
+
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let mut received_samples = vec![];
+        while received_samples.len() < NUMBER_OF_SAMPLES as usize {
+            let sample = subscriber.timed_receive(TIMEOUT).unwrap().unwrap();
+            received_samples.push(*sample);
+        }
+
+        assert_that!(
+            received_samples,
+            eq(0..NUMBER_OF_SAMPLES).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_publishes_container_payload() {
+        let service_name = match service_name_of_parent() {
+            Some(name) => name,
+            None => return,
+        };
+
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .open::<ContainerPayload>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        assert_that!(publisher.send_copy(container_payload()), eq Ok(1));
+
+        wait_until(|| sut.dynamic_config().number_of_subscribers() == 0);
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_recreates_service_and_publishes_until_killed() {
+        let service_name = match service_name_of_parent() {
+            Some(name) => name,
+            None => return,
+        };
+
+        // a restarted process does not know whether its previous instance left the service
+        // behind, it starts with a fresh one
+        match zero_copy::Service::force_remove(&service_name) {
+            Ok(()) | Err(ServiceRemoveError::DoesNotExist) => (),
+            Err(e) => panic!("unable to remove the service: {:?}", e),
+        }
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        let start = Instant::now();
+        let mut counter = 0;
+        while start.elapsed() < TIMEOUT {
+            publisher.send_copy(counter).unwrap();
+            counter += 1;
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_subscribes_until_a_sample_is_received() {
+        let service_name = match service_name_of_parent() {
+            Some(name) => name,
+            None => return,
+        };
+
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(subscriber.has_publishers(), eq true);
+
+        wait_until(|| subscriber.receive().unwrap().is_some());
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_subscribes_until_killed() {
+        let service_name = match service_name_of_parent() {
+            Some(name) => name,
+            None => return,
+        };
+
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+        let _subscriber = sut.subscriber().create().unwrap();
+        std::thread::sleep(TIMEOUT);
+    }
+
+    #[test]
+    fn publisher_counts_and_monitors_subscribers_of_child_process() {
+        let service_name = generate_name();
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let mut monitor = publisher.connection_monitor().unwrap();
+        assert_that!(publisher.has_subscribers(), eq false);
+
+        let mut child = spawn_child("child_subscribes_until_a_sample_is_received", &service_name);
+        assert_that!(monitor.timed_wait(TIMEOUT), eq Ok(Some(ConnectionTransition::Connected)));
+        assert_that!(publisher.number_of_connected_subscribers(), eq 1);
+
+        // the child removes its subscriber after it received the sample, the publisher has to
+        // stay until then since the sample lives in its data segment
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        assert_that!(monitor.timed_wait(TIMEOUT), eq Ok(Some(ConnectionTransition::Disconnected)));
+        assert_that!(publisher.number_of_connected_subscribers(), eq 0);
+        assert_that!(publisher.has_subscribers(), eq false);
+
+        assert_that!(child.wait().unwrap().success(), eq true);
+    }
+
+    #[test]
+    fn subscribers_of_dead_process_are_not_counted() {
+        let service_name = generate_name();
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let mut monitor = publisher.connection_monitor().unwrap();
+
+        let mut child = spawn_child("child_subscribes_until_killed", &service_name);
+        assert_that!(monitor.timed_wait(TIMEOUT), eq Ok(Some(ConnectionTransition::Connected)));
+        assert_that!(publisher.number_of_connected_subscribers(), eq 1);
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        // the entry of the dead process remains until the stale resources are removed
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+        assert_that!(publisher.number_of_connected_subscribers(), eq 0);
+        assert_that!(publisher.has_subscribers(), eq false);
+        assert_that!(monitor.try_wait(), eq Ok(Some(ConnectionTransition::Disconnected)));
+
+        remove_service_of_killed_child(&service_name);
+    }
+
+    #[test]
+    fn publisher_stops_waiting_for_subscribers_when_the_subscriber_process_dies() {
+        let service_name = generate_name();
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        let mut child = spawn_child("child_subscribes_until_killed", &service_name);
+        assert_that!(publisher.wait_for_subscribers(1, TIMEOUT), eq Ok(1));
+
+        let killer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(100));
+            child.kill().unwrap();
+            child.wait().unwrap();
+            // the dead process cannot wake up the publisher, the cleanup of its subscriber does
+            assert_that!(zero_copy::Service::remove_stale_resources(), is_ok);
+        });
+
+        // the second subscriber never comes, the dead one must not keep the publisher waiting
+        // until the timeout
+        let start = Instant::now();
+        assert_that!(
+            publisher.wait_for_subscribers(2, TIMEOUT),
+            eq Err(WaitForConnectionsError::CounterpartDied)
+        );
+        assert_that!(start.elapsed(), lt TIMEOUT);
+        killer.join().unwrap();
+
+        remove_service_of_killed_child(&service_name);
+    }
+
+    #[test]
+    fn subscriber_with_auto_reconnect_resumes_after_restart_of_publisher_process() {
+        let service_name = generate_name();
+        let mut child = spawn_child(
+            "child_recreates_service_and_publishes_until_killed",
+            &service_name,
+        );
+
+        let mut sut = None;
+        wait_until(|| {
+            sut = zero_copy::Service::new(&service_name)
+                .publish_subscribe()
+                .open::<u64>()
+                .ok();
+            sut.is_some()
+        });
+        let sut = sut.unwrap();
+        let subscriber = sut.subscriber().auto_reconnect(true).create().unwrap();
+        wait_until(|| subscriber.receive().unwrap().is_some());
+
+        // the opened service is the instance of the child, the subscriber stays with it
+        std::thread::sleep(Subscriber::<zero_copy::Service, u64>::RECONNECT_CHECK_INTERVAL);
+        while subscriber.receive().unwrap().is_some() {}
+        assert_that!(subscriber.number_of_reconnects(), eq 0);
+        assert_that!(subscriber.service_instance_uuid(), eq sut.instance_uuid());
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+        let mut child = spawn_child(
+            "child_recreates_service_and_publishes_until_killed",
+            &service_name,
+        );
+
+        wait_until(|| {
+            while let Some(sample) = subscriber.receive().unwrap() {
+                if subscriber.number_of_reconnects() == 1 {
+                    assert_that!(subscriber.number_of_lost_samples(sample.origin()), is_some);
+                    return true;
+                }
+            }
+            false
+        });
+        assert_that!(subscriber.service_instance_uuid(), ne sut.instance_uuid());
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        drop(subscriber);
+        drop(sut);
+        remove_service_of_killed_child(&service_name);
+    }
+
+    #[test]
+    fn subscriber_receives_samples_of_publisher_of_child_process() {
+        let service_name = generate_name();
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let mut child = spawn_child("child_publishes_samples", &service_name);
+
+        let mut received_samples = vec![];
+        wait_until(|| {
+            while let Some(sample) = subscriber.receive().unwrap() {
+                received_samples.push(*sample);
+            }
+            received_samples.len() == NUMBER_OF_SAMPLES as usize
+        });
+        assert_that!(received_samples, eq vec![0, 3, 6, 9]);
+
+        drop(subscriber);
+        assert_that!(child.wait().unwrap().success(), eq true);
+    }
+
+    #[test]
+    fn subscriber_of_child_process_is_woken_up_by_publisher() {
+        let service_name = generate_name();
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        let mut child = spawn_child("child_receives_samples", &service_name);
+        wait_until(|| sut.dynamic_config().number_of_subscribers() == 1);
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), eq Ok(1));
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        // the samples live in the data segment of the publisher, it has to stay until the
+        // child has consumed them and removed its subscriber
+        wait_until(|| sut.dynamic_config().number_of_subscribers() == 0);
+        drop(publisher);
+        assert_that!(child.wait().unwrap().success(), eq true);
+    }
+
+    #[test]
+    fn subscribers_of_same_and_other_process_receive_the_samples_of_a_publisher() {
+        let service_name = generate_name();
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().has_intra_process_fast_path(), eq true);
+
+        let publisher = sut.publisher().create().unwrap();
+        // connected via the intra process fast path while the subscriber of the child is
+        // connected via shared memory
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let mut child = spawn_child("child_receives_samples", &service_name);
+        wait_until(|| sut.dynamic_config().number_of_subscribers() == 2);
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), eq Ok(2));
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq i);
+        }
+
+        wait_until(|| sut.dynamic_config().number_of_subscribers() == 1);
+        drop(publisher);
+        assert_that!(child.wait().unwrap().success(), eq true);
+    }
+
+    #[test]
+    fn subscriber_receives_container_payload_of_publisher_of_child_process() {
+        let service_name = generate_name();
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .create::<ContainerPayload>()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let mut child = spawn_child("child_publishes_container_payload", &service_name);
+
+        // the relative pointer of the vector has to refer to the elements inside the sample
+        wait_until(|| match subscriber.receive().unwrap() {
+            Some(sample) => {
+                assert_that!(*sample, eq container_payload());
+                true
+            }
+            None => false,
+        });
+
+        drop(subscriber);
+        assert_that!(child.wait().unwrap().success(), eq true);
+    }
+}
@@ -13,17 +13,56 @@
 #[generic_tests::define]
 mod service_publish_subscribe {
     use iceoryx2::config::Config;
-    use iceoryx2::port::publish::{PublisherCreateError, PublisherLoanError};
-    use iceoryx2::port::subscribe::SubscriberCreateError;
-    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::port::publish::{
+        DeliveryFailureReason, PublisherCreateError, PublisherLoanError, PublisherWaitError,
+    };
+    use iceoryx2::port::subscribe::{
+        SubscriberCreateError, SubscriberReceiveError, SubscriberWaitError,
+    };
+    use iceoryx2::port::subscriber::PublisherConnectionState;
+    use iceoryx2::port::update_connections::ConnectionFailure;
     use iceoryx2::prelude::*;
+    use iceoryx2::sample_mut::SampleMutSetTimeStampError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
-    use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::dynamic_config::publish_subscribe::PortId;
+    use iceoryx2::service::header::publish_subscribe::{ClockType, Header};
+    use iceoryx2::service::port_factory::publisher::{AllocationStrategy, UnableToDeliverStrategy};
+    use iceoryx2::service::port_factory::subscriber::ReceiveOrder;
+    use iceoryx2::service::static_config::publish_subscribe::{Requirements, TypeDetails};
     use iceoryx2::service::static_config::StaticConfig;
     use iceoryx2::service::{Details, Service};
+    use iceoryx2::testing::{set_header_layout_version, set_header_number_of_elements};
+    use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
+    use iceoryx2_bb_posix::clock::{ClockType as PosixClockType, Time, TimeBuilder};
+    use iceoryx2_bb_posix::process::Process;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::zero_copy_connection::ZeroCopyCreationError;
+    use std::cell::Cell;
+    use std::panic::AssertUnwindSafe;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    fn incompatible_setting_names(error: Option<PublishSubscribeOpenError>) -> Vec<&'static str> {
+        match error {
+            Some(PublishSubscribeOpenError::IncompatibleSettings(settings)) => {
+                settings.iter().map(|setting| setting.name).collect()
+            }
+            _ => vec![],
+        }
+    }
+
+    fn incompatible_types(
+        error: Option<PublishSubscribeOpenError>,
+    ) -> Option<(TypeDetails, TypeDetails)> {
+        match error {
+            Some(PublishSubscribeOpenError::IncompatibleTypes { expected, actual }) => {
+                Some((*expected, *actual))
+            }
+            _ => None,
+        }
+    }
 
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
@@ -43,6 +82,35 @@ mod service_publish_subscribe {
         assert_that!(*sut.name(), eq service_name);
     }
 
+    #[test]
+    fn payload_type_can_be_defined_before_the_terminal_call<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut3, is_ok);
+
+        let sut4 = Sut::new(&service_name).publish_subscribe::<u32>().open();
+        let (expected, actual) = incompatible_types(sut4.err()).unwrap();
+        assert_that!(expected.type_name(), eq std::any::type_name::<u64>());
+        assert_that!(actual.type_name(), eq std::any::type_name::<u32>());
+
+        let sut5 = Sut::new(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create();
+        assert_that!(sut5, is_ok);
+    }
+
     #[test]
     fn creating_same_service_twice_fails<Sut: Service>() {
         let service_name = generate_name();
@@ -57,6 +125,84 @@ mod service_publish_subscribe {
         );
     }
 
+    #[test]
+    fn concurrent_create_of_same_service_succeeds_exactly_once<Sut: Service + Details<'static>>() {
+        const NUMBER_OF_THREADS: u32 = 4;
+        let service_name = generate_name();
+        let number_of_created_services = AtomicUsize::new(0);
+
+        let start_handle = BarrierHandle::new();
+        let end_handle = BarrierHandle::new();
+        let start_barrier = BarrierBuilder::new(NUMBER_OF_THREADS)
+            .create(&start_handle)
+            .unwrap();
+        let end_barrier = BarrierBuilder::new(NUMBER_OF_THREADS)
+            .create(&end_handle)
+            .unwrap();
+
+        std::thread::scope(|s| {
+            for _ in 0..NUMBER_OF_THREADS {
+                s.spawn(|| {
+                    start_barrier.wait();
+                    let sut = Sut::new(&service_name).publish_subscribe().create::<u64>();
+                    match &sut {
+                        Ok(_) => {
+                            number_of_created_services.fetch_add(1, Ordering::Relaxed);
+                        }
+                        Err(e) => {
+                            assert_that!(
+                                *e == PublishSubscribeCreateError::AlreadyExists
+                                    || *e == PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance,
+                                eq true
+                            );
+                        }
+                    }
+                    // keep the service alive until every thread has tried to create it
+                    end_barrier.wait();
+                });
+            }
+        });
+
+        assert_that!(number_of_created_services.load(Ordering::Relaxed), eq 1);
+        assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
+    }
+
+    #[test]
+    fn open_returns_does_not_exist_or_service_while_it_is_concurrently_created<Sut: Service>() {
+        let service_name = generate_name();
+
+        let start_handle = BarrierHandle::new();
+        let end_handle = BarrierHandle::new();
+        let start_barrier = BarrierBuilder::new(2).create(&start_handle).unwrap();
+        let end_barrier = BarrierBuilder::new(2).create(&end_handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                start_barrier.wait();
+                let sut = Sut::new(&service_name).publish_subscribe().create::<u64>();
+                assert_that!(sut, is_ok);
+                end_barrier.wait();
+            });
+
+            s.spawn(|| {
+                start_barrier.wait();
+                let sut = loop {
+                    match Sut::new(&service_name).publish_subscribe().open::<u64>() {
+                        Ok(sut) => break sut,
+                        Err(e) => assert_that!(e, eq PublishSubscribeOpenError::DoesNotExist),
+                    }
+                };
+
+                // the opened service must be fully functional
+                let publisher = sut.publisher().create().unwrap();
+                let subscriber = sut.subscriber().create().unwrap();
+                assert_that!(publisher.send_copy(1234), is_ok);
+                assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+                end_barrier.wait();
+            });
+        });
+    }
+
     #[test]
     fn recreate_after_drop_works<Sut: Service>() {
         let service_name = generate_name();
@@ -95,7 +241,171 @@ mod service_publish_subscribe {
 
         let sut2 = Sut::new(&service_name).publish_subscribe().open::<i64>();
         assert_that!(sut2, is_err);
-        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+        let (expected, actual) = incompatible_types(sut2.err()).unwrap();
+        assert_that!(expected.type_name(), eq std::any::type_name::<u64>());
+        assert_that!(actual.type_name(), eq std::any::type_name::<i64>());
+    }
+
+    #[test]
+    fn open_with_type_details_unchecked_accepts_type_with_same_layout<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).publish_subscribe().create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = unsafe {
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .open_with_type_details_unchecked::<i64>()
+        };
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(sut2.static_config().type_name(), eq std::any::type_name::<u64>());
+
+        let sut2 = unsafe {
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .open_with_type_details_unchecked::<u32>()
+        };
+        assert_that!(sut2, is_err);
+        let (expected, actual) = incompatible_types(sut2.err()).unwrap();
+        assert_that!(expected.type_name(), eq std::any::type_name::<u64>());
+        assert_that!(actual.type_name(), eq std::any::type_name::<u32>());
+    }
+
+    mod crate_a {
+        use iceoryx2::prelude::*;
+
+        #[derive(Debug, ZeroCopySend)]
+        #[repr(C)]
+        pub struct Position {
+            pub x: u32,
+            pub y: u16,
+            pub z: u16,
+        }
+    }
+
+    mod crate_b {
+        use iceoryx2::prelude::*;
+
+        #[derive(Debug, ZeroCopySend)]
+        #[repr(C)]
+        pub struct Coordinate {
+            pub x: u32,
+            pub y: u16,
+            pub z: u16,
+        }
+
+        #[derive(Debug, ZeroCopySend)]
+        #[repr(C)]
+        pub struct Position {
+            pub y: u16,
+            pub z: u16,
+            pub x: u32,
+        }
+    }
+
+    #[test]
+    fn open_succeeds_when_type_with_other_name_has_same_structure<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<crate_a::Position>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .open::<crate_b::Coordinate>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_type_with_same_size_has_different_structure<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<crate_a::Position>();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        let existing = sut.static_config().payload_type_details();
+
+        // only the layout hash differs, the type name is the one of the existing service
+        let layout_hash = <crate_b::Position as ZeroCopySend>::LAYOUT_HASH;
+        assert_that!(layout_hash, ne existing.layout_hash());
+        let sut2 = unsafe {
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .payload_type_details(TypeDetails::from_parts(
+                    existing.type_name(),
+                    existing.size(),
+                    existing.alignment(),
+                    layout_hash,
+                ))
+                .open::<crate_b::Position>()
+        };
+        assert_that!(sut2, is_err);
+        let (expected, actual) = incompatible_types(sut2.err()).unwrap();
+        assert_that!(&expected, eq existing);
+        assert_that!(actual.layout_hash(), eq layout_hash);
+    }
+
+    #[test]
+    fn open_with_custom_type_details_compares_layout_hash<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).publish_subscribe().create::<u64>();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        let existing = sut.static_config().payload_type_details();
+        assert_that!(existing.layout_hash(), is_some);
+
+        let sut2 = unsafe {
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .payload_type_details(TypeDetails::from_parts(
+                    "ForeignU64",
+                    8,
+                    8,
+                    existing.layout_hash(),
+                ))
+                .open::<u64>()
+        };
+        assert_that!(sut2, is_ok);
+
+        let sut2 = unsafe {
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .payload_type_details(TypeDetails::from_parts(
+                    "ForeignU64",
+                    8,
+                    8,
+                    existing.layout_hash().map(|v| v.wrapping_add(1)),
+                ))
+                .open::<u64>()
+        };
+        assert_that!(sut2, is_err);
+        let (expected, actual) = incompatible_types(sut2.err()).unwrap();
+        assert_that!(&expected, eq existing);
+        assert_that!(actual.type_name(), eq "ForeignU64");
+        assert_that!(actual.layout_hash(), eq existing.layout_hash().map(|v| v.wrapping_add(1)));
+
+        // without a layout hash only the size and the alignment are verified
+        let sut2 = unsafe {
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .payload_type_details(TypeDetails::from_parts("ForeignU64", 8, 8, None))
+                .open::<u64>()
+        };
+        assert_that!(sut2, is_ok);
+
+        let sut2 = unsafe {
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .payload_type_details(TypeDetails::from_parts("ForeignU64", 4, 4, None))
+                .open::<u64>()
+        };
+        assert_that!(sut2, is_err);
+        let (expected, actual) = incompatible_types(sut2.err()).unwrap();
+        assert_that!(&expected, eq existing);
+        assert_that!(actual.size(), eq 4);
     }
 
     #[test]
@@ -120,8 +430,7 @@ mod service_publish_subscribe {
 
         assert_that!(sut2, is_err);
         assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers
+            incompatible_setting_names(sut2.err()), eq vec!["max_publishers"]
         );
 
         let sut2 = Sut::new(&service_name)
@@ -139,8 +448,7 @@ mod service_publish_subscribe {
 
         assert_that!(sut2, is_err);
         assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers
+            incompatible_setting_names(sut2.err()), eq vec!["max_subscribers"]
         );
 
         let sut2 = Sut::new(&service_name)
@@ -158,8 +466,7 @@ mod service_publish_subscribe {
 
         assert_that!(sut2, is_err);
         assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::IncompatibleOverflowBehavior
+            incompatible_setting_names(sut2.err()), eq vec!["enable_safe_overflow"]
         );
 
         // history size
@@ -170,8 +477,7 @@ mod service_publish_subscribe {
 
         assert_that!(sut2, is_err);
         assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize
+            incompatible_setting_names(sut2.err()), eq vec!["history_size"]
         );
 
         let sut2 = Sut::new(&service_name)
@@ -189,8 +495,7 @@ mod service_publish_subscribe {
 
         assert_that!(sut2, is_err);
         assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples
+            incompatible_setting_names(sut2.err()), eq vec!["subscriber_max_borrowed_samples"]
         );
 
         let sut2 = Sut::new(&service_name)
@@ -208,8 +513,7 @@ mod service_publish_subscribe {
 
         assert_that!(sut2, is_err);
         assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize
+            incompatible_setting_names(sut2.err()), eq vec!["subscriber_max_buffer_size"]
         );
 
         let sut2 = Sut::new(&service_name)
@@ -510,6 +814,72 @@ mod service_publish_subscribe {
         assert_that!(*result.unwrap(), eq 4567);
     }
 
+    #[test]
+    fn intra_process_fast_path_setting_is_defined_by_the_creator<Sut: Service>() {
+        let service_name = generate_name();
+        let mut custom_config = Config::default();
+        custom_config
+            .defaults
+            .publish_subscribe
+            .enable_intra_process_fast_path = false;
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&custom_config)
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().has_intra_process_fast_path(), eq false);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+        assert_that!(sut2.static_config().has_intra_process_fast_path(), eq false);
+    }
+
+    #[test]
+    fn communication_works_with_and_without_intra_process_fast_path<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        const NUMBER_OF_SAMPLES: u64 = 16;
+
+        for enable_intra_process_fast_path in [false, true] {
+            let service_name = generate_name();
+            let mut custom_config = Config::default();
+            custom_config
+                .defaults
+                .publish_subscribe
+                .enable_intra_process_fast_path = enable_intra_process_fast_path;
+
+            let sut = Sut::new(&service_name)
+                .publish_subscribe_with_custom_config(&custom_config)
+                .enable_safe_overflow(true)
+                .subscriber_max_buffer_size(BUFFER_SIZE)
+                .create::<u64>()
+                .unwrap();
+            assert_that!(sut.static_config().has_intra_process_fast_path(), eq enable_intra_process_fast_path);
+
+            let publisher = sut.publisher().create().unwrap();
+            let subscriber = sut.subscriber().create().unwrap();
+
+            // the released samples must return to the publisher, otherwise it runs out of
+            // memory
+            for i in 0..NUMBER_OF_SAMPLES {
+                assert_that!(publisher.send_copy(i), eq Ok(1));
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(*sample, eq i);
+            }
+
+            for i in 0..NUMBER_OF_SAMPLES {
+                assert_that!(publisher.send_copy(i), eq Ok(1));
+            }
+
+            for i in NUMBER_OF_SAMPLES - BUFFER_SIZE as u64..NUMBER_OF_SAMPLES {
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(*sample, eq i);
+            }
+            assert_that!(subscriber.receive().unwrap(), is_none);
+        }
+    }
+
     #[test]
     fn communication_with_max_subscribers_and_publishers<Sut: Service>() {
         const MAX_PUB: usize = 4;
@@ -716,37 +1086,44 @@ mod service_publish_subscribe {
     }
 
     #[test]
-    fn publish_history_of_zero_works<Sut: Service>() {
-        const BUFFER_SIZE: usize = 2;
+    fn publish_history_is_delivered_in_order_to_late_subscriber<Sut: Service>() {
         let service_name = generate_name();
 
         let sut = Sut::new(&service_name)
             .publish_subscribe()
-            .history_size(0)
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .history_size(3)
+            .subscriber_max_buffer_size(3)
             .create::<usize>()
             .unwrap();
 
         let sut_publisher = sut.publisher().create().unwrap();
         assert_that!(sut_publisher.send_copy(29), is_ok);
+        assert_that!(sut_publisher.send_copy(32), is_ok);
+        assert_that!(sut_publisher.send_copy(35), is_ok);
 
         let sut_subscriber = sut.subscriber().create().unwrap();
         assert_that!(sut_publisher.update_connections(), is_ok);
 
+        for i in 0..3 {
+            let data = sut_subscriber.receive().unwrap();
+            assert_that!(data, is_some);
+            assert_that!(*data.unwrap(), eq 29 + i * 3);
+        }
+
         let data = sut_subscriber.receive().unwrap();
         assert_that!(data, is_none);
     }
 
     #[test]
-    fn publish_send_copy_with_huge_overflow_works<Sut: Service>() {
+    fn publish_history_with_smaller_buffer_does_not_leak_samples<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 4;
         let service_name = generate_name();
-        const BUFFER_SIZE: usize = 5;
 
         let sut = Sut::new(&service_name)
             .publish_subscribe()
-            .max_publishers(1)
-            .max_subscribers(2)
-            .history_size(0)
+            .max_subscribers(1)
+            .history_size(HISTORY_SIZE)
             .subscriber_max_buffer_size(BUFFER_SIZE)
             .subscriber_max_borrowed_samples(1)
             .create::<usize>()
@@ -754,411 +1131,3835 @@ mod service_publish_subscribe {
 
         let sut_publisher = sut.publisher().max_loaned_samples(1).create().unwrap();
 
-        let mut subscribers = vec![];
-        for _ in 0..2 {
-            let sut_subscriber = sut.subscriber().create();
-            subscribers.push(sut_subscriber);
-        }
+        for n in 0..100 {
+            for k in 0..HISTORY_SIZE {
+                assert_that!(sut_publisher.send_copy(n * HISTORY_SIZE + k), is_ok);
+            }
 
-        for _ in 0..BUFFER_SIZE * 100 {
-            assert_that!(sut_publisher.send_copy(889), is_ok);
+            let sut_subscriber = sut.subscriber().create().unwrap();
+            assert_that!(sut_publisher.update_connections(), is_ok);
+
+            let data = sut_subscriber.receive().unwrap();
+            assert_that!(data, is_some);
+            assert_that!(*data.unwrap(), eq n * HISTORY_SIZE + HISTORY_SIZE - 1);
         }
     }
 
-    fn publisher_never_goes_out_of_memory_impl<Sut: Service>(
-        buffer_size: usize,
-        history_size: usize,
-        max_borrow: usize,
-        max_subscribers: usize,
-        max_loan: usize,
-    ) {
-        const ITERATIONS: usize = 16;
+    #[test]
+    fn receive_batch_returns_samples_in_order<Sut: Service>() {
+        const BUFFER_SIZE: usize = 8;
         let service_name = generate_name();
 
         let sut = Sut::new(&service_name)
             .publish_subscribe()
-            .max_publishers(1)
-            .max_subscribers(max_subscribers)
-            .enable_safe_overflow(true)
-            .history_size(history_size)
-            .subscriber_max_buffer_size(buffer_size)
-            .subscriber_max_borrowed_samples(max_borrow)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(BUFFER_SIZE)
             .create::<usize>()
             .unwrap();
 
-        let sut_publisher = sut
-            .publisher()
-            .max_loaned_samples(max_loan)
-            .create()
-            .unwrap();
+        let sut_publisher = sut.publisher().create().unwrap();
+        let sut_subscriber = sut.subscriber().create().unwrap();
 
-        let mut subscribers = vec![];
-        for _ in 0..max_subscribers {
-            let sut_subscriber = sut.subscriber().create().unwrap();
-            subscribers.push(sut_subscriber);
+        for i in 0..BUFFER_SIZE {
+            assert_that!(sut_publisher.send_copy(i), is_ok);
         }
 
-        for _ in 0..ITERATIONS {
-            // max out borrow
-            let mut borrowed_samples = vec![];
-            while borrowed_samples.len() < max_borrow * max_subscribers {
-                for _ in 0..buffer_size {
-                    assert_that!(sut_publisher.send_copy(889), is_ok);
-                }
+        let samples = sut_subscriber.receive_batch(3).unwrap();
+        assert_that!(samples.len(), eq 3);
+        for (i, sample) in samples.iter().enumerate() {
+            assert_that!(**sample, eq i);
+        }
 
-                for (i, s) in subscribers.iter().enumerate().take(max_subscribers) {
-                    while let Ok(Some(sample)) = s.receive() {
-                        borrowed_samples.push((i, sample));
-                    }
-                }
-            }
+        let samples = sut_subscriber.receive_batch(BUFFER_SIZE).unwrap();
+        assert_that!(samples.len(), eq BUFFER_SIZE - 3);
+        for (i, sample) in samples.iter().enumerate() {
+            assert_that!(**sample, eq i + 3);
+        }
 
-            // max out buffer
-            for _ in 0..buffer_size {
-                assert_that!(sut_publisher.send_copy(8127), is_ok);
-            }
+        // all samples the subscriber can borrow are held, some have to be returned first
+        drop(samples);
+        let samples = sut_subscriber.receive_batch(BUFFER_SIZE).unwrap();
+        assert_that!(samples, is_empty);
+    }
 
-            // max out history
-            for _ in 0..history_size {
-                assert_that!(sut_publisher.send_copy(17283), is_ok);
-            }
+    #[test]
+    fn receive_batch_preserves_order_of_every_publisher<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 4;
+        let service_name = generate_name();
 
-            // max out loan
-            let mut loaned_samples = vec![];
-            for _ in 0..max_loan {
-                let sample = sut_publisher.loan_uninit();
-                assert_that!(sample, is_ok);
-                loaned_samples.push(sample);
-            }
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .subscriber_max_borrowed_samples(2 * NUMBER_OF_SAMPLES)
+            .create::<usize>()
+            .unwrap();
 
-            let sample = sut_publisher.loan_uninit();
-            assert_that!(sample, is_err);
-            assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedChunks);
+        let sut_publisher_1 = sut.publisher().create().unwrap();
+        let sut_publisher_2 = sut.publisher().create().unwrap();
+        let sut_subscriber = sut.subscriber().create().unwrap();
 
-            // cleanup
-            borrowed_samples.clear();
-            loaned_samples.clear();
-            for s in subscribers.iter().take(max_subscribers) {
-                while let Ok(Some(_)) = s.receive() {}
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(sut_publisher_1.send_copy(i), is_ok);
+            assert_that!(sut_publisher_2.send_copy(100 + i), is_ok);
+        }
+
+        let samples = sut_subscriber.receive_batch(2 * NUMBER_OF_SAMPLES).unwrap();
+        assert_that!(samples.len(), eq 2 * NUMBER_OF_SAMPLES);
+
+        let mut first = vec![];
+        let mut second = vec![];
+        for sample in samples.iter() {
+            if **sample < 100 {
+                first.push(**sample);
+            } else {
+                second.push(**sample);
             }
         }
+
+        assert_that!(first, eq(0..NUMBER_OF_SAMPLES).collect::<Vec<_>>());
+        assert_that!(second, eq(100..100 + NUMBER_OF_SAMPLES).collect::<Vec<_>>());
     }
 
     #[test]
-    fn publisher_never_goes_out_of_memory_with_huge_max_loan<Sut: Service>() {
-        const BUFFER_SIZE: usize = 1;
-        const HISTORY_SIZE: usize = 0;
-        const MAX_BORROW: usize = 1;
-        const MAX_SUBSCRIBERS: usize = 1;
-        const MAX_LOAN: usize = 100;
+    fn receive_batch_returns_received_samples_when_max_borrowed_samples_is_reached<Sut: Service>() {
+        const MAX_BORROWED_SAMPLES: usize = 2;
+        let service_name = generate_name();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(2 * MAX_BORROWED_SAMPLES)
+            .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .create::<usize>()
+            .unwrap();
+
+        let sut_publisher = sut.publisher().create().unwrap();
+        let sut_subscriber = sut.subscriber().create().unwrap();
+
+        for i in 0..2 * MAX_BORROWED_SAMPLES {
+            assert_that!(sut_publisher.send_copy(i), is_ok);
+        }
+
+        let samples = sut_subscriber
+            .receive_batch(2 * MAX_BORROWED_SAMPLES)
+            .unwrap();
+        assert_that!(samples.len(), eq MAX_BORROWED_SAMPLES);
+
+        let result = sut_subscriber.receive_batch(1);
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq SubscriberReceiveError::ExceedsMaxBorrowedSamples);
+
+        drop(samples);
+        let samples = sut_subscriber
+            .receive_batch(2 * MAX_BORROWED_SAMPLES)
+            .unwrap();
+        assert_that!(samples.len(), eq MAX_BORROWED_SAMPLES);
+        for (i, sample) in samples.iter().enumerate() {
+            assert_that!(**sample, eq i + MAX_BORROWED_SAMPLES);
+        }
     }
 
     #[test]
-    fn publisher_never_goes_out_of_memory_with_huge_max_subscribers<Sut: Service>() {
-        const BUFFER_SIZE: usize = 1;
-        const HISTORY_SIZE: usize = 0;
-        const MAX_BORROW: usize = 1;
-        const MAX_SUBSCRIBERS: usize = 50;
-        const MAX_LOAN: usize = 1;
+    fn receive_latest_returns_only_the_newest_sample_and_releases_all_others<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 100;
+        let service_name = generate_name();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .history_size(0)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .subscriber_max_borrowed_samples(1)
+            .create::<usize>()
+            .unwrap();
+
+        let sut_publisher = sut.publisher().create().unwrap();
+        let sut_subscriber = sut.subscriber().create().unwrap();
+
+        for round in 0..2 {
+            // a chunk that is not returned to the publisher would let the second round run out
+            // of memory
+            for i in 0..NUMBER_OF_SAMPLES {
+                assert_that!(sut_publisher.send_copy(round * NUMBER_OF_SAMPLES + i), eq Ok(1));
+            }
+
+            let sample = sut_subscriber.receive_latest().unwrap().unwrap();
+            assert_that!(*sample, eq(round + 1) * NUMBER_OF_SAMPLES - 1);
+            assert_that!(sut_subscriber.statistics().borrowed_samples(), eq 1);
+            drop(sample);
+
+            assert_that!(sut_subscriber.receive_latest().unwrap(), is_none);
+            assert_that!(sut_subscriber.statistics().borrowed_samples(), eq 0);
+            assert_that!(
+                sut_subscriber.statistics().received_samples(),
+                eq((round + 1) * NUMBER_OF_SAMPLES) as u64
+            );
+        }
     }
 
     #[test]
-    fn publisher_never_goes_out_of_memory_with_huge_max_borrow<Sut: Service>() {
-        const BUFFER_SIZE: usize = 1;
-        const HISTORY_SIZE: usize = 0;
-        const MAX_BORROW: usize = 100;
-        const MAX_SUBSCRIBERS: usize = 1;
-        const MAX_LOAN: usize = 1;
+    fn receive_latest_returns_the_newest_sample_of_all_publishers<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 4;
+        let service_name = generate_name();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .history_size(0)
+            .subscriber_max_buffer_size(2 * NUMBER_OF_SAMPLES)
+            .create::<usize>()
+            .unwrap();
+
+        let sut_publisher_1 = sut.publisher().create().unwrap();
+        let sut_publisher_2 = sut.publisher().create().unwrap();
+        let sut_subscriber = sut.subscriber().create().unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(sut_publisher_1.send_copy(i), is_ok);
+            assert_that!(sut_publisher_2.send_copy(100 + i), is_ok);
+        }
+        assert_that!(sut_publisher_1.send_copy(1234), is_ok);
+
+        let sample = sut_subscriber.receive_latest().unwrap().unwrap();
+        assert_that!(*sample, eq 1234);
+        drop(sample);
+
+        assert_that!(sut_publisher_2.send_copy(5678), is_ok);
+        let sample = sut_subscriber.receive_latest().unwrap().unwrap();
+        assert_that!(*sample, eq 5678);
+        drop(sample);
+
+        assert_that!(sut_subscriber.receive_latest().unwrap(), is_none);
+    }
+
+    #[test]
+    fn time_stamps_of_samples_are_strictly_increasing_across_publishers<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 16;
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .history_size(0)
+            .subscriber_max_buffer_size(2 * NUMBER_OF_SAMPLES)
+            .create::<usize>()
+            .unwrap();
+
+        let sut_publisher_1 = sut.publisher().create().unwrap();
+        let sut_publisher_2 = sut.publisher().create().unwrap();
+        let sut_subscriber = sut.subscriber().create().unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(sut_publisher_1.send_copy(2 * i), is_ok);
+            assert_that!(sut_publisher_2.send_copy(2 * i + 1), is_ok);
+        }
+
+        let mut time_stamps = vec![None; 2 * NUMBER_OF_SAMPLES];
+        while let Some(sample) = sut_subscriber.receive().unwrap() {
+            time_stamps[*sample] = Some(sample.header().time_stamp().as_duration());
+        }
+
+        for i in 1..2 * NUMBER_OF_SAMPLES {
+            assert_that!(time_stamps[i - 1].unwrap(), lt time_stamps[i].unwrap());
+        }
+
+        // the newest sample is determined by the time stamp even when both are sent within the
+        // resolution of the clock
+        assert_that!(sut_publisher_2.send_copy(1234), is_ok);
+        assert_that!(sut_publisher_1.send_copy(5678), is_ok);
+        let sample = sut_subscriber.receive_latest().unwrap().unwrap();
+        assert_that!(*sample, eq 5678);
+    }
+
+    #[test]
+    fn sent_samples_have_consecutive_sequence_numbers<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(4)
+            .create::<u64>()
+            .unwrap();
+
+        let sut_publisher = sut.publisher().create().unwrap();
+        let sut_subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(sut_publisher.send_copy(0), is_ok);
+        // dropping a loaned sample does not consume a sequence number
+        drop(sut_publisher.loan_uninit().unwrap());
+        assert_that!(sut_publisher.send_copy(1), is_ok);
+        assert_that!(sut_publisher.loan().unwrap().send(), is_ok);
+
+        for i in 0..3 {
+            let sample = sut_subscriber.receive().unwrap();
+            assert_that!(sample, is_some);
+            assert_that!(sample.unwrap().header().sequence_number(), eq i);
+        }
+    }
+
+    #[test]
+    fn user_time_stamp_is_delivered_and_sequence_number_is_assigned<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(2)
+            .allow_user_time_stamps(true)
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().allows_user_time_stamps(), eq true);
+
+        let sut_publisher = sut.publisher().create().unwrap();
+        let sut_subscriber = sut.subscriber().create().unwrap();
+
+        let time_stamp = TimeBuilder::new()
+            .clock_type(sut.static_config().clock_type().into())
+            .seconds(1234)
+            .nanoseconds(5678)
+            .create();
+
+        assert_that!(sut_publisher.send_copy(0), is_ok);
+        let mut sample = sut_publisher.loan_uninit().unwrap().write_payload(1);
+        assert_that!(sample.set_time_stamp(time_stamp), is_ok);
+        assert_that!(sample.header().time_stamp(), eq time_stamp);
+        assert_that!(sample.send(), is_ok);
+
+        let sample = sut_subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().time_stamp(), ne time_stamp);
+        assert_that!(sample.header().sequence_number(), eq 0);
+
+        let sample = sut_subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1);
+        assert_that!(sample.header().time_stamp(), eq time_stamp);
+        assert_that!(sample.header().sequence_number(), eq 1);
+    }
+
+    #[test]
+    fn set_time_stamp_fails_when_user_time_stamps_are_not_allowed<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().allows_user_time_stamps(), eq false);
+
+        let sut_publisher = sut.publisher().create().unwrap();
+        let time_stamp = TimeBuilder::new()
+            .clock_type(sut.static_config().clock_type().into())
+            .seconds(1234)
+            .create();
+
+        let mut sample = sut_publisher.loan().unwrap();
+        assert_that!(
+            sample.set_time_stamp(time_stamp), eq
+            Err(SampleMutSetTimeStampError::UserTimeStampsNotAllowed)
         );
+        assert_that!(sample.header().time_stamp(), ne time_stamp);
     }
 
     #[test]
-    fn publisher_never_goes_out_of_memory_with_huge_history_size<Sut: Service>() {
-        const BUFFER_SIZE: usize = 1;
-        const HISTORY_SIZE: usize = 100;
-        const MAX_BORROW: usize = 1;
-        const MAX_SUBSCRIBERS: usize = 1;
-        const MAX_LOAN: usize = 1;
+    fn set_time_stamp_fails_with_time_of_another_clock<Sut: Service>() {
+        let service_name = generate_name();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .clock_type(ClockType::Realtime)
+            .allow_user_time_stamps(true)
+            .create::<u64>()
+            .unwrap();
+
+        let sut_publisher = sut.publisher().create().unwrap();
+        let time_stamp = TimeBuilder::new()
+            .clock_type(PosixClockType::Monotonic)
+            .seconds(1234)
+            .create();
+
+        let mut sample = sut_publisher.loan().unwrap();
+        assert_that!(
+            sample.set_time_stamp(time_stamp), eq
+            Err(SampleMutSetTimeStampError::IncompatibleClockType)
         );
     }
 
     #[test]
-    fn publisher_never_goes_out_of_memory_with_huge_buffer_size<Sut: Service>() {
-        const BUFFER_SIZE: usize = 3;
-        const HISTORY_SIZE: usize = 0;
-        const MAX_BORROW: usize = 1;
-        const MAX_SUBSCRIBERS: usize = 1;
-        const MAX_LOAN: usize = 1;
+    fn open_fails_when_user_time_stamp_setting_does_not_match<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .allow_user_time_stamps(true)
+            .create::<u64>();
+        assert_that!(sut, is_ok);
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .allow_user_time_stamps(false)
+            .open::<u64>();
+        assert_that!(
+            incompatible_setting_names(sut2.err()), eq vec!["allow_user_time_stamps"]
         );
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .allow_user_time_stamps(true)
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn subscriber_reports_samples_lost_due_to_overflow<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .create::<u64>()
+            .unwrap();
+
+        let sut_publisher = sut.publisher().create().unwrap();
+        let sut_subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(sut_publisher.send_copy(0), is_ok);
+        let sample = sut_subscriber.receive().unwrap().unwrap();
+        let publisher_id = sample.header().publisher_id();
+        drop(sample);
+        assert_that!(sut_subscriber.number_of_lost_samples(publisher_id), eq Some(0));
+
+        // the samples 1, 2 and 3 are overridden in the subscriber buffer
+        for i in 1..6 {
+            assert_that!(sut_publisher.send_copy(i), is_ok);
+        }
+
+        for i in 4..6 {
+            let sample = sut_subscriber.receive().unwrap();
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq i);
+        }
+
+        assert_that!(sut_subscriber.number_of_lost_samples(publisher_id), eq Some(3));
+    }
+
+    #[test]
+    fn number_of_lost_samples_of_unknown_publisher_is_none<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let other_sut = Sut::new(&generate_name())
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let other_publisher = other_sut.publisher().create().unwrap();
+        let other_subscriber = other_sut.subscriber().create().unwrap();
+        assert_that!(other_publisher.send_copy(0), is_ok);
+        let publisher_id = other_subscriber
+            .receive()
+            .unwrap()
+            .unwrap()
+            .header()
+            .publisher_id();
+
+        let sut_subscriber = sut.subscriber().create().unwrap();
+        assert_that!(sut_subscriber.number_of_lost_samples(publisher_id), eq None);
+    }
+
+    #[test]
+    fn publish_history_of_zero_works<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create::<usize>()
+            .unwrap();
+
+        let sut_publisher = sut.publisher().create().unwrap();
+        assert_that!(sut_publisher.send_copy(29), is_ok);
+
+        let sut_subscriber = sut.subscriber().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(data, is_none);
+    }
+
+    #[test]
+    fn initialized_samples_dereference_to_their_payload<Sut: Service>() {
+        #[derive(Debug, Default, ZeroCopySend)]
+        #[repr(C)]
+        struct Payload {
+            value: u64,
+            counter: u32,
+        }
+
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<Payload>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let mut sample = publisher.loan().unwrap();
+        sample.value = 1234;
+        sample.counter += 5;
+        assert_that!(sample.value, eq 1234);
+        assert_that!(sample.header().publisher_id(), eq publisher.id());
+        sample.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.value, eq 1234);
+        assert_that!(sample.counter, eq 5);
+        assert_that!(sample.header().publisher_id(), eq publisher.id());
+    }
+
+    #[test]
+    fn publish_send_copy_with_huge_overflow_works<Sut: Service>() {
+        let service_name = generate_name();
+        const BUFFER_SIZE: usize = 5;
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(1)
+            .max_subscribers(2)
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(1)
+            .create::<usize>()
+            .unwrap();
+
+        let sut_publisher = sut.publisher().max_loaned_samples(1).create().unwrap();
+
+        let mut subscribers = vec![];
+        for _ in 0..2 {
+            let sut_subscriber = sut.subscriber().create();
+            subscribers.push(sut_subscriber);
+        }
+
+        for _ in 0..BUFFER_SIZE * 100 {
+            assert_that!(sut_publisher.send_copy(889), is_ok);
+        }
+    }
+
+    fn publisher_never_goes_out_of_memory_impl<Sut: Service>(
+        buffer_size: usize,
+        history_size: usize,
+        max_borrow: usize,
+        max_subscribers: usize,
+        max_loan: usize,
+    ) {
+        const ITERATIONS: usize = 16;
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(1)
+            .max_subscribers(max_subscribers)
+            .enable_safe_overflow(true)
+            .history_size(history_size)
+            .subscriber_max_buffer_size(buffer_size)
+            .subscriber_max_borrowed_samples(max_borrow)
+            .create::<usize>()
+            .unwrap();
+
+        let sut_publisher = sut
+            .publisher()
+            .max_loaned_samples(max_loan)
+            .create()
+            .unwrap();
+
+        let mut subscribers = vec![];
+        for _ in 0..max_subscribers {
+            let sut_subscriber = sut.subscriber().create().unwrap();
+            subscribers.push(sut_subscriber);
+        }
+
+        for _ in 0..ITERATIONS {
+            // max out borrow
+            let mut borrowed_samples = vec![];
+            while borrowed_samples.len() < max_borrow * max_subscribers {
+                for _ in 0..buffer_size {
+                    assert_that!(sut_publisher.send_copy(889), is_ok);
+                }
+
+                for (i, s) in subscribers.iter().enumerate().take(max_subscribers) {
+                    while let Ok(Some(sample)) = s.receive() {
+                        borrowed_samples.push((i, sample));
+                    }
+                }
+            }
+
+            // max out buffer
+            for _ in 0..buffer_size {
+                assert_that!(sut_publisher.send_copy(8127), is_ok);
+            }
+
+            // max out history
+            for _ in 0..history_size {
+                assert_that!(sut_publisher.send_copy(17283), is_ok);
+            }
+
+            // max out loan
+            let mut loaned_samples = vec![];
+            for _ in 0..max_loan {
+                let sample = sut_publisher.loan_uninit();
+                assert_that!(sample, is_ok);
+                loaned_samples.push(sample);
+            }
+
+            let sample = sut_publisher.loan_uninit();
+            assert_that!(sample, is_err);
+            assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+            // cleanup
+            borrowed_samples.clear();
+            loaned_samples.clear();
+            for s in subscribers.iter().take(max_subscribers) {
+                while let Ok(Some(_)) = s.receive() {}
+            }
+        }
+    }
+
+    #[test]
+    fn publisher_never_goes_out_of_memory_with_huge_max_loan<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 0;
+        const MAX_BORROW: usize = 1;
+        const MAX_SUBSCRIBERS: usize = 1;
+        const MAX_LOAN: usize = 100;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[test]
+    fn publisher_never_goes_out_of_memory_with_huge_max_subscribers<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 0;
+        const MAX_BORROW: usize = 1;
+        const MAX_SUBSCRIBERS: usize = 50;
+        const MAX_LOAN: usize = 1;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[test]
+    fn publisher_never_goes_out_of_memory_with_huge_max_borrow<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 0;
+        const MAX_BORROW: usize = 100;
+        const MAX_SUBSCRIBERS: usize = 1;
+        const MAX_LOAN: usize = 1;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[test]
+    fn publisher_never_goes_out_of_memory_with_huge_history_size<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 100;
+        const MAX_BORROW: usize = 1;
+        const MAX_SUBSCRIBERS: usize = 1;
+        const MAX_LOAN: usize = 1;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[test]
+    fn publisher_never_goes_out_of_memory_with_huge_buffer_size<Sut: Service>() {
+        const BUFFER_SIZE: usize = 3;
+        const HISTORY_SIZE: usize = 0;
+        const MAX_BORROW: usize = 1;
+        const MAX_SUBSCRIBERS: usize = 1;
+        const MAX_LOAN: usize = 1;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[test]
+    fn publisher_never_goes_out_of_memory_with_huge_values<Sut: Service>() {
+        const BUFFER_SIZE: usize = 29;
+        const HISTORY_SIZE: usize = 31;
+        const MAX_BORROW: usize = 12;
+        const MAX_SUBSCRIBERS: usize = 25;
+        const MAX_LOAN: usize = 35;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[test]
+    fn concurrently_created_publishers_do_not_exceed_max_publishers<Sut: Service>() {
+        const NUMBER_OF_THREADS: u32 = 4;
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(1)
+            .create::<u64>()
+            .unwrap();
+
+        let number_of_publishers = AtomicUsize::new(0);
+        let start_handle = BarrierHandle::new();
+        let end_handle = BarrierHandle::new();
+        let start_barrier = BarrierBuilder::new(NUMBER_OF_THREADS)
+            .create(&start_handle)
+            .unwrap();
+        let end_barrier = BarrierBuilder::new(NUMBER_OF_THREADS)
+            .create(&end_handle)
+            .unwrap();
+
+        std::thread::scope(|s| {
+            for _ in 0..NUMBER_OF_THREADS {
+                s.spawn(|| {
+                    let service = Sut::new(&service_name)
+                        .publish_subscribe()
+                        .open::<u64>()
+                        .unwrap();
+
+                    start_barrier.wait();
+                    let publisher = service.publisher().create();
+                    match &publisher {
+                        Ok(_) => {
+                            number_of_publishers.fetch_add(1, Ordering::Relaxed);
+                        }
+                        Err(e) => {
+                            assert_that!(*e, eq PublisherCreateError::ExceedsMaxSupportedPublishers)
+                        }
+                    }
+                    end_barrier.wait();
+                });
+            }
+        });
+
+        assert_that!(number_of_publishers.load(Ordering::Relaxed), eq 1);
+    }
+
+    #[test]
+    fn subscriber_slots_are_reusable_right_after_a_subscriber_is_dropped<Sut: Service>() {
+        const MAX_SUBSCRIBERS: usize = 2;
+        const ITERATIONS: usize = 10000;
+
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_subscribers(MAX_SUBSCRIBERS)
+            .create::<u64>()
+            .unwrap();
+
+        let _subscriber = sut.subscriber().create().unwrap();
+        for _ in 0..ITERATIONS {
+            let subscriber = sut.subscriber().create();
+            assert_that!(subscriber, is_ok);
+        }
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+    }
+
+    #[test]
+    fn concurrently_churning_subscribers_fail_only_when_all_slots_are_occupied<Sut: Service>() {
+        const MAX_SUBSCRIBERS: usize = 2;
+        const NUMBER_OF_THREADS: u32 = 4;
+        const ITERATIONS: usize = 2500;
+
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_subscribers(MAX_SUBSCRIBERS)
+            .create::<u64>()
+            .unwrap();
+
+        let start_handle = BarrierHandle::new();
+        let start_barrier = BarrierBuilder::new(NUMBER_OF_THREADS)
+            .create(&start_handle)
+            .unwrap();
+
+        std::thread::scope(|s| {
+            for _ in 0..NUMBER_OF_THREADS {
+                s.spawn(|| {
+                    let service = Sut::new(&service_name)
+                        .publish_subscribe()
+                        .open::<u64>()
+                        .unwrap();
+
+                    start_barrier.wait();
+                    let mut number_of_subscribers = 0;
+                    while number_of_subscribers < ITERATIONS {
+                        match service.subscriber().create() {
+                            Ok(_) => number_of_subscribers += 1,
+                            // the other threads occupy all slots, they free them right away
+                            Err(SubscriberCreateError::ExceedsMaxSupportedSubscribers) => {
+                                std::thread::yield_now()
+                            }
+                            Err(e) => assert_that!(e, eq SubscriberCreateError::ExceedsMaxSupportedSubscribers),
+                        }
+                    }
+                });
+            }
+        });
+
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 0);
+    }
+
+    #[test]
+    fn publisher_slots_are_reused_after_a_publisher_is_dropped<Sut: Service>() {
+        const MAX_PUBLISHERS: usize = 3;
+        const ITERATIONS: usize = 5;
+
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(MAX_PUBLISHERS)
+            .create::<u64>()
+            .unwrap();
+
+        let mut publishers = vec![];
+        for _ in 0..MAX_PUBLISHERS {
+            publishers.push(sut.publisher().create().unwrap());
+        }
+
+        for i in 0..ITERATIONS {
+            publishers.remove(i % MAX_PUBLISHERS);
+            assert_that!(sut.dynamic_config().number_of_publishers(), eq MAX_PUBLISHERS - 1);
+
+            let publisher = sut.publisher().create();
+            assert_that!(publisher, is_ok);
+            publishers.insert(i % MAX_PUBLISHERS, publisher.unwrap());
+            assert_that!(sut.dynamic_config().number_of_publishers(), eq MAX_PUBLISHERS);
+
+            let publisher = sut.publisher().create();
+            assert_that!(publisher, is_err);
+            assert_that!(publisher.err().unwrap(), eq PublisherCreateError::ExceedsMaxSupportedPublishers);
+        }
+    }
+
+    #[test]
+    fn creating_max_supported_amount_of_ports_work<Sut: Service>() {
+        const MAX_PUBLISHERS: usize = 4;
+        const MAX_SUBSCRIBERS: usize = 8;
+
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(MAX_PUBLISHERS)
+            .max_subscribers(MAX_SUBSCRIBERS)
+            .create::<u64>()
+            .unwrap();
+
+        let mut publishers = vec![];
+        let mut subscribers = vec![];
+
+        // acquire all possible ports
+        for _ in 0..MAX_PUBLISHERS {
+            let publisher = sut.publisher().create();
+            assert_that!(publisher, is_ok);
+            publishers.push(publisher);
+        }
+
+        for _ in 0..MAX_SUBSCRIBERS {
+            let subscriber = sut.subscriber().create();
+            assert_that!(subscriber, is_ok);
+            subscribers.push(subscriber);
+        }
+
+        // create additional ports and fail
+        let publisher = sut.publisher().create();
+        assert_that!(publisher, is_err);
+        assert_that!(
+            publisher.err().unwrap(), eq
+            PublisherCreateError::ExceedsMaxSupportedPublishers
+        );
+
+        let subscriber = sut.subscriber().create();
+        assert_that!(subscriber, is_err);
+        assert_that!(
+            subscriber.err().unwrap(), eq
+            SubscriberCreateError::ExceedsMaxSupportedSubscribers
+        );
+
+        // remove a publisher and subscriber
+        assert_that!(publishers.remove(0), is_ok);
+        assert_that!(subscribers.remove(0), is_ok);
+
+        // create additional ports shall work again
+        let publisher = sut.publisher().create();
+        assert_that!(publisher, is_ok);
+
+        let subscriber = sut.subscriber().create();
+        assert_that!(subscriber, is_ok);
+    }
+
+    #[test]
+    fn set_max_publishers_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(0)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_supported_publishers(), eq 1);
+    }
+
+    #[test]
+    fn set_max_subscribers_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_subscribers(0)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_supported_subscribers(), eq 1);
+    }
+
+    #[test]
+    fn set_subscriber_max_borrowed_samples_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_borrowed_samples(0)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 1);
+    }
+
+    #[test]
+    fn set_buffer_size_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(0)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 1);
+    }
+
+    #[test]
+    fn set_max_slice_len_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(0)
+            .create::<[u8]>()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_slice_len(), eq 1);
+    }
+
+    #[test]
+    fn slice_communication_works<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(16)
+            .create::<[u8]>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(3).unwrap();
+        let sample = sample.write_from_slice(&[1, 2, 3]);
+        assert_that!(sample.send(), is_ok);
+
+        let sample = publisher.loan_slice_uninit(16).unwrap();
+        let sample = sample.write_from_slice(&[7; 16]);
+        assert_that!(sample.send(), is_ok);
+
+        let result = subscriber.receive().unwrap();
+        assert_that!(result, is_some);
+        let result = result.unwrap();
+        assert_that!(result.header().number_of_elements(), eq 3);
+        assert_that!(*result, eq [1u8, 2, 3]);
+
+        let result = subscriber.receive().unwrap();
+        assert_that!(result, is_some);
+        let result = result.unwrap();
+        assert_that!(result.payload().len(), eq 16);
+        assert_that!(*result, eq [7u8; 16]);
+    }
+
+    #[test]
+    fn received_slice_sample_has_the_number_of_sent_elements<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(16)
+            .create::<[u16]>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        for len in [1, 5, 16] {
+            let sample = publisher.loan_slice_uninit(len).unwrap();
+            let sample = sample.write_from_fn(|idx| idx as u16 + 256);
+            assert_that!(sample.send(), is_ok);
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.len(), eq len);
+            assert_that!(sample.is_empty(), eq false);
+            assert_that!(sample.payload(), len len);
+            assert_that!(sample.header().number_of_elements(), eq len as u64);
+
+            let bytes = (0..len)
+                .flat_map(|idx| (idx as u16 + 256).to_ne_bytes())
+                .collect::<Vec<_>>();
+            assert_that!(sample.payload_as_bytes(), eq bytes.as_slice());
+        }
+    }
+
+    #[test]
+    fn header_contains_the_payload_size_and_the_node_id<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(16)
+            .create::<[u16]>()
+            .unwrap();
+        assert_that!(sut.static_config().header_layout_version(), eq Header::LAYOUT_VERSION);
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(5).unwrap();
+        let sample = sample.write_from_fn(|idx| idx as u16);
+        assert_that!(sample.send(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().layout_version(), eq Header::LAYOUT_VERSION);
+        assert_that!(sample.header().number_of_elements(), eq 5);
+        assert_that!(sample.header().payload_size_bytes(), eq 10);
+        assert_that!(sample.header().node_id(), is_none);
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let node_sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let subscriber = node_sut.subscriber().create().unwrap();
+        let publisher = node_sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(1234), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().payload_size_bytes(), eq 8);
+        assert_that!(sample.header().node_id(), eq Some(node.id()));
+    }
+
+    #[test]
+    fn sample_with_incompatible_header_version_is_rejected<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(2)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().max_loaned_samples(1).create().unwrap();
+
+        let mut sample = publisher.loan_uninit().unwrap().write_payload(1234);
+        set_header_layout_version(&mut sample, Header::LAYOUT_VERSION - 1);
+        assert_that!(sample.send(), is_ok);
+
+        let result = subscriber.receive();
+        assert_that!(result.err(), eq Some(SubscriberReceiveError::IncompatibleHeaderVersion));
+
+        // the rejected sample was returned to the publisher
+        assert_that!(publisher.send_copy(5678), is_ok);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 5678);
+    }
+
+    #[test]
+    fn slice_sample_that_exceeds_the_data_segment_is_rejected<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(4)
+            .create::<[u64]>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().max_loaned_samples(1).create().unwrap();
+
+        // the first number of elements overflows the payload size, the second one describes a
+        // payload that is larger than the data segment
+        for number_of_elements in [u64::MAX, 1 << 40] {
+            let sample = publisher.loan_slice_uninit(2).unwrap();
+            let mut sample = sample.write_from_fn(|idx| idx as u64);
+            set_header_number_of_elements(&mut sample, number_of_elements);
+            assert_that!(sample.send(), is_ok);
+
+            let result = subscriber.receive();
+            assert_that!(result.err(), eq Some(SubscriberReceiveError::CorruptedSample));
+        }
+
+        // the rejected samples were returned to the publisher
+        let sample = publisher.loan_slice_uninit(2).unwrap();
+        assert_that!(sample.write_from_fn(|idx| idx as u64 + 7).send(), is_ok);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq [7, 8]);
+    }
+
+    #[test]
+    fn payload_as_bytes_of_sized_payload_contains_the_sent_bytes<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        assert_that!(publisher.send_copy(0x0102030405060708), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload_as_bytes(), eq 0x0102030405060708u64.to_ne_bytes());
+    }
+
+    #[test]
+    fn loan_slice_with_more_than_max_slice_len_elements_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(8)
+            .create::<[u64]>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(9);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanSize);
+
+        let sample = publisher.loan_slice_uninit(8);
+        assert_that!(sample, is_ok);
+    }
+
+    thread_local! {
+        static NUMBER_OF_DROPPED_ELEMENTS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn number_of_dropped_elements() -> usize {
+        NUMBER_OF_DROPPED_ELEMENTS.with(|n| n.get())
+    }
+
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    struct DropCounter(usize);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            NUMBER_OF_DROPPED_ELEMENTS.with(|n| n.set(n.get() + 1));
+        }
+    }
+
+    // reports a length that differs from the number of elements it actually provides
+    struct MisreportingIterator {
+        provided: usize,
+        available: usize,
+        reported_len: usize,
+    }
+
+    impl Iterator for MisreportingIterator {
+        type Item = DropCounter;
+
+        fn next(&mut self) -> Option<DropCounter> {
+            if self.provided == self.available {
+                return None;
+            }
+            self.provided += 1;
+            Some(DropCounter(self.provided))
+        }
+    }
+
+    impl ExactSizeIterator for MisreportingIterator {
+        fn len(&self) -> usize {
+            self.reported_len
+        }
+    }
+
+    #[test]
+    fn write_from_iter_and_write_from_fn_initialize_slice_samples<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 8;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create::<[u64]>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        for len in 1..=MAX_SLICE_LEN {
+            let sample = publisher.loan_slice_uninit(len).unwrap();
+            let sample = sample.write_from_iter((0..len).map(|n| n as u64 * 3));
+            assert_that!(sample.unwrap().send(), is_ok);
+
+            let sample = publisher.loan_slice_uninit(len).unwrap();
+            let sample = sample.write_from_fn(|idx| idx as u64 + 5);
+            assert_that!(sample.send(), is_ok);
+
+            let received = subscriber.receive().unwrap().unwrap();
+            assert_that!(
+                *received,
+                eq(0..len as u64).map(|n| n * 3).collect::<Vec<_>>()
+            );
+            let received = subscriber.receive().unwrap().unwrap();
+            assert_that!(
+                *received,
+                eq(0..len as u64).map(|n| n + 5).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn write_from_iter_with_wrong_number_of_elements_returns_uninit_sample<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 8;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN + 1)
+            .create::<[DropCounter]>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+
+        for len in 1..=MAX_SLICE_LEN {
+            NUMBER_OF_DROPPED_ELEMENTS.with(|n| n.set(0));
+            let mut sample = publisher.loan_slice_uninit(len).unwrap();
+
+            for number_of_elements in [len - 1, len + 1] {
+                let result = sample.write_from_iter((0..number_of_elements).map(DropCounter));
+                assert_that!(result, is_err);
+                sample = result.err().unwrap();
+            }
+            assert_that!(number_of_dropped_elements(), eq 0);
+
+            for available in [len - 1, len + 1] {
+                let result = sample.write_from_iter(MisreportingIterator {
+                    provided: 0,
+                    available,
+                    reported_len: len,
+                });
+                assert_that!(result, is_err);
+                sample = result.err().unwrap();
+            }
+            // the written elements and the one surplus element were dropped
+            assert_that!(number_of_dropped_elements(), eq 2 * len);
+
+            let result = sample.write_from_iter((0..len).map(DropCounter));
+            assert_that!(result, is_ok);
+            assert_that!(result.unwrap().payload(), len len);
+            assert_that!(number_of_dropped_elements(), eq 2 * len);
+        }
+    }
+
+    #[test]
+    fn write_from_fn_drops_written_elements_when_initializer_panics<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 8;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create::<[DropCounter]>()
+            .unwrap();
+
+        // the loan fails when a chunk was not returned during unwinding
+        let publisher = sut.publisher().max_loaned_samples(1).create().unwrap();
+
+        for len in 1..=MAX_SLICE_LEN {
+            for panic_at in 0..len {
+                NUMBER_OF_DROPPED_ELEMENTS.with(|n| n.set(0));
+                let sample = publisher.loan_slice_uninit(len).unwrap();
+                let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                    sample.write_from_fn(|idx| {
+                        if idx == panic_at {
+                            panic!("initialization of element {} fails", idx);
+                        }
+                        DropCounter(idx)
+                    })
+                }));
+                assert_that!(result, is_err);
+                assert_that!(number_of_dropped_elements(), eq panic_at);
+            }
+        }
+    }
+
+    #[test]
+    fn compatibility_diff_names_the_incompatible_setting<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .history_size(2)
+            .subscriber_max_buffer_size(2)
+            .enable_notifications(false)
+            .clock_type(ClockType::Realtime)
+            .create::<u64>()
+            .unwrap();
+
+        let static_config = sut.static_config();
+        assert_that!(
+            static_config.compatibility_diff(&Requirements::new()),
+            is_empty
+        );
+        assert_that!(
+            static_config.compatibility_diff(&Requirements::new().history_size(1)),
+            is_empty
+        );
+
+        let diff = static_config.compatibility_diff(&Requirements::new().history_size(3));
+        assert_that!(diff, len 1);
+        assert_that!(diff[0].name, eq "history_size");
+        assert_that!(diff[0].existing, eq "2");
+        assert_that!(diff[0].requested, eq "3");
+        let result = Sut::new(&service_name)
+            .publish_subscribe()
+            .history_size(3)
+            .open::<u64>();
+        assert_that!(incompatible_setting_names(result.err()), eq vec!["history_size"]);
+
+        let diff =
+            static_config.compatibility_diff(&Requirements::new().enable_notifications(true));
+        assert_that!(diff, len 1);
+        assert_that!(diff[0].name, eq "enable_notifications");
+        assert_that!(diff[0].existing, eq "false");
+        assert_that!(diff[0].requested, eq "true");
+        let result = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .open::<u64>();
+        assert_that!(incompatible_setting_names(result.err()), eq vec!["enable_notifications"]);
+
+        let diff =
+            static_config.compatibility_diff(&Requirements::new().clock_type(ClockType::Monotonic));
+        assert_that!(diff, len 1);
+        assert_that!(diff[0].name, eq "clock_type");
+        assert_that!(diff[0].existing, eq "Realtime");
+        assert_that!(diff[0].requested, eq "Monotonic");
+        let result = Sut::new(&service_name)
+            .publish_subscribe()
+            .clock_type(ClockType::Monotonic)
+            .open::<u64>();
+        assert_that!(incompatible_setting_names(result.err()), eq vec!["clock_type"]);
+
+        // every incompatible setting is reported
+        let diff = static_config.compatibility_diff(
+            &Requirements::new()
+                .history_size(3)
+                .enable_notifications(true)
+                .clock_type(ClockType::Monotonic),
+        );
+        let names: Vec<_> = diff.iter().map(|setting| setting.name).collect();
+        assert_that!(names, eq vec!["history_size", "enable_notifications", "clock_type"]);
+        let result = Sut::new(&service_name)
+            .publish_subscribe()
+            .history_size(3)
+            .enable_notifications(true)
+            .clock_type(ClockType::Monotonic)
+            .open::<u64>();
+        assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IncompatibleSettings(diff)));
+
+        // the type details are compared by their layout hash
+        let payload_type_details = static_config.payload_type_details();
+        assert_that!(
+            static_config.compatibility_diff(
+                &Requirements::new().payload_type_details(payload_type_details.clone())
+            ),
+            is_empty
+        );
+        let other_type_details = TypeDetails::from_parts(
+            payload_type_details.type_name(),
+            payload_type_details.size(),
+            payload_type_details.alignment(),
+            payload_type_details.layout_hash().map(|hash| hash ^ 1),
+        );
+        let diff = static_config
+            .compatibility_diff(&Requirements::new().payload_type_details(other_type_details));
+        assert_that!(diff, len 1);
+        assert_that!(diff[0].name, eq "payload_type_details");
+        assert_that!(diff[0].existing, eq payload_type_details.to_string());
+
+        let diff = static_config.compatibility_diff(
+            &Requirements::new()
+                .user_header_type_details(TypeDetails::from_parts("u64", 8, 8, None)),
+        );
+        assert_that!(diff, len 1);
+        assert_that!(diff[0].name, eq "user_header_type_details");
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_support_requested_max_slice_len<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(8)
+            .create::<[u64]>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(9)
+            .open::<[u64]>();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            incompatible_setting_names(sut2.err()), eq vec!["max_slice_len"]
+        );
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(4)
+            .open::<[u64]>();
+        assert_that!(sut2, is_ok);
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2, is_err);
+        let (expected, actual) = incompatible_types(sut2.err()).unwrap();
+        assert_that!(expected.type_name(), eq std::any::type_name::<[u64]>());
+        assert_that!(actual.type_name(), eq std::any::type_name::<u64>());
+    }
+
+    #[test]
+    fn enable_single_subscriber_restricts_subscribers_and_history<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_single_subscriber(true)
+            .max_subscribers(8)
+            .history_size(4)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.static_config().has_single_subscriber(), eq true);
+        assert_that!(sut.static_config().max_supported_subscribers(), eq 1);
+        assert_that!(sut.static_config().history_size(), eq 0);
+
+        let _subscriber = sut.subscriber().create().unwrap();
+        let subscriber = sut.subscriber().create();
+        assert_that!(subscriber, is_err);
+        assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::ExceedsMaxSupportedSubscribers);
+    }
+
+    #[test]
+    fn payload_mut_of_received_sample_works_with_single_subscriber<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_single_subscriber(true)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(1234), is_ok);
+
+        let mut sample = subscriber.receive().unwrap().unwrap();
+        let payload = sample.payload_mut();
+        assert_that!(payload, is_some);
+        *payload.unwrap() = 5678;
+        assert_that!(*sample, eq 5678);
+    }
+
+    #[test]
+    fn payload_mut_of_received_sample_is_unavailable_without_single_subscriber<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(1234), is_ok);
+
+        let mut sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload_mut(), is_none);
+        assert_that!(*sample, eq 1234);
+    }
+
+    #[test]
+    fn open_fails_when_single_subscriber_setting_does_not_match<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_single_subscriber(true)
+            .create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_single_subscriber(false)
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            incompatible_setting_names(sut2.err()), eq vec!["enable_single_subscriber"]
+        );
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_single_subscriber(true)
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[derive(Debug, Default, ZeroCopySend, PartialEq, Eq, Clone, Copy)]
+    #[repr(C)]
+    struct TestUserHeader {
+        trace_id: u64,
+        priority: u8,
+    }
+
+    #[test]
+    fn user_header_is_delivered_with_payload<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .user_header::<TestUserHeader>()
+            .create::<u32>()
+            .unwrap();
+
+        assert_that!(
+            sut.static_config().user_header_type_name(), eq
+            std::any::type_name::<TestUserHeader>()
+        );
+
+        let sut_publisher = sut.publisher().create().unwrap();
+        let sut_subscriber = sut.subscriber().create().unwrap();
+
+        let mut sample = sut_publisher.loan_uninit().unwrap();
+        assert_that!(*sample.user_header(), eq TestUserHeader::default());
+        *sample.user_header_mut() = TestUserHeader {
+            trace_id: 0xdeadbeef,
+            priority: 3,
+        };
+        let sample = sample.write_payload(81);
+        assert_that!(sample.send(), is_ok);
+
+        // the user header is initialized with the default value on every loan
+        assert_that!(sut_publisher.send_copy(82), is_ok);
+
+        let sample = sut_subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.user_header().trace_id, eq 0xdeadbeef);
+        assert_that!(sample.user_header().priority, eq 3);
+        assert_that!(*sample, eq 81);
+
+        let sample = sut_subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.user_header(), eq TestUserHeader::default());
+        assert_that!(*sample, eq 82);
+    }
+
+    #[test]
+    fn user_header_works_with_slice_payload<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(8)
+            .user_header::<u8>()
+            .create::<[u64]>()
+            .unwrap();
+
+        let sut_publisher = sut.publisher().create().unwrap();
+        let sut_subscriber = sut.subscriber().create().unwrap();
+
+        let mut sample = sut_publisher.loan_slice_uninit(3).unwrap();
+        *sample.user_header_mut() = 5;
+        let sample = sample.write_from_slice(&[1, 2, 3]);
+        assert_that!(sample.send(), is_ok);
+
+        let sample = sut_subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.user_header(), eq 5);
+        assert_that!(*sample, eq [1u64, 2, 3]);
+    }
+
+    #[test]
+    fn subscribers_with_complementary_filters_receive_their_half_of_the_stream<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 8;
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .user_header::<u8>()
+            .create::<u64>()
+            .unwrap();
+
+        let even_subscriber = sut
+            .subscriber()
+            .filter(|_, shard| *shard == 0)
+            .create()
+            .unwrap();
+        let odd_subscriber = sut
+            .subscriber()
+            .filter(|_, shard| *shard == 1)
+            .create()
+            .unwrap();
+        let sut_publisher = sut.publisher().create().unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            let mut sample = sut_publisher.loan_uninit().unwrap();
+            *sample.user_header_mut() = (i % 2) as u8;
+            assert_that!(sample.write_payload(i).send(), eq Ok(2));
+        }
+
+        let mut even_samples = vec![];
+        while let Some(sample) = even_subscriber.receive().unwrap() {
+            assert_that!(*sample.user_header(), eq 0);
+            even_samples.push(*sample);
+        }
+        assert_that!(even_samples, eq vec![0, 2, 4, 6]);
+
+        let mut odd_samples = vec![];
+        while let Some(sample) = odd_subscriber.receive().unwrap() {
+            assert_that!(*sample.user_header(), eq 1);
+            odd_samples.push(*sample);
+        }
+        assert_that!(odd_samples, eq vec![1, 3, 5, 7]);
+
+        // filtered samples are no lost samples
+        assert_that!(even_subscriber.number_of_lost_samples(sut_publisher.id()), eq Some(0));
+    }
+
+    #[test]
+    fn receive_skips_a_full_buffer_of_filtered_samples<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 4096;
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .user_header::<u8>()
+            .create::<u64>()
+            .unwrap();
+
+        let sut_subscriber = sut.subscriber().filter(|_, _| false).create().unwrap();
+        let sut_publisher = sut.publisher().create().unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(sut_publisher.send_copy(i as u64), eq Ok(1));
+        }
+
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+        assert_that!(sut_subscriber.has_samples().unwrap(), eq false);
+
+        // all filtered samples were returned, the publisher can fill the buffer again
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(sut_publisher.send_copy(i as u64), eq Ok(1));
+        }
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn open_fails_when_user_header_type_does_not_match<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .user_header::<TestUserHeader>()
+            .create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2, is_err);
+        let (expected, actual) = incompatible_types(sut2.err()).unwrap();
+        assert_that!(expected.type_name(), eq std::any::type_name::<TestUserHeader>());
+        assert_that!(actual.type_name(), eq std::any::type_name::<()>());
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .user_header::<u64>()
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        let (expected, actual) = incompatible_types(sut2.err()).unwrap();
+        assert_that!(expected.type_name(), eq std::any::type_name::<TestUserHeader>());
+        assert_that!(actual.type_name(), eq std::any::type_name::<u64>());
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .user_header::<TestUserHeader>()
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_with_type_details_unchecked_requires_user_header_with_same_layout<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .user_header::<TestUserHeader>()
+            .create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = unsafe {
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .user_header::<u64>()
+                .open_with_type_details_unchecked::<u64>()
+        };
+        assert_that!(sut2, is_err);
+        let (expected, actual) = incompatible_types(sut2.err()).unwrap();
+        assert_that!(expected.type_name(), eq std::any::type_name::<TestUserHeader>());
+        assert_that!(actual.type_name(), eq std::any::type_name::<u64>());
+
+        let sut2 = unsafe {
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .user_header::<[u64; 2]>()
+                .open_with_type_details_unchecked::<u64>()
+        };
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn user_header_mut_of_received_sample_requires_single_subscriber<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .user_header::<u64>()
+            .create::<u64>()
+            .unwrap();
+
+        let sut_publisher = sut.publisher().create().unwrap();
+        let sut_subscriber = sut.subscriber().create().unwrap();
+        assert_that!(sut_publisher.send_copy(1), is_ok);
+
+        let mut sample = sut_subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.user_header_mut(), is_none);
+    }
+
+    #[test]
+    fn does_exist_works_single<Sut: Service + Details<'static>>() {
+        let service_name = generate_name();
+        assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
+
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(Sut::does_exist(&service_name).unwrap(), eq true);
+        assert_that!(Sut::does_exist(&service_name).unwrap(), eq true);
+
+        drop(_sut);
+
+        assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
+    }
+
+    #[test]
+    fn does_exist_works_many<Sut: Service + Details<'static>>() {
+        const NUMBER_OF_SERVICES: usize = 8;
+
+        let mut services = vec![];
+        let mut service_names = vec![];
+
+        for i in 0..NUMBER_OF_SERVICES {
+            let service_name = generate_name();
+            assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
+
+            services.push(
+                Sut::new(&service_name)
+                    .publish_subscribe()
+                    .create::<u64>()
+                    .unwrap(),
+            );
+            service_names.push(service_name);
+
+            for s in service_names.iter().take(i + 1) {
+                assert_that!(Sut::does_exist(s).unwrap(), eq true);
+            }
+        }
+
+        for i in 0..NUMBER_OF_SERVICES {
+            for s in service_names.iter().take(NUMBER_OF_SERVICES - i) {
+                assert_that!(Sut::does_exist(s).unwrap(), eq true);
+            }
+
+            for s in service_names
+                .iter()
+                .take(NUMBER_OF_SERVICES)
+                .skip(NUMBER_OF_SERVICES - i)
+            {
+                assert_that!(Sut::does_exist(s).unwrap(), eq false);
+            }
+
+            services.pop();
+        }
+    }
+
+    #[test]
+    fn list_works<Sut: Service + Details<'static>>() {
+        const NUMBER_OF_SERVICES: usize = 8;
+
+        let mut services = vec![];
+        let mut service_names = vec![];
+
+        let contains_service_names = |names, state: Vec<StaticConfig>| {
+            for n in names {
+                let mut name_found = false;
+                for s in &state {
+                    if *s.service_name() == n {
+                        name_found = true;
+                        break;
+                    }
+                }
+
+                if !name_found {
+                    return false;
+                }
+            }
+
+            true
+        };
+
+        for i in 0..NUMBER_OF_SERVICES {
+            let service_name = generate_name();
+
+            services.push(
+                Sut::new(&service_name)
+                    .publish_subscribe()
+                    .create::<u64>()
+                    .unwrap(),
+            );
+            service_names.push(service_name);
+
+            let service_list = Sut::list().unwrap();
+            assert_that!(service_list, len i + 1);
+
+            assert_that!(contains_service_names(service_names.clone(), service_list), eq true);
+        }
+
+        for i in 0..NUMBER_OF_SERVICES {
+            services.pop();
+            service_names.pop();
+
+            let service_list = Sut::list().unwrap();
+            assert_that!(service_list, len NUMBER_OF_SERVICES - i - 1);
+            assert_that!(contains_service_names(service_names.clone(), service_list), eq true);
+        }
+    }
+
+    #[test]
+    fn subscribers_with_different_buffer_sizes_retain_their_amount_of_samples<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 10;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(8)
+            .history_size(0)
+            .enable_safe_overflow(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let latest_only = sut.subscriber().buffer_size(1).create().unwrap();
+        let deep_buffer = sut.subscriber().buffer_size(8).create().unwrap();
+        assert_that!(latest_only.buffer_size(), eq 1);
+        assert_that!(deep_buffer.buffer_size(), eq 8);
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(n), eq Ok(2));
+        }
+
+        let mut received = vec![];
+        while let Some(sample) = latest_only.receive().unwrap() {
+            received.push(*sample);
+        }
+        assert_that!(received, eq vec![NUMBER_OF_SAMPLES - 1]);
+
+        received.clear();
+        while let Some(sample) = deep_buffer.receive().unwrap() {
+            received.push(*sample);
+        }
+        let expected: Vec<u64> = (2..NUMBER_OF_SAMPLES).collect();
+        assert_that!(received, eq expected);
+    }
+
+    #[test]
+    fn subscriber_buffer_size_is_clamped_to_service_maximum<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(4)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().buffer_size(100).create().unwrap();
+        assert_that!(subscriber.buffer_size(), eq 4);
+
+        let subscriber = sut.subscriber().buffer_size(0).create().unwrap();
+        assert_that!(subscriber.buffer_size(), eq 1);
+
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(subscriber.buffer_size(), eq 4);
+    }
+
+    #[test]
+    fn connection_uses_the_smaller_buffer_size_of_publisher_and_subscriber<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 10;
+        const PUBLISHER_BUFFER_SIZE: usize = 4;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(8)
+            .enable_safe_overflow(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut
+            .publisher()
+            .subscriber_max_buffer_size(PUBLISHER_BUFFER_SIZE)
+            .create()
+            .unwrap();
+        assert_that!(publisher.subscriber_max_buffer_size(), eq PUBLISHER_BUFFER_SIZE);
+
+        // the subscriber requests a greater, an equal and a smaller buffer than the publisher
+        let subscribers = [
+            (sut.subscriber().buffer_size(8).create().unwrap(), 4),
+            (sut.subscriber().buffer_size(4).create().unwrap(), 4),
+            (sut.subscriber().buffer_size(2).create().unwrap(), 2),
+        ];
+        assert_that!(publisher.update_connections(), is_ok);
+
+        for (subscriber, expected_buffer_size) in &subscribers {
+            assert_that!(subscriber.effective_buffer_size(publisher.id()), eq Some(*expected_buffer_size));
+            assert_that!(publisher.effective_buffer_size(subscriber.id()), eq Some(*expected_buffer_size));
+        }
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(n), eq Ok(subscribers.len()));
+        }
+
+        for (subscriber, expected_buffer_size) in &subscribers {
+            let mut received = vec![];
+            while let Some(sample) = subscriber.receive().unwrap() {
+                received.push(*sample);
+            }
+            let expected: Vec<u64> =
+                (NUMBER_OF_SAMPLES - *expected_buffer_size as u64..NUMBER_OF_SAMPLES).collect();
+            assert_that!(received, eq expected);
+        }
+    }
+
+    #[test]
+    fn connection_is_refused_when_the_buffer_size_is_below_the_required_minimum<Sut: Service>() {
+        const PUBLISHER_BUFFER_SIZE: usize = 4;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(8)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut
+            .publisher()
+            .subscriber_max_buffer_size(PUBLISHER_BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let subscriber_factory = sut.subscriber().buffer_size(8);
+        let requires_less = subscriber_factory
+            .clone()
+            .required_min_buffer_size(PUBLISHER_BUFFER_SIZE - 1)
+            .create()
+            .unwrap();
+        let requires_equal = subscriber_factory
+            .clone()
+            .required_min_buffer_size(PUBLISHER_BUFFER_SIZE)
+            .create()
+            .unwrap();
+        let requires_more = subscriber_factory
+            .clone()
+            .required_min_buffer_size(PUBLISHER_BUFFER_SIZE + 1)
+            .create()
+            .unwrap();
+        assert_that!(requires_more.required_min_buffer_size(), eq PUBLISHER_BUFFER_SIZE + 1);
+
+        for subscriber in [&requires_less, &requires_equal] {
+            assert_that!(subscriber.connection_errors(), len 0);
+            assert_that!(subscriber.effective_buffer_size(publisher.id()), eq Some(PUBLISHER_BUFFER_SIZE));
+        }
+
+        assert_that!(requires_more.effective_buffer_size(publisher.id()), is_none);
+        assert_that!(
+            requires_more.connection_errors(),
+            eq vec![(
+                publisher.id(),
+                ConnectionFailure::FailedToEstablishConnection(
+                    ZeroCopyCreationError::IncompatibleBufferSize
+                )
+            )]
+        );
+
+        let failures = match publisher.send_copy(1234) {
+            Err(PublisherSendError::PartialDelivery {
+                number_of_recipients,
+                failures,
+            }) => {
+                assert_that!(number_of_recipients, eq 2);
+                failures
+            }
+            result => panic!("expected a partial delivery but got {:?}", result),
+        };
+        assert_that!(failures, len 1);
+        assert_that!(failures[0].subscriber_id(), eq requires_more.id());
+        assert_that!(
+            failures[0].reason(),
+            eq DeliveryFailureReason::UnableToEstablishConnection(
+                ZeroCopyCreationError::IncompatibleBufferSize
+            )
+        );
+        assert_that!(publisher.effective_buffer_size(requires_more.id()), is_none);
+        assert_that!(publisher.effective_buffer_size(requires_equal.id()), eq Some(PUBLISHER_BUFFER_SIZE));
+
+        assert_that!(*requires_equal.receive().unwrap().unwrap(), eq 1234);
+        assert_that!(requires_more.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn failed_connection_is_reported_once_while_the_healthy_publishers_deliver<Sut: Service>() {
+        const REQUIRED_MIN_BUFFER_SIZE: usize = 4;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(3)
+            .subscriber_max_buffer_size(8)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber()
+            .buffer_size(8)
+            .required_min_buffer_size(REQUIRED_MIN_BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let healthy_publisher = sut.publisher().create().unwrap();
+        let failing_publisher = sut
+            .publisher()
+            .subscriber_max_buffer_size(REQUIRED_MIN_BUFFER_SIZE - 1)
+            .create()
+            .unwrap();
+
+        assert_that!(healthy_publisher.send_copy(1), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1);
+        assert_that!(
+            subscriber.connection_errors(),
+            eq vec![(
+                failing_publisher.id(),
+                ConnectionFailure::FailedToEstablishConnection(
+                    ZeroCopyCreationError::IncompatibleBufferSize
+                )
+            )]
+        );
+
+        // the connections are updated again when another publisher joins, the failure of the
+        // same publisher is not reported a second time
+        let another_healthy_publisher = sut.publisher().create().unwrap();
+        assert_that!(healthy_publisher.send_copy(2), eq Ok(1));
+        assert_that!(another_healthy_publisher.send_copy(3), eq Ok(1));
+
+        let mut received = vec![];
+        while let Some(sample) = subscriber.receive().unwrap() {
+            received.push(*sample);
+        }
+        received.sort();
+        assert_that!(received, eq vec![2, 3]);
+        assert_that!(subscriber.connection_errors(), len 0);
+        assert_that!(
+            subscriber.effective_buffer_size(failing_publisher.id()),
+            is_none
+        );
+    }
+
+    #[test]
+    fn subscriber_with_required_min_buffer_size_above_its_buffer_size_cannot_be_created<
+        Sut: Service,
+    >() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(4)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber()
+            .buffer_size(100)
+            .required_min_buffer_size(5)
+            .create();
+        assert_that!(subscriber.err(), eq Some(SubscriberCreateError::RequiredMinBufferSizeExceedsBufferSize));
+
+        let subscriber = sut
+            .subscriber()
+            .buffer_size(100)
+            .required_min_buffer_size(4)
+            .create();
+        assert_that!(subscriber, is_ok);
+    }
+
+    #[test]
+    fn publisher_subscriber_max_buffer_size_is_clamped_to_service_maximum<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(3)
+            .subscriber_max_buffer_size(4)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut
+            .publisher()
+            .subscriber_max_buffer_size(100)
+            .create()
+            .unwrap();
+        assert_that!(publisher.subscriber_max_buffer_size(), eq 4);
+
+        let publisher = sut
+            .publisher()
+            .subscriber_max_buffer_size(0)
+            .create()
+            .unwrap();
+        assert_that!(publisher.subscriber_max_buffer_size(), eq 1);
+
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.subscriber_max_buffer_size(), eq 4);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_support_requested_deadline<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(100);
+        let service_name = generate_name();
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().create::<u64>();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().deadline(), is_none);
+
+        let service_name = generate_name();
+        // the service has to stay alive, otherwise it is removed before it is opened
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .deadline(DEADLINE)
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().deadline(), eq Some(DEADLINE));
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .deadline(DEADLINE / 2)
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            incompatible_setting_names(sut2.err()), eq vec!["deadline"]
+        );
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .deadline(DEADLINE * 2)
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().deadline(), eq Some(DEADLINE));
+    }
+
+    #[test]
+    fn subscriber_reports_missed_deadline_once_per_silent_period<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .deadline(DEADLINE)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_copy(1), is_ok);
+        let publisher_id = subscriber
+            .receive()
+            .unwrap()
+            .unwrap()
+            .header()
+            .publisher_id();
+        assert_that!(subscriber.missed_deadlines().unwrap(), is_empty);
+
+        std::thread::sleep(DEADLINE * 2);
+
+        let missed_deadlines = subscriber.missed_deadlines().unwrap();
+        assert_that!(missed_deadlines, len 1);
+        assert_that!(missed_deadlines[0].publisher_id(), eq publisher_id);
+        assert_that!(missed_deadlines[0].elapsed(), time_at_least DEADLINE * 2);
+        assert_that!(subscriber.missed_deadlines().unwrap(), is_empty);
+
+        assert_that!(publisher.send_copy(2), is_ok);
+        assert_that!(subscriber.receive().unwrap(), is_some);
+        assert_that!(subscriber.missed_deadlines().unwrap(), is_empty);
+
+        std::thread::sleep(DEADLINE * 2);
+        assert_that!(subscriber.missed_deadlines().unwrap(), len 1);
+    }
+
+    #[test]
+    fn deadline_window_is_not_restarted_by_receive_without_sample<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .deadline(DEADLINE)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let _publisher = sut.publisher().create().unwrap();
+
+        // the window starts when the subscriber connects to the publisher
+        assert_that!(subscriber.missed_deadlines().unwrap(), is_empty);
+
+        for _ in 0..4 {
+            std::thread::sleep(DEADLINE / 2);
+            assert_that!(subscriber.receive().unwrap(), is_none);
+        }
+
+        assert_that!(subscriber.missed_deadlines().unwrap(), len 1);
+    }
+
+    #[test]
+    fn subscriber_without_deadline_never_reports_missed_deadlines<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let _publisher = sut.publisher().create().unwrap();
+
+        std::thread::sleep(Duration::from_millis(10));
+        assert_that!(subscriber.missed_deadlines().unwrap(), is_empty);
+    }
+
+    #[test]
+    fn subscriber_receives_buffered_samples_of_disconnected_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_copy(0), is_ok);
+        let publisher_id = subscriber
+            .receive()
+            .unwrap()
+            .unwrap()
+            .header()
+            .publisher_id();
+        assert_that!(subscriber.connection_state(publisher_id).unwrap(), eq Some(PublisherConnectionState::Connected));
+
+        assert_that!(publisher.send_copy(1), is_ok);
+        assert_that!(publisher.send_copy(2), is_ok);
+        drop(publisher);
+
+        assert_that!(subscriber.connection_state(publisher_id).unwrap(), eq Some(PublisherConnectionState::Disconnected));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 2);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(subscriber.connection_state(publisher_id).unwrap(), eq Some(PublisherConnectionState::Disconnected));
+    }
+
+    #[test]
+    fn subscriber_connects_to_new_publisher_in_slot_of_disconnected_one<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(1)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(1), is_ok);
+        let old_publisher_id = subscriber
+            .receive()
+            .unwrap()
+            .unwrap()
+            .header()
+            .publisher_id();
+        drop(publisher);
+        assert_that!(subscriber.update_connections(), is_ok);
+
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(2), is_ok);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2);
+        assert_that!(sample.header().publisher_id(), ne old_publisher_id);
+        assert_that!(subscriber.connection_state(sample.header().publisher_id()).unwrap(), eq Some(PublisherConnectionState::Connected));
+        assert_that!(
+            subscriber.connection_state(old_publisher_id).unwrap(),
+            is_none
+        );
+    }
+
+    #[test]
+    fn held_sample_stays_valid_when_its_publisher_is_replaced<Sut: Service>() {
+        const NUMBER_OF_REPLACEMENTS: u64 = 8;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(1)
+            .subscriber_max_borrowed_samples(NUMBER_OF_REPLACEMENTS as usize + 1)
+            .create::<[u64; 64]>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let mut held_samples = vec![];
+        let mut publisher_ids = vec![];
+
+        for n in 0..NUMBER_OF_REPLACEMENTS {
+            // the new publisher takes over the slot of the dropped one while its sample is
+            // still held
+            let publisher = sut.publisher().create().unwrap();
+            assert_that!(publisher_ids.contains(&publisher.id()), eq false);
+            publisher_ids.push(publisher.id());
+            assert_that!(subscriber.update_connections(), is_ok);
+
+            assert_that!(publisher.send_copy([n; 64]), eq Ok(1));
+            drop(publisher);
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.header().publisher_id(), eq publisher_ids[n as usize]);
+            held_samples.push(sample);
+        }
+
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy([NUMBER_OF_REPLACEMENTS; 64]), eq Ok(1));
+        assert_that!(subscriber.update_connections(), is_ok);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq [NUMBER_OF_REPLACEMENTS; 64]);
+
+        for (n, sample) in held_samples.iter().enumerate() {
+            assert_that!(**sample, eq [n as u64; 64]);
+        }
+        drop(held_samples);
+
+        // the samples of the replaced publishers are not returned to the current one
+        for n in 0..NUMBER_OF_REPLACEMENTS {
+            assert_that!(publisher.send_copy([n; 64]), eq Ok(1));
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq [n; 64]);
+        }
+        assert_that!(subscriber.statistics().borrowed_samples(), eq 0);
+        assert_that!(publisher.statistics().loaned_samples(), eq 0);
+    }
+
+    #[test]
+    fn receive_fails_when_samples_are_held_past_max_borrowed_samples<Sut: Service>() {
+        const MAX_BORROWED_SAMPLES: usize = 3;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(MAX_BORROWED_SAMPLES + 1)
+            .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(subscriber.max_borrowed_samples(), eq MAX_BORROWED_SAMPLES);
+
+        for i in 0..=MAX_BORROWED_SAMPLES as u64 {
+            assert_that!(publisher.send_copy(i), eq Ok(1));
+        }
+
+        let mut samples = vec![];
+        for _ in 0..subscriber.max_borrowed_samples() {
+            samples.push(subscriber.receive().unwrap().unwrap());
+        }
+
+        let result = subscriber.receive();
+        assert_that!(result.err().unwrap(), eq SubscriberReceiveError::ExceedsMaxBorrowedSamples);
+
+        samples.remove(0);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq MAX_BORROWED_SAMPLES as u64);
+
+        // the limit is checked before the buffer, therefore a sample has to be returned first
+        drop(sample);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(subscriber.connection_errors(), len 0);
+    }
+
+    #[test]
+    fn cloned_sample_is_shared_with_threads_and_released_by_the_last_clone<Sut: Service>() {
+        const NUMBER_OF_THREADS: usize = 8;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_borrowed_samples(1)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        assert_that!(publisher.send_copy(5678), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let clone = sample.clone();
+        assert_that!(*clone, eq 1234);
+
+        // all clones count as a single borrowed sample
+        let result = subscriber.receive();
+        assert_that!(result.err().unwrap(), eq SubscriberReceiveError::ExceedsMaxBorrowedSamples);
+
+        let publisher_id = publisher.id();
+        let number_of_readers = AtomicUsize::new(0);
+        let mut samples = vec![sample, clone];
+        while samples.len() < NUMBER_OF_THREADS {
+            samples.push(samples[0].clone());
+        }
+
+        std::thread::scope(|s| {
+            for sample in samples {
+                let number_of_readers = &number_of_readers;
+                s.spawn(move || {
+                    assert_that!(*sample, eq 1234);
+                    assert_that!(sample.header().publisher_id(), eq publisher_id);
+                    number_of_readers.fetch_add(1, Ordering::Relaxed);
+                });
+            }
+        });
+
+        assert_that!(number_of_readers.load(Ordering::Relaxed), eq NUMBER_OF_THREADS);
+
+        // the chunk was returned by the last clone, otherwise the borrow limit would be exceeded
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 5678);
+    }
+
+    #[test]
+    fn sample_origin_is_the_id_of_the_sending_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher_1 = sut.publisher().create().unwrap();
+        let publisher_2 = sut.publisher().create().unwrap();
+
+        assert_that!(publisher_1.send_copy(1), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.origin(), eq publisher_1.id());
+        assert_that!(sample.origin(), eq sample.header().publisher_id());
+
+        assert_that!(publisher_2.send_copy(2), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.origin(), eq publisher_2.id());
+        assert_that!(sample.origin(), ne publisher_1.id());
+    }
+
+    #[test]
+    fn has_samples_does_not_dequeue_samples_of_a_newly_connected_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(subscriber.has_samples(), eq Ok(false));
+
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+
+        assert_that!(subscriber.has_samples(), eq Ok(true));
+        assert_that!(subscriber.has_samples(), eq Ok(true));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1234);
+        assert_that!(sample.origin(), eq publisher.id());
+        assert_that!(subscriber.has_samples(), eq Ok(false));
+    }
+
+    #[test]
+    fn peek_returns_the_header_of_the_sample_the_next_receive_returns<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 4;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .user_header::<u8>()
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(subscriber.peek().unwrap(), is_none);
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            let mut sample = publisher.loan_uninit().unwrap();
+            *sample.user_header_mut() = i as u8 + 10;
+            assert_that!(sample.write_payload(i).send(), eq Ok(1));
+        }
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            let view = subscriber.peek().unwrap().unwrap();
+            let view_again = subscriber.peek().unwrap().unwrap();
+            assert_that!(view.header().sequence_number(), eq view_again.header().sequence_number());
+            assert_that!(*view.user_header(), eq i as u8 + 10);
+            assert_that!(view.header().publisher_id(), eq publisher.id());
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq i);
+            assert_that!(sample.header().sequence_number(), eq view.header().sequence_number());
+        }
+        assert_that!(subscriber.peek().unwrap(), is_none);
+        assert_that!(subscriber.statistics().received_samples(), eq NUMBER_OF_SAMPLES);
+    }
+
+    #[test]
+    fn peek_follows_the_publisher_order_of_receive<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(3)
+            .subscriber_max_buffer_size(2)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publishers: Vec<_> = (0..3).map(|_| sut.publisher().create().unwrap()).collect();
+        for (n, publisher) in publishers.iter().enumerate().rev() {
+            assert_that!(publisher.send_copy(n as u64), eq Ok(1));
+            assert_that!(publisher.send_copy(n as u64 + 10), eq Ok(1));
+        }
+
+        let mut number_of_samples = 0;
+        while let Some(view) = subscriber.peek().unwrap() {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.origin(), eq view.header().publisher_id());
+            assert_that!(sample.header().sequence_number(), eq view.header().sequence_number());
+            number_of_samples += 1;
+        }
+        assert_that!(number_of_samples, eq 6);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn peek_skips_filtered_samples_and_does_not_borrow<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(1)
+            .user_header::<u8>()
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber()
+            .filter(|_, shard| *shard == 1)
+            .create()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        for (i, shard) in [1, 1, 0, 1].iter().enumerate() {
+            let mut sample = publisher.loan_uninit().unwrap();
+            *sample.user_header_mut() = *shard;
+            assert_that!(sample.write_payload(i as u64).send(), eq Ok(1));
+        }
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 0);
+
+        // the only borrow slot is in use, peeking still works
+        let view = subscriber.peek().unwrap().unwrap();
+        assert_that!(view.header().sequence_number(), eq sample.header().sequence_number() + 1);
+        assert_that!(subscriber.receive().err(), eq Some(SubscriberReceiveError::ExceedsMaxBorrowedSamples));
+
+        drop(sample);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1);
+        drop(sample);
+
+        let view = subscriber.peek().unwrap().unwrap();
+        assert_that!(*view.user_header(), eq 1);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 3);
+        assert_that!(sample.header().sequence_number(), eq view.header().sequence_number());
+        drop(sample);
+
+        assert_that!(subscriber.peek().unwrap(), is_none);
+        assert_that!(subscriber.number_of_lost_samples(publisher.id()), eq Some(0));
+    }
+
+    #[test]
+    fn round_robin_receive_does_not_starve_a_slow_publisher<Sut: Service>() {
+        const NUMBER_OF_ROUNDS: usize = 5;
+        const FAST_RATE: usize = 10;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(FAST_RATE)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(sut.subscriber().effective_receive_order(), eq ReceiveOrder::RoundRobin);
+        let fast_publisher = sut.publisher().create().unwrap();
+        let slow_publisher = sut.publisher().create().unwrap();
+
+        for _ in 0..NUMBER_OF_ROUNDS {
+            for i in 0..FAST_RATE {
+                assert_that!(fast_publisher.send_copy(i as u64), eq Ok(1));
+            }
+            assert_that!(slow_publisher.send_copy(1234), eq Ok(1));
+
+            let mut origins = vec![];
+            while let Some(sample) = subscriber.receive().unwrap() {
+                origins.push(sample.origin());
+            }
+            assert_that!(origins, len FAST_RATE + 1);
+
+            // the slow publisher is visited at the latest in the second receive of the cycle
+            let position = origins.iter().position(|id| *id == slow_publisher.id());
+            assert_that!(position, is_some);
+            assert_that!(position.unwrap(), le 1);
+        }
+    }
+
+    #[test]
+    fn receive_by_timestamp_returns_the_oldest_sample_of_all_publishers<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 6;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber()
+            .receive_order(ReceiveOrder::ByTimestamp)
+            .create()
+            .unwrap();
+        let publishers = [
+            sut.publisher().create().unwrap(),
+            sut.publisher().create().unwrap(),
+        ];
+
+        // the second publisher sends first, a round robin receive would start with the first
+        for i in 0..NUMBER_OF_SAMPLES {
+            let publisher = &publishers[(i as usize + 1) % 2];
+            assert_that!(publisher.send_copy(i), eq Ok(1));
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        let view = subscriber.peek().unwrap().unwrap();
+        assert_that!(view.header().publisher_id(), eq publishers[1].id());
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq i);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn waiting_receive_fails_when_notifications_are_not_enabled<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().has_notifications(), eq false);
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let _publisher = sut.publisher().create().unwrap();
+
+        assert_that!(subscriber.timed_receive(Duration::from_millis(1)).err(), eq Some(SubscriberWaitError::NotificationsNotEnabled));
+        assert_that!(subscriber.blocking_receive().err(), eq Some(SubscriberWaitError::NotificationsNotEnabled));
+    }
+
+    #[test]
+    fn open_fails_with_incompatible_notification_setting<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(false)
+            .open::<u64>();
+        assert_that!(incompatible_setting_names(sut2.err()), eq vec!["enable_notifications"]);
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2.unwrap().static_config().has_notifications(), eq true);
+    }
+
+    #[test]
+    fn timed_receive_returns_available_sample_immediately<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(8192), eq Ok(1));
+
+        let sample = subscriber.timed_receive(Duration::from_secs(10)).unwrap();
+        assert_that!(*sample.unwrap(), eq 8192);
+    }
+
+    #[test]
+    fn timed_receive_returns_none_after_timeout<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(25);
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let _publisher = sut.publisher().create().unwrap();
+
+        let start = std::time::Instant::now();
+        assert_that!(subscriber.timed_receive(TIMEOUT).unwrap(), is_none);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    }
+
+    #[test]
+    fn timed_receive_wakes_up_when_a_sample_is_sent<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = Sut::new(&service_name)
+                    .publish_subscribe()
+                    .open::<u64>()
+                    .unwrap();
+                let publisher = service.publisher().create().unwrap();
+                std::thread::sleep(Duration::from_millis(10));
+                assert_that!(publisher.send_copy(42), eq Ok(1));
+
+                // keep the publisher alive until the sample was received
+                while service.dynamic_config().number_of_subscribers() == 1 {
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+            });
+
+            // without a publisher the subscriber does not wait
+            while sut.dynamic_config().number_of_publishers() == 0 {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+
+            let sample = subscriber.timed_receive(Duration::from_secs(10)).unwrap();
+            assert_that!(*sample.unwrap(), eq 42);
+            drop(subscriber);
+        });
+    }
+
+    #[test]
+    fn blocking_receive_returns_none_when_last_publisher_is_dropped<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(subscriber.blocking_receive().unwrap(), is_none);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = Sut::new(&service_name)
+                    .publish_subscribe()
+                    .open::<u64>()
+                    .unwrap();
+                let publisher = service.publisher().create().unwrap();
+                std::thread::sleep(Duration::from_millis(10));
+                drop(publisher);
+            });
+
+            while sut.dynamic_config().number_of_publishers() == 0 {
+                std::thread::yield_now();
+            }
+            assert_that!(subscriber.blocking_receive().unwrap(), is_none);
+        });
+    }
+
+    #[test]
+    fn blocking_receive_returns_none_when_last_publisher_is_dropped_before_it_connected<
+        Sut: Service,
+    >() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+        let barrier = std::sync::Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = Sut::new(&service_name)
+                    .publish_subscribe()
+                    .open::<u64>()
+                    .unwrap();
+                let publisher = service.publisher().create().unwrap();
+                barrier.wait();
+                // the publisher never updates its connections, the subscriber is unknown to it
+                std::thread::sleep(Duration::from_millis(10));
+                drop(publisher);
+            });
+
+            barrier.wait();
+            let subscriber = sut.subscriber().create().unwrap();
+            assert_that!(subscriber.blocking_receive().unwrap(), is_none);
+        });
+    }
+
+    #[test]
+    fn waiting_loan_fails_when_notifications_are_not_enabled<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+
+        assert_that!(publisher.timed_loan_uninit(Duration::from_millis(1)).err(), eq Some(PublisherWaitError::NotificationsNotEnabled));
+        assert_that!(publisher.blocking_loan_uninit().err(), eq Some(PublisherWaitError::NotificationsNotEnabled));
+    }
+
+    #[test]
+    fn timed_loan_uninit_returns_exceeded_loans_without_waiting<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_secs(10);
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().max_loaned_samples(1).create().unwrap();
+        let sample = publisher.timed_loan_uninit(TIMEOUT).unwrap();
+        assert_that!(sample, is_some);
+
+        let start = std::time::Instant::now();
+        assert_that!(publisher.timed_loan_uninit(TIMEOUT).err(), eq Some(PublisherWaitError::LoanError(PublisherLoanError::ExceedsMaxLoanedSamples)));
+        assert_that!(start.elapsed(), lt TIMEOUT);
+    }
+
+    #[test]
+    fn timed_loan_uninit_wakes_up_when_a_subscriber_releases_a_sample<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_secs(10);
+        let service_name = generate_name();
+        // the data segment of the publisher holds 1 * (2 + 1) + 0 + 1 + 1 = 5 samples
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .max_subscribers(1)
+            .subscriber_max_buffer_size(2)
+            .subscriber_max_borrowed_samples(1)
+            .history_size(0)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().max_loaned_samples(1).create().unwrap();
+
+        // the samples in the buffer of a subscriber that leaves are not returned to the
+        // publisher
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(publisher.send_copy(0), eq Ok(1));
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+        drop(subscriber);
+
+        let connected_handle = BarrierHandle::new();
+        let holding_handle = BarrierHandle::new();
+        let end_handle = BarrierHandle::new();
+        let connected_barrier = BarrierBuilder::new(2).create(&connected_handle).unwrap();
+        let holding_barrier = BarrierBuilder::new(2).create(&holding_handle).unwrap();
+        let end_barrier = BarrierBuilder::new(2).create(&end_handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = Sut::new(&service_name)
+                    .publish_subscribe()
+                    .open::<u64>()
+                    .unwrap();
+                let subscriber = service.subscriber().create().unwrap();
+                connected_barrier.wait();
+
+                let sample = subscriber.timed_receive(TIMEOUT).unwrap().unwrap();
+                assert_that!(*sample, eq 2);
+                holding_barrier.wait();
+
+                std::thread::sleep(Duration::from_millis(50));
+                drop(sample);
+                // keep the subscriber alive until the publisher loaned the released sample
+                end_barrier.wait();
+            });
+
+            connected_barrier.wait();
+            assert_that!(publisher.send_copy(2), eq Ok(1));
+            assert_that!(publisher.send_copy(3), eq Ok(1));
+            holding_barrier.wait();
+            assert_that!(publisher.send_copy(4), eq Ok(1));
+
+            // the subscriber holds one sample and has two in its buffer, no sample is left
+            assert_that!(publisher.loan_uninit().err(), eq Some(PublisherLoanError::OutOfMemory));
+
+            let start = std::time::Instant::now();
+            let sample = publisher.timed_loan_uninit(TIMEOUT).unwrap();
+            assert_that!(sample, is_some);
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            drop(sample);
+            end_barrier.wait();
+        });
+    }
+
+    #[test]
+    fn flush_fails_when_notifications_are_not_enabled<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(false)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        assert_that!(publisher.flush(Duration::from_millis(1)).err(), eq Some(PublisherWaitError::NotificationsNotEnabled));
+    }
+
+    #[test]
+    fn flush_returns_the_samples_that_were_not_received_within_the_timeout<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 3;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .history_size(0)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.flush(Duration::from_millis(1)), eq Ok(0));
+
+        let subscriber = sut.subscriber().create().unwrap();
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(n as u64), eq Ok(1));
+        }
+
+        assert_that!(publisher.pending_samples_per_subscriber(), eq vec![(subscriber.id(), NUMBER_OF_SAMPLES)]);
+        assert_that!(publisher.flush(Duration::from_millis(1)), eq Ok(NUMBER_OF_SAMPLES));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(publisher.pending_samples_per_subscriber(), eq vec![(subscriber.id(), NUMBER_OF_SAMPLES - 1)]);
+
+        // a subscriber that left is not waited for
+        drop(sample);
+        drop(subscriber);
+        assert_that!(publisher.pending_samples_per_subscriber(), is_empty);
+        assert_that!(publisher.flush(Duration::from_millis(1)), eq Ok(0));
+    }
+
+    #[test]
+    fn flush_waits_until_a_slow_subscriber_received_all_samples<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_secs(10);
+        const NUMBER_OF_SAMPLES: usize = 5;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .history_size(0)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        let connected_handle = BarrierHandle::new();
+        let sent_handle = BarrierHandle::new();
+        let connected_barrier = BarrierBuilder::new(2).create(&connected_handle).unwrap();
+        let sent_barrier = BarrierBuilder::new(2).create(&sent_handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = Sut::new(&service_name)
+                    .publish_subscribe()
+                    .open::<u64>()
+                    .unwrap();
+                let subscriber = service.subscriber().create().unwrap();
+                connected_barrier.wait();
+                sent_barrier.wait();
+
+                for n in 0..NUMBER_OF_SAMPLES {
+                    std::thread::sleep(Duration::from_millis(10));
+                    assert_that!(*subscriber.receive().unwrap().unwrap(), eq n as u64);
+                }
+            });
+
+            connected_barrier.wait();
+            for n in 0..NUMBER_OF_SAMPLES {
+                assert_that!(publisher.send_copy(n as u64), eq Ok(1));
+            }
+            sent_barrier.wait();
+
+            let start = std::time::Instant::now();
+            assert_that!(publisher.flush(TIMEOUT), eq Ok(0));
+            assert_that!(start.elapsed(), lt TIMEOUT);
+        });
+    }
+
+    #[test]
+    fn wait_consumed_requires_consumption_tracking_and_notifications<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let token = publisher
+            .loan_uninit()
+            .unwrap()
+            .write_payload(1)
+            .send_with_token()
+            .unwrap();
+        assert_that!(publisher.wait_consumed(token, Duration::from_millis(1)).err(), eq Some(PublisherWaitError::ConsumptionTrackingNotEnabled));
+        assert_that!(publisher.consumed_up_to(), is_none);
+
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(false)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut
+            .publisher()
+            .enable_consumption_tracking(true)
+            .create()
+            .unwrap();
+        let token = publisher
+            .loan_uninit()
+            .unwrap()
+            .write_payload(1)
+            .send_with_token()
+            .unwrap();
+        assert_that!(publisher.wait_consumed(token, Duration::from_millis(1)).err(), eq Some(PublisherWaitError::NotificationsNotEnabled));
+    }
+
+    #[test]
+    fn consumed_up_to_reports_the_samples_released_by_all_subscribers<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 3;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .subscriber_max_borrowed_samples(NUMBER_OF_SAMPLES)
+            .history_size(0)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut
+            .publisher()
+            .enable_consumption_tracking(true)
+            .create()
+            .unwrap();
+        let subscriber_a = sut.subscriber().create().unwrap();
+        let subscriber_b = sut.subscriber().create().unwrap();
+        assert_that!(publisher.consumed_up_to(), is_none);
+
+        let mut tokens = vec![];
+        for n in 0..NUMBER_OF_SAMPLES {
+            let token = publisher
+                .loan_uninit()
+                .unwrap()
+                .write_payload(n as u64)
+                .send_with_token()
+                .unwrap();
+            assert_that!(token.sequence_number(), eq n as u64);
+            tokens.push(token);
+        }
+        // a cancelled loan is never sent and does not block the progress
+        publisher.loan_uninit().unwrap().cancel();
+
+        let mut samples_a = vec![];
+        let mut samples_b = vec![];
+        for _ in 0..NUMBER_OF_SAMPLES {
+            samples_a.push(subscriber_a.receive().unwrap().unwrap());
+            samples_b.push(subscriber_b.receive().unwrap().unwrap());
+        }
+
+        // the second sample is released by all subscribers but the first one is still held
+        drop(samples_a.remove(1));
+        drop(samples_b.remove(1));
+        assert_that!(publisher.consumed_up_to(), is_none);
+
+        // a sample is only consumed when the last subscriber released it
+        drop(samples_a.remove(0));
+        assert_that!(publisher.consumed_up_to(), is_none);
+        drop(samples_b.remove(0));
+        assert_that!(publisher.consumed_up_to(), eq Some(tokens[1].sequence_number()));
+
+        drop(samples_a);
+        drop(samples_b);
+        assert_that!(publisher.consumed_up_to(), eq Some(tokens[2].sequence_number()));
+    }
+
+    #[test]
+    fn leaked_samples_are_returned_and_reported_when_the_subscriber_is_dropped<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .history_size(0)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut
+            .publisher()
+            .enable_consumption_tracking(true)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let token = publisher
+            .loan_uninit()
+            .unwrap()
+            .write_payload(1234)
+            .send_with_token()
+            .unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1234);
+        core::mem::forget(sample);
+        assert_that!(publisher.consumed_up_to(), is_none);
+
+        // debug builds panic with the number of leaked samples after their chunks were returned
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(subscriber)));
+        assert_that!(result.is_err(), eq cfg!(debug_assertions));
+        assert_that!(publisher.consumed_up_to(), eq Some(token.sequence_number()));
+    }
+
+    #[test]
+    fn consumed_up_to_includes_the_history_of_the_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .history_size(1)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut
+            .publisher()
+            .enable_consumption_tracking(true)
+            .create()
+            .unwrap();
+
+        // the history holds the last sample until the next one replaces it
+        let token = publisher
+            .loan_uninit()
+            .unwrap()
+            .write_payload(1)
+            .send_with_token()
+            .unwrap();
+        assert_that!(publisher.consumed_up_to(), is_none);
+        publisher.send_copy(2).unwrap();
+        assert_that!(publisher.consumed_up_to(), eq Some(token.sequence_number()));
+    }
+
+    #[test]
+    fn wait_consumed_waits_until_a_subscriber_released_the_sample<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_secs(10);
+        const HOLD_TIME: Duration = Duration::from_millis(100);
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .history_size(0)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut
+            .publisher()
+            .enable_consumption_tracking(true)
+            .create()
+            .unwrap();
+
+        let connected_handle = BarrierHandle::new();
+        let received_handle = BarrierHandle::new();
+        let connected_barrier = BarrierBuilder::new(2).create(&connected_handle).unwrap();
+        let received_barrier = BarrierBuilder::new(2).create(&received_handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = Sut::new(&service_name)
+                    .publish_subscribe()
+                    .open::<u64>()
+                    .unwrap();
+                let subscriber = service.subscriber().create().unwrap();
+                connected_barrier.wait();
+
+                let sample = subscriber.timed_receive(TIMEOUT).unwrap().unwrap();
+                assert_that!(*sample, eq 1234);
+                received_barrier.wait();
+
+                std::thread::sleep(HOLD_TIME);
+                drop(sample);
+            });
+
+            connected_barrier.wait();
+            let token = publisher
+                .loan_uninit()
+                .unwrap()
+                .write_payload(1234)
+                .send_with_token()
+                .unwrap();
+            received_barrier.wait();
+
+            let start = std::time::Instant::now();
+            assert_that!(publisher.wait_consumed(token, Duration::from_millis(1)), eq Ok(false));
+            assert_that!(publisher.wait_consumed(token, TIMEOUT), eq Ok(true));
+            assert_that!(start.elapsed(), ge HOLD_TIME / 2);
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            assert_that!(publisher.consumed_up_to(), eq Some(token.sequence_number()));
+        });
+    }
+
+    #[test]
+    fn publisher_statistics_count_sent_and_loaned_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.statistics().sent_samples(), eq 0);
+        assert_that!(publisher.statistics().loaned_samples(), eq 0);
+        assert_that!(publisher.statistics().active_connections(), eq 0);
+
+        let _subscriber = sut.subscriber().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(publisher.statistics().active_connections(), eq 1);
+
+        let sample = publisher.loan_uninit().unwrap();
+        assert_that!(publisher.statistics().loaned_samples(), eq 1);
+        assert_that!(sample.write_payload(1).send(), eq Ok(1));
+        assert_that!(publisher.statistics().loaned_samples(), eq 0);
+        assert_that!(publisher.statistics().sent_samples(), eq 1);
+
+        assert_that!(publisher.send_copy(2), eq Ok(1));
+        assert_that!(publisher.statistics().sent_samples(), eq 2);
+
+        drop(publisher);
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.statistics().sent_samples(), eq 0);
+    }
+
+    #[test]
+    fn publisher_statistics_count_cancelled_and_dropped_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let _subscriber = sut.subscriber().create().unwrap();
+        assert_that!(publisher.statistics().cancelled_samples(), eq 0);
+
+        let sample = publisher.loan_uninit().unwrap().write_payload(1);
+        assert_that!(sample.send(), eq Ok(1));
+        assert_that!(publisher.send_copy(2), eq Ok(1));
+        assert_that!(publisher.statistics().cancelled_samples(), eq 0);
+
+        publisher.loan_uninit().unwrap().write_payload(3).cancel();
+        assert_that!(publisher.statistics().cancelled_samples(), eq 1);
+        assert_that!(publisher.statistics().loaned_samples(), eq 0);
+
+        let sample = publisher.loan_uninit().unwrap();
+        drop(sample);
+        assert_that!(publisher.statistics().cancelled_samples(), eq 2);
+        assert_that!(publisher.statistics().loaned_samples(), eq 0);
+        assert_that!(publisher.statistics().sent_samples(), eq 2);
+    }
+
+    #[test]
+    fn subscriber_statistics_count_pending_received_and_borrowed_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(4)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+        assert_that!(publisher.send_copy(2), eq Ok(1));
+
+        assert_that!(subscriber.statistics().pending_samples(), eq 2);
+        assert_that!(subscriber.statistics().received_samples(), eq 0);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(subscriber.statistics().pending_samples(), eq 1);
+        assert_that!(subscriber.statistics().received_samples(), eq 1);
+        assert_that!(subscriber.statistics().borrowed_samples(), eq 1);
+
+        drop(sample);
+        assert_that!(subscriber.statistics().borrowed_samples(), eq 0);
+
+        assert_that!(subscriber.receive().unwrap(), is_some);
+        assert_that!(subscriber.statistics().pending_samples(), eq 0);
+        assert_that!(subscriber.statistics().received_samples(), eq 2);
+    }
+
+    #[test]
+    fn dynamic_info_visits_all_attached_ports<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(4)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().buffer_size(3).create().unwrap();
+
+        let mut ports = vec![];
+        sut.dynamic_info(|port| {
+            ports.push(*port);
+            CallbackProgression::Continue
+        });
+        assert_that!(ports, len 2);
+
+        let publisher_details = ports.iter().find(|p| p.is_publisher()).unwrap();
+        assert_that!(publisher_details.id(), eq PortId::Publisher(publisher.id()));
+        assert_that!(publisher_details.buffer_size(), is_none);
+        assert_that!(publisher_details.pid(), eq Process::from_self().id());
+        assert_that!(publisher_details.is_stale(), eq false);
+
+        let subscriber_details = ports.iter().find(|p| p.is_subscriber()).unwrap();
+        assert_that!(subscriber_details.id(), eq PortId::Subscriber(subscriber.id()));
+        assert_that!(subscriber_details.buffer_size(), eq Some(3));
+        assert_that!(subscriber_details.pid(), eq Process::from_self().id());
+        assert_that!(subscriber_details.is_stale(), eq false);
+
+        let mut number_of_visited_ports = 0;
+        sut.dynamic_info(|_| {
+            number_of_visited_ports += 1;
+            CallbackProgression::Stop
+        });
+        assert_that!(number_of_visited_ports, eq 1);
+
+        drop(subscriber);
+        let mut ports = vec![];
+        sut.dynamic_info(|port| {
+            ports.push(port.id());
+            CallbackProgression::Continue
+        });
+        assert_that!(ports, eq vec![PortId::Publisher(publisher.id())]);
+    }
+
+    #[test]
+    fn payload_is_placed_with_custom_alignment<Sut: Service>() {
+        const ALIGNMENT: usize = 64;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .subscriber_max_buffer_size(4)
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().payload_alignment(), eq ALIGNMENT);
+
+        let publisher = sut.publisher().max_loaned_samples(4).create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let samples = (0..4)
+            .map(|_| publisher.loan_uninit().unwrap())
+            .collect::<Vec<_>>();
+        for (n, sample) in samples.into_iter().enumerate() {
+            assert_that!(sample.payload() as *const _ as usize % ALIGNMENT, eq 0);
+            assert_that!(sample.write_payload(n as u64).send(), eq Ok(1));
+        }
+
+        for n in 0..4 {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload() as *const _ as usize % ALIGNMENT, eq 0);
+            assert_that!(*sample, eq n);
+        }
+    }
+
+    #[test]
+    fn slice_payload_is_placed_with_custom_alignment<Sut: Service>() {
+        const ALIGNMENT: usize = 128;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .max_slice_len(5)
+            .create::<[u8]>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(5).unwrap();
+        assert_that!(sample.payload().as_ptr() as usize % ALIGNMENT, eq 0);
+        assert_that!(sample.write_from_slice(&[1, 2, 3, 4, 5]).send(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload().as_ptr() as usize % ALIGNMENT, eq 0);
+        assert_that!(*sample, eq [1u8, 2, 3, 4, 5]);
     }
 
     #[test]
-    fn publisher_never_goes_out_of_memory_with_huge_values<Sut: Service>() {
-        const BUFFER_SIZE: usize = 29;
-        const HISTORY_SIZE: usize = 31;
-        const MAX_BORROW: usize = 12;
-        const MAX_SUBSCRIBERS: usize = 25;
-        const MAX_LOAN: usize = 35;
+    fn create_fails_when_payload_alignment_is_smaller_than_type_alignment<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .payload_alignment(Alignment::new(2).unwrap())
+            .create::<u64>();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeCreateError::InvalidPayloadAlignment);
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
+        let sut = Sut::new(&service_name).publish_subscribe().create::<u64>();
+        assert_that!(sut.unwrap().static_config().payload_alignment(), eq std::mem::align_of::<u64>());
     }
 
     #[test]
-    fn creating_max_supported_amount_of_ports_work<Sut: Service>() {
-        const MAX_PUBLISHERS: usize = 4;
-        const MAX_SUBSCRIBERS: usize = 8;
+    fn open_fails_when_service_does_not_support_requested_payload_alignment<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .payload_alignment(Alignment::new(32).unwrap())
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .payload_alignment(Alignment::new(64).unwrap())
+            .open::<u64>();
+        assert_that!(incompatible_setting_names(sut2.err()), eq vec!["payload_alignment"]);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .payload_alignment(Alignment::new(16).unwrap())
+            .open::<u64>();
+        assert_that!(sut2.unwrap().static_config().payload_alignment(), eq 32);
+    }
 
+    #[test]
+    fn forgotten_payload_reuses_the_loaned_chunk<Sut: Service>() {
         let service_name = generate_name();
         let sut = Sut::new(&service_name)
             .publish_subscribe()
-            .max_publishers(MAX_PUBLISHERS)
-            .max_subscribers(MAX_SUBSCRIBERS)
             .create::<u64>()
             .unwrap();
 
-        let mut publishers = vec![];
-        let mut subscribers = vec![];
+        let publisher = sut.publisher().max_loaned_samples(1).create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
 
-        // acquire all possible ports
-        for _ in 0..MAX_PUBLISHERS {
-            let publisher = sut.publisher().create();
-            assert_that!(publisher, is_ok);
-            publishers.push(publisher);
-        }
+        let sample = publisher.loan_uninit().unwrap().write_payload(12);
+        let payload_address = sample.payload() as *const u64 as usize;
 
-        for _ in 0..MAX_SUBSCRIBERS {
-            let subscriber = sut.subscriber().create();
-            assert_that!(subscriber, is_ok);
-            subscribers.push(subscriber);
-        }
+        let sample = sample.forget_payload();
+        assert_that!(sample.payload().as_ptr() as usize, eq payload_address);
+        assert_that!(publisher.loan_uninit().err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
 
-        // create additional ports and fail
-        let publisher = sut.publisher().create();
-        assert_that!(publisher, is_err);
-        assert_that!(
-            publisher.err().unwrap(), eq
-            PublisherCreateError::ExceedsMaxSupportedPublishers
-        );
+        let sample = sample.write_payload(34);
+        assert_that!(sample.payload() as *const u64 as usize, eq payload_address);
+        assert_that!(sample.send(), eq Ok(1));
 
-        let subscriber = sut.subscriber().create();
-        assert_that!(subscriber, is_err);
-        assert_that!(
-            subscriber.err().unwrap(), eq
-            SubscriberCreateError::ExceedsMaxSupportedSubscribers
-        );
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 34);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
 
-        // remove a publisher and subscriber
-        assert_that!(publishers.remove(0), is_ok);
-        assert_that!(subscribers.remove(0), is_ok);
+    #[test]
+    fn time_stamp_is_assigned_when_sample_is_sent<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
 
-        // create additional ports shall work again
-        let publisher = sut.publisher().create();
-        assert_that!(publisher, is_ok);
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
 
-        let subscriber = sut.subscriber().create();
-        assert_that!(subscriber, is_ok);
+        let sample = publisher.loan_uninit().unwrap().write_payload(1);
+        let loaned_at = sample.header().time_stamp().as_duration();
+        std::thread::sleep(Duration::from_millis(10));
+
+        let sample = sample.forget_payload().write_payload(2);
+        assert_that!(sample.send(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().time_stamp().as_duration(), ge loaned_at + Duration::from_millis(10));
     }
 
     #[test]
-    fn set_max_publishers_to_zero_adjusts_it_to_one<Sut: Service>() {
+    fn time_stamp_is_close_to_send_time_of_long_held_loan<Sut: Service>() {
+        const LOAN_DURATION: Duration = Duration::from_millis(50);
         let service_name = generate_name();
         let sut = Sut::new(&service_name)
             .publish_subscribe()
-            .max_publishers(0)
+            .clock_type(ClockType::Monotonic)
             .create::<u64>()
             .unwrap();
+        assert_that!(sut.static_config().clock_type(), eq ClockType::Monotonic);
 
-        assert_that!(sut.static_config().max_supported_publishers(), eq 1);
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let sample = publisher.loan_uninit().unwrap().write_payload(1);
+        let loaned_at = sample.header().time_stamp().as_duration();
+        std::thread::sleep(LOAN_DURATION);
+
+        let sent_at = Time::now_with_clock(PosixClockType::Monotonic)
+            .unwrap()
+            .as_duration();
+        assert_that!(sample.send(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().clock_type(), eq ClockType::Monotonic);
+        let time_stamp = sample.header().time_stamp();
+        assert_that!(time_stamp.clock_type(), eq PosixClockType::Monotonic);
+        assert_that!(time_stamp.as_duration(), ge sent_at);
+        assert_that!(time_stamp.as_duration(), ge loaned_at + LOAN_DURATION);
     }
 
     #[test]
-    fn set_max_subscribers_to_zero_adjusts_it_to_one<Sut: Service>() {
+    fn open_fails_with_incompatible_clock_type<Sut: Service>() {
         let service_name = generate_name();
-        let sut = Sut::new(&service_name)
+        let _sut = Sut::new(&service_name)
             .publish_subscribe()
-            .max_subscribers(0)
+            .clock_type(ClockType::Monotonic)
             .create::<u64>()
             .unwrap();
 
-        assert_that!(sut.static_config().max_supported_subscribers(), eq 1);
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .clock_type(ClockType::Realtime)
+            .open::<u64>();
+        assert_that!(incompatible_setting_names(sut2.err()), eq vec!["clock_type"]);
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2.unwrap().static_config().clock_type(), eq ClockType::Monotonic);
     }
 
     #[test]
-    fn set_subscriber_max_borrowed_samples_to_zero_adjusts_it_to_one<Sut: Service>() {
+    fn publisher_can_discard_samples_in_a_blocking_service<Sut: Service>() {
         let service_name = generate_name();
         let sut = Sut::new(&service_name)
             .publish_subscribe()
-            .subscriber_max_borrowed_samples(0)
+            .enable_safe_overflow(false)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .subscriber_max_buffer_size(1)
             .create::<u64>()
             .unwrap();
+        assert_that!(sut.static_config().unable_to_deliver_strategy(), eq UnableToDeliverStrategy::Block);
 
-        assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 1);
+        let blocking_publisher = sut.publisher().create().unwrap();
+        assert_that!(blocking_publisher.unable_to_deliver_strategy(), eq UnableToDeliverStrategy::Block);
+
+        let discarding_publisher = sut
+            .publisher()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        assert_that!(discarding_publisher.unable_to_deliver_strategy(), eq UnableToDeliverStrategy::DiscardSample);
+
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(discarding_publisher.send_copy(1), eq Ok(1));
+        // the buffer is full, the sample is discarded instead of blocking
+        assert_that!(discarding_publisher.send_copy(2), eq Ok(0));
+
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1);
+        assert_that!(subscriber.receive().unwrap(), is_none);
     }
 
     #[test]
-    fn set_buffer_size_to_zero_adjusts_it_to_one<Sut: Service>() {
+    fn blocking_publisher_cannot_be_created_in_a_discarding_service<Sut: Service>() {
         let service_name = generate_name();
         let sut = Sut::new(&service_name)
             .publish_subscribe()
-            .subscriber_max_buffer_size(0)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
             .create::<u64>()
             .unwrap();
 
-        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 1);
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.unable_to_deliver_strategy(), eq UnableToDeliverStrategy::DiscardSample);
+
+        let publisher = sut
+            .publisher()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create();
+        assert_that!(publisher.err(), eq Some(PublisherCreateError::IncompatibleUnableToDeliverStrategy));
     }
 
     #[test]
-    fn does_exist_works_single<Sut: Service + Details<'static>>() {
+    fn open_fails_with_incompatible_unable_to_deliver_strategy<Sut: Service>() {
         let service_name = generate_name();
-        assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
-
         let _sut = Sut::new(&service_name)
             .publish_subscribe()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
             .create::<u64>()
             .unwrap();
 
-        assert_that!(Sut::does_exist(&service_name).unwrap(), eq true);
-        assert_that!(Sut::does_exist(&service_name).unwrap(), eq true);
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .open::<u64>();
+        assert_that!(incompatible_setting_names(sut2.err()), eq vec!["unable_to_deliver_strategy"]);
 
-        drop(_sut);
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2.unwrap().static_config().unable_to_deliver_strategy(), eq UnableToDeliverStrategy::DiscardSample);
+    }
 
-        assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, ZeroCopySend)]
+    #[repr(C)]
+    struct GatewayPayload {
+        id: u32,
+        value: u64,
     }
 
     #[test]
-    fn does_exist_works_many<Sut: Service + Details<'static>>() {
-        const NUMBER_OF_SERVICES: usize = 8;
+    fn untyped_gateway_republishes_bytes_to_another_service<Sut: Service>() {
+        let source_name = generate_name();
+        let destination_name = generate_name();
 
-        let mut services = vec![];
-        let mut service_names = vec![];
+        let source = Sut::new(&source_name)
+            .publish_subscribe()
+            .create::<GatewayPayload>()
+            .unwrap();
+        let destination = Sut::new(&destination_name)
+            .publish_subscribe()
+            .create::<GatewayPayload>()
+            .unwrap();
 
-        for i in 0..NUMBER_OF_SERVICES {
-            let service_name = generate_name();
-            assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
+        let gateway_source = Sut::new(&source_name)
+            .publish_subscribe()
+            .open_untyped()
+            .unwrap();
+        let gateway_destination = Sut::new(&destination_name)
+            .publish_subscribe()
+            .open_untyped()
+            .unwrap();
+        let gateway_subscriber = gateway_source.subscriber().create_untyped().unwrap();
+        let gateway_publisher = gateway_destination.publisher().create_untyped().unwrap();
+        assert_that!(gateway_subscriber.payload_type_details().type_name(), eq std::any::type_name::<GatewayPayload>());
+        assert_that!(gateway_publisher.payload_type_details().size(), eq std::mem::size_of::<GatewayPayload>());
 
-            services.push(
-                Sut::new(&service_name)
-                    .publish_subscribe()
-                    .create::<u64>()
-                    .unwrap(),
-            );
-            service_names.push(service_name);
+        let publisher = source.publisher().create().unwrap();
+        let subscriber = destination.subscriber().create().unwrap();
 
-            for s in service_names.iter().take(i + 1) {
-                assert_that!(Sut::does_exist(s).unwrap(), eq true);
-            }
-        }
+        let payload = GatewayPayload {
+            id: 7,
+            value: 0xdeadbeef,
+        };
+        assert_that!(publisher.send_copy(payload), eq Ok(1));
 
-        for i in 0..NUMBER_OF_SERVICES {
-            for s in service_names.iter().take(NUMBER_OF_SERVICES - i) {
-                assert_that!(Sut::does_exist(s).unwrap(), eq true);
-            }
+        let bytes = gateway_subscriber.receive().unwrap().unwrap();
+        assert_that!(bytes.payload(), len std::mem::size_of::<GatewayPayload>());
 
-            for s in service_names
-                .iter()
-                .take(NUMBER_OF_SERVICES)
-                .skip(NUMBER_OF_SERVICES - i)
-            {
-                assert_that!(Sut::does_exist(s).unwrap(), eq false);
-            }
+        let sample = gateway_publisher.loan_untyped().unwrap();
+        assert_that!(sample.write_from_slice(bytes.payload()).send(), eq Ok(1));
 
-            services.pop();
-        }
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq payload);
     }
 
     #[test]
-    fn list_works<Sut: Service + Details<'static>>() {
-        const NUMBER_OF_SERVICES: usize = 8;
+    fn untyped_loans_must_be_a_multiple_of_the_type_size<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(4)
+            .create::<[u32]>()
+            .unwrap();
 
-        let mut services = vec![];
-        let mut service_names = vec![];
+        let publisher = sut.publisher().create_untyped().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
 
-        let contains_service_names = |names, state: Vec<StaticConfig>| {
-            for n in names {
-                let mut name_found = false;
-                for s in &state {
-                    if *s.service_name() == n {
-                        name_found = true;
-                        break;
-                    }
-                }
+        assert_that!(publisher.loan_slice_uninit(6).err(), eq Some(PublisherLoanError::PayloadSizeIsNotAMultipleOfTypeSize));
+        assert_that!(publisher.loan_slice_uninit(20).err(), eq Some(PublisherLoanError::ExceedsMaxLoanSize));
 
-                if !name_found {
-                    return false;
-                }
-            }
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&3u32.to_ne_bytes());
+        bytes.extend_from_slice(&5u32.to_ne_bytes());
+        let sample = publisher.loan_slice_uninit(bytes.len()).unwrap();
+        assert_that!(sample.write_from_slice(&bytes).send(), eq Ok(1));
 
-            true
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq [3u32, 5]);
+    }
+
+    #[test]
+    fn stored_subscriber_factory_creates_subscribers_with_independent_buffers<Sut: Service>() {
+        struct Holder<F> {
+            factory: F,
+        }
+
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(4)
+            .create::<u64>()
+            .unwrap();
+
+        let holder = Holder {
+            factory: sut.subscriber().buffer_size(2),
         };
+        let subscriber_a = holder.factory.create().unwrap();
+        let subscriber_b = holder.factory.clone().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
 
-        for i in 0..NUMBER_OF_SERVICES {
-            let service_name = generate_name();
+        assert_that!(publisher.send_copy(1), eq Ok(2));
+        assert_that!(publisher.send_copy(2), eq Ok(2));
 
-            services.push(
-                Sut::new(&service_name)
-                    .publish_subscribe()
-                    .create::<u64>()
-                    .unwrap(),
-            );
-            service_names.push(service_name);
+        assert_that!(*subscriber_a.receive().unwrap().unwrap(), eq 1);
+        assert_that!(*subscriber_a.receive().unwrap().unwrap(), eq 2);
+        assert_that!(subscriber_a.receive().unwrap(), is_none);
 
-            let service_list = Sut::list().unwrap();
-            assert_that!(service_list, len i + 1);
+        assert_that!(*subscriber_b.receive().unwrap().unwrap(), eq 1);
+        assert_that!(*subscriber_b.receive().unwrap().unwrap(), eq 2);
+        assert_that!(subscriber_b.receive().unwrap(), is_none);
+    }
 
-            assert_that!(contains_service_names(service_names.clone(), service_list), eq true);
-        }
+    #[test]
+    fn stored_publisher_factory_creates_multiple_publishers<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .create::<u64>()
+            .unwrap();
 
-        for i in 0..NUMBER_OF_SERVICES {
-            services.pop();
-            service_names.pop();
+        let factory = sut.publisher().max_loaned_samples(1);
+        let publisher_a = factory.create().unwrap();
+        let publisher_b = factory.create().unwrap();
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 2);
 
-            let service_list = Sut::list().unwrap();
-            assert_that!(service_list, len NUMBER_OF_SERVICES - i - 1);
-            assert_that!(contains_service_names(service_names.clone(), service_list), eq true);
-        }
+        let _sample_a = publisher_a.loan().unwrap();
+        let _sample_b = publisher_b.loan().unwrap();
+        assert_that!(publisher_a.loan().err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+        assert_that!(publisher_b.loan().err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+
+        assert_that!(factory.create().err(), eq Some(PublisherCreateError::ExceedsMaxSupportedPublishers));
+    }
+
+    #[test]
+    fn port_factories_report_effective_configuration<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(4)
+            .enable_safe_overflow(false)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.subscriber().effective_buffer_size(), eq 4);
+        assert_that!(sut.subscriber().buffer_size(2).effective_buffer_size(), eq 2);
+        assert_that!(sut.subscriber().buffer_size(10).effective_buffer_size(), eq 4);
+        assert_that!(sut.subscriber().buffer_size(0).effective_buffer_size(), eq 1);
+
+        let factory = sut.publisher();
+        assert_that!(factory.effective_unable_to_deliver_strategy(), eq UnableToDeliverStrategy::Block);
+        assert_that!(factory.effective_update_connections_on_send(), eq true);
+        assert_that!(factory.effective_allocation_strategy(), eq AllocationStrategy::Static);
+
+        let factory = factory
+            .max_loaned_samples(3)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .update_connections_on_send(false);
+        assert_that!(factory.effective_max_loaned_samples(), eq 3);
+        assert_that!(factory.effective_unable_to_deliver_strategy(), eq UnableToDeliverStrategy::DiscardSample);
+        assert_that!(factory.effective_update_connections_on_send(), eq false);
+
+        let subscriber = sut.subscriber().buffer_size(10).create().unwrap();
+        assert_that!(subscriber.buffer_size(), eq 4);
     }
 
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
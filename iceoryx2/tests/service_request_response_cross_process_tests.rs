@@ -0,0 +1,126 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// The server runs in a child process that is spawned by re-executing this test binary with a
+// single ignored test selected, the service name is handed over via an environment variable.
+mod service_request_response_cross_process {
+    use std::process::Command;
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const SERVICE_NAME_VARIABLE: &str = "IOX2_CROSS_PROCESS_REQUEST_RESPONSE_SERVICE_NAME";
+    const TIMEOUT: Duration = Duration::from_secs(10);
+    const NUMBER_OF_RESPONSES: u64 = 3;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_request_response_cross_process_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn service_name_of_parent() -> Option<ServiceName> {
+        std::env::var(SERVICE_NAME_VARIABLE)
+            .ok()
+            .map(|name| ServiceName::new(&name).unwrap())
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_streams_responses_to_a_single_request() {
+        let service_name = match service_name_of_parent() {
+            Some(name) => name,
+            None => return,
+        };
+
+        let sut = zero_copy::Service::new(&service_name)
+            .request_response::<u64, u64>()
+            .open()
+            .unwrap();
+        let server = sut.server().create().unwrap();
+
+        let start = Instant::now();
+        let active_request = loop {
+            if let Some(active_request) = server.receive().unwrap() {
+                break active_request;
+            }
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            std::thread::sleep(Duration::from_millis(10));
+        };
+
+        for i in 0..NUMBER_OF_RESPONSES {
+            let response = active_request.loan_uninit().unwrap();
+            assert_that!(
+                response.write_payload(*active_request * 10 + i).send(),
+                is_ok
+            );
+        }
+
+        // the responses live in the data segment of the server, it has to stay until the client
+        // has consumed them and dropped its pending response
+        while active_request.is_connected() {
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn client_receives_responses_streamed_by_server_of_child_process() {
+        let service_name = generate_name();
+        let sut = zero_copy::Service::new(&service_name)
+            .request_response::<u64, u64>()
+            .max_responses_per_request(NUMBER_OF_RESPONSES as usize)
+            .create()
+            .unwrap();
+        let client = sut.client().create().unwrap();
+
+        let mut child = Command::new(std::env::current_exe().unwrap())
+            .args([
+                "service_request_response_cross_process::child_streams_responses_to_a_single_request",
+                "--exact",
+                "--ignored",
+                "--nocapture",
+            ])
+            .env(SERVICE_NAME_VARIABLE, service_name.as_str())
+            .spawn()
+            .unwrap();
+
+        let start = Instant::now();
+        while sut.dynamic_config().number_of_servers() == 0 {
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        let pending_response = client.send_copy(7).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 1);
+
+        let mut received_responses = vec![];
+        while received_responses.len() < NUMBER_OF_RESPONSES as usize {
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            match pending_response.receive().unwrap() {
+                Some(response) => {
+                    assert_that!(response.header().request_id(), eq pending_response.request_id());
+                    received_responses.push(*response);
+                }
+                None => std::thread::sleep(Duration::from_millis(10)),
+            }
+        }
+        assert_that!(received_responses, eq vec![70, 71, 72]);
+
+        drop(pending_response);
+        assert_that!(child.wait().unwrap().success(), eq true);
+    }
+}
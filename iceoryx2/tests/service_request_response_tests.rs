@@ -0,0 +1,251 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_request_response {
+    use iceoryx2::port::client::ClientLoanError;
+    use iceoryx2::port::server::ResponseSendError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::request_response::{
+        RequestResponseCreateError, RequestResponseOpenError,
+    };
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_request_response_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .create();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseCreateError::AlreadyExists);
+    }
+
+    #[test]
+    fn open_fails_with_incompatible_payload_types<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .request_response::<u64, u32>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::IncompatibleTypes);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response::<i64, u64>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn open_fails_when_requested_limits_are_not_supported<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(2)
+            .max_responses_per_request(3)
+            .create()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(3)
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequests);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .max_responses_per_request(4)
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfResponsesPerRequest);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(1)
+            .max_responses_per_request(1)
+            .open()
+            .unwrap();
+        assert_that!(sut2.static_config().max_active_requests(), eq 2);
+        assert_that!(sut2.static_config().max_responses_per_request(), eq 3);
+    }
+
+    #[test]
+    fn responses_are_delivered_to_the_pending_response_of_their_request<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let client = sut.client().create().unwrap();
+        let server = sut.server().create().unwrap();
+
+        let first_pending_response = client.send_copy(10).unwrap();
+        let second_pending_response = client.send_copy(20).unwrap();
+        assert_that!(first_pending_response.number_of_server_connections(), eq 1);
+        assert_that!(first_pending_response.request_id(), ne second_pending_response.request_id());
+
+        while let Some(active_request) = server.receive().unwrap() {
+            assert_that!(active_request.client_id(), eq client.id());
+            assert_that!(active_request.send_copy(*active_request + 1), is_ok);
+            assert_that!(active_request.send_copy(*active_request + 2), is_ok);
+        }
+
+        for pending_response in [&second_pending_response, &first_pending_response] {
+            for offset in 1..=2 {
+                let response = pending_response.receive().unwrap().unwrap();
+                assert_that!(response.header().server_id(), eq server.id());
+                assert_that!(response.header().request_id(), eq pending_response.request_id());
+                assert_that!(*response, eq pending_response.payload() + offset);
+            }
+            assert_that!(pending_response.receive().unwrap(), is_none);
+        }
+    }
+
+    #[test]
+    fn dropped_pending_response_stops_response_delivery<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let client = sut.client().create().unwrap();
+        let server = sut.server().create().unwrap();
+
+        let pending_response = client.send_copy(1).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.is_connected(), eq true);
+        assert_that!(active_request.send_copy(2), is_ok);
+
+        drop(pending_response);
+        assert_that!(client.number_of_active_requests(), eq 0);
+        assert_that!(active_request.is_connected(), eq false);
+        assert_that!(active_request.send_copy(3).err().unwrap(), eq ResponseSendError::RequestNoLongerActive);
+    }
+
+    #[test]
+    fn requests_of_dropped_pending_responses_are_not_received<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let client = sut.client().create().unwrap();
+        let server = sut.server().create().unwrap();
+
+        let pending_response = client.send_copy(1).unwrap();
+        drop(pending_response);
+
+        assert_that!(server.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn loan_fails_when_max_active_requests_are_exceeded<Sut: Service>() {
+        const MAX_ACTIVE_REQUESTS: usize = 3;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(MAX_ACTIVE_REQUESTS)
+            .create()
+            .unwrap();
+        let client = sut.client().create().unwrap();
+
+        let mut pending_responses = vec![];
+        for i in 0..MAX_ACTIVE_REQUESTS - 1 {
+            pending_responses.push(client.send_copy(i as u64).unwrap());
+        }
+        let request = client.loan_uninit().unwrap();
+
+        assert_that!(client.number_of_active_requests(), eq MAX_ACTIVE_REQUESTS);
+        assert_that!(client.loan_uninit().err().unwrap(), eq ClientLoanError::ExceedsMaxActiveRequests);
+
+        drop(request);
+        assert_that!(client.loan_uninit(), is_ok);
+
+        pending_responses.clear();
+        assert_that!(client.number_of_active_requests(), eq 0);
+    }
+
+    #[test]
+    fn send_fails_when_max_responses_per_request_are_exceeded<Sut: Service>() {
+        const MAX_RESPONSES_PER_REQUEST: usize = 2;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .max_responses_per_request(MAX_RESPONSES_PER_REQUEST)
+            .create()
+            .unwrap();
+        let client = sut.client().create().unwrap();
+        let server = sut.server().create().unwrap();
+
+        let _pending_response = client.send_copy(1).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        for i in 0..MAX_RESPONSES_PER_REQUEST {
+            assert_that!(active_request.send_copy(i as u64), is_ok);
+        }
+
+        assert_that!(active_request.number_of_responses(), eq MAX_RESPONSES_PER_REQUEST);
+        assert_that!(active_request.send_copy(0).err().unwrap(), eq ResponseSendError::ExceedsMaxResponsesPerRequest);
+    }
+
+    #[test]
+    fn request_is_delivered_to_every_server<Sut: Service>() {
+        const NUMBER_OF_SERVERS: usize = 2;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(NUMBER_OF_SERVERS)
+            .create()
+            .unwrap();
+        let client = sut.client().create().unwrap();
+        let servers = (0..NUMBER_OF_SERVERS)
+            .map(|_| sut.server().create().unwrap())
+            .collect::<Vec<_>>();
+
+        let pending_response = client.send_copy(5).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq NUMBER_OF_SERVERS);
+
+        for server in &servers {
+            let active_request = server.receive().unwrap().unwrap();
+            assert_that!(*active_request, eq 5);
+            assert_that!(active_request.send_copy(server.id().value() as u64), is_ok);
+        }
+
+        let mut received_server_ids = vec![];
+        while let Some(response) = pending_response.receive().unwrap() {
+            assert_that!(*response, eq response.header().server_id().value() as u64);
+            received_server_ids.push(response.header().server_id());
+        }
+        assert_that!(received_server_ids, len NUMBER_OF_SERVERS);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
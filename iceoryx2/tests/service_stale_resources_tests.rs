@@ -0,0 +1,211 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_stale_resources {
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::{Details, ServiceRemoveStaleResourcesError};
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn remove_stale_resources<'config, Sut: Service>(
+        config: &'config Config,
+    ) -> Result<usize, ServiceRemoveStaleResourcesError> {
+        <Sut::Type<'config> as Details<'config>>::remove_stale_resources_with_custom_config(config)
+    }
+
+    #[test]
+    fn remove_stale_resources_does_not_touch_publish_subscribe_service_of_live_process<
+        Sut: Service,
+    >() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(remove_stale_resources::<Sut>(&config), eq Ok(0));
+
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+    }
+
+    #[test]
+    fn remove_stale_resources_does_not_touch_event_service_of_live_process<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .event_with_custom_config(&config)
+            .create()
+            .unwrap();
+        let notifier = sut.notifier().create().unwrap();
+        let _listener = sut.listener().create().unwrap();
+
+        assert_that!(remove_stale_resources::<Sut>(&config), eq Ok(0));
+
+        assert_that!(sut.dynamic_config().number_of_notifiers(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_listeners(), eq 1);
+        assert_that!(notifier.notify(), eq Ok(1));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
+
+// The child process is spawned by re-executing this test binary with a single ignored test
+// selected, it holds a service with ports until it is killed.
+mod service_stale_resources_cross_process {
+    use std::process::Command;
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
+    use iceoryx2::service::{Details, DynamicDetails};
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::config::temp_directory;
+    use iceoryx2_bb_testing::assert_that;
+
+    const PREFIX_VARIABLE: &str = "IOX2_CROSS_PROCESS_STALE_RESOURCES_PREFIX";
+    const SERVICE_NAME: &str = "stale_resources_tests_crashed_service";
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn config_with_prefix(prefix: &str) -> Config {
+        let mut config = Config::default();
+        config.global.prefix = prefix.to_string();
+        config
+    }
+
+    fn number_of_connection_monitors(config: &Config) -> usize {
+        let temp_directory = temp_directory();
+        let temp_directory = std::str::from_utf8(temp_directory.as_bytes()).unwrap();
+        std::fs::read_dir(temp_directory)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                let file_name = entry.file_name();
+                let file_name = file_name.to_string_lossy();
+                file_name.starts_with(&config.global.prefix)
+                    && file_name.ends_with(&config.global.service.connection_monitor_event_suffix)
+            })
+            .count()
+    }
+
+    fn has_connected_publisher(config: &Config) -> bool {
+        zero_copy::Service::list_details_with_custom_config(config)
+            .unwrap()
+            .iter()
+            .any(|service| {
+                service.dynamic_details()
+                    == Some(DynamicDetails::PublishSubscribe {
+                        number_of_publishers: 1,
+                        number_of_subscribers: 1,
+                    })
+            })
+    }
+
+    #[test]
+    #[ignore = "is executed as child process by the cross process tests"]
+    fn child_holds_service_until_it_is_killed() {
+        let prefix = match std::env::var(PREFIX_VARIABLE) {
+            Ok(prefix) => prefix,
+            Err(_) => return,
+        };
+        let config = config_with_prefix(&prefix);
+
+        let sut = zero_copy::Service::new(&ServiceName::new(SERVICE_NAME).unwrap())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let _monitor = publisher.connection_monitor().unwrap();
+        assert_that!(publisher.send_copy(42), eq Ok(1));
+
+        loop {
+            std::thread::sleep(Duration::from_millis(100));
+            let _ = subscriber.receive();
+        }
+    }
+
+    #[test]
+    fn service_of_killed_process_can_be_recreated_after_removing_stale_resources() {
+        let config = generate_isolated_config();
+        let prefix = config.global.prefix.clone();
+        let service_name = ServiceName::new(SERVICE_NAME).unwrap();
+
+        let mut child = Command::new(std::env::current_exe().unwrap())
+            .args([
+                "service_stale_resources_cross_process::child_holds_service_until_it_is_killed",
+                "--exact",
+                "--ignored",
+                "--nocapture",
+            ])
+            .env(PREFIX_VARIABLE, &prefix)
+            .spawn()
+            .unwrap();
+
+        let start = Instant::now();
+        while !has_connected_publisher(&config) {
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        while number_of_connection_monitors(&config) == 0 {
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeCreateError::AlreadyExists);
+
+        assert_that!(zero_copy::Service::remove_stale_resources_with_custom_config(&config), eq Ok(1));
+        assert_that!(number_of_connection_monitors(&config), eq 0);
+        assert_that!(
+            zero_copy::Service::list_with_custom_config(&config).unwrap(),
+            is_empty
+        );
+
+        let sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+    }
+}
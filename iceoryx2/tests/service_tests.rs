@@ -13,6 +13,10 @@
 #[generic_tests::define]
 mod service {
     use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::messaging_pattern::MessagingPattern;
+    use iceoryx2::service::DynamicDetails;
+    use iceoryx2::testing::generate_isolated_config;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
 
@@ -46,13 +50,441 @@ mod service {
         let received_sample = sut_subscriber.receive().unwrap().unwrap();
         assert_that!(*received_sample, eq SAMPLE_VALUE);
 
-        const EVENT_ID: EventId = EventId::new(10123101301);
+        const EVENT_ID: EventId = EventId::new(10123);
         sut_notifier.notify_with_custom_event_id(EVENT_ID).unwrap();
         let received_event = sut_listener.try_wait().unwrap();
         assert_that!(received_event, len 1);
         assert_that!(received_event[0], eq EVENT_ID);
     }
 
+    #[test]
+    fn list_details_contains_services_of_all_messaging_patterns<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let sut_pub_sub = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .max_publishers(3)
+            .create::<u64>()
+            .unwrap();
+        let sut_slice = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .max_slice_len(32)
+            .create::<[u16]>()
+            .unwrap();
+        let sut_event = Sut::new(&generate_name())
+            .event_with_custom_config(&config)
+            .max_listeners(5)
+            .create()
+            .unwrap();
+
+        let _publisher = sut_pub_sub.publisher().create().unwrap();
+        let _subscriber_1 = sut_slice.subscriber().create().unwrap();
+        let _subscriber_2 = sut_slice.subscriber().create().unwrap();
+        let _notifier = sut_event.notifier().create().unwrap();
+
+        let details = <Sut::Type<'_>>::list_details_with_custom_config(&config).unwrap();
+        assert_that!(details, len 3);
+        let find = |uuid: &str| details.iter().find(|d| d.uuid() == uuid).unwrap();
+
+        let pub_sub_details = find(sut_pub_sub.uuid());
+        assert_that!(pub_sub_details.service_name(), eq sut_pub_sub.name());
+        match pub_sub_details.messaging_pattern() {
+            MessagingPattern::PublishSubscribe(config) => {
+                assert_that!(config.type_name(), eq std::any::type_name::<u64>());
+                assert_that!(config.type_size(), eq std::mem::size_of::<u64>());
+                assert_that!(config.type_alignment(), eq std::mem::align_of::<u64>());
+                assert_that!(config.payload_type_details().type_name(), eq std::any::type_name::<u64>());
+                assert_that!(config.user_header_type_details().type_name(), eq std::any::type_name::<()>());
+                assert_that!(config.user_header_type_details().size(), eq 0);
+                assert_that!(config.max_supported_publishers(), eq 3);
+            }
+            _ => panic!("publish subscribe service listed with wrong messaging pattern"),
+        }
+        assert_that!(pub_sub_details.dynamic_details(), eq Some(DynamicDetails::PublishSubscribe {
+            number_of_publishers: 1,
+            number_of_subscribers: 0
+        }));
+
+        let slice_details = find(sut_slice.uuid());
+        match slice_details.messaging_pattern() {
+            MessagingPattern::PublishSubscribe(config) => {
+                assert_that!(config.type_name(), eq std::any::type_name::<[u16]>());
+                assert_that!(config.type_size(), eq std::mem::size_of::<u16>());
+                assert_that!(config.max_slice_len(), eq 32);
+            }
+            _ => panic!("publish subscribe service listed with wrong messaging pattern"),
+        }
+        assert_that!(slice_details.dynamic_details(), eq Some(DynamicDetails::PublishSubscribe {
+            number_of_publishers: 0,
+            number_of_subscribers: 2
+        }));
+
+        let event_details = find(sut_event.uuid());
+        assert_that!(event_details.service_name(), eq sut_event.name());
+        match event_details.messaging_pattern() {
+            MessagingPattern::Event(config) => {
+                assert_that!(config.max_supported_listeners(), eq 5);
+            }
+            _ => panic!("event service listed with wrong messaging pattern"),
+        }
+        assert_that!(event_details.dynamic_details(), eq Some(DynamicDetails::Event {
+            number_of_notifiers: 1,
+            number_of_listeners: 0
+        }));
+    }
+
+    #[test]
+    fn list_details_contains_port_statistics<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+        assert_that!(publisher.send_copy(2), eq Ok(1));
+        let _sample = subscriber.receive().unwrap().unwrap();
+
+        let details = <Sut::Type<'_>>::list_details_with_custom_config(&config).unwrap();
+        assert_that!(details, len 1);
+
+        let publisher_statistics = details[0].publisher_statistics();
+        assert_that!(publisher_statistics, len 1);
+        assert_that!(publisher_statistics[0].0, eq publisher.id());
+        assert_that!(publisher_statistics[0].1, eq publisher.statistics());
+        assert_that!(publisher_statistics[0].1.sent_samples(), eq 2);
+
+        let subscriber_statistics = details[0].subscriber_statistics();
+        assert_that!(subscriber_statistics, len 1);
+        assert_that!(subscriber_statistics[0].0, eq subscriber.id());
+        assert_that!(subscriber_statistics[0].1.pending_samples(), eq 1);
+        assert_that!(subscriber_statistics[0].1.borrowed_samples(), eq 1);
+    }
+
+    #[test]
+    fn opened_event_and_request_response_services_have_the_instance_uuid_of_the_creator<
+        Sut: Service + Details<'static>,
+    >() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+
+        let event = Sut::new(&service_name)
+            .event_with_custom_config(&config)
+            .create()
+            .unwrap();
+        let opened_event = Sut::new(&service_name)
+            .event_with_custom_config(&config)
+            .open()
+            .unwrap();
+        assert_that!(opened_event.instance_uuid(), eq event.instance_uuid());
+
+        let request_response = Sut::new(&service_name)
+            .request_response_with_custom_config::<u64, u64>(&config)
+            .create()
+            .unwrap();
+        let opened_request_response = Sut::new(&service_name)
+            .request_response_with_custom_config::<u64, u64>(&config)
+            .open()
+            .unwrap();
+        assert_that!(opened_request_response.instance_uuid(), eq request_response.instance_uuid());
+    }
+
+    #[test]
+    fn recreated_service_has_new_instance_uuid<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let uuid = sut.uuid().to_string();
+        let instance_uuid = sut.instance_uuid().to_string();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(publisher.service_instance_uuid(), eq instance_uuid);
+        assert_that!(subscriber.service_instance_uuid(), eq instance_uuid);
+        assert_that!(format!("{:?}", publisher).contains(&instance_uuid), eq true);
+
+        let opened = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .open::<u64>()
+            .unwrap();
+        assert_that!(opened.instance_uuid(), eq instance_uuid);
+        let opened_subscriber = opened.subscriber().create().unwrap();
+        assert_that!(opened_subscriber.service_instance_uuid(), eq instance_uuid);
+
+        let details = <Sut::Type<'_>>::list_details_with_custom_config(&config).unwrap();
+        assert_that!(details, len 1);
+        assert_that!(details[0].instance_uuid(), eq instance_uuid);
+
+        drop(opened_subscriber);
+        drop(opened);
+        drop(subscriber);
+        drop(publisher);
+        drop(sut);
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.uuid(), eq uuid);
+        assert_that!(sut.instance_uuid(), ne instance_uuid);
+    }
+
+    #[test]
+    fn ports_can_be_created_with_a_long_prefix<Sut: Service>() {
+        // the event concept names are part of unix datagram socket paths which are limited to
+        // 108 characters on every platform, with the temp directory and the suffix they must
+        // still fit
+        let mut config = generate_isolated_config();
+        let mut prefix = format!("iox2_{:032x}", UniqueSystemId::new().unwrap().value());
+        prefix.push_str(&"_".repeat(56 - prefix.len()));
+        config.global.prefix = prefix;
+
+        let service_name = generate_name();
+        let sut_event = Sut::new(&service_name)
+            .event_with_custom_config(&config)
+            .create()
+            .unwrap();
+        let mut listener = sut_event.listener().create().unwrap();
+        let notifier = sut_event.notifier().create().unwrap();
+
+        assert_that!(notifier.notify(), eq Ok(1));
+        assert_that!(listener.try_wait().unwrap(), len 1);
+
+        let sut_pub_sub = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut_pub_sub.subscriber().create().unwrap();
+        let publisher = sut_pub_sub.publisher().create().unwrap();
+
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+    }
+
+    #[test]
+    fn services_with_same_name_and_different_prefix_are_isolated<
+        Sut: Service + Details<'static>,
+    >() {
+        let service_name = generate_name();
+        let config_a = generate_isolated_config();
+        let config_b = generate_isolated_config();
+
+        let sut_a = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config_a)
+            .create::<u64>()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config_b)
+            .open::<u64>();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+
+        let sut_b = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config_b)
+            .create::<u64>()
+            .unwrap();
+        let sut_event_a = Sut::new(&service_name)
+            .event_with_custom_config(&config_a)
+            .create()
+            .unwrap();
+        let sut_event_b = Sut::new(&service_name)
+            .event_with_custom_config(&config_b)
+            .create()
+            .unwrap();
+
+        let services_a = <Sut::Type<'_>>::list_with_custom_config(&config_a).unwrap();
+        assert_that!(services_a, len 2);
+        for service in &services_a {
+            assert_that!(service.uuid() == sut_a.uuid() || service.uuid() == sut_event_a.uuid(), eq true);
+        }
+
+        let publisher_a = sut_a.publisher().create().unwrap();
+        let subscriber_a = sut_a.subscriber().create().unwrap();
+        let subscriber_b = sut_b.subscriber().create().unwrap();
+        publisher_a.send_copy(1234).unwrap();
+        assert_that!(*subscriber_a.receive().unwrap().unwrap(), eq 1234);
+        assert_that!(subscriber_b.receive().unwrap(), is_none);
+
+        let mut listener_a = sut_event_a.listener().create().unwrap();
+        let mut listener_b = sut_event_b.listener().create().unwrap();
+        let notifier_a = sut_event_a.notifier().create().unwrap();
+        notifier_a
+            .notify_with_custom_event_id(EventId::new(12))
+            .unwrap();
+        assert_that!(listener_a.try_wait().unwrap(), len 1);
+        assert_that!(listener_b.try_wait().unwrap(), len 0);
+    }
+
+    #[test]
+    fn service_is_removed_with_its_last_instance_by_default<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let opened = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .open::<u64>()
+            .unwrap();
+
+        let services = <Sut::Type<'_>>::list_with_custom_config(&config).unwrap();
+        assert_that!(services, len 1);
+        assert_that!(services[0].persistence(), eq ServicePersistence::RemoveWhenLastPortDrops);
+
+        drop(sut);
+        assert_that!(<Sut::Type<'_>>::does_exist_with_custom_config(&service_name, &config), eq Ok(true));
+        drop(opened);
+        assert_that!(<Sut::Type<'_>>::does_exist_with_custom_config(&service_name, &config), eq Ok(false));
+    }
+
+    #[test]
+    fn persistent_service_outlives_its_last_instance<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .persistence(ServicePersistence::Persistent)
+            .create::<u64>()
+            .unwrap();
+        drop(sut);
+        assert_that!(<Sut::Type<'_>>::does_exist_with_custom_config(&service_name, &config), eq Ok(true));
+
+        for _ in 0..2 {
+            let sut = Sut::new(&service_name)
+                .publish_subscribe_with_custom_config(&config)
+                .open::<u64>()
+                .unwrap();
+            let publisher = sut.publisher().create().unwrap();
+            let subscriber = sut.subscriber().create().unwrap();
+            publisher.send_copy(1234).unwrap();
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+        }
+
+        let services = <Sut::Type<'_>>::list_with_custom_config(&config).unwrap();
+        assert_that!(services, len 1);
+        assert_that!(services[0].persistence(), eq ServicePersistence::Persistent);
+
+        assert_that!(
+            <Sut::Type<'_>>::remove_with_custom_config(&service_name, &config),
+            is_ok
+        );
+        assert_that!(<Sut::Type<'_>>::does_exist_with_custom_config(&service_name, &config), eq Ok(false));
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .open::<u64>();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn remove_fails_while_the_service_is_in_use<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .event_with_custom_config(&config)
+            .persistence(ServicePersistence::Persistent)
+            .create()
+            .unwrap();
+        let listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        let result = <Sut::Type<'_>>::remove_with_custom_config(&service_name, &config);
+        assert_that!(result, eq Err(ServiceRemoveError::InUse));
+
+        drop(notifier);
+        drop(listener);
+        let result = <Sut::Type<'_>>::remove_with_custom_config(&service_name, &config);
+        assert_that!(result, eq Err(ServiceRemoveError::InUse));
+
+        drop(sut);
+        assert_that!(
+            <Sut::Type<'_>>::remove_with_custom_config(&service_name, &config),
+            is_ok
+        );
+        let result = <Sut::Type<'_>>::remove_with_custom_config(&service_name, &config);
+        assert_that!(result, eq Err(ServiceRemoveError::DoesNotExist));
+    }
+
+    #[test]
+    fn remove_removes_the_services_of_all_messaging_patterns_with_the_name<
+        Sut: Service + Details<'static>,
+    >() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let other_service_name = generate_name();
+        let sut_pub_sub = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .persistence(ServicePersistence::Persistent)
+            .create::<u64>()
+            .unwrap();
+        let sut_event = Sut::new(&service_name)
+            .event_with_custom_config(&config)
+            .persistence(ServicePersistence::Persistent)
+            .create()
+            .unwrap();
+        let _other_sut = Sut::new(&other_service_name)
+            .event_with_custom_config(&config)
+            .create()
+            .unwrap();
+        drop(sut_pub_sub);
+        drop(sut_event);
+
+        assert_that!(
+            <Sut::Type<'_>>::remove_with_custom_config(&service_name, &config),
+            is_ok
+        );
+
+        let services = <Sut::Type<'_>>::list_with_custom_config(&config).unwrap();
+        assert_that!(services, len 1);
+        assert_that!(services[0].service_name(), eq & other_service_name);
+    }
+
+    #[test]
+    fn force_remove_removes_a_service_in_use<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .persistence(ServicePersistence::Persistent)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(
+            <Sut::Type<'_>>::force_remove_with_custom_config(&service_name, &config),
+            is_ok
+        );
+        assert_that!(<Sut::Type<'_>>::does_exist_with_custom_config(&service_name, &config), eq Ok(false));
+
+        let opened = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .open::<u64>();
+        assert_that!(opened.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+
+        let new_sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let new_publisher = new_sut.publisher().create().unwrap();
+        let new_subscriber = new_sut.subscriber().create().unwrap();
+
+        // the ports of the removed service do not interfere with the new one
+        drop(subscriber);
+        drop(publisher);
+        drop(sut);
+
+        new_publisher.send_copy(4567).unwrap();
+        assert_that!(*new_subscriber.receive().unwrap().unwrap(), eq 4567);
+        assert_that!(<Sut::Type<'_>>::does_exist_with_custom_config(&service_name, &config), eq Ok(true));
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
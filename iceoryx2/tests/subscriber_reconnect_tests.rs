@@ -0,0 +1,315 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// A restarted process is emulated by removing the service with force while the subscriber
+// still uses it and creating it again under the same name.
+#[generic_tests::define]
+mod subscriber_reconnect {
+    use iceoryx2::port::subscriber::{Subscriber, SubscriberServiceState};
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const BUFFER_SIZE: usize = 4;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "subscriber_reconnect_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    // the interval does not depend on the service type
+    fn wait_for_reconnect_check() {
+        std::thread::sleep(
+            Subscriber::<iceoryx2::service::zero_copy::Service, u64>::RECONNECT_CHECK_INTERVAL,
+        );
+    }
+
+    #[test]
+    fn subscriber_of_existing_service_is_healthy<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().auto_reconnect(true).create().unwrap();
+
+        wait_for_reconnect_check();
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(subscriber.service_state(), eq SubscriberServiceState::Healthy);
+        assert_that!(subscriber.number_of_reconnects(), eq 0);
+        assert_that!(subscriber.service_instance_uuid(), eq sut.instance_uuid());
+    }
+
+    #[test]
+    fn subscriber_of_opened_service_is_healthy<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let opened = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .open::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = opened.subscriber().auto_reconnect(true).create().unwrap();
+
+        wait_for_reconnect_check();
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(subscriber.service_state(), eq SubscriberServiceState::Healthy);
+        assert_that!(subscriber.number_of_reconnects(), eq 0);
+        assert_that!(subscriber.service_instance_uuid(), eq sut.instance_uuid());
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+    }
+
+    #[test]
+    fn subscriber_without_auto_reconnect_stays_with_the_removed_service<
+        Sut: Service + Details<'static>,
+    >() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(
+            <Sut::Type<'_>>::force_remove_with_custom_config(&service_name, &config),
+            is_ok
+        );
+        drop(publisher);
+        assert_that!(subscriber.service_state(), eq SubscriberServiceState::ServiceGone);
+
+        let new_sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let new_publisher = new_sut.publisher().create().unwrap();
+        assert_that!(new_publisher.send_copy(1234), eq Ok(0));
+
+        wait_for_reconnect_check();
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(subscriber.service_state(), eq SubscriberServiceState::ServiceGone);
+        assert_that!(subscriber.number_of_reconnects(), eq 0);
+    }
+
+    #[test]
+    fn subscriber_with_auto_reconnect_resumes_delivery_after_service_restart<
+        Sut: Service + Details<'static>,
+    >() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().auto_reconnect(true).create().unwrap();
+
+        for i in 0..3 {
+            assert_that!(publisher.send_copy(i), eq Ok(1));
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq i);
+        }
+
+        assert_that!(
+            <Sut::Type<'_>>::force_remove_with_custom_config(&service_name, &config),
+            is_ok
+        );
+        drop(publisher);
+
+        let new_sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create::<u64>()
+            .unwrap();
+        let new_publisher = new_sut.publisher().create().unwrap();
+        assert_that!(subscriber.service_state(), eq SubscriberServiceState::ServiceGone);
+
+        wait_for_reconnect_check();
+        assert_that!(subscriber.update_connections(), is_ok);
+        assert_that!(subscriber.number_of_reconnects(), eq 1);
+        assert_that!(subscriber.service_state(), eq SubscriberServiceState::Healthy);
+        assert_that!(subscriber.service_instance_uuid(), eq new_sut.instance_uuid());
+        assert_that!(new_sut.dynamic_config().number_of_subscribers(), eq 1);
+
+        for i in 0..3 {
+            assert_that!(new_publisher.send_copy(100 + i), eq Ok(1));
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq 100 + i);
+            // the publishers of the new instance start with the first sequence number again
+            assert_that!(sample.header().sequence_number(), eq i);
+        }
+        assert_that!(subscriber.number_of_lost_samples(new_publisher.id()), eq Some(0));
+    }
+
+    #[test]
+    fn subscriber_with_auto_reconnect_detects_losses_after_service_restart<
+        Sut: Service + Details<'static>,
+    >() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(true)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().auto_reconnect(true).create().unwrap();
+
+        assert_that!(
+            <Sut::Type<'_>>::force_remove_with_custom_config(&service_name, &config),
+            is_ok
+        );
+        let new_sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(true)
+            .create::<u64>()
+            .unwrap();
+        let new_publisher = new_sut.publisher().create().unwrap();
+
+        wait_for_reconnect_check();
+        assert_that!(subscriber.update_connections(), is_ok);
+        assert_that!(subscriber.number_of_reconnects(), eq 1);
+
+        assert_that!(new_publisher.send_copy(0), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 0);
+        // the second sample is overridden by the third one
+        assert_that!(new_publisher.send_copy(1), eq Ok(1));
+        assert_that!(new_publisher.send_copy(2), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 2);
+        assert_that!(subscriber.number_of_lost_samples(new_publisher.id()), eq Some(1));
+    }
+
+    #[test]
+    fn subscriber_with_auto_reconnect_waits_until_borrowed_samples_are_returned<
+        Sut: Service + Details<'static>,
+    >() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().auto_reconnect(true).create().unwrap();
+
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+
+        assert_that!(
+            <Sut::Type<'_>>::force_remove_with_custom_config(&service_name, &config),
+            is_ok
+        );
+        let new_sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let new_publisher = new_sut.publisher().create().unwrap();
+
+        wait_for_reconnect_check();
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(subscriber.number_of_reconnects(), eq 0);
+
+        drop(sample);
+        wait_for_reconnect_check();
+        assert_that!(subscriber.update_connections(), is_ok);
+        assert_that!(subscriber.number_of_reconnects(), eq 1);
+
+        assert_that!(new_publisher.send_copy(2), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 2);
+    }
+
+    #[test]
+    fn subscriber_with_auto_reconnect_ignores_a_service_with_another_type<
+        Sut: Service + Details<'static>,
+    >() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().auto_reconnect(true).create().unwrap();
+
+        assert_that!(
+            <Sut::Type<'_>>::force_remove_with_custom_config(&service_name, &config),
+            is_ok
+        );
+        let _new_sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u32>()
+            .unwrap();
+
+        wait_for_reconnect_check();
+        assert_that!(subscriber.update_connections(), is_ok);
+        assert_that!(subscriber.number_of_reconnects(), eq 0);
+        assert_that!(subscriber.service_state(), eq SubscriberServiceState::ServiceGone);
+    }
+
+    #[test]
+    fn subscriber_with_auto_reconnect_follows_multiple_restarts<Sut: Service + Details<'static>>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().auto_reconnect(true).create().unwrap();
+
+        let mut instances = vec![];
+        for restart in 1..=3 {
+            assert_that!(
+                <Sut::Type<'_>>::force_remove_with_custom_config(&service_name, &config),
+                is_ok
+            );
+            let new_sut = Sut::new(&service_name)
+                .publish_subscribe_with_custom_config(&config)
+                .create::<u64>()
+                .unwrap();
+            let new_publisher = new_sut.publisher().create().unwrap();
+
+            wait_for_reconnect_check();
+            assert_that!(subscriber.update_connections(), is_ok);
+            assert_that!(subscriber.number_of_reconnects(), eq restart);
+            assert_that!(new_sut.dynamic_config().number_of_subscribers(), eq 1);
+
+            assert_that!(new_publisher.send_copy(restart), eq Ok(1));
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq restart);
+            drop(new_publisher);
+            instances.push(new_sut);
+
+            // the subscriber left the previous instance
+            if let [.., previous_sut, _] = instances.as_slice() {
+                assert_that!(previous_sut.dynamic_config().number_of_subscribers(), eq 0);
+            }
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
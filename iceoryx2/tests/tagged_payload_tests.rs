@@ -0,0 +1,206 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::*;
+use iceoryx2_bb_testing::assert_that;
+
+#[derive(Debug, Clone, Copy, PartialEq, ZeroCopySend)]
+#[repr(C)]
+struct Start {
+    speed: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ZeroCopySend)]
+#[repr(C)]
+struct Shutdown {
+    delay_in_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ZeroCopySend, TaggedPayload)]
+#[repr(C, u32)]
+enum ControlMessage {
+    Start(Start),
+    Shutdown(Shutdown),
+    Reset,
+    Calibrate { offset: i16, gain: f32 } = 10,
+    SelfTest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ZeroCopySend)]
+#[repr(C)]
+struct Level {
+    enabled: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ZeroCopySend, TaggedPayload)]
+#[repr(C, u32)]
+enum Switch {
+    Off,
+    On(Level),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ZeroCopySend, TaggedPayload)]
+#[repr(u8)]
+enum Gear {
+    Park,
+    Drive(u32),
+}
+
+// the discriminants are a property of the type and do not depend on the service
+#[test]
+fn discriminants_follow_the_definition_of_the_enum() {
+    assert_that!(ControlMessage::Start(Start { speed: 0 }).discriminant(), eq 0);
+    assert_that!(ControlMessage::Shutdown(Shutdown { delay_in_ms: 0 }).discriminant(), eq 1);
+    assert_that!(ControlMessage::Reset.discriminant(), eq 2);
+    assert_that!(ControlMessage::Calibrate { offset: 0, gain: 0.0 }.discriminant(), eq 10);
+    assert_that!(ControlMessage::SelfTest.discriminant(), eq 11);
+    assert_that!(Switch::Off.discriminant(), eq 0);
+    assert_that!(Switch::On(Level { enabled: false }).discriminant(), eq 1);
+    assert_that!(Gear::Park.discriminant(), eq 0);
+    assert_that!(Gear::Drive(1).discriminant(), eq 1);
+}
+
+#[generic_tests::define]
+mod tagged_payload {
+    use super::*;
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "tagged_payload_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn every_variant_is_received<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe::<ControlMessage>()
+            .subscriber_max_buffer_size(8)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let variants = [
+            ControlMessage::Start(Start { speed: 12 }),
+            ControlMessage::Shutdown(Shutdown { delay_in_ms: 500 }),
+            ControlMessage::Reset,
+            ControlMessage::Calibrate {
+                offset: -3,
+                gain: 1.5,
+            },
+            ControlMessage::SelfTest,
+        ];
+
+        assert_that!(publisher.send_variant(Start { speed: 12 }), eq Ok(1));
+        assert_that!(publisher.send_variant(Shutdown { delay_in_ms: 500 }), eq Ok(1));
+        for variant in &variants[2..] {
+            assert_that!(publisher.send_variant(*variant), eq Ok(1));
+        }
+
+        for variant in variants {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq variant);
+            assert_that!(sample.discriminant(), eq variant.discriminant());
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn sample_with_corrupted_discriminant_is_rejected<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe::<ControlMessage>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let mut sample = publisher.loan_uninit().unwrap();
+        unsafe {
+            let payload = sample.payload_mut().as_mut_ptr();
+            payload.write_bytes(0, 1);
+            (payload as *mut u32).write(3);
+        }
+        unsafe { sample.assume_init() }.send().unwrap();
+
+        assert_that!(subscriber.receive().err(), eq Some(SubscriberReceiveError::CorruptedSample));
+
+        publisher.send_variant(ControlMessage::Reset).unwrap();
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq ControlMessage::Reset);
+    }
+
+    #[test]
+    fn sample_with_corrupted_discriminant_of_primitive_representation_is_rejected<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe::<Gear>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let mut sample = publisher.loan_uninit().unwrap();
+        unsafe {
+            let payload = sample.payload_mut().as_mut_ptr();
+            payload.write_bytes(0, 1);
+            (payload as *mut u8).write(2);
+        }
+        unsafe { sample.assume_init() }.send().unwrap();
+
+        assert_that!(subscriber.receive().err(), eq Some(SubscriberReceiveError::CorruptedSample));
+
+        publisher.send_variant(Gear::Drive(80)).unwrap();
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq Gear::Drive(80));
+    }
+
+    #[test]
+    fn sample_with_invalid_field_of_variant_is_rejected<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe::<Switch>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        // the discriminant is followed by the field of Level, a bool that is neither 0 nor 1
+        let mut sample = publisher.loan_uninit().unwrap();
+        unsafe {
+            let payload = sample.payload_mut().as_mut_ptr() as *mut u8;
+            payload.write_bytes(0, core::mem::size_of::<Switch>());
+            (payload as *mut u32).write(1);
+            payload.add(core::mem::size_of::<u32>()).write(7);
+        }
+        unsafe { sample.assume_init() }.send().unwrap();
+
+        assert_that!(subscriber.receive().err(), eq Some(SubscriberReceiveError::CorruptedSample));
+
+        publisher.send_variant(Level { enabled: true }).unwrap();
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq Switch::On(Level { enabled: true }));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
@@ -0,0 +1,183 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod tooling {
+    use std::fs::File;
+    use std::io::{BufReader, BufWriter};
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2::tooling::{Recorder, Replayer, ReplayerError, ReplayerPublishError};
+    use iceoryx2_bb_posix::clock::Time;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const NUMBER_OF_SAMPLES: u64 = 4;
+    const TIME_BETWEEN_SAMPLES: Duration = Duration::from_millis(25);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "tooling_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn generate_recording_path() -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "iox2_tooling_tests_{}.rec",
+            UniqueSystemId::new().unwrap().value()
+        ))
+    }
+
+    // records the samples of a `u64` service and returns the sent payloads with their
+    // time stamps
+    fn record_samples<Sut: Service>() -> (Vec<u8>, Vec<(u64, Time)>) {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create::<u64>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+
+        let untyped_service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .open_untyped()
+            .unwrap();
+        let subscriber = untyped_service.subscriber().create_untyped().unwrap();
+
+        let mut recorder = Recorder::new(vec![], &untyped_service);
+        let mut sent = vec![];
+        for n in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(0xdead_beef_0000 + n).unwrap();
+            let sample = subscriber.receive().unwrap().unwrap();
+            sent.push((0xdead_beef_0000 + n, sample.header().time_stamp()));
+            recorder.record(&sample).unwrap();
+            std::thread::sleep(TIME_BETWEEN_SAMPLES);
+        }
+
+        (recorder.into_inner(), sent)
+    }
+
+    #[test]
+    fn recorded_samples_are_replayed_bit_exact_with_their_time_stamps<Sut: Service>() {
+        let config = generate_isolated_config();
+        let path = generate_recording_path();
+
+        let (recording, sent) = record_samples::<Sut>();
+        std::fs::write(&path, &recording).unwrap();
+
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .allow_user_time_stamps(true)
+            .create::<u64>()
+            .unwrap();
+        let publisher = service.publisher().create_untyped().unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+
+        let mut replayer = Replayer::new(BufReader::new(File::open(&path).unwrap()));
+        let start = Instant::now();
+        let mut received = vec![];
+        while let Some(chunk) = replayer.next_chunk().unwrap() {
+            assert_that!(chunk.header().service_uuid().is_empty(), eq false);
+            assert_that!(chunk.header().len(), eq core::mem::size_of::<u64>());
+            assert_that!(replayer.publish(&publisher, &chunk), is_ok);
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload_as_bytes(), eq chunk.payload());
+            received.push((*sample, sample.header().time_stamp()));
+        }
+        let elapsed = start.elapsed();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_that!(received, eq sent);
+        let recorded_span = sent[sent.len() - 1].1.as_duration() - sent[0].1.as_duration();
+        assert_that!(elapsed, ge recorded_span);
+    }
+
+    #[test]
+    fn recording_can_be_written_into_a_file<Sut: Service>() {
+        let path = generate_recording_path();
+        let file = BufWriter::new(File::create(&path).unwrap());
+
+        let (file, sent) = {
+            let config = generate_isolated_config();
+            let service = Sut::new(&generate_name())
+                .publish_subscribe_with_custom_config(&config)
+                .open_or_create::<u64>()
+                .unwrap();
+            let publisher = service.publisher().create().unwrap();
+            let subscriber = service.subscriber().create().unwrap();
+
+            let mut recorder = Recorder::new(file, &service);
+            publisher.send_copy(1234).unwrap();
+            let sample = subscriber.receive().unwrap().unwrap();
+            recorder.record(&sample).unwrap();
+            recorder.flush().unwrap();
+            (recorder.into_inner(), sample.header().time_stamp())
+        };
+        drop(file);
+
+        let mut replayer = Replayer::new(BufReader::new(File::open(&path).unwrap()));
+        let chunk = replayer.next_chunk().unwrap().unwrap();
+        assert_that!(chunk.payload(), eq 1234u64.to_ne_bytes());
+        assert_that!(chunk.header().time_stamp(), eq sent);
+        assert_that!(chunk.header().type_details().size(), eq core::mem::size_of::<u64>());
+        assert_that!(replayer.next_chunk().unwrap(), is_none);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn truncated_recording_is_detected<Sut: Service>() {
+        let (recording, _) = record_samples::<Sut>();
+
+        for len in [1, 10, recording.len() / NUMBER_OF_SAMPLES as usize - 1] {
+            let mut replayer = Replayer::new(&recording[..len]);
+            assert_that!(replayer.next_chunk().err(), eq Some(ReplayerError::CorruptedRecord));
+        }
+
+        let mut corrupted = recording.clone();
+        corrupted[0] = b'X';
+        let mut replayer = Replayer::new(corrupted.as_slice());
+        assert_that!(replayer.next_chunk().err(), eq Some(ReplayerError::CorruptedRecord));
+    }
+
+    #[test]
+    fn replay_into_service_with_another_type_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let (recording, _) = record_samples::<Sut>();
+
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .allow_user_time_stamps(true)
+            .create::<u32>()
+            .unwrap();
+        let publisher = service.publisher().create_untyped().unwrap();
+
+        let mut replayer = Replayer::new(recording.as_slice());
+        let chunk = replayer.next_chunk().unwrap().unwrap();
+        assert_that!(replayer.publish(&publisher, &chunk).err(), eq Some(ReplayerPublishError::IncompatibleType));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
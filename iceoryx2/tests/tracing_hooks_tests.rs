@@ -0,0 +1,193 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod tracing_hooks {
+    use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use iceoryx2::port::tracing_hooks::TracingHooks;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::header::publish_subscribe::Header;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[derive(Debug, Default, ZeroCopySend)]
+    #[repr(C)]
+    struct TraceHeader {
+        trace_id: u64,
+    }
+
+    #[derive(Default)]
+    struct Counters {
+        loaned: AtomicUsize,
+        sent: AtomicUsize,
+        received: AtomicUsize,
+        released: AtomicUsize,
+        last_sequence_number: AtomicU64,
+    }
+
+    struct CountingHooks {
+        counters: Arc<Counters>,
+        trace_id: u64,
+    }
+
+    impl TracingHooks<TraceHeader> for CountingHooks {
+        fn on_loan(&self, _header: &Header, user_header: &mut TraceHeader) {
+            self.counters.loaned.fetch_add(1, Ordering::Relaxed);
+            user_header.trace_id = self.trace_id;
+        }
+
+        fn on_send(&self, header: &Header, user_header: &TraceHeader) {
+            assert_that!(user_header.trace_id, eq self.trace_id);
+            self.counters.sent.fetch_add(1, Ordering::Relaxed);
+            self.counters
+                .last_sequence_number
+                .store(header.sequence_number(), Ordering::Relaxed);
+        }
+
+        fn on_receive(&self, header: &Header, user_header: &TraceHeader) {
+            assert_that!(user_header.trace_id, eq self.trace_id);
+            assert_that!(header.sequence_number(), eq self.counters.last_sequence_number.load(Ordering::Relaxed));
+            self.counters.received.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn on_release(&self, _header: &Header, _user_header: &TraceHeader) {
+            self.counters.released.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "tracing_hooks_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn hooks_are_called_for_every_station_of_a_sample<Sut: Service>() {
+        const TRACE_ID: u64 = 0x7ace;
+        let config = generate_isolated_config();
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .user_header::<TraceHeader>()
+            .create::<u64>()
+            .unwrap();
+        let counters = Arc::new(Counters::default());
+
+        let mut publisher = service.publisher().create().unwrap();
+        publisher.set_tracing_hooks(Some(CountingHooks {
+            counters: counters.clone(),
+            trace_id: TRACE_ID,
+        }));
+        let mut subscriber = service.subscriber().create().unwrap();
+        subscriber.set_tracing_hooks(Some(CountingHooks {
+            counters: counters.clone(),
+            trace_id: TRACE_ID,
+        }));
+
+        for n in 1..=3 {
+            let sample = publisher.loan_uninit().unwrap();
+            assert_that!(counters.loaned.load(Ordering::Relaxed), eq n);
+            assert_that!(sample.user_header().trace_id, eq TRACE_ID);
+
+            assert_that!(sample.write_payload(n as u64).send(), eq Ok(1));
+            assert_that!(counters.sent.load(Ordering::Relaxed), eq n);
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(counters.received.load(Ordering::Relaxed), eq n);
+            assert_that!(sample.user_header().trace_id, eq TRACE_ID);
+
+            // only the last clone releases the sample
+            let clone = sample.clone();
+            drop(sample);
+            assert_that!(counters.released.load(Ordering::Relaxed), eq n - 1);
+            drop(clone);
+            assert_that!(counters.released.load(Ordering::Relaxed), eq n);
+        }
+
+        // a loan that is not sent does not reach the subscriber
+        publisher.loan_uninit().unwrap().cancel();
+        assert_that!(counters.loaned.load(Ordering::Relaxed), eq 4);
+        assert_that!(counters.sent.load(Ordering::Relaxed), eq 3);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(counters.received.load(Ordering::Relaxed), eq 3);
+    }
+
+    #[test]
+    fn removed_hooks_are_no_longer_called<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .user_header::<TraceHeader>()
+            .create::<u64>()
+            .unwrap();
+        let counters = Arc::new(Counters::default());
+
+        let mut publisher = service.publisher().create().unwrap();
+        publisher.set_tracing_hooks(Some(CountingHooks {
+            counters: counters.clone(),
+            trace_id: 1,
+        }));
+        publisher.set_tracing_hooks(None::<CountingHooks>);
+        let subscriber = service.subscriber().create().unwrap();
+
+        let sample = publisher.loan_uninit().unwrap();
+        assert_that!(sample.user_header().trace_id, eq 0);
+        assert_that!(sample.write_payload(1).send(), eq Ok(1));
+        drop(subscriber.receive().unwrap().unwrap());
+
+        assert_that!(counters.loaned.load(Ordering::Relaxed), eq 0);
+        assert_that!(counters.sent.load(Ordering::Relaxed), eq 0);
+    }
+
+    #[test]
+    fn hooks_of_sync_publisher_are_called_from_other_threads<Sut: Service>() {
+        const TRACE_ID: u64 = 0xbeef;
+        let config = generate_isolated_config();
+        let service = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .user_header::<TraceHeader>()
+            .create::<u64>()
+            .unwrap();
+        let counters = Arc::new(Counters::default());
+
+        let publisher = service.publisher().create().unwrap().into_sync();
+        publisher.set_tracing_hooks(Some(CountingHooks {
+            counters: counters.clone(),
+            trace_id: TRACE_ID,
+        }));
+        let subscriber = service.subscriber().create().unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let sample = publisher.loan_uninit().unwrap();
+                assert_that!(sample.user_header().trace_id, eq TRACE_ID);
+                assert_that!(sample.write_payload(1).send(), eq Ok(1));
+            });
+        });
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.user_header().trace_id, eq TRACE_ID);
+        assert_that!(counters.loaned.load(Ordering::Relaxed), eq 1);
+        assert_that!(counters.sent.load(Ordering::Relaxed), eq 1);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
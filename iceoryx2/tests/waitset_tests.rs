@@ -0,0 +1,167 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod waitset {
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::event::PortFactory;
+    use iceoryx2::waitset::{WaitSetAttachmentError, WaitSetBuilder, WaitSetRunError};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    // the port factory is returned with a 'static lifetime, therefore it uses the global config
+    // instead of an isolated one, the unique service names keep the tests apart
+    fn create_event() -> PortFactory<'static, zero_copy::Service<'static>> {
+        let service_name = ServiceName::new(&format!(
+            "waitset_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+
+        zero_copy::Service::new(&service_name)
+            .event()
+            .create()
+            .unwrap()
+    }
+
+    #[test]
+    fn waitset_reports_listener_with_pending_event() {
+        let event = create_event();
+        let mut listener = event.listener().create().unwrap();
+        let notifier = event.notifier().create().unwrap();
+
+        let sut = WaitSetBuilder::new().create().unwrap();
+        let guard = sut.attach(&listener).unwrap();
+        assert_that!(sut, len 1);
+
+        assert_that!(notifier.notify(), is_ok);
+
+        let mut triggered = vec![];
+        assert_that!(sut.wait_and_process(|id| triggered.push(id)), is_ok);
+        assert_that!(triggered, len 1);
+        assert_that!(triggered[0], eq guard.id());
+
+        assert_that!(listener.try_wait().unwrap(), len 1);
+    }
+
+    #[test]
+    fn waitset_reports_only_listeners_with_activity() {
+        let event_a = create_event();
+        let event_b = create_event();
+        let listener_a = event_a.listener().create().unwrap();
+        let listener_b = event_b.listener().create().unwrap();
+        let notifier_b = event_b.notifier().create().unwrap();
+
+        let sut = WaitSetBuilder::new().create().unwrap();
+        let guard_a = sut.attach(&listener_a).unwrap();
+        let guard_b = sut.attach(&listener_b).unwrap();
+        assert_that!(guard_a.id(), ne guard_b.id());
+
+        assert_that!(notifier_b.notify(), is_ok);
+
+        let mut triggered = vec![];
+        assert_that!(
+            sut.timed_wait_and_process(TIMEOUT, |id| triggered.push(id)),
+            is_ok
+        );
+        assert_that!(triggered, len 1);
+        assert_that!(triggered[0], eq guard_b.id());
+    }
+
+    #[test]
+    fn waitset_timed_wait_without_activity_does_not_call_callback() {
+        let event = create_event();
+        let listener = event.listener().create().unwrap();
+
+        let sut = WaitSetBuilder::new().create().unwrap();
+        let _guard = sut.attach(&listener).unwrap();
+
+        let mut number_of_calls = 0;
+        let start = Instant::now();
+        assert_that!(
+            sut.timed_wait_and_process(TIMEOUT, |_| number_of_calls += 1),
+            is_ok
+        );
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(number_of_calls, eq 0);
+
+        assert_that!(sut.try_wait_and_process(|_| number_of_calls += 1), is_ok);
+        assert_that!(number_of_calls, eq 0);
+    }
+
+    #[test]
+    fn waitset_reports_interval_after_it_has_passed() {
+        let event = create_event();
+        let listener = event.listener().create().unwrap();
+
+        let sut = WaitSetBuilder::new().create().unwrap();
+        let _listener_guard = sut.attach(&listener).unwrap();
+        let start = Instant::now();
+        let tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        for _ in 0..3 {
+            let mut triggered = vec![];
+            assert_that!(sut.wait_and_process(|id| triggered.push(id)), is_ok);
+            assert_that!(triggered, len 1);
+            assert_that!(triggered[0], eq tick_guard.id());
+        }
+
+        assert_that!(start.elapsed(), time_at_least TIMEOUT * 3);
+    }
+
+    #[test]
+    fn waitset_dropped_guard_detaches_attachment() {
+        let event = create_event();
+        let listener = event.listener().create().unwrap();
+        let notifier = event.notifier().create().unwrap();
+
+        let sut = WaitSetBuilder::new().create().unwrap();
+        let guard = sut.attach(&listener).unwrap();
+        let tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+        assert_that!(sut, len 2);
+
+        drop(guard);
+        drop(tick_guard);
+        assert_that!(sut, len 0);
+        assert_that!(sut.is_empty(), eq true);
+
+        assert_that!(notifier.notify(), is_ok);
+
+        let mut number_of_calls = 0;
+        assert_that!(
+            sut.timed_wait_and_process(TIMEOUT * 2, |_| number_of_calls += 1),
+            is_ok
+        );
+        assert_that!(number_of_calls, eq 0);
+    }
+
+    #[test]
+    fn waitset_blocking_wait_without_attachments_fails() {
+        let sut = WaitSetBuilder::new().create().unwrap();
+
+        let result = sut.wait_and_process(|_| {});
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq WaitSetRunError::NoAttachments);
+    }
+
+    #[test]
+    fn waitset_attaching_zero_interval_fails() {
+        let sut = WaitSetBuilder::new().create().unwrap();
+
+        let result = sut.attach_interval(Duration::ZERO);
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq WaitSetAttachmentError::InvalidInterval);
+    }
+}